@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'mixer.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'plugins/eq.dart';
+
+
+            
+
+            /// Start a live spectrum of a mixer strip. Every spectrum arrives as `num_points` log-spaced
+/// points over the same frequencies as `get_eq_response_curve`, so the EQ can draw one over the
+/// other. `overlap` is the share of each `fft_size` block the next one reuses (0.0–0.95).
+/// Returns the analyzer id for `stop_spectrum_stream`.
+Stream<List<UiResponseCurvePoint>>  createSpectrumStream({required UiRoutingNode node , required int fftSize , required double overlap , required int numPoints }) => RustLib.instance.api.crateApiAnalyzerCreateSpectrumStream(node: node, fftSize: fftSize, overlap: overlap, numPoints: numPoints);
+
+Future<void>  stopSpectrumStream({required int analyzerId }) => RustLib.instance.api.crateApiAnalyzerStopSpectrumStream(analyzerId: analyzerId);
+
+            
+            
\ No newline at end of file
@@ -4,119 +4,266 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'mixer.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 import 'project.dart';
+part 'audio.freezed.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+
+
+            Future<List<String>>  listAudioHosts() => RustLib.instance.api.crateApiAudioListAudioHosts();
+
+Future<List<UiAudioDeviceInfo>>  listAudioOutputDevices({String? host }) => RustLib.instance.api.crateApiAudioListAudioOutputDevices(host: host);
+
+Future<List<UiAudioDeviceInfo>>  listAudioInputDevices({String? host }) => RustLib.instance.api.crateApiAudioListAudioInputDevices(host: host);
+
+Future<UiAudioDeviceSettings>  getAudioDeviceSettings() => RustLib.instance.api.crateApiAudioGetAudioDeviceSettings();
+
+/// Saves the device settings and restarts the running stream on them
+Future<void>  setAudioDeviceSettings({required UiAudioDeviceSettings settings }) => RustLib.instance.api.crateApiAudioSetAudioDeviceSettings(settings: settings);
+
+/// Where to keep the user settings file, for platforms without a config directory
+Future<void>  setUserSettingsPath({required String path }) => RustLib.instance.api.crateApiAudioSetUserSettingsPath(path: path);
 
 /// GETTER: Fetch details + Downsampled Buffer for UI
-Future<AudioWaveformUiForAudioProperties?> getAudioProperties({
-  required int id,
-}) => RustLib.instance.api.crateApiAudioGetAudioProperties(id: id);
+Future<AudioWaveformUiForAudioProperties?>  getAudioProperties({required int id }) => RustLib.instance.api.crateApiAudioGetAudioProperties(id: id);
 
 /// ACTION: Play the sound via the Engine
-Future<void> playSourcePreview({required int id}) =>
-    RustLib.instance.api.crateApiAudioPlaySourcePreview(id: id);
+Future<void>  playSourcePreview({required int id }) => RustLib.instance.api.crateApiAudioPlaySourcePreview(id: id);
+
+Future<void>  stopAllPreviews() => RustLib.instance.api.crateApiAudioStopAllPreviews();
+
+Future<UiAudioHardwareConfig>  getAudioConfig() => RustLib.instance.api.crateApiAudioGetAudioConfig();
+
+/// Latest loudness analysis of the master output, none while no audio stream is running.
+Future<UiLoudnessReport?>  getLoudnessReport() => RustLib.instance.api.crateApiAudioGetLoudnessReport();
+
+Future<void>  resetLoudnessAnalyzer() => RustLib.instance.api.crateApiAudioResetLoudnessAnalyzer();
+
+/// Render the song into an audio file (format from the extension) and return its loudness.
+Future<UiLoudnessReport>  exportProject({required String path , required int sampleRate , required UiBitPerSample bitPerSample }) => RustLib.instance.api.crateApiAudioExportProject(path: path, sampleRate: sampleRate, bitPerSample: bitPerSample);
+
+/// Latest CPU load of the audio thread, none while no audio stream is running.
+Future<UiEngineLoad?>  getEngineLoad() => RustLib.instance.api.crateApiAudioGetEngineLoad();
 
-Future<void> stopAllPreviews() =>
-    RustLib.instance.api.crateApiAudioStopAllPreviews();
+Future<void>  resetEngineLoad() => RustLib.instance.api.crateApiAudioResetEngineLoad();
 
-Future<UiAudioHardwareConfig> getAudioConfig() =>
-    RustLib.instance.api.crateApiAudioGetAudioConfig();
+/// Frames the mix lags the playhead by, behind plugins with lookahead.
+Future<int>  getMixLatency() => RustLib.instance.api.crateApiAudioGetMixLatency();
 
-Stream<UiTransportFeedback> createPositionStream() =>
-    RustLib.instance.api.crateApiAudioCreatePositionStream();
+/// Times every plugin so [`get_engine_load`] can break the load down by plugin.
+Future<void>  setPluginProfiling({required bool enabled }) => RustLib.instance.api.crateApiAudioSetPluginProfiling(enabled: enabled);
+
+Future<bool>  isPluginProfiling() => RustLib.instance.api.crateApiAudioIsPluginProfiling();
+
+Stream<UiTransportFeedback>  createPositionStream() => RustLib.instance.api.crateApiAudioCreatePositionStream();
 
 /// play preview sound when drawing note or pressing the piano tile on the UI
-Future<void> playPreviewNote({
-  required int trackId,
-  required int noteKey,
-  required int velocity,
-  required bool isOn,
-}) => RustLib.instance.api.crateApiAudioPlayPreviewNote(
-  trackId: trackId,
-  noteKey: noteKey,
-  velocity: velocity,
-  isOn: isOn,
-);
+Future<void>  playPreviewNote({required int trackId , required int noteKey , required int velocity , required bool isOn }) => RustLib.instance.api.crateApiAudioPlayPreviewNote(trackId: trackId, noteKey: noteKey, velocity: velocity, isOn: isOn);
 
 /// Play preview sound directly on a generator (without requiring a track).
 /// Used in plugin editor screens to test synth sounds.
-Future<void> playPreviewNoteGenerator({
-  required int generatorId,
-  required int noteKey,
-  required int velocity,
-  required bool isOn,
-}) => RustLib.instance.api.crateApiAudioPlayPreviewNoteGenerator(
-  generatorId: generatorId,
-  noteKey: noteKey,
-  velocity: velocity,
-  isOn: isOn,
-);
-
-class UiTransportFeedback {
-  final int samples;
-  final int beat;
-  final int bar;
-  final double tempo;
-  final int sampleRate;
-  final bool isPlaying;
-  final bool isLooping;
-  final bool isRecording;
-  final bool isPatternPlaying;
-  final bool isPatternMode;
-  final int patternSamples;
-  final int patternBeat;
-  final int patternBar;
-
-  const UiTransportFeedback({
-    required this.samples,
-    required this.beat,
-    required this.bar,
-    required this.tempo,
-    required this.sampleRate,
-    required this.isPlaying,
-    required this.isLooping,
-    required this.isRecording,
-    required this.isPatternPlaying,
-    required this.isPatternMode,
-    required this.patternSamples,
-    required this.patternBeat,
-    required this.patternBar,
-  });
-
-  @override
-  int get hashCode =>
-      samples.hashCode ^
-      beat.hashCode ^
-      bar.hashCode ^
-      tempo.hashCode ^
-      sampleRate.hashCode ^
-      isPlaying.hashCode ^
-      isLooping.hashCode ^
-      isRecording.hashCode ^
-      isPatternPlaying.hashCode ^
-      isPatternMode.hashCode ^
-      patternSamples.hashCode ^
-      patternBeat.hashCode ^
-      patternBar.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UiTransportFeedback &&
-          runtimeType == other.runtimeType &&
-          samples == other.samples &&
-          beat == other.beat &&
-          bar == other.bar &&
-          tempo == other.tempo &&
-          sampleRate == other.sampleRate &&
-          isPlaying == other.isPlaying &&
-          isLooping == other.isLooping &&
-          isRecording == other.isRecording &&
-          isPatternPlaying == other.isPatternPlaying &&
-          isPatternMode == other.isPatternMode &&
-          patternSamples == other.patternSamples &&
-          patternBeat == other.patternBeat &&
-          patternBar == other.patternBar;
-}
+Future<void>  playPreviewNoteGenerator({required int generatorId , required int noteKey , required int velocity , required bool isOn }) => RustLib.instance.api.crateApiAudioPlayPreviewNoteGenerator(generatorId: generatorId, noteKey: noteKey, velocity: velocity, isOn: isOn);
+
+            class UiAudioDeviceInfo  {
+                final String name;
+final bool isDefault;
+final Uint32List sampleRates;
+final Uint16List channelCounts;
+final Uint32List bufferSizes;
+
+                const UiAudioDeviceInfo({required this.name ,required this.isDefault ,required this.sampleRates ,required this.channelCounts ,required this.bufferSizes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^isDefault.hashCode^sampleRates.hashCode^channelCounts.hashCode^bufferSizes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiAudioDeviceInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& isDefault == other.isDefault&& sampleRates == other.sampleRates&& channelCounts == other.channelCounts&& bufferSizes == other.bufferSizes;
+        
+            }
+
+/// Device choice, `None` fields leave it to the host
+class UiAudioDeviceSettings  {
+                final String? host;
+final String? outputDevice;
+final String? inputDevice;
+final int? sampleRate;
+final int? bufferSize;
+
+                const UiAudioDeviceSettings({this.host ,this.outputDevice ,this.inputDevice ,this.sampleRate ,this.bufferSize ,});
+
+                static Future<UiAudioDeviceSettings>  default_()=>RustLib.instance.api.crateApiAudioUiAudioDeviceSettingsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => host.hashCode^outputDevice.hashCode^inputDevice.hashCode^sampleRate.hashCode^bufferSize.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiAudioDeviceSettings &&
+                runtimeType == other.runtimeType
+                && host == other.host&& outputDevice == other.outputDevice&& inputDevice == other.inputDevice&& sampleRate == other.sampleRate&& bufferSize == other.bufferSize;
+        
+            }
+
+enum UiBitPerSample {
+                    b8,
+b16,
+b24,
+b32,
+                    ;
+                    
+                }
+
+/// CPU load of the audio thread as shares of the real-time budget, 1.0 being all of it.
+class UiEngineLoad  {
+                final double load;
+final double average;
+final double peak;
+final double maxPeak;
+final int overloads;
+final int xruns;
+/// Heaviest first, empty unless plugin profiling is on
+final List<UiPluginLoad> plugins;
+
+                const UiEngineLoad({required this.load ,required this.average ,required this.peak ,required this.maxPeak ,required this.overloads ,required this.xruns ,required this.plugins ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => load.hashCode^average.hashCode^peak.hashCode^maxPeak.hashCode^overloads.hashCode^xruns.hashCode^plugins.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiEngineLoad &&
+                runtimeType == other.runtimeType
+                && load == other.load&& average == other.average&& peak == other.peak&& maxPeak == other.maxPeak&& overloads == other.overloads&& xruns == other.xruns&& plugins == other.plugins;
+        
+            }
+
+/// Loudness of the master output: LUFS, LU for the range, dBTP for the peak.
+/// Negative infinity until there is enough signal to measure.
+class UiLoudnessReport  {
+                final double momentary;
+final double shortTerm;
+final double integrated;
+final double loudnessRange;
+final double truePeak;
+final double maxMomentary;
+final double maxShortTerm;
+
+                const UiLoudnessReport({required this.momentary ,required this.shortTerm ,required this.integrated ,required this.loudnessRange ,required this.truePeak ,required this.maxMomentary ,required this.maxShortTerm ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => momentary.hashCode^shortTerm.hashCode^integrated.hashCode^loudnessRange.hashCode^truePeak.hashCode^maxMomentary.hashCode^maxShortTerm.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiLoudnessReport &&
+                runtimeType == other.runtimeType
+                && momentary == other.momentary&& shortTerm == other.shortTerm&& integrated == other.integrated&& loudnessRange == other.loudnessRange&& truePeak == other.truePeak&& maxMomentary == other.maxMomentary&& maxShortTerm == other.maxShortTerm;
+        
+            }
+
+class UiPluginLoad  {
+                final UiPluginSlot slot;
+final double average;
+final double peak;
+
+                const UiPluginLoad({required this.slot ,required this.average ,required this.peak ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slot.hashCode^average.hashCode^peak.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiPluginLoad &&
+                runtimeType == other.runtimeType
+                && slot == other.slot&& average == other.average&& peak == other.peak;
+        
+            }
+
+@freezed
+                sealed class UiPluginSlot with _$UiPluginSlot  {
+                    const UiPluginSlot._();
+
+                     const factory UiPluginSlot.generator(  int field0,) = UiPluginSlot_Generator;
+ const factory UiPluginSlot.effect({   required UiRoutingNode node ,  required int effectId , }) = UiPluginSlot_Effect;
+
+                    
+
+                    
+                }
+
+class UiTransportFeedback  {
+                final int samples;
+final int beat;
+final int bar;
+final double tempo;
+final int sampleRate;
+final bool isPlaying;
+final bool isLooping;
+final bool isRecording;
+final bool isPatternPlaying;
+final bool isPatternMode;
+final int patternSamples;
+final int patternBeat;
+final int patternBar;
+
+                const UiTransportFeedback({required this.samples ,required this.beat ,required this.bar ,required this.tempo ,required this.sampleRate ,required this.isPlaying ,required this.isLooping ,required this.isRecording ,required this.isPatternPlaying ,required this.isPatternMode ,required this.patternSamples ,required this.patternBeat ,required this.patternBar ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => samples.hashCode^beat.hashCode^bar.hashCode^tempo.hashCode^sampleRate.hashCode^isPlaying.hashCode^isLooping.hashCode^isRecording.hashCode^isPatternPlaying.hashCode^isPatternMode.hashCode^patternSamples.hashCode^patternBeat.hashCode^patternBar.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiTransportFeedback &&
+                runtimeType == other.runtimeType
+                && samples == other.samples&& beat == other.beat&& bar == other.bar&& tempo == other.tempo&& sampleRate == other.sampleRate&& isPlaying == other.isPlaying&& isLooping == other.isLooping&& isRecording == other.isRecording&& isPatternPlaying == other.isPatternPlaying&& isPatternMode == other.isPatternMode&& patternSamples == other.patternSamples&& patternBeat == other.patternBeat&& patternBar == other.patternBar;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,68 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `from`, `from`
+
+
+            Future<String>  getMidiBackendName() => RustLib.instance.api.crateApiMidiGetMidiBackendName();
+
+Future<List<String>>  listMidiInputs() => RustLib.instance.api.crateApiMidiListMidiInputs();
+
+Future<List<String>>  listMidiOutputs() => RustLib.instance.api.crateApiMidiListMidiOutputs();
+
+/// open a MIDI input port by name, replacing the open one
+Future<void>  openMidiInput({required String port }) => RustLib.instance.api.crateApiMidiOpenMidiInput(port: port);
+
+Future<void>  closeMidiInput() => RustLib.instance.api.crateApiMidiCloseMidiInput();
+
+Future<String?>  getOpenMidiInput() => RustLib.instance.api.crateApiMidiGetOpenMidiInput();
+
+/// track played by the MIDI input while no MIDI track is armed (usually the selected track)
+Future<void>  setMidiInputTrack({int? trackId }) => RustLib.instance.api.crateApiMidiSetMidiInputTrack(trackId: trackId);
+
+/// open a MIDI output port by name, replacing the open one
+Future<void>  openMidiOutput({required String port }) => RustLib.instance.api.crateApiMidiOpenMidiOutput(port: port);
+
+Future<void>  closeMidiOutput() => RustLib.instance.api.crateApiMidiCloseMidiOutput();
+
+Future<String?>  getOpenMidiOutput() => RustLib.instance.api.crateApiMidiGetOpenMidiOutput();
+
+Future<UiMidiOutputSettings>  getMidiOutputSettings() => RustLib.instance.api.crateApiMidiGetMidiOutputSettings();
+
+Future<void>  setMidiOutputSettings({required UiMidiOutputSettings settings }) => RustLib.instance.api.crateApiMidiSetMidiOutputSettings(settings: settings);
+
+            /// What the engine sends to the MIDI output port
+class UiMidiOutputSettings  {
+                /// Send clock pulses and start/stop while the song plays
+final bool sendClock;
+/// Track whose notes are sent out
+final int? trackId;
+/// Channel of outgoing notes (0 - 15)
+final int channel;
+
+                const UiMidiOutputSettings({required this.sendClock ,this.trackId ,required this.channel ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sendClock.hashCode^trackId.hashCode^channel.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiMidiOutputSettings &&
+                runtimeType == other.runtimeType
+                && sendClock == other.sendClock&& trackId == other.trackId&& channel == other.channel;
+        
+            }
+            
\ No newline at end of file
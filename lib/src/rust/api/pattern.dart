@@ -5,156 +5,337 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'pattern.freezed.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `from`, `from`
-
-Future<UiPattern> getPattern({required int patternId}) =>
-    RustLib.instance.api.crateApiPatternGetPattern(patternId: patternId);
-
-Future<Map<int, UiPattern>> getPatterns() =>
-    RustLib.instance.api.crateApiPatternGetPatterns();
-
-Future<UiNote> addNote({
-  required int patternId,
-  required int key,
-  required int startTick,
-  int? duration,
-}) => RustLib.instance.api.crateApiPatternAddNote(
-  patternId: patternId,
-  key: key,
-  startTick: startTick,
-  duration: duration,
-);
-
-Future<UiNote> deleteNote({required int patternId, required int noteId}) =>
-    RustLib.instance.api.crateApiPatternDeleteNote(
-      patternId: patternId,
-      noteId: noteId,
-    );
-
-Future<UiNote> resizeNote({
-  required int patternId,
-  required int noteId,
-  required int newDuration,
-}) => RustLib.instance.api.crateApiPatternResizeNote(
-  patternId: patternId,
-  noteId: noteId,
-  newDuration: newDuration,
-);
-
-Future<UiNote> moveNote({
-  required int patternId,
-  required int noteId,
-  required int newStartTick,
-  required int newKey,
-}) => RustLib.instance.api.crateApiPatternMoveNote(
-  patternId: patternId,
-  noteId: noteId,
-  newStartTick: newStartTick,
-  newKey: newKey,
-);
-
-Future<UiNote> changeNoteParams({
-  required int patternId,
-  required int noteId,
-  int? velocity,
-  double? probability,
-  int? microOffset,
-  bool? mute,
-}) => RustLib.instance.api.crateApiPatternChangeNoteParams(
-  patternId: patternId,
-  noteId: noteId,
-  velocity: velocity,
-  probability: probability,
-  microOffset: microOffset,
-  mute: mute,
-);
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+
+
+            Future<UiPattern>  getPattern({required int patternId }) => RustLib.instance.api.crateApiPatternGetPattern(patternId: patternId);
+
+Future<Map<int, UiPattern>>  getPatterns() => RustLib.instance.api.crateApiPatternGetPatterns();
+
+Future<UiNote>  addNote({required int patternId , required int key , required int startTick , int? duration }) => RustLib.instance.api.crateApiPatternAddNote(patternId: patternId, key: key, startTick: startTick, duration: duration);
+
+Future<UiNote>  deleteNote({required int patternId , required int noteId }) => RustLib.instance.api.crateApiPatternDeleteNote(patternId: patternId, noteId: noteId);
+
+Future<UiNote>  resizeNote({required int patternId , required int noteId , required int newDuration }) => RustLib.instance.api.crateApiPatternResizeNote(patternId: patternId, noteId: noteId, newDuration: newDuration);
+
+Future<UiNote>  moveNote({required int patternId , required int noteId , required int newStartTick , required int newKey }) => RustLib.instance.api.crateApiPatternMoveNote(patternId: patternId, noteId: noteId, newStartTick: newStartTick, newKey: newKey);
+
+Future<UiNote>  changeNoteParams({required int patternId , required int noteId , int? velocity , double? probability , int? microOffset , bool? mute }) => RustLib.instance.api.crateApiPatternChangeNoteParams(patternId: patternId, noteId: noteId, velocity: velocity, probability: probability, microOffset: microOffset, mute: mute);
+
+/// quantize the selected notes, or the whole pattern when `note_ids` is empty (undoable)
+Future<List<UiNote>>  quantizeNotes({required int patternId , required List<int> noteIds , required UiQuantizeSettings settings }) => RustLib.instance.api.crateApiPatternQuantizeNotes(patternId: patternId, noteIds: noteIds, settings: settings);
+
+/// apply a bulk transform to the selected notes, or the whole pattern when `note_ids` is
+/// empty (undoable)
+Future<List<UiNote>>  transformNotes({required int patternId , required List<int> noteIds , required UiNoteTransform transform }) => RustLib.instance.api.crateApiPatternTransformNotes(patternId: patternId, noteIds: noteIds, transform: transform);
 
 /// Play a pattern in isolation with a specific generator (looping automatically).
 /// This temporarily switches the engine to Pattern playback mode.
-Future<void> playPatternPreview({
-  required int patternId,
-  required int generatorId,
-}) => RustLib.instance.api.crateApiPatternPlayPatternPreview(
-  patternId: patternId,
-  generatorId: generatorId,
-);
+Future<void>  playPatternPreview({required int patternId , required int generatorId }) => RustLib.instance.api.crateApiPatternPlayPatternPreview(patternId: patternId, generatorId: generatorId);
 
 /// Stop pattern preview and return to Song mode.
-Future<void> stopPatternPreview() =>
-    RustLib.instance.api.crateApiPatternStopPatternPreview();
-
-class UiNote {
-  final int id;
-  final int startTick;
-  final int duration;
-  final int key;
-  final int velocity;
-  final double probability;
-  final int microOffset;
-  final bool mute;
-
-  const UiNote({
-    required this.id,
-    required this.startTick,
-    required this.duration,
-    required this.key,
-    required this.velocity,
-    required this.probability,
-    required this.microOffset,
-    required this.mute,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      startTick.hashCode ^
-      duration.hashCode ^
-      key.hashCode ^
-      velocity.hashCode ^
-      probability.hashCode ^
-      microOffset.hashCode ^
-      mute.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UiNote &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          startTick == other.startTick &&
-          duration == other.duration &&
-          key == other.key &&
-          velocity == other.velocity &&
-          probability == other.probability &&
-          microOffset == other.microOffset &&
-          mute == other.mute;
-}
-
-class UiPattern {
-  final int id;
-  final String name;
-  final int lengthTicks;
-  final List<UiNote> notes;
-
-  const UiPattern({
-    required this.id,
-    required this.name,
-    required this.lengthTicks,
-    required this.notes,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^ name.hashCode ^ lengthTicks.hashCode ^ notes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UiPattern &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          name == other.name &&
-          lengthTicks == other.lengthTicks &&
-          notes == other.notes;
-}
+Future<void>  stopPatternPreview() => RustLib.instance.api.crateApiPatternStopPatternPreview();
+
+/// a swing groove, `percent` goes from 50 (straight) to 75
+Future<UiGroove>  swingGroove({required UiSwingDivision division , required double percent }) => RustLib.instance.api.crateApiPatternSwingGroove(division: division, percent: percent);
+
+/// get the groove a pattern plays with, if any
+Future<UiGroove?>  getPatternGroove({required int patternId }) => RustLib.instance.api.crateApiPatternGetPatternGroove(patternId: patternId);
+
+/// set or clear the groove a pattern plays with
+Future<void>  setPatternGroove({required int patternId , UiGroove? groove }) => RustLib.instance.api.crateApiPatternSetPatternGroove(patternId: patternId, groove: groove);
+
+/// set or clear the groove of one MIDI clip, played instead of its pattern's
+Future<void>  setClipGroove({required int trackId , required int clipId , UiGroove? groove }) => RustLib.instance.api.crateApiPatternSetClipGroove(trackId: trackId, clipId: clipId, groove: groove);
+
+/// take a groove template from the notes of a pattern
+Future<UiGroove>  grooveFromPattern({required int patternId , required int stepTicks }) => RustLib.instance.api.crateApiPatternGrooveFromPattern(patternId: patternId, stepTicks: stepTicks);
+
+/// take a groove template from the notes of a MIDI file
+Future<UiGroove>  grooveFromMidiFile({required String path , required int stepTicks }) => RustLib.instance.api.crateApiPatternGrooveFromMidiFile(path: path, stepTicks: stepTicks);
+
+/// write a pattern's groove into its notes (undoable)
+Future<void>  commitPatternGroove({required int patternId }) => RustLib.instance.api.crateApiPatternCommitPatternGroove(patternId: patternId);
+
+/// write a clip's groove into the notes of its pattern (undoable)
+Future<void>  commitClipGroove({required int trackId , required int clipId }) => RustLib.instance.api.crateApiPatternCommitClipGroove(trackId: trackId, clipId: clipId);
+
+/// get the key of a pattern, if any
+Future<UiScale?>  getPatternScale({required int patternId }) => RustLib.instance.api.crateApiPatternGetPatternScale(patternId: patternId);
+
+/// set the key of a pattern, `root` is a pitch class (0 = C). No mode clears it
+Future<void>  setPatternScale({required int patternId , required int root , UiScaleMode? mode }) => RustLib.instance.api.crateApiPatternSetPatternScale(patternId: patternId, root: root, mode: mode);
+
+/// ids of the notes outside the pattern's key, none when it has no key
+Future<Uint32List>  getOutOfKeyNotes({required int patternId }) => RustLib.instance.api.crateApiPatternGetOutOfKeyNotes(patternId: patternId);
+
+/// keys of a chord spelled up from `root_key`, without adding it
+Future<Uint8List>  chordKeys({required int rootKey , required UiChordType chordType , required int inversion }) => RustLib.instance.api.crateApiPatternChordKeys(rootKey: rootKey, chordType: chordType, inversion: inversion);
+
+/// chords played by the selected notes, or the whole pattern when `note_ids` is empty
+Future<List<UiChordSpan>>  analyseChords({required int patternId , required List<int> noteIds }) => RustLib.instance.api.crateApiPatternAnalyseChords(patternId: patternId, noteIds: noteIds);
+
+/// add the notes of a chord in one go (undoable)
+Future<List<UiNote>>  addChord({required int patternId , required int rootKey , required UiChordType chordType , required int inversion , required int startTick , int? duration }) => RustLib.instance.api.crateApiPatternAddChord(patternId: patternId, rootKey: rootKey, chordType: chordType, inversion: inversion, startTick: startTick, duration: duration);
+
+/// move the selected notes, or the whole pattern when `note_ids` is empty, into the
+/// pattern's key (undoable)
+Future<List<UiNote>>  snapToScale({required int patternId , required List<int> noteIds }) => RustLib.instance.api.crateApiPatternSnapToScale(patternId: patternId, noteIds: noteIds);
+
+            /// UI-compatible chord found in a run of notes
+class UiChordSpan  {
+                final int startTick;
+final int endTick;
+/// Pitch class of the root (0 = C)
+final int root;
+final UiChordType chordType;
+/// Pitch class of the lowest note of a slash chord
+final int? bass;
+/// e.g. "Am7" or "C/E"
+final String name;
+
+                const UiChordSpan({required this.startTick ,required this.endTick ,required this.root ,required this.chordType ,this.bass ,required this.name ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startTick.hashCode^endTick.hashCode^root.hashCode^chordType.hashCode^bass.hashCode^name.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiChordSpan &&
+                runtimeType == other.runtimeType
+                && startTick == other.startTick&& endTick == other.endTick&& root == other.root&& chordType == other.chordType&& bass == other.bass&& name == other.name;
+        
+            }
+
+enum UiChordType {
+                    major,
+minor,
+diminished,
+augmented,
+sus2,
+sus4,
+major6,
+minor6,
+dominant7,
+major7,
+minor7,
+minorMajor7,
+halfDiminished7,
+diminished7,
+add9,
+dominant9,
+major9,
+minor9,
+                    ;
+                    
+                }
+
+/// UI-compatible representation of a groove template
+class UiGroove  {
+                final String name;
+/// Grid the steps sit on, in ticks
+final int stepTicks;
+/// Ticks each step is moved by, negative is early
+final Int32List timings;
+/// Velocity multiplier of each step
+final Float32List velocities;
+/// How much of the groove is applied (0.0 - 1.0)
+final double amount;
+
+                const UiGroove({required this.name ,required this.stepTicks ,required this.timings ,required this.velocities ,required this.amount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^stepTicks.hashCode^timings.hashCode^velocities.hashCode^amount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiGroove &&
+                runtimeType == other.runtimeType
+                && name == other.name&& stepTicks == other.stepTicks&& timings == other.timings&& velocities == other.velocities&& amount == other.amount;
+        
+            }
+
+class UiNote  {
+                final int id;
+final int startTick;
+final int duration;
+final int key;
+final int velocity;
+final double probability;
+final int microOffset;
+final bool mute;
+
+                const UiNote({required this.id ,required this.startTick ,required this.duration ,required this.key ,required this.velocity ,required this.probability ,required this.microOffset ,required this.mute ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^startTick.hashCode^duration.hashCode^key.hashCode^velocity.hashCode^probability.hashCode^microOffset.hashCode^mute.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiNote &&
+                runtimeType == other.runtimeType
+                && id == other.id&& startTick == other.startTick&& duration == other.duration&& key == other.key&& velocity == other.velocity&& probability == other.probability&& microOffset == other.microOffset&& mute == other.mute;
+        
+            }
+
+@freezed
+                sealed class UiNoteTransform with _$UiNoteTransform  {
+                    const UiNoteTransform._();
+
+                     /// `fold_octaves` moves notes leaving 0-127 back by octaves, otherwise they are clamped
+const factory UiNoteTransform.transpose({   required int semitones ,  required bool foldOctaves , }) = UiNoteTransform_Transpose;
+ /// `root` is a pitch class (0 = C)
+const factory UiNoteTransform.scaleTranspose({   required int degrees ,  required int root ,  required UiScaleMode mode , }) = UiNoteTransform_ScaleTranspose;
+ const factory UiNoteTransform.velocityScale({   required double factor , }) = UiNoteTransform_VelocityScale;
+ const factory UiNoteTransform.velocityCompress({   required double amount , }) = UiNoteTransform_VelocityCompress;
+ const factory UiNoteTransform.velocityRandomize({   required int amount , }) = UiNoteTransform_VelocityRandomize;
+ const factory UiNoteTransform.legato() = UiNoteTransform_Legato;
+ const factory UiNoteTransform.reverse() = UiNoteTransform_Reverse;
+ const factory UiNoteTransform.invert({   required int pivot , }) = UiNoteTransform_Invert;
+ const factory UiNoteTransform.timeStretch({   required double factor , }) = UiNoteTransform_TimeStretch;
+ const factory UiNoteTransform.humanize({   required int timing ,  required int velocity , }) = UiNoteTransform_Humanize;
+ const factory UiNoteTransform.splitOverlaps() = UiNoteTransform_SplitOverlaps;
+ const factory UiNoteTransform.mergeOverlaps() = UiNoteTransform_MergeOverlaps;
+ /// `root` is a pitch class (0 = C)
+const factory UiNoteTransform.snapToScale({   required int root ,  required UiScaleMode mode , }) = UiNoteTransform_SnapToScale;
+
+                    
+
+                    
+                }
+
+class UiPattern  {
+                final int id;
+final String name;
+final int lengthTicks;
+final List<UiNote> notes;
+
+                const UiPattern({required this.id ,required this.name ,required this.lengthTicks ,required this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^lengthTicks.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiPattern &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& lengthTicks == other.lengthTicks&& notes == other.notes;
+        
+            }
+
+/// UI-compatible quantize options
+class UiQuantizeSettings  {
+                /// Note value of the grid (4 = quarter, 16 = sixteenth)
+final int noteValue;
+final bool triplet;
+/// How far notes move towards the grid (0.0 - 1.0)
+final double strength;
+/// Fraction of half a grid step a note may be off and still move (1.0 moves all)
+final double window;
+final bool quantizeEnds;
+/// 50 (straight) - 75
+final double swing;
+
+                const UiQuantizeSettings({required this.noteValue ,required this.triplet ,required this.strength ,required this.window ,required this.quantizeEnds ,required this.swing ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => noteValue.hashCode^triplet.hashCode^strength.hashCode^window.hashCode^quantizeEnds.hashCode^swing.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiQuantizeSettings &&
+                runtimeType == other.runtimeType
+                && noteValue == other.noteValue&& triplet == other.triplet&& strength == other.strength&& window == other.window&& quantizeEnds == other.quantizeEnds&& swing == other.swing;
+        
+            }
+
+/// UI-compatible key of a pattern
+class UiScale  {
+                /// Pitch class of the root (0 = C)
+final int root;
+final UiScaleMode mode;
+/// e.g. "D Dorian"
+final String name;
+
+                const UiScale({required this.root ,required this.mode ,required this.name ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => root.hashCode^mode.hashCode^name.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiScale &&
+                runtimeType == other.runtimeType
+                && root == other.root&& mode == other.mode&& name == other.name;
+        
+            }
+
+enum UiScaleMode {
+                    major,
+naturalMinor,
+harmonicMinor,
+melodicMinor,
+dorian,
+phrygian,
+lydian,
+mixolydian,
+locrian,
+majorPentatonic,
+minorPentatonic,
+blues,
+                    ;
+                    
+                }
+
+enum UiSwingDivision {
+                    eighth,
+sixteenth,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,91 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'project.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `from`, `from`, `from`, `from`
+
+
+            Future<List<String>>  listInputDevices() => RustLib.instance.api.crateApiRecordingListInputDevices();
+
+/// open an input device by name (`None` for the system default), returns the opened device name
+Future<String>  setInputDevice({String? name }) => RustLib.instance.api.crateApiRecordingSetInputDevice(name: name);
+
+Future<void>  closeInputDevice() => RustLib.instance.api.crateApiRecordingCloseInputDevice();
+
+/// arm or disarm an audio or MIDI track, armed tracks receive the take when recording stops
+Future<void>  setTrackRecordArm({required int trackId , required bool armed }) => RustLib.instance.api.crateApiRecordingSetTrackRecordArm(trackId: trackId, armed: armed);
+
+Future<Uint32List>  getArmedTracks() => RustLib.instance.api.crateApiRecordingGetArmedTracks();
+
+Future<void>  setTrackMonitorMode({required int trackId , required UiMonitorMode mode }) => RustLib.instance.api.crateApiRecordingSetTrackMonitorMode(trackId: trackId, mode: mode);
+
+Future<UiMonitorMode>  getTrackMonitorMode({required int trackId }) => RustLib.instance.api.crateApiRecordingGetTrackMonitorMode(trackId: trackId);
+
+Future<UiMidiRecordMode>  getMidiRecordMode() => RustLib.instance.api.crateApiRecordingGetMidiRecordMode();
+
+/// input quantize grid for recorded notes in ticks, `None` when notes are kept as played
+Future<int?>  getMidiRecordQuantize() => RustLib.instance.api.crateApiRecordingGetMidiRecordQuantize();
+
+/// `quantize_ticks` snaps recorded note starts to a grid of that many ticks, `None` disables it
+Future<void>  setMidiRecordSettings({required UiMidiRecordMode mode , int? quantizeTicks }) => RustLib.instance.api.crateApiRecordingSetMidiRecordSettings(mode: mode, quantizeTicks: quantizeTicks);
+
+/// start playback and record the input and live notes onto the armed tracks
+Future<void>  startRecording() => RustLib.instance.api.crateApiRecordingStartRecording();
+
+/// stop playback and recording, returns the clips created from the take (positions in samples)
+Future<List<UiRecordedClip>>  stopRecording() => RustLib.instance.api.crateApiRecordingStopRecording();
+
+Future<bool>  isRecording() => RustLib.instance.api.crateApiRecordingIsRecording();
+
+            /// How recorded notes combine with the notes already in the pattern
+enum UiMidiRecordMode {
+                    /// Keep existing notes and add the new ones
+overdub,
+/// Remove existing notes that start inside the recorded range
+replace,
+                    ;
+                    
+                }
+
+/// When an armed audio track plays its live input
+enum UiMonitorMode {
+                    off,
+/// Monitor whenever the track is armed
+always,
+/// Monitor while armed and the transport is stopped or recording
+auto,
+                    ;
+                    
+                }
+
+/// Recorded clip together with the track it was placed on
+class UiRecordedClip  {
+                final int trackId;
+final UiClip clip;
+
+                const UiRecordedClip({required this.trackId ,required this.clip ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => trackId.hashCode^clip.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiRecordedClip &&
+                runtimeType == other.runtimeType
+                && trackId == other.trackId&& clip == other.clip;
+        
+            }
+            
\ No newline at end of file
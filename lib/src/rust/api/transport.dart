@@ -6,23 +6,187 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// set the play state of the transport
-Future<void> setPlaying({required bool val}) =>
-    RustLib.instance.api.crateApiTransportSetPlaying(val: val);
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`, `from`
+
+
+            /// set the play state of the transport
+Future<void>  setPlaying({required bool val }) => RustLib.instance.api.crateApiTransportSetPlaying(val: val);
 
 /// set what position the playhead is at (in samples)
-Future<void> setPlayhead({required int val}) =>
-    RustLib.instance.api.crateApiTransportSetPlayhead(val: val);
+Future<void>  setPlayhead({required int val }) => RustLib.instance.api.crateApiTransportSetPlayhead(val: val);
 
 /// set whether the transport is looping
-Future<void> setLooping({required bool val}) =>
-    RustLib.instance.api.crateApiTransportSetLooping(val: val);
+Future<void>  setLooping({required bool val }) => RustLib.instance.api.crateApiTransportSetLooping(val: val);
+
+/// set the loop in/out points (in samples) used when looping is enabled
+Future<void>  setLoopRegion({required int start , required int end }) => RustLib.instance.api.crateApiTransportSetLoopRegion(start: start, end: end);
+
+/// clear the loop in/out points so looping covers the whole song
+Future<void>  clearLoopRegion() => RustLib.instance.api.crateApiTransportClearLoopRegion();
+
+/// get the loop in/out points (in samples), if any
+Future<UiLoopRegion?>  getLoopRegion() => RustLib.instance.api.crateApiTransportGetLoopRegion();
 
 /// set the BPM of the transport.
 /// writes to both ApplicationState (for serialization) and AudioCommand (for audio thread)
-Future<void> setBpm({required double val}) =>
-    RustLib.instance.api.crateApiTransportSetBpm(val: val);
+Future<void>  setBpm({required double val }) => RustLib.instance.api.crateApiTransportSetBpm(val: val);
 
 /// stop the song playback and reset the playhead to 0
-Future<void> stopSongPlayback() =>
-    RustLib.instance.api.crateApiTransportStopSongPlayback();
+Future<void>  stopSongPlayback() => RustLib.instance.api.crateApiTransportStopSongPlayback();
+
+/// get all points of the project tempo map, ordered by tick
+Future<List<UiTempoPoint>>  getTempoPoints() => RustLib.instance.api.crateApiTransportGetTempoPoints();
+
+/// insert or replace a tempo point at `tick`
+Future<void>  setTempoPoint({required int tick , required double bpm , required bool linear }) => RustLib.instance.api.crateApiTransportSetTempoPoint(tick: tick, bpm: bpm, linear: linear);
+
+/// remove the tempo point at `tick`. The initial tempo point cannot be removed
+Future<UiTempoPoint>  removeTempoPoint({required int tick }) => RustLib.instance.api.crateApiTransportRemoveTempoPoint(tick: tick);
+
+/// convert a tick position into samples using the current tempo map
+Future<int>  ticksToSamples({required int tick }) => RustLib.instance.api.crateApiTransportTicksToSamples(tick: tick);
+
+/// convert a sample position into ticks using the current tempo map
+Future<int>  samplesToTicks({required int sample }) => RustLib.instance.api.crateApiTransportSamplesToTicks(sample: sample);
+
+/// get all time signature changes, ordered by bar
+Future<List<UiTimeSignatureEvent>>  getTimeSignatures() => RustLib.instance.api.crateApiTransportGetTimeSignatures();
+
+/// set the time signature starting on `bar` (0-based). Bar 0 changes the project signature
+Future<void>  setTimeSignature({required int bar , required int numerator , required int denominator }) => RustLib.instance.api.crateApiTransportSetTimeSignature(bar: bar, numerator: numerator, denominator: denominator);
+
+/// remove the time signature change on `bar`. The initial signature cannot be removed
+Future<UiTimeSignatureEvent>  removeTimeSignature({required int bar }) => RustLib.instance.api.crateApiTransportRemoveTimeSignature(bar: bar);
+
+/// get the metronome and count-in settings
+Future<UiMetronomeSettings>  getMetronomeSettings() => RustLib.instance.api.crateApiTransportGetMetronomeSettings();
+
+/// enable or disable the metronome click during song playback
+Future<void>  setMetronomeEnabled({required bool val }) => RustLib.instance.api.crateApiTransportSetMetronomeEnabled(val: val);
+
+/// set the metronome click gain (0.0 - 1.0)
+Future<void>  setMetronomeVolume({required double val }) => RustLib.instance.api.crateApiTransportSetMetronomeVolume(val: val);
+
+/// route the metronome to a bus, or `None` for the direct audio output
+Future<void>  setMetronomeTarget({int? busId }) => RustLib.instance.api.crateApiTransportSetMetronomeTarget(busId: busId);
+
+/// set how many bars (0 - 2) are counted in before playback starts
+Future<void>  setCountInBars({required int bars }) => RustLib.instance.api.crateApiTransportSetCountInBars(bars: bars);
+
+/// get the seed of the note probability rolls
+Future<int>  getRandomSeed() => RustLib.instance.api.crateApiTransportGetRandomSeed();
+
+/// set the seed of the note probability rolls, the same seed always plays the same notes
+Future<void>  setRandomSeed({required int seed }) => RustLib.instance.api.crateApiTransportSetRandomSeed(seed: seed);
+
+            /// UI-compatible representation of the loop region, in samples
+class UiLoopRegion  {
+                final int start;
+final int end;
+
+                const UiLoopRegion({required this.start ,required this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiLoopRegion &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end;
+        
+            }
+
+/// UI-compatible representation of the metronome settings
+class UiMetronomeSettings  {
+                final bool enabled;
+/// Click gain (0.0 - 1.0)
+final double volume;
+/// Bus receiving the click, `None` for the direct audio output
+final int? targetBusId;
+/// Bars of count-in before playback (0 - 2)
+final int countInBars;
+
+                const UiMetronomeSettings({required this.enabled ,required this.volume ,this.targetBusId ,required this.countInBars ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => enabled.hashCode^volume.hashCode^targetBusId.hashCode^countInBars.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiMetronomeSettings &&
+                runtimeType == other.runtimeType
+                && enabled == other.enabled&& volume == other.volume&& targetBusId == other.targetBusId&& countInBars == other.countInBars;
+        
+            }
+
+/// UI-compatible representation of a tempo map point
+class UiTempoPoint  {
+                /// Position in ticks (960 per quarter note)
+final int tick;
+final double bpm;
+/// Whether the tempo ramps linearly from this point to the next one
+final bool linear;
+
+                const UiTempoPoint({required this.tick ,required this.bpm ,required this.linear ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tick.hashCode^bpm.hashCode^linear.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiTempoPoint &&
+                runtimeType == other.runtimeType
+                && tick == other.tick&& bpm == other.bpm&& linear == other.linear;
+        
+            }
+
+/// UI-compatible representation of a time signature change
+class UiTimeSignatureEvent  {
+                /// Bar (0-based) where the signature starts
+final int bar;
+final int numerator;
+final int denominator;
+
+                const UiTimeSignatureEvent({required this.bar ,required this.numerator ,required this.denominator ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bar.hashCode^numerator.hashCode^denominator.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiTimeSignatureEvent &&
+                runtimeType == other.runtimeType
+                && bar == other.bar&& numerator == other.numerator&& denominator == other.denominator;
+        
+            }
+            
\ No newline at end of file
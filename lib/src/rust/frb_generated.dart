@@ -3,12 +3,15 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/analyzer.dart';
 import 'api/audio.dart';
+import 'api/midi.dart';
 import 'api/mixer.dart';
 import 'api/pattern.dart';
 import 'api/plugin.dart';
 import 'api/plugins/eq.dart';
 import 'api/project.dart';
+import 'api/recording.dart';
 import 'api/serialization.dart';
 import 'api/session.dart';
 import 'api/simple.dart';
@@ -17,8503 +20,8508 @@ import 'api/transport.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => -917136446;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'karbeat_flutter_ffi',
-        ioDirectory: 'rust/karbeat-flutter-ffi/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<int> crateApiProjectAddAudioSource({required String filePath});
-
-  Future<void> crateApiMixerAddEffectToBus({
-    required int busId,
-    required int registryId,
-  });
-
-  Future<void> crateApiMixerAddEffectToMasterBus({required int registryId});
-
-  Future<void> crateApiMixerAddEffectToMixerChannelById({
-    required int trackId,
-    required int registryId,
-  });
-
-  Future<UiTrack> crateApiTrackAddMidiTrackWithGeneratorId({
-    required int registryId,
-  });
-
-  Future<UiTrack> crateApiProjectAddNewAudioTrack();
-
-  Future<UiNote> crateApiPatternAddNote({
-    required int patternId,
-    required int key,
-    required int startTick,
-    int? duration,
-  });
-
-  UiAudioHardwareConfig crateApiProjectAudioHardwareConfigNew();
-
-  UiAudioHardwareConfig crateApiProjectAudioHardwareConfigNewWithParam({
-    required String selectedInputDevice,
-    required String selectedOutputDevice,
-    required int sampleRate,
-    required int bufferSize,
-    required double cpuLoad,
-  });
-
-  Future<UiNote> crateApiPatternChangeNoteParams({
-    required int patternId,
-    required int noteId,
-    int? velocity,
-    double? probability,
-    int? microOffset,
-    bool? mute,
-  });
-
-  Future<void> crateApiTrackChangeTrackColor({
-    required int trackId,
-    required String newColor,
-  });
-
-  Future<void> crateApiTrackChangeTrackName({
-    required int trackId,
-    required String newName,
-  });
-
-  Future<UiClipboardContent> crateApiSessionCopyClips({
-    required int trackId,
-    required List<int> clipIds,
-  });
-
-  Future<UiClipboardContent> crateApiSessionCopyPatternNotes({
-    required int patternId,
-    required List<int> noteIds,
-  });
-
-  Future<int> crateApiMixerCreateBus({required String name});
-
-  Future<UiClip> crateApiTrackCreateClip({
-    int? sourceId,
-    required UiSourceType sourceType,
-    required int trackId,
-    required int startTime,
-  });
-
-  Stream<UiMixerParamEvent> crateApiMixerCreateMixerEventStream();
-
-  Stream<UiTransportFeedback> crateApiAudioCreatePositionStream();
-
-  Future<List<UiClip>> crateApiTrackCutClip({
-    required int sourceTrackId,
-    required int clipId,
-    required int cutPointSample,
-  });
-
-  Future<void> crateApiSessionCutClips({
-    required int trackId,
-    required List<int> clipIds,
-  });
-
-  Future<void> crateApiSessionCutPatternNotes({
-    required int patternId,
-    required List<int> noteIds,
-  });
-
-  Future<void> crateApiMixerDeleteBus({required int busId});
-
-  Future<void> crateApiTrackDeleteClip({
-    required int trackId,
-    required int clipId,
-  });
-
-  Future<void> crateApiTrackDeleteClipBatch({
-    required int trackId,
-    required List<int> clipIds,
-  });
-
-  Future<void> crateApiSessionDeleteClips({
-    required int trackId,
-    required List<int> clipIds,
-  });
-
-  Future<UiNote> crateApiPatternDeleteNote({
-    required int patternId,
-    required int noteId,
-  });
-
-  Future<void> crateApiSessionDeletePatternNotes({
-    required int patternId,
-    required List<int> noteIds,
-  });
-
-  Future<String> crateApiPluginExecuteEffectInstanceCommand({
-    required UiEffectTarget target,
-    required int effectId,
-    required String command,
-    required String payloadJson,
-  });
-
-  Future<String> crateApiPluginExecuteGeneratorInstanceCommand({
-    required int generatorId,
-    required String command,
-    required String payloadJson,
-  });
-
-  Future<String?> crateApiPluginExecutePluginCommandEffect({
-    required int effectRegistryId,
-    required String command,
-    required String payloadJson,
-  });
 
-  Future<String?> crateApiPluginExecutePluginCommandGenerator({
-    required int genRegistryId,
-    required String command,
-    required String payloadJson,
-  });
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  Future<UiAudioHardwareConfig> crateApiAudioGetAudioConfig();
+                  RustLib._();
 
-  Future<AudioWaveformUiForAudioProperties?> crateApiAudioGetAudioProperties({
-    required int id,
-  });
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  Future<Map<int, AudioWaveformUiForSourceList>?>
-  crateApiProjectGetAudioSourceList();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  Future<Map<int, AudioWaveformUiForClip>>
-  crateApiTrackGetAudioWaveformClipsData();
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  Future<AudioWaveformUiForClip> crateApiTrackGetAudioWaveformForClip({
-    required int audioSourceId,
-  });
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  Future<Map<int, AudioWaveformUiForClip>>
-  crateApiTrackGetAudioWaveformForClipAllAvailableInTracks();
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  Future<Map<int, AudioWaveformUiForClip>>
-  crateApiTrackGetAudioWaveformForClipOnlyInSpecificTrack({
-    required int trackId,
-  });
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
 
-  Future<List<UiPluginInfo>> crateApiPluginGetAvailableEffectsWithIds();
+                  }
 
-  Future<List<UiPluginInfo>> crateApiPluginGetAvailableGeneratorsWithIds();
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  Future<List<ParameterSpecDTO>?> crateApiMixerGetBusMixerChannelSpecs({
-    required int busId,
-  });
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-  Future<Map<int, UiBus>> crateApiMixerGetBuses();
+                  @override
+                  int get rustContentHash => -773139399;
 
-  Future<UiClip> crateApiTrackGetClip({
-    required int trackId,
-    required int clipId,
-  });
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'karbeat_flutter_ffi',
+                    ioDirectory: 'rust/karbeat-flutter-ffi/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  Future<UiClipboardContent> crateApiSessionGetClipboardContents();
+                abstract class RustLibApi extends BaseApi {
+                  Future<int> crateApiProjectAddAudioSource({required String filePath });
 
-  Future<UiEffectInstance> crateApiPluginGetEffect({
-    required int trackId,
-    required int effectId,
-  });
+Future<List<UiNote>> crateApiPatternAddChord({required int patternId , required int rootKey , required UiChordType chordType , required int inversion , required int startTick , int? duration });
 
-  Future<UiEffectInstance> crateApiPluginGetEffectFromMaster({
-    required int effectId,
-  });
+Future<void> crateApiMixerAddEffectToBus({required int busId , required int registryId });
 
-  Future<List<UiPluginParameter>> crateApiPluginGetEffectParameterSpecs({
-    required UiEffectTarget target,
-    required int effectId,
-  });
+Future<void> crateApiMixerAddEffectToMasterBus({required int registryId });
 
-  Future<List<UiEffectInstance>> crateApiPluginGetEffectsFromTrack({
-    required int trackId,
-  });
+Future<void> crateApiMixerAddEffectToMixerChannelById({required int trackId , required int registryId });
 
-  Future<List<UiResponseCurvePoint>> crateApiPluginsEqGetEqResponseCurve({
-    required UiEffectTarget target,
-    required int effectId,
-    required int numPoints,
-  });
+Future<UiTrack> crateApiTrackAddMidiTrackWithGeneratorId({required int registryId });
 
-  Future<UiGeneratorInstance> crateApiPluginGetGenerator({
-    required int generatorId,
-  });
+Future<UiTrack> crateApiProjectAddNewAudioTrack();
 
-  Future<Map<int, UiGeneratorInstance>> crateApiProjectGetGeneratorList();
+Future<UiNote> crateApiPatternAddNote({required int patternId , required int key , required int startTick , int? duration });
 
-  Future<double> crateApiPluginGetGeneratorParameter({
-    required int generatorId,
-    required int paramId,
-  });
+Future<List<UiChordSpan>> crateApiPatternAnalyseChords({required int patternId , required List<int> noteIds });
 
-  Future<List<UiPluginParameter>> crateApiPluginGetGeneratorParameterSpecs({
-    required int generatorId,
-  });
+UiAudioHardwareConfig crateApiProjectAudioHardwareConfigNew();
 
-  Future<UiMixerChannel> crateApiMixerGetMasterBus();
+UiAudioHardwareConfig crateApiProjectAudioHardwareConfigNewWithParam({required String selectedInputDevice , required String selectedOutputDevice , required int sampleRate , required int bufferSize , required double cpuLoad });
 
-  Future<List<UiEffectInstance>> crateApiMixerGetMasterBusPopulated();
+Future<UiNote> crateApiPatternChangeNoteParams({required int patternId , required int noteId , int? velocity , double? probability , int? microOffset , bool? mute });
 
-  Future<List<ParameterSpecDTO>> crateApiMixerGetMasterChannelSpecs();
+Future<void> crateApiTrackChangeTrackColor({required int trackId , required String newColor });
 
-  Future<List<UiEffectInstance>> crateApiPluginGetMasterEffects();
+Future<void> crateApiTrackChangeTrackName({required int trackId , required String newName });
 
-  Future<int> crateApiProjectGetMaxSampleIndex();
+Future<Uint8List> crateApiPatternChordKeys({required int rootKey , required UiChordType chordType , required int inversion });
 
-  Future<UiMixerChannel> crateApiMixerGetMixerChannel({required int trackId});
+Future<void> crateApiTransportClearLoopRegion();
 
-  Future<(UiMixerChannel, List<UiEffectInstance>)>
-  crateApiMixerGetMixerChannelPopulated({required int trackId});
+Future<void> crateApiRecordingCloseInputDevice();
 
-  Future<UiMixerState> crateApiMixerGetMixerState();
+Future<void> crateApiMidiCloseMidiInput();
 
-  Future<UiPattern> crateApiPatternGetPattern({required int patternId});
+Future<void> crateApiMidiCloseMidiOutput();
 
-  Future<Map<int, UiPattern>> crateApiPatternGetPatterns();
+Future<void> crateApiPatternCommitClipGroove({required int trackId , required int clipId });
 
-  Future<UiProjectMetadata> crateApiProjectGetProjectMetadata();
+Future<void> crateApiPatternCommitPatternGroove({required int patternId });
 
-  Future<List<UiRoutingConnection>> crateApiMixerGetRoutingMatrix();
+Future<UiClipboardContent> crateApiSessionCopyClips({required int trackId , required List<int> clipIds });
 
-  Future<UiTrack> crateApiTrackGetTrack({required int trackId});
+Future<UiClipboardContent> crateApiSessionCopyPatternNotes({required int patternId , required List<int> noteIds });
 
-  Future<List<ParameterSpecDTO>?> crateApiMixerGetTrackMixerChannelSpecs({
-    required int trackId,
-  });
+Future<int> crateApiMixerCreateBus({required String name });
 
-  Future<Map<int, UiTrack>> crateApiProjectGetTracks();
+Future<UiClip> crateApiTrackCreateClip({int? sourceId , required UiSourceType sourceType , required int trackId , required int startTime });
 
-  Future<UiTransportState> crateApiProjectGetTransportState();
+Stream<List<UiMeterLevels>> crateApiMixerCreateMeterStream();
 
-  String crateApiSimpleGreet({required String name});
+Stream<UiMixerParamEvent> crateApiMixerCreateMixerEventStream();
 
-  Future<void> crateApiSimpleInitApp();
+Stream<UiTransportFeedback> crateApiAudioCreatePositionStream();
 
-  Future<UiApplicationState> crateApiSerializationLoadProject({
-    required String pathName,
-  });
+Stream<List<UiResponseCurvePoint>> crateApiAnalyzerCreateSpectrumStream({required UiRoutingNode node , required int fftSize , required double overlap , required int numPoints });
 
-  Future<void> crateApiSessionMoveClip({
-    required int oldTrackId,
-    required int newTrackId,
-    required int clipId,
-    required int newStartTime,
-  });
+Future<List<UiClip>> crateApiTrackCutClip({required int sourceTrackId , required int clipId , required int cutPointSample });
 
-  Future<UiClip> crateApiTrackMoveClip({
-    required int sourceTrackId,
-    required int clipId,
-    required int newStartTime,
-    int? newTrackId,
-  });
+Future<void> crateApiSessionCutClips({required int trackId , required List<int> clipIds });
 
-  Future<List<UiClip>> crateApiTrackMoveClipBatch({
-    required int sourceTrackId,
-    required List<int> clipIds,
-    required int deltaSamples,
-    int? newTrackId,
-  });
+Future<void> crateApiSessionCutPatternNotes({required int patternId , required List<int> noteIds });
 
-  Future<UiNote> crateApiPatternMoveNote({
-    required int patternId,
-    required int noteId,
-    required int newStartTick,
-    required int newKey,
-  });
+Future<void> crateApiMixerDeleteBus({required int busId });
 
-  List<UiResponseCurvePoint> crateApiPluginsEqParseEqCurveResponse({
-    required String jsonStr,
-  });
+Future<void> crateApiTrackDeleteClip({required int trackId , required int clipId });
 
-  Future<void> crateApiSessionPasteClips({
-    required int targetTrackId,
-    required int pasteStartTime,
-  });
+Future<void> crateApiTrackDeleteClipBatch({required int trackId , required List<int> clipIds });
 
-  Future<void> crateApiSessionPastePatternNotes({
-    required int targetPatternId,
-    required int playheadTick,
-  });
+Future<void> crateApiSessionDeleteClips({required int trackId , required List<int> clipIds });
 
-  Future<void> crateApiPatternPlayPatternPreview({
-    required int patternId,
-    required int generatorId,
-  });
+Future<UiNote> crateApiPatternDeleteNote({required int patternId , required int noteId });
 
-  Future<void> crateApiAudioPlayPreviewNote({
-    required int trackId,
-    required int noteKey,
-    required int velocity,
-    required bool isOn,
-  });
+Future<void> crateApiSessionDeletePatternNotes({required int patternId , required List<int> noteIds });
 
-  Future<void> crateApiAudioPlayPreviewNoteGenerator({
-    required int generatorId,
-    required int noteKey,
-    required int velocity,
-    required bool isOn,
-  });
+Future<String> crateApiPluginExecuteEffectInstanceCommand({required UiEffectTarget target , required int effectId , required String command , required String payloadJson });
 
-  Future<void> crateApiAudioPlaySourcePreview({required int id});
+Future<String> crateApiPluginExecuteGeneratorInstanceCommand({required int generatorId , required String command , required String payloadJson });
 
-  Future<List<UiEffectParameterSnapshot>>
-  crateApiPluginPollEffectParameterFeedback();
+Future<String?> crateApiPluginExecutePluginCommandEffect({required int effectRegistryId , required String command , required String payloadJson });
 
-  Future<List<UiGeneratorParameterSnapshot>>
-  crateApiPluginPollGeneratorParameterFeedback();
-
-  UiProjectMetadata crateApiProjectProjectMetadataNew();
-
-  Future<void> crateApiPluginQueryEffectParameters({
-    required UiEffectTarget target,
-    required int effectId,
-  });
-
-  Future<void> crateApiPluginQueryGeneratorParameters({
-    required int generatorId,
-  });
-
-  Future<void> crateApiSessionRedo();
-
-  Future<void> crateApiMixerRemoveEffectFromMasterBus({
-    required int effectInstanceId,
-  });
-
-  Future<void> crateApiMixerRemoveEffectFromMixerChannel({
-    required int trackId,
-    required int effectInstanceId,
-  });
-
-  Future<void> crateApiMixerRemoveRouting({
-    required UiRoutingNode source,
-    required UiRoutingNode destination,
-    required bool isSend,
-  });
-
-  Future<void> crateApiMixerRenameBus({
-    required int busId,
-    required String newName,
-  });
-
-  Future<void> crateApiSessionResizeClip({
-    required int trackId,
-    required int clipId,
-    required UiResizeEdge edge,
-    required int newTimeVal,
-  });
-
-  Future<UiClip> crateApiTrackResizeClip({
-    required int trackId,
-    required int clipId,
-    required UiResizeEdge edge,
-    required int newTimeVal,
-  });
-
-  Future<List<UiClip>> crateApiTrackResizeClipBatch({
-    required int trackId,
-    required List<int> clipIds,
-    required UiResizeEdge edge,
-    required int deltaSamples,
-  });
-
-  Future<UiNote> crateApiPatternResizeNote({
-    required int patternId,
-    required int noteId,
-    required int newDuration,
-  });
-
-  Future<void> crateApiSerializationSaveProject({required String pathName});
-
-  Future<void> crateApiTransportSetBpm({required double val});
-
-  Future<void> crateApiMixerSetBusParams({
-    required int busId,
-    required List<UiMixerChannelParams> params,
-  });
-
-  Future<void> crateApiPluginSetEffectParameter({
-    required UiEffectTarget target,
-    required int effectId,
-    required int paramId,
-    required double value,
-  });
-
-  Future<void> crateApiPluginSetGeneratorParameter({
-    required int generatorId,
-    required int paramId,
-    required double value,
-  });
-
-  Future<void> crateApiTransportSetLooping({required bool val});
-
-  Future<void> crateApiMixerSetMasterBusParams({
-    required List<UiMixerChannelParams> params,
-  });
-
-  Future<void> crateApiMixerSetMixerChannelParams({
-    required int trackId,
-    required List<UiMixerChannelParams> params,
-  });
-
-  Future<void> crateApiTransportSetPlayhead({required int val});
-
-  Future<void> crateApiTransportSetPlaying({required bool val});
-
-  Future<void> crateApiMixerSetRouting({
-    required UiRoutingNode source,
-    required UiRoutingNode destination,
-    required double sendLevel,
-    required bool isSend,
-  });
-
-  Future<void> crateApiAudioStopAllPreviews();
-
-  Future<void> crateApiPatternStopPatternPreview();
-
-  Future<void> crateApiTransportStopSongPlayback();
-
-  Future<void> crateApiPluginSyncEffectParametersFromAudio({
-    required List<UiEffectParameterSnapshot> snapshots,
-  });
-
-  Future<void> crateApiPluginSyncGeneratorParametersFromAudio({
-    required List<UiGeneratorParameterSnapshot> snapshots,
-  });
-
-  UiTransportState crateApiProjectTransportStateNew();
-
-  UiTransportState crateApiProjectTransportStateNewWithParam({
-    required double bpm,
-    required (int, int) timeSignature,
-  });
-
-  Future<UiClipboardContent> crateApiSessionUiClipboardContentDefault();
-
-  UiMixerState crateApiMixerUiMixerStateNew();
-
-  UiMixerState crateApiMixerUiMixerStateNewWithParam({
-    required Map<int, UiMixerChannel> channels,
-    required UiMixerChannel masterBus,
-    required Map<int, UiBus> buses,
-    required List<UiRoutingConnection> routing,
-  });
-
-  Future<UiProjectMetadata> crateApiProjectUiProjectMetadataDefault();
-
-  Future<UiTransportState> crateApiProjectUiTransportStateDefault();
-
-  Future<void> crateApiSessionUndo();
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<int> crateApiProjectAddAudioSource({required String filePath}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+Future<String?> crateApiPluginExecutePluginCommandGenerator({required int genRegistryId , required String command , required String payloadJson });
+
+Future<UiLoudnessReport> crateApiAudioExportProject({required String path , required int sampleRate , required UiBitPerSample bitPerSample });
+
+Future<Uint32List> crateApiRecordingGetArmedTracks();
+
+Future<UiAudioHardwareConfig> crateApiAudioGetAudioConfig();
+
+Future<UiAudioDeviceSettings> crateApiAudioGetAudioDeviceSettings();
+
+Future<AudioWaveformUiForAudioProperties?> crateApiAudioGetAudioProperties({required int id });
+
+Future<Map<int, AudioWaveformUiForSourceList>?> crateApiProjectGetAudioSourceList();
+
+Future<Map<int, AudioWaveformUiForClip>> crateApiTrackGetAudioWaveformClipsData();
+
+Future<AudioWaveformUiForClip> crateApiTrackGetAudioWaveformForClip({required int audioSourceId });
+
+Future<Map<int, AudioWaveformUiForClip>> crateApiTrackGetAudioWaveformForClipAllAvailableInTracks();
+
+Future<Map<int, AudioWaveformUiForClip>> crateApiTrackGetAudioWaveformForClipOnlyInSpecificTrack({required int trackId });
+
+Future<List<UiPluginInfo>> crateApiPluginGetAvailableEffectsWithIds();
+
+Future<List<UiPluginInfo>> crateApiPluginGetAvailableGeneratorsWithIds();
+
+Future<List<ParameterSpecDTO>?> crateApiMixerGetBusMixerChannelSpecs({required int busId });
+
+Future<Map<int, UiBus>> crateApiMixerGetBuses();
+
+Future<UiClip> crateApiTrackGetClip({required int trackId , required int clipId });
+
+Future<UiClipboardContent> crateApiSessionGetClipboardContents();
+
+Future<UiEffectInstance> crateApiPluginGetEffect({required int trackId , required int effectId });
+
+Future<UiEffectInstance> crateApiPluginGetEffectFromMaster({required int effectId });
+
+Future<List<UiPluginParameter>> crateApiPluginGetEffectParameterSpecs({required UiEffectTarget target , required int effectId });
+
+Future<List<UiEffectInstance>> crateApiPluginGetEffectsFromTrack({required int trackId });
+
+Future<UiEngineLoad?> crateApiAudioGetEngineLoad();
+
+Future<List<UiResponseCurvePoint>> crateApiPluginsEqGetEqResponseCurve({required UiEffectTarget target , required int effectId , required int numPoints });
+
+Future<UiGeneratorInstance> crateApiPluginGetGenerator({required int generatorId });
+
+Future<Map<int, UiGeneratorInstance>> crateApiProjectGetGeneratorList();
+
+Future<double> crateApiPluginGetGeneratorParameter({required int generatorId , required int paramId });
+
+Future<List<UiPluginParameter>> crateApiPluginGetGeneratorParameterSpecs({required int generatorId });
+
+Future<UiLoopRegion?> crateApiTransportGetLoopRegion();
+
+Future<UiLoudnessReport?> crateApiAudioGetLoudnessReport();
+
+Future<UiMixerChannel> crateApiMixerGetMasterBus();
+
+Future<List<UiEffectInstance>> crateApiMixerGetMasterBusPopulated();
+
+Future<List<ParameterSpecDTO>> crateApiMixerGetMasterChannelSpecs();
+
+Future<List<UiEffectInstance>> crateApiPluginGetMasterEffects();
+
+Future<int> crateApiProjectGetMaxSampleIndex();
+
+Future<UiMetronomeSettings> crateApiTransportGetMetronomeSettings();
+
+Future<String> crateApiMidiGetMidiBackendName();
+
+Future<UiMidiOutputSettings> crateApiMidiGetMidiOutputSettings();
+
+Future<UiMidiRecordMode> crateApiRecordingGetMidiRecordMode();
+
+Future<int?> crateApiRecordingGetMidiRecordQuantize();
+
+Future<int> crateApiAudioGetMixLatency();
+
+Future<UiMixerChannel> crateApiMixerGetMixerChannel({required int trackId });
+
+Future<(UiMixerChannel,List<UiEffectInstance>)> crateApiMixerGetMixerChannelPopulated({required int trackId });
+
+Future<UiMixerState> crateApiMixerGetMixerState();
+
+Future<String?> crateApiMidiGetOpenMidiInput();
+
+Future<String?> crateApiMidiGetOpenMidiOutput();
+
+Future<Uint32List> crateApiPatternGetOutOfKeyNotes({required int patternId });
+
+Future<UiPattern> crateApiPatternGetPattern({required int patternId });
+
+Future<UiGroove?> crateApiPatternGetPatternGroove({required int patternId });
+
+Future<UiScale?> crateApiPatternGetPatternScale({required int patternId });
+
+Future<Map<int, UiPattern>> crateApiPatternGetPatterns();
+
+Future<UiProjectMetadata> crateApiProjectGetProjectMetadata();
+
+Future<int> crateApiTransportGetRandomSeed();
+
+Future<List<UiRoutingConnection>> crateApiMixerGetRoutingMatrix();
+
+Future<List<UiTempoPoint>> crateApiTransportGetTempoPoints();
+
+Future<List<UiTimeSignatureEvent>> crateApiTransportGetTimeSignatures();
+
+Future<UiTrack> crateApiTrackGetTrack({required int trackId });
+
+Future<List<ParameterSpecDTO>?> crateApiMixerGetTrackMixerChannelSpecs({required int trackId });
+
+Future<UiMonitorMode> crateApiRecordingGetTrackMonitorMode({required int trackId });
+
+Future<Map<int, UiTrack>> crateApiProjectGetTracks();
+
+Future<UiTransportState> crateApiProjectGetTransportState();
+
+String crateApiSimpleGreet({required String name });
+
+Future<UiGroove> crateApiPatternGrooveFromMidiFile({required String path , required int stepTicks });
+
+Future<UiGroove> crateApiPatternGrooveFromPattern({required int patternId , required int stepTicks });
+
+Future<void> crateApiSimpleInitApp();
+
+Future<bool> crateApiAudioIsPluginProfiling();
+
+Future<bool> crateApiRecordingIsRecording();
+
+Future<List<String>> crateApiAudioListAudioHosts();
+
+Future<List<UiAudioDeviceInfo>> crateApiAudioListAudioInputDevices({String? host });
+
+Future<List<UiAudioDeviceInfo>> crateApiAudioListAudioOutputDevices({String? host });
+
+Future<List<String>> crateApiRecordingListInputDevices();
+
+Future<List<String>> crateApiMidiListMidiInputs();
+
+Future<List<String>> crateApiMidiListMidiOutputs();
+
+Future<UiApplicationState> crateApiSerializationLoadProject({required String pathName });
+
+Future<void> crateApiSessionMoveClip({required int oldTrackId , required int newTrackId , required int clipId , required int newStartTime });
+
+Future<UiClip> crateApiTrackMoveClip({required int sourceTrackId , required int clipId , required int newStartTime , int? newTrackId });
+
+Future<List<UiClip>> crateApiTrackMoveClipBatch({required int sourceTrackId , required List<int> clipIds , required int deltaSamples , int? newTrackId });
+
+Future<UiNote> crateApiPatternMoveNote({required int patternId , required int noteId , required int newStartTick , required int newKey });
+
+Future<void> crateApiMidiOpenMidiInput({required String port });
+
+Future<void> crateApiMidiOpenMidiOutput({required String port });
+
+List<UiResponseCurvePoint> crateApiPluginsEqParseEqCurveResponse({required String jsonStr });
+
+Future<void> crateApiSessionPasteClips({required int targetTrackId , required int pasteStartTime });
+
+Future<void> crateApiSessionPastePatternNotes({required int targetPatternId , required int playheadTick });
+
+Future<void> crateApiPatternPlayPatternPreview({required int patternId , required int generatorId });
+
+Future<void> crateApiAudioPlayPreviewNote({required int trackId , required int noteKey , required int velocity , required bool isOn });
+
+Future<void> crateApiAudioPlayPreviewNoteGenerator({required int generatorId , required int noteKey , required int velocity , required bool isOn });
+
+Future<void> crateApiAudioPlaySourcePreview({required int id });
+
+Future<List<UiEffectParameterSnapshot>> crateApiPluginPollEffectParameterFeedback();
+
+Future<List<UiGeneratorParameterSnapshot>> crateApiPluginPollGeneratorParameterFeedback();
+
+UiProjectMetadata crateApiProjectProjectMetadataNew();
+
+Future<List<UiNote>> crateApiPatternQuantizeNotes({required int patternId , required List<int> noteIds , required UiQuantizeSettings settings });
+
+Future<void> crateApiPluginQueryEffectParameters({required UiEffectTarget target , required int effectId });
+
+Future<void> crateApiPluginQueryGeneratorParameters({required int generatorId });
+
+Future<void> crateApiSessionRedo();
+
+Future<void> crateApiMixerRemoveEffectFromMasterBus({required int effectInstanceId });
+
+Future<void> crateApiMixerRemoveEffectFromMixerChannel({required int trackId , required int effectInstanceId });
+
+Future<void> crateApiMixerRemoveRouting({required UiRoutingNode source , required UiRoutingNode destination , required bool isSend });
+
+Future<UiTempoPoint> crateApiTransportRemoveTempoPoint({required int tick });
+
+Future<UiTimeSignatureEvent> crateApiTransportRemoveTimeSignature({required int bar });
+
+Future<void> crateApiMixerRenameBus({required int busId , required String newName });
+
+Future<void> crateApiAudioResetEngineLoad();
+
+Future<void> crateApiAudioResetLoudnessAnalyzer();
+
+Future<void> crateApiSessionResizeClip({required int trackId , required int clipId , required UiResizeEdge edge , required int newTimeVal });
+
+Future<UiClip> crateApiTrackResizeClip({required int trackId , required int clipId , required UiResizeEdge edge , required int newTimeVal });
+
+Future<List<UiClip>> crateApiTrackResizeClipBatch({required int trackId , required List<int> clipIds , required UiResizeEdge edge , required int deltaSamples });
+
+Future<UiNote> crateApiPatternResizeNote({required int patternId , required int noteId , required int newDuration });
+
+Future<int> crateApiTransportSamplesToTicks({required int sample });
+
+Future<void> crateApiSerializationSaveProject({required String pathName });
+
+Future<void> crateApiAudioSetAudioDeviceSettings({required UiAudioDeviceSettings settings });
+
+Future<void> crateApiTransportSetBpm({required double val });
+
+Future<void> crateApiMixerSetBusParams({required int busId , required List<UiMixerChannelParams> params });
+
+Future<void> crateApiPatternSetClipGroove({required int trackId , required int clipId , UiGroove? groove });
+
+Future<void> crateApiTransportSetCountInBars({required int bars });
+
+Future<void> crateApiPluginSetEffectParameter({required UiEffectTarget target , required int effectId , required int paramId , required double value });
+
+Future<void> crateApiPluginSetGeneratorParameter({required int generatorId , required int paramId , required double value });
+
+Future<String> crateApiRecordingSetInputDevice({String? name });
+
+Future<void> crateApiTransportSetLoopRegion({required int start , required int end });
+
+Future<void> crateApiTransportSetLooping({required bool val });
+
+Future<void> crateApiMixerSetMasterBusParams({required List<UiMixerChannelParams> params });
+
+Future<void> crateApiTransportSetMetronomeEnabled({required bool val });
+
+Future<void> crateApiTransportSetMetronomeTarget({int? busId });
+
+Future<void> crateApiTransportSetMetronomeVolume({required double val });
+
+Future<void> crateApiMidiSetMidiInputTrack({int? trackId });
+
+Future<void> crateApiMidiSetMidiOutputSettings({required UiMidiOutputSettings settings });
+
+Future<void> crateApiRecordingSetMidiRecordSettings({required UiMidiRecordMode mode , int? quantizeTicks });
+
+Future<void> crateApiMixerSetMixerChannelParams({required int trackId , required List<UiMixerChannelParams> params });
+
+Future<void> crateApiPatternSetPatternGroove({required int patternId , UiGroove? groove });
+
+Future<void> crateApiPatternSetPatternScale({required int patternId , required int root , UiScaleMode? mode });
+
+Future<void> crateApiTransportSetPlayhead({required int val });
+
+Future<void> crateApiTransportSetPlaying({required bool val });
+
+Future<void> crateApiAudioSetPluginProfiling({required bool enabled });
+
+Future<void> crateApiTransportSetRandomSeed({required int seed });
+
+Future<void> crateApiMixerSetRouting({required UiRoutingNode source , required UiRoutingNode destination , required double sendLevel , required bool isSend });
+
+Future<void> crateApiTransportSetTempoPoint({required int tick , required double bpm , required bool linear });
+
+Future<void> crateApiTransportSetTimeSignature({required int bar , required int numerator , required int denominator });
+
+Future<void> crateApiRecordingSetTrackMonitorMode({required int trackId , required UiMonitorMode mode });
+
+Future<void> crateApiRecordingSetTrackRecordArm({required int trackId , required bool armed });
+
+Future<void> crateApiAudioSetUserSettingsPath({required String path });
+
+Future<List<UiNote>> crateApiPatternSnapToScale({required int patternId , required List<int> noteIds });
+
+Future<void> crateApiRecordingStartRecording();
+
+Future<void> crateApiAudioStopAllPreviews();
+
+Future<void> crateApiPatternStopPatternPreview();
+
+Future<List<UiRecordedClip>> crateApiRecordingStopRecording();
+
+Future<void> crateApiTransportStopSongPlayback();
+
+Future<void> crateApiAnalyzerStopSpectrumStream({required int analyzerId });
+
+Future<UiGroove> crateApiPatternSwingGroove({required UiSwingDivision division , required double percent });
+
+Future<void> crateApiPluginSyncEffectParametersFromAudio({required List<UiEffectParameterSnapshot> snapshots });
+
+Future<void> crateApiPluginSyncGeneratorParametersFromAudio({required List<UiGeneratorParameterSnapshot> snapshots });
+
+Future<int> crateApiTransportTicksToSamples({required int tick });
+
+Future<List<UiNote>> crateApiPatternTransformNotes({required int patternId , required List<int> noteIds , required UiNoteTransform transform });
+
+UiTransportState crateApiProjectTransportStateNew();
+
+UiTransportState crateApiProjectTransportStateNewWithParam({required double bpm , required (int,int) timeSignature });
+
+Future<UiAudioDeviceSettings> crateApiAudioUiAudioDeviceSettingsDefault();
+
+Future<UiClipboardContent> crateApiSessionUiClipboardContentDefault();
+
+UiMixerState crateApiMixerUiMixerStateNew();
+
+UiMixerState crateApiMixerUiMixerStateNewWithParam({required Map<int, UiMixerChannel> channels , required UiMixerChannel masterBus , required Map<int, UiBus> buses , required List<UiRoutingConnection> routing });
+
+Future<UiProjectMetadata> crateApiProjectUiProjectMetadataDefault();
+
+Future<UiTransportState> crateApiProjectUiTransportStateDefault();
+
+Future<void> crateApiSessionUndo();
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<int> crateApiProjectAddAudioSource({required String filePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiProjectAddAudioSourceConstMeta,
-        argValues: [filePath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectAddAudioSourceConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_audio_source",
-        argNames: ["filePath"],
-      );
-
-  @override
-  Future<void> crateApiMixerAddEffectToBus({
-    required int busId,
-    required int registryId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(busId, serializer);
-          sse_encode_u_32(registryId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiProjectAddAudioSourceConstMeta,
+            argValues: [filePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectAddAudioSourceConstMeta => const TaskConstMeta(
+            debugName: "add_audio_source",
+            argNames: ["filePath"],
+        );
+        
+
+@override Future<List<UiNote>> crateApiPatternAddChord({required int patternId , required int rootKey , required UiChordType chordType , required int inversion , required int startTick , int? duration })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_u_8(rootKey, serializer);
+sse_encode_ui_chord_type(chordType, serializer);
+sse_encode_u_32(inversion, serializer);
+sse_encode_CastedPrimitive_u_64(startTick, serializer);
+sse_encode_opt_CastedPrimitive_u_64(duration, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternAddChordConstMeta,
+            argValues: [patternId, rootKey, chordType, inversion, startTick, duration],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternAddChordConstMeta => const TaskConstMeta(
+            debugName: "add_chord",
+            argNames: ["patternId", "rootKey", "chordType", "inversion", "startTick", "duration"],
+        );
+        
+
+@override Future<void> crateApiMixerAddEffectToBus({required int busId , required int registryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(busId, serializer);
+sse_encode_u_32(registryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerAddEffectToBusConstMeta,
-        argValues: [busId, registryId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerAddEffectToBusConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_effect_to_bus",
-        argNames: ["busId", "registryId"],
-      );
-
-  @override
-  Future<void> crateApiMixerAddEffectToMasterBus({required int registryId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(registryId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMixerAddEffectToBusConstMeta,
+            argValues: [busId, registryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerAddEffectToBusConstMeta => const TaskConstMeta(
+            debugName: "add_effect_to_bus",
+            argNames: ["busId", "registryId"],
+        );
+        
+
+@override Future<void> crateApiMixerAddEffectToMasterBus({required int registryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(registryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerAddEffectToMasterBusConstMeta,
-        argValues: [registryId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerAddEffectToMasterBusConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_effect_to_master_bus",
-        argNames: ["registryId"],
-      );
-
-  @override
-  Future<void> crateApiMixerAddEffectToMixerChannelById({
-    required int trackId,
-    required int registryId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_u_32(registryId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMixerAddEffectToMasterBusConstMeta,
+            argValues: [registryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerAddEffectToMasterBusConstMeta => const TaskConstMeta(
+            debugName: "add_effect_to_master_bus",
+            argNames: ["registryId"],
+        );
+        
+
+@override Future<void> crateApiMixerAddEffectToMixerChannelById({required int trackId , required int registryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(registryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerAddEffectToMixerChannelByIdConstMeta,
-        argValues: [trackId, registryId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerAddEffectToMixerChannelByIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_effect_to_mixer_channel_by_id",
-        argNames: ["trackId", "registryId"],
-      );
-
-  @override
-  Future<UiTrack> crateApiTrackAddMidiTrackWithGeneratorId({
-    required int registryId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(registryId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMixerAddEffectToMixerChannelByIdConstMeta,
+            argValues: [trackId, registryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerAddEffectToMixerChannelByIdConstMeta => const TaskConstMeta(
+            debugName: "add_effect_to_mixer_channel_by_id",
+            argNames: ["trackId", "registryId"],
+        );
+        
+
+@override Future<UiTrack> crateApiTrackAddMidiTrackWithGeneratorId({required int registryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(registryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_track,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackAddMidiTrackWithGeneratorIdConstMeta,
-        argValues: [registryId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackAddMidiTrackWithGeneratorIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_midi_track_with_generator_id",
-        argNames: ["registryId"],
-      );
-
-  @override
-  Future<UiTrack> crateApiProjectAddNewAudioTrack() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTrackAddMidiTrackWithGeneratorIdConstMeta,
+            argValues: [registryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackAddMidiTrackWithGeneratorIdConstMeta => const TaskConstMeta(
+            debugName: "add_midi_track_with_generator_id",
+            argNames: ["registryId"],
+        );
+        
+
+@override Future<UiTrack> crateApiProjectAddNewAudioTrack()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_track,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProjectAddNewAudioTrackConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectAddNewAudioTrackConstMeta =>
-      const TaskConstMeta(debugName: "add_new_audio_track", argNames: []);
-
-  @override
-  Future<UiNote> crateApiPatternAddNote({
-    required int patternId,
-    required int key,
-    required int startTick,
-    int? duration,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          sse_encode_u_32(key, serializer);
-          sse_encode_CastedPrimitive_u_64(startTick, serializer);
-          sse_encode_opt_CastedPrimitive_u_64(duration, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiProjectAddNewAudioTrackConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectAddNewAudioTrackConstMeta => const TaskConstMeta(
+            debugName: "add_new_audio_track",
+            argNames: [],
+        );
+        
+
+@override Future<UiNote> crateApiPatternAddNote({required int patternId , required int key , required int startTick , int? duration })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_u_32(key, serializer);
+sse_encode_CastedPrimitive_u_64(startTick, serializer);
+sse_encode_opt_CastedPrimitive_u_64(duration, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_note,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPatternAddNoteConstMeta,
-        argValues: [patternId, key, startTick, duration],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPatternAddNoteConstMeta => const TaskConstMeta(
-    debugName: "add_note",
-    argNames: ["patternId", "key", "startTick", "duration"],
-  );
-
-  @override
-  UiAudioHardwareConfig crateApiProjectAudioHardwareConfigNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPatternAddNoteConstMeta,
+            argValues: [patternId, key, startTick, duration],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternAddNoteConstMeta => const TaskConstMeta(
+            debugName: "add_note",
+            argNames: ["patternId", "key", "startTick", "duration"],
+        );
+        
+
+@override Future<List<UiChordSpan>> crateApiPatternAnalyseChords({required int patternId , required List<int> noteIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_list_prim_u_32_loose(noteIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_chord_span,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternAnalyseChordsConstMeta,
+            argValues: [patternId, noteIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternAnalyseChordsConstMeta => const TaskConstMeta(
+            debugName: "analyse_chords",
+            argNames: ["patternId", "noteIds"],
+        );
+        
+
+@override UiAudioHardwareConfig crateApiProjectAudioHardwareConfigNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_audio_hardware_config,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProjectAudioHardwareConfigNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectAudioHardwareConfigNewConstMeta =>
-      const TaskConstMeta(debugName: "audio_hardware_config_new", argNames: []);
-
-  @override
-  UiAudioHardwareConfig crateApiProjectAudioHardwareConfigNewWithParam({
-    required String selectedInputDevice,
-    required String selectedOutputDevice,
-    required int sampleRate,
-    required int bufferSize,
-    required double cpuLoad,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(selectedInputDevice, serializer);
-          sse_encode_String(selectedOutputDevice, serializer);
-          sse_encode_u_32(sampleRate, serializer);
-          sse_encode_u_32(bufferSize, serializer);
-          sse_encode_f_32(cpuLoad, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiProjectAudioHardwareConfigNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectAudioHardwareConfigNewConstMeta => const TaskConstMeta(
+            debugName: "audio_hardware_config_new",
+            argNames: [],
+        );
+        
+
+@override UiAudioHardwareConfig crateApiProjectAudioHardwareConfigNewWithParam({required String selectedInputDevice , required String selectedOutputDevice , required int sampleRate , required int bufferSize , required double cpuLoad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(selectedInputDevice, serializer);
+sse_encode_String(selectedOutputDevice, serializer);
+sse_encode_u_32(sampleRate, serializer);
+sse_encode_u_32(bufferSize, serializer);
+sse_encode_f_32(cpuLoad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_audio_hardware_config,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProjectAudioHardwareConfigNewWithParamConstMeta,
-        argValues: [
-          selectedInputDevice,
-          selectedOutputDevice,
-          sampleRate,
-          bufferSize,
-          cpuLoad,
-        ],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectAudioHardwareConfigNewWithParamConstMeta =>
-      const TaskConstMeta(
-        debugName: "audio_hardware_config_new_with_param",
-        argNames: [
-          "selectedInputDevice",
-          "selectedOutputDevice",
-          "sampleRate",
-          "bufferSize",
-          "cpuLoad",
-        ],
-      );
-
-  @override
-  Future<UiNote> crateApiPatternChangeNoteParams({
-    required int patternId,
-    required int noteId,
-    int? velocity,
-    double? probability,
-    int? microOffset,
-    bool? mute,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          sse_encode_u_32(noteId, serializer);
-          sse_encode_opt_CastedPrimitive_i_64(velocity, serializer);
-          sse_encode_opt_box_autoadd_f_32(probability, serializer);
-          sse_encode_opt_CastedPrimitive_i_64(microOffset, serializer);
-          sse_encode_opt_box_autoadd_bool(mute, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiProjectAudioHardwareConfigNewWithParamConstMeta,
+            argValues: [selectedInputDevice, selectedOutputDevice, sampleRate, bufferSize, cpuLoad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectAudioHardwareConfigNewWithParamConstMeta => const TaskConstMeta(
+            debugName: "audio_hardware_config_new_with_param",
+            argNames: ["selectedInputDevice", "selectedOutputDevice", "sampleRate", "bufferSize", "cpuLoad"],
+        );
+        
+
+@override Future<UiNote> crateApiPatternChangeNoteParams({required int patternId , required int noteId , int? velocity , double? probability , int? microOffset , bool? mute })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_u_32(noteId, serializer);
+sse_encode_opt_CastedPrimitive_i_64(velocity, serializer);
+sse_encode_opt_box_autoadd_f_32(probability, serializer);
+sse_encode_opt_CastedPrimitive_i_64(microOffset, serializer);
+sse_encode_opt_box_autoadd_bool(mute, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_note,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPatternChangeNoteParamsConstMeta,
-        argValues: [
-          patternId,
-          noteId,
-          velocity,
-          probability,
-          microOffset,
-          mute,
-        ],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPatternChangeNoteParamsConstMeta =>
-      const TaskConstMeta(
-        debugName: "change_note_params",
-        argNames: [
-          "patternId",
-          "noteId",
-          "velocity",
-          "probability",
-          "microOffset",
-          "mute",
-        ],
-      );
-
-  @override
-  Future<void> crateApiTrackChangeTrackColor({
-    required int trackId,
-    required String newColor,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_String(newColor, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPatternChangeNoteParamsConstMeta,
+            argValues: [patternId, noteId, velocity, probability, microOffset, mute],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternChangeNoteParamsConstMeta => const TaskConstMeta(
+            debugName: "change_note_params",
+            argNames: ["patternId", "noteId", "velocity", "probability", "microOffset", "mute"],
+        );
+        
+
+@override Future<void> crateApiTrackChangeTrackColor({required int trackId , required String newColor })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_String(newColor, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackChangeTrackColorConstMeta,
-        argValues: [trackId, newColor],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackChangeTrackColorConstMeta =>
-      const TaskConstMeta(
-        debugName: "change_track_color",
-        argNames: ["trackId", "newColor"],
-      );
-
-  @override
-  Future<void> crateApiTrackChangeTrackName({
-    required int trackId,
-    required String newName,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_String(newName, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTrackChangeTrackColorConstMeta,
+            argValues: [trackId, newColor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackChangeTrackColorConstMeta => const TaskConstMeta(
+            debugName: "change_track_color",
+            argNames: ["trackId", "newColor"],
+        );
+        
+
+@override Future<void> crateApiTrackChangeTrackName({required int trackId , required String newName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_String(newName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackChangeTrackNameConstMeta,
-        argValues: [trackId, newName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackChangeTrackNameConstMeta =>
-      const TaskConstMeta(
-        debugName: "change_track_name",
-        argNames: ["trackId", "newName"],
-      );
-
-  @override
-  Future<UiClipboardContent> crateApiSessionCopyClips({
-    required int trackId,
-    required List<int> clipIds,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_list_prim_u_32_loose(clipIds, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_clipboard_content,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionCopyClipsConstMeta,
-        argValues: [trackId, clipIds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionCopyClipsConstMeta => const TaskConstMeta(
-    debugName: "copy_clips",
-    argNames: ["trackId", "clipIds"],
-  );
-
-  @override
-  Future<UiClipboardContent> crateApiSessionCopyPatternNotes({
-    required int patternId,
-    required List<int> noteIds,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          sse_encode_list_prim_u_32_loose(noteIds, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_clipboard_content,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionCopyPatternNotesConstMeta,
-        argValues: [patternId, noteIds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionCopyPatternNotesConstMeta =>
-      const TaskConstMeta(
-        debugName: "copy_pattern_notes",
-        argNames: ["patternId", "noteIds"],
-      );
-
-  @override
-  Future<int> crateApiMixerCreateBus({required String name}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 15,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_u_32,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerCreateBusConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerCreateBusConstMeta =>
-      const TaskConstMeta(debugName: "create_bus", argNames: ["name"]);
-
-  @override
-  Future<UiClip> crateApiTrackCreateClip({
-    int? sourceId,
-    required UiSourceType sourceType,
-    required int trackId,
-    required int startTime,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_box_autoadd_u_32(sourceId, serializer);
-          sse_encode_ui_source_type(sourceType, serializer);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_u_32(startTime, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 16,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_clip,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackCreateClipConstMeta,
-        argValues: [sourceId, sourceType, trackId, startTime],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackCreateClipConstMeta => const TaskConstMeta(
-    debugName: "create_clip",
-    argNames: ["sourceId", "sourceType", "trackId", "startTime"],
-  );
-
-  @override
-  Stream<UiMixerParamEvent> crateApiMixerCreateMixerEventStream() {
-    final sink = RustStreamSink<UiMixerParamEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_ui_mixer_param_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 17,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_String,
-          ),
-          constMeta: kCrateApiMixerCreateMixerEventStreamConstMeta,
-          argValues: [sink],
-          apiImpl: this,
-        ),
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiMixerCreateMixerEventStreamConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_mixer_event_stream",
-        argNames: ["sink"],
-      );
-
-  @override
-  Stream<UiTransportFeedback> crateApiAudioCreatePositionStream() {
-    final sink = RustStreamSink<UiTransportFeedback>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
+        )
+        ,
+            constMeta: kCrateApiTrackChangeTrackNameConstMeta,
+            argValues: [trackId, newName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackChangeTrackNameConstMeta => const TaskConstMeta(
+            debugName: "change_track_name",
+            argNames: ["trackId", "newName"],
+        );
+        
+
+@override Future<Uint8List> crateApiPatternChordKeys({required int rootKey , required UiChordType chordType , required int inversion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_8(rootKey, serializer);
+sse_encode_ui_chord_type(chordType, serializer);
+sse_encode_u_32(inversion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPatternChordKeysConstMeta,
+            argValues: [rootKey, chordType, inversion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternChordKeysConstMeta => const TaskConstMeta(
+            debugName: "chord_keys",
+            argNames: ["rootKey", "chordType", "inversion"],
+        );
+        
+
+@override Future<void> crateApiTransportClearLoopRegion()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_ui_transport_feedback_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 18,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_String,
-          ),
-          constMeta: kCrateApiAudioCreatePositionStreamConstMeta,
-          argValues: [sink],
-          apiImpl: this,
-        ),
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiAudioCreatePositionStreamConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_position_stream",
-        argNames: ["sink"],
-      );
-
-  @override
-  Future<List<UiClip>> crateApiTrackCutClip({
-    required int sourceTrackId,
-    required int clipId,
-    required int cutPointSample,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(sourceTrackId, serializer);
-          sse_encode_u_32(clipId, serializer);
-          sse_encode_u_32(cutPointSample, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 19,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_ui_clip,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackCutClipConstMeta,
-        argValues: [sourceTrackId, clipId, cutPointSample],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackCutClipConstMeta => const TaskConstMeta(
-    debugName: "cut_clip",
-    argNames: ["sourceTrackId", "clipId", "cutPointSample"],
-  );
-
-  @override
-  Future<void> crateApiSessionCutClips({
-    required int trackId,
-    required List<int> clipIds,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_list_prim_u_32_loose(clipIds, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 20,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionCutClipsConstMeta,
-        argValues: [trackId, clipIds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionCutClipsConstMeta => const TaskConstMeta(
-    debugName: "cut_clips",
-    argNames: ["trackId", "clipIds"],
-  );
-
-  @override
-  Future<void> crateApiSessionCutPatternNotes({
-    required int patternId,
-    required List<int> noteIds,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          sse_encode_list_prim_u_32_loose(noteIds, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 21,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionCutPatternNotesConstMeta,
-        argValues: [patternId, noteIds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionCutPatternNotesConstMeta =>
-      const TaskConstMeta(
-        debugName: "cut_pattern_notes",
-        argNames: ["patternId", "noteIds"],
-      );
-
-  @override
-  Future<void> crateApiMixerDeleteBus({required int busId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(busId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 22,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTransportClearLoopRegionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportClearLoopRegionConstMeta => const TaskConstMeta(
+            debugName: "clear_loop_region",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiRecordingCloseInputDevice()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerDeleteBusConstMeta,
-        argValues: [busId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerDeleteBusConstMeta =>
-      const TaskConstMeta(debugName: "delete_bus", argNames: ["busId"]);
-
-  @override
-  Future<void> crateApiTrackDeleteClip({
-    required int trackId,
-    required int clipId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_u_32(clipId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 23,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecordingCloseInputDeviceConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingCloseInputDeviceConstMeta => const TaskConstMeta(
+            debugName: "close_input_device",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiMidiCloseMidiInput()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackDeleteClipConstMeta,
-        argValues: [trackId, clipId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackDeleteClipConstMeta => const TaskConstMeta(
-    debugName: "delete_clip",
-    argNames: ["trackId", "clipId"],
-  );
-
-  @override
-  Future<void> crateApiTrackDeleteClipBatch({
-    required int trackId,
-    required List<int> clipIds,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_list_prim_u_32_loose(clipIds, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 24,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMidiCloseMidiInputConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiCloseMidiInputConstMeta => const TaskConstMeta(
+            debugName: "close_midi_input",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiMidiCloseMidiOutput()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackDeleteClipBatchConstMeta,
-        argValues: [trackId, clipIds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackDeleteClipBatchConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_clip_batch",
-        argNames: ["trackId", "clipIds"],
-      );
-
-  @override
-  Future<void> crateApiSessionDeleteClips({
-    required int trackId,
-    required List<int> clipIds,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_list_prim_u_32_loose(clipIds, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMidiCloseMidiOutputConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiCloseMidiOutputConstMeta => const TaskConstMeta(
+            debugName: "close_midi_output",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiPatternCommitClipGroove({required int trackId , required int clipId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(clipId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionDeleteClipsConstMeta,
-        argValues: [trackId, clipIds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionDeleteClipsConstMeta => const TaskConstMeta(
-    debugName: "delete_clips",
-    argNames: ["trackId", "clipIds"],
-  );
-
-  @override
-  Future<UiNote> crateApiPatternDeleteNote({
-    required int patternId,
-    required int noteId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          sse_encode_u_32(noteId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 26,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_note,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPatternDeleteNoteConstMeta,
-        argValues: [patternId, noteId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPatternDeleteNoteConstMeta => const TaskConstMeta(
-    debugName: "delete_note",
-    argNames: ["patternId", "noteId"],
-  );
-
-  @override
-  Future<void> crateApiSessionDeletePatternNotes({
-    required int patternId,
-    required List<int> noteIds,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          sse_encode_list_prim_u_32_loose(noteIds, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPatternCommitClipGrooveConstMeta,
+            argValues: [trackId, clipId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternCommitClipGrooveConstMeta => const TaskConstMeta(
+            debugName: "commit_clip_groove",
+            argNames: ["trackId", "clipId"],
+        );
+        
+
+@override Future<void> crateApiPatternCommitPatternGroove({required int patternId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionDeletePatternNotesConstMeta,
-        argValues: [patternId, noteIds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionDeletePatternNotesConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_pattern_notes",
-        argNames: ["patternId", "noteIds"],
-      );
-
-  @override
-  Future<String> crateApiPluginExecuteEffectInstanceCommand({
-    required UiEffectTarget target,
-    required int effectId,
-    required String command,
-    required String payloadJson,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_effect_target(target, serializer);
-          sse_encode_u_32(effectId, serializer);
-          sse_encode_String(command, serializer);
-          sse_encode_String(payloadJson, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 28,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPatternCommitPatternGrooveConstMeta,
+            argValues: [patternId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternCommitPatternGrooveConstMeta => const TaskConstMeta(
+            debugName: "commit_pattern_groove",
+            argNames: ["patternId"],
+        );
+        
+
+@override Future<UiClipboardContent> crateApiSessionCopyClips({required int trackId , required List<int> clipIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_list_prim_u_32_loose(clipIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_clipboard_content,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSessionCopyClipsConstMeta,
+            argValues: [trackId, clipIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionCopyClipsConstMeta => const TaskConstMeta(
+            debugName: "copy_clips",
+            argNames: ["trackId", "clipIds"],
+        );
+        
+
+@override Future<UiClipboardContent> crateApiSessionCopyPatternNotes({required int patternId , required List<int> noteIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_list_prim_u_32_loose(noteIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_clipboard_content,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSessionCopyPatternNotesConstMeta,
+            argValues: [patternId, noteIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionCopyPatternNotesConstMeta => const TaskConstMeta(
+            debugName: "copy_pattern_notes",
+            argNames: ["patternId", "noteIds"],
+        );
+        
+
+@override Future<int> crateApiMixerCreateBus({required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerCreateBusConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerCreateBusConstMeta => const TaskConstMeta(
+            debugName: "create_bus",
+            argNames: ["name"],
+        );
+        
+
+@override Future<UiClip> crateApiTrackCreateClip({int? sourceId , required UiSourceType sourceType , required int trackId , required int startTime })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_u_32(sourceId, serializer);
+sse_encode_ui_source_type(sourceType, serializer);
+sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(startTime, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_clip,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTrackCreateClipConstMeta,
+            argValues: [sourceId, sourceType, trackId, startTime],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackCreateClipConstMeta => const TaskConstMeta(
+            debugName: "create_clip",
+            argNames: ["sourceId", "sourceType", "trackId", "startTime"],
+        );
+        
+
+@override Stream<List<UiMeterLevels>> crateApiMixerCreateMeterStream()  { 
+            final sink = RustStreamSink<List<UiMeterLevels>>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_list_ui_meter_levels_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerCreateMeterStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiMixerCreateMeterStreamConstMeta => const TaskConstMeta(
+            debugName: "create_meter_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<UiMixerParamEvent> crateApiMixerCreateMixerEventStream()  { 
+            final sink = RustStreamSink<UiMixerParamEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_ui_mixer_param_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerCreateMixerEventStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiMixerCreateMixerEventStreamConstMeta => const TaskConstMeta(
+            debugName: "create_mixer_event_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<UiTransportFeedback> crateApiAudioCreatePositionStream()  { 
+            final sink = RustStreamSink<UiTransportFeedback>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_ui_transport_feedback_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAudioCreatePositionStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiAudioCreatePositionStreamConstMeta => const TaskConstMeta(
+            debugName: "create_position_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<List<UiResponseCurvePoint>> crateApiAnalyzerCreateSpectrumStream({required UiRoutingNode node , required int fftSize , required double overlap , required int numPoints })  { 
+            final sink = RustStreamSink<List<UiResponseCurvePoint>>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_routing_node(node, serializer);
+sse_encode_u_32(fftSize, serializer);
+sse_encode_f_32(overlap, serializer);
+sse_encode_u_32(numPoints, serializer);
+sse_encode_StreamSink_list_ui_response_curve_point_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerCreateSpectrumStreamConstMeta,
+            argValues: [node, fftSize, overlap, numPoints, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiAnalyzerCreateSpectrumStreamConstMeta => const TaskConstMeta(
+            debugName: "create_spectrum_stream",
+            argNames: ["node", "fftSize", "overlap", "numPoints", "sink"],
+        );
+        
+
+@override Future<List<UiClip>> crateApiTrackCutClip({required int sourceTrackId , required int clipId , required int cutPointSample })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(sourceTrackId, serializer);
+sse_encode_u_32(clipId, serializer);
+sse_encode_u_32(cutPointSample, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_clip,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTrackCutClipConstMeta,
+            argValues: [sourceTrackId, clipId, cutPointSample],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackCutClipConstMeta => const TaskConstMeta(
+            debugName: "cut_clip",
+            argNames: ["sourceTrackId", "clipId", "cutPointSample"],
+        );
+        
+
+@override Future<void> crateApiSessionCutClips({required int trackId , required List<int> clipIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_list_prim_u_32_loose(clipIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSessionCutClipsConstMeta,
+            argValues: [trackId, clipIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionCutClipsConstMeta => const TaskConstMeta(
+            debugName: "cut_clips",
+            argNames: ["trackId", "clipIds"],
+        );
+        
+
+@override Future<void> crateApiSessionCutPatternNotes({required int patternId , required List<int> noteIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_list_prim_u_32_loose(noteIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSessionCutPatternNotesConstMeta,
+            argValues: [patternId, noteIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionCutPatternNotesConstMeta => const TaskConstMeta(
+            debugName: "cut_pattern_notes",
+            argNames: ["patternId", "noteIds"],
+        );
+        
+
+@override Future<void> crateApiMixerDeleteBus({required int busId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(busId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerDeleteBusConstMeta,
+            argValues: [busId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerDeleteBusConstMeta => const TaskConstMeta(
+            debugName: "delete_bus",
+            argNames: ["busId"],
+        );
+        
+
+@override Future<void> crateApiTrackDeleteClip({required int trackId , required int clipId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(clipId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTrackDeleteClipConstMeta,
+            argValues: [trackId, clipId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackDeleteClipConstMeta => const TaskConstMeta(
+            debugName: "delete_clip",
+            argNames: ["trackId", "clipId"],
+        );
+        
+
+@override Future<void> crateApiTrackDeleteClipBatch({required int trackId , required List<int> clipIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_list_prim_u_32_loose(clipIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTrackDeleteClipBatchConstMeta,
+            argValues: [trackId, clipIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackDeleteClipBatchConstMeta => const TaskConstMeta(
+            debugName: "delete_clip_batch",
+            argNames: ["trackId", "clipIds"],
+        );
+        
+
+@override Future<void> crateApiSessionDeleteClips({required int trackId , required List<int> clipIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_list_prim_u_32_loose(clipIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSessionDeleteClipsConstMeta,
+            argValues: [trackId, clipIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionDeleteClipsConstMeta => const TaskConstMeta(
+            debugName: "delete_clips",
+            argNames: ["trackId", "clipIds"],
+        );
+        
+
+@override Future<UiNote> crateApiPatternDeleteNote({required int patternId , required int noteId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_u_32(noteId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternDeleteNoteConstMeta,
+            argValues: [patternId, noteId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternDeleteNoteConstMeta => const TaskConstMeta(
+            debugName: "delete_note",
+            argNames: ["patternId", "noteId"],
+        );
+        
+
+@override Future<void> crateApiSessionDeletePatternNotes({required int patternId , required List<int> noteIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_list_prim_u_32_loose(noteIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSessionDeletePatternNotesConstMeta,
+            argValues: [patternId, noteIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionDeletePatternNotesConstMeta => const TaskConstMeta(
+            debugName: "delete_pattern_notes",
+            argNames: ["patternId", "noteIds"],
+        );
+        
+
+@override Future<String> crateApiPluginExecuteEffectInstanceCommand({required UiEffectTarget target , required int effectId , required String command , required String payloadJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_effect_target(target, serializer);
+sse_encode_u_32(effectId, serializer);
+sse_encode_String(command, serializer);
+sse_encode_String(payloadJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginExecuteEffectInstanceCommandConstMeta,
-        argValues: [target, effectId, command, payloadJson],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginExecuteEffectInstanceCommandConstMeta =>
-      const TaskConstMeta(
-        debugName: "execute_effect_instance_command",
-        argNames: ["target", "effectId", "command", "payloadJson"],
-      );
-
-  @override
-  Future<String> crateApiPluginExecuteGeneratorInstanceCommand({
-    required int generatorId,
-    required String command,
-    required String payloadJson,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(generatorId, serializer);
-          sse_encode_String(command, serializer);
-          sse_encode_String(payloadJson, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 29,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginExecuteEffectInstanceCommandConstMeta,
+            argValues: [target, effectId, command, payloadJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginExecuteEffectInstanceCommandConstMeta => const TaskConstMeta(
+            debugName: "execute_effect_instance_command",
+            argNames: ["target", "effectId", "command", "payloadJson"],
+        );
+        
+
+@override Future<String> crateApiPluginExecuteGeneratorInstanceCommand({required int generatorId , required String command , required String payloadJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(generatorId, serializer);
+sse_encode_String(command, serializer);
+sse_encode_String(payloadJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginExecuteGeneratorInstanceCommandConstMeta,
-        argValues: [generatorId, command, payloadJson],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginExecuteGeneratorInstanceCommandConstMeta =>
-      const TaskConstMeta(
-        debugName: "execute_generator_instance_command",
-        argNames: ["generatorId", "command", "payloadJson"],
-      );
-
-  @override
-  Future<String?> crateApiPluginExecutePluginCommandEffect({
-    required int effectRegistryId,
-    required String command,
-    required String payloadJson,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(effectRegistryId, serializer);
-          sse_encode_String(command, serializer);
-          sse_encode_String(payloadJson, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 30,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginExecuteGeneratorInstanceCommandConstMeta,
+            argValues: [generatorId, command, payloadJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginExecuteGeneratorInstanceCommandConstMeta => const TaskConstMeta(
+            debugName: "execute_generator_instance_command",
+            argNames: ["generatorId", "command", "payloadJson"],
+        );
+        
+
+@override Future<String?> crateApiPluginExecutePluginCommandEffect({required int effectRegistryId , required String command , required String payloadJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(effectRegistryId, serializer);
+sse_encode_String(command, serializer);
+sse_encode_String(payloadJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPluginExecutePluginCommandEffectConstMeta,
-        argValues: [effectRegistryId, command, payloadJson],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginExecutePluginCommandEffectConstMeta =>
-      const TaskConstMeta(
-        debugName: "execute_plugin_command_effect",
-        argNames: ["effectRegistryId", "command", "payloadJson"],
-      );
-
-  @override
-  Future<String?> crateApiPluginExecutePluginCommandGenerator({
-    required int genRegistryId,
-    required String command,
-    required String payloadJson,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(genRegistryId, serializer);
-          sse_encode_String(command, serializer);
-          sse_encode_String(payloadJson, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginExecutePluginCommandEffectConstMeta,
+            argValues: [effectRegistryId, command, payloadJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginExecutePluginCommandEffectConstMeta => const TaskConstMeta(
+            debugName: "execute_plugin_command_effect",
+            argNames: ["effectRegistryId", "command", "payloadJson"],
+        );
+        
+
+@override Future<String?> crateApiPluginExecutePluginCommandGenerator({required int genRegistryId , required String command , required String payloadJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(genRegistryId, serializer);
+sse_encode_String(command, serializer);
+sse_encode_String(payloadJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPluginExecutePluginCommandGeneratorConstMeta,
-        argValues: [genRegistryId, command, payloadJson],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginExecutePluginCommandGeneratorConstMeta =>
-      const TaskConstMeta(
-        debugName: "execute_plugin_command_generator",
-        argNames: ["genRegistryId", "command", "payloadJson"],
-      );
-
-  @override
-  Future<UiAudioHardwareConfig> crateApiAudioGetAudioConfig() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 32,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginExecutePluginCommandGeneratorConstMeta,
+            argValues: [genRegistryId, command, payloadJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginExecutePluginCommandGeneratorConstMeta => const TaskConstMeta(
+            debugName: "execute_plugin_command_generator",
+            argNames: ["genRegistryId", "command", "payloadJson"],
+        );
+        
+
+@override Future<UiLoudnessReport> crateApiAudioExportProject({required String path , required int sampleRate , required UiBitPerSample bitPerSample })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_u_32(sampleRate, serializer);
+sse_encode_ui_bit_per_sample(bitPerSample, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_loudness_report,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAudioExportProjectConstMeta,
+            argValues: [path, sampleRate, bitPerSample],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioExportProjectConstMeta => const TaskConstMeta(
+            debugName: "export_project",
+            argNames: ["path", "sampleRate", "bitPerSample"],
+        );
+        
+
+@override Future<Uint32List> crateApiRecordingGetArmedTracks()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_32_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecordingGetArmedTracksConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingGetArmedTracksConstMeta => const TaskConstMeta(
+            debugName: "get_armed_tracks",
+            argNames: [],
+        );
+        
+
+@override Future<UiAudioHardwareConfig> crateApiAudioGetAudioConfig()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_audio_hardware_config,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiAudioGetAudioConfigConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAudioGetAudioConfigConstMeta =>
-      const TaskConstMeta(debugName: "get_audio_config", argNames: []);
-
-  @override
-  Future<AudioWaveformUiForAudioProperties?> crateApiAudioGetAudioProperties({
-    required int id,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 33,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_audio_waveform_ui_for_audio_properties,
+        )
+        ,
+            constMeta: kCrateApiAudioGetAudioConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioGetAudioConfigConstMeta => const TaskConstMeta(
+            debugName: "get_audio_config",
+            argNames: [],
+        );
+        
+
+@override Future<UiAudioDeviceSettings> crateApiAudioGetAudioDeviceSettings()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_audio_device_settings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAudioGetAudioDeviceSettingsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioGetAudioDeviceSettingsConstMeta => const TaskConstMeta(
+            debugName: "get_audio_device_settings",
+            argNames: [],
+        );
+        
+
+@override Future<AudioWaveformUiForAudioProperties?> crateApiAudioGetAudioProperties({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_audio_waveform_ui_for_audio_properties,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAudioGetAudioPropertiesConstMeta,
-        argValues: [id],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAudioGetAudioPropertiesConstMeta =>
-      const TaskConstMeta(debugName: "get_audio_properties", argNames: ["id"]);
-
-  @override
-  Future<Map<int, AudioWaveformUiForSourceList>?>
-  crateApiProjectGetAudioSourceList() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 34,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_Map_u_32_audio_waveform_ui_for_source_list_None,
+        )
+        ,
+            constMeta: kCrateApiAudioGetAudioPropertiesConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioGetAudioPropertiesConstMeta => const TaskConstMeta(
+            debugName: "get_audio_properties",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Map<int, AudioWaveformUiForSourceList>?> crateApiProjectGetAudioSourceList()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_Map_u_32_audio_waveform_ui_for_source_list_None,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProjectGetAudioSourceListConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectGetAudioSourceListConstMeta =>
-      const TaskConstMeta(debugName: "get_audio_source_list", argNames: []);
-
-  @override
-  Future<Map<int, AudioWaveformUiForClip>>
-  crateApiTrackGetAudioWaveformClipsData() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 35,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Map_u_32_audio_waveform_ui_for_clip_None,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackGetAudioWaveformClipsDataConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackGetAudioWaveformClipsDataConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_audio_waveform_clips_data",
-        argNames: [],
-      );
-
-  @override
-  Future<AudioWaveformUiForClip> crateApiTrackGetAudioWaveformForClip({
-    required int audioSourceId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(audioSourceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 36,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiProjectGetAudioSourceListConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectGetAudioSourceListConstMeta => const TaskConstMeta(
+            debugName: "get_audio_source_list",
+            argNames: [],
+        );
+        
+
+@override Future<Map<int, AudioWaveformUiForClip>> crateApiTrackGetAudioWaveformClipsData()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Map_u_32_audio_waveform_ui_for_clip_None,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTrackGetAudioWaveformClipsDataConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackGetAudioWaveformClipsDataConstMeta => const TaskConstMeta(
+            debugName: "get_audio_waveform_clips_data",
+            argNames: [],
+        );
+        
+
+@override Future<AudioWaveformUiForClip> crateApiTrackGetAudioWaveformForClip({required int audioSourceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(audioSourceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_audio_waveform_ui_for_clip,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackGetAudioWaveformForClipConstMeta,
-        argValues: [audioSourceId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackGetAudioWaveformForClipConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_audio_waveform_for_clip",
-        argNames: ["audioSourceId"],
-      );
-
-  @override
-  Future<Map<int, AudioWaveformUiForClip>>
-  crateApiTrackGetAudioWaveformForClipAllAvailableInTracks() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 37,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Map_u_32_audio_waveform_ui_for_clip_None,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta:
-            kCrateApiTrackGetAudioWaveformForClipAllAvailableInTracksConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTrackGetAudioWaveformForClipAllAvailableInTracksConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_audio_waveform_for_clip_all_available_in_tracks",
-        argNames: [],
-      );
-
-  @override
-  Future<Map<int, AudioWaveformUiForClip>>
-  crateApiTrackGetAudioWaveformForClipOnlyInSpecificTrack({
-    required int trackId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 38,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Map_u_32_audio_waveform_ui_for_clip_None,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta:
-            kCrateApiTrackGetAudioWaveformForClipOnlyInSpecificTrackConstMeta,
-        argValues: [trackId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTrackGetAudioWaveformForClipOnlyInSpecificTrackConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_audio_waveform_for_clip_only_in_specific_track",
-        argNames: ["trackId"],
-      );
-
-  @override
-  Future<List<UiPluginInfo>> crateApiPluginGetAvailableEffectsWithIds() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 39,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTrackGetAudioWaveformForClipConstMeta,
+            argValues: [audioSourceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackGetAudioWaveformForClipConstMeta => const TaskConstMeta(
+            debugName: "get_audio_waveform_for_clip",
+            argNames: ["audioSourceId"],
+        );
+        
+
+@override Future<Map<int, AudioWaveformUiForClip>> crateApiTrackGetAudioWaveformForClipAllAvailableInTracks()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Map_u_32_audio_waveform_ui_for_clip_None,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTrackGetAudioWaveformForClipAllAvailableInTracksConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackGetAudioWaveformForClipAllAvailableInTracksConstMeta => const TaskConstMeta(
+            debugName: "get_audio_waveform_for_clip_all_available_in_tracks",
+            argNames: [],
+        );
+        
+
+@override Future<Map<int, AudioWaveformUiForClip>> crateApiTrackGetAudioWaveformForClipOnlyInSpecificTrack({required int trackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Map_u_32_audio_waveform_ui_for_clip_None,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTrackGetAudioWaveformForClipOnlyInSpecificTrackConstMeta,
+            argValues: [trackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackGetAudioWaveformForClipOnlyInSpecificTrackConstMeta => const TaskConstMeta(
+            debugName: "get_audio_waveform_for_clip_only_in_specific_track",
+            argNames: ["trackId"],
+        );
+        
+
+@override Future<List<UiPluginInfo>> crateApiPluginGetAvailableEffectsWithIds()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_ui_plugin_info,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginGetAvailableEffectsWithIdsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetAvailableEffectsWithIdsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_available_effects_with_ids",
-        argNames: [],
-      );
-
-  @override
-  Future<List<UiPluginInfo>> crateApiPluginGetAvailableGeneratorsWithIds() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 40,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetAvailableEffectsWithIdsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetAvailableEffectsWithIdsConstMeta => const TaskConstMeta(
+            debugName: "get_available_effects_with_ids",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiPluginInfo>> crateApiPluginGetAvailableGeneratorsWithIds()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_ui_plugin_info,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginGetAvailableGeneratorsWithIdsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetAvailableGeneratorsWithIdsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_available_generators_with_ids",
-        argNames: [],
-      );
-
-  @override
-  Future<List<ParameterSpecDTO>?> crateApiMixerGetBusMixerChannelSpecs({
-    required int busId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(busId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 41,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetAvailableGeneratorsWithIdsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetAvailableGeneratorsWithIdsConstMeta => const TaskConstMeta(
+            debugName: "get_available_generators_with_ids",
+            argNames: [],
+        );
+        
+
+@override Future<List<ParameterSpecDTO>?> crateApiMixerGetBusMixerChannelSpecs({required int busId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(busId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_list_parameter_spec_dto,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerGetBusMixerChannelSpecsConstMeta,
-        argValues: [busId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetBusMixerChannelSpecsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_bus_mixer_channel_specs",
-        argNames: ["busId"],
-      );
-
-  @override
-  Future<Map<int, UiBus>> crateApiMixerGetBuses() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 42,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMixerGetBusMixerChannelSpecsConstMeta,
+            argValues: [busId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetBusMixerChannelSpecsConstMeta => const TaskConstMeta(
+            debugName: "get_bus_mixer_channel_specs",
+            argNames: ["busId"],
+        );
+        
+
+@override Future<Map<int, UiBus>> crateApiMixerGetBuses()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_Map_u_32_ui_bus_None,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerGetBusesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetBusesConstMeta =>
-      const TaskConstMeta(debugName: "get_buses", argNames: []);
-
-  @override
-  Future<UiClip> crateApiTrackGetClip({
-    required int trackId,
-    required int clipId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_u_32(clipId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 43,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMixerGetBusesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetBusesConstMeta => const TaskConstMeta(
+            debugName: "get_buses",
+            argNames: [],
+        );
+        
+
+@override Future<UiClip> crateApiTrackGetClip({required int trackId , required int clipId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(clipId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_clip,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackGetClipConstMeta,
-        argValues: [trackId, clipId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackGetClipConstMeta => const TaskConstMeta(
-    debugName: "get_clip",
-    argNames: ["trackId", "clipId"],
-  );
-
-  @override
-  Future<UiClipboardContent> crateApiSessionGetClipboardContents() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 44,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTrackGetClipConstMeta,
+            argValues: [trackId, clipId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackGetClipConstMeta => const TaskConstMeta(
+            debugName: "get_clip",
+            argNames: ["trackId", "clipId"],
+        );
+        
+
+@override Future<UiClipboardContent> crateApiSessionGetClipboardContents()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_clipboard_content,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSessionGetClipboardContentsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionGetClipboardContentsConstMeta =>
-      const TaskConstMeta(debugName: "get_clipboard_contents", argNames: []);
-
-  @override
-  Future<UiEffectInstance> crateApiPluginGetEffect({
-    required int trackId,
-    required int effectId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_u_32(effectId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 45,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSessionGetClipboardContentsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionGetClipboardContentsConstMeta => const TaskConstMeta(
+            debugName: "get_clipboard_contents",
+            argNames: [],
+        );
+        
+
+@override Future<UiEffectInstance> crateApiPluginGetEffect({required int trackId , required int effectId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(effectId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_effect_instance,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginGetEffectConstMeta,
-        argValues: [trackId, effectId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetEffectConstMeta => const TaskConstMeta(
-    debugName: "get_effect",
-    argNames: ["trackId", "effectId"],
-  );
-
-  @override
-  Future<UiEffectInstance> crateApiPluginGetEffectFromMaster({
-    required int effectId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(effectId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 46,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetEffectConstMeta,
+            argValues: [trackId, effectId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetEffectConstMeta => const TaskConstMeta(
+            debugName: "get_effect",
+            argNames: ["trackId", "effectId"],
+        );
+        
+
+@override Future<UiEffectInstance> crateApiPluginGetEffectFromMaster({required int effectId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(effectId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_effect_instance,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginGetEffectFromMasterConstMeta,
-        argValues: [effectId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetEffectFromMasterConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_effect_from_master",
-        argNames: ["effectId"],
-      );
-
-  @override
-  Future<List<UiPluginParameter>> crateApiPluginGetEffectParameterSpecs({
-    required UiEffectTarget target,
-    required int effectId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_effect_target(target, serializer);
-          sse_encode_u_32(effectId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 47,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetEffectFromMasterConstMeta,
+            argValues: [effectId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetEffectFromMasterConstMeta => const TaskConstMeta(
+            debugName: "get_effect_from_master",
+            argNames: ["effectId"],
+        );
+        
+
+@override Future<List<UiPluginParameter>> crateApiPluginGetEffectParameterSpecs({required UiEffectTarget target , required int effectId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_effect_target(target, serializer);
+sse_encode_u_32(effectId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_ui_plugin_parameter,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginGetEffectParameterSpecsConstMeta,
-        argValues: [target, effectId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetEffectParameterSpecsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_effect_parameter_specs",
-        argNames: ["target", "effectId"],
-      );
-
-  @override
-  Future<List<UiEffectInstance>> crateApiPluginGetEffectsFromTrack({
-    required int trackId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 48,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetEffectParameterSpecsConstMeta,
+            argValues: [target, effectId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetEffectParameterSpecsConstMeta => const TaskConstMeta(
+            debugName: "get_effect_parameter_specs",
+            argNames: ["target", "effectId"],
+        );
+        
+
+@override Future<List<UiEffectInstance>> crateApiPluginGetEffectsFromTrack({required int trackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_ui_effect_instance,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginGetEffectsFromTrackConstMeta,
-        argValues: [trackId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetEffectsFromTrackConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_effects_from_track",
-        argNames: ["trackId"],
-      );
-
-  @override
-  Future<List<UiResponseCurvePoint>> crateApiPluginsEqGetEqResponseCurve({
-    required UiEffectTarget target,
-    required int effectId,
-    required int numPoints,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_effect_target(target, serializer);
-          sse_encode_u_32(effectId, serializer);
-          sse_encode_u_32(numPoints, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 49,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetEffectsFromTrackConstMeta,
+            argValues: [trackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetEffectsFromTrackConstMeta => const TaskConstMeta(
+            debugName: "get_effects_from_track",
+            argNames: ["trackId"],
+        );
+        
+
+@override Future<UiEngineLoad?> crateApiAudioGetEngineLoad()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ui_engine_load,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAudioGetEngineLoadConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioGetEngineLoadConstMeta => const TaskConstMeta(
+            debugName: "get_engine_load",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiResponseCurvePoint>> crateApiPluginsEqGetEqResponseCurve({required UiEffectTarget target , required int effectId , required int numPoints })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_effect_target(target, serializer);
+sse_encode_u_32(effectId, serializer);
+sse_encode_u_32(numPoints, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_ui_response_curve_point,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginsEqGetEqResponseCurveConstMeta,
-        argValues: [target, effectId, numPoints],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginsEqGetEqResponseCurveConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_eq_response_curve",
-        argNames: ["target", "effectId", "numPoints"],
-      );
-
-  @override
-  Future<UiGeneratorInstance> crateApiPluginGetGenerator({
-    required int generatorId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(generatorId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 50,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginsEqGetEqResponseCurveConstMeta,
+            argValues: [target, effectId, numPoints],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginsEqGetEqResponseCurveConstMeta => const TaskConstMeta(
+            debugName: "get_eq_response_curve",
+            argNames: ["target", "effectId", "numPoints"],
+        );
+        
+
+@override Future<UiGeneratorInstance> crateApiPluginGetGenerator({required int generatorId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(generatorId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_generator_instance,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginGetGeneratorConstMeta,
-        argValues: [generatorId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetGeneratorConstMeta => const TaskConstMeta(
-    debugName: "get_generator",
-    argNames: ["generatorId"],
-  );
-
-  @override
-  Future<Map<int, UiGeneratorInstance>> crateApiProjectGetGeneratorList() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 51,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetGeneratorConstMeta,
+            argValues: [generatorId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetGeneratorConstMeta => const TaskConstMeta(
+            debugName: "get_generator",
+            argNames: ["generatorId"],
+        );
+        
+
+@override Future<Map<int, UiGeneratorInstance>> crateApiProjectGetGeneratorList()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_Map_u_32_ui_generator_instance_None,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiProjectGetGeneratorListConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectGetGeneratorListConstMeta =>
-      const TaskConstMeta(debugName: "get_generator_list", argNames: []);
-
-  @override
-  Future<double> crateApiPluginGetGeneratorParameter({
-    required int generatorId,
-    required int paramId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(generatorId, serializer);
-          sse_encode_u_32(paramId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 52,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiProjectGetGeneratorListConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectGetGeneratorListConstMeta => const TaskConstMeta(
+            debugName: "get_generator_list",
+            argNames: [],
+        );
+        
+
+@override Future<double> crateApiPluginGetGeneratorParameter({required int generatorId , required int paramId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(generatorId, serializer);
+sse_encode_u_32(paramId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_f_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginGetGeneratorParameterConstMeta,
-        argValues: [generatorId, paramId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetGeneratorParameterConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_generator_parameter",
-        argNames: ["generatorId", "paramId"],
-      );
-
-  @override
-  Future<List<UiPluginParameter>> crateApiPluginGetGeneratorParameterSpecs({
-    required int generatorId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(generatorId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 53,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetGeneratorParameterConstMeta,
+            argValues: [generatorId, paramId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetGeneratorParameterConstMeta => const TaskConstMeta(
+            debugName: "get_generator_parameter",
+            argNames: ["generatorId", "paramId"],
+        );
+        
+
+@override Future<List<UiPluginParameter>> crateApiPluginGetGeneratorParameterSpecs({required int generatorId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(generatorId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_ui_plugin_parameter,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginGetGeneratorParameterSpecsConstMeta,
-        argValues: [generatorId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetGeneratorParameterSpecsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_generator_parameter_specs",
-        argNames: ["generatorId"],
-      );
-
-  @override
-  Future<UiMixerChannel> crateApiMixerGetMasterBus() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 54,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetGeneratorParameterSpecsConstMeta,
+            argValues: [generatorId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetGeneratorParameterSpecsConstMeta => const TaskConstMeta(
+            debugName: "get_generator_parameter_specs",
+            argNames: ["generatorId"],
+        );
+        
+
+@override Future<UiLoopRegion?> crateApiTransportGetLoopRegion()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ui_loop_region,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTransportGetLoopRegionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportGetLoopRegionConstMeta => const TaskConstMeta(
+            debugName: "get_loop_region",
+            argNames: [],
+        );
+        
+
+@override Future<UiLoudnessReport?> crateApiAudioGetLoudnessReport()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ui_loudness_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAudioGetLoudnessReportConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioGetLoudnessReportConstMeta => const TaskConstMeta(
+            debugName: "get_loudness_report",
+            argNames: [],
+        );
+        
+
+@override Future<UiMixerChannel> crateApiMixerGetMasterBus()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_mixer_channel,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerGetMasterBusConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetMasterBusConstMeta =>
-      const TaskConstMeta(debugName: "get_master_bus", argNames: []);
-
-  @override
-  Future<List<UiEffectInstance>> crateApiMixerGetMasterBusPopulated() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 55,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMixerGetMasterBusConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetMasterBusConstMeta => const TaskConstMeta(
+            debugName: "get_master_bus",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiEffectInstance>> crateApiMixerGetMasterBusPopulated()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_ui_effect_instance,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerGetMasterBusPopulatedConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetMasterBusPopulatedConstMeta =>
-      const TaskConstMeta(debugName: "get_master_bus_populated", argNames: []);
-
-  @override
-  Future<List<ParameterSpecDTO>> crateApiMixerGetMasterChannelSpecs() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 56,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMixerGetMasterBusPopulatedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetMasterBusPopulatedConstMeta => const TaskConstMeta(
+            debugName: "get_master_bus_populated",
+            argNames: [],
+        );
+        
+
+@override Future<List<ParameterSpecDTO>> crateApiMixerGetMasterChannelSpecs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_parameter_spec_dto,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerGetMasterChannelSpecsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetMasterChannelSpecsConstMeta =>
-      const TaskConstMeta(debugName: "get_master_channel_specs", argNames: []);
-
-  @override
-  Future<List<UiEffectInstance>> crateApiPluginGetMasterEffects() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 57,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMixerGetMasterChannelSpecsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetMasterChannelSpecsConstMeta => const TaskConstMeta(
+            debugName: "get_master_channel_specs",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiEffectInstance>> crateApiPluginGetMasterEffects()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_ui_effect_instance,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPluginGetMasterEffectsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginGetMasterEffectsConstMeta =>
-      const TaskConstMeta(debugName: "get_master_effects", argNames: []);
-
-  @override
-  Future<int> crateApiProjectGetMaxSampleIndex() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 58,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginGetMasterEffectsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginGetMasterEffectsConstMeta => const TaskConstMeta(
+            debugName: "get_master_effects",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiProjectGetMaxSampleIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiProjectGetMaxSampleIndexConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectGetMaxSampleIndexConstMeta =>
-      const TaskConstMeta(debugName: "get_max_sample_index", argNames: []);
-
-  @override
-  Future<UiMixerChannel> crateApiMixerGetMixerChannel({required int trackId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 59,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_mixer_channel,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerGetMixerChannelConstMeta,
-        argValues: [trackId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetMixerChannelConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_mixer_channel",
-        argNames: ["trackId"],
-      );
-
-  @override
-  Future<(UiMixerChannel, List<UiEffectInstance>)>
-  crateApiMixerGetMixerChannelPopulated({required int trackId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 60,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_ui_mixer_channel_list_ui_effect_instance,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerGetMixerChannelPopulatedConstMeta,
-        argValues: [trackId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetMixerChannelPopulatedConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_mixer_channel_populated",
-        argNames: ["trackId"],
-      );
-
-  @override
-  Future<UiMixerState> crateApiMixerGetMixerState() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 61,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_mixer_state,
+        )
+        ,
+            constMeta: kCrateApiProjectGetMaxSampleIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectGetMaxSampleIndexConstMeta => const TaskConstMeta(
+            debugName: "get_max_sample_index",
+            argNames: [],
+        );
+        
+
+@override Future<UiMetronomeSettings> crateApiTransportGetMetronomeSettings()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_metronome_settings,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerGetMixerStateConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetMixerStateConstMeta =>
-      const TaskConstMeta(debugName: "get_mixer_state", argNames: []);
-
-  @override
-  Future<UiPattern> crateApiPatternGetPattern({required int patternId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 62,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_pattern,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPatternGetPatternConstMeta,
-        argValues: [patternId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPatternGetPatternConstMeta =>
-      const TaskConstMeta(debugName: "get_pattern", argNames: ["patternId"]);
-
-  @override
-  Future<Map<int, UiPattern>> crateApiPatternGetPatterns() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 63,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_Map_u_32_ui_pattern_None,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPatternGetPatternsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPatternGetPatternsConstMeta =>
-      const TaskConstMeta(debugName: "get_patterns", argNames: []);
-
-  @override
-  Future<UiProjectMetadata> crateApiProjectGetProjectMetadata() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 64,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_project_metadata,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiProjectGetProjectMetadataConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectGetProjectMetadataConstMeta =>
-      const TaskConstMeta(debugName: "get_project_metadata", argNames: []);
-
-  @override
-  Future<List<UiRoutingConnection>> crateApiMixerGetRoutingMatrix() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 65,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_ui_routing_connection,
+        )
+        ,
+            constMeta: kCrateApiTransportGetMetronomeSettingsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportGetMetronomeSettingsConstMeta => const TaskConstMeta(
+            debugName: "get_metronome_settings",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiMidiGetMidiBackendName()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerGetRoutingMatrixConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetRoutingMatrixConstMeta =>
-      const TaskConstMeta(debugName: "get_routing_matrix", argNames: []);
-
-  @override
-  Future<UiTrack> crateApiTrackGetTrack({required int trackId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 66,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_track,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackGetTrackConstMeta,
-        argValues: [trackId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackGetTrackConstMeta =>
-      const TaskConstMeta(debugName: "get_track", argNames: ["trackId"]);
-
-  @override
-  Future<List<ParameterSpecDTO>?> crateApiMixerGetTrackMixerChannelSpecs({
-    required int trackId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 67,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_parameter_spec_dto,
+        )
+        ,
+            constMeta: kCrateApiMidiGetMidiBackendNameConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiGetMidiBackendNameConstMeta => const TaskConstMeta(
+            debugName: "get_midi_backend_name",
+            argNames: [],
+        );
+        
+
+@override Future<UiMidiOutputSettings> crateApiMidiGetMidiOutputSettings()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_midi_output_settings,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerGetTrackMixerChannelSpecsConstMeta,
-        argValues: [trackId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerGetTrackMixerChannelSpecsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_track_mixer_channel_specs",
-        argNames: ["trackId"],
-      );
-
-  @override
-  Future<Map<int, UiTrack>> crateApiProjectGetTracks() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 68,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_Map_u_32_ui_track_None,
+        )
+        ,
+            constMeta: kCrateApiMidiGetMidiOutputSettingsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiGetMidiOutputSettingsConstMeta => const TaskConstMeta(
+            debugName: "get_midi_output_settings",
+            argNames: [],
+        );
+        
+
+@override Future<UiMidiRecordMode> crateApiRecordingGetMidiRecordMode()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_midi_record_mode,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecordingGetMidiRecordModeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingGetMidiRecordModeConstMeta => const TaskConstMeta(
+            debugName: "get_midi_record_mode",
+            argNames: [],
+        );
+        
+
+@override Future<int?> crateApiRecordingGetMidiRecordQuantize()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecordingGetMidiRecordQuantizeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingGetMidiRecordQuantizeConstMeta => const TaskConstMeta(
+            debugName: "get_midi_record_quantize",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiAudioGetMixLatency()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAudioGetMixLatencyConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioGetMixLatencyConstMeta => const TaskConstMeta(
+            debugName: "get_mix_latency",
+            argNames: [],
+        );
+        
+
+@override Future<UiMixerChannel> crateApiMixerGetMixerChannel({required int trackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_mixer_channel,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiProjectGetTracksConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectGetTracksConstMeta =>
-      const TaskConstMeta(debugName: "get_tracks", argNames: []);
-
-  @override
-  Future<UiTransportState> crateApiProjectGetTransportState() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 69,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_transport_state,
+        )
+        ,
+            constMeta: kCrateApiMixerGetMixerChannelConstMeta,
+            argValues: [trackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetMixerChannelConstMeta => const TaskConstMeta(
+            debugName: "get_mixer_channel",
+            argNames: ["trackId"],
+        );
+        
+
+@override Future<(UiMixerChannel,List<UiEffectInstance>)> crateApiMixerGetMixerChannelPopulated({required int trackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_ui_mixer_channel_list_ui_effect_instance,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiProjectGetTransportStateConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectGetTransportStateConstMeta =>
-      const TaskConstMeta(debugName: "get_transport_state", argNames: []);
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerGetMixerChannelPopulatedConstMeta,
+            argValues: [trackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetMixerChannelPopulatedConstMeta => const TaskConstMeta(
+            debugName: "get_mixer_channel_populated",
+            argNames: ["trackId"],
+        );
+        
+
+@override Future<UiMixerState> crateApiMixerGetMixerState()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_mixer_state,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 71,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiMixerGetMixerStateConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetMixerStateConstMeta => const TaskConstMeta(
+            debugName: "get_mixer_state",
+            argNames: [],
+        );
+        
+
+@override Future<String?> crateApiMidiGetOpenMidiInput()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  Future<UiApplicationState> crateApiSerializationLoadProject({
-    required String pathName,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pathName, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 72,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_application_state,
+        )
+        ,
+            constMeta: kCrateApiMidiGetOpenMidiInputConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiGetOpenMidiInputConstMeta => const TaskConstMeta(
+            debugName: "get_open_midi_input",
+            argNames: [],
+        );
+        
+
+@override Future<String?> crateApiMidiGetOpenMidiOutput()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMidiGetOpenMidiOutputConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiGetOpenMidiOutputConstMeta => const TaskConstMeta(
+            debugName: "get_open_midi_output",
+            argNames: [],
+        );
+        
+
+@override Future<Uint32List> crateApiPatternGetOutOfKeyNotes({required int patternId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_32_strict,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSerializationLoadProjectConstMeta,
-        argValues: [pathName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSerializationLoadProjectConstMeta =>
-      const TaskConstMeta(debugName: "load_project", argNames: ["pathName"]);
-
-  @override
-  Future<void> crateApiSessionMoveClip({
-    required int oldTrackId,
-    required int newTrackId,
-    required int clipId,
-    required int newStartTime,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(oldTrackId, serializer);
-          sse_encode_u_32(newTrackId, serializer);
-          sse_encode_u_32(clipId, serializer);
-          sse_encode_u_32(newStartTime, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 73,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiPatternGetOutOfKeyNotesConstMeta,
+            argValues: [patternId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternGetOutOfKeyNotesConstMeta => const TaskConstMeta(
+            debugName: "get_out_of_key_notes",
+            argNames: ["patternId"],
+        );
+        
+
+@override Future<UiPattern> crateApiPatternGetPattern({required int patternId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_pattern,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionMoveClipConstMeta,
-        argValues: [oldTrackId, newTrackId, clipId, newStartTime],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionMoveClipConstMeta => const TaskConstMeta(
-    debugName: "move_clip",
-    argNames: ["oldTrackId", "newTrackId", "clipId", "newStartTime"],
-  );
-
-  @override
-  Future<UiClip> crateApiTrackMoveClip({
-    required int sourceTrackId,
-    required int clipId,
-    required int newStartTime,
-    int? newTrackId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(sourceTrackId, serializer);
-          sse_encode_u_32(clipId, serializer);
-          sse_encode_u_32(newStartTime, serializer);
-          sse_encode_opt_box_autoadd_u_32(newTrackId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 74,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_clip,
+        )
+        ,
+            constMeta: kCrateApiPatternGetPatternConstMeta,
+            argValues: [patternId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternGetPatternConstMeta => const TaskConstMeta(
+            debugName: "get_pattern",
+            argNames: ["patternId"],
+        );
+        
+
+@override Future<UiGroove?> crateApiPatternGetPatternGroove({required int patternId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ui_groove,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackMoveClipConstMeta,
-        argValues: [sourceTrackId, clipId, newStartTime, newTrackId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackMoveClipConstMeta => const TaskConstMeta(
-    debugName: "move_clip",
-    argNames: ["sourceTrackId", "clipId", "newStartTime", "newTrackId"],
-  );
-
-  @override
-  Future<List<UiClip>> crateApiTrackMoveClipBatch({
-    required int sourceTrackId,
-    required List<int> clipIds,
-    required int deltaSamples,
-    int? newTrackId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(sourceTrackId, serializer);
-          sse_encode_list_prim_u_32_loose(clipIds, serializer);
-          sse_encode_CastedPrimitive_i_64(deltaSamples, serializer);
-          sse_encode_opt_box_autoadd_u_32(newTrackId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 75,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_ui_clip,
+        )
+        ,
+            constMeta: kCrateApiPatternGetPatternGrooveConstMeta,
+            argValues: [patternId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternGetPatternGrooveConstMeta => const TaskConstMeta(
+            debugName: "get_pattern_groove",
+            argNames: ["patternId"],
+        );
+        
+
+@override Future<UiScale?> crateApiPatternGetPatternScale({required int patternId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ui_scale,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackMoveClipBatchConstMeta,
-        argValues: [sourceTrackId, clipIds, deltaSamples, newTrackId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackMoveClipBatchConstMeta => const TaskConstMeta(
-    debugName: "move_clip_batch",
-    argNames: ["sourceTrackId", "clipIds", "deltaSamples", "newTrackId"],
-  );
-
-  @override
-  Future<UiNote> crateApiPatternMoveNote({
-    required int patternId,
-    required int noteId,
-    required int newStartTick,
-    required int newKey,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          sse_encode_u_32(noteId, serializer);
-          sse_encode_CastedPrimitive_u_64(newStartTick, serializer);
-          sse_encode_u_32(newKey, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 76,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_note,
+        )
+        ,
+            constMeta: kCrateApiPatternGetPatternScaleConstMeta,
+            argValues: [patternId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternGetPatternScaleConstMeta => const TaskConstMeta(
+            debugName: "get_pattern_scale",
+            argNames: ["patternId"],
+        );
+        
+
+@override Future<Map<int, UiPattern>> crateApiPatternGetPatterns()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Map_u_32_ui_pattern_None,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPatternMoveNoteConstMeta,
-        argValues: [patternId, noteId, newStartTick, newKey],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPatternMoveNoteConstMeta => const TaskConstMeta(
-    debugName: "move_note",
-    argNames: ["patternId", "noteId", "newStartTick", "newKey"],
-  );
-
-  @override
-  List<UiResponseCurvePoint> crateApiPluginsEqParseEqCurveResponse({
-    required String jsonStr,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(jsonStr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_ui_response_curve_point,
+        )
+        ,
+            constMeta: kCrateApiPatternGetPatternsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternGetPatternsConstMeta => const TaskConstMeta(
+            debugName: "get_patterns",
+            argNames: [],
+        );
+        
+
+@override Future<UiProjectMetadata> crateApiProjectGetProjectMetadata()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_project_metadata,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginsEqParseEqCurveResponseConstMeta,
-        argValues: [jsonStr],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginsEqParseEqCurveResponseConstMeta =>
-      const TaskConstMeta(
-        debugName: "parse_eq_curve_response",
-        argNames: ["jsonStr"],
-      );
-
-  @override
-  Future<void> crateApiSessionPasteClips({
-    required int targetTrackId,
-    required int pasteStartTime,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(targetTrackId, serializer);
-          sse_encode_u_32(pasteStartTime, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 78,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiProjectGetProjectMetadataConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectGetProjectMetadataConstMeta => const TaskConstMeta(
+            debugName: "get_project_metadata",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiTransportGetRandomSeed()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTransportGetRandomSeedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportGetRandomSeedConstMeta => const TaskConstMeta(
+            debugName: "get_random_seed",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiRoutingConnection>> crateApiMixerGetRoutingMatrix()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_routing_connection,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMixerGetRoutingMatrixConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetRoutingMatrixConstMeta => const TaskConstMeta(
+            debugName: "get_routing_matrix",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiTempoPoint>> crateApiTransportGetTempoPoints()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_tempo_point,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTransportGetTempoPointsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportGetTempoPointsConstMeta => const TaskConstMeta(
+            debugName: "get_tempo_points",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiTimeSignatureEvent>> crateApiTransportGetTimeSignatures()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_time_signature_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTransportGetTimeSignaturesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportGetTimeSignaturesConstMeta => const TaskConstMeta(
+            debugName: "get_time_signatures",
+            argNames: [],
+        );
+        
+
+@override Future<UiTrack> crateApiTrackGetTrack({required int trackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_track,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionPasteClipsConstMeta,
-        argValues: [targetTrackId, pasteStartTime],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionPasteClipsConstMeta => const TaskConstMeta(
-    debugName: "paste_clips",
-    argNames: ["targetTrackId", "pasteStartTime"],
-  );
-
-  @override
-  Future<void> crateApiSessionPastePatternNotes({
-    required int targetPatternId,
-    required int playheadTick,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(targetPatternId, serializer);
-          sse_encode_CastedPrimitive_u_64(playheadTick, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 79,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiTrackGetTrackConstMeta,
+            argValues: [trackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackGetTrackConstMeta => const TaskConstMeta(
+            debugName: "get_track",
+            argNames: ["trackId"],
+        );
+        
+
+@override Future<List<ParameterSpecDTO>?> crateApiMixerGetTrackMixerChannelSpecs({required int trackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_parameter_spec_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMixerGetTrackMixerChannelSpecsConstMeta,
+            argValues: [trackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerGetTrackMixerChannelSpecsConstMeta => const TaskConstMeta(
+            debugName: "get_track_mixer_channel_specs",
+            argNames: ["trackId"],
+        );
+        
+
+@override Future<UiMonitorMode> crateApiRecordingGetTrackMonitorMode({required int trackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_monitor_mode,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionPastePatternNotesConstMeta,
-        argValues: [targetPatternId, playheadTick],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionPastePatternNotesConstMeta =>
-      const TaskConstMeta(
-        debugName: "paste_pattern_notes",
-        argNames: ["targetPatternId", "playheadTick"],
-      );
-
-  @override
-  Future<void> crateApiPatternPlayPatternPreview({
-    required int patternId,
-    required int generatorId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          sse_encode_u_32(generatorId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 80,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiRecordingGetTrackMonitorModeConstMeta,
+            argValues: [trackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingGetTrackMonitorModeConstMeta => const TaskConstMeta(
+            debugName: "get_track_monitor_mode",
+            argNames: ["trackId"],
+        );
+        
+
+@override Future<Map<int, UiTrack>> crateApiProjectGetTracks()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Map_u_32_ui_track_None,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPatternPlayPatternPreviewConstMeta,
-        argValues: [patternId, generatorId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPatternPlayPatternPreviewConstMeta =>
-      const TaskConstMeta(
-        debugName: "play_pattern_preview",
-        argNames: ["patternId", "generatorId"],
-      );
-
-  @override
-  Future<void> crateApiAudioPlayPreviewNote({
-    required int trackId,
-    required int noteKey,
-    required int velocity,
-    required bool isOn,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_i_32(noteKey, serializer);
-          sse_encode_i_32(velocity, serializer);
-          sse_encode_bool(isOn, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 81,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiProjectGetTracksConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectGetTracksConstMeta => const TaskConstMeta(
+            debugName: "get_tracks",
+            argNames: [],
+        );
+        
+
+@override Future<UiTransportState> crateApiProjectGetTransportState()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_transport_state,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiAudioPlayPreviewNoteConstMeta,
-        argValues: [trackId, noteKey, velocity, isOn],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAudioPlayPreviewNoteConstMeta =>
-      const TaskConstMeta(
-        debugName: "play_preview_note",
-        argNames: ["trackId", "noteKey", "velocity", "isOn"],
-      );
-
-  @override
-  Future<void> crateApiAudioPlayPreviewNoteGenerator({
-    required int generatorId,
-    required int noteKey,
-    required int velocity,
-    required bool isOn,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(generatorId, serializer);
-          sse_encode_i_32(noteKey, serializer);
-          sse_encode_i_32(velocity, serializer);
-          sse_encode_bool(isOn, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 82,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiProjectGetTransportStateConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectGetTransportStateConstMeta => const TaskConstMeta(
+            debugName: "get_transport_state",
+            argNames: [],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<UiGroove> crateApiPatternGrooveFromMidiFile({required String path , required int stepTicks })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_CastedPrimitive_u_64(stepTicks, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_groove,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternGrooveFromMidiFileConstMeta,
+            argValues: [path, stepTicks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternGrooveFromMidiFileConstMeta => const TaskConstMeta(
+            debugName: "groove_from_midi_file",
+            argNames: ["path", "stepTicks"],
+        );
+        
+
+@override Future<UiGroove> crateApiPatternGrooveFromPattern({required int patternId , required int stepTicks })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_CastedPrimitive_u_64(stepTicks, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_groove,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiAudioPlayPreviewNoteGeneratorConstMeta,
-        argValues: [generatorId, noteKey, velocity, isOn],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAudioPlayPreviewNoteGeneratorConstMeta =>
-      const TaskConstMeta(
-        debugName: "play_preview_note_generator",
-        argNames: ["generatorId", "noteKey", "velocity", "isOn"],
-      );
-
-  @override
-  Future<void> crateApiAudioPlaySourcePreview({required int id}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 83,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPatternGrooveFromPatternConstMeta,
+            argValues: [patternId, stepTicks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternGrooveFromPatternConstMeta => const TaskConstMeta(
+            debugName: "groove_from_pattern",
+            argNames: ["patternId", "stepTicks"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAudioPlaySourcePreviewConstMeta,
-        argValues: [id],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAudioPlaySourcePreviewConstMeta =>
-      const TaskConstMeta(debugName: "play_source_preview", argNames: ["id"]);
-
-  @override
-  Future<List<UiEffectParameterSnapshot>>
-  crateApiPluginPollEffectParameterFeedback() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 84,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_ui_effect_parameter_snapshot,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiAudioIsPluginProfiling()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPluginPollEffectParameterFeedbackConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginPollEffectParameterFeedbackConstMeta =>
-      const TaskConstMeta(
-        debugName: "poll_effect_parameter_feedback",
-        argNames: [],
-      );
-
-  @override
-  Future<List<UiGeneratorParameterSnapshot>>
-  crateApiPluginPollGeneratorParameterFeedback() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 85,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_ui_generator_parameter_snapshot,
+        )
+        ,
+            constMeta: kCrateApiAudioIsPluginProfilingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioIsPluginProfilingConstMeta => const TaskConstMeta(
+            debugName: "is_plugin_profiling",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiRecordingIsRecording()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPluginPollGeneratorParameterFeedbackConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginPollGeneratorParameterFeedbackConstMeta =>
-      const TaskConstMeta(
-        debugName: "poll_generator_parameter_feedback",
-        argNames: [],
-      );
-
-  @override
-  UiProjectMetadata crateApiProjectProjectMetadataNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_project_metadata,
+        )
+        ,
+            constMeta: kCrateApiRecordingIsRecordingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingIsRecordingConstMeta => const TaskConstMeta(
+            debugName: "is_recording",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiAudioListAudioHosts()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProjectProjectMetadataNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectProjectMetadataNewConstMeta =>
-      const TaskConstMeta(debugName: "project_metadata_new", argNames: []);
-
-  @override
-  Future<void> crateApiPluginQueryEffectParameters({
-    required UiEffectTarget target,
-    required int effectId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_effect_target(target, serializer);
-          sse_encode_u_32(effectId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 87,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginQueryEffectParametersConstMeta,
-        argValues: [target, effectId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginQueryEffectParametersConstMeta =>
-      const TaskConstMeta(
-        debugName: "query_effect_parameters",
-        argNames: ["target", "effectId"],
-      );
-
-  @override
-  Future<void> crateApiPluginQueryGeneratorParameters({
-    required int generatorId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(generatorId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 88,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiAudioListAudioHostsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioListAudioHostsConstMeta => const TaskConstMeta(
+            debugName: "list_audio_hosts",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiAudioDeviceInfo>> crateApiAudioListAudioInputDevices({String? host })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(host, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_audio_device_info,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginQueryGeneratorParametersConstMeta,
-        argValues: [generatorId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginQueryGeneratorParametersConstMeta =>
-      const TaskConstMeta(
-        debugName: "query_generator_parameters",
-        argNames: ["generatorId"],
-      );
-
-  @override
-  Future<void> crateApiSessionRedo() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 89,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiAudioListAudioInputDevicesConstMeta,
+            argValues: [host],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioListAudioInputDevicesConstMeta => const TaskConstMeta(
+            debugName: "list_audio_input_devices",
+            argNames: ["host"],
+        );
+        
+
+@override Future<List<UiAudioDeviceInfo>> crateApiAudioListAudioOutputDevices({String? host })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(host, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_audio_device_info,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionRedoConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionRedoConstMeta =>
-      const TaskConstMeta(debugName: "redo", argNames: []);
-
-  @override
-  Future<void> crateApiMixerRemoveEffectFromMasterBus({
-    required int effectInstanceId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(effectInstanceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 90,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiAudioListAudioOutputDevicesConstMeta,
+            argValues: [host],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioListAudioOutputDevicesConstMeta => const TaskConstMeta(
+            debugName: "list_audio_output_devices",
+            argNames: ["host"],
+        );
+        
+
+@override Future<List<String>> crateApiRecordingListInputDevices()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerRemoveEffectFromMasterBusConstMeta,
-        argValues: [effectInstanceId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerRemoveEffectFromMasterBusConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_effect_from_master_bus",
-        argNames: ["effectInstanceId"],
-      );
-
-  @override
-  Future<void> crateApiMixerRemoveEffectFromMixerChannel({
-    required int trackId,
-    required int effectInstanceId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_u_32(effectInstanceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 91,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiRecordingListInputDevicesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingListInputDevicesConstMeta => const TaskConstMeta(
+            debugName: "list_input_devices",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiMidiListMidiInputs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerRemoveEffectFromMixerChannelConstMeta,
-        argValues: [trackId, effectInstanceId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerRemoveEffectFromMixerChannelConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_effect_from_mixer_channel",
-        argNames: ["trackId", "effectInstanceId"],
-      );
-
-  @override
-  Future<void> crateApiMixerRemoveRouting({
-    required UiRoutingNode source,
-    required UiRoutingNode destination,
-    required bool isSend,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_routing_node(source, serializer);
-          sse_encode_box_autoadd_ui_routing_node(destination, serializer);
-          sse_encode_bool(isSend, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 92,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiMidiListMidiInputsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiListMidiInputsConstMeta => const TaskConstMeta(
+            debugName: "list_midi_inputs",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiMidiListMidiOutputs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerRemoveRoutingConstMeta,
-        argValues: [source, destination, isSend],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerRemoveRoutingConstMeta => const TaskConstMeta(
-    debugName: "remove_routing",
-    argNames: ["source", "destination", "isSend"],
-  );
-
-  @override
-  Future<void> crateApiMixerRenameBus({
-    required int busId,
-    required String newName,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(busId, serializer);
-          sse_encode_String(newName, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 93,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiMidiListMidiOutputsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiListMidiOutputsConstMeta => const TaskConstMeta(
+            debugName: "list_midi_outputs",
+            argNames: [],
+        );
+        
+
+@override Future<UiApplicationState> crateApiSerializationLoadProject({required String pathName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pathName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_application_state,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerRenameBusConstMeta,
-        argValues: [busId, newName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerRenameBusConstMeta => const TaskConstMeta(
-    debugName: "rename_bus",
-    argNames: ["busId", "newName"],
-  );
-
-  @override
-  Future<void> crateApiSessionResizeClip({
-    required int trackId,
-    required int clipId,
-    required UiResizeEdge edge,
-    required int newTimeVal,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_u_32(clipId, serializer);
-          sse_encode_ui_resize_edge(edge, serializer);
-          sse_encode_u_32(newTimeVal, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 94,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSerializationLoadProjectConstMeta,
+            argValues: [pathName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSerializationLoadProjectConstMeta => const TaskConstMeta(
+            debugName: "load_project",
+            argNames: ["pathName"],
+        );
+        
+
+@override Future<void> crateApiSessionMoveClip({required int oldTrackId , required int newTrackId , required int clipId , required int newStartTime })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(oldTrackId, serializer);
+sse_encode_u_32(newTrackId, serializer);
+sse_encode_u_32(clipId, serializer);
+sse_encode_u_32(newStartTime, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionResizeClipConstMeta,
-        argValues: [trackId, clipId, edge, newTimeVal],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionResizeClipConstMeta => const TaskConstMeta(
-    debugName: "resize_clip",
-    argNames: ["trackId", "clipId", "edge", "newTimeVal"],
-  );
-
-  @override
-  Future<UiClip> crateApiTrackResizeClip({
-    required int trackId,
-    required int clipId,
-    required UiResizeEdge edge,
-    required int newTimeVal,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_u_32(clipId, serializer);
-          sse_encode_ui_resize_edge(edge, serializer);
-          sse_encode_u_32(newTimeVal, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 95,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSessionMoveClipConstMeta,
+            argValues: [oldTrackId, newTrackId, clipId, newStartTime],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionMoveClipConstMeta => const TaskConstMeta(
+            debugName: "move_clip",
+            argNames: ["oldTrackId", "newTrackId", "clipId", "newStartTime"],
+        );
+        
+
+@override Future<UiClip> crateApiTrackMoveClip({required int sourceTrackId , required int clipId , required int newStartTime , int? newTrackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(sourceTrackId, serializer);
+sse_encode_u_32(clipId, serializer);
+sse_encode_u_32(newStartTime, serializer);
+sse_encode_opt_box_autoadd_u_32(newTrackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_clip,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackResizeClipConstMeta,
-        argValues: [trackId, clipId, edge, newTimeVal],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackResizeClipConstMeta => const TaskConstMeta(
-    debugName: "resize_clip",
-    argNames: ["trackId", "clipId", "edge", "newTimeVal"],
-  );
-
-  @override
-  Future<List<UiClip>> crateApiTrackResizeClipBatch({
-    required int trackId,
-    required List<int> clipIds,
-    required UiResizeEdge edge,
-    required int deltaSamples,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_list_prim_u_32_loose(clipIds, serializer);
-          sse_encode_ui_resize_edge(edge, serializer);
-          sse_encode_CastedPrimitive_i_64(deltaSamples, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 96,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTrackMoveClipConstMeta,
+            argValues: [sourceTrackId, clipId, newStartTime, newTrackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackMoveClipConstMeta => const TaskConstMeta(
+            debugName: "move_clip",
+            argNames: ["sourceTrackId", "clipId", "newStartTime", "newTrackId"],
+        );
+        
+
+@override Future<List<UiClip>> crateApiTrackMoveClipBatch({required int sourceTrackId , required List<int> clipIds , required int deltaSamples , int? newTrackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(sourceTrackId, serializer);
+sse_encode_list_prim_u_32_loose(clipIds, serializer);
+sse_encode_CastedPrimitive_i_64(deltaSamples, serializer);
+sse_encode_opt_box_autoadd_u_32(newTrackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_ui_clip,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTrackResizeClipBatchConstMeta,
-        argValues: [trackId, clipIds, edge, deltaSamples],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTrackResizeClipBatchConstMeta =>
-      const TaskConstMeta(
-        debugName: "resize_clip_batch",
-        argNames: ["trackId", "clipIds", "edge", "deltaSamples"],
-      );
-
-  @override
-  Future<UiNote> crateApiPatternResizeNote({
-    required int patternId,
-    required int noteId,
-    required int newDuration,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(patternId, serializer);
-          sse_encode_u_32(noteId, serializer);
-          sse_encode_CastedPrimitive_u_64(newDuration, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 97,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTrackMoveClipBatchConstMeta,
+            argValues: [sourceTrackId, clipIds, deltaSamples, newTrackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackMoveClipBatchConstMeta => const TaskConstMeta(
+            debugName: "move_clip_batch",
+            argNames: ["sourceTrackId", "clipIds", "deltaSamples", "newTrackId"],
+        );
+        
+
+@override Future<UiNote> crateApiPatternMoveNote({required int patternId , required int noteId , required int newStartTick , required int newKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_u_32(noteId, serializer);
+sse_encode_CastedPrimitive_u_64(newStartTick, serializer);
+sse_encode_u_32(newKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_ui_note,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPatternResizeNoteConstMeta,
-        argValues: [patternId, noteId, newDuration],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPatternResizeNoteConstMeta => const TaskConstMeta(
-    debugName: "resize_note",
-    argNames: ["patternId", "noteId", "newDuration"],
-  );
-
-  @override
-  Future<void> crateApiSerializationSaveProject({required String pathName}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pathName, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 98,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSerializationSaveProjectConstMeta,
-        argValues: [pathName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSerializationSaveProjectConstMeta =>
-      const TaskConstMeta(debugName: "save_project", argNames: ["pathName"]);
-
-  @override
-  Future<void> crateApiTransportSetBpm({required double val}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_f_32(val, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 99,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTransportSetBpmConstMeta,
-        argValues: [val],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTransportSetBpmConstMeta =>
-      const TaskConstMeta(debugName: "set_bpm", argNames: ["val"]);
-
-  @override
-  Future<void> crateApiMixerSetBusParams({
-    required int busId,
-    required List<UiMixerChannelParams> params,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(busId, serializer);
-          sse_encode_list_ui_mixer_channel_params(params, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 100,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPatternMoveNoteConstMeta,
+            argValues: [patternId, noteId, newStartTick, newKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternMoveNoteConstMeta => const TaskConstMeta(
+            debugName: "move_note",
+            argNames: ["patternId", "noteId", "newStartTick", "newKey"],
+        );
+        
+
+@override Future<void> crateApiMidiOpenMidiInput({required String port })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(port, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerSetBusParamsConstMeta,
-        argValues: [busId, params],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerSetBusParamsConstMeta => const TaskConstMeta(
-    debugName: "set_bus_params",
-    argNames: ["busId", "params"],
-  );
-
-  @override
-  Future<void> crateApiPluginSetEffectParameter({
-    required UiEffectTarget target,
-    required int effectId,
-    required int paramId,
-    required double value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_effect_target(target, serializer);
-          sse_encode_u_32(effectId, serializer);
-          sse_encode_u_32(paramId, serializer);
-          sse_encode_f_32(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 101,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMidiOpenMidiInputConstMeta,
+            argValues: [port],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiOpenMidiInputConstMeta => const TaskConstMeta(
+            debugName: "open_midi_input",
+            argNames: ["port"],
+        );
+        
+
+@override Future<void> crateApiMidiOpenMidiOutput({required String port })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(port, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginSetEffectParameterConstMeta,
-        argValues: [target, effectId, paramId, value],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginSetEffectParameterConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_effect_parameter",
-        argNames: ["target", "effectId", "paramId", "value"],
-      );
-
-  @override
-  Future<void> crateApiPluginSetGeneratorParameter({
-    required int generatorId,
-    required int paramId,
-    required double value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(generatorId, serializer);
-          sse_encode_u_32(paramId, serializer);
-          sse_encode_f_32(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 102,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiMidiOpenMidiOutputConstMeta,
+            argValues: [port],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiOpenMidiOutputConstMeta => const TaskConstMeta(
+            debugName: "open_midi_output",
+            argNames: ["port"],
+        );
+        
+
+@override List<UiResponseCurvePoint> crateApiPluginsEqParseEqCurveResponse({required String jsonStr })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(jsonStr, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_response_curve_point,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPluginSetGeneratorParameterConstMeta,
-        argValues: [generatorId, paramId, value],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginSetGeneratorParameterConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_generator_parameter",
-        argNames: ["generatorId", "paramId", "value"],
-      );
-
-  @override
-  Future<void> crateApiTransportSetLooping({required bool val}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_bool(val, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 103,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginsEqParseEqCurveResponseConstMeta,
+            argValues: [jsonStr],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginsEqParseEqCurveResponseConstMeta => const TaskConstMeta(
+            debugName: "parse_eq_curve_response",
+            argNames: ["jsonStr"],
+        );
+        
+
+@override Future<void> crateApiSessionPasteClips({required int targetTrackId , required int pasteStartTime })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(targetTrackId, serializer);
+sse_encode_u_32(pasteStartTime, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTransportSetLoopingConstMeta,
-        argValues: [val],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTransportSetLoopingConstMeta =>
-      const TaskConstMeta(debugName: "set_looping", argNames: ["val"]);
-
-  @override
-  Future<void> crateApiMixerSetMasterBusParams({
-    required List<UiMixerChannelParams> params,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_ui_mixer_channel_params(params, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 104,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSessionPasteClipsConstMeta,
+            argValues: [targetTrackId, pasteStartTime],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionPasteClipsConstMeta => const TaskConstMeta(
+            debugName: "paste_clips",
+            argNames: ["targetTrackId", "pasteStartTime"],
+        );
+        
+
+@override Future<void> crateApiSessionPastePatternNotes({required int targetPatternId , required int playheadTick })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(targetPatternId, serializer);
+sse_encode_CastedPrimitive_u_64(playheadTick, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerSetMasterBusParamsConstMeta,
-        argValues: [params],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerSetMasterBusParamsConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_master_bus_params",
-        argNames: ["params"],
-      );
-
-  @override
-  Future<void> crateApiMixerSetMixerChannelParams({
-    required int trackId,
-    required List<UiMixerChannelParams> params,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(trackId, serializer);
-          sse_encode_list_ui_mixer_channel_params(params, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 105,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSessionPastePatternNotesConstMeta,
+            argValues: [targetPatternId, playheadTick],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionPastePatternNotesConstMeta => const TaskConstMeta(
+            debugName: "paste_pattern_notes",
+            argNames: ["targetPatternId", "playheadTick"],
+        );
+        
+
+@override Future<void> crateApiPatternPlayPatternPreview({required int patternId , required int generatorId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_u_32(generatorId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerSetMixerChannelParamsConstMeta,
-        argValues: [trackId, params],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerSetMixerChannelParamsConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_mixer_channel_params",
-        argNames: ["trackId", "params"],
-      );
-
-  @override
-  Future<void> crateApiTransportSetPlayhead({required int val}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(val, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 106,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPatternPlayPatternPreviewConstMeta,
+            argValues: [patternId, generatorId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternPlayPatternPreviewConstMeta => const TaskConstMeta(
+            debugName: "play_pattern_preview",
+            argNames: ["patternId", "generatorId"],
+        );
+        
+
+@override Future<void> crateApiAudioPlayPreviewNote({required int trackId , required int noteKey , required int velocity , required bool isOn })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_i_32(noteKey, serializer);
+sse_encode_i_32(velocity, serializer);
+sse_encode_bool(isOn, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTransportSetPlayheadConstMeta,
-        argValues: [val],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTransportSetPlayheadConstMeta =>
-      const TaskConstMeta(debugName: "set_playhead", argNames: ["val"]);
-
-  @override
-  Future<void> crateApiTransportSetPlaying({required bool val}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_bool(val, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 107,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAudioPlayPreviewNoteConstMeta,
+            argValues: [trackId, noteKey, velocity, isOn],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioPlayPreviewNoteConstMeta => const TaskConstMeta(
+            debugName: "play_preview_note",
+            argNames: ["trackId", "noteKey", "velocity", "isOn"],
+        );
+        
+
+@override Future<void> crateApiAudioPlayPreviewNoteGenerator({required int generatorId , required int noteKey , required int velocity , required bool isOn })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(generatorId, serializer);
+sse_encode_i_32(noteKey, serializer);
+sse_encode_i_32(velocity, serializer);
+sse_encode_bool(isOn, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTransportSetPlayingConstMeta,
-        argValues: [val],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTransportSetPlayingConstMeta =>
-      const TaskConstMeta(debugName: "set_playing", argNames: ["val"]);
-
-  @override
-  Future<void> crateApiMixerSetRouting({
-    required UiRoutingNode source,
-    required UiRoutingNode destination,
-    required double sendLevel,
-    required bool isSend,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_routing_node(source, serializer);
-          sse_encode_box_autoadd_ui_routing_node(destination, serializer);
-          sse_encode_f_32(sendLevel, serializer);
-          sse_encode_bool(isSend, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 108,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMixerSetRoutingConstMeta,
-        argValues: [source, destination, sendLevel, isSend],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerSetRoutingConstMeta => const TaskConstMeta(
-    debugName: "set_routing",
-    argNames: ["source", "destination", "sendLevel", "isSend"],
-  );
-
-  @override
-  Future<void> crateApiAudioStopAllPreviews() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 109,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAudioPlayPreviewNoteGeneratorConstMeta,
+            argValues: [generatorId, noteKey, velocity, isOn],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioPlayPreviewNoteGeneratorConstMeta => const TaskConstMeta(
+            debugName: "play_preview_note_generator",
+            argNames: ["generatorId", "noteKey", "velocity", "isOn"],
+        );
+        
+
+@override Future<void> crateApiAudioPlaySourcePreview({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAudioStopAllPreviewsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAudioStopAllPreviewsConstMeta =>
-      const TaskConstMeta(debugName: "stop_all_previews", argNames: []);
-
-  @override
-  Future<void> crateApiPatternStopPatternPreview() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 110,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAudioPlaySourcePreviewConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioPlaySourcePreviewConstMeta => const TaskConstMeta(
+            debugName: "play_source_preview",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<UiEffectParameterSnapshot>> crateApiPluginPollEffectParameterFeedback()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_effect_parameter_snapshot,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPluginPollEffectParameterFeedbackConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginPollEffectParameterFeedbackConstMeta => const TaskConstMeta(
+            debugName: "poll_effect_parameter_feedback",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiGeneratorParameterSnapshot>> crateApiPluginPollGeneratorParameterFeedback()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_generator_parameter_snapshot,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPluginPollGeneratorParameterFeedbackConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginPollGeneratorParameterFeedbackConstMeta => const TaskConstMeta(
+            debugName: "poll_generator_parameter_feedback",
+            argNames: [],
+        );
+        
+
+@override UiProjectMetadata crateApiProjectProjectMetadataNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_project_metadata,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProjectProjectMetadataNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectProjectMetadataNewConstMeta => const TaskConstMeta(
+            debugName: "project_metadata_new",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiNote>> crateApiPatternQuantizeNotes({required int patternId , required List<int> noteIds , required UiQuantizeSettings settings })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_list_prim_u_32_loose(noteIds, serializer);
+sse_encode_box_autoadd_ui_quantize_settings(settings, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternQuantizeNotesConstMeta,
+            argValues: [patternId, noteIds, settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternQuantizeNotesConstMeta => const TaskConstMeta(
+            debugName: "quantize_notes",
+            argNames: ["patternId", "noteIds", "settings"],
+        );
+        
+
+@override Future<void> crateApiPluginQueryEffectParameters({required UiEffectTarget target , required int effectId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_effect_target(target, serializer);
+sse_encode_u_32(effectId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPluginQueryEffectParametersConstMeta,
+            argValues: [target, effectId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginQueryEffectParametersConstMeta => const TaskConstMeta(
+            debugName: "query_effect_parameters",
+            argNames: ["target", "effectId"],
+        );
+        
+
+@override Future<void> crateApiPluginQueryGeneratorParameters({required int generatorId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(generatorId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiPatternStopPatternPreviewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPatternStopPatternPreviewConstMeta =>
-      const TaskConstMeta(debugName: "stop_pattern_preview", argNames: []);
-
-  @override
-  Future<void> crateApiTransportStopSongPlayback() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 111,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPluginQueryGeneratorParametersConstMeta,
+            argValues: [generatorId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginQueryGeneratorParametersConstMeta => const TaskConstMeta(
+            debugName: "query_generator_parameters",
+            argNames: ["generatorId"],
+        );
+        
+
+@override Future<void> crateApiSessionRedo()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiTransportStopSongPlaybackConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTransportStopSongPlaybackConstMeta =>
-      const TaskConstMeta(debugName: "stop_song_playback", argNames: []);
-
-  @override
-  Future<void> crateApiPluginSyncEffectParametersFromAudio({
-    required List<UiEffectParameterSnapshot> snapshots,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_ui_effect_parameter_snapshot(snapshots, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 112,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSessionRedoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionRedoConstMeta => const TaskConstMeta(
+            debugName: "redo",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiMixerRemoveEffectFromMasterBus({required int effectInstanceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(effectInstanceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPluginSyncEffectParametersFromAudioConstMeta,
-        argValues: [snapshots],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginSyncEffectParametersFromAudioConstMeta =>
-      const TaskConstMeta(
-        debugName: "sync_effect_parameters_from_audio",
-        argNames: ["snapshots"],
-      );
-
-  @override
-  Future<void> crateApiPluginSyncGeneratorParametersFromAudio({
-    required List<UiGeneratorParameterSnapshot> snapshots,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_ui_generator_parameter_snapshot(
-            snapshots,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 113,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerRemoveEffectFromMasterBusConstMeta,
+            argValues: [effectInstanceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerRemoveEffectFromMasterBusConstMeta => const TaskConstMeta(
+            debugName: "remove_effect_from_master_bus",
+            argNames: ["effectInstanceId"],
+        );
+        
+
+@override Future<void> crateApiMixerRemoveEffectFromMixerChannel({required int trackId , required int effectInstanceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(effectInstanceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerRemoveEffectFromMixerChannelConstMeta,
+            argValues: [trackId, effectInstanceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerRemoveEffectFromMixerChannelConstMeta => const TaskConstMeta(
+            debugName: "remove_effect_from_mixer_channel",
+            argNames: ["trackId", "effectInstanceId"],
+        );
+        
+
+@override Future<void> crateApiMixerRemoveRouting({required UiRoutingNode source , required UiRoutingNode destination , required bool isSend })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_routing_node(source, serializer);
+sse_encode_box_autoadd_ui_routing_node(destination, serializer);
+sse_encode_bool(isSend, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerRemoveRoutingConstMeta,
+            argValues: [source, destination, isSend],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerRemoveRoutingConstMeta => const TaskConstMeta(
+            debugName: "remove_routing",
+            argNames: ["source", "destination", "isSend"],
+        );
+        
+
+@override Future<UiTempoPoint> crateApiTransportRemoveTempoPoint({required int tick })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_CastedPrimitive_u_64(tick, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_tempo_point,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportRemoveTempoPointConstMeta,
+            argValues: [tick],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportRemoveTempoPointConstMeta => const TaskConstMeta(
+            debugName: "remove_tempo_point",
+            argNames: ["tick"],
+        );
+        
+
+@override Future<UiTimeSignatureEvent> crateApiTransportRemoveTimeSignature({required int bar })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(bar, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_time_signature_event,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportRemoveTimeSignatureConstMeta,
+            argValues: [bar],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportRemoveTimeSignatureConstMeta => const TaskConstMeta(
+            debugName: "remove_time_signature",
+            argNames: ["bar"],
+        );
+        
+
+@override Future<void> crateApiMixerRenameBus({required int busId , required String newName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(busId, serializer);
+sse_encode_String(newName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerRenameBusConstMeta,
+            argValues: [busId, newName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerRenameBusConstMeta => const TaskConstMeta(
+            debugName: "rename_bus",
+            argNames: ["busId", "newName"],
+        );
+        
+
+@override Future<void> crateApiAudioResetEngineLoad()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPluginSyncGeneratorParametersFromAudioConstMeta,
-        argValues: [snapshots],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPluginSyncGeneratorParametersFromAudioConstMeta =>
-      const TaskConstMeta(
-        debugName: "sync_generator_parameters_from_audio",
-        argNames: ["snapshots"],
-      );
-
-  @override
-  UiTransportState crateApiProjectTransportStateNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 114,
-          )!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_transport_state,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProjectTransportStateNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectTransportStateNewConstMeta =>
-      const TaskConstMeta(debugName: "transport_state_new", argNames: []);
-
-  @override
-  UiTransportState crateApiProjectTransportStateNewWithParam({
-    required double bpm,
-    required (int, int) timeSignature,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_f_32(bpm, serializer);
-          sse_encode_box_autoadd_record_u_8_u_8(timeSignature, serializer);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 115,
-          )!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_transport_state,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProjectTransportStateNewWithParamConstMeta,
-        argValues: [bpm, timeSignature],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectTransportStateNewWithParamConstMeta =>
-      const TaskConstMeta(
-        debugName: "transport_state_new_with_param",
-        argNames: ["bpm", "timeSignature"],
-      );
-
-  @override
-  Future<UiClipboardContent> crateApiSessionUiClipboardContentDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 116,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_clipboard_content,
+        )
+        ,
+            constMeta: kCrateApiAudioResetEngineLoadConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioResetEngineLoadConstMeta => const TaskConstMeta(
+            debugName: "reset_engine_load",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiAudioResetLoudnessAnalyzer()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSessionUiClipboardContentDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionUiClipboardContentDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: "ui_clipboard_content_default",
-        argNames: [],
-      );
-
-  @override
-  UiMixerState crateApiMixerUiMixerStateNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 117,
-          )!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_mixer_state,
+        )
+        ,
+            constMeta: kCrateApiAudioResetLoudnessAnalyzerConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioResetLoudnessAnalyzerConstMeta => const TaskConstMeta(
+            debugName: "reset_loudness_analyzer",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSessionResizeClip({required int trackId , required int clipId , required UiResizeEdge edge , required int newTimeVal })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(clipId, serializer);
+sse_encode_ui_resize_edge(edge, serializer);
+sse_encode_u_32(newTimeVal, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSessionResizeClipConstMeta,
+            argValues: [trackId, clipId, edge, newTimeVal],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionResizeClipConstMeta => const TaskConstMeta(
+            debugName: "resize_clip",
+            argNames: ["trackId", "clipId", "edge", "newTimeVal"],
+        );
+        
+
+@override Future<UiClip> crateApiTrackResizeClip({required int trackId , required int clipId , required UiResizeEdge edge , required int newTimeVal })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(clipId, serializer);
+sse_encode_ui_resize_edge(edge, serializer);
+sse_encode_u_32(newTimeVal, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_clip,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTrackResizeClipConstMeta,
+            argValues: [trackId, clipId, edge, newTimeVal],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackResizeClipConstMeta => const TaskConstMeta(
+            debugName: "resize_clip",
+            argNames: ["trackId", "clipId", "edge", "newTimeVal"],
+        );
+        
+
+@override Future<List<UiClip>> crateApiTrackResizeClipBatch({required int trackId , required List<int> clipIds , required UiResizeEdge edge , required int deltaSamples })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_list_prim_u_32_loose(clipIds, serializer);
+sse_encode_ui_resize_edge(edge, serializer);
+sse_encode_CastedPrimitive_i_64(deltaSamples, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_clip,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTrackResizeClipBatchConstMeta,
+            argValues: [trackId, clipIds, edge, deltaSamples],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackResizeClipBatchConstMeta => const TaskConstMeta(
+            debugName: "resize_clip_batch",
+            argNames: ["trackId", "clipIds", "edge", "deltaSamples"],
+        );
+        
+
+@override Future<UiNote> crateApiPatternResizeNote({required int patternId , required int noteId , required int newDuration })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_u_32(noteId, serializer);
+sse_encode_CastedPrimitive_u_64(newDuration, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternResizeNoteConstMeta,
+            argValues: [patternId, noteId, newDuration],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternResizeNoteConstMeta => const TaskConstMeta(
+            debugName: "resize_note",
+            argNames: ["patternId", "noteId", "newDuration"],
+        );
+        
+
+@override Future<int> crateApiTransportSamplesToTicks({required int sample })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_CastedPrimitive_u_64(sample, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_u_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerUiMixerStateNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerUiMixerStateNewConstMeta =>
-      const TaskConstMeta(debugName: "ui_mixer_state_new", argNames: []);
-
-  @override
-  UiMixerState crateApiMixerUiMixerStateNewWithParam({
-    required Map<int, UiMixerChannel> channels,
-    required UiMixerChannel masterBus,
-    required Map<int, UiBus> buses,
-    required List<UiRoutingConnection> routing,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Map_u_32_ui_mixer_channel_None(channels, serializer);
-          sse_encode_box_autoadd_ui_mixer_channel(masterBus, serializer);
-          sse_encode_Map_u_32_ui_bus_None(buses, serializer);
-          sse_encode_list_ui_routing_connection(routing, serializer);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 118,
-          )!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_mixer_state,
+        )
+        ,
+            constMeta: kCrateApiTransportSamplesToTicksConstMeta,
+            argValues: [sample],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSamplesToTicksConstMeta => const TaskConstMeta(
+            debugName: "samples_to_ticks",
+            argNames: ["sample"],
+        );
+        
+
+@override Future<void> crateApiSerializationSaveProject({required String pathName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pathName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSerializationSaveProjectConstMeta,
+            argValues: [pathName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSerializationSaveProjectConstMeta => const TaskConstMeta(
+            debugName: "save_project",
+            argNames: ["pathName"],
+        );
+        
+
+@override Future<void> crateApiAudioSetAudioDeviceSettings({required UiAudioDeviceSettings settings })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_audio_device_settings(settings, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAudioSetAudioDeviceSettingsConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioSetAudioDeviceSettingsConstMeta => const TaskConstMeta(
+            debugName: "set_audio_device_settings",
+            argNames: ["settings"],
+        );
+        
+
+@override Future<void> crateApiTransportSetBpm({required double val })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_32(val, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetBpmConstMeta,
+            argValues: [val],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetBpmConstMeta => const TaskConstMeta(
+            debugName: "set_bpm",
+            argNames: ["val"],
+        );
+        
+
+@override Future<void> crateApiMixerSetBusParams({required int busId , required List<UiMixerChannelParams> params })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(busId, serializer);
+sse_encode_list_ui_mixer_channel_params(params, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerSetBusParamsConstMeta,
+            argValues: [busId, params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerSetBusParamsConstMeta => const TaskConstMeta(
+            debugName: "set_bus_params",
+            argNames: ["busId", "params"],
+        );
+        
+
+@override Future<void> crateApiPatternSetClipGroove({required int trackId , required int clipId , UiGroove? groove })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_u_32(clipId, serializer);
+sse_encode_opt_box_autoadd_ui_groove(groove, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternSetClipGrooveConstMeta,
+            argValues: [trackId, clipId, groove],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternSetClipGrooveConstMeta => const TaskConstMeta(
+            debugName: "set_clip_groove",
+            argNames: ["trackId", "clipId", "groove"],
+        );
+        
+
+@override Future<void> crateApiTransportSetCountInBars({required int bars })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_8(bars, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetCountInBarsConstMeta,
+            argValues: [bars],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetCountInBarsConstMeta => const TaskConstMeta(
+            debugName: "set_count_in_bars",
+            argNames: ["bars"],
+        );
+        
+
+@override Future<void> crateApiPluginSetEffectParameter({required UiEffectTarget target , required int effectId , required int paramId , required double value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_effect_target(target, serializer);
+sse_encode_u_32(effectId, serializer);
+sse_encode_u_32(paramId, serializer);
+sse_encode_f_32(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPluginSetEffectParameterConstMeta,
+            argValues: [target, effectId, paramId, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginSetEffectParameterConstMeta => const TaskConstMeta(
+            debugName: "set_effect_parameter",
+            argNames: ["target", "effectId", "paramId", "value"],
+        );
+        
+
+@override Future<void> crateApiPluginSetGeneratorParameter({required int generatorId , required int paramId , required double value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(generatorId, serializer);
+sse_encode_u_32(paramId, serializer);
+sse_encode_f_32(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPluginSetGeneratorParameterConstMeta,
+            argValues: [generatorId, paramId, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginSetGeneratorParameterConstMeta => const TaskConstMeta(
+            debugName: "set_generator_parameter",
+            argNames: ["generatorId", "paramId", "value"],
+        );
+        
+
+@override Future<String> crateApiRecordingSetInputDevice({String? name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRecordingSetInputDeviceConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingSetInputDeviceConstMeta => const TaskConstMeta(
+            debugName: "set_input_device",
+            argNames: ["name"],
+        );
+        
+
+@override Future<void> crateApiTransportSetLoopRegion({required int start , required int end })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(start, serializer);
+sse_encode_u_32(end, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetLoopRegionConstMeta,
+            argValues: [start, end],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetLoopRegionConstMeta => const TaskConstMeta(
+            debugName: "set_loop_region",
+            argNames: ["start", "end"],
+        );
+        
+
+@override Future<void> crateApiTransportSetLooping({required bool val })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(val, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetLoopingConstMeta,
+            argValues: [val],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetLoopingConstMeta => const TaskConstMeta(
+            debugName: "set_looping",
+            argNames: ["val"],
+        );
+        
+
+@override Future<void> crateApiMixerSetMasterBusParams({required List<UiMixerChannelParams> params })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_ui_mixer_channel_params(params, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerSetMasterBusParamsConstMeta,
+            argValues: [params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerSetMasterBusParamsConstMeta => const TaskConstMeta(
+            debugName: "set_master_bus_params",
+            argNames: ["params"],
+        );
+        
+
+@override Future<void> crateApiTransportSetMetronomeEnabled({required bool val })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(val, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetMetronomeEnabledConstMeta,
+            argValues: [val],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetMetronomeEnabledConstMeta => const TaskConstMeta(
+            debugName: "set_metronome_enabled",
+            argNames: ["val"],
+        );
+        
+
+@override Future<void> crateApiTransportSetMetronomeTarget({int? busId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_u_32(busId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetMetronomeTargetConstMeta,
+            argValues: [busId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetMetronomeTargetConstMeta => const TaskConstMeta(
+            debugName: "set_metronome_target",
+            argNames: ["busId"],
+        );
+        
+
+@override Future<void> crateApiTransportSetMetronomeVolume({required double val })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_32(val, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetMetronomeVolumeConstMeta,
+            argValues: [val],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetMetronomeVolumeConstMeta => const TaskConstMeta(
+            debugName: "set_metronome_volume",
+            argNames: ["val"],
+        );
+        
+
+@override Future<void> crateApiMidiSetMidiInputTrack({int? trackId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_u_32(trackId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMixerUiMixerStateNewWithParamConstMeta,
-        argValues: [channels, masterBus, buses, routing],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMixerUiMixerStateNewWithParamConstMeta =>
-      const TaskConstMeta(
-        debugName: "ui_mixer_state_new_with_param",
-        argNames: ["channels", "masterBus", "buses", "routing"],
-      );
-
-  @override
-  Future<UiProjectMetadata> crateApiProjectUiProjectMetadataDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 119,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_project_metadata,
+        )
+        ,
+            constMeta: kCrateApiMidiSetMidiInputTrackConstMeta,
+            argValues: [trackId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiSetMidiInputTrackConstMeta => const TaskConstMeta(
+            debugName: "set_midi_input_track",
+            argNames: ["trackId"],
+        );
+        
+
+@override Future<void> crateApiMidiSetMidiOutputSettings({required UiMidiOutputSettings settings })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_midi_output_settings(settings, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMidiSetMidiOutputSettingsConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMidiSetMidiOutputSettingsConstMeta => const TaskConstMeta(
+            debugName: "set_midi_output_settings",
+            argNames: ["settings"],
+        );
+        
+
+@override Future<void> crateApiRecordingSetMidiRecordSettings({required UiMidiRecordMode mode , int? quantizeTicks })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_ui_midi_record_mode(mode, serializer);
+sse_encode_opt_box_autoadd_u_32(quantizeTicks, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProjectUiProjectMetadataDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectUiProjectMetadataDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: "ui_project_metadata_default",
-        argNames: [],
-      );
-
-  @override
-  Future<UiTransportState> crateApiProjectUiTransportStateDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 120,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_transport_state,
+        )
+        ,
+            constMeta: kCrateApiRecordingSetMidiRecordSettingsConstMeta,
+            argValues: [mode, quantizeTicks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingSetMidiRecordSettingsConstMeta => const TaskConstMeta(
+            debugName: "set_midi_record_settings",
+            argNames: ["mode", "quantizeTicks"],
+        );
+        
+
+@override Future<void> crateApiMixerSetMixerChannelParams({required int trackId , required List<UiMixerChannelParams> params })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_list_ui_mixer_channel_params(params, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerSetMixerChannelParamsConstMeta,
+            argValues: [trackId, params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerSetMixerChannelParamsConstMeta => const TaskConstMeta(
+            debugName: "set_mixer_channel_params",
+            argNames: ["trackId", "params"],
+        );
+        
+
+@override Future<void> crateApiPatternSetPatternGroove({required int patternId , UiGroove? groove })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_opt_box_autoadd_ui_groove(groove, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternSetPatternGrooveConstMeta,
+            argValues: [patternId, groove],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternSetPatternGrooveConstMeta => const TaskConstMeta(
+            debugName: "set_pattern_groove",
+            argNames: ["patternId", "groove"],
+        );
+        
+
+@override Future<void> crateApiPatternSetPatternScale({required int patternId , required int root , UiScaleMode? mode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_u_8(root, serializer);
+sse_encode_opt_box_autoadd_ui_scale_mode(mode, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternSetPatternScaleConstMeta,
+            argValues: [patternId, root, mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternSetPatternScaleConstMeta => const TaskConstMeta(
+            debugName: "set_pattern_scale",
+            argNames: ["patternId", "root", "mode"],
+        );
+        
+
+@override Future<void> crateApiTransportSetPlayhead({required int val })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(val, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetPlayheadConstMeta,
+            argValues: [val],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetPlayheadConstMeta => const TaskConstMeta(
+            debugName: "set_playhead",
+            argNames: ["val"],
+        );
+        
+
+@override Future<void> crateApiTransportSetPlaying({required bool val })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(val, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetPlayingConstMeta,
+            argValues: [val],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetPlayingConstMeta => const TaskConstMeta(
+            debugName: "set_playing",
+            argNames: ["val"],
+        );
+        
+
+@override Future<void> crateApiAudioSetPluginProfiling({required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProjectUiTransportStateDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProjectUiTransportStateDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: "ui_transport_state_default",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiSessionUndo() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 121,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAudioSetPluginProfilingConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioSetPluginProfilingConstMeta => const TaskConstMeta(
+            debugName: "set_plugin_profiling",
+            argNames: ["enabled"],
+        );
+        
+
+@override Future<void> crateApiTransportSetRandomSeed({required int seed })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_CastedPrimitive_u_64(seed, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetRandomSeedConstMeta,
+            argValues: [seed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetRandomSeedConstMeta => const TaskConstMeta(
+            debugName: "set_random_seed",
+            argNames: ["seed"],
+        );
+        
+
+@override Future<void> crateApiMixerSetRouting({required UiRoutingNode source , required UiRoutingNode destination , required double sendLevel , required bool isSend })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ui_routing_node(source, serializer);
+sse_encode_box_autoadd_ui_routing_node(destination, serializer);
+sse_encode_f_32(sendLevel, serializer);
+sse_encode_bool(isSend, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMixerSetRoutingConstMeta,
+            argValues: [source, destination, sendLevel, isSend],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerSetRoutingConstMeta => const TaskConstMeta(
+            debugName: "set_routing",
+            argNames: ["source", "destination", "sendLevel", "isSend"],
+        );
+        
+
+@override Future<void> crateApiTransportSetTempoPoint({required int tick , required double bpm , required bool linear })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_CastedPrimitive_u_64(tick, serializer);
+sse_encode_f_32(bpm, serializer);
+sse_encode_bool(linear, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSessionUndoConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSessionUndoConstMeta =>
-      const TaskConstMeta(debugName: "undo", argNames: []);
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  int dco_decode_CastedPrimitive_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError(
-      'Not implemented in this codec, please use the other one',
-    );
-  }
-
-  @protected
-  int dco_decode_CastedPrimitive_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError(
-      'Not implemented in this codec, please use the other one',
-    );
-  }
-
-  @protected
-  int dco_decode_CastedPrimitive_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError(
-      'Not implemented in this codec, please use the other one',
-    );
-  }
-
-  @protected
-  Map<int, AudioWaveformUiForClip>
-  dco_decode_Map_u_32_audio_waveform_ui_for_clip_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_u_32_audio_waveform_ui_for_clip(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  Map<int, AudioWaveformUiForSourceList>
-  dco_decode_Map_u_32_audio_waveform_ui_for_source_list_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_u_32_audio_waveform_ui_for_source_list(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  Map<int, double> dco_decode_Map_u_32_f_32_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_u_32_f_32(raw).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  Map<int, UiBus> dco_decode_Map_u_32_ui_bus_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_u_32_ui_bus(raw).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  Map<int, UiGeneratorInstance> dco_decode_Map_u_32_ui_generator_instance_None(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_u_32_ui_generator_instance(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  Map<int, UiMixerChannel> dco_decode_Map_u_32_ui_mixer_channel_None(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_u_32_ui_mixer_channel(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  Map<int, UiPattern> dco_decode_Map_u_32_ui_pattern_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_u_32_ui_pattern(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  Map<int, UiTrack> dco_decode_Map_u_32_ui_track_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_u_32_ui_track(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  RustStreamSink<UiMixerParamEvent>
-  dco_decode_StreamSink_ui_mixer_param_event_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  RustStreamSink<UiTransportFeedback>
-  dco_decode_StreamSink_ui_transport_feedback_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  AudioWaveformUiForAudioProperties
-  dco_decode_audio_waveform_ui_for_audio_properties(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 13)
-      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
-    return AudioWaveformUiForAudioProperties(
-      previewBuffer: dco_decode_list_prim_i_8_strict(arr[0]),
-      filePath: dco_decode_String(arr[1]),
-      name: dco_decode_String(arr[2]),
-      sampleRate: dco_decode_u_32(arr[3]),
-      channels: dco_decode_u_16(arr[4]),
-      duration: dco_decode_f_64(arr[5]),
-      rootNote: dco_decode_u_8(arr[6]),
-      fineTune: dco_decode_i_16(arr[7]),
-      trimStart: dco_decode_u_32(arr[8]),
-      trimEnd: dco_decode_u_32(arr[9]),
-      isLooping: dco_decode_bool(arr[10]),
-      normalized: dco_decode_bool(arr[11]),
-      muted: dco_decode_bool(arr[12]),
-    );
-  }
-
-  @protected
-  AudioWaveformUiForClip dco_decode_audio_waveform_ui_for_clip(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return AudioWaveformUiForClip(
-      name: dco_decode_String(arr[0]),
-      previewBuffer: dco_decode_list_prim_i_8_strict(arr[1]),
-      sampleRate: dco_decode_u_32(arr[2]),
-      channels: dco_decode_u_16(arr[3]),
-      duration: dco_decode_f_64(arr[4]),
-    );
-  }
-
-  @protected
-  AudioWaveformUiForSourceList dco_decode_audio_waveform_ui_for_source_list(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return AudioWaveformUiForSourceList(
-      name: dco_decode_String(arr[0]),
-      muted: dco_decode_bool(arr[1]),
-      sampleRate: dco_decode_u_32(arr[2]),
-    );
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  AudioWaveformUiForAudioProperties
-  dco_decode_box_autoadd_audio_waveform_ui_for_audio_properties(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_audio_waveform_ui_for_audio_properties(raw);
-  }
-
-  @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  double dco_decode_box_autoadd_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  (int, int) dco_decode_box_autoadd_record_u_8_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as (int, int);
-  }
-
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  UiEffectTarget dco_decode_box_autoadd_ui_effect_target(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_ui_effect_target(raw);
-  }
-
-  @protected
-  UiMixerChannel dco_decode_box_autoadd_ui_mixer_channel(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_ui_mixer_channel(raw);
-  }
-
-  @protected
-  UiPluginInstance dco_decode_box_autoadd_ui_plugin_instance(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_ui_plugin_instance(raw);
-  }
-
-  @protected
-  UiRoutingNode dco_decode_box_autoadd_ui_routing_node(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_ui_routing_node(raw);
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  int dco_decode_i_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  KarbeatPluginType dco_decode_karbeat_plugin_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return KarbeatPluginType.values[raw as int];
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<ParameterSpecDTO> dco_decode_list_parameter_spec_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_parameter_spec_dto).toList();
-  }
-
-  @protected
-  Int8List dco_decode_list_prim_i_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Int8List;
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_32_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint32List;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<(int, AudioWaveformUiForClip)>
-  dco_decode_list_record_u_32_audio_waveform_ui_for_clip(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_u_32_audio_waveform_ui_for_clip)
-        .toList();
-  }
-
-  @protected
-  List<(int, AudioWaveformUiForSourceList)>
-  dco_decode_list_record_u_32_audio_waveform_ui_for_source_list(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_u_32_audio_waveform_ui_for_source_list)
-        .toList();
-  }
-
-  @protected
-  List<(int, double)> dco_decode_list_record_u_32_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_u_32_f_32).toList();
-  }
-
-  @protected
-  List<(int, UiBus)> dco_decode_list_record_u_32_ui_bus(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_u_32_ui_bus).toList();
-  }
-
-  @protected
-  List<(int, UiGeneratorInstance)>
-  dco_decode_list_record_u_32_ui_generator_instance(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_u_32_ui_generator_instance)
-        .toList();
-  }
-
-  @protected
-  List<(int, UiMixerChannel)> dco_decode_list_record_u_32_ui_mixer_channel(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_u_32_ui_mixer_channel)
-        .toList();
-  }
-
-  @protected
-  List<(int, UiPattern)> dco_decode_list_record_u_32_ui_pattern(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_u_32_ui_pattern)
-        .toList();
-  }
-
-  @protected
-  List<(int, UiTrack)> dco_decode_list_record_u_32_ui_track(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_u_32_ui_track).toList();
-  }
-
-  @protected
-  List<UiClip> dco_decode_list_ui_clip(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ui_clip).toList();
-  }
-
-  @protected
-  List<UiEffectInstance> dco_decode_list_ui_effect_instance(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ui_effect_instance).toList();
-  }
-
-  @protected
-  List<UiEffectParameterSnapshot> dco_decode_list_ui_effect_parameter_snapshot(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_ui_effect_parameter_snapshot)
-        .toList();
-  }
-
-  @protected
-  List<UiEffectSummary> dco_decode_list_ui_effect_summary(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ui_effect_summary).toList();
-  }
-
-  @protected
-  List<UiGeneratorParameterSnapshot>
-  dco_decode_list_ui_generator_parameter_snapshot(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_ui_generator_parameter_snapshot)
-        .toList();
-  }
-
-  @protected
-  List<UiMixerChannelParams> dco_decode_list_ui_mixer_channel_params(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_ui_mixer_channel_params)
-        .toList();
-  }
-
-  @protected
-  List<UiNote> dco_decode_list_ui_note(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ui_note).toList();
-  }
-
-  @protected
-  List<UiParameterValue> dco_decode_list_ui_parameter_value(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ui_parameter_value).toList();
-  }
-
-  @protected
-  List<UiPluginInfo> dco_decode_list_ui_plugin_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ui_plugin_info).toList();
-  }
-
-  @protected
-  List<UiPluginParameter> dco_decode_list_ui_plugin_parameter(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ui_plugin_parameter).toList();
-  }
-
-  @protected
-  List<UiResponseCurvePoint> dco_decode_list_ui_response_curve_point(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_ui_response_curve_point)
-        .toList();
-  }
-
-  @protected
-  List<UiRoutingConnection> dco_decode_list_ui_routing_connection(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_ui_routing_connection)
-        .toList();
-  }
-
-  @protected
-  int? dco_decode_opt_CastedPrimitive_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_CastedPrimitive_i_64(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_CastedPrimitive_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_CastedPrimitive_u_64(raw);
-  }
-
-  @protected
-  Map<int, AudioWaveformUiForSourceList>?
-  dco_decode_opt_Map_u_32_audio_waveform_ui_for_source_list_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_Map_u_32_audio_waveform_ui_for_source_list_None(raw);
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  AudioWaveformUiForAudioProperties?
-  dco_decode_opt_box_autoadd_audio_waveform_ui_for_audio_properties(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_audio_waveform_ui_for_audio_properties(raw);
-  }
-
-  @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
-  }
-
-  @protected
-  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
-  }
-
-  @protected
-  List<ParameterSpecDTO>? dco_decode_opt_list_parameter_spec_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_parameter_spec_dto(raw);
-  }
-
-  @protected
-  ParameterSpecDTO dco_decode_parameter_spec_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return ParameterSpecDTO(
-      id: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      group: dco_decode_String(arr[2]),
-      value: dco_decode_f_32(arr[3]),
-      min: dco_decode_f_32(arr[4]),
-      max: dco_decode_f_32(arr[5]),
-      defaultValue: dco_decode_f_32(arr[6]),
-      step: dco_decode_f_32(arr[7]),
-      valueType: dco_decode_parameter_value_type_dto(arr[8]),
-      choices: dco_decode_list_String(arr[9]),
-    );
-  }
-
-  @protected
-  ParameterValueTypeDTO dco_decode_parameter_value_type_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ParameterValueTypeDTO.values[raw as int];
-  }
-
-  @protected
-  (int, AudioWaveformUiForClip)
-  dco_decode_record_u_32_audio_waveform_ui_for_clip(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_u_32(arr[0]),
-      dco_decode_audio_waveform_ui_for_clip(arr[1]),
-    );
-  }
-
-  @protected
-  (int, AudioWaveformUiForSourceList)
-  dco_decode_record_u_32_audio_waveform_ui_for_source_list(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_u_32(arr[0]),
-      dco_decode_audio_waveform_ui_for_source_list(arr[1]),
-    );
-  }
-
-  @protected
-  (int, double) dco_decode_record_u_32_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_u_32(arr[0]), dco_decode_f_32(arr[1]));
-  }
-
-  @protected
-  (int, UiBus) dco_decode_record_u_32_ui_bus(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_u_32(arr[0]), dco_decode_ui_bus(arr[1]));
-  }
-
-  @protected
-  (int, UiGeneratorInstance) dco_decode_record_u_32_ui_generator_instance(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_u_32(arr[0]), dco_decode_ui_generator_instance(arr[1]));
-  }
-
-  @protected
-  (int, UiMixerChannel) dco_decode_record_u_32_ui_mixer_channel(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_u_32(arr[0]), dco_decode_ui_mixer_channel(arr[1]));
-  }
-
-  @protected
-  (int, UiPattern) dco_decode_record_u_32_ui_pattern(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_u_32(arr[0]), dco_decode_ui_pattern(arr[1]));
-  }
-
-  @protected
-  (int, UiTrack) dco_decode_record_u_32_ui_track(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_u_32(arr[0]), dco_decode_ui_track(arr[1]));
-  }
-
-  @protected
-  (int, int) dco_decode_record_u_8_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_u_8(arr[0]), dco_decode_u_8(arr[1]));
-  }
-
-  @protected
-  (UiMixerChannel, List<UiEffectInstance>)
-  dco_decode_record_ui_mixer_channel_list_ui_effect_instance(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_ui_mixer_channel(arr[0]),
-      dco_decode_list_ui_effect_instance(arr[1]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  UiApplicationState dco_decode_ui_application_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return UiApplicationState(
-      metadata: dco_decode_ui_project_metadata(arr[0]),
-      transport: dco_decode_ui_transport_state(arr[1]),
-      hardwareConfig: dco_decode_ui_audio_hardware_config(arr[2]),
-      tracks: dco_decode_Map_u_32_ui_track_None(arr[3]),
-      generators: dco_decode_Map_u_32_ui_generator_instance_None(arr[4]),
-      patterns: dco_decode_Map_u_32_ui_pattern_None(arr[5]),
-      mixer: dco_decode_ui_mixer_state(arr[6]),
-      maxSampleIndex: dco_decode_u_32(arr[7]),
-      audioSources: dco_decode_Map_u_32_audio_waveform_ui_for_source_list_None(
-        arr[8],
-      ),
-    );
-  }
-
-  @protected
-  UiAudioHardwareConfig dco_decode_ui_audio_hardware_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return UiAudioHardwareConfig(
-      selectedInputDevice: dco_decode_String(arr[0]),
-      selectedOutputDevice: dco_decode_String(arr[1]),
-      sampleRate: dco_decode_u_32(arr[2]),
-      bufferSize: dco_decode_u_32(arr[3]),
-      cpuLoad: dco_decode_f_32(arr[4]),
-    );
-  }
-
-  @protected
-  UiBus dco_decode_ui_bus(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return UiBus(
-      id: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      channel: dco_decode_ui_mixer_channel(arr[2]),
-    );
-  }
-
-  @protected
-  UiClip dco_decode_ui_clip(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return UiClip(
-      name: dco_decode_String(arr[0]),
-      id: dco_decode_u_32(arr[1]),
-      startTime: dco_decode_u_32(arr[2]),
-      source: dco_decode_ui_clip_source(arr[3]),
-      offsetStart: dco_decode_u_32(arr[4]),
-      loopLength: dco_decode_u_32(arr[5]),
-    );
-  }
-
-  @protected
-  UiClipSource dco_decode_ui_clip_source(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return UiClipSource_Audio(sourceId: dco_decode_u_32(raw[1]));
-      case 1:
-        return UiClipSource_Midi(patternId: dco_decode_u_32(raw[1]));
-      case 2:
-        return UiClipSource_None();
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  UiClipboardContent dco_decode_ui_clipboard_content(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return UiClipboardContent_Empty();
-      case 1:
-        return UiClipboardContent_Notes(dco_decode_list_ui_note(raw[1]));
-      case 2:
-        return UiClipboardContent_Clips(dco_decode_list_ui_clip(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  UiEffectInstance dco_decode_ui_effect_instance(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return UiEffectInstance(
-      id: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      parameters: dco_decode_Map_u_32_f_32_None(arr[2]),
-    );
-  }
-
-  @protected
-  UiEffectParameterSnapshot dco_decode_ui_effect_parameter_snapshot(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return UiEffectParameterSnapshot(
-      target: dco_decode_ui_effect_target(arr[0]),
-      effectId: dco_decode_u_32(arr[1]),
-      parameters: dco_decode_list_ui_parameter_value(arr[2]),
-    );
-  }
-
-  @protected
-  UiEffectSummary dco_decode_ui_effect_summary(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return UiEffectSummary(
-      id: dco_decode_u_32(arr[0]),
-      registryId: dco_decode_u_32(arr[1]),
-      name: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  UiEffectTarget dco_decode_ui_effect_target(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return UiEffectTarget_Track(dco_decode_u_32(raw[1]));
-      case 1:
-        return UiEffectTarget_Master();
-      case 2:
-        return UiEffectTarget_Bus(dco_decode_u_32(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  UiGeneratorInstance dco_decode_ui_generator_instance(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return UiGeneratorInstance(
-      id: dco_decode_u_32(arr[0]),
-      instanceType: dco_decode_ui_generator_instance_type(arr[1]),
-    );
-  }
-
-  @protected
-  UiGeneratorInstanceType dco_decode_ui_generator_instance_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return UiGeneratorInstanceType_Plugin(
-          dco_decode_box_autoadd_ui_plugin_instance(raw[1]),
-        );
-      case 1:
-        return UiGeneratorInstanceType_Sampler(
-          assetId: dco_decode_u_32(raw[1]),
-          rootNote: dco_decode_u_8(raw[2]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  UiGeneratorParameterSnapshot dco_decode_ui_generator_parameter_snapshot(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return UiGeneratorParameterSnapshot(
-      generatorId: dco_decode_u_32(arr[0]),
-      parameters: dco_decode_list_ui_parameter_value(arr[1]),
-    );
-  }
-
-  @protected
-  UiMixerChannel dco_decode_ui_mixer_channel(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return UiMixerChannel(
-      volume: dco_decode_f_32(arr[0]),
-      pan: dco_decode_f_32(arr[1]),
-      mute: dco_decode_bool(arr[2]),
-      solo: dco_decode_bool(arr[3]),
-      invertedPhase: dco_decode_bool(arr[4]),
-      effects: dco_decode_list_ui_effect_summary(arr[5]),
-    );
-  }
-
-  @protected
-  UiMixerChannelParams dco_decode_ui_mixer_channel_params(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return UiMixerChannelParams_Volume(dco_decode_f_32(raw[1]));
-      case 1:
-        return UiMixerChannelParams_Pan(dco_decode_f_32(raw[1]));
-      case 2:
-        return UiMixerChannelParams_Mute(dco_decode_bool(raw[1]));
-      case 3:
-        return UiMixerChannelParams_InvertedPhase(dco_decode_bool(raw[1]));
-      case 4:
-        return UiMixerChannelParams_Solo(dco_decode_bool(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  UiMixerParamEvent dco_decode_ui_mixer_param_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return UiMixerParamEvent(
-      trackId: dco_decode_u_32(arr[0]),
-      volume: dco_decode_opt_box_autoadd_f_32(arr[1]),
-      pan: dco_decode_opt_box_autoadd_f_32(arr[2]),
-      mute: dco_decode_opt_box_autoadd_bool(arr[3]),
-      solo: dco_decode_opt_box_autoadd_bool(arr[4]),
-    );
-  }
-
-  @protected
-  UiMixerState dco_decode_ui_mixer_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return UiMixerState.raw(
-      channels: dco_decode_Map_u_32_ui_mixer_channel_None(arr[0]),
-      masterBus: dco_decode_ui_mixer_channel(arr[1]),
-      buses: dco_decode_Map_u_32_ui_bus_None(arr[2]),
-      routing: dco_decode_list_ui_routing_connection(arr[3]),
-    );
-  }
-
-  @protected
-  UiNote dco_decode_ui_note(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return UiNote(
-      id: dco_decode_u_32(arr[0]),
-      startTick: dco_decode_CastedPrimitive_u_64(arr[1]),
-      duration: dco_decode_CastedPrimitive_u_64(arr[2]),
-      key: dco_decode_u_8(arr[3]),
-      velocity: dco_decode_u_8(arr[4]),
-      probability: dco_decode_f_32(arr[5]),
-      microOffset: dco_decode_i_8(arr[6]),
-      mute: dco_decode_bool(arr[7]),
-    );
-  }
-
-  @protected
-  UiParameterType dco_decode_ui_parameter_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return UiParameterType.values[raw as int];
-  }
-
-  @protected
-  UiParameterValue dco_decode_ui_parameter_value(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return UiParameterValue(
-      paramId: dco_decode_u_32(arr[0]),
-      value: dco_decode_f_32(arr[1]),
-    );
-  }
-
-  @protected
-  UiPattern dco_decode_ui_pattern(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return UiPattern(
-      id: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      lengthTicks: dco_decode_CastedPrimitive_u_64(arr[2]),
-      notes: dco_decode_list_ui_note(arr[3]),
-    );
-  }
-
-  @protected
-  UiPluginInfo dco_decode_ui_plugin_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return UiPluginInfo(
-      id: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      pluginType: dco_decode_karbeat_plugin_type(arr[2]),
-    );
-  }
-
-  @protected
-  UiPluginInstance dco_decode_ui_plugin_instance(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return UiPluginInstance(
-      registryId: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      bypass: dco_decode_bool(arr[2]),
-      parameters: dco_decode_Map_u_32_f_32_None(arr[3]),
-    );
-  }
-
-  @protected
-  UiPluginParameter dco_decode_ui_plugin_parameter(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return UiPluginParameter(
-      id: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      group: dco_decode_String(arr[2]),
-      value: dco_decode_f_32(arr[3]),
-      min: dco_decode_f_32(arr[4]),
-      max: dco_decode_f_32(arr[5]),
-      defaultValue: dco_decode_f_32(arr[6]),
-      step: dco_decode_f_32(arr[7]),
-      paramType: dco_decode_ui_parameter_type(arr[8]),
-      choices: dco_decode_list_String(arr[9]),
-    );
-  }
-
-  @protected
-  UiProjectMetadata dco_decode_ui_project_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return UiProjectMetadata(
-      name: dco_decode_String(arr[0]),
-      author: dco_decode_String(arr[1]),
-      version: dco_decode_String(arr[2]),
-      createdAt: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  UiResizeEdge dco_decode_ui_resize_edge(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return UiResizeEdge.values[raw as int];
-  }
-
-  @protected
-  UiResponseCurvePoint dco_decode_ui_response_curve_point(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return UiResponseCurvePoint(
-      frequency: dco_decode_f_32(arr[0]),
-      magnitudeDb: dco_decode_f_32(arr[1]),
-    );
-  }
-
-  @protected
-  UiRoutingConnection dco_decode_ui_routing_connection(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return UiRoutingConnection(
-      source: dco_decode_ui_routing_node(arr[0]),
-      destination: dco_decode_ui_routing_node(arr[1]),
-      sendLevel: dco_decode_f_32(arr[2]),
-      isSend: dco_decode_bool(arr[3]),
-    );
-  }
-
-  @protected
-  UiRoutingNode dco_decode_ui_routing_node(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return UiRoutingNode_Track(dco_decode_u_32(raw[1]));
-      case 1:
-        return UiRoutingNode_Bus(dco_decode_u_32(raw[1]));
-      case 2:
-        return UiRoutingNode_Master();
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  UiSourceType dco_decode_ui_source_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return UiSourceType.values[raw as int];
-  }
-
-  @protected
-  UiTrack dco_decode_ui_track(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return UiTrack(
-      id: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      color: dco_decode_String(arr[2]),
-      trackType: dco_decode_ui_track_type(arr[3]),
-      clips: dco_decode_list_ui_clip(arr[4]),
-      generatorId: dco_decode_opt_box_autoadd_u_32(arr[5]),
-    );
-  }
-
-  @protected
-  UiTrackType dco_decode_ui_track_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return UiTrackType.values[raw as int];
-  }
-
-  @protected
-  UiTransportFeedback dco_decode_ui_transport_feedback(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 13)
-      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
-    return UiTransportFeedback(
-      samples: dco_decode_u_32(arr[0]),
-      beat: dco_decode_CastedPrimitive_usize(arr[1]),
-      bar: dco_decode_CastedPrimitive_usize(arr[2]),
-      tempo: dco_decode_f_32(arr[3]),
-      sampleRate: dco_decode_u_32(arr[4]),
-      isPlaying: dco_decode_bool(arr[5]),
-      isLooping: dco_decode_bool(arr[6]),
-      isRecording: dco_decode_bool(arr[7]),
-      isPatternPlaying: dco_decode_bool(arr[8]),
-      isPatternMode: dco_decode_bool(arr[9]),
-      patternSamples: dco_decode_u_32(arr[10]),
-      patternBeat: dco_decode_CastedPrimitive_usize(arr[11]),
-      patternBar: dco_decode_CastedPrimitive_usize(arr[12]),
-    );
-  }
-
-  @protected
-  UiTransportState dco_decode_ui_transport_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return UiTransportState(
-      bpm: dco_decode_f_32(arr[0]),
-      timeSignature: dco_decode_record_u_8_u_8(arr[1]),
-    );
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_64(deserializer);
-    return inner.toInt();
-  }
-
-  @protected
-  int sse_decode_CastedPrimitive_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_u_64(deserializer);
-    return inner.toInt();
-  }
-
-  @protected
-  int sse_decode_CastedPrimitive_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_usize(deserializer);
-    return inner.toInt();
-  }
-
-  @protected
-  Map<int, AudioWaveformUiForClip>
-  sse_decode_Map_u_32_audio_waveform_ui_for_clip_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_u_32_audio_waveform_ui_for_clip(
-      deserializer,
-    );
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<int, AudioWaveformUiForSourceList>
-  sse_decode_Map_u_32_audio_waveform_ui_for_source_list_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_u_32_audio_waveform_ui_for_source_list(
-      deserializer,
-    );
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<int, double> sse_decode_Map_u_32_f_32_None(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_u_32_f_32(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<int, UiBus> sse_decode_Map_u_32_ui_bus_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_u_32_ui_bus(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<int, UiGeneratorInstance> sse_decode_Map_u_32_ui_generator_instance_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_u_32_ui_generator_instance(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<int, UiMixerChannel> sse_decode_Map_u_32_ui_mixer_channel_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_u_32_ui_mixer_channel(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<int, UiPattern> sse_decode_Map_u_32_ui_pattern_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_u_32_ui_pattern(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<int, UiTrack> sse_decode_Map_u_32_ui_track_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_u_32_ui_track(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  RustStreamSink<UiMixerParamEvent>
-  sse_decode_StreamSink_ui_mixer_param_event_Sse(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  RustStreamSink<UiTransportFeedback>
-  sse_decode_StreamSink_ui_transport_feedback_Sse(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  AudioWaveformUiForAudioProperties
-  sse_decode_audio_waveform_ui_for_audio_properties(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_previewBuffer = sse_decode_list_prim_i_8_strict(deserializer);
-    var var_filePath = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_sampleRate = sse_decode_u_32(deserializer);
-    var var_channels = sse_decode_u_16(deserializer);
-    var var_duration = sse_decode_f_64(deserializer);
-    var var_rootNote = sse_decode_u_8(deserializer);
-    var var_fineTune = sse_decode_i_16(deserializer);
-    var var_trimStart = sse_decode_u_32(deserializer);
-    var var_trimEnd = sse_decode_u_32(deserializer);
-    var var_isLooping = sse_decode_bool(deserializer);
-    var var_normalized = sse_decode_bool(deserializer);
-    var var_muted = sse_decode_bool(deserializer);
-    return AudioWaveformUiForAudioProperties(
-      previewBuffer: var_previewBuffer,
-      filePath: var_filePath,
-      name: var_name,
-      sampleRate: var_sampleRate,
-      channels: var_channels,
-      duration: var_duration,
-      rootNote: var_rootNote,
-      fineTune: var_fineTune,
-      trimStart: var_trimStart,
-      trimEnd: var_trimEnd,
-      isLooping: var_isLooping,
-      normalized: var_normalized,
-      muted: var_muted,
-    );
-  }
-
-  @protected
-  AudioWaveformUiForClip sse_decode_audio_waveform_ui_for_clip(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_previewBuffer = sse_decode_list_prim_i_8_strict(deserializer);
-    var var_sampleRate = sse_decode_u_32(deserializer);
-    var var_channels = sse_decode_u_16(deserializer);
-    var var_duration = sse_decode_f_64(deserializer);
-    return AudioWaveformUiForClip(
-      name: var_name,
-      previewBuffer: var_previewBuffer,
-      sampleRate: var_sampleRate,
-      channels: var_channels,
-      duration: var_duration,
-    );
-  }
-
-  @protected
-  AudioWaveformUiForSourceList sse_decode_audio_waveform_ui_for_source_list(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_muted = sse_decode_bool(deserializer);
-    var var_sampleRate = sse_decode_u_32(deserializer);
-    return AudioWaveformUiForSourceList(
-      name: var_name,
-      muted: var_muted,
-      sampleRate: var_sampleRate,
-    );
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  AudioWaveformUiForAudioProperties
-  sse_decode_box_autoadd_audio_waveform_ui_for_audio_properties(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_audio_waveform_ui_for_audio_properties(deserializer));
-  }
-
-  @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_bool(deserializer));
-  }
-
-  @protected
-  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_32(deserializer));
-  }
-
-  @protected
-  (int, int) sse_decode_box_autoadd_record_u_8_u_8(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_record_u_8_u_8(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
-  }
-
-  @protected
-  UiEffectTarget sse_decode_box_autoadd_ui_effect_target(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_ui_effect_target(deserializer));
-  }
-
-  @protected
-  UiMixerChannel sse_decode_box_autoadd_ui_mixer_channel(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_ui_mixer_channel(deserializer));
-  }
-
-  @protected
-  UiPluginInstance sse_decode_box_autoadd_ui_plugin_instance(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_ui_plugin_instance(deserializer));
-  }
-
-  @protected
-  UiRoutingNode sse_decode_box_autoadd_ui_routing_node(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_ui_routing_node(deserializer));
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  int sse_decode_i_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt16();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  int sse_decode_i_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt8();
-  }
-
-  @protected
-  KarbeatPluginType sse_decode_karbeat_plugin_type(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return KarbeatPluginType.values[inner];
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ParameterSpecDTO> sse_decode_list_parameter_spec_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ParameterSpecDTO>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_parameter_spec_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Int8List sse_decode_list_prim_i_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getInt8List(len_);
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint32List(len_);
-  }
-
-  @protected
-  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint32List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(int, AudioWaveformUiForClip)>
-  sse_decode_list_record_u_32_audio_waveform_ui_for_clip(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, AudioWaveformUiForClip)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_32_audio_waveform_ui_for_clip(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(int, AudioWaveformUiForSourceList)>
-  sse_decode_list_record_u_32_audio_waveform_ui_for_source_list(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, AudioWaveformUiForSourceList)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-        sse_decode_record_u_32_audio_waveform_ui_for_source_list(deserializer),
-      );
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(int, double)> sse_decode_list_record_u_32_f_32(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, double)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_32_f_32(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(int, UiBus)> sse_decode_list_record_u_32_ui_bus(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, UiBus)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_32_ui_bus(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(int, UiGeneratorInstance)>
-  sse_decode_list_record_u_32_ui_generator_instance(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, UiGeneratorInstance)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_32_ui_generator_instance(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(int, UiMixerChannel)> sse_decode_list_record_u_32_ui_mixer_channel(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, UiMixerChannel)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_32_ui_mixer_channel(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(int, UiPattern)> sse_decode_list_record_u_32_ui_pattern(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, UiPattern)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_32_ui_pattern(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(int, UiTrack)> sse_decode_list_record_u_32_ui_track(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, UiTrack)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_32_ui_track(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiClip> sse_decode_list_ui_clip(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiClip>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_clip(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiEffectInstance> sse_decode_list_ui_effect_instance(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiEffectInstance>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_effect_instance(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiEffectParameterSnapshot> sse_decode_list_ui_effect_parameter_snapshot(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiEffectParameterSnapshot>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_effect_parameter_snapshot(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiEffectSummary> sse_decode_list_ui_effect_summary(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiEffectSummary>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_effect_summary(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiGeneratorParameterSnapshot>
-  sse_decode_list_ui_generator_parameter_snapshot(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiGeneratorParameterSnapshot>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_generator_parameter_snapshot(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiMixerChannelParams> sse_decode_list_ui_mixer_channel_params(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiMixerChannelParams>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_mixer_channel_params(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiNote> sse_decode_list_ui_note(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiNote>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_note(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiParameterValue> sse_decode_list_ui_parameter_value(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiParameterValue>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_parameter_value(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiPluginInfo> sse_decode_list_ui_plugin_info(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiPluginInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_plugin_info(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiPluginParameter> sse_decode_list_ui_plugin_parameter(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiPluginParameter>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_plugin_parameter(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiResponseCurvePoint> sse_decode_list_ui_response_curve_point(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiResponseCurvePoint>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_response_curve_point(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<UiRoutingConnection> sse_decode_list_ui_routing_connection(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UiRoutingConnection>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ui_routing_connection(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  int? sse_decode_opt_CastedPrimitive_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_CastedPrimitive_i_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_CastedPrimitive_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_CastedPrimitive_u_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Map<int, AudioWaveformUiForSourceList>?
-  sse_decode_opt_Map_u_32_audio_waveform_ui_for_source_list_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_Map_u_32_audio_waveform_ui_for_source_list_None(
-        deserializer,
-      ));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  AudioWaveformUiForAudioProperties?
-  sse_decode_opt_box_autoadd_audio_waveform_ui_for_audio_properties(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_audio_waveform_ui_for_audio_properties(
-        deserializer,
-      ));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  List<ParameterSpecDTO>? sse_decode_opt_list_parameter_spec_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_parameter_spec_dto(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  ParameterSpecDTO sse_decode_parameter_spec_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_group = sse_decode_String(deserializer);
-    var var_value = sse_decode_f_32(deserializer);
-    var var_min = sse_decode_f_32(deserializer);
-    var var_max = sse_decode_f_32(deserializer);
-    var var_defaultValue = sse_decode_f_32(deserializer);
-    var var_step = sse_decode_f_32(deserializer);
-    var var_valueType = sse_decode_parameter_value_type_dto(deserializer);
-    var var_choices = sse_decode_list_String(deserializer);
-    return ParameterSpecDTO(
-      id: var_id,
-      name: var_name,
-      group: var_group,
-      value: var_value,
-      min: var_min,
-      max: var_max,
-      defaultValue: var_defaultValue,
-      step: var_step,
-      valueType: var_valueType,
-      choices: var_choices,
-    );
-  }
-
-  @protected
-  ParameterValueTypeDTO sse_decode_parameter_value_type_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return ParameterValueTypeDTO.values[inner];
-  }
-
-  @protected
-  (int, AudioWaveformUiForClip)
-  sse_decode_record_u_32_audio_waveform_ui_for_clip(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_audio_waveform_ui_for_clip(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, AudioWaveformUiForSourceList)
-  sse_decode_record_u_32_audio_waveform_ui_for_source_list(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_audio_waveform_ui_for_source_list(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, double) sse_decode_record_u_32_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_f_32(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, UiBus) sse_decode_record_u_32_ui_bus(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_ui_bus(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, UiGeneratorInstance) sse_decode_record_u_32_ui_generator_instance(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_ui_generator_instance(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, UiMixerChannel) sse_decode_record_u_32_ui_mixer_channel(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_ui_mixer_channel(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, UiPattern) sse_decode_record_u_32_ui_pattern(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_ui_pattern(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, UiTrack) sse_decode_record_u_32_ui_track(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_ui_track(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, int) sse_decode_record_u_8_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_8(deserializer);
-    var var_field1 = sse_decode_u_8(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (UiMixerChannel, List<UiEffectInstance>)
-  sse_decode_record_ui_mixer_channel_list_ui_effect_instance(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_ui_mixer_channel(deserializer);
-    var var_field1 = sse_decode_list_ui_effect_instance(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  UiApplicationState sse_decode_ui_application_state(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_metadata = sse_decode_ui_project_metadata(deserializer);
-    var var_transport = sse_decode_ui_transport_state(deserializer);
-    var var_hardwareConfig = sse_decode_ui_audio_hardware_config(deserializer);
-    var var_tracks = sse_decode_Map_u_32_ui_track_None(deserializer);
-    var var_generators = sse_decode_Map_u_32_ui_generator_instance_None(
-      deserializer,
-    );
-    var var_patterns = sse_decode_Map_u_32_ui_pattern_None(deserializer);
-    var var_mixer = sse_decode_ui_mixer_state(deserializer);
-    var var_maxSampleIndex = sse_decode_u_32(deserializer);
-    var var_audioSources =
-        sse_decode_Map_u_32_audio_waveform_ui_for_source_list_None(
-          deserializer,
-        );
-    return UiApplicationState(
-      metadata: var_metadata,
-      transport: var_transport,
-      hardwareConfig: var_hardwareConfig,
-      tracks: var_tracks,
-      generators: var_generators,
-      patterns: var_patterns,
-      mixer: var_mixer,
-      maxSampleIndex: var_maxSampleIndex,
-      audioSources: var_audioSources,
-    );
-  }
-
-  @protected
-  UiAudioHardwareConfig sse_decode_ui_audio_hardware_config(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_selectedInputDevice = sse_decode_String(deserializer);
-    var var_selectedOutputDevice = sse_decode_String(deserializer);
-    var var_sampleRate = sse_decode_u_32(deserializer);
-    var var_bufferSize = sse_decode_u_32(deserializer);
-    var var_cpuLoad = sse_decode_f_32(deserializer);
-    return UiAudioHardwareConfig(
-      selectedInputDevice: var_selectedInputDevice,
-      selectedOutputDevice: var_selectedOutputDevice,
-      sampleRate: var_sampleRate,
-      bufferSize: var_bufferSize,
-      cpuLoad: var_cpuLoad,
-    );
-  }
-
-  @protected
-  UiBus sse_decode_ui_bus(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_channel = sse_decode_ui_mixer_channel(deserializer);
-    return UiBus(id: var_id, name: var_name, channel: var_channel);
-  }
-
-  @protected
-  UiClip sse_decode_ui_clip(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_id = sse_decode_u_32(deserializer);
-    var var_startTime = sse_decode_u_32(deserializer);
-    var var_source = sse_decode_ui_clip_source(deserializer);
-    var var_offsetStart = sse_decode_u_32(deserializer);
-    var var_loopLength = sse_decode_u_32(deserializer);
-    return UiClip(
-      name: var_name,
-      id: var_id,
-      startTime: var_startTime,
-      source: var_source,
-      offsetStart: var_offsetStart,
-      loopLength: var_loopLength,
-    );
-  }
-
-  @protected
-  UiClipSource sse_decode_ui_clip_source(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_sourceId = sse_decode_u_32(deserializer);
-        return UiClipSource_Audio(sourceId: var_sourceId);
-      case 1:
-        var var_patternId = sse_decode_u_32(deserializer);
-        return UiClipSource_Midi(patternId: var_patternId);
-      case 2:
-        return UiClipSource_None();
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  UiClipboardContent sse_decode_ui_clipboard_content(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return UiClipboardContent_Empty();
-      case 1:
-        var var_field0 = sse_decode_list_ui_note(deserializer);
-        return UiClipboardContent_Notes(var_field0);
-      case 2:
-        var var_field0 = sse_decode_list_ui_clip(deserializer);
-        return UiClipboardContent_Clips(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  UiEffectInstance sse_decode_ui_effect_instance(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_parameters = sse_decode_Map_u_32_f_32_None(deserializer);
-    return UiEffectInstance(
-      id: var_id,
-      name: var_name,
-      parameters: var_parameters,
-    );
-  }
-
-  @protected
-  UiEffectParameterSnapshot sse_decode_ui_effect_parameter_snapshot(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_target = sse_decode_ui_effect_target(deserializer);
-    var var_effectId = sse_decode_u_32(deserializer);
-    var var_parameters = sse_decode_list_ui_parameter_value(deserializer);
-    return UiEffectParameterSnapshot(
-      target: var_target,
-      effectId: var_effectId,
-      parameters: var_parameters,
-    );
-  }
-
-  @protected
-  UiEffectSummary sse_decode_ui_effect_summary(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_registryId = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    return UiEffectSummary(
-      id: var_id,
-      registryId: var_registryId,
-      name: var_name,
-    );
-  }
-
-  @protected
-  UiEffectTarget sse_decode_ui_effect_target(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_u_32(deserializer);
-        return UiEffectTarget_Track(var_field0);
-      case 1:
-        return UiEffectTarget_Master();
-      case 2:
-        var var_field0 = sse_decode_u_32(deserializer);
-        return UiEffectTarget_Bus(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  UiGeneratorInstance sse_decode_ui_generator_instance(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_instanceType = sse_decode_ui_generator_instance_type(deserializer);
-    return UiGeneratorInstance(id: var_id, instanceType: var_instanceType);
-  }
-
-  @protected
-  UiGeneratorInstanceType sse_decode_ui_generator_instance_type(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_box_autoadd_ui_plugin_instance(
-          deserializer,
-        );
-        return UiGeneratorInstanceType_Plugin(var_field0);
-      case 1:
-        var var_assetId = sse_decode_u_32(deserializer);
-        var var_rootNote = sse_decode_u_8(deserializer);
-        return UiGeneratorInstanceType_Sampler(
-          assetId: var_assetId,
-          rootNote: var_rootNote,
-        );
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  UiGeneratorParameterSnapshot sse_decode_ui_generator_parameter_snapshot(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_generatorId = sse_decode_u_32(deserializer);
-    var var_parameters = sse_decode_list_ui_parameter_value(deserializer);
-    return UiGeneratorParameterSnapshot(
-      generatorId: var_generatorId,
-      parameters: var_parameters,
-    );
-  }
-
-  @protected
-  UiMixerChannel sse_decode_ui_mixer_channel(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_volume = sse_decode_f_32(deserializer);
-    var var_pan = sse_decode_f_32(deserializer);
-    var var_mute = sse_decode_bool(deserializer);
-    var var_solo = sse_decode_bool(deserializer);
-    var var_invertedPhase = sse_decode_bool(deserializer);
-    var var_effects = sse_decode_list_ui_effect_summary(deserializer);
-    return UiMixerChannel(
-      volume: var_volume,
-      pan: var_pan,
-      mute: var_mute,
-      solo: var_solo,
-      invertedPhase: var_invertedPhase,
-      effects: var_effects,
-    );
-  }
-
-  @protected
-  UiMixerChannelParams sse_decode_ui_mixer_channel_params(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_f_32(deserializer);
-        return UiMixerChannelParams_Volume(var_field0);
-      case 1:
-        var var_field0 = sse_decode_f_32(deserializer);
-        return UiMixerChannelParams_Pan(var_field0);
-      case 2:
-        var var_field0 = sse_decode_bool(deserializer);
-        return UiMixerChannelParams_Mute(var_field0);
-      case 3:
-        var var_field0 = sse_decode_bool(deserializer);
-        return UiMixerChannelParams_InvertedPhase(var_field0);
-      case 4:
-        var var_field0 = sse_decode_bool(deserializer);
-        return UiMixerChannelParams_Solo(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  UiMixerParamEvent sse_decode_ui_mixer_param_event(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_trackId = sse_decode_u_32(deserializer);
-    var var_volume = sse_decode_opt_box_autoadd_f_32(deserializer);
-    var var_pan = sse_decode_opt_box_autoadd_f_32(deserializer);
-    var var_mute = sse_decode_opt_box_autoadd_bool(deserializer);
-    var var_solo = sse_decode_opt_box_autoadd_bool(deserializer);
-    return UiMixerParamEvent(
-      trackId: var_trackId,
-      volume: var_volume,
-      pan: var_pan,
-      mute: var_mute,
-      solo: var_solo,
-    );
-  }
-
-  @protected
-  UiMixerState sse_decode_ui_mixer_state(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_channels = sse_decode_Map_u_32_ui_mixer_channel_None(deserializer);
-    var var_masterBus = sse_decode_ui_mixer_channel(deserializer);
-    var var_buses = sse_decode_Map_u_32_ui_bus_None(deserializer);
-    var var_routing = sse_decode_list_ui_routing_connection(deserializer);
-    return UiMixerState.raw(
-      channels: var_channels,
-      masterBus: var_masterBus,
-      buses: var_buses,
-      routing: var_routing,
-    );
-  }
-
-  @protected
-  UiNote sse_decode_ui_note(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_startTick = sse_decode_CastedPrimitive_u_64(deserializer);
-    var var_duration = sse_decode_CastedPrimitive_u_64(deserializer);
-    var var_key = sse_decode_u_8(deserializer);
-    var var_velocity = sse_decode_u_8(deserializer);
-    var var_probability = sse_decode_f_32(deserializer);
-    var var_microOffset = sse_decode_i_8(deserializer);
-    var var_mute = sse_decode_bool(deserializer);
-    return UiNote(
-      id: var_id,
-      startTick: var_startTick,
-      duration: var_duration,
-      key: var_key,
-      velocity: var_velocity,
-      probability: var_probability,
-      microOffset: var_microOffset,
-      mute: var_mute,
-    );
-  }
-
-  @protected
-  UiParameterType sse_decode_ui_parameter_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return UiParameterType.values[inner];
-  }
-
-  @protected
-  UiParameterValue sse_decode_ui_parameter_value(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_paramId = sse_decode_u_32(deserializer);
-    var var_value = sse_decode_f_32(deserializer);
-    return UiParameterValue(paramId: var_paramId, value: var_value);
-  }
-
-  @protected
-  UiPattern sse_decode_ui_pattern(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_lengthTicks = sse_decode_CastedPrimitive_u_64(deserializer);
-    var var_notes = sse_decode_list_ui_note(deserializer);
-    return UiPattern(
-      id: var_id,
-      name: var_name,
-      lengthTicks: var_lengthTicks,
-      notes: var_notes,
-    );
-  }
-
-  @protected
-  UiPluginInfo sse_decode_ui_plugin_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_pluginType = sse_decode_karbeat_plugin_type(deserializer);
-    return UiPluginInfo(id: var_id, name: var_name, pluginType: var_pluginType);
-  }
-
-  @protected
-  UiPluginInstance sse_decode_ui_plugin_instance(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_registryId = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_bypass = sse_decode_bool(deserializer);
-    var var_parameters = sse_decode_Map_u_32_f_32_None(deserializer);
-    return UiPluginInstance(
-      registryId: var_registryId,
-      name: var_name,
-      bypass: var_bypass,
-      parameters: var_parameters,
-    );
-  }
-
-  @protected
-  UiPluginParameter sse_decode_ui_plugin_parameter(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_group = sse_decode_String(deserializer);
-    var var_value = sse_decode_f_32(deserializer);
-    var var_min = sse_decode_f_32(deserializer);
-    var var_max = sse_decode_f_32(deserializer);
-    var var_defaultValue = sse_decode_f_32(deserializer);
-    var var_step = sse_decode_f_32(deserializer);
-    var var_paramType = sse_decode_ui_parameter_type(deserializer);
-    var var_choices = sse_decode_list_String(deserializer);
-    return UiPluginParameter(
-      id: var_id,
-      name: var_name,
-      group: var_group,
-      value: var_value,
-      min: var_min,
-      max: var_max,
-      defaultValue: var_defaultValue,
-      step: var_step,
-      paramType: var_paramType,
-      choices: var_choices,
-    );
-  }
-
-  @protected
-  UiProjectMetadata sse_decode_ui_project_metadata(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_author = sse_decode_String(deserializer);
-    var var_version = sse_decode_String(deserializer);
-    var var_createdAt = sse_decode_String(deserializer);
-    return UiProjectMetadata(
-      name: var_name,
-      author: var_author,
-      version: var_version,
-      createdAt: var_createdAt,
-    );
-  }
-
-  @protected
-  UiResizeEdge sse_decode_ui_resize_edge(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return UiResizeEdge.values[inner];
-  }
-
-  @protected
-  UiResponseCurvePoint sse_decode_ui_response_curve_point(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_frequency = sse_decode_f_32(deserializer);
-    var var_magnitudeDb = sse_decode_f_32(deserializer);
-    return UiResponseCurvePoint(
-      frequency: var_frequency,
-      magnitudeDb: var_magnitudeDb,
-    );
-  }
-
-  @protected
-  UiRoutingConnection sse_decode_ui_routing_connection(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_source = sse_decode_ui_routing_node(deserializer);
-    var var_destination = sse_decode_ui_routing_node(deserializer);
-    var var_sendLevel = sse_decode_f_32(deserializer);
-    var var_isSend = sse_decode_bool(deserializer);
-    return UiRoutingConnection(
-      source: var_source,
-      destination: var_destination,
-      sendLevel: var_sendLevel,
-      isSend: var_isSend,
-    );
-  }
-
-  @protected
-  UiRoutingNode sse_decode_ui_routing_node(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_u_32(deserializer);
-        return UiRoutingNode_Track(var_field0);
-      case 1:
-        var var_field0 = sse_decode_u_32(deserializer);
-        return UiRoutingNode_Bus(var_field0);
-      case 2:
-        return UiRoutingNode_Master();
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  UiSourceType sse_decode_ui_source_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return UiSourceType.values[inner];
-  }
-
-  @protected
-  UiTrack sse_decode_ui_track(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_color = sse_decode_String(deserializer);
-    var var_trackType = sse_decode_ui_track_type(deserializer);
-    var var_clips = sse_decode_list_ui_clip(deserializer);
-    var var_generatorId = sse_decode_opt_box_autoadd_u_32(deserializer);
-    return UiTrack(
-      id: var_id,
-      name: var_name,
-      color: var_color,
-      trackType: var_trackType,
-      clips: var_clips,
-      generatorId: var_generatorId,
-    );
-  }
-
-  @protected
-  UiTrackType sse_decode_ui_track_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return UiTrackType.values[inner];
-  }
-
-  @protected
-  UiTransportFeedback sse_decode_ui_transport_feedback(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_samples = sse_decode_u_32(deserializer);
-    var var_beat = sse_decode_CastedPrimitive_usize(deserializer);
-    var var_bar = sse_decode_CastedPrimitive_usize(deserializer);
-    var var_tempo = sse_decode_f_32(deserializer);
-    var var_sampleRate = sse_decode_u_32(deserializer);
-    var var_isPlaying = sse_decode_bool(deserializer);
-    var var_isLooping = sse_decode_bool(deserializer);
-    var var_isRecording = sse_decode_bool(deserializer);
-    var var_isPatternPlaying = sse_decode_bool(deserializer);
-    var var_isPatternMode = sse_decode_bool(deserializer);
-    var var_patternSamples = sse_decode_u_32(deserializer);
-    var var_patternBeat = sse_decode_CastedPrimitive_usize(deserializer);
-    var var_patternBar = sse_decode_CastedPrimitive_usize(deserializer);
-    return UiTransportFeedback(
-      samples: var_samples,
-      beat: var_beat,
-      bar: var_bar,
-      tempo: var_tempo,
-      sampleRate: var_sampleRate,
-      isPlaying: var_isPlaying,
-      isLooping: var_isLooping,
-      isRecording: var_isRecording,
-      isPatternPlaying: var_isPatternPlaying,
-      isPatternMode: var_isPatternMode,
-      patternSamples: var_patternSamples,
-      patternBeat: var_patternBeat,
-      patternBar: var_patternBar,
-    );
-  }
-
-  @protected
-  UiTransportState sse_decode_ui_transport_state(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_bpm = sse_decode_f_32(deserializer);
-    var var_timeSignature = sse_decode_record_u_8_u_8(deserializer);
-    return UiTransportState(bpm: var_bpm, timeSignature: var_timeSignature);
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(sseEncodeCastedPrimitiveI64(self), serializer);
-  }
-
-  @protected
-  void sse_encode_CastedPrimitive_u_64(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(sseEncodeCastedPrimitiveU64(self), serializer);
-  }
-
-  @protected
-  void sse_encode_CastedPrimitive_usize(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(sseEncodeCastedPrimitiveU64(self), serializer);
-  }
-
-  @protected
-  void sse_encode_Map_u_32_audio_waveform_ui_for_clip_None(
-    Map<int, AudioWaveformUiForClip> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_u_32_audio_waveform_ui_for_clip(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_u_32_audio_waveform_ui_for_source_list_None(
-    Map<int, AudioWaveformUiForSourceList> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_u_32_audio_waveform_ui_for_source_list(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_u_32_f_32_None(
-    Map<int, double> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_u_32_f_32(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_u_32_ui_bus_None(
-    Map<int, UiBus> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_u_32_ui_bus(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_u_32_ui_generator_instance_None(
-    Map<int, UiGeneratorInstance> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_u_32_ui_generator_instance(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_u_32_ui_mixer_channel_None(
-    Map<int, UiMixerChannel> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_u_32_ui_mixer_channel(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_u_32_ui_pattern_None(
-    Map<int, UiPattern> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_u_32_ui_pattern(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_u_32_ui_track_None(
-    Map<int, UiTrack> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_u_32_ui_track(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_StreamSink_ui_mixer_param_event_Sse(
-    RustStreamSink<UiMixerParamEvent> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_mixer_param_event,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_StreamSink_ui_transport_feedback_Sse(
-    RustStreamSink<UiTransportFeedback> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_ui_transport_feedback,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_audio_waveform_ui_for_audio_properties(
-    AudioWaveformUiForAudioProperties self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_i_8_strict(self.previewBuffer, serializer);
-    sse_encode_String(self.filePath, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-    sse_encode_u_16(self.channels, serializer);
-    sse_encode_f_64(self.duration, serializer);
-    sse_encode_u_8(self.rootNote, serializer);
-    sse_encode_i_16(self.fineTune, serializer);
-    sse_encode_u_32(self.trimStart, serializer);
-    sse_encode_u_32(self.trimEnd, serializer);
-    sse_encode_bool(self.isLooping, serializer);
-    sse_encode_bool(self.normalized, serializer);
-    sse_encode_bool(self.muted, serializer);
-  }
-
-  @protected
-  void sse_encode_audio_waveform_ui_for_clip(
-    AudioWaveformUiForClip self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_list_prim_i_8_strict(self.previewBuffer, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-    sse_encode_u_16(self.channels, serializer);
-    sse_encode_f_64(self.duration, serializer);
-  }
-
-  @protected
-  void sse_encode_audio_waveform_ui_for_source_list(
-    AudioWaveformUiForSourceList self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_bool(self.muted, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_audio_waveform_ui_for_audio_properties(
-    AudioWaveformUiForAudioProperties self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_audio_waveform_ui_for_audio_properties(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_record_u_8_u_8(
-    (int, int) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_record_u_8_u_8(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_ui_effect_target(
-    UiEffectTarget self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ui_effect_target(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_ui_mixer_channel(
-    UiMixerChannel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ui_mixer_channel(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_ui_plugin_instance(
-    UiPluginInstance self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ui_plugin_instance(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_ui_routing_node(
-    UiRoutingNode self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ui_routing_node(self, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt16(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_i_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt8(self);
-  }
-
-  @protected
-  void sse_encode_karbeat_plugin_type(
-    KarbeatPluginType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_parameter_spec_dto(
-    List<ParameterSpecDTO> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_parameter_spec_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_i_8_strict(
-    Int8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putInt8List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_32_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint32List(
-      self is Uint32List ? self : Uint32List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint32List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_record_u_32_audio_waveform_ui_for_clip(
-    List<(int, AudioWaveformUiForClip)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_32_audio_waveform_ui_for_clip(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_u_32_audio_waveform_ui_for_source_list(
-    List<(int, AudioWaveformUiForSourceList)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_32_audio_waveform_ui_for_source_list(
-        item,
-        serializer,
-      );
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_u_32_f_32(
-    List<(int, double)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_32_f_32(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_u_32_ui_bus(
-    List<(int, UiBus)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_32_ui_bus(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_u_32_ui_generator_instance(
-    List<(int, UiGeneratorInstance)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_32_ui_generator_instance(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_u_32_ui_mixer_channel(
-    List<(int, UiMixerChannel)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_32_ui_mixer_channel(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_u_32_ui_pattern(
-    List<(int, UiPattern)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_32_ui_pattern(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_u_32_ui_track(
-    List<(int, UiTrack)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_32_ui_track(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_clip(List<UiClip> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_clip(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_effect_instance(
-    List<UiEffectInstance> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_effect_instance(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_effect_parameter_snapshot(
-    List<UiEffectParameterSnapshot> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_effect_parameter_snapshot(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_effect_summary(
-    List<UiEffectSummary> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_effect_summary(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_generator_parameter_snapshot(
-    List<UiGeneratorParameterSnapshot> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_generator_parameter_snapshot(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_mixer_channel_params(
-    List<UiMixerChannelParams> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_mixer_channel_params(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_note(List<UiNote> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_note(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_parameter_value(
-    List<UiParameterValue> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_parameter_value(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_plugin_info(
-    List<UiPluginInfo> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_plugin_info(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_plugin_parameter(
-    List<UiPluginParameter> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_plugin_parameter(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_response_curve_point(
-    List<UiResponseCurvePoint> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_response_curve_point(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ui_routing_connection(
-    List<UiRoutingConnection> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ui_routing_connection(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_CastedPrimitive_i_64(
-    int? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_CastedPrimitive_i_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_CastedPrimitive_u_64(
-    int? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_CastedPrimitive_u_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_Map_u_32_audio_waveform_ui_for_source_list_None(
-    Map<int, AudioWaveformUiForSourceList>? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_Map_u_32_audio_waveform_ui_for_source_list_None(
-        self,
-        serializer,
-      );
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_audio_waveform_ui_for_audio_properties(
-    AudioWaveformUiForAudioProperties? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_audio_waveform_ui_for_audio_properties(
-        self,
-        serializer,
-      );
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_parameter_spec_dto(
-    List<ParameterSpecDTO>? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_parameter_spec_dto(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_parameter_spec_dto(
-    ParameterSpecDTO self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.group, serializer);
-    sse_encode_f_32(self.value, serializer);
-    sse_encode_f_32(self.min, serializer);
-    sse_encode_f_32(self.max, serializer);
-    sse_encode_f_32(self.defaultValue, serializer);
-    sse_encode_f_32(self.step, serializer);
-    sse_encode_parameter_value_type_dto(self.valueType, serializer);
-    sse_encode_list_String(self.choices, serializer);
-  }
-
-  @protected
-  void sse_encode_parameter_value_type_dto(
-    ParameterValueTypeDTO self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_audio_waveform_ui_for_clip(
-    (int, AudioWaveformUiForClip) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_audio_waveform_ui_for_clip(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_audio_waveform_ui_for_source_list(
-    (int, AudioWaveformUiForSourceList) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_audio_waveform_ui_for_source_list(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_f_32(
-    (int, double) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_f_32(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_ui_bus(
-    (int, UiBus) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_ui_bus(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_ui_generator_instance(
-    (int, UiGeneratorInstance) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_ui_generator_instance(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_ui_mixer_channel(
-    (int, UiMixerChannel) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_ui_mixer_channel(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_ui_pattern(
-    (int, UiPattern) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_ui_pattern(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_ui_track(
-    (int, UiTrack) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_ui_track(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_8_u_8((int, int) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_8(self.$1, serializer);
-    sse_encode_u_8(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_ui_mixer_channel_list_ui_effect_instance(
-    (UiMixerChannel, List<UiEffectInstance>) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ui_mixer_channel(self.$1, serializer);
-    sse_encode_list_ui_effect_instance(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_ui_application_state(
-    UiApplicationState self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ui_project_metadata(self.metadata, serializer);
-    sse_encode_ui_transport_state(self.transport, serializer);
-    sse_encode_ui_audio_hardware_config(self.hardwareConfig, serializer);
-    sse_encode_Map_u_32_ui_track_None(self.tracks, serializer);
-    sse_encode_Map_u_32_ui_generator_instance_None(self.generators, serializer);
-    sse_encode_Map_u_32_ui_pattern_None(self.patterns, serializer);
-    sse_encode_ui_mixer_state(self.mixer, serializer);
-    sse_encode_u_32(self.maxSampleIndex, serializer);
-    sse_encode_Map_u_32_audio_waveform_ui_for_source_list_None(
-      self.audioSources,
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_ui_audio_hardware_config(
-    UiAudioHardwareConfig self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.selectedInputDevice, serializer);
-    sse_encode_String(self.selectedOutputDevice, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-    sse_encode_u_32(self.bufferSize, serializer);
-    sse_encode_f_32(self.cpuLoad, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_bus(UiBus self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_ui_mixer_channel(self.channel, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_clip(UiClip self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_u_32(self.startTime, serializer);
-    sse_encode_ui_clip_source(self.source, serializer);
-    sse_encode_u_32(self.offsetStart, serializer);
-    sse_encode_u_32(self.loopLength, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_clip_source(UiClipSource self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case UiClipSource_Audio(sourceId: final sourceId):
-        sse_encode_i_32(0, serializer);
-        sse_encode_u_32(sourceId, serializer);
-      case UiClipSource_Midi(patternId: final patternId):
-        sse_encode_i_32(1, serializer);
-        sse_encode_u_32(patternId, serializer);
-      case UiClipSource_None():
-        sse_encode_i_32(2, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_ui_clipboard_content(
-    UiClipboardContent self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case UiClipboardContent_Empty():
-        sse_encode_i_32(0, serializer);
-      case UiClipboardContent_Notes(field0: final field0):
-        sse_encode_i_32(1, serializer);
-        sse_encode_list_ui_note(field0, serializer);
-      case UiClipboardContent_Clips(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_list_ui_clip(field0, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_ui_effect_instance(
-    UiEffectInstance self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_Map_u_32_f_32_None(self.parameters, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_effect_parameter_snapshot(
-    UiEffectParameterSnapshot self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ui_effect_target(self.target, serializer);
-    sse_encode_u_32(self.effectId, serializer);
-    sse_encode_list_ui_parameter_value(self.parameters, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_effect_summary(
-    UiEffectSummary self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_u_32(self.registryId, serializer);
-    sse_encode_String(self.name, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_effect_target(
-    UiEffectTarget self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case UiEffectTarget_Track(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_u_32(field0, serializer);
-      case UiEffectTarget_Master():
-        sse_encode_i_32(1, serializer);
-      case UiEffectTarget_Bus(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_u_32(field0, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_ui_generator_instance(
-    UiGeneratorInstance self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_ui_generator_instance_type(self.instanceType, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_generator_instance_type(
-    UiGeneratorInstanceType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case UiGeneratorInstanceType_Plugin(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_box_autoadd_ui_plugin_instance(field0, serializer);
-      case UiGeneratorInstanceType_Sampler(
-        assetId: final assetId,
-        rootNote: final rootNote,
-      ):
-        sse_encode_i_32(1, serializer);
-        sse_encode_u_32(assetId, serializer);
-        sse_encode_u_8(rootNote, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_ui_generator_parameter_snapshot(
-    UiGeneratorParameterSnapshot self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.generatorId, serializer);
-    sse_encode_list_ui_parameter_value(self.parameters, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_mixer_channel(
-    UiMixerChannel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.volume, serializer);
-    sse_encode_f_32(self.pan, serializer);
-    sse_encode_bool(self.mute, serializer);
-    sse_encode_bool(self.solo, serializer);
-    sse_encode_bool(self.invertedPhase, serializer);
-    sse_encode_list_ui_effect_summary(self.effects, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_mixer_channel_params(
-    UiMixerChannelParams self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case UiMixerChannelParams_Volume(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_f_32(field0, serializer);
-      case UiMixerChannelParams_Pan(field0: final field0):
-        sse_encode_i_32(1, serializer);
-        sse_encode_f_32(field0, serializer);
-      case UiMixerChannelParams_Mute(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_bool(field0, serializer);
-      case UiMixerChannelParams_InvertedPhase(field0: final field0):
-        sse_encode_i_32(3, serializer);
-        sse_encode_bool(field0, serializer);
-      case UiMixerChannelParams_Solo(field0: final field0):
-        sse_encode_i_32(4, serializer);
-        sse_encode_bool(field0, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_ui_mixer_param_event(
-    UiMixerParamEvent self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.trackId, serializer);
-    sse_encode_opt_box_autoadd_f_32(self.volume, serializer);
-    sse_encode_opt_box_autoadd_f_32(self.pan, serializer);
-    sse_encode_opt_box_autoadd_bool(self.mute, serializer);
-    sse_encode_opt_box_autoadd_bool(self.solo, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_mixer_state(UiMixerState self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Map_u_32_ui_mixer_channel_None(self.channels, serializer);
-    sse_encode_ui_mixer_channel(self.masterBus, serializer);
-    sse_encode_Map_u_32_ui_bus_None(self.buses, serializer);
-    sse_encode_list_ui_routing_connection(self.routing, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_note(UiNote self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_CastedPrimitive_u_64(self.startTick, serializer);
-    sse_encode_CastedPrimitive_u_64(self.duration, serializer);
-    sse_encode_u_8(self.key, serializer);
-    sse_encode_u_8(self.velocity, serializer);
-    sse_encode_f_32(self.probability, serializer);
-    sse_encode_i_8(self.microOffset, serializer);
-    sse_encode_bool(self.mute, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_parameter_type(
-    UiParameterType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_parameter_value(
-    UiParameterValue self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.paramId, serializer);
-    sse_encode_f_32(self.value, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_pattern(UiPattern self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_CastedPrimitive_u_64(self.lengthTicks, serializer);
-    sse_encode_list_ui_note(self.notes, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_plugin_info(UiPluginInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_karbeat_plugin_type(self.pluginType, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_plugin_instance(
-    UiPluginInstance self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.registryId, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_bool(self.bypass, serializer);
-    sse_encode_Map_u_32_f_32_None(self.parameters, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_plugin_parameter(
-    UiPluginParameter self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.group, serializer);
-    sse_encode_f_32(self.value, serializer);
-    sse_encode_f_32(self.min, serializer);
-    sse_encode_f_32(self.max, serializer);
-    sse_encode_f_32(self.defaultValue, serializer);
-    sse_encode_f_32(self.step, serializer);
-    sse_encode_ui_parameter_type(self.paramType, serializer);
-    sse_encode_list_String(self.choices, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_project_metadata(
-    UiProjectMetadata self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.author, serializer);
-    sse_encode_String(self.version, serializer);
-    sse_encode_String(self.createdAt, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_resize_edge(UiResizeEdge self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_response_curve_point(
-    UiResponseCurvePoint self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.frequency, serializer);
-    sse_encode_f_32(self.magnitudeDb, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_routing_connection(
-    UiRoutingConnection self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ui_routing_node(self.source, serializer);
-    sse_encode_ui_routing_node(self.destination, serializer);
-    sse_encode_f_32(self.sendLevel, serializer);
-    sse_encode_bool(self.isSend, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_routing_node(
-    UiRoutingNode self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case UiRoutingNode_Track(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_u_32(field0, serializer);
-      case UiRoutingNode_Bus(field0: final field0):
-        sse_encode_i_32(1, serializer);
-        sse_encode_u_32(field0, serializer);
-      case UiRoutingNode_Master():
-        sse_encode_i_32(2, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_ui_source_type(UiSourceType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_track(UiTrack self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.color, serializer);
-    sse_encode_ui_track_type(self.trackType, serializer);
-    sse_encode_list_ui_clip(self.clips, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.generatorId, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_track_type(UiTrackType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_transport_feedback(
-    UiTransportFeedback self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.samples, serializer);
-    sse_encode_CastedPrimitive_usize(self.beat, serializer);
-    sse_encode_CastedPrimitive_usize(self.bar, serializer);
-    sse_encode_f_32(self.tempo, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-    sse_encode_bool(self.isPlaying, serializer);
-    sse_encode_bool(self.isLooping, serializer);
-    sse_encode_bool(self.isRecording, serializer);
-    sse_encode_bool(self.isPatternPlaying, serializer);
-    sse_encode_bool(self.isPatternMode, serializer);
-    sse_encode_u_32(self.patternSamples, serializer);
-    sse_encode_CastedPrimitive_usize(self.patternBeat, serializer);
-    sse_encode_CastedPrimitive_usize(self.patternBar, serializer);
-  }
-
-  @protected
-  void sse_encode_ui_transport_state(
-    UiTransportState self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.bpm, serializer);
-    sse_encode_record_u_8_u_8(self.timeSignature, serializer);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiTransportSetTempoPointConstMeta,
+            argValues: [tick, bpm, linear],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetTempoPointConstMeta => const TaskConstMeta(
+            debugName: "set_tempo_point",
+            argNames: ["tick", "bpm", "linear"],
+        );
+        
+
+@override Future<void> crateApiTransportSetTimeSignature({required int bar , required int numerator , required int denominator })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(bar, serializer);
+sse_encode_u_8(numerator, serializer);
+sse_encode_u_8(denominator, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportSetTimeSignatureConstMeta,
+            argValues: [bar, numerator, denominator],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportSetTimeSignatureConstMeta => const TaskConstMeta(
+            debugName: "set_time_signature",
+            argNames: ["bar", "numerator", "denominator"],
+        );
+        
+
+@override Future<void> crateApiRecordingSetTrackMonitorMode({required int trackId , required UiMonitorMode mode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_ui_monitor_mode(mode, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRecordingSetTrackMonitorModeConstMeta,
+            argValues: [trackId, mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingSetTrackMonitorModeConstMeta => const TaskConstMeta(
+            debugName: "set_track_monitor_mode",
+            argNames: ["trackId", "mode"],
+        );
+        
+
+@override Future<void> crateApiRecordingSetTrackRecordArm({required int trackId , required bool armed })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(trackId, serializer);
+sse_encode_bool(armed, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRecordingSetTrackRecordArmConstMeta,
+            argValues: [trackId, armed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingSetTrackRecordArmConstMeta => const TaskConstMeta(
+            debugName: "set_track_record_arm",
+            argNames: ["trackId", "armed"],
+        );
+        
+
+@override Future<void> crateApiAudioSetUserSettingsPath({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAudioSetUserSettingsPathConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioSetUserSettingsPathConstMeta => const TaskConstMeta(
+            debugName: "set_user_settings_path",
+            argNames: ["path"],
+        );
+        
+
+@override Future<List<UiNote>> crateApiPatternSnapToScale({required int patternId , required List<int> noteIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_list_prim_u_32_loose(noteIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternSnapToScaleConstMeta,
+            argValues: [patternId, noteIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternSnapToScaleConstMeta => const TaskConstMeta(
+            debugName: "snap_to_scale",
+            argNames: ["patternId", "noteIds"],
+        );
+        
+
+@override Future<void> crateApiRecordingStartRecording()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRecordingStartRecordingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingStartRecordingConstMeta => const TaskConstMeta(
+            debugName: "start_recording",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiAudioStopAllPreviews()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAudioStopAllPreviewsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioStopAllPreviewsConstMeta => const TaskConstMeta(
+            debugName: "stop_all_previews",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiPatternStopPatternPreview()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternStopPatternPreviewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternStopPatternPreviewConstMeta => const TaskConstMeta(
+            debugName: "stop_pattern_preview",
+            argNames: [],
+        );
+        
+
+@override Future<List<UiRecordedClip>> crateApiRecordingStopRecording()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_recorded_clip,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRecordingStopRecordingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordingStopRecordingConstMeta => const TaskConstMeta(
+            debugName: "stop_recording",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTransportStopSongPlayback()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTransportStopSongPlaybackConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportStopSongPlaybackConstMeta => const TaskConstMeta(
+            debugName: "stop_song_playback",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiAnalyzerStopSpectrumStream({required int analyzerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(analyzerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerStopSpectrumStreamConstMeta,
+            argValues: [analyzerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerStopSpectrumStreamConstMeta => const TaskConstMeta(
+            debugName: "stop_spectrum_stream",
+            argNames: ["analyzerId"],
+        );
+        
+
+@override Future<UiGroove> crateApiPatternSwingGroove({required UiSwingDivision division , required double percent })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_ui_swing_division(division, serializer);
+sse_encode_f_32(percent, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_groove,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPatternSwingGrooveConstMeta,
+            argValues: [division, percent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternSwingGrooveConstMeta => const TaskConstMeta(
+            debugName: "swing_groove",
+            argNames: ["division", "percent"],
+        );
+        
+
+@override Future<void> crateApiPluginSyncEffectParametersFromAudio({required List<UiEffectParameterSnapshot> snapshots })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_ui_effect_parameter_snapshot(snapshots, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPluginSyncEffectParametersFromAudioConstMeta,
+            argValues: [snapshots],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginSyncEffectParametersFromAudioConstMeta => const TaskConstMeta(
+            debugName: "sync_effect_parameters_from_audio",
+            argNames: ["snapshots"],
+        );
+        
+
+@override Future<void> crateApiPluginSyncGeneratorParametersFromAudio({required List<UiGeneratorParameterSnapshot> snapshots })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_ui_generator_parameter_snapshot(snapshots, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPluginSyncGeneratorParametersFromAudioConstMeta,
+            argValues: [snapshots],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPluginSyncGeneratorParametersFromAudioConstMeta => const TaskConstMeta(
+            debugName: "sync_generator_parameters_from_audio",
+            argNames: ["snapshots"],
+        );
+        
+
+@override Future<int> crateApiTransportTicksToSamples({required int tick })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_CastedPrimitive_u_64(tick, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTransportTicksToSamplesConstMeta,
+            argValues: [tick],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTransportTicksToSamplesConstMeta => const TaskConstMeta(
+            debugName: "ticks_to_samples",
+            argNames: ["tick"],
+        );
+        
+
+@override Future<List<UiNote>> crateApiPatternTransformNotes({required int patternId , required List<int> noteIds , required UiNoteTransform transform })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(patternId, serializer);
+sse_encode_list_prim_u_32_loose(noteIds, serializer);
+sse_encode_box_autoadd_ui_note_transform(transform, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ui_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPatternTransformNotesConstMeta,
+            argValues: [patternId, noteIds, transform],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPatternTransformNotesConstMeta => const TaskConstMeta(
+            debugName: "transform_notes",
+            argNames: ["patternId", "noteIds", "transform"],
+        );
+        
+
+@override UiTransportState crateApiProjectTransportStateNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_transport_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProjectTransportStateNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectTransportStateNewConstMeta => const TaskConstMeta(
+            debugName: "transport_state_new",
+            argNames: [],
+        );
+        
+
+@override UiTransportState crateApiProjectTransportStateNewWithParam({required double bpm , required (int,int) timeSignature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_32(bpm, serializer);
+sse_encode_box_autoadd_record_u_8_u_8(timeSignature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_transport_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProjectTransportStateNewWithParamConstMeta,
+            argValues: [bpm, timeSignature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectTransportStateNewWithParamConstMeta => const TaskConstMeta(
+            debugName: "transport_state_new_with_param",
+            argNames: ["bpm", "timeSignature"],
+        );
+        
+
+@override Future<UiAudioDeviceSettings> crateApiAudioUiAudioDeviceSettingsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_audio_device_settings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAudioUiAudioDeviceSettingsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioUiAudioDeviceSettingsDefaultConstMeta => const TaskConstMeta(
+            debugName: "ui_audio_device_settings_default",
+            argNames: [],
+        );
+        
+
+@override Future<UiClipboardContent> crateApiSessionUiClipboardContentDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_clipboard_content,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSessionUiClipboardContentDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionUiClipboardContentDefaultConstMeta => const TaskConstMeta(
+            debugName: "ui_clipboard_content_default",
+            argNames: [],
+        );
+        
+
+@override UiMixerState crateApiMixerUiMixerStateNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_mixer_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMixerUiMixerStateNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerUiMixerStateNewConstMeta => const TaskConstMeta(
+            debugName: "ui_mixer_state_new",
+            argNames: [],
+        );
+        
+
+@override UiMixerState crateApiMixerUiMixerStateNewWithParam({required Map<int, UiMixerChannel> channels , required UiMixerChannel masterBus , required Map<int, UiBus> buses , required List<UiRoutingConnection> routing })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Map_u_32_ui_mixer_channel_None(channels, serializer);
+sse_encode_box_autoadd_ui_mixer_channel(masterBus, serializer);
+sse_encode_Map_u_32_ui_bus_None(buses, serializer);
+sse_encode_list_ui_routing_connection(routing, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_mixer_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMixerUiMixerStateNewWithParamConstMeta,
+            argValues: [channels, masterBus, buses, routing],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMixerUiMixerStateNewWithParamConstMeta => const TaskConstMeta(
+            debugName: "ui_mixer_state_new_with_param",
+            argNames: ["channels", "masterBus", "buses", "routing"],
+        );
+        
+
+@override Future<UiProjectMetadata> crateApiProjectUiProjectMetadataDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_project_metadata,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProjectUiProjectMetadataDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectUiProjectMetadataDefaultConstMeta => const TaskConstMeta(
+            debugName: "ui_project_metadata_default",
+            argNames: [],
+        );
+        
+
+@override Future<UiTransportState> crateApiProjectUiTransportStateDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_transport_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProjectUiTransportStateDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectUiTransportStateDefaultConstMeta => const TaskConstMeta(
+            debugName: "ui_transport_state_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSessionUndo()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSessionUndoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionUndoConstMeta => const TaskConstMeta(
+            debugName: "undo",
+            argNames: [],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected int dco_decode_CastedPrimitive_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError('Not implemented in this codec, please use the other one'); }
+
+@protected int dco_decode_CastedPrimitive_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError('Not implemented in this codec, please use the other one'); }
+
+@protected int dco_decode_CastedPrimitive_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError('Not implemented in this codec, please use the other one'); }
+
+@protected Map<int, AudioWaveformUiForClip> dco_decode_Map_u_32_audio_waveform_ui_for_clip_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_u_32_audio_waveform_ui_for_clip(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, AudioWaveformUiForSourceList> dco_decode_Map_u_32_audio_waveform_ui_for_source_list_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_u_32_audio_waveform_ui_for_source_list(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, double> dco_decode_Map_u_32_f_32_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_u_32_f_32(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiBus> dco_decode_Map_u_32_ui_bus_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_u_32_ui_bus(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiGeneratorInstance> dco_decode_Map_u_32_ui_generator_instance_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_u_32_ui_generator_instance(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiMixerChannel> dco_decode_Map_u_32_ui_mixer_channel_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_u_32_ui_mixer_channel(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiPattern> dco_decode_Map_u_32_ui_pattern_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_u_32_ui_pattern(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiTrack> dco_decode_Map_u_32_ui_track_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_u_32_ui_track(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected RustStreamSink<List<UiMeterLevels>> dco_decode_StreamSink_list_ui_meter_levels_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<List<UiResponseCurvePoint>> dco_decode_StreamSink_list_ui_response_curve_point_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<UiMixerParamEvent> dco_decode_StreamSink_ui_mixer_param_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<UiTransportFeedback> dco_decode_StreamSink_ui_transport_feedback_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AudioWaveformUiForAudioProperties dco_decode_audio_waveform_ui_for_audio_properties(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return AudioWaveformUiForAudioProperties(previewBuffer: dco_decode_list_prim_i_8_strict(arr[0]),
+filePath: dco_decode_String(arr[1]),
+name: dco_decode_String(arr[2]),
+sampleRate: dco_decode_u_32(arr[3]),
+channels: dco_decode_u_16(arr[4]),
+duration: dco_decode_f_64(arr[5]),
+rootNote: dco_decode_u_8(arr[6]),
+fineTune: dco_decode_i_16(arr[7]),
+trimStart: dco_decode_u_32(arr[8]),
+trimEnd: dco_decode_u_32(arr[9]),
+isLooping: dco_decode_bool(arr[10]),
+normalized: dco_decode_bool(arr[11]),
+muted: dco_decode_bool(arr[12]),); }
+
+@protected AudioWaveformUiForClip dco_decode_audio_waveform_ui_for_clip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return AudioWaveformUiForClip(name: dco_decode_String(arr[0]),
+previewBuffer: dco_decode_list_prim_i_8_strict(arr[1]),
+sampleRate: dco_decode_u_32(arr[2]),
+channels: dco_decode_u_16(arr[3]),
+duration: dco_decode_f_64(arr[4]),); }
+
+@protected AudioWaveformUiForSourceList dco_decode_audio_waveform_ui_for_source_list(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return AudioWaveformUiForSourceList(name: dco_decode_String(arr[0]),
+muted: dco_decode_bool(arr[1]),
+sampleRate: dco_decode_u_32(arr[2]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AudioWaveformUiForAudioProperties dco_decode_box_autoadd_audio_waveform_ui_for_audio_properties(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_audio_waveform_ui_for_audio_properties(raw); }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected double dco_decode_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected (int,int) dco_decode_box_autoadd_record_u_8_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as (int,int); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected UiAudioDeviceSettings dco_decode_box_autoadd_ui_audio_device_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_audio_device_settings(raw); }
+
+@protected UiEffectTarget dco_decode_box_autoadd_ui_effect_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_effect_target(raw); }
+
+@protected UiEngineLoad dco_decode_box_autoadd_ui_engine_load(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_engine_load(raw); }
+
+@protected UiGroove dco_decode_box_autoadd_ui_groove(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_groove(raw); }
+
+@protected UiLoopRegion dco_decode_box_autoadd_ui_loop_region(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_loop_region(raw); }
+
+@protected UiLoudnessReport dco_decode_box_autoadd_ui_loudness_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_loudness_report(raw); }
+
+@protected UiMidiOutputSettings dco_decode_box_autoadd_ui_midi_output_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_midi_output_settings(raw); }
+
+@protected UiMixerChannel dco_decode_box_autoadd_ui_mixer_channel(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_mixer_channel(raw); }
+
+@protected UiNoteTransform dco_decode_box_autoadd_ui_note_transform(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_note_transform(raw); }
+
+@protected UiPluginInstance dco_decode_box_autoadd_ui_plugin_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_plugin_instance(raw); }
+
+@protected UiQuantizeSettings dco_decode_box_autoadd_ui_quantize_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_quantize_settings(raw); }
+
+@protected UiRoutingNode dco_decode_box_autoadd_ui_routing_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_routing_node(raw); }
+
+@protected UiScale dco_decode_box_autoadd_ui_scale(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_scale(raw); }
+
+@protected UiScaleMode dco_decode_box_autoadd_ui_scale_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_scale_mode(raw); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected int dco_decode_i_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected int dco_decode_i_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected KarbeatPluginType dco_decode_karbeat_plugin_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return KarbeatPluginType.values[raw as int]; }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<ParameterSpecDTO> dco_decode_list_parameter_spec_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_parameter_spec_dto).toList(); }
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float32List; }
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int32List; }
+
+@protected Int8List dco_decode_list_prim_i_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int8List; }
+
+@protected Uint16List dco_decode_list_prim_u_16_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint16List; }
+
+@protected List<int> dco_decode_list_prim_u_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<(int,AudioWaveformUiForClip)> dco_decode_list_record_u_32_audio_waveform_ui_for_clip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_audio_waveform_ui_for_clip).toList(); }
+
+@protected List<(int,AudioWaveformUiForSourceList)> dco_decode_list_record_u_32_audio_waveform_ui_for_source_list(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_audio_waveform_ui_for_source_list).toList(); }
+
+@protected List<(int,double)> dco_decode_list_record_u_32_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_f_32).toList(); }
+
+@protected List<(int,UiBus)> dco_decode_list_record_u_32_ui_bus(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_ui_bus).toList(); }
+
+@protected List<(int,UiGeneratorInstance)> dco_decode_list_record_u_32_ui_generator_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_ui_generator_instance).toList(); }
+
+@protected List<(int,UiMixerChannel)> dco_decode_list_record_u_32_ui_mixer_channel(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_ui_mixer_channel).toList(); }
+
+@protected List<(int,UiPattern)> dco_decode_list_record_u_32_ui_pattern(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_ui_pattern).toList(); }
+
+@protected List<(int,UiTrack)> dco_decode_list_record_u_32_ui_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_ui_track).toList(); }
+
+@protected List<UiAudioDeviceInfo> dco_decode_list_ui_audio_device_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_audio_device_info).toList(); }
+
+@protected List<UiChordSpan> dco_decode_list_ui_chord_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_chord_span).toList(); }
+
+@protected List<UiClip> dco_decode_list_ui_clip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_clip).toList(); }
+
+@protected List<UiEffectInstance> dco_decode_list_ui_effect_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_effect_instance).toList(); }
+
+@protected List<UiEffectParameterSnapshot> dco_decode_list_ui_effect_parameter_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_effect_parameter_snapshot).toList(); }
+
+@protected List<UiEffectSummary> dco_decode_list_ui_effect_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_effect_summary).toList(); }
+
+@protected List<UiGeneratorParameterSnapshot> dco_decode_list_ui_generator_parameter_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_generator_parameter_snapshot).toList(); }
+
+@protected List<UiMeterLevels> dco_decode_list_ui_meter_levels(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_meter_levels).toList(); }
+
+@protected List<UiMixerChannelParams> dco_decode_list_ui_mixer_channel_params(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_mixer_channel_params).toList(); }
+
+@protected List<UiNote> dco_decode_list_ui_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_note).toList(); }
+
+@protected List<UiParameterValue> dco_decode_list_ui_parameter_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_parameter_value).toList(); }
+
+@protected List<UiPluginInfo> dco_decode_list_ui_plugin_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_plugin_info).toList(); }
+
+@protected List<UiPluginLoad> dco_decode_list_ui_plugin_load(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_plugin_load).toList(); }
+
+@protected List<UiPluginParameter> dco_decode_list_ui_plugin_parameter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_plugin_parameter).toList(); }
+
+@protected List<UiRecordedClip> dco_decode_list_ui_recorded_clip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_recorded_clip).toList(); }
+
+@protected List<UiResponseCurvePoint> dco_decode_list_ui_response_curve_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_response_curve_point).toList(); }
+
+@protected List<UiRoutingConnection> dco_decode_list_ui_routing_connection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_routing_connection).toList(); }
+
+@protected List<UiTempoPoint> dco_decode_list_ui_tempo_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_tempo_point).toList(); }
+
+@protected List<UiTimeSignatureEvent> dco_decode_list_ui_time_signature_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ui_time_signature_event).toList(); }
+
+@protected int? dco_decode_opt_CastedPrimitive_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_CastedPrimitive_i_64(raw); }
+
+@protected int? dco_decode_opt_CastedPrimitive_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_CastedPrimitive_u_64(raw); }
+
+@protected Map<int, AudioWaveformUiForSourceList>? dco_decode_opt_Map_u_32_audio_waveform_ui_for_source_list_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_Map_u_32_audio_waveform_ui_for_source_list_None(raw); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected AudioWaveformUiForAudioProperties? dco_decode_opt_box_autoadd_audio_waveform_ui_for_audio_properties(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_audio_waveform_ui_for_audio_properties(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_32(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected UiEngineLoad? dco_decode_opt_box_autoadd_ui_engine_load(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ui_engine_load(raw); }
+
+@protected UiGroove? dco_decode_opt_box_autoadd_ui_groove(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ui_groove(raw); }
+
+@protected UiLoopRegion? dco_decode_opt_box_autoadd_ui_loop_region(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ui_loop_region(raw); }
+
+@protected UiLoudnessReport? dco_decode_opt_box_autoadd_ui_loudness_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ui_loudness_report(raw); }
+
+@protected UiScale? dco_decode_opt_box_autoadd_ui_scale(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ui_scale(raw); }
+
+@protected UiScaleMode? dco_decode_opt_box_autoadd_ui_scale_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ui_scale_mode(raw); }
+
+@protected List<ParameterSpecDTO>? dco_decode_opt_list_parameter_spec_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_parameter_spec_dto(raw); }
+
+@protected ParameterSpecDTO dco_decode_parameter_spec_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return ParameterSpecDTO(id: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+group: dco_decode_String(arr[2]),
+value: dco_decode_f_32(arr[3]),
+min: dco_decode_f_32(arr[4]),
+max: dco_decode_f_32(arr[5]),
+defaultValue: dco_decode_f_32(arr[6]),
+step: dco_decode_f_32(arr[7]),
+valueType: dco_decode_parameter_value_type_dto(arr[8]),
+choices: dco_decode_list_String(arr[9]),); }
+
+@protected ParameterValueTypeDTO dco_decode_parameter_value_type_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ParameterValueTypeDTO.values[raw as int]; }
+
+@protected (int,AudioWaveformUiForClip) dco_decode_record_u_32_audio_waveform_ui_for_clip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_audio_waveform_ui_for_clip(arr[1]),); }
+
+@protected (int,AudioWaveformUiForSourceList) dco_decode_record_u_32_audio_waveform_ui_for_source_list(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_audio_waveform_ui_for_source_list(arr[1]),); }
+
+@protected (int,double) dco_decode_record_u_32_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_f_32(arr[1]),); }
+
+@protected (int,UiBus) dco_decode_record_u_32_ui_bus(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_ui_bus(arr[1]),); }
+
+@protected (int,UiGeneratorInstance) dco_decode_record_u_32_ui_generator_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_ui_generator_instance(arr[1]),); }
+
+@protected (int,UiMixerChannel) dco_decode_record_u_32_ui_mixer_channel(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_ui_mixer_channel(arr[1]),); }
+
+@protected (int,UiPattern) dco_decode_record_u_32_ui_pattern(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_ui_pattern(arr[1]),); }
+
+@protected (int,UiTrack) dco_decode_record_u_32_ui_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_ui_track(arr[1]),); }
+
+@protected (int,int) dco_decode_record_u_8_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_8(arr[0]),dco_decode_u_8(arr[1]),); }
+
+@protected (UiMixerChannel,List<UiEffectInstance>) dco_decode_record_ui_mixer_channel_list_ui_effect_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_ui_mixer_channel(arr[0]),dco_decode_list_ui_effect_instance(arr[1]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected UiApplicationState dco_decode_ui_application_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return UiApplicationState(metadata: dco_decode_ui_project_metadata(arr[0]),
+transport: dco_decode_ui_transport_state(arr[1]),
+hardwareConfig: dco_decode_ui_audio_hardware_config(arr[2]),
+tracks: dco_decode_Map_u_32_ui_track_None(arr[3]),
+generators: dco_decode_Map_u_32_ui_generator_instance_None(arr[4]),
+patterns: dco_decode_Map_u_32_ui_pattern_None(arr[5]),
+mixer: dco_decode_ui_mixer_state(arr[6]),
+maxSampleIndex: dco_decode_u_32(arr[7]),
+audioSources: dco_decode_Map_u_32_audio_waveform_ui_for_source_list_None(arr[8]),); }
+
+@protected UiAudioDeviceInfo dco_decode_ui_audio_device_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UiAudioDeviceInfo(name: dco_decode_String(arr[0]),
+isDefault: dco_decode_bool(arr[1]),
+sampleRates: dco_decode_list_prim_u_32_strict(arr[2]),
+channelCounts: dco_decode_list_prim_u_16_strict(arr[3]),
+bufferSizes: dco_decode_list_prim_u_32_strict(arr[4]),); }
+
+@protected UiAudioDeviceSettings dco_decode_ui_audio_device_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UiAudioDeviceSettings(host: dco_decode_opt_String(arr[0]),
+outputDevice: dco_decode_opt_String(arr[1]),
+inputDevice: dco_decode_opt_String(arr[2]),
+sampleRate: dco_decode_opt_box_autoadd_u_32(arr[3]),
+bufferSize: dco_decode_opt_box_autoadd_u_32(arr[4]),); }
+
+@protected UiAudioHardwareConfig dco_decode_ui_audio_hardware_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UiAudioHardwareConfig(selectedInputDevice: dco_decode_String(arr[0]),
+selectedOutputDevice: dco_decode_String(arr[1]),
+sampleRate: dco_decode_u_32(arr[2]),
+bufferSize: dco_decode_u_32(arr[3]),
+cpuLoad: dco_decode_f_32(arr[4]),); }
+
+@protected UiBitPerSample dco_decode_ui_bit_per_sample(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiBitPerSample.values[raw as int]; }
+
+@protected UiBus dco_decode_ui_bus(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiBus(id: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+channel: dco_decode_ui_mixer_channel(arr[2]),); }
+
+@protected UiChordSpan dco_decode_ui_chord_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return UiChordSpan(startTick: dco_decode_CastedPrimitive_u_64(arr[0]),
+endTick: dco_decode_CastedPrimitive_u_64(arr[1]),
+root: dco_decode_u_8(arr[2]),
+chordType: dco_decode_ui_chord_type(arr[3]),
+bass: dco_decode_opt_box_autoadd_u_8(arr[4]),
+name: dco_decode_String(arr[5]),); }
+
+@protected UiChordType dco_decode_ui_chord_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiChordType.values[raw as int]; }
+
+@protected UiClip dco_decode_ui_clip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return UiClip(name: dco_decode_String(arr[0]),
+id: dco_decode_u_32(arr[1]),
+startTime: dco_decode_u_32(arr[2]),
+source: dco_decode_ui_clip_source(arr[3]),
+offsetStart: dco_decode_u_32(arr[4]),
+loopLength: dco_decode_u_32(arr[5]),); }
+
+@protected UiClipSource dco_decode_ui_clip_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UiClipSource_Audio(sourceId: dco_decode_u_32(raw[1]),);
+case 1: return UiClipSource_Midi(patternId: dco_decode_u_32(raw[1]),);
+case 2: return UiClipSource_None();
+                default: throw Exception("unreachable");
+            } }
+
+@protected UiClipboardContent dco_decode_ui_clipboard_content(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UiClipboardContent_Empty();
+case 1: return UiClipboardContent_Notes(dco_decode_list_ui_note(raw[1]),);
+case 2: return UiClipboardContent_Clips(dco_decode_list_ui_clip(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected UiEffectInstance dco_decode_ui_effect_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiEffectInstance(id: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+parameters: dco_decode_Map_u_32_f_32_None(arr[2]),); }
+
+@protected UiEffectParameterSnapshot dco_decode_ui_effect_parameter_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiEffectParameterSnapshot(target: dco_decode_ui_effect_target(arr[0]),
+effectId: dco_decode_u_32(arr[1]),
+parameters: dco_decode_list_ui_parameter_value(arr[2]),); }
+
+@protected UiEffectSummary dco_decode_ui_effect_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiEffectSummary(id: dco_decode_u_32(arr[0]),
+registryId: dco_decode_u_32(arr[1]),
+name: dco_decode_String(arr[2]),); }
+
+@protected UiEffectTarget dco_decode_ui_effect_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UiEffectTarget_Track(dco_decode_u_32(raw[1]),);
+case 1: return UiEffectTarget_Master();
+case 2: return UiEffectTarget_Bus(dco_decode_u_32(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected UiEngineLoad dco_decode_ui_engine_load(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return UiEngineLoad(load: dco_decode_f_32(arr[0]),
+average: dco_decode_f_32(arr[1]),
+peak: dco_decode_f_32(arr[2]),
+maxPeak: dco_decode_f_32(arr[3]),
+overloads: dco_decode_CastedPrimitive_u_64(arr[4]),
+xruns: dco_decode_CastedPrimitive_u_64(arr[5]),
+plugins: dco_decode_list_ui_plugin_load(arr[6]),); }
+
+@protected UiGeneratorInstance dco_decode_ui_generator_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UiGeneratorInstance(id: dco_decode_u_32(arr[0]),
+instanceType: dco_decode_ui_generator_instance_type(arr[1]),); }
+
+@protected UiGeneratorInstanceType dco_decode_ui_generator_instance_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UiGeneratorInstanceType_Plugin(dco_decode_box_autoadd_ui_plugin_instance(raw[1]),);
+case 1: return UiGeneratorInstanceType_Sampler(assetId: dco_decode_u_32(raw[1]),rootNote: dco_decode_u_8(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected UiGeneratorParameterSnapshot dco_decode_ui_generator_parameter_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UiGeneratorParameterSnapshot(generatorId: dco_decode_u_32(arr[0]),
+parameters: dco_decode_list_ui_parameter_value(arr[1]),); }
+
+@protected UiGroove dco_decode_ui_groove(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UiGroove(name: dco_decode_String(arr[0]),
+stepTicks: dco_decode_CastedPrimitive_u_64(arr[1]),
+timings: dco_decode_list_prim_i_32_strict(arr[2]),
+velocities: dco_decode_list_prim_f_32_strict(arr[3]),
+amount: dco_decode_f_32(arr[4]),); }
+
+@protected UiLoopRegion dco_decode_ui_loop_region(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UiLoopRegion(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),); }
+
+@protected UiLoudnessReport dco_decode_ui_loudness_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return UiLoudnessReport(momentary: dco_decode_f_32(arr[0]),
+shortTerm: dco_decode_f_32(arr[1]),
+integrated: dco_decode_f_32(arr[2]),
+loudnessRange: dco_decode_f_32(arr[3]),
+truePeak: dco_decode_f_32(arr[4]),
+maxMomentary: dco_decode_f_32(arr[5]),
+maxShortTerm: dco_decode_f_32(arr[6]),); }
+
+@protected UiMeterLevels dco_decode_ui_meter_levels(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return UiMeterLevels(node: dco_decode_ui_routing_node(arr[0]),
+peakLeft: dco_decode_f_32(arr[1]),
+peakRight: dco_decode_f_32(arr[2]),
+rmsLeft: dco_decode_f_32(arr[3]),
+rmsRight: dco_decode_f_32(arr[4]),
+peakHoldLeft: dco_decode_f_32(arr[5]),
+peakHoldRight: dco_decode_f_32(arr[6]),
+clipped: dco_decode_bool(arr[7]),); }
+
+@protected UiMetronomeSettings dco_decode_ui_metronome_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return UiMetronomeSettings(enabled: dco_decode_bool(arr[0]),
+volume: dco_decode_f_32(arr[1]),
+targetBusId: dco_decode_opt_box_autoadd_u_32(arr[2]),
+countInBars: dco_decode_u_8(arr[3]),); }
+
+@protected UiMidiOutputSettings dco_decode_ui_midi_output_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiMidiOutputSettings(sendClock: dco_decode_bool(arr[0]),
+trackId: dco_decode_opt_box_autoadd_u_32(arr[1]),
+channel: dco_decode_u_8(arr[2]),); }
+
+@protected UiMidiRecordMode dco_decode_ui_midi_record_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiMidiRecordMode.values[raw as int]; }
+
+@protected UiMixerChannel dco_decode_ui_mixer_channel(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return UiMixerChannel(volume: dco_decode_f_32(arr[0]),
+pan: dco_decode_f_32(arr[1]),
+mute: dco_decode_bool(arr[2]),
+solo: dco_decode_bool(arr[3]),
+invertedPhase: dco_decode_bool(arr[4]),
+effects: dco_decode_list_ui_effect_summary(arr[5]),); }
+
+@protected UiMixerChannelParams dco_decode_ui_mixer_channel_params(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UiMixerChannelParams_Volume(dco_decode_f_32(raw[1]),);
+case 1: return UiMixerChannelParams_Pan(dco_decode_f_32(raw[1]),);
+case 2: return UiMixerChannelParams_Mute(dco_decode_bool(raw[1]),);
+case 3: return UiMixerChannelParams_InvertedPhase(dco_decode_bool(raw[1]),);
+case 4: return UiMixerChannelParams_Solo(dco_decode_bool(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected UiMixerParamEvent dco_decode_ui_mixer_param_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UiMixerParamEvent(trackId: dco_decode_u_32(arr[0]),
+volume: dco_decode_opt_box_autoadd_f_32(arr[1]),
+pan: dco_decode_opt_box_autoadd_f_32(arr[2]),
+mute: dco_decode_opt_box_autoadd_bool(arr[3]),
+solo: dco_decode_opt_box_autoadd_bool(arr[4]),); }
+
+@protected UiMixerState dco_decode_ui_mixer_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return UiMixerState.raw(channels: dco_decode_Map_u_32_ui_mixer_channel_None(arr[0]),
+masterBus: dco_decode_ui_mixer_channel(arr[1]),
+buses: dco_decode_Map_u_32_ui_bus_None(arr[2]),
+routing: dco_decode_list_ui_routing_connection(arr[3]),); }
+
+@protected UiMonitorMode dco_decode_ui_monitor_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiMonitorMode.values[raw as int]; }
+
+@protected UiNote dco_decode_ui_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return UiNote(id: dco_decode_u_32(arr[0]),
+startTick: dco_decode_CastedPrimitive_u_64(arr[1]),
+duration: dco_decode_CastedPrimitive_u_64(arr[2]),
+key: dco_decode_u_8(arr[3]),
+velocity: dco_decode_u_8(arr[4]),
+probability: dco_decode_f_32(arr[5]),
+microOffset: dco_decode_i_8(arr[6]),
+mute: dco_decode_bool(arr[7]),); }
+
+@protected UiNoteTransform dco_decode_ui_note_transform(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UiNoteTransform_Transpose(semitones: dco_decode_i_16(raw[1]),foldOctaves: dco_decode_bool(raw[2]),);
+case 1: return UiNoteTransform_ScaleTranspose(degrees: dco_decode_i_32(raw[1]),root: dco_decode_u_8(raw[2]),mode: dco_decode_ui_scale_mode(raw[3]),);
+case 2: return UiNoteTransform_VelocityScale(factor: dco_decode_f_32(raw[1]),);
+case 3: return UiNoteTransform_VelocityCompress(amount: dco_decode_f_32(raw[1]),);
+case 4: return UiNoteTransform_VelocityRandomize(amount: dco_decode_u_8(raw[1]),);
+case 5: return UiNoteTransform_Legato();
+case 6: return UiNoteTransform_Reverse();
+case 7: return UiNoteTransform_Invert(pivot: dco_decode_u_8(raw[1]),);
+case 8: return UiNoteTransform_TimeStretch(factor: dco_decode_f_32(raw[1]),);
+case 9: return UiNoteTransform_Humanize(timing: dco_decode_CastedPrimitive_u_64(raw[1]),velocity: dco_decode_u_8(raw[2]),);
+case 10: return UiNoteTransform_SplitOverlaps();
+case 11: return UiNoteTransform_MergeOverlaps();
+case 12: return UiNoteTransform_SnapToScale(root: dco_decode_u_8(raw[1]),mode: dco_decode_ui_scale_mode(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected UiParameterType dco_decode_ui_parameter_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiParameterType.values[raw as int]; }
+
+@protected UiParameterValue dco_decode_ui_parameter_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UiParameterValue(paramId: dco_decode_u_32(arr[0]),
+value: dco_decode_f_32(arr[1]),); }
+
+@protected UiPattern dco_decode_ui_pattern(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return UiPattern(id: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+lengthTicks: dco_decode_CastedPrimitive_u_64(arr[2]),
+notes: dco_decode_list_ui_note(arr[3]),); }
+
+@protected UiPluginInfo dco_decode_ui_plugin_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiPluginInfo(id: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+pluginType: dco_decode_karbeat_plugin_type(arr[2]),); }
+
+@protected UiPluginInstance dco_decode_ui_plugin_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return UiPluginInstance(registryId: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+bypass: dco_decode_bool(arr[2]),
+parameters: dco_decode_Map_u_32_f_32_None(arr[3]),); }
+
+@protected UiPluginLoad dco_decode_ui_plugin_load(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiPluginLoad(slot: dco_decode_ui_plugin_slot(arr[0]),
+average: dco_decode_f_32(arr[1]),
+peak: dco_decode_f_32(arr[2]),); }
+
+@protected UiPluginParameter dco_decode_ui_plugin_parameter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return UiPluginParameter(id: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+group: dco_decode_String(arr[2]),
+value: dco_decode_f_32(arr[3]),
+min: dco_decode_f_32(arr[4]),
+max: dco_decode_f_32(arr[5]),
+defaultValue: dco_decode_f_32(arr[6]),
+step: dco_decode_f_32(arr[7]),
+paramType: dco_decode_ui_parameter_type(arr[8]),
+choices: dco_decode_list_String(arr[9]),); }
+
+@protected UiPluginSlot dco_decode_ui_plugin_slot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UiPluginSlot_Generator(dco_decode_u_32(raw[1]),);
+case 1: return UiPluginSlot_Effect(node: dco_decode_box_autoadd_ui_routing_node(raw[1]),effectId: dco_decode_u_32(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected UiProjectMetadata dco_decode_ui_project_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return UiProjectMetadata(name: dco_decode_String(arr[0]),
+author: dco_decode_String(arr[1]),
+version: dco_decode_String(arr[2]),
+createdAt: dco_decode_String(arr[3]),); }
+
+@protected UiQuantizeSettings dco_decode_ui_quantize_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return UiQuantizeSettings(noteValue: dco_decode_u_32(arr[0]),
+triplet: dco_decode_bool(arr[1]),
+strength: dco_decode_f_32(arr[2]),
+window: dco_decode_f_32(arr[3]),
+quantizeEnds: dco_decode_bool(arr[4]),
+swing: dco_decode_f_32(arr[5]),); }
+
+@protected UiRecordedClip dco_decode_ui_recorded_clip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UiRecordedClip(trackId: dco_decode_u_32(arr[0]),
+clip: dco_decode_ui_clip(arr[1]),); }
+
+@protected UiResizeEdge dco_decode_ui_resize_edge(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiResizeEdge.values[raw as int]; }
+
+@protected UiResponseCurvePoint dco_decode_ui_response_curve_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UiResponseCurvePoint(frequency: dco_decode_f_32(arr[0]),
+magnitudeDb: dco_decode_f_32(arr[1]),); }
+
+@protected UiRoutingConnection dco_decode_ui_routing_connection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return UiRoutingConnection(source: dco_decode_ui_routing_node(arr[0]),
+destination: dco_decode_ui_routing_node(arr[1]),
+sendLevel: dco_decode_f_32(arr[2]),
+isSend: dco_decode_bool(arr[3]),); }
+
+@protected UiRoutingNode dco_decode_ui_routing_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UiRoutingNode_Track(dco_decode_u_32(raw[1]),);
+case 1: return UiRoutingNode_Bus(dco_decode_u_32(raw[1]),);
+case 2: return UiRoutingNode_Master();
+                default: throw Exception("unreachable");
+            } }
+
+@protected UiScale dco_decode_ui_scale(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiScale(root: dco_decode_u_8(arr[0]),
+mode: dco_decode_ui_scale_mode(arr[1]),
+name: dco_decode_String(arr[2]),); }
+
+@protected UiScaleMode dco_decode_ui_scale_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiScaleMode.values[raw as int]; }
+
+@protected UiSourceType dco_decode_ui_source_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiSourceType.values[raw as int]; }
+
+@protected UiSwingDivision dco_decode_ui_swing_division(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiSwingDivision.values[raw as int]; }
+
+@protected UiTempoPoint dco_decode_ui_tempo_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiTempoPoint(tick: dco_decode_CastedPrimitive_u_64(arr[0]),
+bpm: dco_decode_f_32(arr[1]),
+linear: dco_decode_bool(arr[2]),); }
+
+@protected UiTimeSignatureEvent dco_decode_ui_time_signature_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UiTimeSignatureEvent(bar: dco_decode_u_32(arr[0]),
+numerator: dco_decode_u_8(arr[1]),
+denominator: dco_decode_u_8(arr[2]),); }
+
+@protected UiTrack dco_decode_ui_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return UiTrack(id: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+color: dco_decode_String(arr[2]),
+trackType: dco_decode_ui_track_type(arr[3]),
+clips: dco_decode_list_ui_clip(arr[4]),
+generatorId: dco_decode_opt_box_autoadd_u_32(arr[5]),); }
+
+@protected UiTrackType dco_decode_ui_track_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiTrackType.values[raw as int]; }
+
+@protected UiTransportFeedback dco_decode_ui_transport_feedback(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return UiTransportFeedback(samples: dco_decode_u_32(arr[0]),
+beat: dco_decode_CastedPrimitive_usize(arr[1]),
+bar: dco_decode_CastedPrimitive_usize(arr[2]),
+tempo: dco_decode_f_32(arr[3]),
+sampleRate: dco_decode_u_32(arr[4]),
+isPlaying: dco_decode_bool(arr[5]),
+isLooping: dco_decode_bool(arr[6]),
+isRecording: dco_decode_bool(arr[7]),
+isPatternPlaying: dco_decode_bool(arr[8]),
+isPatternMode: dco_decode_bool(arr[9]),
+patternSamples: dco_decode_u_32(arr[10]),
+patternBeat: dco_decode_CastedPrimitive_usize(arr[11]),
+patternBar: dco_decode_CastedPrimitive_usize(arr[12]),); }
+
+@protected UiTransportState dco_decode_ui_transport_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UiTransportState(bpm: dco_decode_f_32(arr[0]),
+timeSignature: dco_decode_record_u_8_u_8(arr[1]),); }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_64(deserializer);
+        return inner.toInt(); }
+
+@protected int sse_decode_CastedPrimitive_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_u_64(deserializer);
+        return inner.toInt(); }
+
+@protected int sse_decode_CastedPrimitive_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_usize(deserializer);
+        return inner.toInt(); }
+
+@protected Map<int, AudioWaveformUiForClip> sse_decode_Map_u_32_audio_waveform_ui_for_clip_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_u_32_audio_waveform_ui_for_clip(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, AudioWaveformUiForSourceList> sse_decode_Map_u_32_audio_waveform_ui_for_source_list_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_u_32_audio_waveform_ui_for_source_list(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, double> sse_decode_Map_u_32_f_32_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_u_32_f_32(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiBus> sse_decode_Map_u_32_ui_bus_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_u_32_ui_bus(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiGeneratorInstance> sse_decode_Map_u_32_ui_generator_instance_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_u_32_ui_generator_instance(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiMixerChannel> sse_decode_Map_u_32_ui_mixer_channel_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_u_32_ui_mixer_channel(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiPattern> sse_decode_Map_u_32_ui_pattern_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_u_32_ui_pattern(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<int, UiTrack> sse_decode_Map_u_32_ui_track_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_u_32_ui_track(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected RustStreamSink<List<UiMeterLevels>> sse_decode_StreamSink_list_ui_meter_levels_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<List<UiResponseCurvePoint>> sse_decode_StreamSink_list_ui_response_curve_point_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<UiMixerParamEvent> sse_decode_StreamSink_ui_mixer_param_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<UiTransportFeedback> sse_decode_StreamSink_ui_transport_feedback_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AudioWaveformUiForAudioProperties sse_decode_audio_waveform_ui_for_audio_properties(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_previewBuffer = sse_decode_list_prim_i_8_strict(deserializer);
+var var_filePath = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_channels = sse_decode_u_16(deserializer);
+var var_duration = sse_decode_f_64(deserializer);
+var var_rootNote = sse_decode_u_8(deserializer);
+var var_fineTune = sse_decode_i_16(deserializer);
+var var_trimStart = sse_decode_u_32(deserializer);
+var var_trimEnd = sse_decode_u_32(deserializer);
+var var_isLooping = sse_decode_bool(deserializer);
+var var_normalized = sse_decode_bool(deserializer);
+var var_muted = sse_decode_bool(deserializer);
+return AudioWaveformUiForAudioProperties(previewBuffer: var_previewBuffer, filePath: var_filePath, name: var_name, sampleRate: var_sampleRate, channels: var_channels, duration: var_duration, rootNote: var_rootNote, fineTune: var_fineTune, trimStart: var_trimStart, trimEnd: var_trimEnd, isLooping: var_isLooping, normalized: var_normalized, muted: var_muted); }
+
+@protected AudioWaveformUiForClip sse_decode_audio_waveform_ui_for_clip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_previewBuffer = sse_decode_list_prim_i_8_strict(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_channels = sse_decode_u_16(deserializer);
+var var_duration = sse_decode_f_64(deserializer);
+return AudioWaveformUiForClip(name: var_name, previewBuffer: var_previewBuffer, sampleRate: var_sampleRate, channels: var_channels, duration: var_duration); }
+
+@protected AudioWaveformUiForSourceList sse_decode_audio_waveform_ui_for_source_list(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_muted = sse_decode_bool(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+return AudioWaveformUiForSourceList(name: var_name, muted: var_muted, sampleRate: var_sampleRate); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AudioWaveformUiForAudioProperties sse_decode_box_autoadd_audio_waveform_ui_for_audio_properties(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_audio_waveform_ui_for_audio_properties(deserializer)); }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_32(deserializer)); }
+
+@protected (int,int) sse_decode_box_autoadd_record_u_8_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_record_u_8_u_8(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected UiAudioDeviceSettings sse_decode_box_autoadd_ui_audio_device_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_audio_device_settings(deserializer)); }
+
+@protected UiEffectTarget sse_decode_box_autoadd_ui_effect_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_effect_target(deserializer)); }
+
+@protected UiEngineLoad sse_decode_box_autoadd_ui_engine_load(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_engine_load(deserializer)); }
+
+@protected UiGroove sse_decode_box_autoadd_ui_groove(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_groove(deserializer)); }
+
+@protected UiLoopRegion sse_decode_box_autoadd_ui_loop_region(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_loop_region(deserializer)); }
+
+@protected UiLoudnessReport sse_decode_box_autoadd_ui_loudness_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_loudness_report(deserializer)); }
+
+@protected UiMidiOutputSettings sse_decode_box_autoadd_ui_midi_output_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_midi_output_settings(deserializer)); }
+
+@protected UiMixerChannel sse_decode_box_autoadd_ui_mixer_channel(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_mixer_channel(deserializer)); }
+
+@protected UiNoteTransform sse_decode_box_autoadd_ui_note_transform(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_note_transform(deserializer)); }
+
+@protected UiPluginInstance sse_decode_box_autoadd_ui_plugin_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_plugin_instance(deserializer)); }
+
+@protected UiQuantizeSettings sse_decode_box_autoadd_ui_quantize_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_quantize_settings(deserializer)); }
+
+@protected UiRoutingNode sse_decode_box_autoadd_ui_routing_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_routing_node(deserializer)); }
+
+@protected UiScale sse_decode_box_autoadd_ui_scale(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_scale(deserializer)); }
+
+@protected UiScaleMode sse_decode_box_autoadd_ui_scale_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_scale_mode(deserializer)); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected int sse_decode_i_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt16(); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected int sse_decode_i_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt8(); }
+
+@protected KarbeatPluginType sse_decode_karbeat_plugin_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return KarbeatPluginType.values[inner]; }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ParameterSpecDTO> sse_decode_list_parameter_spec_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ParameterSpecDTO>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_parameter_spec_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt32List(len_); }
+
+@protected Int8List sse_decode_list_prim_i_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt8List(len_); }
+
+@protected Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint16List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<(int,AudioWaveformUiForClip)> sse_decode_list_record_u_32_audio_waveform_ui_for_clip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,AudioWaveformUiForClip)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_audio_waveform_ui_for_clip(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(int,AudioWaveformUiForSourceList)> sse_decode_list_record_u_32_audio_waveform_ui_for_source_list(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,AudioWaveformUiForSourceList)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_audio_waveform_ui_for_source_list(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(int,double)> sse_decode_list_record_u_32_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,double)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_f_32(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(int,UiBus)> sse_decode_list_record_u_32_ui_bus(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,UiBus)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_ui_bus(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(int,UiGeneratorInstance)> sse_decode_list_record_u_32_ui_generator_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,UiGeneratorInstance)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_ui_generator_instance(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(int,UiMixerChannel)> sse_decode_list_record_u_32_ui_mixer_channel(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,UiMixerChannel)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_ui_mixer_channel(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(int,UiPattern)> sse_decode_list_record_u_32_ui_pattern(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,UiPattern)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_ui_pattern(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(int,UiTrack)> sse_decode_list_record_u_32_ui_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,UiTrack)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_ui_track(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiAudioDeviceInfo> sse_decode_list_ui_audio_device_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiAudioDeviceInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_audio_device_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiChordSpan> sse_decode_list_ui_chord_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiChordSpan>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_chord_span(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiClip> sse_decode_list_ui_clip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiClip>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_clip(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiEffectInstance> sse_decode_list_ui_effect_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiEffectInstance>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_effect_instance(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiEffectParameterSnapshot> sse_decode_list_ui_effect_parameter_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiEffectParameterSnapshot>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_effect_parameter_snapshot(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiEffectSummary> sse_decode_list_ui_effect_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiEffectSummary>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_effect_summary(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiGeneratorParameterSnapshot> sse_decode_list_ui_generator_parameter_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiGeneratorParameterSnapshot>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_generator_parameter_snapshot(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiMeterLevels> sse_decode_list_ui_meter_levels(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiMeterLevels>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_meter_levels(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiMixerChannelParams> sse_decode_list_ui_mixer_channel_params(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiMixerChannelParams>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_mixer_channel_params(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiNote> sse_decode_list_ui_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiNote>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_note(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiParameterValue> sse_decode_list_ui_parameter_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiParameterValue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_parameter_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiPluginInfo> sse_decode_list_ui_plugin_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiPluginInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_plugin_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiPluginLoad> sse_decode_list_ui_plugin_load(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiPluginLoad>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_plugin_load(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiPluginParameter> sse_decode_list_ui_plugin_parameter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiPluginParameter>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_plugin_parameter(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiRecordedClip> sse_decode_list_ui_recorded_clip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiRecordedClip>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_recorded_clip(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiResponseCurvePoint> sse_decode_list_ui_response_curve_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiResponseCurvePoint>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_response_curve_point(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiRoutingConnection> sse_decode_list_ui_routing_connection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiRoutingConnection>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_routing_connection(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiTempoPoint> sse_decode_list_ui_tempo_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiTempoPoint>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_tempo_point(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UiTimeSignatureEvent> sse_decode_list_ui_time_signature_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UiTimeSignatureEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ui_time_signature_event(deserializer)); }
+        return ans_;
+         }
+
+@protected int? sse_decode_opt_CastedPrimitive_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_CastedPrimitive_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_CastedPrimitive_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_CastedPrimitive_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Map<int, AudioWaveformUiForSourceList>? sse_decode_opt_Map_u_32_audio_waveform_ui_for_source_list_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_Map_u_32_audio_waveform_ui_for_source_list_None(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected AudioWaveformUiForAudioProperties? sse_decode_opt_box_autoadd_audio_waveform_ui_for_audio_properties(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_audio_waveform_ui_for_audio_properties(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UiEngineLoad? sse_decode_opt_box_autoadd_ui_engine_load(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ui_engine_load(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UiGroove? sse_decode_opt_box_autoadd_ui_groove(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ui_groove(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UiLoopRegion? sse_decode_opt_box_autoadd_ui_loop_region(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ui_loop_region(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UiLoudnessReport? sse_decode_opt_box_autoadd_ui_loudness_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ui_loudness_report(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UiScale? sse_decode_opt_box_autoadd_ui_scale(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ui_scale(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UiScaleMode? sse_decode_opt_box_autoadd_ui_scale_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ui_scale_mode(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<ParameterSpecDTO>? sse_decode_opt_list_parameter_spec_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_parameter_spec_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ParameterSpecDTO sse_decode_parameter_spec_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_group = sse_decode_String(deserializer);
+var var_value = sse_decode_f_32(deserializer);
+var var_min = sse_decode_f_32(deserializer);
+var var_max = sse_decode_f_32(deserializer);
+var var_defaultValue = sse_decode_f_32(deserializer);
+var var_step = sse_decode_f_32(deserializer);
+var var_valueType = sse_decode_parameter_value_type_dto(deserializer);
+var var_choices = sse_decode_list_String(deserializer);
+return ParameterSpecDTO(id: var_id, name: var_name, group: var_group, value: var_value, min: var_min, max: var_max, defaultValue: var_defaultValue, step: var_step, valueType: var_valueType, choices: var_choices); }
+
+@protected ParameterValueTypeDTO sse_decode_parameter_value_type_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ParameterValueTypeDTO.values[inner]; }
+
+@protected (int,AudioWaveformUiForClip) sse_decode_record_u_32_audio_waveform_ui_for_clip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_audio_waveform_ui_for_clip(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,AudioWaveformUiForSourceList) sse_decode_record_u_32_audio_waveform_ui_for_source_list(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_audio_waveform_ui_for_source_list(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,double) sse_decode_record_u_32_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_f_32(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,UiBus) sse_decode_record_u_32_ui_bus(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_ui_bus(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,UiGeneratorInstance) sse_decode_record_u_32_ui_generator_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_ui_generator_instance(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,UiMixerChannel) sse_decode_record_u_32_ui_mixer_channel(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_ui_mixer_channel(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,UiPattern) sse_decode_record_u_32_ui_pattern(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_ui_pattern(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,UiTrack) sse_decode_record_u_32_ui_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_ui_track(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,int) sse_decode_record_u_8_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_8(deserializer);
+var var_field1 = sse_decode_u_8(deserializer);
+return (var_field0, var_field1); }
+
+@protected (UiMixerChannel,List<UiEffectInstance>) sse_decode_record_ui_mixer_channel_list_ui_effect_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_ui_mixer_channel(deserializer);
+var var_field1 = sse_decode_list_ui_effect_instance(deserializer);
+return (var_field0, var_field1); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected UiApplicationState sse_decode_ui_application_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_metadata = sse_decode_ui_project_metadata(deserializer);
+var var_transport = sse_decode_ui_transport_state(deserializer);
+var var_hardwareConfig = sse_decode_ui_audio_hardware_config(deserializer);
+var var_tracks = sse_decode_Map_u_32_ui_track_None(deserializer);
+var var_generators = sse_decode_Map_u_32_ui_generator_instance_None(deserializer);
+var var_patterns = sse_decode_Map_u_32_ui_pattern_None(deserializer);
+var var_mixer = sse_decode_ui_mixer_state(deserializer);
+var var_maxSampleIndex = sse_decode_u_32(deserializer);
+var var_audioSources = sse_decode_Map_u_32_audio_waveform_ui_for_source_list_None(deserializer);
+return UiApplicationState(metadata: var_metadata, transport: var_transport, hardwareConfig: var_hardwareConfig, tracks: var_tracks, generators: var_generators, patterns: var_patterns, mixer: var_mixer, maxSampleIndex: var_maxSampleIndex, audioSources: var_audioSources); }
+
+@protected UiAudioDeviceInfo sse_decode_ui_audio_device_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_isDefault = sse_decode_bool(deserializer);
+var var_sampleRates = sse_decode_list_prim_u_32_strict(deserializer);
+var var_channelCounts = sse_decode_list_prim_u_16_strict(deserializer);
+var var_bufferSizes = sse_decode_list_prim_u_32_strict(deserializer);
+return UiAudioDeviceInfo(name: var_name, isDefault: var_isDefault, sampleRates: var_sampleRates, channelCounts: var_channelCounts, bufferSizes: var_bufferSizes); }
+
+@protected UiAudioDeviceSettings sse_decode_ui_audio_device_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_host = sse_decode_opt_String(deserializer);
+var var_outputDevice = sse_decode_opt_String(deserializer);
+var var_inputDevice = sse_decode_opt_String(deserializer);
+var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_bufferSize = sse_decode_opt_box_autoadd_u_32(deserializer);
+return UiAudioDeviceSettings(host: var_host, outputDevice: var_outputDevice, inputDevice: var_inputDevice, sampleRate: var_sampleRate, bufferSize: var_bufferSize); }
+
+@protected UiAudioHardwareConfig sse_decode_ui_audio_hardware_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_selectedInputDevice = sse_decode_String(deserializer);
+var var_selectedOutputDevice = sse_decode_String(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_bufferSize = sse_decode_u_32(deserializer);
+var var_cpuLoad = sse_decode_f_32(deserializer);
+return UiAudioHardwareConfig(selectedInputDevice: var_selectedInputDevice, selectedOutputDevice: var_selectedOutputDevice, sampleRate: var_sampleRate, bufferSize: var_bufferSize, cpuLoad: var_cpuLoad); }
+
+@protected UiBitPerSample sse_decode_ui_bit_per_sample(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiBitPerSample.values[inner]; }
+
+@protected UiBus sse_decode_ui_bus(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_channel = sse_decode_ui_mixer_channel(deserializer);
+return UiBus(id: var_id, name: var_name, channel: var_channel); }
+
+@protected UiChordSpan sse_decode_ui_chord_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startTick = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_endTick = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_root = sse_decode_u_8(deserializer);
+var var_chordType = sse_decode_ui_chord_type(deserializer);
+var var_bass = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_name = sse_decode_String(deserializer);
+return UiChordSpan(startTick: var_startTick, endTick: var_endTick, root: var_root, chordType: var_chordType, bass: var_bass, name: var_name); }
+
+@protected UiChordType sse_decode_ui_chord_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiChordType.values[inner]; }
+
+@protected UiClip sse_decode_ui_clip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_id = sse_decode_u_32(deserializer);
+var var_startTime = sse_decode_u_32(deserializer);
+var var_source = sse_decode_ui_clip_source(deserializer);
+var var_offsetStart = sse_decode_u_32(deserializer);
+var var_loopLength = sse_decode_u_32(deserializer);
+return UiClip(name: var_name, id: var_id, startTime: var_startTime, source: var_source, offsetStart: var_offsetStart, loopLength: var_loopLength); }
+
+@protected UiClipSource sse_decode_ui_clip_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_sourceId = sse_decode_u_32(deserializer);
+return UiClipSource_Audio(sourceId: var_sourceId);case 1: var var_patternId = sse_decode_u_32(deserializer);
+return UiClipSource_Midi(patternId: var_patternId);case 2: return UiClipSource_None(); default: throw UnimplementedError(''); }
+             }
+
+@protected UiClipboardContent sse_decode_ui_clipboard_content(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return UiClipboardContent_Empty();case 1: var var_field0 = sse_decode_list_ui_note(deserializer);
+return UiClipboardContent_Notes(var_field0);case 2: var var_field0 = sse_decode_list_ui_clip(deserializer);
+return UiClipboardContent_Clips(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected UiEffectInstance sse_decode_ui_effect_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_parameters = sse_decode_Map_u_32_f_32_None(deserializer);
+return UiEffectInstance(id: var_id, name: var_name, parameters: var_parameters); }
+
+@protected UiEffectParameterSnapshot sse_decode_ui_effect_parameter_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_target = sse_decode_ui_effect_target(deserializer);
+var var_effectId = sse_decode_u_32(deserializer);
+var var_parameters = sse_decode_list_ui_parameter_value(deserializer);
+return UiEffectParameterSnapshot(target: var_target, effectId: var_effectId, parameters: var_parameters); }
+
+@protected UiEffectSummary sse_decode_ui_effect_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_registryId = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+return UiEffectSummary(id: var_id, registryId: var_registryId, name: var_name); }
+
+@protected UiEffectTarget sse_decode_ui_effect_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_u_32(deserializer);
+return UiEffectTarget_Track(var_field0);case 1: return UiEffectTarget_Master();case 2: var var_field0 = sse_decode_u_32(deserializer);
+return UiEffectTarget_Bus(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected UiEngineLoad sse_decode_ui_engine_load(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_load = sse_decode_f_32(deserializer);
+var var_average = sse_decode_f_32(deserializer);
+var var_peak = sse_decode_f_32(deserializer);
+var var_maxPeak = sse_decode_f_32(deserializer);
+var var_overloads = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_xruns = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_plugins = sse_decode_list_ui_plugin_load(deserializer);
+return UiEngineLoad(load: var_load, average: var_average, peak: var_peak, maxPeak: var_maxPeak, overloads: var_overloads, xruns: var_xruns, plugins: var_plugins); }
+
+@protected UiGeneratorInstance sse_decode_ui_generator_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_instanceType = sse_decode_ui_generator_instance_type(deserializer);
+return UiGeneratorInstance(id: var_id, instanceType: var_instanceType); }
+
+@protected UiGeneratorInstanceType sse_decode_ui_generator_instance_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_box_autoadd_ui_plugin_instance(deserializer);
+return UiGeneratorInstanceType_Plugin(var_field0);case 1: var var_assetId = sse_decode_u_32(deserializer);
+var var_rootNote = sse_decode_u_8(deserializer);
+return UiGeneratorInstanceType_Sampler(assetId: var_assetId, rootNote: var_rootNote); default: throw UnimplementedError(''); }
+             }
+
+@protected UiGeneratorParameterSnapshot sse_decode_ui_generator_parameter_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_generatorId = sse_decode_u_32(deserializer);
+var var_parameters = sse_decode_list_ui_parameter_value(deserializer);
+return UiGeneratorParameterSnapshot(generatorId: var_generatorId, parameters: var_parameters); }
+
+@protected UiGroove sse_decode_ui_groove(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_stepTicks = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_timings = sse_decode_list_prim_i_32_strict(deserializer);
+var var_velocities = sse_decode_list_prim_f_32_strict(deserializer);
+var var_amount = sse_decode_f_32(deserializer);
+return UiGroove(name: var_name, stepTicks: var_stepTicks, timings: var_timings, velocities: var_velocities, amount: var_amount); }
+
+@protected UiLoopRegion sse_decode_ui_loop_region(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+return UiLoopRegion(start: var_start, end: var_end); }
+
+@protected UiLoudnessReport sse_decode_ui_loudness_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_momentary = sse_decode_f_32(deserializer);
+var var_shortTerm = sse_decode_f_32(deserializer);
+var var_integrated = sse_decode_f_32(deserializer);
+var var_loudnessRange = sse_decode_f_32(deserializer);
+var var_truePeak = sse_decode_f_32(deserializer);
+var var_maxMomentary = sse_decode_f_32(deserializer);
+var var_maxShortTerm = sse_decode_f_32(deserializer);
+return UiLoudnessReport(momentary: var_momentary, shortTerm: var_shortTerm, integrated: var_integrated, loudnessRange: var_loudnessRange, truePeak: var_truePeak, maxMomentary: var_maxMomentary, maxShortTerm: var_maxShortTerm); }
+
+@protected UiMeterLevels sse_decode_ui_meter_levels(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_node = sse_decode_ui_routing_node(deserializer);
+var var_peakLeft = sse_decode_f_32(deserializer);
+var var_peakRight = sse_decode_f_32(deserializer);
+var var_rmsLeft = sse_decode_f_32(deserializer);
+var var_rmsRight = sse_decode_f_32(deserializer);
+var var_peakHoldLeft = sse_decode_f_32(deserializer);
+var var_peakHoldRight = sse_decode_f_32(deserializer);
+var var_clipped = sse_decode_bool(deserializer);
+return UiMeterLevels(node: var_node, peakLeft: var_peakLeft, peakRight: var_peakRight, rmsLeft: var_rmsLeft, rmsRight: var_rmsRight, peakHoldLeft: var_peakHoldLeft, peakHoldRight: var_peakHoldRight, clipped: var_clipped); }
+
+@protected UiMetronomeSettings sse_decode_ui_metronome_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_enabled = sse_decode_bool(deserializer);
+var var_volume = sse_decode_f_32(deserializer);
+var var_targetBusId = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_countInBars = sse_decode_u_8(deserializer);
+return UiMetronomeSettings(enabled: var_enabled, volume: var_volume, targetBusId: var_targetBusId, countInBars: var_countInBars); }
+
+@protected UiMidiOutputSettings sse_decode_ui_midi_output_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sendClock = sse_decode_bool(deserializer);
+var var_trackId = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_channel = sse_decode_u_8(deserializer);
+return UiMidiOutputSettings(sendClock: var_sendClock, trackId: var_trackId, channel: var_channel); }
+
+@protected UiMidiRecordMode sse_decode_ui_midi_record_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiMidiRecordMode.values[inner]; }
+
+@protected UiMixerChannel sse_decode_ui_mixer_channel(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_volume = sse_decode_f_32(deserializer);
+var var_pan = sse_decode_f_32(deserializer);
+var var_mute = sse_decode_bool(deserializer);
+var var_solo = sse_decode_bool(deserializer);
+var var_invertedPhase = sse_decode_bool(deserializer);
+var var_effects = sse_decode_list_ui_effect_summary(deserializer);
+return UiMixerChannel(volume: var_volume, pan: var_pan, mute: var_mute, solo: var_solo, invertedPhase: var_invertedPhase, effects: var_effects); }
+
+@protected UiMixerChannelParams sse_decode_ui_mixer_channel_params(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_f_32(deserializer);
+return UiMixerChannelParams_Volume(var_field0);case 1: var var_field0 = sse_decode_f_32(deserializer);
+return UiMixerChannelParams_Pan(var_field0);case 2: var var_field0 = sse_decode_bool(deserializer);
+return UiMixerChannelParams_Mute(var_field0);case 3: var var_field0 = sse_decode_bool(deserializer);
+return UiMixerChannelParams_InvertedPhase(var_field0);case 4: var var_field0 = sse_decode_bool(deserializer);
+return UiMixerChannelParams_Solo(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected UiMixerParamEvent sse_decode_ui_mixer_param_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_trackId = sse_decode_u_32(deserializer);
+var var_volume = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_pan = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_mute = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_solo = sse_decode_opt_box_autoadd_bool(deserializer);
+return UiMixerParamEvent(trackId: var_trackId, volume: var_volume, pan: var_pan, mute: var_mute, solo: var_solo); }
+
+@protected UiMixerState sse_decode_ui_mixer_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_channels = sse_decode_Map_u_32_ui_mixer_channel_None(deserializer);
+var var_masterBus = sse_decode_ui_mixer_channel(deserializer);
+var var_buses = sse_decode_Map_u_32_ui_bus_None(deserializer);
+var var_routing = sse_decode_list_ui_routing_connection(deserializer);
+return UiMixerState.raw(channels: var_channels, masterBus: var_masterBus, buses: var_buses, routing: var_routing); }
+
+@protected UiMonitorMode sse_decode_ui_monitor_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiMonitorMode.values[inner]; }
+
+@protected UiNote sse_decode_ui_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_startTick = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_duration = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_key = sse_decode_u_8(deserializer);
+var var_velocity = sse_decode_u_8(deserializer);
+var var_probability = sse_decode_f_32(deserializer);
+var var_microOffset = sse_decode_i_8(deserializer);
+var var_mute = sse_decode_bool(deserializer);
+return UiNote(id: var_id, startTick: var_startTick, duration: var_duration, key: var_key, velocity: var_velocity, probability: var_probability, microOffset: var_microOffset, mute: var_mute); }
+
+@protected UiNoteTransform sse_decode_ui_note_transform(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_semitones = sse_decode_i_16(deserializer);
+var var_foldOctaves = sse_decode_bool(deserializer);
+return UiNoteTransform_Transpose(semitones: var_semitones, foldOctaves: var_foldOctaves);case 1: var var_degrees = sse_decode_i_32(deserializer);
+var var_root = sse_decode_u_8(deserializer);
+var var_mode = sse_decode_ui_scale_mode(deserializer);
+return UiNoteTransform_ScaleTranspose(degrees: var_degrees, root: var_root, mode: var_mode);case 2: var var_factor = sse_decode_f_32(deserializer);
+return UiNoteTransform_VelocityScale(factor: var_factor);case 3: var var_amount = sse_decode_f_32(deserializer);
+return UiNoteTransform_VelocityCompress(amount: var_amount);case 4: var var_amount = sse_decode_u_8(deserializer);
+return UiNoteTransform_VelocityRandomize(amount: var_amount);case 5: return UiNoteTransform_Legato();case 6: return UiNoteTransform_Reverse();case 7: var var_pivot = sse_decode_u_8(deserializer);
+return UiNoteTransform_Invert(pivot: var_pivot);case 8: var var_factor = sse_decode_f_32(deserializer);
+return UiNoteTransform_TimeStretch(factor: var_factor);case 9: var var_timing = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_velocity = sse_decode_u_8(deserializer);
+return UiNoteTransform_Humanize(timing: var_timing, velocity: var_velocity);case 10: return UiNoteTransform_SplitOverlaps();case 11: return UiNoteTransform_MergeOverlaps();case 12: var var_root = sse_decode_u_8(deserializer);
+var var_mode = sse_decode_ui_scale_mode(deserializer);
+return UiNoteTransform_SnapToScale(root: var_root, mode: var_mode); default: throw UnimplementedError(''); }
+             }
+
+@protected UiParameterType sse_decode_ui_parameter_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiParameterType.values[inner]; }
+
+@protected UiParameterValue sse_decode_ui_parameter_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_paramId = sse_decode_u_32(deserializer);
+var var_value = sse_decode_f_32(deserializer);
+return UiParameterValue(paramId: var_paramId, value: var_value); }
+
+@protected UiPattern sse_decode_ui_pattern(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_lengthTicks = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_notes = sse_decode_list_ui_note(deserializer);
+return UiPattern(id: var_id, name: var_name, lengthTicks: var_lengthTicks, notes: var_notes); }
+
+@protected UiPluginInfo sse_decode_ui_plugin_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_pluginType = sse_decode_karbeat_plugin_type(deserializer);
+return UiPluginInfo(id: var_id, name: var_name, pluginType: var_pluginType); }
+
+@protected UiPluginInstance sse_decode_ui_plugin_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_registryId = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_bypass = sse_decode_bool(deserializer);
+var var_parameters = sse_decode_Map_u_32_f_32_None(deserializer);
+return UiPluginInstance(registryId: var_registryId, name: var_name, bypass: var_bypass, parameters: var_parameters); }
+
+@protected UiPluginLoad sse_decode_ui_plugin_load(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slot = sse_decode_ui_plugin_slot(deserializer);
+var var_average = sse_decode_f_32(deserializer);
+var var_peak = sse_decode_f_32(deserializer);
+return UiPluginLoad(slot: var_slot, average: var_average, peak: var_peak); }
+
+@protected UiPluginParameter sse_decode_ui_plugin_parameter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_group = sse_decode_String(deserializer);
+var var_value = sse_decode_f_32(deserializer);
+var var_min = sse_decode_f_32(deserializer);
+var var_max = sse_decode_f_32(deserializer);
+var var_defaultValue = sse_decode_f_32(deserializer);
+var var_step = sse_decode_f_32(deserializer);
+var var_paramType = sse_decode_ui_parameter_type(deserializer);
+var var_choices = sse_decode_list_String(deserializer);
+return UiPluginParameter(id: var_id, name: var_name, group: var_group, value: var_value, min: var_min, max: var_max, defaultValue: var_defaultValue, step: var_step, paramType: var_paramType, choices: var_choices); }
+
+@protected UiPluginSlot sse_decode_ui_plugin_slot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_u_32(deserializer);
+return UiPluginSlot_Generator(var_field0);case 1: var var_node = sse_decode_box_autoadd_ui_routing_node(deserializer);
+var var_effectId = sse_decode_u_32(deserializer);
+return UiPluginSlot_Effect(node: var_node, effectId: var_effectId); default: throw UnimplementedError(''); }
+             }
+
+@protected UiProjectMetadata sse_decode_ui_project_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_author = sse_decode_String(deserializer);
+var var_version = sse_decode_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return UiProjectMetadata(name: var_name, author: var_author, version: var_version, createdAt: var_createdAt); }
+
+@protected UiQuantizeSettings sse_decode_ui_quantize_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_noteValue = sse_decode_u_32(deserializer);
+var var_triplet = sse_decode_bool(deserializer);
+var var_strength = sse_decode_f_32(deserializer);
+var var_window = sse_decode_f_32(deserializer);
+var var_quantizeEnds = sse_decode_bool(deserializer);
+var var_swing = sse_decode_f_32(deserializer);
+return UiQuantizeSettings(noteValue: var_noteValue, triplet: var_triplet, strength: var_strength, window: var_window, quantizeEnds: var_quantizeEnds, swing: var_swing); }
+
+@protected UiRecordedClip sse_decode_ui_recorded_clip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_trackId = sse_decode_u_32(deserializer);
+var var_clip = sse_decode_ui_clip(deserializer);
+return UiRecordedClip(trackId: var_trackId, clip: var_clip); }
+
+@protected UiResizeEdge sse_decode_ui_resize_edge(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiResizeEdge.values[inner]; }
+
+@protected UiResponseCurvePoint sse_decode_ui_response_curve_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_frequency = sse_decode_f_32(deserializer);
+var var_magnitudeDb = sse_decode_f_32(deserializer);
+return UiResponseCurvePoint(frequency: var_frequency, magnitudeDb: var_magnitudeDb); }
+
+@protected UiRoutingConnection sse_decode_ui_routing_connection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_ui_routing_node(deserializer);
+var var_destination = sse_decode_ui_routing_node(deserializer);
+var var_sendLevel = sse_decode_f_32(deserializer);
+var var_isSend = sse_decode_bool(deserializer);
+return UiRoutingConnection(source: var_source, destination: var_destination, sendLevel: var_sendLevel, isSend: var_isSend); }
+
+@protected UiRoutingNode sse_decode_ui_routing_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_u_32(deserializer);
+return UiRoutingNode_Track(var_field0);case 1: var var_field0 = sse_decode_u_32(deserializer);
+return UiRoutingNode_Bus(var_field0);case 2: return UiRoutingNode_Master(); default: throw UnimplementedError(''); }
+             }
+
+@protected UiScale sse_decode_ui_scale(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_root = sse_decode_u_8(deserializer);
+var var_mode = sse_decode_ui_scale_mode(deserializer);
+var var_name = sse_decode_String(deserializer);
+return UiScale(root: var_root, mode: var_mode, name: var_name); }
+
+@protected UiScaleMode sse_decode_ui_scale_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiScaleMode.values[inner]; }
+
+@protected UiSourceType sse_decode_ui_source_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiSourceType.values[inner]; }
+
+@protected UiSwingDivision sse_decode_ui_swing_division(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiSwingDivision.values[inner]; }
+
+@protected UiTempoPoint sse_decode_ui_tempo_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tick = sse_decode_CastedPrimitive_u_64(deserializer);
+var var_bpm = sse_decode_f_32(deserializer);
+var var_linear = sse_decode_bool(deserializer);
+return UiTempoPoint(tick: var_tick, bpm: var_bpm, linear: var_linear); }
+
+@protected UiTimeSignatureEvent sse_decode_ui_time_signature_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bar = sse_decode_u_32(deserializer);
+var var_numerator = sse_decode_u_8(deserializer);
+var var_denominator = sse_decode_u_8(deserializer);
+return UiTimeSignatureEvent(bar: var_bar, numerator: var_numerator, denominator: var_denominator); }
+
+@protected UiTrack sse_decode_ui_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_color = sse_decode_String(deserializer);
+var var_trackType = sse_decode_ui_track_type(deserializer);
+var var_clips = sse_decode_list_ui_clip(deserializer);
+var var_generatorId = sse_decode_opt_box_autoadd_u_32(deserializer);
+return UiTrack(id: var_id, name: var_name, color: var_color, trackType: var_trackType, clips: var_clips, generatorId: var_generatorId); }
+
+@protected UiTrackType sse_decode_ui_track_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiTrackType.values[inner]; }
+
+@protected UiTransportFeedback sse_decode_ui_transport_feedback(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_samples = sse_decode_u_32(deserializer);
+var var_beat = sse_decode_CastedPrimitive_usize(deserializer);
+var var_bar = sse_decode_CastedPrimitive_usize(deserializer);
+var var_tempo = sse_decode_f_32(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_isPlaying = sse_decode_bool(deserializer);
+var var_isLooping = sse_decode_bool(deserializer);
+var var_isRecording = sse_decode_bool(deserializer);
+var var_isPatternPlaying = sse_decode_bool(deserializer);
+var var_isPatternMode = sse_decode_bool(deserializer);
+var var_patternSamples = sse_decode_u_32(deserializer);
+var var_patternBeat = sse_decode_CastedPrimitive_usize(deserializer);
+var var_patternBar = sse_decode_CastedPrimitive_usize(deserializer);
+return UiTransportFeedback(samples: var_samples, beat: var_beat, bar: var_bar, tempo: var_tempo, sampleRate: var_sampleRate, isPlaying: var_isPlaying, isLooping: var_isLooping, isRecording: var_isRecording, isPatternPlaying: var_isPatternPlaying, isPatternMode: var_isPatternMode, patternSamples: var_patternSamples, patternBeat: var_patternBeat, patternBar: var_patternBar); }
+
+@protected UiTransportState sse_decode_ui_transport_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bpm = sse_decode_f_32(deserializer);
+var var_timeSignature = sse_decode_record_u_8_u_8(deserializer);
+return UiTransportState(bpm: var_bpm, timeSignature: var_timeSignature); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(sseEncodeCastedPrimitiveI64(self), serializer); }
+
+@protected void sse_encode_CastedPrimitive_u_64(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(sseEncodeCastedPrimitiveU64(self), serializer); }
+
+@protected void sse_encode_CastedPrimitive_usize(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(sseEncodeCastedPrimitiveU64(self), serializer); }
+
+@protected void sse_encode_Map_u_32_audio_waveform_ui_for_clip_None(Map<int, AudioWaveformUiForClip> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_audio_waveform_ui_for_clip(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_u_32_audio_waveform_ui_for_source_list_None(Map<int, AudioWaveformUiForSourceList> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_audio_waveform_ui_for_source_list(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_u_32_f_32_None(Map<int, double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_f_32(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_u_32_ui_bus_None(Map<int, UiBus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_ui_bus(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_u_32_ui_generator_instance_None(Map<int, UiGeneratorInstance> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_ui_generator_instance(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_u_32_ui_mixer_channel_None(Map<int, UiMixerChannel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_ui_mixer_channel(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_u_32_ui_pattern_None(Map<int, UiPattern> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_ui_pattern(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_u_32_ui_track_None(Map<int, UiTrack> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_ui_track(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_StreamSink_list_ui_meter_levels_Sse(RustStreamSink<List<UiMeterLevels>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_list_ui_meter_levels,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_list_ui_response_curve_point_Sse(RustStreamSink<List<UiResponseCurvePoint>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_list_ui_response_curve_point,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_ui_mixer_param_event_Sse(RustStreamSink<UiMixerParamEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_ui_mixer_param_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_ui_transport_feedback_Sse(RustStreamSink<UiTransportFeedback> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_ui_transport_feedback,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_audio_waveform_ui_for_audio_properties(AudioWaveformUiForAudioProperties self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_i_8_strict(self.previewBuffer, serializer);
+sse_encode_String(self.filePath, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_u_16(self.channels, serializer);
+sse_encode_f_64(self.duration, serializer);
+sse_encode_u_8(self.rootNote, serializer);
+sse_encode_i_16(self.fineTune, serializer);
+sse_encode_u_32(self.trimStart, serializer);
+sse_encode_u_32(self.trimEnd, serializer);
+sse_encode_bool(self.isLooping, serializer);
+sse_encode_bool(self.normalized, serializer);
+sse_encode_bool(self.muted, serializer);
+ }
+
+@protected void sse_encode_audio_waveform_ui_for_clip(AudioWaveformUiForClip self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_list_prim_i_8_strict(self.previewBuffer, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_u_16(self.channels, serializer);
+sse_encode_f_64(self.duration, serializer);
+ }
+
+@protected void sse_encode_audio_waveform_ui_for_source_list(AudioWaveformUiForSourceList self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_bool(self.muted, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_audio_waveform_ui_for_audio_properties(AudioWaveformUiForAudioProperties self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_audio_waveform_ui_for_audio_properties(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_record_u_8_u_8((int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_u_8_u_8(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_audio_device_settings(UiAudioDeviceSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_audio_device_settings(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_effect_target(UiEffectTarget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_effect_target(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_engine_load(UiEngineLoad self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_engine_load(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_groove(UiGroove self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_groove(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_loop_region(UiLoopRegion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_loop_region(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_loudness_report(UiLoudnessReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_loudness_report(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_midi_output_settings(UiMidiOutputSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_midi_output_settings(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_mixer_channel(UiMixerChannel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_mixer_channel(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_note_transform(UiNoteTransform self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_note_transform(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_plugin_instance(UiPluginInstance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_plugin_instance(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_quantize_settings(UiQuantizeSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_quantize_settings(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_routing_node(UiRoutingNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_routing_node(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_scale(UiScale self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_scale(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_scale_mode(UiScaleMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_scale_mode(self, serializer); }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_i_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt16(self); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_i_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt8(self); }
+
+@protected void sse_encode_karbeat_plugin_type(KarbeatPluginType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_parameter_spec_dto(List<ParameterSpecDTO> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_parameter_spec_dto(item, serializer); } }
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self); }
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt32List(self); }
+
+@protected void sse_encode_list_prim_i_8_strict(Int8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt8List(self); }
+
+@protected void sse_encode_list_prim_u_16_strict(Uint16List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint16List(self); }
+
+@protected void sse_encode_list_prim_u_32_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self is Uint32List ? self : Uint32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_record_u_32_audio_waveform_ui_for_clip(List<(int,AudioWaveformUiForClip)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_audio_waveform_ui_for_clip(item, serializer); } }
+
+@protected void sse_encode_list_record_u_32_audio_waveform_ui_for_source_list(List<(int,AudioWaveformUiForSourceList)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_audio_waveform_ui_for_source_list(item, serializer); } }
+
+@protected void sse_encode_list_record_u_32_f_32(List<(int,double)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_f_32(item, serializer); } }
+
+@protected void sse_encode_list_record_u_32_ui_bus(List<(int,UiBus)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_ui_bus(item, serializer); } }
+
+@protected void sse_encode_list_record_u_32_ui_generator_instance(List<(int,UiGeneratorInstance)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_ui_generator_instance(item, serializer); } }
+
+@protected void sse_encode_list_record_u_32_ui_mixer_channel(List<(int,UiMixerChannel)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_ui_mixer_channel(item, serializer); } }
+
+@protected void sse_encode_list_record_u_32_ui_pattern(List<(int,UiPattern)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_ui_pattern(item, serializer); } }
+
+@protected void sse_encode_list_record_u_32_ui_track(List<(int,UiTrack)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_ui_track(item, serializer); } }
+
+@protected void sse_encode_list_ui_audio_device_info(List<UiAudioDeviceInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_audio_device_info(item, serializer); } }
+
+@protected void sse_encode_list_ui_chord_span(List<UiChordSpan> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_chord_span(item, serializer); } }
+
+@protected void sse_encode_list_ui_clip(List<UiClip> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_clip(item, serializer); } }
+
+@protected void sse_encode_list_ui_effect_instance(List<UiEffectInstance> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_effect_instance(item, serializer); } }
+
+@protected void sse_encode_list_ui_effect_parameter_snapshot(List<UiEffectParameterSnapshot> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_effect_parameter_snapshot(item, serializer); } }
+
+@protected void sse_encode_list_ui_effect_summary(List<UiEffectSummary> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_effect_summary(item, serializer); } }
+
+@protected void sse_encode_list_ui_generator_parameter_snapshot(List<UiGeneratorParameterSnapshot> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_generator_parameter_snapshot(item, serializer); } }
+
+@protected void sse_encode_list_ui_meter_levels(List<UiMeterLevels> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_meter_levels(item, serializer); } }
+
+@protected void sse_encode_list_ui_mixer_channel_params(List<UiMixerChannelParams> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_mixer_channel_params(item, serializer); } }
+
+@protected void sse_encode_list_ui_note(List<UiNote> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_note(item, serializer); } }
+
+@protected void sse_encode_list_ui_parameter_value(List<UiParameterValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_parameter_value(item, serializer); } }
+
+@protected void sse_encode_list_ui_plugin_info(List<UiPluginInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_plugin_info(item, serializer); } }
+
+@protected void sse_encode_list_ui_plugin_load(List<UiPluginLoad> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_plugin_load(item, serializer); } }
+
+@protected void sse_encode_list_ui_plugin_parameter(List<UiPluginParameter> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_plugin_parameter(item, serializer); } }
+
+@protected void sse_encode_list_ui_recorded_clip(List<UiRecordedClip> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_recorded_clip(item, serializer); } }
+
+@protected void sse_encode_list_ui_response_curve_point(List<UiResponseCurvePoint> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_response_curve_point(item, serializer); } }
+
+@protected void sse_encode_list_ui_routing_connection(List<UiRoutingConnection> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_routing_connection(item, serializer); } }
+
+@protected void sse_encode_list_ui_tempo_point(List<UiTempoPoint> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_tempo_point(item, serializer); } }
+
+@protected void sse_encode_list_ui_time_signature_event(List<UiTimeSignatureEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ui_time_signature_event(item, serializer); } }
+
+@protected void sse_encode_opt_CastedPrimitive_i_64(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_CastedPrimitive_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_CastedPrimitive_u_64(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_CastedPrimitive_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_Map_u_32_audio_waveform_ui_for_source_list_None(Map<int, AudioWaveformUiForSourceList>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_Map_u_32_audio_waveform_ui_for_source_list_None(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_audio_waveform_ui_for_audio_properties(AudioWaveformUiForAudioProperties? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_audio_waveform_ui_for_audio_properties(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ui_engine_load(UiEngineLoad? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ui_engine_load(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ui_groove(UiGroove? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ui_groove(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ui_loop_region(UiLoopRegion? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ui_loop_region(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ui_loudness_report(UiLoudnessReport? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ui_loudness_report(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ui_scale(UiScale? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ui_scale(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ui_scale_mode(UiScaleMode? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ui_scale_mode(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_parameter_spec_dto(List<ParameterSpecDTO>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_parameter_spec_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_parameter_spec_dto(ParameterSpecDTO self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.group, serializer);
+sse_encode_f_32(self.value, serializer);
+sse_encode_f_32(self.min, serializer);
+sse_encode_f_32(self.max, serializer);
+sse_encode_f_32(self.defaultValue, serializer);
+sse_encode_f_32(self.step, serializer);
+sse_encode_parameter_value_type_dto(self.valueType, serializer);
+sse_encode_list_String(self.choices, serializer);
+ }
+
+@protected void sse_encode_parameter_value_type_dto(ParameterValueTypeDTO self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_record_u_32_audio_waveform_ui_for_clip((int,AudioWaveformUiForClip) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_audio_waveform_ui_for_clip(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_audio_waveform_ui_for_source_list((int,AudioWaveformUiForSourceList) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_audio_waveform_ui_for_source_list(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_f_32((int,double) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_f_32(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_ui_bus((int,UiBus) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_ui_bus(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_ui_generator_instance((int,UiGeneratorInstance) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_ui_generator_instance(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_ui_mixer_channel((int,UiMixerChannel) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_ui_mixer_channel(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_ui_pattern((int,UiPattern) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_ui_pattern(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_ui_track((int,UiTrack) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_ui_track(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_8_u_8((int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.$1, serializer);
+sse_encode_u_8(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_ui_mixer_channel_list_ui_effect_instance((UiMixerChannel,List<UiEffectInstance>) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_mixer_channel(self.$1, serializer);
+sse_encode_list_ui_effect_instance(self.$2, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_ui_application_state(UiApplicationState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_project_metadata(self.metadata, serializer);
+sse_encode_ui_transport_state(self.transport, serializer);
+sse_encode_ui_audio_hardware_config(self.hardwareConfig, serializer);
+sse_encode_Map_u_32_ui_track_None(self.tracks, serializer);
+sse_encode_Map_u_32_ui_generator_instance_None(self.generators, serializer);
+sse_encode_Map_u_32_ui_pattern_None(self.patterns, serializer);
+sse_encode_ui_mixer_state(self.mixer, serializer);
+sse_encode_u_32(self.maxSampleIndex, serializer);
+sse_encode_Map_u_32_audio_waveform_ui_for_source_list_None(self.audioSources, serializer);
+ }
+
+@protected void sse_encode_ui_audio_device_info(UiAudioDeviceInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_bool(self.isDefault, serializer);
+sse_encode_list_prim_u_32_strict(self.sampleRates, serializer);
+sse_encode_list_prim_u_16_strict(self.channelCounts, serializer);
+sse_encode_list_prim_u_32_strict(self.bufferSizes, serializer);
+ }
+
+@protected void sse_encode_ui_audio_device_settings(UiAudioDeviceSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.host, serializer);
+sse_encode_opt_String(self.outputDevice, serializer);
+sse_encode_opt_String(self.inputDevice, serializer);
+sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
+sse_encode_opt_box_autoadd_u_32(self.bufferSize, serializer);
+ }
+
+@protected void sse_encode_ui_audio_hardware_config(UiAudioHardwareConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.selectedInputDevice, serializer);
+sse_encode_String(self.selectedOutputDevice, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_u_32(self.bufferSize, serializer);
+sse_encode_f_32(self.cpuLoad, serializer);
+ }
+
+@protected void sse_encode_ui_bit_per_sample(UiBitPerSample self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_bus(UiBus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_ui_mixer_channel(self.channel, serializer);
+ }
+
+@protected void sse_encode_ui_chord_span(UiChordSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_CastedPrimitive_u_64(self.startTick, serializer);
+sse_encode_CastedPrimitive_u_64(self.endTick, serializer);
+sse_encode_u_8(self.root, serializer);
+sse_encode_ui_chord_type(self.chordType, serializer);
+sse_encode_opt_box_autoadd_u_8(self.bass, serializer);
+sse_encode_String(self.name, serializer);
+ }
+
+@protected void sse_encode_ui_chord_type(UiChordType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_clip(UiClip self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.id, serializer);
+sse_encode_u_32(self.startTime, serializer);
+sse_encode_ui_clip_source(self.source, serializer);
+sse_encode_u_32(self.offsetStart, serializer);
+sse_encode_u_32(self.loopLength, serializer);
+ }
+
+@protected void sse_encode_ui_clip_source(UiClipSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UiClipSource_Audio(sourceId: final sourceId): sse_encode_i_32(0, serializer); sse_encode_u_32(sourceId, serializer);
+case UiClipSource_Midi(patternId: final patternId): sse_encode_i_32(1, serializer); sse_encode_u_32(patternId, serializer);
+case UiClipSource_None(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_ui_clipboard_content(UiClipboardContent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UiClipboardContent_Empty(): sse_encode_i_32(0, serializer); case UiClipboardContent_Notes(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_list_ui_note(field0, serializer);
+case UiClipboardContent_Clips(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_list_ui_clip(field0, serializer);
+  } }
+
+@protected void sse_encode_ui_effect_instance(UiEffectInstance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_Map_u_32_f_32_None(self.parameters, serializer);
+ }
+
+@protected void sse_encode_ui_effect_parameter_snapshot(UiEffectParameterSnapshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_effect_target(self.target, serializer);
+sse_encode_u_32(self.effectId, serializer);
+sse_encode_list_ui_parameter_value(self.parameters, serializer);
+ }
+
+@protected void sse_encode_ui_effect_summary(UiEffectSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_u_32(self.registryId, serializer);
+sse_encode_String(self.name, serializer);
+ }
+
+@protected void sse_encode_ui_effect_target(UiEffectTarget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UiEffectTarget_Track(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_u_32(field0, serializer);
+case UiEffectTarget_Master(): sse_encode_i_32(1, serializer); case UiEffectTarget_Bus(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_u_32(field0, serializer);
+  } }
+
+@protected void sse_encode_ui_engine_load(UiEngineLoad self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.load, serializer);
+sse_encode_f_32(self.average, serializer);
+sse_encode_f_32(self.peak, serializer);
+sse_encode_f_32(self.maxPeak, serializer);
+sse_encode_CastedPrimitive_u_64(self.overloads, serializer);
+sse_encode_CastedPrimitive_u_64(self.xruns, serializer);
+sse_encode_list_ui_plugin_load(self.plugins, serializer);
+ }
+
+@protected void sse_encode_ui_generator_instance(UiGeneratorInstance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_ui_generator_instance_type(self.instanceType, serializer);
+ }
+
+@protected void sse_encode_ui_generator_instance_type(UiGeneratorInstanceType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UiGeneratorInstanceType_Plugin(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_ui_plugin_instance(field0, serializer);
+case UiGeneratorInstanceType_Sampler(assetId: final assetId,rootNote: final rootNote): sse_encode_i_32(1, serializer); sse_encode_u_32(assetId, serializer);
+sse_encode_u_8(rootNote, serializer);
+  } }
+
+@protected void sse_encode_ui_generator_parameter_snapshot(UiGeneratorParameterSnapshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.generatorId, serializer);
+sse_encode_list_ui_parameter_value(self.parameters, serializer);
+ }
+
+@protected void sse_encode_ui_groove(UiGroove self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_CastedPrimitive_u_64(self.stepTicks, serializer);
+sse_encode_list_prim_i_32_strict(self.timings, serializer);
+sse_encode_list_prim_f_32_strict(self.velocities, serializer);
+sse_encode_f_32(self.amount, serializer);
+ }
+
+@protected void sse_encode_ui_loop_region(UiLoopRegion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+ }
+
+@protected void sse_encode_ui_loudness_report(UiLoudnessReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.momentary, serializer);
+sse_encode_f_32(self.shortTerm, serializer);
+sse_encode_f_32(self.integrated, serializer);
+sse_encode_f_32(self.loudnessRange, serializer);
+sse_encode_f_32(self.truePeak, serializer);
+sse_encode_f_32(self.maxMomentary, serializer);
+sse_encode_f_32(self.maxShortTerm, serializer);
+ }
+
+@protected void sse_encode_ui_meter_levels(UiMeterLevels self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_routing_node(self.node, serializer);
+sse_encode_f_32(self.peakLeft, serializer);
+sse_encode_f_32(self.peakRight, serializer);
+sse_encode_f_32(self.rmsLeft, serializer);
+sse_encode_f_32(self.rmsRight, serializer);
+sse_encode_f_32(self.peakHoldLeft, serializer);
+sse_encode_f_32(self.peakHoldRight, serializer);
+sse_encode_bool(self.clipped, serializer);
+ }
+
+@protected void sse_encode_ui_metronome_settings(UiMetronomeSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.enabled, serializer);
+sse_encode_f_32(self.volume, serializer);
+sse_encode_opt_box_autoadd_u_32(self.targetBusId, serializer);
+sse_encode_u_8(self.countInBars, serializer);
+ }
+
+@protected void sse_encode_ui_midi_output_settings(UiMidiOutputSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.sendClock, serializer);
+sse_encode_opt_box_autoadd_u_32(self.trackId, serializer);
+sse_encode_u_8(self.channel, serializer);
+ }
+
+@protected void sse_encode_ui_midi_record_mode(UiMidiRecordMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_mixer_channel(UiMixerChannel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.volume, serializer);
+sse_encode_f_32(self.pan, serializer);
+sse_encode_bool(self.mute, serializer);
+sse_encode_bool(self.solo, serializer);
+sse_encode_bool(self.invertedPhase, serializer);
+sse_encode_list_ui_effect_summary(self.effects, serializer);
+ }
+
+@protected void sse_encode_ui_mixer_channel_params(UiMixerChannelParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UiMixerChannelParams_Volume(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_f_32(field0, serializer);
+case UiMixerChannelParams_Pan(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_f_32(field0, serializer);
+case UiMixerChannelParams_Mute(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_bool(field0, serializer);
+case UiMixerChannelParams_InvertedPhase(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_bool(field0, serializer);
+case UiMixerChannelParams_Solo(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_bool(field0, serializer);
+  } }
+
+@protected void sse_encode_ui_mixer_param_event(UiMixerParamEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.trackId, serializer);
+sse_encode_opt_box_autoadd_f_32(self.volume, serializer);
+sse_encode_opt_box_autoadd_f_32(self.pan, serializer);
+sse_encode_opt_box_autoadd_bool(self.mute, serializer);
+sse_encode_opt_box_autoadd_bool(self.solo, serializer);
+ }
+
+@protected void sse_encode_ui_mixer_state(UiMixerState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Map_u_32_ui_mixer_channel_None(self.channels, serializer);
+sse_encode_ui_mixer_channel(self.masterBus, serializer);
+sse_encode_Map_u_32_ui_bus_None(self.buses, serializer);
+sse_encode_list_ui_routing_connection(self.routing, serializer);
+ }
+
+@protected void sse_encode_ui_monitor_mode(UiMonitorMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_note(UiNote self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_CastedPrimitive_u_64(self.startTick, serializer);
+sse_encode_CastedPrimitive_u_64(self.duration, serializer);
+sse_encode_u_8(self.key, serializer);
+sse_encode_u_8(self.velocity, serializer);
+sse_encode_f_32(self.probability, serializer);
+sse_encode_i_8(self.microOffset, serializer);
+sse_encode_bool(self.mute, serializer);
+ }
+
+@protected void sse_encode_ui_note_transform(UiNoteTransform self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UiNoteTransform_Transpose(semitones: final semitones,foldOctaves: final foldOctaves): sse_encode_i_32(0, serializer); sse_encode_i_16(semitones, serializer);
+sse_encode_bool(foldOctaves, serializer);
+case UiNoteTransform_ScaleTranspose(degrees: final degrees,root: final root,mode: final mode): sse_encode_i_32(1, serializer); sse_encode_i_32(degrees, serializer);
+sse_encode_u_8(root, serializer);
+sse_encode_ui_scale_mode(mode, serializer);
+case UiNoteTransform_VelocityScale(factor: final factor): sse_encode_i_32(2, serializer); sse_encode_f_32(factor, serializer);
+case UiNoteTransform_VelocityCompress(amount: final amount): sse_encode_i_32(3, serializer); sse_encode_f_32(amount, serializer);
+case UiNoteTransform_VelocityRandomize(amount: final amount): sse_encode_i_32(4, serializer); sse_encode_u_8(amount, serializer);
+case UiNoteTransform_Legato(): sse_encode_i_32(5, serializer); case UiNoteTransform_Reverse(): sse_encode_i_32(6, serializer); case UiNoteTransform_Invert(pivot: final pivot): sse_encode_i_32(7, serializer); sse_encode_u_8(pivot, serializer);
+case UiNoteTransform_TimeStretch(factor: final factor): sse_encode_i_32(8, serializer); sse_encode_f_32(factor, serializer);
+case UiNoteTransform_Humanize(timing: final timing,velocity: final velocity): sse_encode_i_32(9, serializer); sse_encode_CastedPrimitive_u_64(timing, serializer);
+sse_encode_u_8(velocity, serializer);
+case UiNoteTransform_SplitOverlaps(): sse_encode_i_32(10, serializer); case UiNoteTransform_MergeOverlaps(): sse_encode_i_32(11, serializer); case UiNoteTransform_SnapToScale(root: final root,mode: final mode): sse_encode_i_32(12, serializer); sse_encode_u_8(root, serializer);
+sse_encode_ui_scale_mode(mode, serializer);
+  } }
+
+@protected void sse_encode_ui_parameter_type(UiParameterType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_parameter_value(UiParameterValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.paramId, serializer);
+sse_encode_f_32(self.value, serializer);
+ }
+
+@protected void sse_encode_ui_pattern(UiPattern self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_CastedPrimitive_u_64(self.lengthTicks, serializer);
+sse_encode_list_ui_note(self.notes, serializer);
+ }
+
+@protected void sse_encode_ui_plugin_info(UiPluginInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_karbeat_plugin_type(self.pluginType, serializer);
+ }
+
+@protected void sse_encode_ui_plugin_instance(UiPluginInstance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.registryId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_bool(self.bypass, serializer);
+sse_encode_Map_u_32_f_32_None(self.parameters, serializer);
+ }
+
+@protected void sse_encode_ui_plugin_load(UiPluginLoad self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_plugin_slot(self.slot, serializer);
+sse_encode_f_32(self.average, serializer);
+sse_encode_f_32(self.peak, serializer);
+ }
+
+@protected void sse_encode_ui_plugin_parameter(UiPluginParameter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.group, serializer);
+sse_encode_f_32(self.value, serializer);
+sse_encode_f_32(self.min, serializer);
+sse_encode_f_32(self.max, serializer);
+sse_encode_f_32(self.defaultValue, serializer);
+sse_encode_f_32(self.step, serializer);
+sse_encode_ui_parameter_type(self.paramType, serializer);
+sse_encode_list_String(self.choices, serializer);
+ }
+
+@protected void sse_encode_ui_plugin_slot(UiPluginSlot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UiPluginSlot_Generator(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_u_32(field0, serializer);
+case UiPluginSlot_Effect(node: final node,effectId: final effectId): sse_encode_i_32(1, serializer); sse_encode_box_autoadd_ui_routing_node(node, serializer);
+sse_encode_u_32(effectId, serializer);
+  } }
+
+@protected void sse_encode_ui_project_metadata(UiProjectMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.author, serializer);
+sse_encode_String(self.version, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_ui_quantize_settings(UiQuantizeSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.noteValue, serializer);
+sse_encode_bool(self.triplet, serializer);
+sse_encode_f_32(self.strength, serializer);
+sse_encode_f_32(self.window, serializer);
+sse_encode_bool(self.quantizeEnds, serializer);
+sse_encode_f_32(self.swing, serializer);
+ }
+
+@protected void sse_encode_ui_recorded_clip(UiRecordedClip self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.trackId, serializer);
+sse_encode_ui_clip(self.clip, serializer);
+ }
+
+@protected void sse_encode_ui_resize_edge(UiResizeEdge self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_response_curve_point(UiResponseCurvePoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.frequency, serializer);
+sse_encode_f_32(self.magnitudeDb, serializer);
+ }
+
+@protected void sse_encode_ui_routing_connection(UiRoutingConnection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_routing_node(self.source, serializer);
+sse_encode_ui_routing_node(self.destination, serializer);
+sse_encode_f_32(self.sendLevel, serializer);
+sse_encode_bool(self.isSend, serializer);
+ }
+
+@protected void sse_encode_ui_routing_node(UiRoutingNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UiRoutingNode_Track(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_u_32(field0, serializer);
+case UiRoutingNode_Bus(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_u_32(field0, serializer);
+case UiRoutingNode_Master(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_ui_scale(UiScale self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.root, serializer);
+sse_encode_ui_scale_mode(self.mode, serializer);
+sse_encode_String(self.name, serializer);
+ }
+
+@protected void sse_encode_ui_scale_mode(UiScaleMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_source_type(UiSourceType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_swing_division(UiSwingDivision self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_tempo_point(UiTempoPoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_CastedPrimitive_u_64(self.tick, serializer);
+sse_encode_f_32(self.bpm, serializer);
+sse_encode_bool(self.linear, serializer);
+ }
+
+@protected void sse_encode_ui_time_signature_event(UiTimeSignatureEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.bar, serializer);
+sse_encode_u_8(self.numerator, serializer);
+sse_encode_u_8(self.denominator, serializer);
+ }
+
+@protected void sse_encode_ui_track(UiTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.color, serializer);
+sse_encode_ui_track_type(self.trackType, serializer);
+sse_encode_list_ui_clip(self.clips, serializer);
+sse_encode_opt_box_autoadd_u_32(self.generatorId, serializer);
+ }
+
+@protected void sse_encode_ui_track_type(UiTrackType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_transport_feedback(UiTransportFeedback self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.samples, serializer);
+sse_encode_CastedPrimitive_usize(self.beat, serializer);
+sse_encode_CastedPrimitive_usize(self.bar, serializer);
+sse_encode_f_32(self.tempo, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_bool(self.isPlaying, serializer);
+sse_encode_bool(self.isLooping, serializer);
+sse_encode_bool(self.isRecording, serializer);
+sse_encode_bool(self.isPatternPlaying, serializer);
+sse_encode_bool(self.isPatternMode, serializer);
+sse_encode_u_32(self.patternSamples, serializer);
+sse_encode_CastedPrimitive_usize(self.patternBeat, serializer);
+sse_encode_CastedPrimitive_usize(self.patternBar, serializer);
+ }
+
+@protected void sse_encode_ui_transport_state(UiTransportState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.bpm, serializer);
+sse_encode_record_u_8_u_8(self.timeSignature, serializer);
+ }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
@@ -0,0 +1,112 @@
+use karbeat_dsp::spectrum::{ MAX_FFT_SIZE, MIN_FFT_SIZE };
+use rtrb::RingBuffer;
+
+use crate::{
+    audio::analyzer::{
+        AnalyzerConfig,
+        AnalyzerHandle,
+        AnalyzerTap,
+        MAX_ANALYZER_TAPS,
+        SpectrumFrame,
+    },
+    commands::AudioCommand,
+    context::{ ctx, utils::{ collect_garbage, send_audio_command } },
+    core::project::mixer::RoutingNode,
+    lock::get_app_read,
+};
+
+/// Seconds of audio a tap buffers for its worker
+const TAP_BUFFER_SECONDS: f32 = 0.5;
+
+/// Starts a spectrum analyzer on a mixer strip (after its effects, volume and pan).
+/// `sink` gets every spectrum and returns false to stop. Analyzers end with the audio stream
+/// they were started on. Returns the analyzer id.
+pub fn start_spectrum_analyzer(
+    node: RoutingNode,
+    mut config: AnalyzerConfig,
+    sink: impl FnMut(SpectrumFrame) -> bool + Send + 'static
+) -> anyhow::Result<u32> {
+    collect_garbage();
+    // The size the analyzer will use, before the ring is sized from it
+    config.fft_size = config.fft_size.clamp(MIN_FFT_SIZE, MAX_FFT_SIZE).next_power_of_two();
+
+    let sample_rate = {
+        let app = get_app_read();
+        let exists = match node {
+            RoutingNode::Track(track_id) => app.mixer.channels.contains_key(&track_id),
+            RoutingNode::Bus(bus_id) => app.mixer.buses.contains_key(&bus_id),
+            RoutingNode::Master => true,
+        };
+        if !exists {
+            return Err(anyhow::anyhow!("Mixer strip {:?} not found", node));
+        }
+        app.audio_config.sample_rate
+    };
+
+    let capacity = (((sample_rate as f32) * TAP_BUFFER_SECONDS) as usize).max(config.fft_size * 2);
+    let (producer, consumer) = RingBuffer::new(capacity);
+
+    let mut analyzers = ctx().spectrum_analyzers.lock();
+    if analyzers.running.len() >= MAX_ANALYZER_TAPS {
+        return Err(anyhow::anyhow!("At most {} spectrum analyzers can run", MAX_ANALYZER_TAPS));
+    }
+    analyzers.counter += 1;
+    let id = analyzers.counter;
+    {
+        let mut sender = ctx().command_sender.lock();
+        let sender = sender
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("No audio stream is running"))?;
+        sender
+            .push(AudioCommand::AddAnalyzerTap(AnalyzerTap { id, node, producer }))
+            .map_err(|_| anyhow::anyhow!("Audio command queue is full"))?;
+    }
+
+    // The worker ending by itself takes its tap and handle along. The lock held here keeps it
+    // from looking for the handle before it is stored
+    let on_exit = Box::new(move || {
+        send_audio_command(AudioCommand::RemoveAnalyzerTap(id));
+        let handle = ctx().spectrum_analyzers.lock().running.remove(&id);
+        drop(handle);
+    });
+    let handle = AnalyzerHandle::spawn(
+        id,
+        node,
+        consumer,
+        config,
+        sample_rate,
+        Box::new(sink),
+        on_exit
+    );
+    match handle {
+        Ok(handle) => {
+            analyzers.running.insert(id, handle);
+            Ok(id)
+        }
+        Err(e) => {
+            send_audio_command(AudioCommand::RemoveAnalyzerTap(id));
+            Err(e)
+        }
+    }
+}
+
+pub fn stop_spectrum_analyzer(analyzer_id: u32) -> anyhow::Result<()> {
+    let handle = ctx()
+        .spectrum_analyzers.lock()
+        .running.remove(&analyzer_id)
+        .ok_or_else(|| anyhow::anyhow!("Spectrum analyzer {} not found", analyzer_id))?;
+    send_audio_command(AudioCommand::RemoveAnalyzerTap(analyzer_id));
+    // Joins the worker
+    drop(handle);
+    collect_garbage();
+    Ok(())
+}
+
+/// Ids and strips of the running analyzers.
+pub fn list_spectrum_analyzers() -> Vec<(u32, RoutingNode)> {
+    let analyzers = ctx().spectrum_analyzers.lock();
+    analyzers.running
+        .iter()
+        .map(|(&id, handle)| (id, handle.node))
+        .collect()
+}
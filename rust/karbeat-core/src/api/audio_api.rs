@@ -1,12 +1,26 @@
+use std::{ path::{ Path, PathBuf }, sync::Arc };
+
+use karbeat_dsp::loudness::LoudnessReport;
+
 use crate::{
-    audio::event::TransportFeedback,
+    api::{ midi_api, project_api, recording_api },
+    audio::{
+        backend::{ self, AudioBackend, AudioDeviceInfo, BUFFER_SIZES },
+        event::TransportFeedback,
+        exporter,
+        load::{ self, EngineLoadReport },
+        writer::{ self, AudioFormat, BitPerSample },
+    },
     commands::AudioCommand,
     context::{ctx, utils::send_audio_command},
     core::{
-        file_manager::audio_loader::AudioLoader,
+        file_manager::{
+            audio_loader::AudioLoader,
+            user_settings::{ AudioDeviceSettings, load_user_settings, save_user_settings },
+        },
         project::{ AudioHardwareConfig, AudioSourceId, AudioWaveform, GeneratorId, TrackId },
     },
-    lock::get_app_read,
+    lock::{ get_app_read, get_app_write },
 };
 
 pub fn get_audio_source<T, F>(id: AudioSourceId, mapper: F) -> Option<T>
@@ -31,9 +45,175 @@ pub fn stop_all_previews() {
     send_audio_command(AudioCommand::StopAllPreviews);
 }
 
+pub fn get_audio_backend_name() -> String {
+    ctx().audio_backend.read().name().to_string()
+}
+
+/// Swaps the audio backend, e.g. for the null backend on a machine without sound hardware.
+/// Takes effect when the audio stream is next started.
+pub fn set_audio_backend(backend: Arc<dyn AudioBackend>) {
+    *ctx().audio_backend.write() = backend;
+}
+
+// =========================================================================
+// Devices
+// =========================================================================
+
+/// Audio hosts (driver APIs) available on this machine.
+pub fn list_audio_hosts() -> Vec<String> {
+    backend::list_hosts()
+}
+
+/// Output devices of `host` (the selected host when `None`) with what they support.
+pub fn list_audio_output_devices(host: Option<&str>) -> anyhow::Result<Vec<AudioDeviceInfo>> {
+    backend::list_output_device_info(host)
+}
+
+/// Input devices of `host` (the selected host when `None`) with what they support.
+pub fn list_audio_input_devices(host: Option<&str>) -> anyhow::Result<Vec<AudioDeviceInfo>> {
+    backend::list_input_device_info(host)
+}
+
+pub fn get_audio_device_settings() -> AudioDeviceSettings {
+    ctx().user_settings.lock().audio.clone()
+}
+
+/// Chooses the host, devices, sample rate and buffer size, and saves them to the user settings
+/// file. A running stream is restarted on them; if that fails the previous settings are put
+/// back and the stream restarted on those.
+pub fn set_audio_device_settings(settings: AudioDeviceSettings) -> anyhow::Result<()> {
+    if let Some(size) = settings.buffer_size {
+        if !BUFFER_SIZES.contains(&size) {
+            return Err(anyhow::anyhow!("Buffer size must be one of {:?}, got {}", BUFFER_SIZES, size));
+        }
+    }
+    if let Some(host) = &settings.host {
+        if !backend::list_hosts().contains(host) {
+            return Err(anyhow::anyhow!("Audio host '{}' is not available", host));
+        }
+    }
+
+    let previous = std::mem::replace(&mut ctx().user_settings.lock().audio, settings.clone());
+    apply_input_device(&settings);
+
+    if ctx().stream_guard.lock().is_some() {
+        if let Err(e) = restart_audio_engine() {
+            ctx().user_settings.lock().audio = previous.clone();
+            apply_input_device(&previous);
+            if let Err(rollback) = restart_audio_engine() {
+                log::error!("Previous audio settings failed to restart too: {:#}", rollback);
+            }
+            return Err(e.context("Audio settings not applied"));
+        }
+    }
+    save_settings()
+}
+
+fn apply_input_device(settings: &AudioDeviceSettings) {
+    get_app_write().audio_config.selected_input_device = settings.input_device
+        .clone()
+        .unwrap_or_default();
+}
+
+/// Remembers the input device the user picked, without touching the running streams.
+pub(crate) fn remember_input_device(name: &str) {
+    ctx().user_settings.lock().audio.input_device = Some(name.to_string());
+    if let Err(e) = save_settings() {
+        log::warn!("{:#}", e);
+    }
+}
+
+/// Points the user settings at another file (e.g. the app's data directory on mobile) and
+/// loads it. Takes effect when the audio stream is next started.
+pub fn set_user_settings_path(path: PathBuf) -> anyhow::Result<()> {
+    let settings = load_user_settings(&path)?;
+    get_app_write().audio_config.selected_input_device = settings.audio.input_device
+        .clone()
+        .unwrap_or_default();
+    *ctx().user_settings.lock() = settings;
+    *ctx().user_settings_path.write() = Some(path);
+    Ok(())
+}
+
+fn save_settings() -> anyhow::Result<()> {
+    let Some(path) = ctx().user_settings_path.read().clone() else {
+        return Ok(());
+    };
+    let settings = ctx().user_settings.lock().clone();
+    save_user_settings(&path, &settings)
+}
+
+/// Restarts the audio engine on the current backend and settings. Plugins are re-created and
+/// prepared at the new sample rate and buffer size, open audio and MIDI ports are reconnected.
+/// A running recording is finished first, the transport comes back stopped.
+pub fn restart_audio_engine() -> anyhow::Result<()> {
+    if recording_api::is_recording() {
+        recording_api::stop_recording()?;
+    }
+
+    let input_open = recording_api::is_input_open();
+    let midi_input = midi_api::get_open_midi_input();
+    let midi_output = midi_api::get_open_midi_output();
+    // The ports feed the old engine, and the input has to match the new rate
+    backend::stop_input_stream();
+    midi_api::close_midi_input();
+    midi_api::close_midi_output();
+
+    backend::start_audio_engine()?;
+    project_api::sync_engine_with_project();
+    send_audio_command(AudioCommand::SetMidiOutputSettings(*ctx().midi_output_settings.lock()));
+
+    if input_open {
+        if let Err(e) = recording_api::open_selected_input() {
+            log::warn!("Audio input not reopened: {:#}", e);
+        }
+    }
+    if let Some(port) = midi_input {
+        if let Err(e) = midi_api::open_midi_input(&port) {
+            log::warn!("MIDI input not reopened: {:#}", e);
+        }
+    }
+    if let Some(port) = midi_output {
+        if let Err(e) = midi_api::open_midi_output(&port) {
+            log::warn!("MIDI output not reopened: {:#}", e);
+        }
+    }
+    Ok(())
+}
+
+/// The audio configuration, with `cpu_load` the engine's smoothed load (0.0 when stopped).
 pub fn get_audio_config<T, F>(mapper: F) -> T where F: FnOnce(&AudioHardwareConfig) -> T {
-    let app = get_app_read();
-    mapper(&app.audio_config)
+    let mut config = get_app_read().audio_config.clone();
+    config.cpu_load = get_engine_load(|report| report.average).unwrap_or_default();
+    mapper(&config)
+}
+
+/// Latest CPU load report of the audio thread, none while no audio stream is running.
+pub fn get_engine_load<T, F>(mapper: F) -> Option<T> where F: FnOnce(&EngineLoadReport) -> T {
+    ctx()
+        .engine_load.lock()
+        .as_mut()
+        .map(|report| mapper(report.read()))
+}
+
+/// Clears the peak, overload and xrun counts of the load report.
+pub fn reset_engine_load() {
+    send_audio_command(AudioCommand::ResetEngineLoad);
+}
+
+/// Plugin latency of the mix in frames: how far what is heard lags the playhead. Takes are
+/// shifted back by it along with the device latency.
+pub fn get_mix_latency() -> u32 {
+    ctx().device_latency.mix()
+}
+
+/// Times every plugin's `process` call so the load report can break the load down by plugin.
+pub fn set_plugin_profiling(enabled: bool) {
+    load::set_plugin_profiling(enabled);
+}
+
+pub fn is_plugin_profiling() -> bool {
+    load::is_plugin_profiling()
 }
 
 /// Drains the position feedback ring buffer and maps it to UI types
@@ -47,6 +227,39 @@ pub fn drain_position_feedback<T, F>(mut mapper: F) -> Vec<T> where F: FnMut(Tra
     results
 }
 
+/// Latest loudness report of the master output, none while no audio stream is running.
+pub fn get_loudness_report<T, F>(mapper: F) -> Option<T> where F: FnOnce(&LoudnessReport) -> T {
+    ctx()
+        .loudness_report.lock()
+        .as_mut()
+        .map(|report| mapper(report.read()))
+}
+
+/// Renders the song into an audio file, its format picked from the extension, and returns the
+/// loudness of what was written. The project is copied first so it stays editable meanwhile.
+pub fn export_project(
+    path: &Path,
+    sample_rate: u32,
+    bit_per_sample: BitPerSample
+) -> anyhow::Result<LoudnessReport> {
+    let app = get_app_read().clone();
+    let format = AudioFormat { sample_rate, channels: 2, bit_per_sample };
+    let writer = writer::create_writer(path, format)?;
+    let report = exporter::export_project(
+        &app,
+        &path.to_string_lossy(),
+        sample_rate,
+        bit_per_sample,
+        writer
+    )?;
+    Ok(report)
+}
+
+/// Starts the loudness analysis of the master output over.
+pub fn reset_loudness_analyzer() {
+    send_audio_command(AudioCommand::ResetLoudness);
+}
+
 pub fn play_preview_note(
     track_id: TrackId,
     note_key: u8,
@@ -2,6 +2,7 @@ use crate::context::utils::broadcast_state_change;
 use crate::core::history::ProjectAction;
 use crate::core::project::clip::{Clip, ClipSourceType, ResizeEdge};
 use crate::core::project::clipboard::ClipboardContent;
+use crate::core::project::musical_time::{GridDivision, SnapMode};
 use crate::lock::{get_app_read, get_app_write, get_history_lock};
 use crate::shared::id::*;
 use std::sync::Arc;
@@ -19,6 +20,13 @@ where
     Ok(mapper(clip.as_ref()))
 }
 
+/// Snaps a timeline position (in ticks) to the grid, following the project time signatures.
+pub fn snap_to_grid(tick: u32, grid: GridDivision, mode: SnapMode) -> u32 {
+    let app = get_app_read();
+    let snapped = app.transport.time_signature_map.snap(tick as u64, grid, mode);
+    snapped.min(u32::MAX as u64) as u32
+}
+
 pub fn add_clip(
     source_id: Option<u32>,
     source_type: ClipSourceType,
@@ -139,7 +147,7 @@ pub fn resize_clip(
 pub fn cut_clip(
     track_id: TrackId,
     clip_id: ClipId,
-    cut_point_sample: u32,
+    cut_point_tick: u32,
 ) -> anyhow::Result<(Clip, Clip)> {
     // 1. Capture old state
     let old_clip = {
@@ -151,7 +159,7 @@ pub fn cut_clip(
     // 2. Mutate state
     let (c1, c2) = {
         let mut app = get_app_write();
-        app.cut_clip(&track_id, &clip_id, cut_point_sample)
+        app.cut_clip(&track_id, &clip_id, cut_point_tick)
             .map_err(|e| anyhow::anyhow!("{}", e))?
     };
 
@@ -210,7 +218,7 @@ pub fn batch_move_clips(
     source_track_id: TrackId,
     target_track_id: TrackId,
     clip_ids: Vec<ClipId>,
-    delta_samples: i64,
+    delta_ticks: i64,
 ) -> anyhow::Result<Vec<Clip>> {
     // 1. Capture old states
     let old_clips = {
@@ -224,7 +232,7 @@ pub fn batch_move_clips(
     // 2. Mutate state
     let modified_clips = {
         let mut app = get_app_write();
-        app.move_clip_batch(source_track_id, target_track_id, clip_ids, delta_samples)
+        app.move_clip_batch(source_track_id, target_track_id, clip_ids, delta_ticks)
             .map_err(|e| anyhow::anyhow!("{}", e))?
     };
 
@@ -258,7 +266,7 @@ pub fn batch_resize_clips(
     track_id: TrackId,
     clip_ids: Vec<ClipId>,
     edge: ResizeEdge,
-    delta_samples: i64,
+    delta_ticks: i64,
 ) -> anyhow::Result<Vec<Clip>> {
     // 1. Capture old states
     let old_clips = {
@@ -272,7 +280,7 @@ pub fn batch_resize_clips(
     // 2. Mutate state
     let modified_clips = {
         let mut app = get_app_write();
-        app.resize_clip_batch(track_id, clip_ids, edge, delta_samples)
+        app.resize_clip_batch(track_id, clip_ids, edge, delta_ticks)
             .map_err(|e| anyhow::anyhow!("{}", e))?
     };
 
@@ -370,6 +378,7 @@ pub fn paste_clips(target_track_id: TrackId, paste_start_time: u32) -> anyhow::R
                     source: clip.source.clone(),
                     offset_start: clip.offset_start,
                     loop_length: clip.loop_length,
+                    groove: clip.groove.clone(),
                 }
             })
             .collect();
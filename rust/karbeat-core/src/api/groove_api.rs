@@ -0,0 +1,169 @@
+use std::{ path::Path, sync::Arc };
+
+use crate::context::utils::broadcast_state_change;
+use crate::core::file_manager::midi_loader;
+use crate::core::history::ProjectAction;
+use crate::core::project::{ groove::Groove, KarbeatSource, Pattern };
+use crate::lock::{ get_app_read, get_app_write, get_history_lock };
+use crate::shared::id::*;
+
+/// Sets or clears the groove a pattern plays with. Its notes are left where they are.
+pub fn set_pattern_groove(pattern_id: PatternId, groove: Option<Groove>) -> anyhow::Result<()> {
+    let old_groove = {
+        let mut app = get_app_write();
+        let pattern_arc = app.pattern_pool
+            .get_mut(&pattern_id)
+            .ok_or_else(|| anyhow::anyhow!("Pattern {} not found", pattern_id.to_u32()))?;
+        std::mem::replace(&mut Arc::make_mut(pattern_arc).groove, groove.clone())
+    };
+
+    {
+        let mut history = get_history_lock();
+        history.push(ProjectAction::SetPatternGroove {
+            pattern_id,
+            old_groove,
+            new_groove: groove,
+        });
+    }
+    broadcast_state_change();
+    Ok(())
+}
+
+/// Sets or clears the groove of one MIDI clip, played instead of its pattern's.
+pub fn set_clip_groove(
+    track_id: TrackId,
+    clip_id: ClipId,
+    groove: Option<Groove>
+) -> anyhow::Result<()> {
+    let (old_clip, _) = {
+        let mut app = get_app_write();
+        app.set_clip_groove(track_id, clip_id, groove.clone()).map_err(|e| anyhow::anyhow!(e))?
+    };
+
+    {
+        let mut history = get_history_lock();
+        history.push(ProjectAction::SetClipGroove {
+            track_id,
+            clip_id,
+            old_groove: old_clip.groove,
+            new_groove: groove,
+        });
+    }
+    broadcast_state_change();
+    Ok(())
+}
+
+/// Takes a groove template from the notes of a pattern, one step per `step_ticks`.
+pub fn groove_from_pattern(pattern_id: PatternId, step_ticks: u64) -> anyhow::Result<Groove> {
+    let app = get_app_read();
+    let pattern = app.pattern_pool
+        .get(&pattern_id)
+        .ok_or_else(|| anyhow::anyhow!("Pattern {} not found", pattern_id.to_u32()))?;
+    Groove::from_notes(pattern.name.clone(), &pattern.notes, step_ticks, pattern.length_ticks)
+}
+
+/// Takes a groove template from the notes of a MIDI file, one step per `step_ticks`.
+pub fn groove_from_midi_file(path: &Path, step_ticks: u64) -> anyhow::Result<Groove> {
+    let midi = midi_loader::load_midi_file(path)?;
+    let name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("MIDI groove");
+    // Bars follow the file's meter, or the project's when the file has none
+    let signatures = midi.time_signatures
+        .unwrap_or_else(|| get_app_read().transport.time_signature_map.clone());
+    Groove::from_played_notes(name, &midi.notes, step_ticks, &signatures)
+}
+
+/// Writes a pattern's groove into its notes and clears it, as one undo step.
+pub fn commit_pattern_groove(pattern_id: PatternId) -> anyhow::Result<()> {
+    let actions = {
+        let mut app = get_app_write();
+        let pattern_arc = app.pattern_pool
+            .get_mut(&pattern_id)
+            .ok_or_else(|| anyhow::anyhow!("Pattern {} not found", pattern_id.to_u32()))?;
+        let pattern = Arc::make_mut(pattern_arc);
+        let groove = pattern.groove
+            .take()
+            .ok_or_else(|| anyhow::anyhow!("Pattern {} has no groove", pattern_id.to_u32()))?;
+
+        let mut actions = note_updates(pattern_id, pattern, &groove);
+        actions.push(ProjectAction::SetPatternGroove {
+            pattern_id,
+            old_groove: Some(groove),
+            new_groove: None,
+        });
+        actions
+    };
+
+    get_history_lock().push(ProjectAction::Batch(actions));
+    broadcast_state_change();
+    Ok(())
+}
+
+/// Writes a clip's groove into the notes of its pattern and clears it, as one undo step. The
+/// pattern's own groove is cleared too, the clip played instead of it. Refused when other clips
+/// play the pattern, they would hear the clip's groove as well.
+pub fn commit_clip_groove(track_id: TrackId, clip_id: ClipId) -> anyhow::Result<()> {
+    let actions = {
+        let mut app = get_app_write();
+        let clip = app
+            .get_clip(&track_id, &clip_id)
+            .ok_or_else(|| anyhow::anyhow!("Clip {:?} not found in track {:?}", clip_id, track_id))?;
+        let KarbeatSource::Midi(pattern_id) = clip.source else {
+            return Err(anyhow::anyhow!("Only MIDI clips can have a groove"));
+        };
+        let groove = clip.groove
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Clip {:?} has no groove", clip_id))?;
+
+        let shared = app.tracks
+            .values()
+            .flat_map(|track| track.clips.iter())
+            .any(|other| {
+                other.id != clip_id && matches!(other.source, KarbeatSource::Midi(id) if id == pattern_id)
+            });
+        if shared {
+            return Err(
+                anyhow::anyhow!(
+                    "Pattern {} is played by other clips, make it unique before committing the groove",
+                    pattern_id.to_u32()
+                )
+            );
+        }
+
+        let pattern_arc = app.pattern_pool
+            .get_mut(&pattern_id)
+            .ok_or_else(|| anyhow::anyhow!("Pattern {} not found", pattern_id.to_u32()))?;
+        let pattern = Arc::make_mut(pattern_arc);
+        let mut actions = note_updates(pattern_id, pattern, &groove);
+        if let Some(pattern_groove) = pattern.groove.take() {
+            actions.push(ProjectAction::SetPatternGroove {
+                pattern_id,
+                old_groove: Some(pattern_groove),
+                new_groove: None,
+            });
+        }
+
+        app.set_clip_groove(track_id, clip_id, None).map_err(|e| anyhow::anyhow!(e))?;
+        actions.push(ProjectAction::SetClipGroove {
+            track_id,
+            clip_id,
+            old_groove: Some(groove),
+            new_groove: None,
+        });
+        actions
+    };
+
+    get_history_lock().push(ProjectAction::Batch(actions));
+    broadcast_state_change();
+    Ok(())
+}
+
+fn note_updates(pattern_id: PatternId, pattern: &mut Pattern, groove: &Groove) -> Vec<ProjectAction> {
+    pattern
+        .commit_groove(groove)
+        .into_iter()
+        .map(|(old_note, new_note)| ProjectAction::UpdateNote { pattern_id, old_note, new_note })
+        .collect()
+}
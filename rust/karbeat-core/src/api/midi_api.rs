@@ -0,0 +1,118 @@
+use std::{ sync::Arc, time::Duration };
+
+use crate::{
+    api::midi_mapping_api,
+    audio::midi::{ MidiBackend, MidiOutputHandle, MidiOutputSettings, input_channel },
+    commands::AudioCommand,
+    context::{ ctx, utils::send_audio_command },
+    lock::get_app_read,
+    shared::id::TrackId,
+};
+
+// =========================================================================
+// Backend
+// =========================================================================
+
+pub fn get_midi_backend_name() -> String {
+    ctx().midi_backend.read().name().to_string()
+}
+
+/// Swaps the MIDI API, e.g. for a virtual backend. Open ports are closed.
+pub fn set_midi_backend(backend: Arc<dyn MidiBackend>) {
+    close_midi_input();
+    close_midi_output();
+    *ctx().midi_backend.write() = backend;
+}
+
+// =========================================================================
+// Input
+// =========================================================================
+
+pub fn list_midi_inputs() -> anyhow::Result<Vec<String>> {
+    ctx().midi_backend.read().input_ports()
+}
+
+/// Opens a MIDI input port, replacing the open one. Mapped and learned CCs drive their targets,
+/// notes, other CCs, pitch bend and aftertouch play the generators of armed MIDI tracks, or of
+/// the MIDI input track when none is armed.
+pub fn open_midi_input(port: &str) -> anyhow::Result<()> {
+    close_midi_input();
+
+    let (handler, engine_port) = input_channel(midi_mapping_api::handle_control_change);
+    let connection = ctx().midi_backend.read().open_input(port, handler)?;
+    send_audio_command(AudioCommand::SetMidiInput(Some(engine_port)));
+    *ctx().midi_input.lock() = Some(connection);
+    log::info!("MIDI input opened: {}", port);
+    Ok(())
+}
+
+pub fn close_midi_input() {
+    if ctx().midi_input.lock().take().is_some() {
+        send_audio_command(AudioCommand::SetMidiInput(None));
+    }
+}
+
+pub fn get_open_midi_input() -> Option<String> {
+    ctx()
+        .midi_input.lock()
+        .as_ref()
+        .map(|connection| connection.port_name().to_string())
+}
+
+/// Track that plays the MIDI input while no MIDI track is armed (usually the selected track).
+pub fn set_midi_input_track(track_id: Option<TrackId>) {
+    send_audio_command(AudioCommand::SetMidiInputTrack(track_id));
+}
+
+// =========================================================================
+// Output
+// =========================================================================
+
+pub fn list_midi_outputs() -> anyhow::Result<Vec<String>> {
+    ctx().midi_backend.read().output_ports()
+}
+
+/// Opens a MIDI output port, replacing the open one.
+pub fn open_midi_output(port: &str) -> anyhow::Result<()> {
+    close_midi_output();
+
+    let connection = ctx().midi_backend.read().open_output(port)?;
+    let sample_rate = get_app_read().audio_config.sample_rate.max(1);
+    // Messages wait for the audio rendered alongside them to reach the speakers
+    let (handle, engine_port) = MidiOutputHandle::start(connection, move || {
+        Duration::from_secs_f64((ctx().device_latency.playback() as f64) / (sample_rate as f64))
+    })?;
+
+    send_audio_command(AudioCommand::SetMidiOutput(Some(engine_port)));
+    *ctx().midi_output.lock() = Some(handle);
+    log::info!("MIDI output opened: {}", port);
+    Ok(())
+}
+
+pub fn close_midi_output() {
+    if ctx().midi_output.lock().take().is_some() {
+        send_audio_command(AudioCommand::SetMidiOutput(None));
+    }
+}
+
+pub fn get_open_midi_output() -> Option<String> {
+    ctx()
+        .midi_output.lock()
+        .as_ref()
+        .map(|handle| handle.port_name().to_string())
+}
+
+pub fn get_midi_output_settings() -> MidiOutputSettings {
+    *ctx().midi_output_settings.lock()
+}
+
+/// Sets what goes out of the MIDI output: clock and transport while the song plays, and the
+/// notes of one track on `settings.channel`.
+pub fn set_midi_output_settings(settings: MidiOutputSettings) -> anyhow::Result<()> {
+    if settings.channel > 15 {
+        return Err(anyhow::anyhow!("MIDI channel must be 0-15, got {}", settings.channel));
+    }
+    *ctx().midi_output_settings.lock() = settings;
+    send_audio_command(AudioCommand::SetMidiOutputSettings(settings));
+    Ok(())
+}
@@ -0,0 +1,305 @@
+use karbeat_plugin_types::ParameterSpec;
+
+use crate::{
+    api::{ mixer_api, plugin_api, transport_api },
+    commands::EffectTarget,
+    context::{ ctx, utils::broadcast_state_change },
+    core::project::{
+        automation::AutomationTarget,
+        midi_mapping::{ MidiCc, MidiLearnState, MidiMapping },
+        mixer::MixerChannelParams,
+    },
+    lock::{ get_app_read, get_app_write },
+};
+
+/// Tempo range a controller sweeps by default
+const TEMPO_MIN_BPM: f32 = 20.0;
+const TEMPO_MAX_BPM: f32 = 300.0;
+
+/// A soft-takeover controller picks up the target within one CC step of its value
+const TAKEOVER_TOLERANCE: f32 = 1.0 / 127.0;
+
+// =========================================================================
+// Mappings
+// =========================================================================
+
+pub fn get_midi_mappings<C, U, M>(mapper: M) -> C
+    where M: Fn(&MidiMapping) -> U, C: FromIterator<U>
+{
+    let app = get_app_read();
+    app.midi_mappings.mappings.iter().map(mapper).collect()
+}
+
+/// Maps a control change to a target over the target's full range.
+pub fn add_midi_mapping(source: MidiCc, target: AutomationTarget) -> anyhow::Result<MidiMapping> {
+    validate_source(source)?;
+    let (min, max, _) = read_target(&target)?;
+    let mapping = {
+        let mut app = get_app_write();
+        app.midi_mappings.add(source, target, min, max).clone()
+    };
+
+    broadcast_state_change();
+
+    // TODO: add history
+
+    Ok(mapping)
+}
+
+/// Replaces the source, range and options of a mapping. Its target stays.
+pub fn update_midi_mapping(mapping: MidiMapping) -> anyhow::Result<()> {
+    validate_source(mapping.source)?;
+    {
+        let mut app = get_app_write();
+        let existing = app.midi_mappings
+            .get_mut(mapping.id)
+            .ok_or_else(|| anyhow::anyhow!("MIDI mapping {} not found", mapping.id))?;
+        existing.source = mapping.source;
+        existing.min = mapping.min;
+        existing.max = mapping.max;
+        existing.invert = mapping.invert;
+        existing.soft_takeover = mapping.soft_takeover;
+    }
+    ctx().midi_learn.lock().takeover.remove(&mapping.id);
+
+    broadcast_state_change();
+    Ok(())
+}
+
+pub fn remove_midi_mapping(mapping_id: u32) -> anyhow::Result<()> {
+    {
+        let mut app = get_app_write();
+        app.midi_mappings
+            .remove(mapping_id)
+            .ok_or_else(|| anyhow::anyhow!("MIDI mapping {} not found", mapping_id))?;
+    }
+    ctx().midi_learn.lock().takeover.remove(&mapping_id);
+
+    broadcast_state_change();
+    Ok(())
+}
+
+fn validate_source(source: MidiCc) -> anyhow::Result<()> {
+    if source.channel > 15 || source.controller > 127 {
+        return Err(
+            anyhow::anyhow!(
+                "Invalid MIDI CC: channel {} controller {}",
+                source.channel,
+                source.controller
+            )
+        );
+    }
+    Ok(())
+}
+
+// =========================================================================
+// Learn
+// =========================================================================
+
+/// Arms MIDI learn: the next control change from the MIDI input gets mapped to `target`,
+/// replacing the mappings it had.
+pub fn start_midi_learn(target: AutomationTarget) -> anyhow::Result<()> {
+    // Fail now rather than when the controller moves
+    read_target(&target)?;
+    ctx().midi_learn.lock().armed = Some(target);
+    Ok(())
+}
+
+pub fn cancel_midi_learn() {
+    ctx().midi_learn.lock().armed = None;
+}
+
+/// Target waiting for a control change, if MIDI learn is armed.
+pub fn get_midi_learn_target() -> Option<AutomationTarget> {
+    ctx().midi_learn.lock().armed.clone()
+}
+
+/// Session state belongs to the project the mapping ids came from.
+pub(crate) fn reset_midi_learn() {
+    *ctx().midi_learn.lock() = MidiLearnState::default();
+}
+
+fn learn(source: MidiCc, target: AutomationTarget) -> anyhow::Result<()> {
+    let (min, max, _) = read_target(&target)?;
+    let mapping_id = {
+        let mut app = get_app_write();
+        app.midi_mappings.remove_for_target(&target);
+        app.midi_mappings.add(source, target, min, max).id
+    };
+    log::info!(
+        "MIDI learn: channel {} CC {} mapped (mapping {})",
+        source.channel + 1,
+        source.controller,
+        mapping_id
+    );
+
+    broadcast_state_change();
+    Ok(())
+}
+
+// =========================================================================
+// Incoming control changes
+// =========================================================================
+
+/// Runs on the MIDI input thread for every control change. Returns true when the CC was used
+/// for learning or drives a mapping, so it doesn't also reach the generators.
+pub fn handle_control_change(channel: u8, controller: u8, value: u8) -> bool {
+    let source = MidiCc { channel, controller };
+
+    let armed = ctx().midi_learn.lock().armed.take();
+    if let Some(target) = armed {
+        if let Err(e) = learn(source, target) {
+            log::warn!("MIDI learn failed: {}", e);
+        }
+        return true;
+    }
+
+    let mappings: Vec<MidiMapping> = {
+        let app = get_app_read();
+        app.midi_mappings.for_source(source).cloned().collect()
+    };
+    for mapping in &mappings {
+        if let Err(e) = drive(mapping, value) {
+            log::warn!("MIDI mapping {}: {}", mapping.id, e);
+        }
+    }
+    !mappings.is_empty()
+}
+
+fn drive(mapping: &MidiMapping, cc_value: u8) -> anyhow::Result<()> {
+    let position = mapping.position(cc_value);
+    let value = mapping.value_at(position);
+
+    if mapping.soft_takeover {
+        let (_, _, current) = read_target(&mapping.target)?;
+        let mut learn_state = ctx().midi_learn.lock();
+        let takeover = learn_state.takeover.entry(mapping.id).or_default();
+        takeover.check_moved(current, (mapping.max - mapping.min).abs() * TAKEOVER_TOLERANCE);
+        if !takeover.update(position, mapping.position_of(current), TAKEOVER_TOLERANCE) {
+            return Ok(());
+        }
+        takeover.last_sent = Some(value);
+    }
+
+    apply(&mapping.target, value)
+}
+
+// =========================================================================
+// Targets
+// =========================================================================
+
+/// (min, max, current value) of a target.
+fn read_target(target: &AutomationTarget) -> anyhow::Result<(f32, f32, f32)> {
+    let param_range = |specs: Result<Vec<(u32, f32, f32, f32)>, String>, param_id: u32| {
+        specs
+            .map_err(|e| anyhow::anyhow!(e))?
+            .into_iter()
+            .find(|(id, ..)| *id == param_id)
+            .map(|(_, min, max, value)| (min, max, value))
+            .ok_or_else(|| anyhow::anyhow!("Parameter {} not found", param_id))
+    };
+    let spec = |p: ParameterSpec, value: f32| (p.id, p.min, p.max, value);
+
+    let app = get_app_read();
+    let channel_spec = |specs: Vec<ParameterSpec>, index: usize| {
+        specs
+            .get(index)
+            .map(|p| (p.min, p.max, p.value))
+            .ok_or_else(|| anyhow::anyhow!("Channel parameter missing"))
+    };
+
+    match target {
+        AutomationTarget::TrackVolume(track_id) | AutomationTarget::TrackPan(track_id) => {
+            let channel = app.mixer.channels
+                .get(track_id)
+                .ok_or_else(|| anyhow::anyhow!("Track channel {} not found", track_id.0))?;
+            let index = if matches!(target, AutomationTarget::TrackVolume(_)) { 0 } else { 1 };
+            channel_spec(channel.get_channel_specs(), index)
+        }
+        AutomationTarget::BusVolume(bus_id) | AutomationTarget::BusPan(bus_id) => {
+            let bus = app.mixer.buses
+                .get(bus_id)
+                .ok_or_else(|| anyhow::anyhow!("Bus {} not found", bus_id.0))?;
+            let index = if matches!(target, AutomationTarget::BusVolume(_)) { 0 } else { 1 };
+            channel_spec(bus.channel.get_channel_specs(), index)
+        }
+        AutomationTarget::MasterVolume => channel_spec(app.mixer.master_bus.get_channel_specs(), 0),
+        AutomationTarget::MasterPan => channel_spec(app.mixer.master_bus.get_channel_specs(), 1),
+        AutomationTarget::TempoBpm => Ok((TEMPO_MIN_BPM, TEMPO_MAX_BPM, app.transport.bpm)),
+        AutomationTarget::TrackGeneratorPluginParam { track_id, param_id } => {
+            let generator_id = app.tracks
+                .get(track_id)
+                .and_then(|t| t.generator.as_ref().map(|g| g.id))
+                .ok_or_else(|| anyhow::anyhow!("Track {} has no generator", track_id.0))?;
+            drop(app);
+            param_range(plugin_api::get_generator_parameter_specs(&generator_id, spec), *param_id)
+        }
+        AutomationTarget::TrackPluginParam { track_id, effect_id, param_id } => {
+            drop(app);
+            let target = EffectTarget::Track(*track_id);
+            param_range(plugin_api::get_effect_parameter_specs(&target, effect_id, spec), *param_id)
+        }
+        AutomationTarget::BusPluginParam { bus_id, effect_id, param_id } => {
+            drop(app);
+            let target = EffectTarget::Bus(*bus_id);
+            param_range(plugin_api::get_effect_parameter_specs(&target, effect_id, spec), *param_id)
+        }
+        AutomationTarget::MasterPluginParam { effect_id, param_id } => {
+            drop(app);
+            let target = EffectTarget::Master;
+            param_range(plugin_api::get_effect_parameter_specs(&target, effect_id, spec), *param_id)
+        }
+    }
+}
+
+/// Sends a value to a target the way the UI would.
+fn apply(target: &AutomationTarget, value: f32) -> anyhow::Result<()> {
+    match target {
+        AutomationTarget::TrackVolume(track_id) => {
+            mixer_api::set_mixer_channel_params(*track_id, &[MixerChannelParams::Volume(value)])
+        }
+        AutomationTarget::TrackPan(track_id) => {
+            mixer_api::set_mixer_channel_params(*track_id, &[MixerChannelParams::Pan(value)])
+        }
+        AutomationTarget::BusVolume(bus_id) => {
+            mixer_api::set_bus_params(*bus_id, &[MixerChannelParams::Volume(value)])
+        }
+        AutomationTarget::BusPan(bus_id) => {
+            mixer_api::set_bus_params(*bus_id, &[MixerChannelParams::Pan(value)])
+        }
+        AutomationTarget::MasterVolume => {
+            mixer_api::set_master_bus_params(&[MixerChannelParams::Volume(value)])
+        }
+        AutomationTarget::MasterPan => {
+            mixer_api::set_master_bus_params(&[MixerChannelParams::Pan(value)])
+        }
+        AutomationTarget::TempoBpm => {
+            transport_api::set_bpm(value);
+            Ok(())
+        }
+        AutomationTarget::TrackGeneratorPluginParam { track_id, param_id } => {
+            let generator_id = get_app_read()
+                .tracks.get(track_id)
+                .and_then(|t| t.generator.as_ref().map(|g| g.id))
+                .ok_or_else(|| anyhow::anyhow!("Track {} has no generator", track_id.0))?;
+            plugin_api
+                ::set_generator_parameter(&generator_id, *param_id, value)
+                .map_err(|e| anyhow::anyhow!(e))
+        }
+        AutomationTarget::TrackPluginParam { track_id, effect_id, param_id } => {
+            plugin_api
+                ::set_effect_parameter(&EffectTarget::Track(*track_id), effect_id, *param_id, value)
+                .map_err(|e| anyhow::anyhow!(e))
+        }
+        AutomationTarget::BusPluginParam { bus_id, effect_id, param_id } => {
+            plugin_api
+                ::set_effect_parameter(&EffectTarget::Bus(*bus_id), effect_id, *param_id, value)
+                .map_err(|e| anyhow::anyhow!(e))
+        }
+        AutomationTarget::MasterPluginParam { effect_id, param_id } => {
+            plugin_api
+                ::set_effect_parameter(&EffectTarget::Master, effect_id, *param_id, value)
+                .map_err(|e| anyhow::anyhow!(e))
+        }
+    }
+}
@@ -3,7 +3,8 @@ use std::sync::Arc;
 use karbeat_plugin_types::ParameterSpec;
 
 use crate::{
-    context::utils::broadcast_state_change,
+    audio::metering::MeterFeedback,
+    context::{ ctx, utils::broadcast_state_change },
     core::project::{
         TrackId,
         mixer::{
@@ -20,6 +21,17 @@ use crate::{
     lock::{ get_app_read, get_app_write },
 };
 
+/// Drains the level meter ring buffer and maps the readings to UI types
+pub fn drain_meter_feedback<T, F>(mut mapper: F) -> Vec<T> where F: FnMut(MeterFeedback) -> T {
+    let mut results = Vec::new();
+    if let Some(consumer) = ctx().meter_consumer.lock().as_mut() {
+        while let Ok(reading) = consumer.pop() {
+            results.push(mapper(reading));
+        }
+    }
+    results
+}
+
 /// **GETTER: Fetch the mixer state from application state and map it to T value**
 pub fn get_mixer_state<T, F>(mapper: F) -> T where F: FnOnce(&MixerState) -> T {
     let app = get_app_read();
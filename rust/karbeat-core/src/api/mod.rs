@@ -1,3 +1,4 @@
+pub mod analyzer_api;
 pub mod automation_api;
 pub mod transport_api;
 pub mod audio_api;
@@ -7,9 +8,13 @@ pub mod project_api;
 pub mod audio_waveform_api;
 pub mod mixer_api;
 pub mod pattern_api;
+pub mod groove_api;
 pub mod note_api;
 pub mod clip_api;
 pub mod track_api;
+pub mod recording_api;
+pub mod midi_api;
+pub mod midi_mapping_api;
 
 use crate::{ context::utils::broadcast_state_change, lock::{ get_app_write, get_history_lock } };
 
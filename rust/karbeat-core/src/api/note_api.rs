@@ -1,8 +1,15 @@
 use crate::context::utils::broadcast_state_change;
 use crate::core::history::ProjectAction;
 use crate::shared::id::*;
-use crate::core::project::{ clipboard::ClipboardContent, Note, NoteId };
-use crate::lock::{ get_app_write, get_history_lock };
+use crate::core::project::{
+    clipboard::ClipboardContent,
+    quantize::QuantizeSettings,
+    theory::{ Chord, ChordType },
+    transform::NoteTransform,
+    Note,
+    NoteId,
+};
+use crate::lock::{ get_app_read, get_app_write, get_history_lock };
 use std::sync::Arc;
 
 pub fn add_note(
@@ -130,6 +137,161 @@ pub fn change_note_params(
     Ok(note)
 }
 
+/// Quantizes the selected notes of a pattern, or all of them when `note_ids` is empty, as
+/// one undo step. Returns the notes that moved or were resized.
+pub fn quantize_notes(
+    pattern_id: PatternId,
+    note_ids: Vec<NoteId>,
+    settings: QuantizeSettings
+) -> anyhow::Result<Vec<Note>> {
+    let mut actions = Vec::new();
+    let mut changed_notes = Vec::new();
+
+    // 1. Mutate state and collect actions
+    {
+        let mut app = get_app_write();
+        // Patterns use the project start signature
+        let signature = app.transport.time_signature_map.initial();
+        let pattern_arc = app.pattern_pool
+            .get_mut(&pattern_id)
+            .ok_or_else(|| anyhow::anyhow!("Pattern not found"))?;
+        let pattern = Arc::make_mut(pattern_arc);
+
+        for (old, new) in pattern.quantize(&note_ids, signature, &settings) {
+            if old.start_tick != new.start_tick {
+                actions.push(ProjectAction::MoveNote {
+                    pattern_id,
+                    note_id: new.id,
+                    old_tick: old.start_tick,
+                    old_key: old.key,
+                    new_tick: new.start_tick,
+                    new_key: new.key,
+                });
+            }
+            if old.duration != new.duration {
+                actions.push(ProjectAction::ResizeNote {
+                    pattern_id,
+                    note_id: new.id,
+                    old_duration: old.duration,
+                    new_duration: new.duration,
+                });
+            }
+            changed_notes.push(new);
+        }
+    }
+
+    // 2. Update history
+    if !actions.is_empty() {
+        let mut history = get_history_lock();
+        history.push(ProjectAction::Batch(actions));
+    }
+
+    broadcast_state_change();
+    Ok(changed_notes)
+}
+
+/// Applies a bulk transform to the selected notes of a pattern, or all of them when
+/// `note_ids` is empty, as one undo step. Returns the new states of the changed notes.
+pub fn transform_notes(
+    pattern_id: PatternId,
+    note_ids: Vec<NoteId>,
+    transform: NoteTransform
+) -> anyhow::Result<Vec<Note>> {
+    // 1. Mutate state
+    let (old_notes, new_notes) = {
+        let mut app = get_app_write();
+        let pattern_arc = app.pattern_pool
+            .get_mut(&pattern_id)
+            .ok_or_else(|| anyhow::anyhow!("Pattern not found"))?;
+        Arc::make_mut(pattern_arc).transform_notes(&note_ids, &transform)?
+    };
+
+    // 2. Update history: changed notes are updated, merged ones deleted
+    if !old_notes.is_empty() {
+        let actions = old_notes
+            .into_iter()
+            .map(|old_note| {
+                match new_notes.iter().find(|new| new.id == old_note.id) {
+                    Some(new_note) =>
+                        ProjectAction::UpdateNote {
+                            pattern_id,
+                            old_note,
+                            new_note: new_note.clone(),
+                        },
+                    None => ProjectAction::DeleteNote { pattern_id, note: old_note },
+                }
+            })
+            .collect();
+        let mut history = get_history_lock();
+        history.push(ProjectAction::Batch(actions));
+    }
+
+    broadcast_state_change();
+    Ok(new_notes)
+}
+
+/// Adds the notes of a chord spelled up from `root_key`, as one undo step.
+pub fn add_chord(
+    pattern_id: PatternId,
+    root_key: u8,
+    chord_type: ChordType,
+    inversion: usize,
+    start_tick: u64,
+    duration: Option<u64>
+) -> anyhow::Result<Vec<Note>> {
+    // Checked up front so a chord is either added whole or not at all
+    if root_key > 127 {
+        return Err(anyhow::anyhow!("MIDI key must be 0-127, got {}", root_key));
+    }
+    if duration == Some(0) {
+        return Err(anyhow::anyhow!("Note duration must be > 0"));
+    }
+    let keys = Chord::keys(root_key, chord_type, inversion);
+
+    // 1. Mutate state
+    let notes = {
+        let mut app = get_app_write();
+        let pattern_arc = app.pattern_pool
+            .get_mut(&pattern_id)
+            .ok_or_else(|| anyhow::anyhow!("Pattern not found"))?;
+        let pattern = Arc::make_mut(pattern_arc);
+
+        keys.into_iter()
+            .map(|key| pattern.add_note(key, start_tick, duration))
+            .collect::<anyhow::Result<Vec<Note>>>()?
+    };
+
+    // 2. Update history
+    {
+        let mut history = get_history_lock();
+        history.push(
+            ProjectAction::Batch(
+                notes
+                    .iter()
+                    .map(|note| ProjectAction::AddNote { pattern_id, note: note.clone() })
+                    .collect()
+            )
+        );
+    }
+
+    broadcast_state_change();
+    Ok(notes)
+}
+
+/// Moves the selected notes, or all of them when `note_ids` is empty, into the pattern's
+/// key, as one undo step.
+pub fn snap_to_scale(pattern_id: PatternId, note_ids: Vec<NoteId>) -> anyhow::Result<Vec<Note>> {
+    let scale = {
+        let app = get_app_read();
+        app.pattern_pool
+            .get(&pattern_id)
+            .ok_or_else(|| anyhow::anyhow!("Pattern not found"))?
+            .scale
+            .ok_or_else(|| anyhow::anyhow!("Pattern has no key to snap to"))?
+    };
+    transform_notes(pattern_id, note_ids, NoteTransform::SnapToScale { scale })
+}
+
 pub fn delete_notes_batch(pattern_id: PatternId, note_ids: Vec<NoteId>) -> anyhow::Result<()> {
     let mut actions = Vec::new();
 
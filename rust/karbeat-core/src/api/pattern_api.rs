@@ -3,9 +3,18 @@ use std::sync::Arc;
 use crate::{
     audio::engine::PlaybackMode,
     commands::AudioCommand,
-    context::utils::try_send_audio_command_chain,
-    core::project::{ ClipboardContent, GeneratorId, Note, NoteId, Pattern, PatternId },
-    lock::{ get_app_read, get_app_write },
+    context::utils::{ broadcast_state_change, try_send_audio_command_chain },
+    core::history::ProjectAction,
+    core::project::{
+        ClipboardContent,
+        GeneratorId,
+        Note,
+        NoteId,
+        Pattern,
+        PatternId,
+        theory::{ self, ChordSpan, Scale },
+    },
+    lock::{ get_app_read, get_app_write, get_history_lock },
 };
 
 pub fn get_pattern(pattern_id: &PatternId) -> anyhow::Result<Arc<Pattern>> {
@@ -101,3 +110,42 @@ pub fn copy_pattern_notes<T, F>(
     // Pass the internal reference to the closure before the lock drops
     Ok(mapper(&app.clipboard))
 }
+
+/// Sets or clears the key of a pattern. Notes are left as they are.
+pub fn set_pattern_scale(pattern_id: PatternId, scale: Option<Scale>) -> anyhow::Result<()> {
+    let old_scale = {
+        let mut app = get_app_write();
+        let pattern_arc = app.pattern_pool
+            .get_mut(&pattern_id)
+            .ok_or_else(|| anyhow::anyhow!("Pattern {:?} not found", pattern_id))?;
+        std::mem::replace(&mut Arc::make_mut(pattern_arc).scale, scale)
+    };
+
+    {
+        let mut history = get_history_lock();
+        history.push(ProjectAction::SetPatternScale { pattern_id, old_scale, new_scale: scale });
+    }
+    broadcast_state_change();
+    Ok(())
+}
+
+/// Notes outside the pattern's key, none when the pattern has no key.
+pub fn get_out_of_key_notes(pattern_id: PatternId) -> anyhow::Result<Vec<NoteId>> {
+    let pattern = get_pattern(&pattern_id)?;
+    Ok(
+        pattern.scale
+            .map(|scale| scale.out_of_key(&pattern.notes))
+            .unwrap_or_default()
+    )
+}
+
+/// Chords played by the selected notes, or the whole pattern when `note_ids` is empty.
+pub fn analyse_chords(pattern_id: PatternId, note_ids: &[NoteId]) -> anyhow::Result<Vec<ChordSpan>> {
+    let pattern = get_pattern(&pattern_id)?;
+    let notes: Vec<Note> = pattern.notes
+        .iter()
+        .filter(|n| note_ids.is_empty() || note_ids.contains(&n.id))
+        .cloned()
+        .collect();
+    Ok(theory::analyse_chords(&notes))
+}
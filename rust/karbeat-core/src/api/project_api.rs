@@ -1,9 +1,13 @@
 use std::path::Path;
 
+use crate::api::{midi_mapping_api, transport_api};
 use crate::audio::render_state::broadcast_plugin_state_loading;
 use crate::commands::AudioCommand;
 use crate::context::utils::{broadcast_state_change, send_audio_command};
-use crate::core::file_manager::project_loader::{load_karbeat_project, save_karbeat_project};
+use crate::core::file_manager::project_loader::{
+    load_karbeat_project_at_sample_rate,
+    save_karbeat_project,
+};
 use crate::core::project::ApplicationState;
 use crate::lock::{get_app_read, get_app_write};
 use crate::core::project::{ProjectMetadata, transport::TransportState, generator::GeneratorInstance};
@@ -29,9 +33,15 @@ where
     Ok(app.generator_pool.iter().map(|(&id, gen)| mapper(id.to_u32(), gen.as_ref())).collect())
 }
 
+pub fn get_max_tick_index() -> anyhow::Result<u32> {
+    let app = get_app_read();
+    Ok(app.max_tick_index)
+}
+
+/// End of the last clip, converted to output samples through the tempo map.
 pub fn get_max_sample_index() -> anyhow::Result<u32> {
     let app = get_app_read();
-    Ok(app.max_sample_index)
+    Ok(app.tick_converter().ticks_to_samples(app.max_tick_index) as u32)
 }
 
 pub fn save_project(path_name: &str) -> anyhow::Result<()> {
@@ -44,12 +54,16 @@ pub fn load_project<T, F>(path_name: &str, mapper: F) -> anyhow::Result<T>
 where
     F: FnOnce(&ApplicationState) -> T,
 {
-    // 1. Load the project from disk
-    let loaded_app = load_karbeat_project(Path::new(path_name))?;
+    // 1. Load the project from disk. Legacy sample positions are migrated
+    // using the sample rate of the running session.
+    let audio_config = get_app_read().audio_config.clone();
+    let mut loaded_app = load_karbeat_project_at_sample_rate(
+        Path::new(path_name),
+        audio_config.sample_rate
+    )?;
+    // Hardware config is session data and must survive a project switch
+    loaded_app.audio_config = audio_config;
     
-    // Extract the BPM before we move the loaded app into the global lock
-    let bpm = loaded_app.transport.bpm;
-
     // 2. Halt the audio engine immediately to prevent reading partially overwritten state
     send_audio_command(AudioCommand::StopAndReset);
 
@@ -61,9 +75,23 @@ where
     };
 
     // 4. Fire all necessary sync and loading events to the engine/UI
+    sync_engine_with_project();
+    midi_mapping_api::reset_midi_learn();
+
+    Ok(mapped_ui_state)
+}
+
+/// Sends the whole project to a reset or newly started engine: the render state, transport
+/// settings and freshly created plugin instances, prepared at the engine's rate.
+pub(crate) fn sync_engine_with_project() {
+    let (bpm, loop_region, metronome) = {
+        let app = get_app_read();
+        (app.transport.bpm, app.transport.loop_region, app.transport.metronome)
+    };
+
     broadcast_state_change();
     send_audio_command(AudioCommand::SetBPM(bpm));
+    send_audio_command(AudioCommand::SetLoopRegion(loop_region));
+    transport_api::sync_metronome(metronome);
     broadcast_plugin_state_loading();
-
-    Ok(mapped_ui_state)
 }
\ No newline at end of file
@@ -0,0 +1,512 @@
+use std::{ collections::BTreeMap, sync::Arc };
+
+use crate::{
+    api::audio_api,
+    audio::{
+        backend,
+        note_recorder::{ NoteTakeHandle, RecordedNote, RecordedNotes },
+        recorder::{ ActiveRecording, AudioRecording, TakeHandle, next_take_path },
+    },
+    commands::AudioCommand,
+    context::{ ctx, utils::{ broadcast_state_change, send_audio_command } },
+    core::{
+        file_manager::audio_loader::{ AudioLoader, load_audio_file },
+        history::ProjectAction,
+        project::{
+            ApplicationState,
+            Clip,
+            KarbeatSource,
+            Note,
+            NoteId,
+            Pattern,
+            TrackType,
+            musical_time::{ GridDivision, SnapMode },
+            track::MonitorMode,
+            transport::{ MidiRecordMode, MidiRecordSettings },
+        },
+    },
+    lock::{ get_app_read, get_app_write, get_history_lock },
+    shared::id::{ ClipId, PatternId, TrackId },
+};
+
+// =========================================================================
+// Input Device
+// =========================================================================
+
+pub fn list_input_devices() -> anyhow::Result<Vec<String>> {
+    backend::list_input_devices()
+}
+
+/// Opens an input device (the system default when `None`) and returns its name.
+/// The choice is kept in the user settings.
+pub fn set_input_device(name: Option<String>) -> anyhow::Result<String> {
+    let opened = backend::start_input_stream(name.as_deref())?;
+    audio_api::remember_input_device(&opened);
+    Ok(opened)
+}
+
+pub fn close_input_device() {
+    backend::stop_input_stream();
+}
+
+pub fn is_input_open() -> bool {
+    ctx().input_stream_guard.lock().is_some()
+}
+
+// =========================================================================
+// Record Arm
+// =========================================================================
+
+/// Arms or disarms an audio or MIDI track for recording. Arming an audio track opens the
+/// selected input device if no input is open yet.
+pub fn set_track_record_arm(track_id: TrackId, armed: bool) -> anyhow::Result<()> {
+    let track_type = {
+        let mut app = get_app_write();
+        let track_arc = app.tracks
+            .get_mut(&track_id)
+            .ok_or_else(|| anyhow::anyhow!("Track {:?} not found", track_id))?;
+        if armed && track_arc.track_type == TrackType::Automation {
+            return Err(anyhow::anyhow!("Automation tracks cannot be armed for recording"));
+        }
+        Arc::make_mut(track_arc).record_armed = armed;
+        track_arc.track_type.clone()
+    };
+
+    if armed && track_type == TrackType::Audio && !is_input_open() {
+        open_selected_input()?;
+    }
+    broadcast_state_change();
+    Ok(())
+}
+
+pub fn get_armed_tracks() -> Vec<TrackId> {
+    let app = get_app_read();
+    app.tracks
+        .values()
+        .filter(|track| track.record_armed)
+        .map(|track| track.id)
+        .collect()
+}
+
+fn armed_tracks_of_type(track_type: TrackType) -> Vec<TrackId> {
+    let app = get_app_read();
+    app.tracks
+        .values()
+        .filter(|track| track.record_armed && track.track_type == track_type)
+        .map(|track| track.id)
+        .collect()
+}
+
+/// Sets when an armed audio track plays its live input through its channel strip.
+pub fn set_track_monitor_mode(track_id: TrackId, mode: MonitorMode) -> anyhow::Result<()> {
+    {
+        let mut app = get_app_write();
+        let track_arc = app.tracks
+            .get_mut(&track_id)
+            .ok_or_else(|| anyhow::anyhow!("Track {:?} not found", track_id))?;
+        if track_arc.track_type != TrackType::Audio {
+            return Err(anyhow::anyhow!("Only audio tracks can monitor the input"));
+        }
+        Arc::make_mut(track_arc).monitor_mode = mode;
+    }
+    broadcast_state_change();
+    Ok(())
+}
+
+pub fn get_track_monitor_mode(track_id: TrackId) -> anyhow::Result<MonitorMode> {
+    let app = get_app_read();
+    app.tracks
+        .get(&track_id)
+        .map(|track| track.monitor_mode)
+        .ok_or_else(|| anyhow::anyhow!("Track {:?} not found", track_id))
+}
+
+// =========================================================================
+// Recording
+// =========================================================================
+
+pub fn is_recording() -> bool {
+    ctx().recording.lock().is_some()
+}
+
+pub fn get_midi_record_settings() -> MidiRecordSettings {
+    let app = get_app_read();
+    app.transport.midi_record
+}
+
+/// Sets overdub/replace and the input quantize grid used by the next note takes.
+pub fn set_midi_record_settings(mode: MidiRecordMode, quantize: Option<GridDivision>) {
+    let mut app = get_app_write();
+    app.transport.midi_record = MidiRecordSettings { mode, quantize };
+}
+
+/// Starts song playback and records the armed tracks: the audio input onto armed audio tracks,
+/// live notes onto armed MIDI tracks. Capture begins once the count-in (if any) has elapsed.
+pub fn start_recording() -> anyhow::Result<()> {
+    if is_recording() {
+        return Err(anyhow::anyhow!("Already recording"));
+    }
+    let audio_tracks = armed_tracks_of_type(TrackType::Audio);
+    let midi_tracks = armed_tracks_of_type(TrackType::Midi);
+    if audio_tracks.is_empty() && midi_tracks.is_empty() {
+        return Err(anyhow::anyhow!("No track is armed for recording"));
+    }
+
+    let mut audio = None;
+    if !audio_tracks.is_empty() {
+        if !is_input_open() {
+            open_selected_input()?;
+        }
+        let sample_rate = get_app_read().audio_config.sample_rate;
+        let (path, name) = next_take_path()?;
+        let (take, audio_take) = TakeHandle::start(&path, sample_rate)?;
+        log::info!("Recording {} to {:?}", name, take.path());
+
+        send_audio_command(AudioCommand::StartRecording(audio_take));
+        audio = Some(AudioRecording { take, name, tracks: audio_tracks });
+    }
+
+    let mut notes = None;
+    if !midi_tracks.is_empty() {
+        let (handle, note_take) = NoteTakeHandle::start();
+        send_audio_command(AudioCommand::StartNoteRecording(note_take));
+        notes = Some(handle);
+    }
+
+    *ctx().recording.lock() = Some(ActiveRecording { audio, notes });
+    send_audio_command(AudioCommand::SetPlaying(true));
+    Ok(())
+}
+
+/// Stops recording and playback, then writes the takes into the project as one undo step:
+/// - the audio take becomes a clip on every armed audio track, at the position recording
+///   started, shifted back by the device round-trip latency;
+/// - recorded notes go into the pattern of the MIDI clip under the take start, or a new clip.
+///
+/// Returns the clips created or extended by the takes (empty when nothing was recorded).
+/// When a take fails to import, the other one is still placed, whatever made it into the
+/// project is still recorded as an undo step, and the first error is returned.
+pub fn stop_recording() -> anyhow::Result<Vec<(TrackId, Clip)>> {
+    let Some(recording) = ctx().recording.lock().take() else {
+        return Ok(Vec::new());
+    };
+    if recording.audio.is_some() {
+        send_audio_command(AudioCommand::StopRecording);
+    }
+    if recording.notes.is_some() {
+        send_audio_command(AudioCommand::StopNoteRecording);
+    }
+    send_audio_command(AudioCommand::SetPlaying(false));
+
+    let mut clips = Vec::new();
+    let mut actions = Vec::new();
+    let mut result = Ok(());
+
+    if let Some(audio) = recording.audio {
+        result = place_audio_take(audio, &mut clips, &mut actions);
+    }
+    if let Some(notes) = recording.notes {
+        let recorded = notes.finish();
+        let mut app = get_app_write();
+        let placed = place_note_take(&mut app, &recorded, &mut clips, &mut actions);
+        result = result.and(placed);
+    }
+
+    if !actions.is_empty() {
+        let mut history = get_history_lock();
+        if actions.len() == 1 {
+            history.push(actions.remove(0));
+        } else {
+            history.push(ProjectAction::Batch(actions));
+        }
+    }
+
+    broadcast_state_change();
+    result.map(|()| clips)
+}
+
+fn place_audio_take(
+    audio: AudioRecording,
+    clips: &mut Vec<(TrackId, Clip)>,
+    actions: &mut Vec<ProjectAction>
+) -> anyhow::Result<()> {
+    let take = audio.take.finish()?;
+    let start_sample = match take.start_sample {
+        Some(start) if take.frames > 0 => start,
+        // The transport never rolled past the count-in
+        _ => {
+            let _ = std::fs::remove_file(&take.path);
+            return Ok(());
+        }
+    };
+
+    let latency = ctx().device_latency.round_trip() as u64;
+    let path = take.path.to_string_lossy().into_owned();
+    let converter = get_app_read().tick_converter();
+
+    // A take that starts inside the latency window is trimmed at the song start
+    let timeline_start = start_sample.saturating_sub(latency);
+    let trimmed = (start_sample + take.frames).saturating_sub(latency) - timeline_start;
+    let start_time = converter.samples_to_ticks(timeline_start).ticks() as u32;
+    let offset_start = converter.span_to_ticks(0u64, latency.saturating_sub(start_sample)) as u32;
+    let loop_length = converter.span_to_ticks(start_time, trimmed) as u32;
+    if loop_length == 0 {
+        // Nothing is left after the latency trim, don't import it
+        let _ = std::fs::remove_file(&take.path);
+        return Ok(());
+    }
+
+    // Decoding the take is slow, keep it outside the project lock
+    let waveform = load_audio_file(&path, Some(&audio.name))?;
+    let mut app = get_app_write();
+    let source_id = app.insert_audio(waveform);
+
+    for track_id in audio.tracks {
+        let clip = Clip {
+            name: audio.name.clone(),
+            id: ClipId::next(&mut app.clip_counter),
+            start_time,
+            source: KarbeatSource::Audio(source_id),
+            offset_start,
+            loop_length,
+            groove: None,
+        };
+        match app.add_clip_to_track(track_id, clip.clone(), true) {
+            Ok(()) => {
+                actions.push(ProjectAction::AddClip { track_id, clip: clip.clone() });
+                clips.push((track_id, clip));
+            }
+            Err(e) => log::warn!("Could not place take on track {:?}: {}", track_id, e),
+        }
+    }
+    Ok(())
+}
+
+fn place_note_take(
+    app: &mut ApplicationState,
+    recorded: &RecordedNotes,
+    clips: &mut Vec<(TrackId, Clip)>,
+    actions: &mut Vec<ProjectAction>
+) -> anyhow::Result<()> {
+    let mut by_track: BTreeMap<TrackId, Vec<RecordedNote>> = BTreeMap::new();
+    for note in &recorded.notes {
+        by_track.entry(note.track_id).or_default().push(*note);
+    }
+
+    // A track that fails doesn't keep the others from getting their notes, the actions
+    // collected so far stay in `actions` for the caller to record
+    let mut result = Ok(());
+    for (track_id, notes) in by_track {
+        let is_midi_track = app.tracks
+            .get(&track_id)
+            .is_some_and(|track| track.track_type == TrackType::Midi);
+        if !is_midi_track {
+            continue;
+        }
+        let placed = place_track_notes(app, recorded, track_id, notes, clips, actions);
+        result = result.and(placed);
+    }
+    result
+}
+
+fn place_track_notes(
+    app: &mut ApplicationState,
+    recorded: &RecordedNotes,
+    track_id: TrackId,
+    notes: Vec<RecordedNote>,
+    clips: &mut Vec<(TrackId, Clip)>,
+    actions: &mut Vec<ProjectAction>
+) -> anyhow::Result<()> {
+    let settings = app.transport.midi_record;
+
+    // Input quantize moves note starts, the played lengths are kept
+    let notes: Vec<(RecordedNote, u64, u64)> = notes
+        .into_iter()
+        .map(|note| {
+            let duration = note.end_tick.saturating_sub(note.start_tick).max(1);
+            let start = match settings.quantize {
+                Some(grid) =>
+                    app.transport.time_signature_map.snap(note.start_tick, grid, SnapMode::Nearest),
+                None => note.start_tick,
+            };
+            (note, start, duration)
+        })
+        .collect();
+
+    let first_note = notes.iter().map(|(_, start, _)| *start).min().unwrap_or(0);
+    let last_note = notes
+        .iter()
+        .map(|(_, start, duration)| start + duration)
+        .max()
+        .unwrap_or(0);
+    let take_start = recorded.start_tick.unwrap_or(first_note).min(first_note);
+    let take_end = recorded.end_tick.unwrap_or(last_note).max(last_note);
+
+    let (clip, pattern_id) = target_clip(app, track_id, take_start, take_end, actions)?;
+    clips.push((track_id, clip.clone()));
+
+    // Pattern tick 0 sits at the clip start minus its offset, and the clip loops the
+    // pattern from there. Song ticks are folded into the pattern so every repetition
+    // gets the notes instead of the pattern growing
+    let anchor = (clip.start_time as u64).saturating_sub(clip.offset_start as u64);
+    let pattern_arc = app.pattern_pool
+        .get_mut(&pattern_id)
+        .ok_or_else(|| anyhow::anyhow!("Pattern {} not found", pattern_id.to_u32()))?;
+    let pattern: &mut Pattern = Arc::make_mut(pattern_arc);
+    let pattern_len = pattern.length_ticks;
+    if pattern_len == 0 {
+        return Err(anyhow::anyhow!("Pattern {} is empty", pattern_id.to_u32()));
+    }
+
+    if settings.mode == MidiRecordMode::Replace {
+        let from = take_start.saturating_sub(anchor);
+        let to = take_end.saturating_sub(anchor);
+        let replaced: Vec<Note> = pattern.notes
+            .iter()
+            .filter(|n| in_looped_range(n.start_tick, from, to, pattern_len))
+            .cloned()
+            .collect();
+        let replaced_ids: Vec<NoteId> = replaced
+            .iter()
+            .map(|n| n.id)
+            .collect();
+        pattern.delete_notes_by_id(replaced_ids.into());
+        actions.extend(replaced.into_iter().map(|note| ProjectAction::DeleteNote { pattern_id, note }));
+    }
+
+    for (recorded_note, start, duration) in notes {
+        if start < anchor {
+            log::warn!("Dropping note {} played before its clip", recorded_note.key);
+            continue;
+        }
+        // Notes are cut at the pattern end rather than growing the pattern
+        let start_tick = (start - anchor) % pattern_len;
+        let note = pattern.insert_note(Note {
+            id: NoteId::default(),
+            start_tick,
+            duration: duration.min(pattern_len - start_tick),
+            key: recorded_note.key,
+            velocity: recorded_note.velocity,
+            probability: 1.0,
+            micro_offset: 0,
+            mute: false,
+        })?;
+        actions.push(ProjectAction::AddNote { pattern_id, note });
+    }
+    Ok(())
+}
+
+/// Whether a pattern tick falls in the song span `from..to` (relative to the pattern start) of a
+/// clip looping the pattern every `pattern_len` ticks.
+fn in_looped_range(tick: u64, from: u64, to: u64, pattern_len: u64) -> bool {
+    if to <= from {
+        return false;
+    }
+    if to - from >= pattern_len {
+        return true;
+    }
+    let (from, to) = (from % pattern_len, to % pattern_len);
+    if from < to {
+        tick >= from && tick < to
+    } else {
+        // The span crosses a repetition boundary
+        tick >= from || tick < to
+    }
+}
+
+/// Finds the MIDI clip under `take_start` on the track, extending it to `take_end` if needed,
+/// or creates a bar-aligned clip with a new pattern covering the take.
+fn target_clip(
+    app: &mut ApplicationState,
+    track_id: TrackId,
+    take_start: u64,
+    take_end: u64,
+    actions: &mut Vec<ProjectAction>
+) -> anyhow::Result<(Clip, PatternId)> {
+    let track_arc = app.tracks
+        .get_mut(&track_id)
+        .ok_or_else(|| anyhow::anyhow!("Track {:?} not found", track_id))?;
+
+    let existing = track_arc.clips
+        .iter()
+        .find(|clip| {
+            let start = clip.start_time as u64;
+            let end = start + (clip.loop_length as u64);
+            matches!(clip.source, KarbeatSource::Midi(_)) && start <= take_start && take_start < end
+        })
+        .cloned();
+
+    if let Some(old_clip) = existing {
+        let KarbeatSource::Midi(pattern_id) = old_clip.source else {
+            return Err(anyhow::anyhow!("Clip {:?} has no pattern", old_clip.id));
+        };
+        let clip_end = (old_clip.start_time as u64) + (old_clip.loop_length as u64);
+        if take_end <= clip_end {
+            return Ok(((*old_clip).clone(), pattern_id));
+        }
+
+        let mut new_clip = (*old_clip).clone();
+        new_clip.loop_length = (take_end - (old_clip.start_time as u64)) as u32;
+        let track = Arc::make_mut(track_arc);
+        track.clips.retain(|c| c.id != old_clip.id);
+        track.clips.insert(Arc::new(new_clip.clone()));
+        track.update_max_tick_index();
+        app.update_max_tick_index();
+
+        actions.push(ProjectAction::ResizeClip {
+            track_id,
+            old_clip: (*old_clip).clone(),
+            new_clip: new_clip.clone(),
+        });
+        return Ok((new_clip, pattern_id));
+    }
+
+    let signatures = &app.transport.time_signature_map;
+    let start = signatures.snap(take_start, GridDivision::Bar, SnapMode::Floor);
+    let mut end = signatures.snap(take_end, GridDivision::Bar, SnapMode::Floor);
+    if end < take_end || end == start {
+        end += signatures.ticks_per_bar_at(end);
+    }
+
+    let pattern_id = PatternId::next(&mut app.pattern_counter);
+    let name = format!("Pattern {}", pattern_id.to_u32());
+    app.pattern_pool.insert(
+        pattern_id,
+        Arc::new(Pattern {
+            id: pattern_id,
+            name: name.clone(),
+            length_ticks: end - start,
+            notes: Vec::new(),
+            next_note_id: 0,
+            groove: None,
+            scale: None,
+        })
+    );
+
+    let clip = Clip {
+        name,
+        id: ClipId::next(&mut app.clip_counter),
+        start_time: start as u32,
+        source: KarbeatSource::Midi(pattern_id),
+        offset_start: 0,
+        loop_length: (end - start) as u32,
+        groove: None,
+    };
+    app.add_clip_to_track(track_id, clip.clone(), true)?;
+    actions.push(ProjectAction::AddClip { track_id, clip: clip.clone() });
+    Ok((clip, pattern_id))
+}
+
+/// Opens the input device stored in the audio config, falling back to the default input.
+pub(crate) fn open_selected_input() -> anyhow::Result<()> {
+    let selected = get_app_read().audio_config.selected_input_device.clone();
+    if !selected.is_empty() {
+        match backend::start_input_stream(Some(&selected)) {
+            Ok(_) => {
+                return Ok(());
+            }
+            Err(e) => log::warn!("Selected input '{}' unavailable ({}), using default", selected, e),
+        }
+    }
+    backend::start_input_stream(None).map(|_| ())
+}
@@ -1,8 +1,18 @@
+use crate::api::recording_api;
 use crate::commands::AudioCommand;
 use crate::context::utils::{broadcast_state_change, send_audio_command};
-use crate::lock::get_app_write;
+use crate::core::project::musical_time::{
+    MusicalPosition, TimeSignature, TimeSignatureEvent, TimeSignatureMap,
+};
+use crate::core::project::tempo_map::{TempoMap, TempoPoint, TempoRamp, TickConverter};
+use crate::core::project::transport::{LoopRegion, MetronomeSettings};
+use crate::shared::id::BusId;
+use crate::lock::{get_app_read, get_app_write};
 
 pub fn set_playing(val: bool) {
+    if !val {
+        finish_recording();
+    }
     send_audio_command(AudioCommand::SetPlaying(val));
 }
 
@@ -14,15 +24,240 @@ pub fn set_looping(val: bool) {
     send_audio_command(AudioCommand::SetLooping(val));
 }
 
+/// Sets the loop in/out points (in ticks) and persists them in the project.
+pub fn set_loop_region(start_tick: u64, end_tick: u64) -> anyhow::Result<LoopRegion> {
+    let region = LoopRegion::new(start_tick, end_tick).ok_or_else(|| {
+        anyhow::anyhow!("Invalid loop region: end {end_tick} must be after start {start_tick}")
+    })?;
+
+    {
+        let mut app = get_app_write();
+        app.transport.loop_region = Some(region);
+    }
+    send_audio_command(AudioCommand::SetLoopRegion(Some(region)));
+    Ok(region)
+}
+
+/// Removes the loop in/out points, looping falls back to the whole song.
+pub fn clear_loop_region() {
+    {
+        let mut app = get_app_write();
+        app.transport.loop_region = None;
+    }
+    send_audio_command(AudioCommand::SetLoopRegion(None));
+}
+
+pub fn get_loop_region() -> Option<LoopRegion> {
+    let app = get_app_read();
+    app.transport.loop_region
+}
+
+// =========================================================================
+// Metronome
+// =========================================================================
+
+pub fn get_metronome_settings() -> MetronomeSettings {
+    let app = get_app_read();
+    app.transport.metronome
+}
+
+pub fn set_metronome_enabled(enabled: bool) {
+    {
+        let mut app = get_app_write();
+        app.transport.metronome.enabled = enabled;
+    }
+    send_audio_command(AudioCommand::SetMetronomeEnabled(enabled));
+}
+
+/// Sets the click gain, clamped to 0.0 - 1.0.
+pub fn set_metronome_volume(volume: f32) {
+    let volume = volume.clamp(0.0, 1.0);
+    {
+        let mut app = get_app_write();
+        app.transport.metronome.volume = volume;
+    }
+    send_audio_command(AudioCommand::SetMetronomeVolume(volume));
+}
+
+/// Routes the click to a bus. `None` sends it straight to the audio output.
+pub fn set_metronome_target(target: Option<BusId>) -> anyhow::Result<()> {
+    {
+        let mut app = get_app_write();
+        if let Some(bus_id) = target {
+            if !app.mixer.buses.contains_key(&bus_id) {
+                return Err(anyhow::anyhow!("Bus {:?} not found", bus_id));
+            }
+        }
+        app.transport.metronome.target = target;
+    }
+    send_audio_command(AudioCommand::SetMetronomeTarget(target));
+    Ok(())
+}
+
+/// Sets the number of count-in bars (0 disables the count-in).
+pub fn set_count_in_bars(bars: u8) -> anyhow::Result<()> {
+    if bars > MetronomeSettings::MAX_COUNT_IN_BARS {
+        return Err(anyhow::anyhow!(
+            "Count-in must be between 0 and {} bars",
+            MetronomeSettings::MAX_COUNT_IN_BARS
+        ));
+    }
+    {
+        let mut app = get_app_write();
+        app.transport.metronome.count_in_bars = bars;
+    }
+    send_audio_command(AudioCommand::SetCountInBars(bars));
+    Ok(())
+}
+
+/// Pushes the stored metronome settings to the audio thread (e.g. after loading a project).
+pub(crate) fn sync_metronome(settings: MetronomeSettings) {
+    send_audio_command(AudioCommand::SetMetronomeEnabled(settings.enabled));
+    send_audio_command(AudioCommand::SetMetronomeVolume(settings.volume));
+    send_audio_command(AudioCommand::SetMetronomeTarget(settings.target));
+    send_audio_command(AudioCommand::SetCountInBars(settings.count_in_bars));
+}
+
 pub fn set_bpm(val: f32) {
     {
         let mut app = get_app_write();
-        app.transport.bpm = val;
+        app.transport.set_bpm(val);
     }
     send_audio_command(AudioCommand::SetBPM(val));
     broadcast_state_change();
 }
 
 pub fn stop_song_playback() {
+    finish_recording();
     send_audio_command(AudioCommand::StopAndReset);
-}
\ No newline at end of file
+}
+
+/// Stopping the transport ends a running recording and places its take.
+fn finish_recording() {
+    if recording_api::is_recording() {
+        if let Err(e) = recording_api::stop_recording() {
+            log::error!("Failed to finish recording: {}", e);
+        }
+    }
+}
+
+/// Seed of the note probability rolls. Patterns with probabilistic notes play the same way
+/// for the same seed, in playback and in exports.
+pub fn get_random_seed() -> u64 {
+    let app = get_app_read();
+    app.transport.random_seed
+}
+
+pub fn set_random_seed(seed: u64) {
+    {
+        let mut app = get_app_write();
+        app.transport.random_seed = seed;
+    }
+    broadcast_state_change();
+}
+
+// =========================================================================
+// Tempo Map
+// =========================================================================
+
+/// Snapshot of the tick <-> sample converter for the current session.
+/// Take it before mapping project data so conversions don't need the project lock.
+pub fn get_tick_converter() -> TickConverter {
+    let app = get_app_read();
+    app.tick_converter()
+}
+
+pub fn get_tempo_map<T, F>(mapper: F) -> T
+where
+    F: FnOnce(&TempoMap) -> T,
+{
+    let app = get_app_read();
+    mapper(&app.transport.tempo_map)
+}
+
+/// Adds (or replaces) a tempo change at `tick`. A point at tick 0 also updates the project BPM.
+pub fn set_tempo_point(tick: u64, bpm: f32, ramp: TempoRamp) -> anyhow::Result<()> {
+    if !bpm.is_finite() || bpm <= 0.0 {
+        return Err(anyhow::anyhow!("Invalid tempo: {bpm}"));
+    }
+
+    {
+        let mut app = get_app_write();
+        if tick == 0 {
+            app.transport.bpm = bpm;
+        }
+        app.transport.tempo_map.insert_point(TempoPoint::new(tick, bpm, ramp));
+    }
+    if tick == 0 {
+        send_audio_command(AudioCommand::SetBPM(bpm));
+    }
+    broadcast_state_change();
+    Ok(())
+}
+
+/// Removes the tempo change at `tick`. The initial tempo point can't be removed.
+pub fn remove_tempo_point(tick: u64) -> anyhow::Result<TempoPoint> {
+    let removed = {
+        let mut app = get_app_write();
+        app.transport.tempo_map
+            .remove_point_at(tick)
+            .ok_or_else(|| anyhow::anyhow!("No removable tempo point at tick {tick}"))?
+    };
+    broadcast_state_change();
+    Ok(removed)
+}
+
+// =========================================================================
+// Time Signature
+// =========================================================================
+
+pub fn get_time_signature_map<T, F>(mapper: F) -> T
+where
+    F: FnOnce(&TimeSignatureMap) -> T,
+{
+    let app = get_app_read();
+    mapper(&app.transport.time_signature_map)
+}
+
+/// Adds (or replaces) a meter change starting on `bar` (0-based).
+/// A change on bar 0 also updates the project time signature.
+pub fn set_time_signature(bar: u32, signature: TimeSignature) -> anyhow::Result<()> {
+    if !signature.is_valid() {
+        return Err(anyhow::anyhow!(
+            "Invalid time signature: {}/{}",
+            signature.numerator,
+            signature.denominator
+        ));
+    }
+
+    {
+        let mut app = get_app_write();
+        if bar == 0 {
+            app.transport.set_time_signature(signature);
+        } else {
+            app.transport
+                .time_signature_map
+                .insert(TimeSignatureEvent::new(bar, signature));
+        }
+    }
+    broadcast_state_change();
+    Ok(())
+}
+
+/// Removes the meter change on `bar`. The initial signature can't be removed.
+pub fn remove_time_signature(bar: u32) -> anyhow::Result<TimeSignatureEvent> {
+    let removed = {
+        let mut app = get_app_write();
+        app.transport.time_signature_map
+            .remove_at(bar)
+            .ok_or_else(|| anyhow::anyhow!("No removable time signature on bar {bar}"))?
+    };
+    broadcast_state_change();
+    Ok(removed)
+}
+
+/// Bar/beat/tick breakdown of a timeline position.
+pub fn get_musical_position(tick: u64) -> MusicalPosition {
+    let app = get_app_read();
+    app.transport.time_signature_map.position_at(tick)
+}
@@ -0,0 +1,261 @@
+// src/audio/analyzer.rs
+//
+// Spectrum analyzer taps. A tap copies the mono sum of one mixer strip into a lock-free ring on
+// the audio thread, a worker thread per tap reads it back and computes windowed FFT magnitude
+// spectra. The audio thread never does more than push samples.
+
+use std::{
+    sync::{ Arc, atomic::{ AtomicBool, Ordering } },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use anyhow::Context;
+use hashbrown::HashMap;
+use karbeat_dsp::{ spectrum::{ SpectrumAnalyzer, log_spaced }, windowing::Windowing };
+use rtrb::{ Consumer, Producer };
+
+use crate::core::project::mixer::RoutingNode;
+
+/// Taps the engine feeds at once, its slots are reserved up front
+pub const MAX_ANALYZER_TAPS: usize = 8;
+
+/// How long a worker sleeps when its tap has nothing new
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Overlap is kept below 1 so every spectrum has at least a few new samples
+const MAX_OVERLAP: f32 = 0.95;
+
+/// Frequency span of [`SpectrumFrame::log_spaced`], the same as the EQ response curve
+const CURVE_MIN_FREQ: f32 = 20.0;
+const CURVE_MAX_FREQ: f32 = 20_000.0;
+
+/// How the spectra of a tap are computed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnalyzerConfig {
+    /// Samples per FFT, rounded to a power of two
+    pub fft_size: usize,
+    /// Share of each FFT block that the next one reuses (0.0–0.95)
+    pub overlap: f32,
+    pub window: Windowing,
+}
+
+impl Default for AnalyzerConfig {
+    fn default() -> Self {
+        Self { fft_size: 2048, overlap: 0.5, window: Windowing::BlackmanHarris }
+    }
+}
+
+/// Audio thread side of a tap.
+pub struct AnalyzerTap {
+    pub id: u32,
+    pub node: RoutingNode,
+    pub producer: Producer<f32>,
+}
+
+impl AnalyzerTap {
+    /// Pushes the mono sum of an interleaved buffer into every tap on `node`.
+    /// Samples that don't fit are dropped, the worker just sees a gap.
+    pub fn feed(taps: &mut [AnalyzerTap], node: RoutingNode, buffer: &[f32], channels: usize) {
+        let channels = channels.max(1);
+        let gain = 1.0 / (channels as f32);
+        for tap in taps.iter_mut().filter(|tap| tap.node == node) {
+            let frames = (buffer.len() / channels).min(tap.producer.slots());
+            let Ok(mut chunk) = tap.producer.write_chunk(frames) else {
+                continue;
+            };
+            let (first, second) = chunk.as_mut_slices();
+            for (slot, frame) in first
+                .iter_mut()
+                .chain(second.iter_mut())
+                .zip(buffer.chunks_exact(channels)) {
+                *slot = frame.iter().sum::<f32>() * gain;
+            }
+            chunk.commit_all();
+        }
+    }
+}
+
+/// One spectrum of a tap.
+#[derive(Clone, Debug)]
+pub struct SpectrumFrame {
+    pub analyzer_id: u32,
+    pub node: RoutingNode,
+    pub sample_rate: u32,
+    pub fft_size: usize,
+    /// dBFS from DC to Nyquist, `fft_size / 2 + 1` bins
+    pub magnitudes: Vec<f32>,
+}
+
+impl SpectrumFrame {
+    /// Frequency of a bin in [`Self::magnitudes`].
+    pub fn bin_frequency(&self, bin: usize) -> f32 {
+        ((bin as f32) * (self.sample_rate as f32)) / (self.fft_size.max(1) as f32)
+    }
+
+    /// The spectrum as `(frequency, dB)` at the log-spaced points of the EQ response curve.
+    pub fn log_spaced(&self, num_points: usize) -> Vec<(f32, f32)> {
+        log_spaced(&self.magnitudes, self.sample_rate, CURVE_MIN_FREQ, CURVE_MAX_FREQ, num_points)
+    }
+}
+
+/// Receives the spectra of a tap, returns false to stop the analyzer.
+pub type SpectrumSink = Box<dyn FnMut(SpectrumFrame) -> bool + Send>;
+
+/// Called on the worker when it ends by itself: the sink returned false or the tap was
+/// abandoned. Not called when the handle stops it.
+pub type AnalyzerExit = Box<dyn FnOnce() + Send>;
+
+/// Worker thread of a tap, stopped when dropped.
+pub struct AnalyzerHandle {
+    pub node: RoutingNode,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl AnalyzerHandle {
+    /// Starts computing spectra from `consumer`. The worker ends when the handle is dropped,
+    /// the sink returns false or the audio engine that fed the tap goes away, the last two
+    /// calling `on_exit`.
+    pub fn spawn(
+        analyzer_id: u32,
+        node: RoutingNode,
+        mut consumer: Consumer<f32>,
+        config: AnalyzerConfig,
+        sample_rate: u32,
+        mut sink: SpectrumSink,
+        on_exit: AnalyzerExit
+    ) -> anyhow::Result<Self> {
+        let stop = Arc::new(AtomicBool::new(false));
+        let worker_stop = Arc::clone(&stop);
+
+        let thread = std::thread::Builder
+            ::new()
+            .name(format!("karbeat-analyzer-{}", analyzer_id))
+            .spawn(move || {
+                let mut analyzer = SpectrumAnalyzer::new(config.fft_size, config.window);
+                let size = analyzer.size();
+                let overlap = config.overlap.clamp(0.0, MAX_OVERLAP);
+                let hop = (((size as f32) * (1.0 - overlap)) as usize).max(1);
+                // Last `size` samples, oldest at `write`
+                let mut history = vec![0.0; size];
+                let mut block = vec![0.0; size];
+                let mut write = 0;
+                let mut fresh = 0;
+
+                while !worker_stop.load(Ordering::Acquire) {
+                    let available = consumer.slots();
+                    if available == 0 {
+                        if consumer.is_abandoned() {
+                            break;
+                        }
+                        std::thread::sleep(POLL_INTERVAL);
+                        continue;
+                    }
+
+                    if let Ok(chunk) = consumer.read_chunk(available) {
+                        for sample in chunk {
+                            history[write] = sample;
+                            write = (write + 1) % size;
+                        }
+                    }
+                    fresh += available;
+                    if fresh < hop {
+                        continue;
+                    }
+                    // Behind by several hops: only the newest spectrum is worth computing
+                    fresh = 0;
+
+                    let (newest, oldest) = history.split_at(write);
+                    block[..oldest.len()].copy_from_slice(oldest);
+                    block[oldest.len()..].copy_from_slice(newest);
+                    let frame = SpectrumFrame {
+                        analyzer_id,
+                        node,
+                        sample_rate,
+                        fft_size: size,
+                        magnitudes: analyzer.analyze(&block).to_vec(),
+                    };
+                    if !sink(frame) {
+                        break;
+                    }
+                }
+
+                if !worker_stop.load(Ordering::Acquire) {
+                    on_exit();
+                }
+            })
+            .context("Failed to spawn analyzer thread")?;
+
+        Ok(Self { node, stop, thread: Some(thread) })
+    }
+}
+
+impl Drop for AnalyzerHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            // `on_exit` may drop the handle on the worker itself, which is ending anyway
+            if thread.thread().id() != std::thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+/// Running analyzers by id.
+#[derive(Default)]
+pub struct SpectrumAnalyzers {
+    pub counter: u32,
+    pub running: HashMap<u32, AnalyzerHandle>,
+}
+
+#[cfg(test)]
+mod test {
+    use rtrb::RingBuffer;
+
+    use super::*;
+
+    #[test]
+    fn tapped_sine_shows_up_in_the_spectrum() -> anyhow::Result<()> {
+        let sample_rate = 48_000;
+        let (producer, consumer) = RingBuffer::new(8192);
+        let mut taps = vec![AnalyzerTap { id: 1, node: RoutingNode::Master, producer }];
+
+        let (frames_in, frames_out) = std::sync::mpsc::channel();
+        let config = AnalyzerConfig { fft_size: 1024, ..AnalyzerConfig::default() };
+        let handle = AnalyzerHandle::spawn(
+            1,
+            RoutingNode::Master,
+            consumer,
+            config,
+            sample_rate,
+            Box::new(move |frame| frames_in.send(frame).is_ok()),
+            Box::new(|| {})
+        )?;
+
+        // 1.5 kHz on both channels, and something else on a bus nobody taps
+        let frequency = 1_500.0;
+        let buffer: Vec<f32> = (0..1024)
+            .flat_map(|i| {
+                let t = (i as f32) / (sample_rate as f32);
+                let s = 0.5 * (2.0 * std::f32::consts::PI * frequency * t).sin();
+                [s, s]
+            })
+            .collect();
+        AnalyzerTap::feed(&mut taps, RoutingNode::Bus(1.into()), &[1.0; 64], 2);
+        AnalyzerTap::feed(&mut taps, RoutingNode::Master, &buffer, 2);
+
+        let frame = frames_out.recv_timeout(Duration::from_secs(5))?;
+        assert_eq!(frame.magnitudes.len(), 513);
+        let loudest = (0..frame.magnitudes.len())
+            .max_by(|&a, &b| frame.magnitudes[a].total_cmp(&frame.magnitudes[b]))
+            .unwrap_or_default();
+        assert!((frame.bin_frequency(loudest) - frequency).abs() < 50.0);
+        // -6 dBFS sine, give or take the scalloping between bins
+        assert!((frame.magnitudes[loudest] + 6.0).abs() < 1.5, "{}", frame.magnitudes[loudest]);
+
+        drop(handle);
+        Ok(())
+    }
+}
@@ -0,0 +1,295 @@
+// src/audio/automation.rs
+//
+// Sample-accurate automation. Each block, lanes are sampled every few frames and turned into
+// events that carry the frame their value is reached at. Channel strips ramp volume and pan
+// through those points sample by sample, and plugins are processed in pieces split where their
+// parameters change, so fast curves no longer step at the buffer rate.
+
+use std::ops::Range;
+
+use karbeat_utils::audio::db_to_linear;
+use wide::f32x4;
+
+use crate::{
+    audio::{
+        event::{ AutomationEvent, ChannelAutomationEvent },
+        render_state::{ AudioAutomationLane, AudioEffectInstance },
+    },
+    core::project::{ mixer::MixerChannel, tempo_map::TempoMap },
+};
+
+/// Frames between the points a lane is sampled at, under a millisecond at 48 kHz
+pub const AUTOMATION_INTERVAL: usize = 32;
+
+/// Samples `lane` over a block of `frames` starting at `playhead`. Flat stretches are passed to
+/// `push` by their two ends only, so a lane that doesn't move gives a single point at 0. The
+/// last point may sit at `frames`, the start of the next block, to give the final slope.
+pub fn sample_lane(
+    lane: &AudioAutomationLane,
+    tempo_map: &TempoMap,
+    playhead: u32,
+    frames: usize,
+    sample_rate: u32,
+    mut push: impl FnMut(usize, f32)
+) {
+    let value_at = |offset: usize| {
+        let tick = tempo_map.sample_to_tick((playhead as u64) + (offset as u64), sample_rate);
+        lane.value_at_ticks(tick.ticks() as u32)
+    };
+
+    let mut last = value_at(0);
+    push(0, last);
+
+    // End of the flat stretch since the last point, not pushed unless the value moves again
+    let mut flat_until = None;
+    let mut offset = 0;
+    while offset < frames {
+        offset = (offset + AUTOMATION_INTERVAL).min(frames);
+        let value = value_at(offset);
+        if value == last {
+            flat_until = Some(offset);
+            continue;
+        }
+        if let Some(flat_offset) = flat_until.take() {
+            push(flat_offset, last);
+        }
+        push(offset, value);
+        last = value;
+    }
+}
+
+/// Splits a block of `frames` at the offsets of the events `is_change` picks. Each range is
+/// processed after applying the changes at its start, see `changes_at`. A block with no such
+/// change inside it is a single range.
+pub fn change_segments<'a, T>(
+    events: &'a [AutomationEvent<T>],
+    frames: usize,
+    is_change: impl Fn(&T) -> bool + 'a
+) -> impl Iterator<Item = Range<usize>> + 'a {
+    let mut start = 0;
+    std::iter::from_fn(move || {
+        if start >= frames {
+            return None;
+        }
+        let end = events
+            .iter()
+            .filter(|event| event.sample_offset > start && event.sample_offset < frames)
+            .filter(|event| is_change(&event.data))
+            .map(|event| event.sample_offset)
+            .min()
+            .unwrap_or(frames);
+        let range = start..end;
+        start = end;
+        Some(range)
+    })
+}
+
+/// The events that take effect at the start of `range`.
+pub fn changes_at<'a, T>(
+    events: &'a [AutomationEvent<T>],
+    range: &Range<usize>
+) -> impl Iterator<Item = &'a T> + 'a {
+    let start = range.start;
+    events
+        .iter()
+        .filter(move |event| event.sample_offset == start)
+        .map(|event| &event.data)
+}
+
+/// Runs a strip's effects chain over `buffer`, applying automated plugin parameters at the
+/// frame they change.
+pub fn process_effects(
+    effects: &mut [AudioEffectInstance],
+    events: &[AutomationEvent<ChannelAutomationEvent>],
+    buffer: &mut [f32],
+    channels: usize
+) {
+    let frames = buffer.len() / channels;
+    let is_plugin_param = |event: &ChannelAutomationEvent| {
+        matches!(event, ChannelAutomationEvent::PluginParam { .. })
+    };
+
+    for range in change_segments(events, frames, is_plugin_param) {
+        for event in changes_at(events, &range) {
+            if let ChannelAutomationEvent::PluginParam { effect_id, param_id, value } = event {
+                if let Some(effect) = effects.iter_mut().find(|e| e.id == *effect_id) {
+                    effect.plugin.apply_automation(*param_id, *value);
+                }
+            }
+        }
+
+        let segment = &mut buffer[range.start * channels..range.end * channels];
+        for effect in effects.iter_mut() {
+            effect.load.time(|| effect.plugin.process(segment));
+        }
+    }
+}
+
+/// Applies a strip's volume (dB) and pan to `buffer`. Automated values are joined by straight
+/// lines between their points, so fades follow the lane sample by sample.
+pub fn apply_channel_gain(
+    channel: &MixerChannel,
+    events: &[AutomationEvent<ChannelAutomationEvent>],
+    buffer: &mut [f32],
+    channels: usize
+) {
+    let mut volume = Line::new(
+        channel.volume.get(),
+        events.iter().filter_map(|event| {
+            match event.data {
+                ChannelAutomationEvent::Volume(v) => {
+                    Some((event.sample_offset, channel.volume.automated_value(v)))
+                }
+                _ => None,
+            }
+        })
+    );
+    let mut pan = Line::new(
+        channel.pan.get(),
+        events.iter().filter_map(|event| {
+            match event.data {
+                ChannelAutomationEvent::Pan(v) => {
+                    Some((event.sample_offset, channel.pan.automated_value(v)))
+                }
+                _ => None,
+            }
+        })
+    );
+
+    if !volume.moves() && !pan.moves() {
+        let (left_gain, right_gain) = pan_gains(volume.at(0), pan.at(0), channels);
+        apply_gain(buffer, left_gain, right_gain, channels);
+        return;
+    }
+
+    for (frame, samples) in buffer.chunks_exact_mut(channels).enumerate() {
+        let (left_gain, right_gain) = pan_gains(volume.at(frame), pan.at(frame), channels);
+        if channels == 2 {
+            samples[0] *= left_gain;
+            samples[1] *= right_gain;
+        } else {
+            samples.iter_mut().for_each(|sample| *sample *= left_gain);
+        }
+    }
+}
+
+/// Left and right gains of a volume in dB and a pan, constant power
+fn pan_gains(volume_db: f32, pan: f32, channels: usize) -> (f32, f32) {
+    let volume = db_to_linear(volume_db);
+    if channels == 2 {
+        let p = (pan + 1.0) * 0.5;
+        ((1.0 - p).sqrt() * volume, p.sqrt() * volume)
+    } else {
+        (volume, volume)
+    }
+}
+
+fn apply_gain(buffer: &mut [f32], left_gain: f32, right_gain: f32, channels: usize) {
+    let gain_v = if channels == 2 {
+        f32x4::new([left_gain, right_gain, left_gain, right_gain])
+    } else {
+        f32x4::splat(left_gain)
+    };
+    let mut iter = buffer.chunks_exact_mut(4);
+
+    for chunk in iter.by_ref() {
+        let mut v = f32x4::new([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        v *= gain_v;
+        chunk.copy_from_slice(&v.to_array());
+    }
+
+    // Remainder will be 0 or 2 floats (1 stereo frame) in stereo
+    if channels == 2 {
+        for chunk in iter.into_remainder().chunks_exact_mut(2) {
+            chunk[0] *= left_gain;
+            chunk[1] *= right_gain;
+        }
+    } else {
+        for sample in iter.into_remainder() {
+            *sample *= left_gain;
+        }
+    }
+}
+
+/// One automated value through a block: held before its first point and after its last,
+/// linear in between. Read frame by frame in order.
+struct Line<I> {
+    from: (usize, f32),
+    to: Option<(usize, f32)>,
+    points: I,
+}
+
+impl<I: Iterator<Item = (usize, f32)>> Line<I> {
+    /// `value` is used when there are no points at all
+    fn new(value: f32, mut points: I) -> Self {
+        let from = (0, points.next().map_or(value, |(_, first)| first));
+        let to = points.next();
+        Self { from, to, points }
+    }
+
+    /// Whether the value changes within the block
+    fn moves(&self) -> bool {
+        self.to.is_some()
+    }
+
+    fn at(&mut self, frame: usize) -> f32 {
+        while let Some(to) = self.to {
+            if to.0 > frame {
+                break;
+            }
+            self.from = to;
+            self.to = self.points.next();
+        }
+        match self.to {
+            Some((offset, value)) => {
+                let t = ((frame - self.from.0) as f32) / ((offset - self.from.0) as f32);
+                self.from.1 + (value - self.from.1) * t
+            }
+            None => self.from.1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::shared::id::EffectId;
+
+    #[test]
+    fn gain_ramps_through_the_points() {
+        let channel = MixerChannel::default();
+        let events = [
+            AutomationEvent { sample_offset: 0, data: ChannelAutomationEvent::Volume(-60.0) },
+            AutomationEvent { sample_offset: 64, data: ChannelAutomationEvent::Volume(0.0) },
+        ];
+        let mut buffer = vec![1.0; 128 * 2];
+        apply_channel_gain(&channel, &events, &mut buffer, 2);
+
+        let left: Vec<f32> = buffer.iter().step_by(2).copied().collect();
+        // -60 dB at the first point, rising every frame up to the second, then held
+        assert!((left[0] - 0.001 * (0.5f32).sqrt()).abs() < 1e-6);
+        assert!(left[1..64].windows(2).all(|pair| pair[1] > pair[0]));
+        assert!(left[64..].iter().all(|&s| (s - left[64]).abs() < 1e-6));
+        assert!((left[64] - (0.5f32).sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn blocks_split_where_plugin_params_change() {
+        let param = |offset| AutomationEvent {
+            sample_offset: offset,
+            data: ChannelAutomationEvent::PluginParam {
+                effect_id: EffectId::from(1),
+                param_id: 0,
+                value: 0.0,
+            },
+        };
+        let volume = AutomationEvent { sample_offset: 32, data: ChannelAutomationEvent::Volume(0.0) };
+        let events = [param(0), volume, param(96), param(96), param(128)];
+
+        let ranges: Vec<_> = change_segments(&events, 128, |event| {
+            matches!(event, ChannelAutomationEvent::PluginParam { .. })
+        }).collect();
+        assert_eq!(ranges, [0..96, 96..128]);
+        assert_eq!(changes_at(&events, &ranges[1]).count(), 2);
+    }
+}
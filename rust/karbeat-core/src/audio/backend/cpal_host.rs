@@ -0,0 +1,624 @@
+// src/audio/backend/cpal_host.rs
+//
+// Sound card output and input through cpal, on the lowest-latency host of each platform.
+
+use anyhow::{ anyhow, Context, Result };
+use cpal::{
+    traits::{ DeviceTrait, HostTrait, StreamTrait },
+    FromSample,
+    InputCallbackInfo,
+    OutputCallbackInfo,
+    SizedSample,
+};
+use rtrb::{ Producer, RingBuffer };
+use std::sync::atomic::Ordering;
+
+use crate::{
+    audio::{
+        backend::{ AudioBackend, AudioOutput, AudioStream, DEFAULT_BUFFER_SIZE, OutputFormat },
+        engine::AudioEngine,
+        recorder::{ AudioInputPort, duration_to_frames },
+    },
+    commands::AudioCommand,
+    context::{ ctx, utils::send_audio_command },
+};
+
+/// Output sample formats a stream can be built for
+const OUTPUT_FORMATS: [cpal::SampleFormat; 4] = [
+    cpal::SampleFormat::F32,
+    cpal::SampleFormat::I16,
+    cpal::SampleFormat::U16,
+    cpal::SampleFormat::U8,
+];
+
+struct AudioContext {
+    engine: AudioEngine,
+    /// Render target for sample formats other than f32
+    scratch: Vec<f32>,
+}
+
+impl AudioContext {
+    /// Renders through `scratch` one block at a time, converting into the device format.
+    fn render_converted<T>(&mut self, data: &mut [T], convert: impl Fn(f32) -> T) {
+        for chunk in data.chunks_mut(self.scratch.len()) {
+            let rendered = &mut self.scratch[..chunk.len()];
+            self.engine.process(rendered);
+            for (out, &sample) in chunk.iter_mut().zip(rendered.iter()) {
+                *out = convert(sample);
+            }
+        }
+    }
+}
+
+/// Macro to generate the stream building logic. The engine renders straight into the
+/// callback buffer when the device takes f32, there is no queue in between.
+/// $device: cpal device
+/// $config: cpal config
+/// $ctx: The AudioContext (moved into the closure)
+/// $sample_type: The primitive type (f32, i16, etc)
+/// $render: A closure |&mut AudioContext, &mut [$sample_type]| filling the callback buffer
+macro_rules! run_stream {
+    (
+        $device:expr,
+        $config:expr,
+        $audio_ctx:expr,
+        $sample_type:ty,
+        $render:expr,
+        $err_fn:expr
+    ) => {
+        {
+        let mut audio_ctx = $audio_ctx;
+        let sample_rate = $config.sample_rate;
+
+        $device.build_output_stream(
+            &$config,
+            move |data: &mut [$sample_type], info: &OutputCallbackInfo| {
+                $render(&mut audio_ctx, data);
+
+                // Only the device's own buffering sits between the engine and the speakers
+                let timestamp = info.timestamp();
+                if let Some(latency) = timestamp.playback.duration_since(&timestamp.callback) {
+                    ctx().device_latency.set_output(duration_to_frames(latency, sample_rate));
+                }
+            },
+            $err_fn,
+            None,
+        )
+        }
+    };
+}
+
+/// Set host to the one chosen in the user settings, otherwise the optimized host for each platform.
+/// - Windows: ASIO, then fallback to WASAPI (low latency)
+/// - Android: AAudio (low latency, requires API 26+)
+/// - Linux: JACK
+/// - Other platforms: default host
+fn set_host() -> cpal::Host {
+    let chosen = ctx().user_settings.lock().audio.host.clone();
+    if let Some(name) = chosen {
+        match host_by_name(&name) {
+            Ok(host) => {
+                log::info!("Connected to {} Host", name);
+                return host;
+            }
+            Err(e) => log::warn!("{}, falling back to the platform host", e),
+        }
+    }
+
+    #[allow(unused_assignments)]
+    let mut host = cpal::default_host();
+
+    #[cfg(target_os = "windows")]
+    {
+        if let Ok(asio_host) = cpal::host_from_id(cpal::HostId::Asio) {
+            host = asio_host;
+            log::info!("Connected to ASIO Host");
+        } else if let Ok(wasapi_host) = cpal::host_from_id(cpal::HostId::Wasapi) {
+            host = wasapi_host;
+            log::info!("Connected to WASAPI Host");
+        } else {
+            log::warn!("Neither ASIO nor WASAPI available, falling back to default host");
+        }
+    }
+
+    #[cfg(target_os = "android")]
+    {
+        match cpal::host_from_id(cpal::HostId::AAudio) {
+            Ok(aaudio_host) => {
+                host = aaudio_host;
+                log::info!("Connected to AAudio Host");
+            }
+            Err(e) => {
+                log::warn!("AAudio not available, falling back to default host: {}", e);
+                host = cpal::default_host();
+            }
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        match cpal::host_from_id(cpal::HostId::Jack) {
+            Ok(jack_host) => {
+                host = jack_host;
+                log::info!("Connected to JACK Host");
+            }
+            Err(e) => {
+                log::warn!("JACK not available, falling back to default host: {}", e);
+                host = cpal::default_host();
+            }
+        }
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    {
+        if let Ok(host) = cpal::host_from_id(cpal::HostId::CoreAudio) {
+            log::info!("Connected to CoreAudio Host");
+            return host;
+        }
+    }
+
+    host
+}
+
+fn host_by_name(name: &str) -> Result<cpal::Host> {
+    let id = cpal::available_hosts()
+        .into_iter()
+        .find(|id| id.name() == name)
+        .with_context(|| format!("audio host '{}' not available", name))?;
+    cpal::host_from_id(id).map_err(|e| anyhow!("audio host '{}' unavailable: {e}", name))
+}
+
+/// `name` when given, otherwise the host `set_host` picks.
+fn host_or_selected(name: Option<&str>) -> Result<cpal::Host> {
+    match name {
+        Some(name) => host_by_name(name),
+        None => Ok(set_host()),
+    }
+}
+
+/// Names of the audio hosts (driver APIs) on this machine.
+pub fn list_hosts() -> Vec<String> {
+    cpal::available_hosts()
+        .into_iter()
+        .map(|id| id.name().to_string())
+        .collect()
+}
+
+/// Rates offered when a device supports them
+const COMMON_SAMPLE_RATES: [u32; 8] = [22_050, 32_000, 44_100, 48_000, 88_200, 96_000, 176_400, 192_000];
+
+/// Buffer sizes the engine can run, the render graph expects a power of two
+pub const BUFFER_SIZES: [u32; 8] = [32, 64, 128, 256, 512, 1024, 2048, 4096];
+
+/// What a device can do, merged over all its supported configurations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AudioDeviceInfo {
+    pub name: String,
+    pub is_default: bool,
+    pub sample_rates: Vec<u32>,
+    pub channel_counts: Vec<u16>,
+    /// Power-of-two buffer sizes in the device's range, all of them when it does not report one
+    pub buffer_sizes: Vec<u32>,
+}
+
+fn describe_device(
+    device: &cpal::Device,
+    default_name: Option<&str>,
+    configs: Vec<cpal::SupportedStreamConfigRange>
+) -> Option<AudioDeviceInfo> {
+    let name = device.description().ok()?.to_string();
+
+    let mut sample_rates = Vec::new();
+    let mut channel_counts = Vec::new();
+    let mut buffer_sizes = Vec::new();
+    for config in &configs {
+        for rate in COMMON_SAMPLE_RATES {
+            if (config.min_sample_rate()..=config.max_sample_rate()).contains(&rate) {
+                sample_rates.push(rate);
+            }
+        }
+        channel_counts.push(config.channels());
+        buffer_sizes.extend(
+            BUFFER_SIZES.into_iter().filter(|size| match config.buffer_size() {
+                cpal::SupportedBufferSize::Range { min, max } => (*min..=*max).contains(size),
+                cpal::SupportedBufferSize::Unknown => true,
+            })
+        );
+    }
+    for list in [&mut sample_rates, &mut buffer_sizes] {
+        list.sort_unstable();
+        list.dedup();
+    }
+    channel_counts.sort_unstable();
+    channel_counts.dedup();
+
+    Some(AudioDeviceInfo {
+        is_default: default_name == Some(name.as_str()),
+        name,
+        sample_rates,
+        channel_counts,
+        buffer_sizes,
+    })
+}
+
+/// Output devices of a host (the selected host when `None`).
+pub fn list_output_device_info(host: Option<&str>) -> Result<Vec<AudioDeviceInfo>> {
+    let host = host_or_selected(host)?;
+    let default_name = host
+        .default_output_device()
+        .and_then(|device| device.description().ok())
+        .map(|desc| desc.to_string());
+    let devices = host.output_devices().map_err(|e| anyhow!("error listing output devices: {e}"))?;
+    Ok(
+        devices
+            .filter_map(|device| {
+                let configs = device.supported_output_configs().ok()?.collect();
+                describe_device(&device, default_name.as_deref(), configs)
+            })
+            .collect()
+    )
+}
+
+/// Input devices of a host (the selected host when `None`).
+pub fn list_input_device_info(host: Option<&str>) -> Result<Vec<AudioDeviceInfo>> {
+    let host = host_or_selected(host)?;
+    let default_name = host
+        .default_input_device()
+        .and_then(|device| device.description().ok())
+        .map(|desc| desc.to_string());
+    let devices = host.input_devices().map_err(|e| anyhow!("error listing input devices: {e}"))?;
+    Ok(
+        devices
+            .filter_map(|device| {
+                let configs = device.supported_input_configs().ok()?.collect();
+                describe_device(&device, default_name.as_deref(), configs)
+            })
+            .collect()
+    )
+}
+
+/// The output device named in the user settings, the host default when unset or missing.
+fn selected_output_device(host: &cpal::Host, name: Option<&str>) -> Result<cpal::Device> {
+    if let Some(name) = name {
+        let found = host
+            .output_devices()
+            .map_err(|e| anyhow!("error listing output devices: {e}"))?
+            .find(|device| {
+                device
+                    .description()
+                    .is_ok_and(|desc| desc.to_string() == name)
+            });
+        match found {
+            Some(device) => {
+                return Ok(device);
+            }
+            None => log::warn!("Output device '{}' not found, using default", name),
+        }
+    }
+    host.default_output_device().context("no audio output device available")
+}
+
+pub struct CpalAudioBackend;
+
+impl AudioBackend for CpalAudioBackend {
+    fn name(&self) -> &str {
+        "cpal"
+    }
+
+    fn open_output(&self) -> Result<Box<dyn AudioOutput>> {
+        let settings = ctx().user_settings.lock().audio.clone();
+        let host = set_host();
+
+        let device = selected_output_device(&host, settings.output_device.as_deref())?;
+
+        // debug!("Output dev");
+        let device_name = match device.description() {
+            Ok(desc) => desc.to_string(),
+            Err(_) => "Unknown".into(),
+        };
+        log::info!("Output device: {}", device_name);
+
+        let supported_configs_range = device
+            .supported_output_configs()
+            .map_err(|e| anyhow!("error querying configs: {e}"))?;
+
+        // The engine renders mono or stereo. Stereo f32 is preferred, it is written without
+        // conversion; other formats go through the scratch buffer
+        let mut candidates: Vec<_> = supported_configs_range
+            .filter(|c| OUTPUT_FORMATS.contains(&c.sample_format()) && (1..=2).contains(&c.channels()))
+            .collect();
+        candidates.sort_by_key(|c| (c.sample_format() != cpal::SampleFormat::F32, c.channels() != 2));
+        let Some(preferred) = candidates
+            .first()
+            .map(|c| (c.sample_format(), c.channels())) else {
+            return Err(anyhow!("device supports no mono or stereo f32, i16, u16 or u8 output"));
+        };
+
+        // The chosen rate when the device has it, otherwise the highest of the preferred format
+        let requested = settings.sample_rate.and_then(|rate| {
+            let config = candidates.iter().find_map(|c| c.try_with_sample_rate(rate));
+            if config.is_none() {
+                log::warn!("Output device does not support {} Hz, using its maximum", rate);
+            }
+            config
+        });
+        let supported_config = match requested {
+            Some(config) => config,
+            None =>
+                candidates
+                    .into_iter()
+                    .filter(|c| (c.sample_format(), c.channels()) == preferred)
+                    .map(|c| c.with_max_sample_rate())
+                    .max_by_key(|c| c.sample_rate())
+                    .context("device has no output config")?,
+        };
+
+        // Request 512 frames (good balance of latency vs stability) unless another size was chosen
+        let desired = settings.buffer_size.unwrap_or(DEFAULT_BUFFER_SIZE);
+
+        // This prevents PipeWire/JACK from resizing the buffer dynamically.
+        let (buffer_size, block_size) = match supported_config.buffer_size() {
+            cpal::SupportedBufferSize::Range { min, max } => {
+                // Clamp it to ensure we don't request something invalid
+                let frames = desired.clamp(*min, *max);
+                (cpal::BufferSize::Fixed(frames), frames)
+            }
+            cpal::SupportedBufferSize::Unknown => (cpal::BufferSize::Default, desired),
+        };
+
+        // Construct the concrete config manually
+        let config = cpal::StreamConfig {
+            channels: supported_config.channels(),
+            sample_rate: supported_config.sample_rate(),
+            buffer_size,
+        };
+
+        let sample_format = supported_config.sample_format();
+
+        log::info!(
+            "Stream Config: {:?} Hz, {} Channels, {} frames",
+            config.sample_rate,
+            config.channels,
+            block_size
+        );
+        log::info!("Sample format: {}", sample_format);
+
+        Ok(
+            Box::new(CpalOutput {
+                format: OutputFormat {
+                    device_name,
+                    sample_rate: config.sample_rate,
+                    channels: config.channels,
+                    buffer_size: block_size,
+                },
+                device,
+                config,
+                sample_format,
+            })
+        )
+    }
+}
+
+struct CpalOutput {
+    format: OutputFormat,
+    device: cpal::Device,
+    config: cpal::StreamConfig,
+    sample_format: cpal::SampleFormat,
+}
+
+impl AudioOutput for CpalOutput {
+    fn format(&self) -> &OutputFormat {
+        &self.format
+    }
+
+    fn start(self: Box<Self>, engine: AudioEngine) -> Result<Box<dyn AudioStream>> {
+        let CpalOutput { format, device, config, sample_format } = *self;
+        let channels = config.channels as usize;
+
+        let scratch = vec![0.0; (format.buffer_size as usize).max(1) * channels];
+        let xruns = engine.xrun_counter();
+        let audio_ctx = AudioContext { engine, scratch };
+
+        // Underruns show up in the engine's load report, logging each one would flood the log.
+        // Backend errors (e.g. an ALSA poll error) interrupt the running stream too, so they
+        // count as xruns as well. A lost device or invalidated stream is not a dropout but the
+        // end of the stream, it is only logged
+        let err_fn = move |err| {
+            match err {
+                cpal::StreamError::BufferUnderrun => {
+                    xruns.fetch_add(1, Ordering::Relaxed);
+                }
+                cpal::StreamError::BackendSpecific { err } => {
+                    xruns.fetch_add(1, Ordering::Relaxed);
+                    log::warn!("Audio stream error: {}", err);
+                }
+                err => log::error!("Audio stream error: {}", err),
+            }
+        };
+
+        let stream = (match sample_format {
+            cpal::SampleFormat::F32 => {
+                run_stream!(
+                    device,
+                    config,
+                    audio_ctx,
+                    f32,
+                    |ctx: &mut AudioContext, data: &mut [f32]| ctx.engine.process(data),
+                    err_fn
+                )
+            }
+
+            cpal::SampleFormat::I16 =>
+                run_stream!(
+                    device,
+                    config,
+                    audio_ctx,
+                    i16,
+                    |ctx: &mut AudioContext, data: &mut [i16]| {
+                        ctx.render_converted(data, |s: f32| {
+                            (s * (i16::MAX as f32)).clamp(i16::MIN as f32, i16::MAX as f32) as i16
+                        })
+                    },
+                    err_fn
+                ),
+
+            cpal::SampleFormat::U16 =>
+                run_stream!(
+                    device,
+                    config,
+                    audio_ctx,
+                    u16,
+                    |ctx: &mut AudioContext, data: &mut [u16]| {
+                        ctx.render_converted(data, |s: f32| {
+                            ((s + 1.0) * 0.5 * (u16::MAX as f32)).clamp(0.0, u16::MAX as f32) as u16
+                        })
+                    },
+                    err_fn
+                ),
+
+            cpal::SampleFormat::U8 =>
+                run_stream!(
+                    device,
+                    config,
+                    audio_ctx,
+                    u8,
+                    |ctx: &mut AudioContext, data: &mut [u8]| {
+                        ctx.render_converted(data, |s: f32| ((s + 1.0) * 0.5 * 255.0).clamp(0.0, 255.0) as u8)
+                    },
+                    err_fn
+                ),
+
+            other => {
+                return Err(anyhow!("Unsupported sample format: {:?}", other));
+            }
+        })?;
+
+        // Play and store
+        stream.play().context("Failed to play stream")?;
+        Ok(Box::new(stream))
+    }
+}
+
+impl AudioStream for cpal::Stream {}
+
+/// Names of the available audio input devices.
+pub fn list_input_devices() -> Result<Vec<String>> {
+    let host = set_host();
+    let devices = host.input_devices().map_err(|e| anyhow!("error listing input devices: {e}"))?;
+    Ok(
+        devices
+            .filter_map(|device| device.description().ok())
+            .map(|desc| desc.to_string())
+            .collect()
+    )
+}
+
+/// Opens the input device called `device_name` (the default input when `None`) and connects it
+/// to the audio engine, replacing any open input. Returns the name of the opened device.
+///
+/// The input must run at the output sample rate, input is not resampled.
+pub fn start_input_stream(device_name: Option<&str>) -> Result<String> {
+    stop_input_stream();
+
+    let host = set_host();
+    let device = match device_name {
+        Some(name) =>
+            host
+                .input_devices()
+                .map_err(|e| anyhow!("error listing input devices: {e}"))?
+                .find(|device| {
+                    device
+                        .description()
+                        .is_ok_and(|desc| desc.to_string() == name)
+                })
+                .with_context(|| format!("input device '{}' not found", name))?,
+        None => host.default_input_device().context("no audio input device available")?,
+    };
+    let device_name = match device.description() {
+        Ok(desc) => desc.to_string(),
+        Err(_) => "Unknown".into(),
+    };
+
+    let sample_rate = ctx().app_state.read().audio_config.sample_rate;
+
+    // Prefer f32 and at most stereo, those need no conversion or channel dropping
+    let mut candidates: Vec<_> = device
+        .supported_input_configs()
+        .map_err(|e| anyhow!("error querying input configs: {e}"))?
+        .filter_map(|c| c.try_with_sample_rate(sample_rate))
+        .collect();
+    candidates.sort_by_key(|c| (c.sample_format() != cpal::SampleFormat::F32, c.channels() > 2));
+    let supported_config = candidates
+        .into_iter()
+        .next()
+        .with_context(|| format!("input device '{}' does not support {} Hz", device_name, sample_rate))?;
+
+    let config = cpal::StreamConfig {
+        channels: supported_config.channels(),
+        sample_rate,
+        buffer_size: cpal::BufferSize::Default,
+    };
+    let channels = config.channels;
+
+    log::info!("Input device: {}", device_name);
+    log::info!("Input Config: {:?} Hz, {} Channels", sample_rate, channels);
+
+    // One second of input, the engine skips anything beyond a block anyway
+    let (producer, consumer) = RingBuffer::<f32>::new((sample_rate as usize) * (channels as usize));
+
+    let stream = match supported_config.sample_format() {
+        cpal::SampleFormat::F32 => build_input_stream::<f32>(&device, &config, producer)?,
+        cpal::SampleFormat::I16 => build_input_stream::<i16>(&device, &config, producer)?,
+        cpal::SampleFormat::I32 => build_input_stream::<i32>(&device, &config, producer)?,
+        cpal::SampleFormat::U16 => build_input_stream::<u16>(&device, &config, producer)?,
+        cpal::SampleFormat::U8 => build_input_stream::<u8>(&device, &config, producer)?,
+        other => {
+            return Err(anyhow!("Unsupported input sample format: {:?}", other));
+        }
+    };
+    stream.play().context("Failed to start input stream")?;
+
+    send_audio_command(AudioCommand::SetAudioInput(Some(AudioInputPort { consumer, channels })));
+    *ctx().input_stream_guard.lock() = Some(stream);
+    ctx().app_state.write().audio_config.selected_input_device = device_name.clone();
+
+    Ok(device_name)
+}
+
+/// Closes the audio input and disconnects it from the engine.
+pub fn stop_input_stream() {
+    let stream = ctx().input_stream_guard.lock().take();
+    if stream.is_some() {
+        log::info!("Stopping audio input stream...");
+        send_audio_command(AudioCommand::SetAudioInput(None));
+        ctx().device_latency.set_input(0);
+    }
+}
+
+fn build_input_stream<T>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    mut producer: Producer<f32>
+) -> Result<cpal::Stream>
+    where T: SizedSample, f32: FromSample<T>
+{
+    let sample_rate = config.sample_rate;
+    let stream = device.build_input_stream(
+        config,
+        move |data: &[T], info: &InputCallbackInfo| {
+            let timestamp = info.timestamp();
+            if let Some(latency) = timestamp.callback.duration_since(&timestamp.capture) {
+                ctx().device_latency.set_input(duration_to_frames(latency, sample_rate));
+            }
+
+            // Input that does not fit is dropped, the engine drains the ring every block
+            let writable = data.len().min(producer.slots());
+            if let Ok(chunk) = producer.write_chunk_uninit(writable) {
+                chunk.fill_from_iter(data.iter().map(|&s| s.to_sample::<f32>()));
+            }
+        },
+        |err| log::error!("Audio input stream error: {}", err),
+        None
+    )?;
+    Ok(stream)
+}
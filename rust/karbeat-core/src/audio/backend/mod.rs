@@ -0,0 +1,257 @@
+// src/audio/backend/mod.rs
+//
+// Audio output backends. An `AudioBackend` opens an output and reports the format the engine has
+// to render, the engine is then built for that format and handed to the output, which calls
+// `AudioEngine::process` for as long as its stream lives. Besides the sound card (cpal) there is
+// a null backend that renders on a timer thread and a manual one that tests pump block by block.
+// The manual backend never plays on its own, so it is only handed over in code and can't be picked
+// from the environment.
+
+pub mod cpal_host;
+pub mod null;
+
+use std::sync::Arc;
+
+use anyhow::{ Result, anyhow };
+use karbeat_dsp::loudness::LoudnessReport;
+use rtrb::{ Consumer, RingBuffer };
+use triple_buffer::{ Output, TripleBuffer };
+
+pub use cpal_host::{
+    AudioDeviceInfo,
+    BUFFER_SIZES,
+    CpalAudioBackend,
+    list_hosts,
+    list_input_device_info,
+    list_input_devices,
+    list_output_device_info,
+    start_input_stream,
+    stop_input_stream,
+};
+pub use null::{ ManualAudioBackend, NullAudioBackend };
+
+use crate::{
+    audio::{
+        analyzer::MAX_ANALYZER_TAPS,
+        engine::{ AudioEngine, EngineHandles },
+        event::TransportFeedback,
+        load::EngineLoadReport,
+        metering::MeterFeedback,
+        render_state::AudioRenderState,
+    },
+    commands::{ AudioCommand, Garbage },
+    context::{ ctx, utils::broadcast_state_change },
+    lock::get_app_read,
+};
+
+/// Commands the UI can queue between two audio blocks
+const COMMAND_QUEUE_CAPACITY: usize = 128;
+
+/// Meter readings the UI can fall behind by, a few windows of a large mixer
+const METER_QUEUE_CAPACITY: usize = 1024;
+
+/// Removals waiting to be freed, every tap the engine can hold and then some
+const GARBAGE_CAPACITY: usize = MAX_ANALYZER_TAPS * 4;
+
+/// Frames per block when no buffer size is chosen
+pub const DEFAULT_BUFFER_SIZE: u32 = 512;
+
+/// Environment variable that picks the backend at startup, see [`backend_from_spec`]
+pub const AUDIO_BACKEND_ENV: &str = "KARBEAT_AUDIO_BACKEND";
+
+/// Somewhere the engine's output can go.
+pub trait AudioBackend: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Opens the output. Nothing plays until the output is started with an engine.
+    fn open_output(&self) -> Result<Box<dyn AudioOutput>>;
+}
+
+/// What an opened output expects from the engine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutputFormat {
+    pub device_name: String,
+    pub sample_rate: u32,
+    pub channels: u16,
+    /// Frames the engine renders per block
+    pub buffer_size: u32,
+}
+
+/// An opened output that has not started yet.
+pub trait AudioOutput {
+    fn format(&self) -> &OutputFormat;
+
+    /// Starts running the engine. It stops when the returned stream is dropped.
+    fn start(self: Box<Self>, engine: AudioEngine) -> Result<Box<dyn AudioStream>>;
+}
+
+/// A running output, stopped when dropped.
+pub trait AudioStream: Send {}
+
+/// Picks a backend by name: `cpal` (the sound card) or `null`. The null backend takes an optional
+/// sample rate and buffer size, e.g. `null:44100:256`.
+pub fn backend_from_spec(spec: &str) -> Result<Arc<dyn AudioBackend>> {
+    let mut parts = spec.trim().split(':');
+    let name = parts.next().unwrap_or_default().to_ascii_lowercase();
+    let mut number = |default: u32| -> Result<u32> {
+        match parts.next() {
+            Some(value) =>
+                value
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|&value| value > 0)
+                    .ok_or_else(|| anyhow!("Invalid number '{}' in audio backend '{}'", value, spec)),
+            None => Ok(default),
+        }
+    };
+
+    let backend: Arc<dyn AudioBackend> = match name.as_str() {
+        "cpal" | "" => Arc::new(CpalAudioBackend),
+        "null" => {
+            let sample_rate = number(null::DEFAULT_SAMPLE_RATE)?;
+            let buffer_size = number(DEFAULT_BUFFER_SIZE)?;
+            Arc::new(NullAudioBackend::new(sample_rate, buffer_size as usize))
+        }
+        "manual" => {
+            return Err(anyhow!("The manual audio backend can only be set from code"));
+        }
+        other => {
+            return Err(anyhow!("Unknown audio backend '{}'", other));
+        }
+    };
+    Ok(backend)
+}
+
+/// The backend named by `KARBEAT_AUDIO_BACKEND`, the sound card when unset or invalid.
+pub fn default_backend() -> Arc<dyn AudioBackend> {
+    match std::env::var(AUDIO_BACKEND_ENV) {
+        Ok(spec) =>
+            backend_from_spec(&spec).unwrap_or_else(|e| {
+                log::warn!("{}, using the sound card", e);
+                Arc::new(CpalAudioBackend)
+            }),
+        Err(_) => Arc::new(CpalAudioBackend),
+    }
+}
+
+/// Start the audio strem by initializing the Command Queue and Audio Engine
+/// and then building the audio stream on the backend selected in the context
+pub fn start_audio_stream(
+    mut state_consumer: Output<AudioRenderState>,
+    command_consumer: Consumer<AudioCommand>,
+    initial_state: AudioRenderState
+) -> Result<()> {
+    {
+        let mut guard = ctx().stream_guard.lock();
+        if guard.is_some() {
+            log::info!("Stopping previous audio stream...");
+            *guard = None; // This drops the stream, stopping the audio thread
+        }
+    }
+    let backend = Arc::clone(&*ctx().audio_backend.read());
+    log::info!("Audio backend: {}", backend.name());
+
+    let output = backend.open_output()?;
+    let OutputFormat { device_name, sample_rate, channels, buffer_size } = output.format().clone();
+
+    {
+        let mut state = ctx().app_state.write();
+        state.audio_config.sample_rate = sample_rate;
+        state.audio_config.buffer_size = buffer_size;
+        state.audio_config.selected_output_device = device_name;
+    }
+
+    state_consumer.update();
+
+    let (pos_producer, pos_consumer) = RingBuffer::<TransportFeedback>::new(100);
+
+    // Store Consumer in context
+    *ctx().position_consumer.lock() = Some(pos_consumer);
+
+    // Create feedback ring buffer (Audio → UI for parameter updates)
+    let (feedback_producer, feedback_consumer) = RingBuffer::<crate::commands::AudioFeedback>::new(
+        256
+    );
+    *ctx().feedback_consumer.lock() = Some(feedback_consumer);
+
+    // Removed analyzer taps (Audio → UI), so their memory isn't freed on the audio thread
+    let (garbage_producer, garbage_consumer) = RingBuffer::<Garbage>::new(GARBAGE_CAPACITY);
+    *ctx().garbage_consumer.lock() = Some(garbage_consumer);
+
+    // Level meters (Audio → UI)
+    let (meter_producer, meter_consumer) = RingBuffer::<MeterFeedback>::new(METER_QUEUE_CAPACITY);
+    *ctx().meter_consumer.lock() = Some(meter_consumer);
+
+    // Master loudness analysis (Audio → UI), only the latest report matters
+    let (loudness_input, loudness_output) = TripleBuffer::new(&LoudnessReport::default()).split();
+    *ctx().loudness_report.lock() = Some(loudness_output);
+
+    // CPU load of the audio thread (Audio → UI)
+    let (load_input, load_output) = TripleBuffer::<EngineLoadReport>::default().split();
+    *ctx().engine_load.lock() = Some(load_output);
+
+    // Read initial BPM from app state for the audio engine
+    let initial_bpm = {
+        let app = ctx().app_state.read();
+        app.transport.bpm
+    };
+
+    let mut engine = AudioEngine::new(
+        state_consumer,
+        command_consumer,
+        EngineHandles {
+            position_producer: pos_producer,
+            feedback_producer,
+            meter_producer,
+            loudness_report: loudness_input,
+            load_report: load_input,
+        },
+        sample_rate,
+        channels,
+        initial_bpm,
+        initial_state
+    );
+    engine.set_latency_report(ctx().device_latency.mix_report());
+    engine.set_garbage_sink(garbage_producer);
+
+    let stream = output.start(engine)?;
+
+    // store the stream in context so it does not get dropped
+    let mut guard = ctx().stream_guard.lock();
+    *guard = Some(stream);
+
+    log::info!("Successfully initialize Audio backend");
+    Ok(())
+}
+
+/// Starts a new engine with fresh state and command queues on the selected backend, replacing
+/// the running one. The engine starts empty: plugins and transport settings have to be sent again.
+pub fn start_audio_engine() -> Result<()> {
+    let initial_state = AudioRenderState::from(&*get_app_read());
+    let (state_in, state_out) = TripleBuffer::new(&initial_state).split();
+    *ctx().render_state_producer.lock() = Some(state_in);
+
+    let (command_producer, command_consumer) = RingBuffer::new(COMMAND_QUEUE_CAPACITY);
+    *ctx().command_sender.lock() = Some(command_producer);
+
+    start_audio_stream(state_out, command_consumer, initial_state)?;
+
+    // The state above was built before the stream settled on its rate and buffer size
+    broadcast_state_change();
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn specs_pick_backends_but_not_the_manual_one() -> Result<()> {
+        assert_eq!(backend_from_spec("null:44100:256")?.name(), "null");
+        assert_eq!(backend_from_spec("")?.name(), "cpal");
+        assert!(backend_from_spec("manual").is_err());
+        assert!(backend_from_spec("manual:48000:128").is_err());
+        assert!(backend_from_spec("null:0").is_err());
+        Ok(())
+    }
+}
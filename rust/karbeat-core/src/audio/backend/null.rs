@@ -0,0 +1,332 @@
+// src/audio/backend/null.rs
+//
+// Backends without sound hardware. The null backend renders in real time on a timer thread and
+// throws the audio away, the manual backend renders only when asked to. Both run the engine the
+// same way a sound card would, so the whole stack works headless.
+
+use std::{
+    sync::{ Arc, atomic::{ AtomicBool, Ordering } },
+    thread::JoinHandle,
+    time::{ Duration, Instant },
+};
+
+use anyhow::{ Context, Result, anyhow };
+use parking_lot::Mutex;
+
+use crate::{
+    audio::{
+        backend::{ AudioBackend, AudioOutput, AudioStream, OutputFormat },
+        engine::AudioEngine,
+    },
+    context::ctx,
+};
+
+pub const DEFAULT_SAMPLE_RATE: u32 = 48_000;
+const DEFAULT_BUFFER_SIZE: usize = super::DEFAULT_BUFFER_SIZE as usize;
+
+/// The engine renders interleaved stereo
+const CHANNELS: u16 = 2;
+
+// =========================================================================
+// Null
+// =========================================================================
+
+/// Runs the engine in real time at a fixed sample rate and buffer size, output is discarded.
+pub struct NullAudioBackend {
+    sample_rate: u32,
+    buffer_size: usize,
+}
+
+impl NullAudioBackend {
+    pub fn new(sample_rate: u32, buffer_size: usize) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1),
+            buffer_size: buffer_size.max(1),
+        }
+    }
+}
+
+impl Default for NullAudioBackend {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLE_RATE, DEFAULT_BUFFER_SIZE)
+    }
+}
+
+impl AudioBackend for NullAudioBackend {
+    fn name(&self) -> &str {
+        "null"
+    }
+
+    fn open_output(&self) -> Result<Box<dyn AudioOutput>> {
+        Ok(
+            Box::new(NullOutput {
+                format: OutputFormat {
+                    device_name: "Null output".into(),
+                    sample_rate: self.sample_rate,
+                    channels: CHANNELS,
+                    buffer_size: self.buffer_size as u32,
+                },
+                buffer_size: self.buffer_size,
+            })
+        )
+    }
+}
+
+struct NullOutput {
+    format: OutputFormat,
+    buffer_size: usize,
+}
+
+impl AudioOutput for NullOutput {
+    fn format(&self) -> &OutputFormat {
+        &self.format
+    }
+
+    fn start(self: Box<Self>, mut engine: AudioEngine) -> Result<Box<dyn AudioStream>> {
+        let period = Duration::from_secs_f64(
+            (self.buffer_size as f64) / (self.format.sample_rate as f64)
+        );
+        let mut buffer = vec![0.0; self.buffer_size * (CHANNELS as usize)];
+        let stop = Arc::new(AtomicBool::new(false));
+        // Nothing sits between the engine and the "speakers"
+        ctx().device_latency.set_output(0);
+
+        let timer_stop = Arc::clone(&stop);
+        let timer = std::thread::Builder
+            ::new()
+            .name("karbeat-null-audio".into())
+            .spawn(move || {
+                let mut next_block = Instant::now();
+                while !timer_stop.load(Ordering::Acquire) {
+                    engine.process(&mut buffer);
+
+                    next_block += period;
+                    let now = Instant::now();
+                    if next_block > now {
+                        std::thread::sleep(next_block - now);
+                    } else if now - next_block > period {
+                        // Fell behind by more than a block: drop the backlog instead of racing
+                        next_block = now;
+                    }
+                }
+            })
+            .context("Failed to spawn null audio thread")?;
+
+        Ok(Box::new(NullStream { stop, timer: Some(timer) }))
+    }
+}
+
+struct NullStream {
+    stop: Arc<AtomicBool>,
+    timer: Option<JoinHandle<()>>,
+}
+
+impl AudioStream for NullStream {}
+
+impl Drop for NullStream {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(timer) = self.timer.take() {
+            let _ = timer.join();
+        }
+    }
+}
+
+// =========================================================================
+// Manual
+// =========================================================================
+
+/// Runs the engine only when pumped. Keep a clone of the backend to pump the stream it started.
+#[derive(Clone)]
+pub struct ManualAudioBackend {
+    sample_rate: u32,
+    buffer_size: usize,
+    /// Engine of the running stream, none while stopped
+    engine: Arc<Mutex<Option<AudioEngine>>>,
+}
+
+impl ManualAudioBackend {
+    pub fn new(sample_rate: u32, buffer_size: usize) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1),
+            buffer_size: buffer_size.max(1),
+            engine: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.engine.lock().is_some()
+    }
+
+    /// Renders `blocks` blocks of `buffer_size` frames and returns them as interleaved stereo.
+    pub fn pump(&self, blocks: usize) -> Result<Vec<f32>> {
+        let block_len = self.buffer_size * (CHANNELS as usize);
+        let mut output = vec![0.0; blocks * block_len];
+
+        let mut engine = self.engine.lock();
+        let engine = engine.as_mut().ok_or_else(|| anyhow!("No audio stream is running"))?;
+        for block in output.chunks_mut(block_len) {
+            engine.process(block);
+        }
+        Ok(output)
+    }
+}
+
+impl Default for ManualAudioBackend {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLE_RATE, DEFAULT_BUFFER_SIZE)
+    }
+}
+
+impl AudioBackend for ManualAudioBackend {
+    fn name(&self) -> &str {
+        "manual"
+    }
+
+    fn open_output(&self) -> Result<Box<dyn AudioOutput>> {
+        Ok(
+            Box::new(ManualOutput {
+                format: OutputFormat {
+                    device_name: "Manual output".into(),
+                    sample_rate: self.sample_rate,
+                    channels: CHANNELS,
+                    buffer_size: self.buffer_size as u32,
+                },
+                engine: Arc::clone(&self.engine),
+            })
+        )
+    }
+}
+
+struct ManualOutput {
+    format: OutputFormat,
+    engine: Arc<Mutex<Option<AudioEngine>>>,
+}
+
+impl AudioOutput for ManualOutput {
+    fn format(&self) -> &OutputFormat {
+        &self.format
+    }
+
+    fn start(self: Box<Self>, engine: AudioEngine) -> Result<Box<dyn AudioStream>> {
+        ctx().device_latency.set_output(0);
+        *self.engine.lock() = Some(engine);
+        Ok(Box::new(ManualStream { engine: self.engine }))
+    }
+}
+
+struct ManualStream {
+    engine: Arc<Mutex<Option<AudioEngine>>>,
+}
+
+impl AudioStream for ManualStream {}
+
+impl Drop for ManualStream {
+    fn drop(&mut self) {
+        self.engine.lock().take();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use karbeat_dsp::loudness::LoudnessReport;
+    use rtrb::RingBuffer;
+    use triple_buffer::TripleBuffer;
+
+    use super::*;
+    use crate::{
+        audio::{ engine::EngineHandles, load::EngineLoadReport, render_state::AudioRenderState },
+        commands::AudioCommand,
+        core::project::{ tempo_map::TICKS_PER_BEAT, transport::LoopRegion },
+    };
+
+    /// Starts a stream on `backend` and sets it playing a four beat loop, an empty song
+    /// would stop right away.
+    fn start_looping(
+        backend: &ManualAudioBackend
+    ) -> Result<(Box<dyn AudioStream>, rtrb::Consumer<crate::audio::event::TransportFeedback>)> {
+        let output = backend.open_output()?;
+        let sample_rate = output.format().sample_rate;
+
+        let initial_state = AudioRenderState::default();
+        let (_state_input, state_output) = TripleBuffer::new(&initial_state).split();
+        let (mut commands, command_consumer) = RingBuffer::new(16);
+        let (position_producer, positions) = RingBuffer::new(64);
+        let (feedback_producer, _feedback) = RingBuffer::new(16);
+        let (meter_producer, _meters) = RingBuffer::new(16);
+        let (loudness_input, _loudness) = TripleBuffer::new(&LoudnessReport::default()).split();
+        let (load_input, _load) = TripleBuffer::<EngineLoadReport>::default().split();
+        let engine = AudioEngine::new(
+            state_output,
+            command_consumer,
+            EngineHandles {
+                position_producer,
+                feedback_producer,
+                meter_producer,
+                loudness_report: loudness_input,
+                load_report: load_input,
+            },
+            sample_rate,
+            CHANNELS,
+            120.0,
+            initial_state
+        );
+
+        let stream = output.start(engine)?;
+        let region = LoopRegion { start: 0, end: 4 * (TICKS_PER_BEAT as u64) };
+        for command in [
+            AudioCommand::SetLoopRegion(Some(region)),
+            AudioCommand::SetLooping(true),
+            AudioCommand::SetPlaying(true),
+        ] {
+            commands.push(command).map_err(|_| anyhow!("command queue full"))?;
+        }
+        Ok((stream, positions))
+    }
+
+    #[test]
+    fn manual_backend_renders_when_pumped() -> Result<()> {
+        let backend = ManualAudioBackend::new(48_000, 256);
+        let (stream, mut positions) = start_looping(&backend)?;
+        assert!(backend.is_running());
+        // 20 blocks of 256 frames, the playhead reports whole blocks
+        let audio = backend.pump(20)?;
+        assert_eq!(audio.len(), 20 * 256 * 2);
+        let mut last = None;
+        while let Ok(position) = positions.pop() {
+            last = Some(position);
+        }
+        let last = last.ok_or_else(|| anyhow!("no position feedback"))?;
+        assert!(last.is_playing);
+        assert!(last.samples > 0 && last.samples <= 20 * 256);
+        assert_eq!(last.samples % 256, 0);
+
+        drop(stream);
+        assert!(!backend.is_running());
+        assert!(backend.pump(1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn long_callback_buffers_are_split_into_blocks() -> Result<()> {
+        // More frames per callback than the plugins are prepared for
+        let backend = ManualAudioBackend::new(48_000, 1500);
+        let (_stream, mut positions) = start_looping(&backend)?;
+
+        let audio = backend.pump(2)?;
+        assert_eq!(audio.len(), 2 * 1500 * 2);
+        let mut reports = Vec::new();
+        while let Ok(position) = positions.pop() {
+            reports.push(position.samples);
+        }
+        // Rendered as 512 + 512 + 476 frames per callback, so the playhead is reported between
+        // callback boundaries and still ends up on the frames played
+        assert!(reports.iter().any(|&samples| samples % 1500 != 0));
+        assert_eq!(reports.last().copied(), Some(3000));
+        Ok(())
+    }
+}
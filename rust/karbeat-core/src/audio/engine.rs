@@ -7,20 +7,40 @@
 
 use dasp::slice;
 use rtrb::{ Consumer, Producer };
+use indexmap::IndexMap;
 use smallvec::SmallVec;
 use wide::f32x4;
-use std::{ collections::HashMap, sync::Arc };
-use triple_buffer::Output;
+use std::{
+    collections::HashMap,
+    sync::{ Arc, atomic::{ AtomicU32, AtomicU64, Ordering } },
+    time::{ Duration, Instant },
+};
+use triple_buffer::{ Input, Output };
 
 use crate::{
     audio::{
+        analyzer::{ AnalyzerTap, MAX_ANALYZER_TAPS },
+        automation::{
+            apply_channel_gain,
+            change_segments,
+            changes_at,
+            process_effects,
+            sample_lane,
+        },
         event::{
-            BusAutomationEvent,
+            AutomationEvent,
+            ChannelAutomationEvent,
             GeneratorAutomationEvent,
-            MasterAutomationEvent,
-            TrackAutomationEvent,
             TransportFeedback,
         },
+        latency::{ DelayCompensation, chain_latency },
+        load::{ EngineLoadReport, LoadMonitor, PluginTimer },
+        metering::{ ChannelMeter, MeterFeedback, MeterTarget, MixerMeters },
+        metronome::Metronome,
+        midi::{ LiveMidiInput, MidiOutput, midi_clock_now },
+        note_recorder::{ NoteTake, RecordedNoteEvent },
+        recorder::{ InputRecorder, frames_to_duration },
+        render_pool::{ RenderPool, default_render_threads },
         render_state::{
             AudioEffectInstance,
             AudioGeneratorInstance,
@@ -33,7 +53,9 @@ use crate::{
         AudioFeedback,
         EffectParameterSnapshot,
         EffectTarget,
+        Garbage,
         GeneratorParameterSnapshot,
+        PreparedPlugins,
     },
     core::project::{
         AudioWaveform,
@@ -42,17 +64,29 @@ use crate::{
         GeneratorInstance,
         KarbeatSource,
         KarbeatTrack,
+        Note,
         Pattern,
         PatternId,
         TrackId,
         automation::AutomationTarget,
+        groove::{ Groove, MAX_GROOVE_SHIFT },
         mixer::{ MixerChannel, RoutingNode },
         plugin::{ MidiEvent, MidiMessage },
+        musical_time::TimeSignature,
+        transport::LoopRegion,
+        tempo_map::{ TICKS_PER_BEAT, TempoMap },
     },
     shared::id::*,
     utils::{ apply_simd_mix, apply_simd_mix_gain, get_waveform_buffer },
 };
-use karbeat_utils::{ audio::db_to_linear, math::hermite_interp };
+use karbeat_dsp::loudness::{ LoudnessMeter, LoudnessReport };
+use karbeat_utils::math::hermite_interp;
+
+/// Plugins are prepared for at least this many frames per block
+const MIN_PREPARED_BLOCK_FRAMES: usize = 512;
+
+/// Furthest a note's groove and micro-offset move it off the grid, in ticks
+const NOTE_SHIFT_REACH: u32 = 128 + (MAX_GROOVE_SHIFT as u32);
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PlaybackMode {
@@ -63,19 +97,50 @@ pub enum PlaybackMode {
     },
 }
 
+/// Where the engine publishes to the UI.
+pub struct EngineHandles {
+    pub position_producer: Producer<TransportFeedback>,
+    pub feedback_producer: Producer<AudioFeedback>,
+    /// Level meter readings of every strip
+    pub meter_producer: Producer<MeterFeedback>,
+    /// Loudness of the master output
+    pub loudness_report: Input<LoudnessReport>,
+    /// CPU load of the callbacks
+    pub load_report: Input<EngineLoadReport>,
+}
+
 pub struct AudioEngine {
     // Comms
     state_consumer: Output<AudioRenderState>,
     position_producer: Producer<TransportFeedback>,
     feedback_producer: Producer<AudioFeedback>,
     current_state: AudioRenderState,
+    /// Level meters of every strip, published to the UI
+    meters: MixerMeters,
+    /// EBU R128 analyzer of the master output and where its report is published. `None` when
+    /// the owner measures the output itself, as the exporter does
+    loudness: Option<LoudnessMeter>,
+    loudness_report: Input<LoudnessReport>,
+    /// CPU load of the callbacks, published to the UI
+    load: LoadMonitor,
+    /// Strips copied to spectrum analyzers
+    analyzer_taps: Vec<AnalyzerTap>,
+    /// Where removed taps go to be freed off the audio thread, dropped in place without one
+    garbage: Option<Producer<Garbage>>,
+    /// Delays lining up the paths through the mixer behind plugins with latency
+    delay_compensation: DelayCompensation,
+    /// The graph or the plugins changed since the delay lines were last given room
+    delay_lines_stale: bool,
+    /// Latency of the whole mix, read by the recorder to place takes
+    mix_latency: Arc<AtomicU32>,
 
     // ======================================
     // Transport State (owned by audio thread)
     // ======================================
     is_playing: bool,
     is_looping: bool,
-    is_recording: bool,
+    /// Loop in/out points in ticks. `None` loops the whole song
+    loop_region: Option<LoopRegion>,
     is_pattern_playing: bool,
     bpm: f32,
 
@@ -85,6 +150,7 @@ pub struct AudioEngine {
     playhead_samples: u32,
     current_beat: usize,
     current_bar: usize,
+    current_signature: TimeSignature,
 
     // Timeline (Pattern mode - independent from song)
     pattern_playhead_samples: u32,
@@ -108,12 +174,45 @@ pub struct AudioEngine {
 
     mix_buffer: Vec<f32>,
 
+    /// Per-track render state of the block, in track order
+    track_lanes: Vec<TrackLane>,
+    /// Channel strip of tracks missing from the mixer, built once so no block has to
+    default_channel: MixerChannel,
+    /// Threads the tracks render on besides the audio thread
+    render_pool: RenderPool,
+
     /// Intermediate buffers for each bus (for routing matrix)
     bus_buffers: HashMap<BusId, Vec<f32>>,
 
     /// Temporary buffer for bus processing (avoids allocation in audio thread)
     bus_temp_buffer: Vec<f32>,
 
+    /// Click track and count-in
+    metronome: Metronome,
+    /// Metronome output for the current block (avoids allocation in audio thread)
+    click_buffer: Vec<f32>,
+    /// True when `click_buffer` holds clicks that still need to be mixed
+    click_pending: bool,
+
+    /// Audio input of the current block and the take being recorded
+    recorder: InputRecorder,
+    /// Live notes being recorded on armed MIDI tracks
+    note_take: Option<NoteTake>,
+    /// Hardware MIDI input of the current block
+    midi_input: LiveMidiInput,
+    /// Clock and notes sent to the hardware MIDI output
+    midi_output: MidiOutput,
+    /// Frames of the current block already rendered. The engine renders a block in slices
+    /// (count-in, loop wrap), this places each slice on its own input and MIDI events
+    block_cursor: usize,
+    /// Times song and pattern playback wrapped around since they started. Note probabilities
+    /// are rolled again on every pass
+    loop_pass: u64,
+    pattern_pass: u64,
+
+    /// Scratch space for the keys held across the block being scheduled
+    held_keys: HeldKeys,
+
     /// Cached routing order (updated only when state changes, not every callback)
     cached_routing_order: Vec<RoutingNode>,
 
@@ -123,18 +222,21 @@ pub struct AudioEngine {
     // =========================
     // Automation Event Queue
     // =========================
-    track_automation_events: SmallVec<[(TrackId, Vec<TrackAutomationEvent>); 4]>,
-    bus_automation_events: SmallVec<[(BusId, Vec<BusAutomationEvent>); 4]>,
-    master_automation_events: SmallVec<[MasterAutomationEvent; 4]>,
+    track_automation_events: SmallVec<[(TrackId, StripAutomation); 4]>,
+    bus_automation_events: SmallVec<[(BusId, StripAutomation); 4]>,
+    master_automation_events: SmallVec<[AutomationEvent<ChannelAutomationEvent>; 4]>,
 }
 
+/// Automation events of one channel strip for the current block
+type StripAutomation = Vec<AutomationEvent<ChannelAutomationEvent>>;
+
 /// Lightweight voice reference - the actual plugin lives in AudioPluginState
 pub struct GeneratorVoice {
     pub id: GeneratorId,
     pub track_id: TrackId,
     // Events queued for the CURRENT buffer block only
     pub midi_events: SmallVec<[MidiEvent; 4]>,
-    pub automation_events: SmallVec<[GeneratorAutomationEvent; 4]>,
+    pub automation_events: SmallVec<[AutomationEvent<GeneratorAutomationEvent>; 4]>,
     // Track if this generator is persistent or temporary
     pub active: bool,
     pub playing_keys: Vec<u8>,
@@ -153,9 +255,52 @@ impl GeneratorVoice {
     }
 }
 
+/// Keys a generator's notes hold at the start and end of the block being scheduled. One pair
+/// is reused for every generator and block, so scheduling does not allocate.
+#[derive(Default)]
+struct HeldKeys {
+    at_start: Vec<u8>,
+    at_end: Vec<u8>,
+}
+
+impl HeldKeys {
+    fn with_capacity(keys: usize) -> Self {
+        Self { at_start: Vec::with_capacity(keys), at_end: Vec::with_capacity(keys) }
+    }
+
+    fn clear(&mut self) {
+        self.at_start.clear();
+        self.at_end.clear();
+    }
+
+    /// Sends a NoteOff for every key the voice was playing that no note holds anymore (a clip
+    /// ended, a note was deleted, the playhead jumped), and hands the keys held at the end of
+    /// the block to the voice. Its old keys come back as scratch space.
+    fn release_interrupted(&mut self, voice: &mut GeneratorVoice) {
+        // `playing_keys` is deduplicated, so every key is released once
+        for &key in &voice.playing_keys {
+            if !self.at_start.contains(&key) {
+                voice.midi_events.push(MidiEvent {
+                    sample_offset: 0,
+                    data: MidiMessage::NoteOff { key },
+                });
+            }
+        }
+
+        self.at_end.sort_unstable();
+        self.at_end.dedup();
+        std::mem::swap(&mut voice.playing_keys, &mut self.at_end);
+
+        // Sort all events (including injected NoteOffs). On the same sample note offs go first,
+        // so a key that starts over is released before it is struck again
+        voice.midi_events.sort_by_key(|e| (e.sample_offset, matches!(e.data, MidiMessage::NoteOn { .. })));
+    }
+}
+
 pub struct AudioVoice {
     pub track_id: TrackId,
-    pub waveform: AudioWaveform,
+    /// Shared with the asset library, voices are rebuilt every block
+    pub waveform: Arc<AudioWaveform>,
     /// Where in the output buffer do we start writing? (0 to buffer_len)
     pub output_offset_samples: usize,
     /// Where in the source WAV file do we start reading?
@@ -186,28 +331,95 @@ impl PreviewVoice {
     }
 }
 
+/// What one track renders with during a block. The track's generator, effects and one-shot
+/// voices move in for the block so tracks can render on different threads.
+struct TrackLane {
+    track_id: TrackId,
+    /// Not muted, and soloed when anything is
+    audible: bool,
+    monitoring: bool,
+    /// Index of the track's voice in `active_generators`
+    generator_voice: Option<usize>,
+    generator: Option<AudioGeneratorInstance>,
+    effects: Vec<AudioEffectInstance>,
+    oneshots: Vec<AudioVoice>,
+    buffer: Vec<f32>,
+    /// MIDI events of the piece of the block being rendered, relative to its start
+    midi_events: Vec<MidiEvent>,
+    /// Rendered something and went through the channel strip
+    has_signal: bool,
+}
+
+impl TrackLane {
+    fn new(block_len: usize) -> Self {
+        Self {
+            track_id: TrackId::default(),
+            audible: false,
+            monitoring: false,
+            generator_voice: None,
+            generator: None,
+            effects: Vec::new(),
+            oneshots: Vec::with_capacity(16),
+            buffer: Vec::with_capacity(block_len),
+            midi_events: Vec::with_capacity(64),
+            has_signal: false,
+        }
+    }
+}
+
+/// What every track lane reads during a block.
+struct LaneBlock<'a> {
+    channels: &'a IndexMap<TrackId, Arc<MixerChannel>>,
+    /// Strip of tracks the mixer has no channel for
+    default_channel: &'a MixerChannel,
+    generators: &'a [GeneratorVoice],
+    automation: &'a [(TrackId, StripAutomation)],
+    /// Live input of this slice of the block
+    input: Option<&'a [f32]>,
+    sample_rate: u32,
+    buf_len: usize,
+    channels_count: usize,
+}
+
 impl AudioEngine {
     pub fn new(
         state_consumer: Output<AudioRenderState>,
         command_consumer: Consumer<AudioCommand>,
-        position_producer: Producer<TransportFeedback>,
-        feedback_producer: Producer<AudioFeedback>,
+        handles: EngineHandles,
         sample_rate: u32,
         num_channels: u16,
         initial_bpm: f32,
         initial_state: AudioRenderState
     ) -> Self {
+        let EngineHandles {
+            position_producer,
+            feedback_producer,
+            meter_producer,
+            loudness_report,
+            load_report,
+        } = handles;
         let mix_buffer = Vec::with_capacity(2048);
+        let mut meters = MixerMeters::new(meter_producer);
+        meters.set_strips(meter_targets(&initial_state));
         Self {
             state_consumer,
             command_consumer,
             position_producer,
             feedback_producer,
             current_state: initial_state,
+            meters,
+            loudness: Some(LoudnessMeter::new(sample_rate)),
+            loudness_report,
+            load: LoadMonitor::new(load_report),
+            analyzer_taps: Vec::with_capacity(MAX_ANALYZER_TAPS),
+            garbage: None,
+            delay_compensation: DelayCompensation::new(2, 2048),
+            delay_lines_stale: true,
+            mix_latency: Arc::new(AtomicU32::new(0)),
             // Transport state
             is_playing: false,
             is_looping: false,
-            is_recording: false,
+            loop_region: None,
             is_pattern_playing: false,
             bpm: initial_bpm,
             sample_rate,
@@ -219,14 +431,32 @@ impl AudioEngine {
             plugin_state: AudioPluginState::default(),
             current_beat: 1,
             current_bar: 1,
+            current_signature: TimeSignature::COMMON,
             pattern_playhead_samples: 0,
             pattern_beat: 1,
             pattern_bar: 1,
             last_emitted_pattern_samples: 0,
             last_emitted_samples: 0,
             mix_buffer,
+            track_lanes: Vec::new(),
+            default_channel: MixerChannel::default(),
+            render_pool: RenderPool::new(default_render_threads()).unwrap_or_else(|e| {
+                log::warn!("Rendering tracks on the audio thread only: {:#}", e);
+                RenderPool::serial()
+            }),
             bus_buffers: HashMap::new(),
             bus_temp_buffer: Vec::with_capacity(2048),
+            metronome: Metronome::default(),
+            click_buffer: Vec::with_capacity(2048),
+            click_pending: false,
+            recorder: InputRecorder::default(),
+            note_take: None,
+            midi_input: LiveMidiInput::default(),
+            midi_output: MidiOutput::default(),
+            block_cursor: 0,
+            loop_pass: 0,
+            pattern_pass: 0,
+            held_keys: HeldKeys::with_capacity(128),
             cached_routing_order: Vec::new(),
             playback_mode: PlaybackMode::Song,
             track_automation_events: SmallVec::new(),
@@ -235,27 +465,111 @@ impl AudioEngine {
         }
     }
 
+    /// Renders interleaved frames of the engine's channel count straight into `output_buffer`,
+    /// whatever its length. Buffers longer than the plugins were prepared for are rendered in
+    /// several blocks.
     pub fn process(&mut self, output_buffer: &mut [f32]) {
+        let started = Instant::now();
+        let callback_time = midi_clock_now();
+        let channels = self.num_channels.max(1) as usize;
+        let total_frames = output_buffer.len() / channels;
+
+        let mut start = 0;
+        while start < total_frames {
+            let frames = (total_frames - start).min(self.max_block_frames());
+            let block = &mut output_buffer[start * channels..(start + frames) * channels];
+
+            // Outgoing MIDI is due when the block plays, incoming MIDI replays the window
+            // that ends as far before the callback as the block ends before the buffer does
+            let block_time = callback_time + frames_to_duration(start, self.sample_rate);
+            let input_until = callback_time.saturating_sub(
+                frames_to_duration(total_frames - start - frames, self.sample_rate)
+            );
+            self.process_block(block, block_time, input_until);
+            start += frames;
+        }
+
+        self.load.record(started.elapsed(), total_frames, self.sample_rate, &mut self.plugin_state);
+    }
+
+    /// Counter of the xruns the backend running this engine has seen, shown in the load report.
+    pub fn xrun_counter(&self) -> Arc<AtomicU64> {
+        self.load.xrun_counter()
+    }
+
+    /// Publishes the plugin latency of the mix to `latency` from now on, so takes can be placed
+    /// where they were heard.
+    pub fn set_latency_report(&mut self, latency: Arc<AtomicU32>) {
+        self.mix_latency = latency;
+    }
+
+    /// Hands what the engine removes to `sink` to be freed on another thread.
+    pub fn set_garbage_sink(&mut self, sink: Producer<Garbage>) {
+        self.garbage = Some(sink);
+    }
+
+    /// Lets go of something removed, off the audio thread when there is a garbage sink.
+    fn discard(&mut self, garbage: Garbage) {
+        if let Some(sink) = self.garbage.as_mut() {
+            // A full sink means it is freed here after all
+            let _ = sink.push(garbage);
+        }
+    }
+
+    /// Stops analysing the loudness of the master output, for renders measured elsewhere.
+    pub fn disable_loudness(&mut self) {
+        self.loudness = None;
+    }
+
+    /// Renders tracks on `threads` workers besides the audio thread, 0 renders them serially.
+    /// Spawns threads, so call it before the engine is handed to an output.
+    pub fn set_render_threads(&mut self, threads: usize) -> anyhow::Result<()> {
+        self.render_pool = RenderPool::new(threads)?;
+        Ok(())
+    }
+
+    /// Largest block the plugins are prepared for.
+    fn max_block_frames(&self) -> usize {
+        self.current_state.graph.buffer_size.max(MIN_PREPARED_BLOCK_FRAMES)
+    }
+
+    fn process_block(
+        &mut self,
+        output_buffer: &mut [f32],
+        block_time: Duration,
+        input_until: Duration
+    ) {
+        self.midi_output.begin_block(block_time, self.sample_rate);
+
         // Sync graph state (transport no longer comes via triple buffer)
         if self.state_consumer.update() {
             let new_state = self.state_consumer.read().clone();
 
             // Update cached routing order only when state changes (not every callback)
             self.cached_routing_order = new_state.graph.mixer_state.get_routing_order();
+            self.meters.set_strips(meter_targets(&new_state));
 
             self.current_state = new_state;
+            self.delay_lines_stale = true;
         }
 
         // Process Commands (Play, Stop, Seek)
         while let Ok(cmd) = self.command_consumer.pop() {
             self.process_command(cmd);
+            // Plugins coming and going, or their settings, can change the latencies
+            self.delay_lines_stale = true;
         }
 
         // Clear Buffer
         output_buffer.fill(0.0);
-        let channels = 2;
+        let channels = self.num_channels.max(1) as usize;
         let frame_count = output_buffer.len() / channels;
 
+        // Drain the input every block so it never piles up, recording or not
+        self.recorder.pull(frame_count);
+        self.midi_input.pull(frame_count, self.sample_rate, input_until);
+        self.block_cursor = 0;
+
         // Transport Logic
         if self.is_playing {
             match self.playback_mode {
@@ -280,8 +594,14 @@ impl AudioEngine {
             self.emit_static_position();
         }
 
-        // Always Render Previews (Metronome, Browser Preview)
+        // Always Render Previews (Browser Preview)
         self.render_previews_to_buffer(output_buffer, channels);
+
+        if self.meters.advance(frame_count, self.sample_rate) {
+            if let Some(loudness) = &self.loudness {
+                self.loudness_report.write(loudness.report());
+            }
+        }
     }
 
     fn advance_playhead(&mut self, frame_count: usize) {
@@ -301,18 +621,19 @@ impl AudioEngine {
     /// Recalculates pattern beat/bar based on pattern_playhead_samples
     fn recalculate_pattern_beat_bar(&mut self) {
         let tempo = self.bpm;
-        if tempo <= 0.0 {
+        if tempo <= 0.0 || self.sample_rate == 0 {
             return;
         }
 
-        let samples_per_beat = ((60.0 / tempo) * (self.sample_rate as f32)) as usize;
-        if samples_per_beat == 0 {
-            return;
-        }
+        // Patterns are not on the timeline, so they use the project start signature
+        let seconds = (self.pattern_playhead_samples as f64) / (self.sample_rate as f64);
+        let tick = (seconds * (tempo as f64) * (TICKS_PER_BEAT as f64)) / 60.0;
+        let signature = self.current_state.graph.time_signatures.initial();
+        let position = signature.position_at(tick as u64);
 
         // Pattern beat/bar are 1-indexed within the pattern
-        self.pattern_beat = (self.pattern_playhead_samples as usize) / samples_per_beat + 1;
-        self.pattern_bar = (self.pattern_beat - 1) / 4 + 1;
+        self.pattern_beat = (position.beat as usize) + 1;
+        self.pattern_bar = (position.bar as usize) + 1;
     }
 
     fn process_song_mode(
@@ -321,30 +642,132 @@ impl AudioEngine {
         output_buffer: &mut [f32],
         channels: usize
     ) {
-        if self.playhead_samples > self.current_state.graph.max_sample_index {
-            if self.is_looping {
-                // Reset playhead back to 0 without changing `is_playing` state
-                self.playhead_samples = 0;
-                self.recalculate_beat_bar();
-                self.last_emitted_samples = 0;
+        // The playhead waits while the metronome counts the bars in
+        let counted = self.process_count_in(frame_count, output_buffer, channels);
+        if counted == frame_count {
+            return;
+        }
+        let frame_count = frame_count - counted;
+        let output_buffer = &mut output_buffer[counted * channels..];
+
+        let song_end_samples = self.current_state.graph.tempo_map.tick_to_sample(
+            self.current_state.graph.max_tick_index,
+            self.sample_rate
+        ) as u32;
+        let loop_bounds = self.active_loop_bounds(song_end_samples);
+
+        // Past the end of the song and not heading into a loop end.
+        // Recording keeps rolling so takes can extend the song
+        let past_loop = loop_bounds.is_none_or(|(_, loop_end)| self.playhead_samples >= loop_end);
+        if self.playhead_samples > song_end_samples && past_loop && !self.is_recording() {
+            if !self.is_looping {
+                // If not looping, stop playback normally
+                self.stop_playback();
+                return;
+            }
+            let loop_start = loop_bounds.map_or(0, |(loop_start, _)| loop_start);
+            self.wrap_playhead(loop_start);
+        }
 
-                // Kill trailing notes/audio to prevent a massive wall of sound
-                // from release tails accumulating when jumping back to bar 1
-                self.stop_all_active_generators();
-                self.active_oneshots.clear();
+        // Takes are linear: a loop wrap does not split them
+        self.recorder.capture(counted, self.playhead_samples);
+        if self.note_take.is_some() {
+            let tick = self.current_tick();
+            if let Some(take) = self.note_take.as_mut() {
+                take.mark_start(tick);
+            }
+        }
+
+        // Split the block at the loop end so the wrap lands on the exact sample
+        let mut rendered = 0;
+        while rendered < frame_count {
+            let mut chunk = frame_count - rendered;
+            if let Some((loop_start, loop_end)) = loop_bounds {
+                if self.playhead_samples == loop_end {
+                    self.wrap_playhead(loop_start);
+                }
+                if self.playhead_samples < loop_end {
+                    chunk = chunk.min((loop_end - self.playhead_samples) as usize);
+                }
+            }
 
-                // Immediately process the first block of the new loop
-                self.process_block_song_mode(frame_count, output_buffer, channels);
+            let slice = &mut output_buffer[rendered * channels..(rendered + chunk) * channels];
+            self.process_block_song_mode(chunk, slice, channels);
+            rendered += chunk;
+        }
+    }
 
-                // Force a UI update to snap the playhead back visually
-                self.emit_current_playback_position();
-            } else {
-                // If not looping, stop playback normally
-                self.stop_playback();
+    /// Renders the part of the block taken by a running count-in and returns its length in frames.
+    fn process_count_in(
+        &mut self,
+        frame_count: usize,
+        output_buffer: &mut [f32],
+        channels: usize
+    ) -> usize {
+        if !self.metronome.is_counting_in() {
+            return 0;
+        }
+
+        let len = frame_count * channels;
+        self.prepare_click_buffer(len);
+        let counted = self.metronome.render_count_in(
+            &mut self.click_buffer[..len],
+            channels,
+            self.sample_rate
+        );
+        self.click_pending = true;
+
+        // Voices keep rendering so tails from before playback finish naturally
+        self.render_voices_to_buffer(&mut output_buffer[..counted * channels], channels);
+        self.cleanup_finished_voices();
+
+        counted
+    }
+
+    /// Zeroes the first `len` samples of the click buffer, growing it if needed.
+    fn prepare_click_buffer(&mut self, len: usize) {
+        if self.click_buffer.len() < len {
+            self.click_buffer.resize(len, 0.0);
+        }
+        self.click_buffer[..len].fill(0.0);
+    }
+
+    /// Loop bounds on the sample timeline, or `None` when song playback should not loop.
+    fn active_loop_bounds(&self, song_end_samples: u32) -> Option<(u32, u32)> {
+        if !self.is_looping {
+            return None;
+        }
+        let tempo_map = &self.current_state.graph.tempo_map;
+        let (start, end) = match self.loop_region {
+            Some(region) => {
+                (
+                    tempo_map.tick_to_sample(region.start, self.sample_rate) as u32,
+                    tempo_map.tick_to_sample(region.end, self.sample_rate) as u32,
+                )
+            }
+            None => (0, song_end_samples),
+        };
+        (end > start).then_some((start, end))
+    }
+
+    /// Jumps the song playhead back to the loop start.
+    /// Generators are left running so release tails ring across the loop seam;
+    /// notes still held are released by the interrupted-note check in `process_track`.
+    fn wrap_playhead(&mut self, loop_start: u32) {
+        if self.note_take.is_some() {
+            let tick = self.current_tick();
+            if let Some(take) = self.note_take.as_mut() {
+                take.push(RecordedNoteEvent::LoopWrap { tick });
             }
-        } else {
-            self.process_block_song_mode(frame_count, output_buffer, channels);
         }
+        self.playhead_samples = loop_start;
+        self.loop_pass += 1;
+        self.recalculate_beat_bar();
+        self.last_emitted_samples = loop_start;
+        self.midi_output.transport_jumped(self.current_tick(), self.block_cursor);
+
+        // Force a UI update to snap the playhead back visually
+        self.emit_current_playback_position();
     }
 
     // Process a block of frame rendering in SONG mode (normal playback)
@@ -358,7 +781,26 @@ impl AudioEngine {
         self.resolve_sequencer_events(buffer_size);
 
         // Apply Automation (parameter modulation)
-        self.evaluate_automation_lanes();
+        self.evaluate_automation_lanes(buffer_size);
+
+        self.midi_output.send_clock(
+            &self.current_state.graph.tempo_map,
+            self.playhead_samples,
+            buffer_size,
+            self.block_cursor
+        );
+
+        // Metronome clicks, mixed in by render_voices_to_buffer
+        let click_len = buffer_size * channels;
+        self.prepare_click_buffer(click_len);
+        self.click_pending = self.metronome.render_song_block(
+            &mut self.click_buffer[..click_len],
+            channels,
+            &self.current_state.graph.tempo_map,
+            &self.current_state.graph.time_signatures,
+            self.playhead_samples,
+            self.sample_rate
+        );
 
         // Render Active Voices
         self.render_voices_to_buffer(output_buffer, channels);
@@ -405,6 +847,7 @@ impl AudioEngine {
         if self.pattern_playhead_samples >= loop_len_samples {
             self.pattern_playhead_samples = 0;
             self.last_emitted_pattern_samples = 0;
+            self.pattern_pass += 1;
 
             // This safely clears tracked keys to prevent hang on pattern loop
             Self::stop_all_active_generators_impl(
@@ -434,35 +877,21 @@ impl AudioEngine {
         let gen_voice = &mut self.active_generators[voice_idx];
         gen_voice.active = true;
 
-        let mut expected_at_start = Vec::new();
-        let mut expected_at_end = Vec::new();
+        let held_keys = &mut self.held_keys;
+        held_keys.clear();
 
         Self::schedule_pattern_notes_raw(
             &mut gen_voice.midi_events,
-            &mut expected_at_start,
-            &mut expected_at_end,
-            &pattern.notes,
+            held_keys,
+            pattern,
             self.sample_rate,
             tempo,
+            mix_seed(self.current_state.graph.random_seed, self.pattern_pass),
             start_time,
             end_time
         );
 
-        let mut missed_keys = Vec::new();
-        for &key in &gen_voice.playing_keys {
-            if !expected_at_start.contains(&key) && !missed_keys.contains(&key) {
-                missed_keys.push(key);
-                gen_voice.midi_events.push(MidiEvent {
-                    sample_offset: 0,
-                    data: MidiMessage::NoteOff { key },
-                });
-            }
-        }
-
-        expected_at_end.sort_unstable();
-        expected_at_end.dedup();
-        gen_voice.playing_keys = expected_at_end;
-        gen_voice.midi_events.sort_by_key(|e| e.sample_offset);
+        held_keys.release_interrupted(gen_voice);
 
         // Render voices to buffer
         self.render_voices_to_buffer(output_buffer, channels);
@@ -472,10 +901,15 @@ impl AudioEngine {
     }
 
     fn stop_playback(&mut self) {
+        self.midi_output.transport_stopped();
         self.is_playing = false;
         self.is_pattern_playing = false;
+        self.metronome.reset();
         self.stop_all_active_generators();
         self.stop_all_automation_events();
+        self.delay_compensation.reset();
+        self.loop_pass = 0;
+        self.pattern_pass = 0;
         self.reset_playhead();
     }
 
@@ -515,6 +949,17 @@ impl AudioEngine {
                 if self.is_playing && !val {
                     // Stopping: silence all active generators
                     self.stop_all_active_generators();
+                    self.metronome.reset();
+                    self.midi_output.transport_stopped();
+                }
+                if !self.is_playing && val && self.playback_mode == PlaybackMode::Song {
+                    self.midi_output.transport_started();
+                    self.metronome.start_count_in(
+                        &self.current_state.graph.tempo_map,
+                        &self.current_state.graph.time_signatures,
+                        self.playhead_samples,
+                        self.sample_rate
+                    );
                 }
                 self.is_playing = val;
                 self.emit_current_playback_position();
@@ -523,6 +968,78 @@ impl AudioEngine {
                 self.is_looping = val;
                 self.emit_current_playback_position();
             }
+            AudioCommand::SetLoopRegion(region) => {
+                self.loop_region = region;
+            }
+            AudioCommand::SetMetronomeEnabled(enabled) => {
+                self.metronome.set_enabled(enabled);
+            }
+            AudioCommand::SetMetronomeVolume(volume) => {
+                self.metronome.set_volume(volume);
+            }
+            AudioCommand::SetMetronomeTarget(target) => {
+                self.metronome.set_target(target);
+            }
+            AudioCommand::AddAnalyzerTap(tap) => {
+                // Never grow past the reserved slots, a refused tap's worker sees it abandoned
+                if self.analyzer_taps.len() < MAX_ANALYZER_TAPS {
+                    self.analyzer_taps.push(tap);
+                } else {
+                    self.discard(Garbage::AnalyzerTap(tap));
+                }
+            }
+            AudioCommand::RemoveAnalyzerTap(id) => {
+                if let Some(index) = self.analyzer_taps.iter().position(|tap| tap.id == id) {
+                    let tap = self.analyzer_taps.swap_remove(index);
+                    self.discard(Garbage::AnalyzerTap(tap));
+                }
+            }
+            AudioCommand::ResetLoudness => {
+                if let Some(loudness) = self.loudness.as_mut() {
+                    loudness.reset();
+                }
+                self.loudness_report.write(LoudnessReport::default());
+            }
+            AudioCommand::ResetEngineLoad => {
+                self.load.reset();
+            }
+            AudioCommand::SetCountInBars(bars) => {
+                self.metronome.set_count_in_bars(bars);
+            }
+            AudioCommand::SetAudioInput(port) => {
+                self.recorder.set_port(port);
+            }
+            AudioCommand::StartRecording(take) => {
+                self.recorder.start_take(take);
+                self.emit_current_playback_position();
+            }
+            AudioCommand::StopRecording => {
+                self.recorder.stop_take();
+                self.emit_current_playback_position();
+            }
+            AudioCommand::StartNoteRecording(take) => {
+                self.note_take = Some(take);
+                self.emit_current_playback_position();
+            }
+            AudioCommand::StopNoteRecording => {
+                let tick = self.current_tick();
+                if let Some(mut take) = self.note_take.take() {
+                    take.push(RecordedNoteEvent::End { tick });
+                }
+                self.emit_current_playback_position();
+            }
+            AudioCommand::SetMidiInput(port) => {
+                self.midi_input.set_port(port);
+            }
+            AudioCommand::SetMidiInputTrack(track_id) => {
+                self.midi_input.set_target(track_id);
+            }
+            AudioCommand::SetMidiOutput(port) => {
+                self.midi_output.set_port(port);
+            }
+            AudioCommand::SetMidiOutputSettings(settings) => {
+                self.midi_output.set_settings(settings);
+            }
             AudioCommand::StopAndReset => {
                 self.stop_playback();
             }
@@ -531,6 +1048,7 @@ impl AudioEngine {
                 self.playhead_samples = samples as u32;
                 self.recalculate_beat_bar();
                 self.last_emitted_samples = self.playhead_samples;
+                self.midi_output.transport_jumped(self.current_tick(), 0);
                 self.emit_current_playback_position(); // Snap UI immediately
             }
             AudioCommand::PlayPreviewNote { note_key, generator_id, velocity, is_note_on } => {
@@ -552,6 +1070,7 @@ impl AudioEngine {
                 match (self.playback_mode, playback_mode) {
                     (PlaybackMode::Song, PlaybackMode::Pattern { .. }) => {
                         self.pattern_playhead_samples = 0;
+                        self.pattern_pass = 0;
                         self.last_emitted_pattern_samples = 0;
                         self.recalculate_pattern_beat_bar();
                         self.is_pattern_playing = true;
@@ -573,7 +1092,7 @@ impl AudioEngine {
             }
             AudioCommand::AddGenerator { generator_id, track_id, mut plugin } => {
                 // Prepare the plugin with current sample rate and buffer size
-                let buf_size = self.current_state.graph.buffer_size.max(512);
+                let buf_size = self.max_block_frames();
                 plugin.prepare(self.sample_rate as f32, self.num_channels as usize, buf_size);
 
                 let id_index = generator_id.to_u32() as usize;
@@ -582,6 +1101,7 @@ impl AudioEngine {
                     id: generator_id,
                     track_id,
                     plugin,
+                    load: PluginTimer::default(),
                 });
                 log::info!(
                     "[AudioEngine] Added generator {:?} for track {:?}",
@@ -630,12 +1150,13 @@ impl AudioEngine {
             }
             AudioCommand::AddTrackEffect { track_id, effect_id, mut effect } => {
                 // Prepare the effect
-                let buf_size = self.current_state.graph.buffer_size.max(512);
+                let buf_size = self.max_block_frames();
                 effect.prepare(self.sample_rate as f32, self.num_channels as usize, buf_size);
 
                 self.plugin_state.add_track_effect(track_id.to_u32() as usize, AudioEffectInstance {
                     id: effect_id,
                     plugin: effect,
+                    load: PluginTimer::default(),
                 });
                 log::info!("[AudioEngine] Added effect to track {:?}", track_id);
             }
@@ -686,11 +1207,12 @@ impl AudioEngine {
                 }
             }
             AudioCommand::AddMasterEffect { effect_id, mut effect } => {
-                let buf_size = self.current_state.graph.buffer_size;
+                let buf_size = self.max_block_frames();
                 effect.prepare(self.sample_rate as f32, self.num_channels as usize, buf_size);
                 self.plugin_state.master_effects.push(AudioEffectInstance {
                     id: effect_id,
                     plugin: effect,
+                    load: PluginTimer::default(),
                 });
             }
             AudioCommand::RemoveMasterEffect { effect_id } => {
@@ -737,12 +1259,13 @@ impl AudioEngine {
                 );
             }
             AudioCommand::AddBusEffect { bus_id, effect_id, mut effect } => {
-                let buf_size = self.current_state.graph.buffer_size.max(512);
+                let buf_size = self.max_block_frames();
                 effect.prepare(self.sample_rate as f32, self.num_channels as usize, buf_size);
 
                 self.plugin_state.add_bus_effect(bus_id.to_u32() as usize, AudioEffectInstance {
                     id: effect_id,
                     plugin: effect,
+                    load: PluginTimer::default(),
                 });
                 log::info!("[AudioEngine] Added effect {:?} to bus {:?}", effect_id, bus_id);
             }
@@ -845,13 +1368,10 @@ impl AudioEngine {
                     }
                 }
             }
-            AudioCommand::PreparePlugin {
-                track_effects,
-                master_effects,
-                bus_effects,
-                generators,
-            } => {
-                let buf_size = self.current_state.graph.buffer_size.max(512);
+            AudioCommand::PreparePlugin(plugins) => {
+                let PreparedPlugins { track_effects, master_effects, bus_effects, generators } =
+                    *plugins;
+                let buf_size = self.max_block_frames();
                 let sample_rate = self.sample_rate as f32;
                 let channels = self.num_channels as usize;
 
@@ -881,6 +1401,7 @@ impl AudioEngine {
                             id: gen_id,
                             track_id,
                             plugin,
+                            load: PluginTimer::default(),
                         }
                     );
                 }
@@ -894,6 +1415,7 @@ impl AudioEngine {
                             AudioEffectInstance {
                                 id: effect_id,
                                 plugin,
+                                load: PluginTimer::default(),
                             }
                         );
                     }
@@ -910,6 +1432,7 @@ impl AudioEngine {
                         self.plugin_state.add_bus_effect(bus_id_index, AudioEffectInstance {
                             id: effect_id,
                             plugin,
+                            load: PluginTimer::default(),
                         });
                     }
                 }
@@ -929,6 +1452,7 @@ impl AudioEngine {
                     self.plugin_state.master_effects.push(AudioEffectInstance {
                         id: effect_id,
                         plugin,
+                        load: PluginTimer::default(),
                     });
                 }
 
@@ -940,18 +1464,15 @@ impl AudioEngine {
     /// Recalculates current Beat and Bar based on playhead_samples
     /// Uses 1-based indexing for musical time.
     fn recalculate_beat_bar(&mut self) {
-        let tempo = self.bpm;
-        if tempo <= 0.0 {
-            return;
-        }
-
-        let samples_per_beat = ((60.0 / tempo) * (self.sample_rate as f32)) as usize;
-        if samples_per_beat == 0 {
-            return;
-        }
-
-        self.current_beat = (self.playhead_samples as usize) / samples_per_beat + 1;
-        self.current_bar = (self.current_beat - 1) / 4 + 1;
+        let tempo_map = &self.current_state.graph.tempo_map;
+        let tick = tempo_map.sample_to_tick(self.playhead_samples as u64, self.sample_rate).ticks();
+
+        // Follow the tempo map so feedback reports the tempo under the playhead
+        self.bpm = tempo_map.bpm_at_tick(tick as f64);
+        let position = self.current_state.graph.time_signatures.position_at(tick);
+        self.current_beat = (position.beat as usize) + 1;
+        self.current_bar = (position.bar as usize) + 1;
+        self.current_signature = position.signature;
     }
 
     fn reset_playhead(&mut self) {
@@ -959,6 +1480,7 @@ impl AudioEngine {
         self.playhead_samples = 0;
         self.current_beat = 1;
         self.current_bar = 1;
+        self.current_signature = self.current_state.graph.time_signatures.initial();
         self.last_emitted_samples = 0;
         self.emit_static_position();
     }
@@ -994,6 +1516,11 @@ impl AudioEngine {
     fn build_position_struct(&self, is_playing: Option<bool>) -> TransportFeedback {
         let is_playing = is_playing.unwrap_or(self.is_playing);
         let is_pattern_mode = matches!(self.playback_mode, PlaybackMode::Pattern { .. });
+        let signature = if is_pattern_mode {
+            self.current_state.graph.time_signatures.initial()
+        } else {
+            self.current_signature
+        };
 
         TransportFeedback {
             // Song position
@@ -1002,16 +1529,19 @@ impl AudioEngine {
             bar: self.current_bar,
             tempo: self.bpm,
             sample_rate: self.current_state.graph.sample_rate,
+            numerator: signature.numerator,
+            denominator: signature.denominator,
             // Transport state
             is_playing,
             is_looping: self.is_looping,
-            is_recording: self.is_recording,
+            is_recording: self.is_recording(),
             is_pattern_playing: self.is_pattern_playing,
             // Pattern position (independent)
             is_pattern_mode,
             pattern_samples: self.pattern_playhead_samples,
             pattern_beat: self.pattern_beat,
             pattern_bar: self.pattern_bar,
+            latency_samples: self.delay_compensation.total_latency(),
         }
     }
 
@@ -1037,19 +1567,119 @@ impl AudioEngine {
         self.active_oneshots.clear();
     }
 
+    /// Whether an audio or a note take is running.
+    fn is_recording(&self) -> bool {
+        self.recorder.is_recording() || self.note_take.is_some()
+    }
+
+    /// Song playhead in ticks.
+    fn current_tick(&self) -> u64 {
+        self.current_state.graph.tempo_map.sample_to_tick(self.playhead_samples as u64, self.sample_rate).ticks()
+    }
+
+    /// Stamps a live note on an armed track into the running note take.
+    /// `offset` is the note's frame within the slice being rendered.
+    /// Notes only count while song playback rolls, not during the count-in.
+    fn record_live_note(
+        &mut self,
+        track_id: TrackId,
+        key: u8,
+        velocity: u8,
+        is_on: bool,
+        offset: usize
+    ) {
+        let rolling =
+            self.is_playing &&
+            self.playback_mode == PlaybackMode::Song &&
+            !self.metronome.is_counting_in();
+        if !rolling || self.note_take.is_none() {
+            return;
+        }
+
+        let tick = self.current_state.graph.tempo_map
+            .sample_to_tick((self.playhead_samples as u64) + (offset as u64), self.sample_rate)
+            .ticks();
+        let event = if is_on {
+            RecordedNoteEvent::On { track_id, key, velocity, tick }
+        } else {
+            RecordedNoteEvent::Off { track_id, key, tick }
+        };
+        if let Some(take) = self.note_take.as_mut() {
+            take.push(event);
+        }
+    }
+
+    /// Hands the hardware MIDI of frames `start..start + frames` of the block to the generators
+    /// of armed MIDI tracks, or of the MIDI input track when none is armed.
+    fn dispatch_live_midi(&mut self, start: usize, frames: usize) {
+        let span = self.midi_input.span(start, frames);
+        if span.is_empty() {
+            return;
+        }
+
+        let tracks = Arc::clone(&self.current_state.graph.tracks);
+        let any_armed = tracks.iter().any(|t| t.record_armed && t.generator.is_some());
+        for track in tracks.iter() {
+            let Some(gen_instance) = &track.generator else {
+                continue;
+            };
+            let targeted = if any_armed {
+                track.record_armed
+            } else {
+                self.midi_input.target() == Some(track.id)
+            };
+            if !targeted {
+                continue;
+            }
+
+            let Some(voice_idx) = Self::ensure_generator_voice(
+                &mut self.active_generators,
+                &self.plugin_state,
+                track.id,
+                gen_instance
+            ) else {
+                continue;
+            };
+            let voice = &mut self.active_generators[voice_idx];
+            for index in span.clone() {
+                let (offset, message) = self.midi_input.event(index, start);
+                voice.midi_events.push(MidiEvent { sample_offset: offset, data: message });
+            }
+            voice.midi_events.sort_by_key(|e| e.sample_offset);
+            voice.active = true;
+
+            if track.record_armed {
+                for index in span.clone() {
+                    match self.midi_input.event(index, start) {
+                        (offset, MidiMessage::NoteOn { key, velocity }) => {
+                            self.record_live_note(track.id, key, velocity, true, offset);
+                        }
+                        (offset, MidiMessage::NoteOff { key }) => {
+                            self.record_live_note(track.id, key, 0, false, offset);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
     fn trigger_live_note(&mut self, generator_id: GeneratorId, key: u8, velocity: u8, is_on: bool) {
         // Try to find the track that has this generator from current_state
         let target_info = self.current_state.graph.tracks.iter().find_map(|t| {
             if let Some(gen) = &t.generator {
                 if gen.id == generator_id {
-                    return Some((t.id, gen.clone()));
+                    return Some((t.id, gen.clone(), t.record_armed));
                 }
             }
             None
         });
 
         // If we found the track info, use it
-        if let Some((track_id, gen_instance)) = target_info {
+        if let Some((track_id, gen_instance, record_armed)) = target_info {
+            if record_armed {
+                self.record_live_note(track_id, key, velocity, is_on, 0);
+            }
             if
                 let Some(voice_idx) = Self::ensure_generator_voice(
                     &mut self.active_generators,
@@ -1120,115 +1750,83 @@ impl AudioEngine {
             buf.fill(0.0);
         }
 
+        self.update_delay_compensation();
+
         // Check for solo state
         let is_any_solo = self.current_state.graph.mixer_state.channels.values().any(|ch| ch.solo);
 
-        // Get routing info
-        let routing = &self.current_state.graph.mixer_state.routing;
-
-        // ==== Phase 1: Render all tracks and route to destinations ====
-        for track in self.current_state.graph.tracks.iter() {
-            let track_id = track.id;
-
-            let default_channel = Arc::new(MixerChannel::default());
-
-            let mut channel = self.current_state.graph.mixer_state.channels
-                .get(&track_id)
-                .cloned()
-                .unwrap_or(default_channel);
-
-            let channel_mut = Arc::make_mut(&mut channel);
-
-            // Check mute/solo
-            if channel_mut.mute {
-                continue;
-            }
-            if is_any_solo && !channel_mut.solo {
-                continue;
-            }
+        // Live input for this slice of the block, heard on monitoring tracks
+        let input_frames = buf_len / channels;
+        let input_start = self.block_cursor;
+        self.block_cursor += input_frames;
+        let is_recording = self.is_recording();
 
-            // Ensure mix_buffer is sized correctly
-            if self.mix_buffer.len() != buf_len {
-                self.mix_buffer.resize(buf_len, 0.0);
-            }
-            self.mix_buffer.fill(0.0);
-
-            let mut has_signal = false;
+        self.dispatch_live_midi(input_start, input_frames);
 
-            // Generator Voice - use plugin_state directly (no lock!)
-            if
-                let Some(gen_voice) = self.active_generators
-                    .iter()
-                    .find(|g| g.track_id == track_id && g.active)
-            {
-                let gen_id = gen_voice.id;
-                let events = &gen_voice.midi_events;
-                let param_events = &gen_voice.automation_events;
+        // ==== Phase 1: Render all tracks and route to destinations ====
+        // Each track renders on its own into its lane, spread over the render pool
+        self.gather_track_lanes(is_any_solo, input_start, is_recording);
+        {
+            let block = LaneBlock {
+                channels: &self.current_state.graph.mixer_state.channels,
+                default_channel: &self.default_channel,
+                generators: &self.active_generators,
+                automation: &self.track_automation_events,
+                input: self.recorder.input_span(input_start, input_frames),
+                sample_rate: self.sample_rate,
+                buf_len,
+                channels_count: channels,
+            };
+            self.render_pool.run(&mut self.track_lanes, &(|lane| Self::render_track_lane(lane, &block)));
+        }
 
-                if
-                    let Some(gen_instance) = self.plugin_state.get_generator_mut(
-                        gen_id.to_u32() as usize
-                    )
-                {
-                    // CONSUME AUTOMATION
-                    for ev in param_events {
-                        #[allow(irrefutable_let_patterns)]
-                        if let GeneratorAutomationEvent::PluginParam { param_id, value } = ev {
-                            gen_instance.plugin.set_parameter(*param_id, *value);
-                        }
-                    }
-                    // PROCESS AUDIO
-                    gen_instance.plugin.process(&mut self.mix_buffer, events);
-                    has_signal = true;
+        // Mixing stays serial and in track order, so the sums come out the same whatever
+        // thread rendered which track
+        let routing = &self.current_state.graph.mixer_state.routing;
+        for lane in self.track_lanes.iter_mut() {
+            let track_id = lane.track_id;
+            if lane.has_signal {
+                if let Some(meter) = self.meters.meter(MeterTarget::Track(track_id)) {
+                    meter.measure(&lane.buffer, channels);
                 }
-            }
-
-            // Audio Voice
-            if
-                Self::render_oneshots(
-                    &mut self.active_oneshots,
-                    self.sample_rate,
-                    track_id,
-                    &mut self.mix_buffer,
+                AnalyzerTap::feed(
+                    &mut self.analyzer_taps,
+                    RoutingNode::Track(track_id),
+                    &lane.buffer,
                     channels
-                )
-            {
-                has_signal = true;
-            }
-
-            if !has_signal {
+                );
+            } else if self.delay_compensation.is_ringing(RoutingNode::Track(track_id)) {
+                // A silent track still plays out what its delay lines hold
+                lane.buffer.resize(buf_len, 0.0);
+                lane.buffer.fill(0.0);
+            } else {
                 continue;
             }
-
-            // Apply track mixer channel (volume/pan/phase) and effects
-            Self::apply_mixer_channel_with_effects(
-                channel_mut,
-                &mut self.plugin_state.track_effects,
-                &self.track_automation_events,
-                track_id,
-                &mut self.mix_buffer,
-                channels
-            );
+            let source = RoutingNode::Track(track_id);
 
             // Route the track signal to destinations based on routing matrix
-            let track_routes: Vec<_> = routing
+            let mut track_routes = routing
                 .iter()
                 .filter(|c| c.source == RoutingNode::Track(track_id))
-                .collect();
+                .peekable();
 
-            if track_routes.is_empty() {
+            if track_routes.peek().is_none() {
                 // No explicit routing: go directly to master (backward compatibility)
-                apply_simd_mix(output, &self.mix_buffer);
+                let signal = self.delay_compensation.delay(source, RoutingNode::Master, &lane.buffer);
+                apply_simd_mix(output, signal);
             } else {
-                // Route to each destination with appropriate send level
+                // Route to each destination with appropriate send level, delayed to line up
+                // with the other paths into it
                 for conn in track_routes {
                     match conn.destination {
                         RoutingNode::Master => {
-                            apply_simd_mix_gain(output, &self.mix_buffer, conn.send_level);
+                            let signal = self.delay_compensation.delay(source, conn.destination, &lane.buffer);
+                            apply_simd_mix_gain(output, signal, conn.send_level);
                         }
                         RoutingNode::Bus(bus_id) => {
                             if let Some(bus_buf) = self.bus_buffers.get_mut(&bus_id) {
-                                apply_simd_mix_gain(bus_buf, &self.mix_buffer, conn.send_level);
+                                let signal = self.delay_compensation.delay(source, conn.destination, &lane.buffer);
+                                apply_simd_mix_gain(bus_buf, signal, conn.send_level);
                             }
                         }
                         RoutingNode::Track(_) => {
@@ -1238,13 +1836,32 @@ impl AudioEngine {
                 }
             }
         }
+        self.return_track_lanes();
 
-        // ==== Phase 2: Process buses in topological order ====
-        // Use cached routing order (computed only on state update, not every callback)
-        for node in self.cached_routing_order.clone().iter() {
-            if let RoutingNode::Bus(bus_id) = node {
-                // Copy bus audio to temp buffer (avoid clone allocation)
-                let bus_buf = match self.bus_buffers.get(bus_id) {
+        // Delayed clicks keep playing out after the last one
+        if !self.click_pending && self.delay_compensation.is_click_ringing() {
+            self.prepare_click_buffer(buf_len);
+            self.click_pending = true;
+        }
+
+        // Metronome routed to a bus joins the mix before the buses are processed
+        if self.click_pending {
+            if let Some(bus_buf) = self.metronome
+                .target()
+                .and_then(|bus_id| self.bus_buffers.get_mut(&bus_id))
+            {
+                apply_simd_mix(bus_buf, self.delay_compensation.delay_click(&self.click_buffer[..buf_len]));
+                self.click_pending = false;
+            }
+        }
+
+        // ==== Phase 2: Process buses in topological order ====
+        let routing = &self.current_state.graph.mixer_state.routing;
+        // Use cached routing order (computed only on state update, not every callback)
+        for index in 0..self.cached_routing_order.len() {
+            if let RoutingNode::Bus(bus_id) = &self.cached_routing_order[index] {
+                // Copy bus audio to temp buffer (avoid clone allocation)
+                let bus_buf = match self.bus_buffers.get(bus_id) {
                     Some(buf) => buf,
                     None => {
                         continue;
@@ -1258,16 +1875,14 @@ impl AudioEngine {
                 self.bus_temp_buffer.copy_from_slice(bus_buf);
 
                 // Get bus channel settings
-                let bus_channel = self.current_state.graph.mixer_state.buses
-                    .get_mut(bus_id)
-                    .map(|b| Arc::make_mut(b));
-
-                let Some(bus_settings) = bus_channel else {
+                let Some(bus_settings) = self.current_state.graph.mixer_state.buses.get(bus_id) else {
                     continue;
                 };
 
-                // Skip if muted
-                if bus_settings.channel.mute {
+                // A muted bus is silent, but still plays out what its delay lines hold
+                let source = RoutingNode::Bus(*bus_id);
+                let muted = bus_settings.channel.mute;
+                if muted && !self.delay_compensation.is_ringing(source) {
                     continue;
                 }
 
@@ -1275,109 +1890,55 @@ impl AudioEngine {
                 if self.mix_buffer.len() != buf_len {
                     self.mix_buffer.resize(buf_len, 0.0);
                 }
-                self.mix_buffer.copy_from_slice(&self.bus_temp_buffer);
+                if muted {
+                    self.mix_buffer.fill(0.0);
+                } else {
+                    self.mix_buffer.copy_from_slice(&self.bus_temp_buffer);
 
-                let bus_volume = &mut bus_settings.channel.volume;
-                let bus_pan = &mut bus_settings.channel.pan;
 
-                // CONSUME BUS AUTOMATION
-                if
-                    let Some((_, auto_events)) = self.bus_automation_events
+                    // Automation overrides the current UI state for this block only
+                    let auto_events = self.bus_automation_events
                         .iter()
                         .find(|(id, _)| *id == *bus_id)
-                {
-                    for event in auto_events {
-                        match event {
-                            BusAutomationEvent::Volume(v) => {
-                                bus_volume.apply_automation(*v);
-                            }
-                            BusAutomationEvent::Pan(v) => {
-                                bus_pan.apply_automation(*v);
-                            }
-                            BusAutomationEvent::PluginParam { effect_id, param_id, value } => {
-                                if
-                                    let Some(effects) = self.plugin_state.get_bus_effects_mut(
-                                        bus_id.to_u32() as usize
-                                    )
-                                {
-                                    if
-                                        let Some(effect) = effects
-                                            .iter_mut()
-                                            .find(|e| e.id == *effect_id)
-                                    {
-                                        effect.plugin.set_parameter(*param_id, *value);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-
-                // Apply bus effects
-                if
-                    let Some(effects) = self.plugin_state.get_bus_effects_mut(
-                        bus_id.to_u32() as usize
-                    )
-                {
-                    for effect in effects.iter_mut() {
-                        effect.plugin.process(&mut self.mix_buffer);
-                    }
-                }
-
-                // Apply volume and pan (volume is stored in dB)
-                let volume = db_to_linear(bus_volume.get());
-                let pan = bus_pan.get();
-                let (left_gain, right_gain) = if channels == 2 {
-                    let p = (pan + 1.0) * 0.5;
-                    ((1.0 - p).sqrt() * volume, p.sqrt() * volume)
-                } else {
-                    (volume, volume)
-                };
-
-                if channels == 2 {
-                    let gain_v = f32x4::new([left_gain, right_gain, left_gain, right_gain]);
-                    let mut iter = self.mix_buffer.chunks_exact_mut(4);
-
-                    for chunk in iter.by_ref() {
-                        let mut v = f32x4::new([chunk[0], chunk[1], chunk[2], chunk[3]]);
-                        v *= gain_v;
-                        chunk.copy_from_slice(&v.to_array());
-                    }
+                        .map_or(&[][..], |(_, events)| events.as_slice());
 
-                    // Remainder will be 0 or 2 floats (1 stereo frame)
-                    for chunk in iter.into_remainder().chunks_exact_mut(2) {
-                        chunk[0] *= left_gain;
-                        chunk[1] *= right_gain;
-                    }
-                } else {
-                    let gain_v = f32x4::splat(left_gain);
-                    let mut iter = self.mix_buffer.chunks_exact_mut(4);
-
-                    for chunk in iter.by_ref() {
-                        let mut v = f32x4::new([chunk[0], chunk[1], chunk[2], chunk[3]]);
-                        v *= gain_v;
-                        chunk.copy_from_slice(&v.to_array());
+                    // Apply bus effects, in pieces where automated parameters change
+                    if
+                        let Some(effects) = self.plugin_state.get_bus_effects_mut(
+                            bus_id.to_u32() as usize
+                        )
+                    {
+                        process_effects(effects, auto_events, &mut self.mix_buffer, channels);
                     }
 
-                    for s in iter.into_remainder() {
-                        *s *= left_gain;
+                    // Apply volume and pan (volume is stored in dB), ramped through their points
+                    apply_channel_gain(&bus_settings.channel, auto_events, &mut self.mix_buffer, channels);
+                    if let Some(meter) = self.meters.meter(MeterTarget::Bus(*bus_id)) {
+                        meter.measure(&self.mix_buffer, channels);
                     }
+                    AnalyzerTap::feed(
+                        &mut self.analyzer_taps,
+                        RoutingNode::Bus(*bus_id),
+                        &self.mix_buffer,
+                        channels
+                    );
                 }
 
                 // Route bus output to destinations
-                let bus_routes: Vec<_> = routing
+                let bus_routes = routing
                     .iter()
-                    .filter(|c| c.source == RoutingNode::Bus(*bus_id))
-                    .collect();
+                    .filter(|c| c.source == RoutingNode::Bus(*bus_id));
 
                 for conn in bus_routes {
                     match conn.destination {
                         RoutingNode::Master => {
-                            apply_simd_mix_gain(output, &self.mix_buffer, conn.send_level);
+                            let signal = self.delay_compensation.delay(source, conn.destination, &self.mix_buffer);
+                            apply_simd_mix_gain(output, signal, conn.send_level);
                         }
                         RoutingNode::Bus(dest_bus_id) => {
                             if let Some(dest_buf) = self.bus_buffers.get_mut(&dest_bus_id) {
-                                apply_simd_mix_gain(dest_buf, &self.mix_buffer, conn.send_level);
+                                let signal = self.delay_compensation.delay(source, conn.destination, &self.mix_buffer);
+                                apply_simd_mix_gain(dest_buf, signal, conn.send_level);
                             }
                         }
                         RoutingNode::Track(_) => {}
@@ -1387,15 +1948,233 @@ impl AudioEngine {
         }
 
         // ==== Phase 3: Apply master bus effects ====
-        let mut master_bus = self.current_state.graph.mixer_state.master_bus.clone();
-        let master_bus_mut = Arc::make_mut(&mut master_bus);
         Self::apply_master_bus_with_effects(
-            master_bus_mut,
+            &self.current_state.graph.mixer_state.master_bus,
             &mut self.plugin_state.master_effects,
             &self.master_automation_events,
+            self.meters.master(),
+            self.loudness.as_mut(),
             output,
             channels
         );
+        AnalyzerTap::feed(&mut self.analyzer_taps, RoutingNode::Master, output, channels);
+
+        // Unrouted metronome goes straight to the output, after the master bus
+        if self.click_pending {
+            apply_simd_mix(output, self.delay_compensation.delay_click(&self.click_buffer[..buf_len]));
+            self.click_pending = false;
+        }
+    }
+
+    /// Recomputes the delays that line up the paths through the mixer. Plugins can change their
+    /// latency at any time (a lookahead setting), so this runs every block. The delay lines only
+    /// get room for new delays after the graph or the plugins changed.
+    fn update_delay_compensation(&mut self) {
+        let plugins = &self.plugin_state;
+        let graph = &self.current_state.graph;
+        let click_target = self.metronome
+            .target()
+            .filter(|bus_id| self.bus_buffers.contains_key(bus_id));
+
+        self.delay_compensation.update(
+            graph.tracks.iter().map(|track| track.id),
+            self.cached_routing_order.iter().filter_map(|node| {
+                match node {
+                    RoutingNode::Bus(bus_id) => Some(*bus_id),
+                    _ => None,
+                }
+            }),
+            &graph.mixer_state.routing,
+            click_target,
+            |node| {
+                match node {
+                    RoutingNode::Track(track_id) => {
+                        // Whether or not a voice is playing, so notes starting don't move the mix
+                        let generator = graph.tracks
+                            .iter()
+                            .find(|track| track.id == track_id)
+                            .and_then(|track| track.generator.as_ref())
+                            .and_then(|generator| plugins.get_generator(generator.id.to_u32() as usize))
+                            .map_or(0, |generator| generator.plugin.latency_samples());
+                        let effects = plugins
+                            .get_track_effects(track_id.to_u32() as usize)
+                            .map_or(0, |effects| chain_latency(effects));
+                        generator.saturating_add(effects)
+                    }
+                    RoutingNode::Bus(bus_id) =>
+                        plugins
+                            .get_bus_effects(bus_id.to_u32() as usize)
+                            .map_or(0, |effects| chain_latency(effects)),
+                    RoutingNode::Master => chain_latency(&plugins.master_effects),
+                }
+            }
+        );
+        if self.delay_lines_stale {
+            self.delay_compensation.reserve_lines();
+            self.delay_lines_stale = false;
+        }
+        self.mix_latency.store(self.delay_compensation.total_latency(), Ordering::Relaxed);
+    }
+
+    /// Moves what each audible track renders with into its lane: the generator playing on it,
+    /// its effects and one-shot voices. MIDI sent out for the tracks goes here too, the port
+    /// stays on the audio thread.
+    fn gather_track_lanes(&mut self, is_any_solo: bool, input_start: usize, is_recording: bool) {
+        let tracks = &self.current_state.graph.tracks;
+        if self.track_lanes.len() != tracks.len() {
+            let block_len = self.max_block_frames() * (self.num_channels.max(2) as usize);
+            self.track_lanes.resize_with(tracks.len(), || TrackLane::new(block_len));
+        }
+
+        for (lane, track) in self.track_lanes.iter_mut().zip(tracks.iter()) {
+            let track_id = track.id;
+            lane.track_id = track_id;
+            lane.has_signal = false;
+            lane.generator_voice = None;
+
+            // Check mute/solo
+            let (mute, solo) = self.current_state.graph.mixer_state.channels
+                .get(&track_id)
+                .map_or((false, false), |channel| (channel.mute, channel.solo));
+            lane.audible = !mute && (!is_any_solo || solo);
+            if !lane.audible {
+                continue;
+            }
+            lane.monitoring = track.is_monitoring(self.is_playing, is_recording);
+
+            // Generator Voice - use plugin_state directly (no lock!)
+            if
+                let Some(voice_index) = self.active_generators
+                    .iter()
+                    .position(|g| g.track_id == track_id && g.active)
+            {
+                let gen_voice = &self.active_generators[voice_index];
+                lane.generator = self.plugin_state.generators
+                    .get_mut(gen_voice.id.to_u32() as usize)
+                    .and_then(Option::take);
+                if lane.generator.is_some() {
+                    lane.generator_voice = Some(voice_index);
+                    self.midi_output.send_track_events(track_id, input_start, &gen_voice.midi_events);
+                }
+            }
+
+            if let Some(effects) = self.plugin_state.get_track_effects_mut(track_id.to_u32() as usize) {
+                std::mem::swap(&mut lane.effects, effects);
+            }
+            lane.oneshots.extend(
+                self.active_oneshots.extract_if(.., |voice| voice.track_id == track_id)
+            );
+        }
+    }
+
+    /// Gives the lanes' plugins and voices back once the tracks are rendered.
+    fn return_track_lanes(&mut self) {
+        for lane in self.track_lanes.iter_mut() {
+            if let Some(generator) = lane.generator.take() {
+                let index = generator.id.to_u32() as usize;
+                if let Some(slot) = self.plugin_state.generators.get_mut(index) {
+                    *slot = Some(generator);
+                }
+            }
+            if let Some(effects) = self.plugin_state.get_track_effects_mut(lane.track_id.to_u32() as usize) {
+                std::mem::swap(&mut lane.effects, effects);
+            }
+            self.active_oneshots.append(&mut lane.oneshots);
+        }
+    }
+
+    /// Renders one track into its lane's buffer through its channel strip. Runs on any render
+    /// thread, so it only touches the lane and what `block` shares read-only.
+    fn render_track_lane(lane: &mut TrackLane, block: &LaneBlock) {
+        if !lane.audible {
+            return;
+        }
+        let channels = block.channels_count;
+        let track_id = lane.track_id;
+
+        // Ensure the lane buffer is sized correctly
+        if lane.buffer.len() != block.buf_len {
+            lane.buffer.resize(block.buf_len, 0.0);
+        }
+        lane.buffer.fill(0.0);
+
+        let mut has_signal = false;
+
+        if
+            let (Some(gen_instance), Some(gen_voice)) = (
+                lane.generator.as_mut(),
+                lane.generator_voice.and_then(|index| block.generators.get(index)),
+            )
+        {
+            // CONSUME AUTOMATION: parameters change on the frame their automation reaches, so the
+            // block is rendered in pieces between changes, each with its own MIDI events
+            let frames = block.buf_len / channels;
+            let automation = &gen_voice.automation_events;
+            for range in change_segments(automation, frames, |_| true) {
+                for GeneratorAutomationEvent::PluginParam { param_id, value } in changes_at(
+                    automation,
+                    &range
+                ) {
+                    gen_instance.plugin.set_parameter(*param_id, *value);
+                }
+
+                // PROCESS AUDIO
+                let buffer = &mut lane.buffer[range.start * channels..range.end * channels];
+                if range.len() == frames {
+                    gen_instance.load.time(|| gen_instance.plugin.process(buffer, &gen_voice.midi_events));
+                    continue;
+                }
+                let is_last = range.end == frames;
+                lane.midi_events.clear();
+                lane.midi_events.extend(
+                    gen_voice.midi_events
+                        .iter()
+                        .filter(|e| range.contains(&e.sample_offset) || (is_last && e.sample_offset >= frames))
+                        .map(|e| MidiEvent { sample_offset: e.sample_offset - range.start, data: e.data })
+                );
+                let midi_events = &lane.midi_events;
+                gen_instance.load.time(|| gen_instance.plugin.process(buffer, midi_events));
+            }
+            has_signal = true;
+        }
+
+        // Audio Voice
+        if
+            Self::render_oneshots(
+                &mut lane.oneshots,
+                block.sample_rate,
+                track_id,
+                &mut lane.buffer,
+                channels
+            )
+        {
+            has_signal = true;
+        }
+
+        // Input monitoring goes through the same channel strip and routing as playback
+        if lane.monitoring {
+            if let Some(input) = block.input {
+                apply_simd_mix(&mut lane.buffer, input);
+                has_signal = true;
+            }
+        }
+
+        if !has_signal {
+            return;
+        }
+
+        let channel = block.channels.get(&track_id).map_or(block.default_channel, Arc::as_ref);
+
+        // Apply track mixer channel (volume/pan/phase) and effects
+        Self::apply_mixer_channel_with_effects(
+            channel,
+            &mut lane.effects,
+            block.automation,
+            track_id,
+            &mut lane.buffer,
+            channels
+        );
+        lane.has_signal = true;
     }
 
     fn render_oneshots(
@@ -1568,41 +2347,18 @@ impl AudioEngine {
 
     /// Apply mixer channel settings (volume, pan, phase) and effects from plugin_state
     fn apply_mixer_channel_with_effects(
-        mixer_channel: &mut MixerChannel,
-        track_effects: &mut Vec<Vec<AudioEffectInstance>>,
-        track_automation_events: &[(TrackId, Vec<TrackAutomationEvent>)],
+        mixer_channel: &MixerChannel,
+        track_effects: &mut [AudioEffectInstance],
+        track_automation_events: &[(TrackId, StripAutomation)],
         track_id: TrackId,
         buffer: &mut [f32],
         channels: usize
     ) {
-        // Extract base values from the current UI state
-        let track_volume = &mut mixer_channel.volume;
-        let track_pan = &mut mixer_channel.pan;
-
-        // CONSUME TRACK AUTOMATION
-        if
-            let Some((_, auto_events)) = track_automation_events
-                .iter()
-                .find(|(id, _)| *id == track_id)
-        {
-            for event in auto_events {
-                match event {
-                    TrackAutomationEvent::Volume(v) => {
-                        track_volume.apply_automation(*v);
-                    }
-                    TrackAutomationEvent::Pan(v) => {
-                        track_pan.apply_automation(*v);
-                    }
-                    TrackAutomationEvent::PluginParam { effect_id, param_id, value } => {
-                        if let Some(effects) = track_effects.get_mut(track_id.to_u32() as usize) {
-                            if let Some(effect) = effects.iter_mut().find(|e| e.id == *effect_id) {
-                                effect.plugin.apply_automation(*param_id, *value);
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        // Automation overrides the current UI state for this block only
+        let auto_events = track_automation_events
+            .iter()
+            .find(|(id, _)| *id == track_id)
+            .map_or(&[][..], |(_, events)| events.as_slice());
 
         // ==== SIMD Phase Inversion ====
         if mixer_channel.inverted_phase {
@@ -1620,54 +2376,11 @@ impl AudioEngine {
             }
         }
 
-        // Effects chain from plugin_state
-        if let Some(effects) = track_effects.get_mut(track_id.to_u32() as usize) {
-            for effect in effects.iter_mut() {
-                effect.plugin.process(buffer);
-            }
-        }
-
-        // Apply calculated Volume and Pan
-        let pan = track_pan.get();
-        let volume = db_to_linear(track_volume.get());
-
-        let (left_gain, right_gain) = if channels == 2 {
-            let p = (pan + 1.0) * 0.5;
-            ((1.0 - p).sqrt() * volume, p.sqrt() * volume)
-        } else {
-            (volume, volume)
-        };
-
-        // Apply gain
-        if channels == 2 {
-            let gain_v = f32x4::new([left_gain, right_gain, left_gain, right_gain]);
-            let mut iter = buffer.chunks_exact_mut(4);
-
-            for chunk in iter.by_ref() {
-                let mut v = f32x4::new([chunk[0], chunk[1], chunk[2], chunk[3]]);
-                v *= gain_v;
-                chunk.copy_from_slice(&v.to_array());
-            }
+        // Effects chain from plugin_state, processed in pieces where automated parameters change
+        process_effects(track_effects, auto_events, buffer, channels);
 
-            // Handle remainder (0 or 2 floats for stereo)
-            for chunk in iter.into_remainder().chunks_exact_mut(2) {
-                chunk[0] *= left_gain;
-                chunk[1] *= right_gain;
-            }
-        } else {
-            let gain_v = f32x4::splat(left_gain);
-            let mut iter = buffer.chunks_exact_mut(4);
-
-            for chunk in iter.by_ref() {
-                let mut v = f32x4::new([chunk[0], chunk[1], chunk[2], chunk[3]]);
-                v *= gain_v;
-                chunk.copy_from_slice(&v.to_array());
-            }
-
-            for sample in iter.into_remainder() {
-                *sample *= left_gain;
-            }
-        }
+        // Volume and pan, ramped through their automation points
+        apply_channel_gain(mixer_channel, auto_events, buffer, channels);
     }
 
     /// Apply master bus settings (volume, pan, phase) and effects from plugin_state
@@ -1676,35 +2389,19 @@ impl AudioEngine {
     ///
     /// * `master_bus` - The master bus settings
     /// * `master_effects` - The master bus effects chain
+    /// * `meter` - The master meter, measuring the result
+    /// * `loudness` - The loudness analyzer measuring the result, if any
     /// * `buffer` - The buffer to apply the master bus settings to
     /// * `channels` - The number of channels in the buffer
     fn apply_master_bus_with_effects(
-        master_bus: &mut MixerChannel,
+        master_bus: &MixerChannel,
         master_effects: &mut [AudioEffectInstance],
-        master_automation_events: &[MasterAutomationEvent],
+        master_automation_events: &[AutomationEvent<ChannelAutomationEvent>],
+        meter: &mut ChannelMeter,
+        loudness: Option<&mut LoudnessMeter>,
         buffer: &mut [f32],
         channels: usize
     ) {
-        let master_volume = &mut master_bus.volume;
-        let master_pan = &mut master_bus.pan;
-
-        // CONSUME MASTER AUTOMATION
-        for event in master_automation_events {
-            match event {
-                MasterAutomationEvent::Volume(v) => {
-                    master_volume.apply_automation(*v);
-                }
-                MasterAutomationEvent::Pan(v) => {
-                    master_pan.apply_automation(*v);
-                }
-                MasterAutomationEvent::PluginParam { effect_id, param_id, value } => {
-                    if let Some(effect) = master_effects.iter_mut().find(|e| e.id == *effect_id) {
-                        effect.plugin.apply_automation(*param_id, *value);
-                    }
-                }
-            }
-        }
-
         // ==== SIMD Phase Inversion ====
         if master_bus.inverted_phase {
             let neg_one = f32x4::splat(-1.0);
@@ -1721,50 +2418,15 @@ impl AudioEngine {
             }
         }
 
-        // Master effects chain
-        for effect in master_effects.iter_mut() {
-            effect.plugin.process(buffer);
-        }
-
-        // Volume and Pan (volume is stored in dB)
-        let pan = master_pan.get();
-        let volume = db_to_linear(master_volume.get());
-        let (left_gain, right_gain) = if channels == 2 {
-            let p = (pan + 1.0) * 0.5;
-            ((1.0 - p).sqrt() * volume, p.sqrt() * volume)
-        } else {
-            (volume, volume)
-        };
-
-        // ==== SIMD Apply Gain and Pan ====
-        if channels == 2 {
-            let gain_v = f32x4::new([left_gain, right_gain, left_gain, right_gain]);
-            let mut iter = buffer.chunks_exact_mut(4);
-
-            for chunk in iter.by_ref() {
-                let mut v = f32x4::new([chunk[0], chunk[1], chunk[2], chunk[3]]);
-                v *= gain_v;
-                chunk.copy_from_slice(&v.to_array());
-            }
-
-            // Handle remainder (0 or 2 floats for stereo)
-            for chunk in iter.into_remainder().chunks_exact_mut(2) {
-                chunk[0] *= left_gain;
-                chunk[1] *= right_gain;
-            }
-        } else {
-            let gain_v = f32x4::splat(left_gain);
-            let mut iter = buffer.chunks_exact_mut(4);
+        // Master effects chain, processed in pieces where automated parameters change
+        process_effects(master_effects, master_automation_events, buffer, channels);
 
-            for chunk in iter.by_ref() {
-                let mut v = f32x4::new([chunk[0], chunk[1], chunk[2], chunk[3]]);
-                v *= gain_v;
-                chunk.copy_from_slice(&v.to_array());
-            }
+        // Volume and pan (volume is stored in dB), ramped through their automation points
+        apply_channel_gain(master_bus, master_automation_events, buffer, channels);
 
-            for sample in iter.into_remainder() {
-                *sample *= left_gain;
-            }
+        meter.measure(buffer, channels);
+        if let Some(loudness) = loudness {
+            loudness.process(buffer, channels);
         }
     }
 
@@ -1772,8 +2434,9 @@ impl AudioEngine {
         let start_time = self.playhead_samples;
         let end_time = start_time + (buffer_size as u32);
 
-        // Use the tracks from the current audio graph state
-        let tracks = self.current_state.graph.tracks.clone();
+        // Use the tracks from the current audio graph state. The slice is shared, not copied:
+        // processing a track needs `self` mutably
+        let tracks = Arc::clone(&self.current_state.graph.tracks);
 
         for track in tracks.iter() {
             self.process_track(track.as_ref(), start_time, end_time);
@@ -1794,15 +2457,26 @@ impl AudioEngine {
             );
         }
 
-        let mut expected_at_start = Vec::new();
-        let mut expected_at_end = Vec::new();
+        let mut held_keys = std::mem::take(&mut self.held_keys);
+        held_keys.clear();
 
         // Process Clips
         for clip in track.clips() {
-            if clip.start_time > end_time {
+            // Clips are positioned in ticks, the tempo map places them on the sample timeline
+            let tempo_map = &self.current_state.graph.tempo_map;
+            let clip_start = tempo_map.tick_to_sample(clip.start_time, self.sample_rate) as u32;
+            // Notes pulled early by their groove or micro-offset sound a little before the clip
+            let lead_in = tempo_map.tick_to_sample(
+                clip.start_time.saturating_sub(NOTE_SHIFT_REACH),
+                self.sample_rate
+            ) as u32;
+            if lead_in > end_time {
                 break;
-            } // Optimization: Clips are sorted? If not, remove break.
-            let clip_end = clip.start_time + clip.loop_length;
+            } // Clips are sorted by start tick, and the tempo map is monotonic
+            let clip_end = tempo_map.tick_to_sample(
+                clip.start_time + clip.loop_length,
+                self.sample_rate
+            ) as u32;
             if clip_end < start_time {
                 continue;
             }
@@ -1814,7 +2488,14 @@ impl AudioEngine {
                         .get(source_id)
                         .cloned();
                     if let Some(waveform) = waveform_opt {
-                        self.prepare_audio_voice(track.id, clip, &waveform, start_time, end_time);
+                        self.prepare_audio_voice(
+                            track.id,
+                            clip,
+                            &waveform,
+                            (clip_start, clip_end),
+                            start_time,
+                            end_time
+                        );
                     }
                 }
                 KarbeatSource::Midi(id) => {
@@ -1826,12 +2507,12 @@ impl AudioEngine {
                             let gen_voice = &mut self.active_generators[idx];
                             Self::schedule_midi_events(
                                 &mut gen_voice.midi_events,
-                                &mut expected_at_start,
-                                &mut expected_at_end,
+                                &mut held_keys,
+                                &self.current_state.graph.tempo_map,
                                 self.sample_rate,
-                                self.bpm,
                                 clip,
                                 pattern,
+                                mix_seed(self.current_state.graph.random_seed, self.loop_pass),
                                 start_time,
                                 end_time
                             );
@@ -1844,26 +2525,9 @@ impl AudioEngine {
 
         // Detect interrupted notes and inject NoteOff
         if let Some(idx) = gen_voice_idx {
-            let voice = &mut self.active_generators[idx];
-
-            let mut missed_keys = Vec::new();
-            for &key in &voice.playing_keys {
-                if !expected_at_start.contains(&key) && !missed_keys.contains(&key) {
-                    missed_keys.push(key);
-                    voice.midi_events.push(MidiEvent {
-                        sample_offset: 0,
-                        data: MidiMessage::NoteOff { key },
-                    });
-                }
-            }
-
-            expected_at_end.sort_unstable();
-            expected_at_end.dedup();
-            voice.playing_keys = expected_at_end;
-
-            // Sort all events (including injected NoteOffs)
-            voice.midi_events.sort_by_key(|e| e.sample_offset);
+            held_keys.release_interrupted(&mut self.active_generators[idx]);
         }
+        self.held_keys = held_keys;
     }
 
     /// Ensure that the generator voice is active
@@ -1966,18 +2630,20 @@ impl AudioEngine {
         self.preview_voices.retain(|v| !v.is_finished);
     }
 
-    /// Prepare audio voice from Audio Waveform that will be rendered
+    /// Prepare audio voice from Audio Waveform that will be rendered.
+    /// `clip_bounds` is the clip's (start, end) on the sample timeline.
     fn prepare_audio_voice(
         &mut self,
         track_id: TrackId,
         clip: &Clip,
-        waveform: &AudioWaveform,
+        waveform: &Arc<AudioWaveform>,
+        clip_bounds: (u32, u32),
         buffer_start: u32,
         buffer_end: u32
     ) {
-        let clip_timeline_start = clip.start_time;
+        let (clip_timeline_start, clip_timeline_end) = clip_bounds;
         let render_start = std::cmp::max(buffer_start, clip_timeline_start);
-        let render_end = std::cmp::min(buffer_end, clip_timeline_start + clip.loop_length);
+        let render_end = std::cmp::min(buffer_end, clip_timeline_end);
 
         if render_end <= render_start {
             return;
@@ -1985,10 +2651,17 @@ impl AudioEngine {
 
         let output_offset = (render_start - buffer_start) as usize;
         let samples_elapsed = render_start - clip_timeline_start;
-        let effective_pos = samples_elapsed + clip.offset_start;
+
+        // The source is anchored at `start_time - offset_start` (in ticks), so the read
+        // position is the distance from that anchor on the sample timeline.
+        let source_anchor = self.current_state.graph.tempo_map.tick_to_sample_f64(
+            (clip.start_time as f64) - (clip.offset_start as f64),
+            self.sample_rate
+        );
+        let effective_pos = ((render_start as f64) - source_anchor).max(0.0);
 
         let ratio = (waveform.sample_rate as f64) / (self.sample_rate as f64);
-        let source_elapsed_frames = (effective_pos as f64) * ratio;
+        let source_elapsed_frames = effective_pos * ratio;
 
         let trim_start = waveform.trim_start as f64;
         let trim_end = if waveform.trim_end > 0 {
@@ -2015,85 +2688,110 @@ impl AudioEngine {
 
         self.active_oneshots.push(AudioVoice {
             track_id,
-            waveform: waveform.clone(),
+            waveform: Arc::clone(waveform),
             output_offset_samples: output_offset,
             source_read_index: source_read_idx,
             start_boundary: trim_start,
             end_boundary: trim_end,
             clip_elapsed_samples: samples_elapsed,
-            clip_loop_length: clip.loop_length,
+            clip_loop_length: clip_timeline_end - clip_timeline_start,
         });
     }
 
+    /// Schedules the notes of a pattern clip that fall in the block. Muted notes and notes
+    /// losing their probability roll are skipped, rolls come from `seed` so the same pass
+    /// always plays the same notes.
     fn schedule_midi_events(
         events: &mut SmallVec<[MidiEvent; 4]>,
-        expected_at_start: &mut Vec<u8>,
-        expected_at_end: &mut Vec<u8>,
+        held_keys: &mut HeldKeys,
+        tempo_map: &TempoMap,
         sample_rate: u32,
-        tempo: f32,
         clip: &Clip,
         pattern: &Pattern,
+        seed: u64,
         buffer_start: u32,
         buffer_end: u32
     ) {
-        let samples_per_beat = ((60.0 / tempo) * (sample_rate as f32)) as u32;
-        if samples_per_beat == 0 {
+        // Everything below is laid out in ticks and only converted to samples at the end,
+        // so notes stay locked to the grid whatever the tempo map does.
+        let pattern_len = pattern.length_ticks;
+        if pattern_len == 0 {
             return;
         }
 
-        let pattern_len_samples = (((pattern.length_ticks as f64) / 960.0) *
-            (samples_per_beat as f64)) as u32;
-        if pattern_len_samples == 0 {
-            return;
-        }
+        let clip_start = clip.start_time as u64;
+        let clip_offset = clip.offset_start as u64;
+        let clip_end_tick = clip_start + (clip.loop_length as u64);
+        let clip_end = tempo_map.tick_to_sample(clip_end_tick, sample_rate) as u32;
 
-        // Calculate the clip's actual end boundary on the timeline
-        let clip_end = clip.start_time + clip.loop_length;
+        // Skip repetitions that can't touch this block
+        let block_start_tick = tempo_map.sample_to_tick(buffer_start as u64, sample_rate).ticks();
+        let block_end_tick = tempo_map.sample_to_tick(buffer_end as u64, sample_rate).ticks() + 1;
+        let longest_note_end = pattern.notes
+            .iter()
+            .map(|n| n.start_tick + n.duration)
+            .max()
+            .unwrap_or(0) + (NOTE_SHIFT_REACH as u64);
+        let clip_seed = mix_seed(seed, clip.id.to_u32() as u64);
+        // A clip's own groove replaces its pattern's
+        let groove = clip.groove.as_ref().or(pattern.groove.as_ref());
 
         // Ensure pattern loops if the clip is dragged out longer than the pattern length
-        let max_iters = clip.loop_length / pattern_len_samples + 1;
+        let content_end = clip_offset + (clip.loop_length as u64);
+        let mut pattern_offset = 0;
 
-        for i in 0..=max_iters {
-            let pattern_offset = i * pattern_len_samples;
+        while pattern_offset < content_end {
+            // Absolute tick where this repetition would start (may be before the clip start)
+            let repetition_start = (clip_start + pattern_offset) as i64 - (clip_offset as i64);
+            let repetition_seed = mix_seed(clip_seed, pattern_offset / pattern_len);
+            pattern_offset += pattern_len;
 
-            // Stop processing if this repetition is beyond the clip's end
-            if clip.start_time + pattern_offset >= clip_end {
+            if repetition_start + (longest_note_end as i64) + 1 < (block_start_tick as i64) {
+                continue;
+            }
+            if repetition_start - (NOTE_SHIFT_REACH as i64) > (block_end_tick as i64) {
                 break;
             }
 
             for note in &pattern.notes {
-                let note_start = (((note.start_tick as f64) / 960.0) *
-                    (samples_per_beat as f64)) as u32;
-                let note_dur = (((note.duration as f64) / 960.0) *
-                    (samples_per_beat as f64)) as u32;
-
-                // Note position within the pattern (in samples from pattern start)
-                let note_pos_in_pattern = pattern_offset + note_start;
-
-                // Skip notes that start before the clip's trim offset
-                if note_pos_in_pattern < clip.offset_start {
+                // Skip notes whose grid position is before the clip's trim offset
+                let grid_tick = repetition_start + (note.start_tick as i64);
+                if grid_tick < (clip_start as i64) {
                     continue;
                 }
 
-                // Calculate absolute timeline position: clip start + (note position - trim offset)
-                let abs_start = clip.start_time + note_pos_in_pattern - clip.offset_start;
-                let abs_end = abs_start + note_dur;
+                // Skip notes on or after the clip end (outside trimmed region)
+                if (grid_tick as u64) >= clip_end_tick {
+                    continue;
+                }
 
-                // Skip notes that start at or after the clip end (outside trimmed region)
-                if abs_start >= clip_end {
+                if !note_plays(note, repetition_seed) {
                     continue;
                 }
 
+                // The groove and micro-offset move the note off the grid, early notes may
+                // start before the clip does
+                let (onset, velocity) = note_onset(note, groove);
+                let abs_start_tick = (repetition_start + onset).max(0) as u64;
+
+                let abs_start = tempo_map.tick_to_sample(abs_start_tick, sample_rate) as u32;
+                let abs_end = tempo_map.tick_to_sample(
+                    abs_start_tick + note.duration,
+                    sample_rate
+                ) as u32;
+
                 // Clamp note-off to clip boundary if it would extend past the clip end
                 // This prevents hanging notes when clips are trimmed
                 let effective_end = abs_end.min(clip_end);
 
-                // Track the expected note for hang prevention during moving of active voice
-                if abs_start <= buffer_start && effective_end > buffer_start {
-                    expected_at_start.push(note.key);
+                // Track the expected note for hang prevention during moving of active voice.
+                // A note starting on the block start is new, even when the same key was
+                // playing before a jump
+                if abs_start < buffer_start && effective_end > buffer_start {
+                    held_keys.at_start.push(note.key);
                 }
-                if abs_start <= buffer_end && effective_end > buffer_end {
-                    expected_at_end.push(note.key);
+                if abs_start < buffer_end && effective_end > buffer_end {
+                    held_keys.at_end.push(note.key);
                 }
 
                 // Schedule NoteOn if it falls within the buffer
@@ -2102,7 +2800,7 @@ impl AudioEngine {
                         sample_offset: (abs_start - buffer_start) as usize,
                         data: MidiMessage::NoteOn {
                             key: note.key,
-                            velocity: note.velocity,
+                            velocity,
                         },
                     });
                 }
@@ -2122,25 +2820,30 @@ impl AudioEngine {
     // Helper to schedule notes without a Clip wrapper
     fn schedule_pattern_notes_raw(
         events: &mut SmallVec<[MidiEvent; 4]>,
-        expected_at_start: &mut Vec<u8>,
-        expected_at_end: &mut Vec<u8>,
-        notes: &[crate::core::project::Note],
+        held_keys: &mut HeldKeys,
+        pattern: &Pattern,
         sample_rate: u32,
         tempo: f32,
+        seed: u64,
         buffer_start: u32,
         buffer_end: u32
     ) {
         let samples_per_tick = ((60.0 / tempo) * (sample_rate as f32)) / 960.0;
 
-        for note in notes {
-            let note_start = ((note.start_tick as f32) * samples_per_tick) as u32;
+        for note in &pattern.notes {
+            if !note_plays(note, seed) {
+                continue;
+            }
+            // Early notes on the first tick can't start before the pattern does
+            let (start_tick, velocity) = note_onset(note, pattern.groove.as_ref());
+            let note_start = ((start_tick.max(0) as f32) * samples_per_tick) as u32;
             let note_end = note_start + (((note.duration as f32) * samples_per_tick) as u32);
 
-            if note_start <= buffer_start && note_end > buffer_start {
-                expected_at_start.push(note.key);
+            if note_start < buffer_start && note_end > buffer_start {
+                held_keys.at_start.push(note.key);
             }
-            if note_start <= buffer_end && note_end > buffer_end {
-                expected_at_end.push(note.key);
+            if note_start < buffer_end && note_end > buffer_end {
+                held_keys.at_end.push(note.key);
             }
 
             if note_start >= buffer_start && note_start < buffer_end {
@@ -2148,7 +2851,7 @@ impl AudioEngine {
                     sample_offset: (note_start - buffer_start) as usize,
                     data: MidiMessage::NoteOn {
                         key: note.key,
-                        velocity: note.velocity,
+                        velocity,
                     },
                 });
             }
@@ -2162,20 +2865,13 @@ impl AudioEngine {
         // events.sort_by_key(|e| e.sample_offset);
     }
 
-    fn evaluate_automation_lanes(&mut self) {
-        let tempo = self.bpm;
-        if tempo <= 0.0 {
-            return;
-        }
-
-        // Convert playhead from samples to ticks (960 ticks per beat)
-        let samples_per_beat = (60.0 / tempo) * (self.sample_rate as f32);
-        let samples_per_tick = samples_per_beat / 960.0;
-        let current_tick = ((self.playhead_samples as f64) / (samples_per_tick as f64)) as u32;
+    fn evaluate_automation_lanes(&mut self, frames: usize) {
+        // Automation points are anchored in ticks, lanes are sampled through the tempo map
+        // across the block so every event lands on the frame its value is reached at
+        let tempo_map = &self.current_state.graph.tempo_map;
+        let (playhead, sample_rate) = (self.playhead_samples, self.sample_rate);
 
         for lane in self.current_state.graph.automation_lanes.values() {
-            let value = lane.value_at_ticks(current_tick);
-
             match &lane.target {
                 AutomationTarget::TrackGeneratorPluginParam { track_id, param_id } => {
                     if
@@ -2183,81 +2879,84 @@ impl AudioEngine {
                             .iter_mut()
                             .find(|v| v.track_id == *track_id)
                     {
-                        voice.automation_events.push(GeneratorAutomationEvent::PluginParam {
-                            param_id: *param_id,
-                            value,
+                        sample_lane(lane, tempo_map, playhead, frames, sample_rate, |offset, value| {
+                            voice.automation_events.push(AutomationEvent {
+                                sample_offset: offset,
+                                data: GeneratorAutomationEvent::PluginParam {
+                                    param_id: *param_id,
+                                    value,
+                                },
+                            });
                         });
                     }
                 }
                 AutomationTarget::TrackVolume(track_id) => {
-                    let pos = self.track_automation_events
-                        .iter()
-                        .position(|(id, _)| id == track_id)
-                        .unwrap_or_else(|| {
-                            let idx = self.track_automation_events.len();
-                            self.track_automation_events.push((*track_id, Vec::new()));
-                            idx
+                    let events = strip_events(&mut self.track_automation_events, *track_id);
+                    sample_lane(lane, tempo_map, playhead, frames, sample_rate, |offset, value| {
+                        events.push(AutomationEvent {
+                            sample_offset: offset,
+                            data: ChannelAutomationEvent::Volume(value),
                         });
-                    self.track_automation_events[pos].1.push(TrackAutomationEvent::Volume(value));
+                    });
                 }
                 AutomationTarget::TrackPan(track_id) => {
-                    let pos = self.track_automation_events
-                        .iter()
-                        .position(|(id, _)| id == track_id)
-                        .unwrap_or_else(|| {
-                            let idx = self.track_automation_events.len();
-                            self.track_automation_events.push((*track_id, Vec::new()));
-                            idx
+                    let events = strip_events(&mut self.track_automation_events, *track_id);
+                    sample_lane(lane, tempo_map, playhead, frames, sample_rate, |offset, value| {
+                        events.push(AutomationEvent {
+                            sample_offset: offset,
+                            data: ChannelAutomationEvent::Pan(value),
                         });
-                    self.track_automation_events[pos].1.push(TrackAutomationEvent::Pan(value));
+                    });
                 }
                 AutomationTarget::TrackPluginParam { track_id, effect_id, param_id } => {
-                    let pos = self.track_automation_events
-                        .iter()
-                        .position(|(id, _)| id == track_id)
-                        .unwrap_or_else(|| {
-                            let idx = self.track_automation_events.len();
-                            self.track_automation_events.push((*track_id, Vec::new()));
-                            idx
+                    let events = strip_events(&mut self.track_automation_events, *track_id);
+                    sample_lane(lane, tempo_map, playhead, frames, sample_rate, |offset, value| {
+                        events.push(AutomationEvent {
+                            sample_offset: offset,
+                            data: ChannelAutomationEvent::PluginParam {
+                                effect_id: *effect_id,
+                                param_id: *param_id,
+                                value,
+                            },
                         });
-                    self.track_automation_events[pos].1.push(TrackAutomationEvent::PluginParam {
-                        effect_id: *effect_id,
-                        param_id: *param_id,
-                        value,
                     });
                 }
                 AutomationTarget::BusVolume(bus_id) => {
-                    let pos = self.bus_automation_events
-                        .iter()
-                        .position(|(id, _)| id == bus_id)
-                        .unwrap_or_else(|| {
-                            let idx = self.bus_automation_events.len();
-                            self.bus_automation_events.push((*bus_id, Vec::new()));
-                            idx
+                    let events = strip_events(&mut self.bus_automation_events, *bus_id);
+                    sample_lane(lane, tempo_map, playhead, frames, sample_rate, |offset, value| {
+                        events.push(AutomationEvent {
+                            sample_offset: offset,
+                            data: ChannelAutomationEvent::Volume(value),
                         });
-                    self.bus_automation_events[pos].1.push(BusAutomationEvent::Volume(value));
+                    });
                 }
                 AutomationTarget::BusPluginParam { bus_id, effect_id, param_id } => {
-                    let pos = self.bus_automation_events
-                        .iter()
-                        .position(|(id, _)| id == bus_id)
-                        .unwrap_or_else(|| {
-                            let idx = self.bus_automation_events.len();
-                            self.bus_automation_events.push((*bus_id, Vec::new()));
-                            idx
+                    let events = strip_events(&mut self.bus_automation_events, *bus_id);
+                    sample_lane(lane, tempo_map, playhead, frames, sample_rate, |offset, value| {
+                        events.push(AutomationEvent {
+                            sample_offset: offset,
+                            data: ChannelAutomationEvent::PluginParam {
+                                effect_id: *effect_id,
+                                param_id: *param_id,
+                                value,
+                            },
                         });
-                    self.bus_automation_events[pos].1.push(BusAutomationEvent::PluginParam {
-                        effect_id: *effect_id,
-                        param_id: *param_id,
-                        value,
                     });
                 }
                 AutomationTarget::MasterVolume => {
-                    self.master_automation_events.push(MasterAutomationEvent::Volume(value));
+                    let events = &mut self.master_automation_events;
+                    sample_lane(lane, tempo_map, playhead, frames, sample_rate, |offset, value| {
+                        events.push(AutomationEvent {
+                            sample_offset: offset,
+                            data: ChannelAutomationEvent::Volume(value),
+                        });
+                    });
                 }
                 AutomationTarget::TempoBpm => {
-                    // Global target handled immediately since transport is owned by AudioEngine
-                    self.bpm = value;
+                    // Tempo automation is folded into the graph's tempo map, which already
+                    // drives event placement. Mirror it here for transport feedback.
+                    let tick = tempo_map.sample_to_tick(playhead as u64, sample_rate);
+                    self.bpm = lane.value_at_ticks(tick.ticks() as u32);
                 }
                 _ => {}
             }
@@ -2265,6 +2964,59 @@ impl AudioEngine {
     }
 }
 
+/// A new seed drawn from `seed` and `value`, for the rolls of one loop pass, clip or repetition.
+fn mix_seed(seed: u64, value: u64) -> u64 {
+    fastrand::Rng::with_seed(seed ^ value.wrapping_mul(0x9e37_79b9_7f4a_7c15)).u64(..)
+}
+
+/// Whether a note sounds: muted notes never do, the others win a roll against their
+/// probability. Each note rolls on its own, so editing one leaves the others' rolls alone.
+fn note_plays(note: &Note, seed: u64) -> bool {
+    if note.mute {
+        return false;
+    }
+    if note.probability >= 1.0 {
+        return true;
+    }
+    let mut rng = fastrand::Rng::with_seed(mix_seed(seed, note.id.to_u32() as u64));
+    rng.f32() < note.probability
+}
+
+/// Start of a note in ticks from the start of its pattern, and its velocity, once the groove
+/// and its micro-offset are applied. Early notes may start before the pattern.
+fn note_onset(note: &Note, groove: Option<&Groove>) -> (i64, u8) {
+    let (start_tick, velocity) = match groove {
+        Some(groove) => groove.apply(note.start_tick, note.velocity),
+        None => (note.start_tick, note.velocity),
+    };
+    ((start_tick as i64) + (note.micro_offset as i64), velocity)
+}
+
+/// Strips of the graph that get a level meter: every track and bus, the master is always metered.
+fn meter_targets(state: &AudioRenderState) -> impl Iterator<Item = MeterTarget> + '_ {
+    let graph = &state.graph;
+    graph.tracks
+        .iter()
+        .map(|track| MeterTarget::Track(track.id))
+        .chain(graph.mixer_state.buses.keys().map(|bus_id| MeterTarget::Bus(*bus_id)))
+}
+
+/// Automation events of a track's or bus's strip for this block, added on first use. Entries
+/// are cleared rather than removed between blocks, so they keep their capacity.
+fn strip_events<K: PartialEq>(
+    strips: &mut SmallVec<[(K, StripAutomation); 4]>,
+    id: K
+) -> &mut StripAutomation {
+    let pos = strips
+        .iter()
+        .position(|(strip_id, _)| *strip_id == id)
+        .unwrap_or_else(|| {
+            strips.push((id, Vec::new()));
+            strips.len() - 1
+        });
+    &mut strips[pos].1
+}
+
 /// Sample a waveform at a specific position using dasp interpolation.
 /// Handles fallback from 1-channel to 2-channel stereo.
 #[inline]
@@ -2342,3 +3094,725 @@ fn get_read_pos(
         rp
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{ alloc::{ GlobalAlloc, Layout, System }, cell::Cell };
+
+    use karbeat_plugin_api::traits::KarbeatGenerator;
+    use karbeat_plugin_types::ParameterSpec;
+    use karbeat_plugins::registry::PluginRegistry;
+    use memmap2::MmapMut;
+    use rtrb::RingBuffer;
+    use triple_buffer::TripleBuffer;
+
+    use super::*;
+    use crate::{
+        audio::{
+            load::set_plugin_profiling,
+            recorder::AudioInputPort,
+            render_state::AudioAutomationLane,
+        },
+        core::project::{
+            AssetLibrary,
+            GeneratorInstanceType,
+            Note,
+            NoteId,
+            PluginInstance,
+            automation::{ AutomationPoint, CurveType },
+            mixer::{ MixerBus, RoutingConnection },
+            track::{ MonitorMode, TrackType },
+        },
+    };
+
+    const TRACKS: u32 = 6;
+    const BLOCK_FRAMES: usize = 256;
+
+    thread_local! {
+        static COUNTING: Cell<bool> = const { Cell::new(false) };
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Counts the allocations, reallocations and frees made by a thread inside
+    /// `count_allocations`. Other threads and tests allocate as usual.
+    struct CountingAllocator;
+
+    impl CountingAllocator {
+        fn note(&self) {
+            if COUNTING.try_with(Cell::get).unwrap_or(false) {
+                let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            }
+        }
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            self.note();
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            self.note();
+            unsafe { System.alloc_zeroed(layout) }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            self.note();
+            unsafe { System.realloc(ptr, layout, new_size) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            self.note();
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Heap operations `run` makes on the calling thread.
+    fn count_allocations(run: impl FnOnce()) -> usize {
+        ALLOCATIONS.with(|count| count.set(0));
+        COUNTING.with(|counting| counting.set(true));
+        run();
+        COUNTING.with(|counting| counting.set(false));
+        ALLOCATIONS.with(Cell::get)
+    }
+
+    /// An engine on `state` with `threads` render workers, the queue feeding it commands and
+    /// the buffer publishing later states to it.
+    fn start_engine(
+        state: AudioRenderState,
+        threads: usize
+    ) -> anyhow::Result<(AudioEngine, Producer<AudioCommand>, Input<AudioRenderState>)> {
+        // The first block picks the state up from the triple buffer, routing order included
+        let (mut state_in, state_out) = TripleBuffer::new(&state).split();
+        state_in.write(state.clone());
+        let (commands, command_consumer) = RingBuffer::new(64);
+        let (position_producer, _positions) = RingBuffer::new(1024);
+        let (feedback_producer, _feedback) = RingBuffer::new(64);
+        let (meter_producer, _meters) = RingBuffer::new(1024);
+        let (loudness_input, _loudness) = TripleBuffer::new(&LoudnessReport::default()).split();
+        let (load_input, _load) = TripleBuffer::default().split();
+        let mut engine = AudioEngine::new(
+            state_out,
+            command_consumer,
+            EngineHandles {
+                position_producer,
+                feedback_producer,
+                meter_producer,
+                loudness_report: loudness_input,
+                load_report: load_input,
+            },
+            48_000,
+            2,
+            120.0,
+            state
+        );
+        engine.set_render_threads(threads)?;
+        Ok((engine, commands, state_in))
+    }
+
+    fn send_all(
+        commands: &mut Producer<AudioCommand>,
+        queue: Vec<AudioCommand>
+    ) -> anyhow::Result<()> {
+        for command in queue {
+            commands.push(command).map_err(|_| anyhow::anyhow!("Command queue full"))?;
+        }
+        Ok(())
+    }
+
+    /// Six synth tracks, two of them with an EQ and two summed on a bus, each holding a note.
+    /// Renders `blocks` blocks with `threads` render workers.
+    fn render_song(threads: usize, blocks: usize) -> anyhow::Result<Vec<f32>> {
+        let registry = PluginRegistry::new_with_defaults();
+        let bus_id = BusId::from(1);
+
+        let mut state = AudioRenderState::default();
+        state.graph.buffer_size = BLOCK_FRAMES;
+        state.graph.tracks = (1..=TRACKS)
+            .map(|id| {
+                Arc::new(KarbeatTrack {
+                    id: TrackId::from(id),
+                    track_type: TrackType::Midi,
+                    ..KarbeatTrack::default()
+                })
+            })
+            .collect();
+        let mixer = &mut state.graph.mixer_state;
+        mixer.buses.insert(bus_id, Arc::new(MixerBus::new(bus_id, "Drums")));
+        for track in [1, 2] {
+            mixer.routing.push(
+                RoutingConnection::new(RoutingNode::Track(TrackId::from(track)), RoutingNode::Bus(bus_id))
+            );
+        }
+        mixer.routing.push(RoutingConnection::new(RoutingNode::Bus(bus_id), RoutingNode::Master));
+        let (mut engine, mut commands, _states) = start_engine(state, threads)?;
+
+        let mut queue = vec![AudioCommand::AddBus { bus_id, name: "Drums".to_string() }];
+        for id in 1..=TRACKS {
+            let plugin = registry
+                .create_generator("Karbeatzer V2")
+                .ok_or_else(|| anyhow::anyhow!("Synth not registered"))?;
+            queue.push(AudioCommand::AddGenerator {
+                generator_id: GeneratorId::from(id),
+                track_id: TrackId::from(id),
+                plugin,
+            });
+            if id % 3 == 0 {
+                let effect = registry
+                    .create_effect("Parametric EQ")
+                    .ok_or_else(|| anyhow::anyhow!("EQ not registered"))?;
+                queue.push(AudioCommand::AddTrackEffect {
+                    track_id: TrackId::from(id),
+                    effect_id: EffectId::from(id),
+                    effect,
+                });
+            }
+            queue.push(AudioCommand::PlayPreviewNote {
+                note_key: 48 + (id as u8) * 5,
+                generator_id: GeneratorId::from(id),
+                velocity: 100,
+                is_note_on: true,
+            });
+        }
+        send_all(&mut commands, queue)?;
+
+        let mut rendered = Vec::with_capacity(blocks * BLOCK_FRAMES * 2);
+        let mut buffer = vec![0.0; BLOCK_FRAMES * 2];
+        for _ in 0..blocks {
+            engine.process(&mut buffer);
+            rendered.extend_from_slice(&buffer);
+        }
+        Ok(rendered)
+    }
+
+    #[test]
+    fn parallel_track_rendering_matches_serial_bit_for_bit() -> anyhow::Result<()> {
+        let serial = render_song(0, 40)?;
+        assert!(serial.iter().any(|&s| s.abs() > 1e-3), "the song should not be silent");
+
+        for threads in [1, 3] {
+            let parallel = render_song(threads, 40)?;
+            assert_eq!(serial.len(), parallel.len());
+            let first_difference = serial
+                .iter()
+                .zip(&parallel)
+                .position(|(a, b)| a.to_bits() != b.to_bits());
+            assert_eq!(first_difference, None, "{} render threads", threads);
+        }
+        Ok(())
+    }
+
+    /// An anonymous, read-only mapping holding a stereo sine, like a decoded sample.
+    fn sine_waveform(id: AudioSourceId, frames: usize) -> anyhow::Result<AudioWaveform> {
+        let mut map = MmapMut::map_anon(frames * 2 * std::mem::size_of::<f32>())?;
+        for (frame, samples) in map.chunks_exact_mut(8).enumerate() {
+            let sample = ((frame as f32) * 0.05).sin() * 0.5;
+            samples[..4].copy_from_slice(&sample.to_ne_bytes());
+            samples[4..].copy_from_slice(&sample.to_ne_bytes());
+        }
+        Ok(AudioWaveform {
+            id: Some(id),
+            buffer: Some(Arc::new(map.make_read_only()?)),
+            file_path: Default::default(),
+            name: "Sine".to_string(),
+            sample_rate: 44_100,
+            channels: 2,
+            duration: (frames as f64) / 44_100.0,
+            root_note: 60,
+            fine_tune: 0,
+            trim_start: 0,
+            trim_end: 0,
+            is_looping: true,
+            normalized: false,
+            muted: false,
+            effects: Default::default(),
+        })
+    }
+
+    fn automation_lane(target: AutomationTarget, min: f32, max: f32) -> AudioAutomationLane {
+        let point = |time_ticks, value| AutomationPoint {
+            time_ticks,
+            value,
+            curve_type: CurveType::Linear,
+        };
+        AudioAutomationLane {
+            target,
+            points: vec![point(0, 0.2), point(TICKS_PER_BEAT * 3, 1.0), point(TICKS_PER_BEAT * 6, 0.5)],
+            enabled: true,
+            min,
+            max,
+            default_value: 0.5,
+        }
+    }
+
+    #[test]
+    fn steady_state_playback_does_not_allocate() -> anyhow::Result<()> {
+        let registry = PluginRegistry::new_with_defaults();
+        let bus_id = BusId::from(1);
+        let source_id = AudioSourceId::from(1);
+        let song_ticks = TICKS_PER_BEAT * 8;
+
+        // Two synth tracks playing a pattern clip, one on a bus, and a looping audio clip
+        let pattern_id = PatternId::from(1);
+        let notes = (0..16u32)
+            .map(|step| Note {
+                id: NoteId::from(step),
+                start_tick: u64::from(step * TICKS_PER_BEAT / 2),
+                duration: u64::from(TICKS_PER_BEAT / 3),
+                key: 48 + ((step * 7) % 24) as u8,
+                velocity: 100,
+                probability: 1.0,
+                micro_offset: 0,
+                mute: false,
+            })
+            .collect();
+        let pattern = Pattern {
+            id: pattern_id,
+            name: "Arp".to_string(),
+            length_ticks: u64::from(song_ticks),
+            notes,
+            next_note_id: 16,
+            groove: None,
+            scale: None,
+        };
+
+        let mut tracks = Vec::new();
+        for id in 1..=2 {
+            let mut track = KarbeatTrack {
+                id: TrackId::from(id),
+                track_type: TrackType::Midi,
+                generator: Some(GeneratorInstance {
+                    id: GeneratorId::from(id),
+                    instance_type: GeneratorInstanceType::Plugin(
+                        PluginInstance::new("Karbeatzer V2")
+                    ),
+                }),
+                ..KarbeatTrack::default()
+            };
+            track.add_clip(Clip {
+                name: "Arp".to_string(),
+                id: ClipId::from(id),
+                start_time: 0,
+                source: KarbeatSource::Midi(pattern_id),
+                offset_start: 0,
+                loop_length: song_ticks,
+                groove: None,
+            })?;
+            tracks.push(Arc::new(track));
+        }
+        let mut audio_track = KarbeatTrack {
+            id: TrackId::from(3),
+            track_type: TrackType::Audio,
+            ..KarbeatTrack::default()
+        };
+        audio_track.add_clip(Clip {
+            name: "Sine".to_string(),
+            id: ClipId::from(3),
+            start_time: TICKS_PER_BEAT,
+            source: KarbeatSource::Audio(source_id),
+            offset_start: 0,
+            loop_length: TICKS_PER_BEAT * 4,
+            groove: None,
+        })?;
+        tracks.push(Arc::new(audio_track));
+
+        let mut state = AudioRenderState::default();
+        state.graph.buffer_size = BLOCK_FRAMES;
+        state.graph.tracks = tracks.into();
+        state.graph.patterns.insert(pattern_id, Arc::new(pattern));
+        let mut assets = AssetLibrary::default();
+        assets.source_map.insert(source_id, Arc::new(sine_waveform(source_id, 22_050)?));
+        state.graph.asset_library = Arc::new(assets);
+        state.graph.max_tick_index = song_ticks;
+
+        let mixer = &mut state.graph.mixer_state;
+        for id in 1..=3 {
+            mixer.channels.insert(TrackId::from(id), Arc::new(MixerChannel::default()));
+        }
+        mixer.buses.insert(bus_id, Arc::new(MixerBus::new(bus_id, "Synths")));
+        mixer.routing.push(
+            RoutingConnection::new(RoutingNode::Track(TrackId::from(2)), RoutingNode::Bus(bus_id))
+        );
+        mixer.routing.push(RoutingConnection::new(RoutingNode::Bus(bus_id), RoutingNode::Master));
+
+        let lanes = [
+            automation_lane(AutomationTarget::TrackVolume(TrackId::from(1)), -24.0, 0.0),
+            automation_lane(AutomationTarget::TrackPan(TrackId::from(3)), -1.0, 1.0),
+            automation_lane(
+                AutomationTarget::TrackGeneratorPluginParam { track_id: TrackId::from(2), param_id: 8 },
+                0.0,
+                1.0
+            ),
+            automation_lane(AutomationTarget::BusVolume(bus_id), -12.0, 0.0),
+            automation_lane(AutomationTarget::MasterVolume, -6.0, 0.0),
+        ];
+        for (id, lane) in lanes.into_iter().enumerate() {
+            state.graph.automation_lanes.insert(AutomationId::from(id as u32), lane);
+        }
+
+        let (mut engine, mut commands, _states) = start_engine(state, 0)?;
+        let mut queue = vec![AudioCommand::AddBus { bus_id, name: "Synths".to_string() }];
+        for id in 1..=2 {
+            let plugin = registry
+                .create_generator("Karbeatzer V2")
+                .ok_or_else(|| anyhow::anyhow!("Synth not registered"))?;
+            queue.push(AudioCommand::AddGenerator {
+                generator_id: GeneratorId::from(id),
+                track_id: TrackId::from(id),
+                plugin,
+            });
+        }
+        let eq = |id| {
+            registry
+                .create_effect("Parametric EQ")
+                .ok_or_else(|| anyhow::anyhow!("EQ not registered"))
+                .map(|effect| (EffectId::from(id), effect))
+        };
+        let (effect_id, effect) = eq(1)?;
+        queue.push(AudioCommand::AddTrackEffect { track_id: TrackId::from(1), effect_id, effect });
+        let (effect_id, effect) = eq(2)?;
+        queue.push(AudioCommand::AddBusEffect { bus_id, effect_id, effect });
+        let (effect_id, effect) = eq(3)?;
+        queue.push(AudioCommand::AddMasterEffect { effect_id, effect });
+        queue.extend([
+            AudioCommand::SetMetronomeEnabled(true),
+            AudioCommand::SetLooping(true),
+            AudioCommand::SetPlaying(true),
+        ]);
+        send_all(&mut commands, queue)?;
+        // Load reports then list every plugin
+        set_plugin_profiling(true);
+
+        // Two bars at 120 BPM. The first pass warms up: voices, meters and scratch buffers
+        // reach their working size
+        let song_blocks = 4 * 48_000 / BLOCK_FRAMES + 1;
+        let mut buffer = vec![0.0; BLOCK_FRAMES * 2];
+        let mut peak = 0.0f32;
+        for _ in 0..song_blocks {
+            engine.process(&mut buffer);
+            peak = buffer.iter().fold(peak, |peak, s| peak.max(s.abs()));
+        }
+        assert!(peak > 1e-3, "the song should not be silent");
+
+        // A full loop of the song, across the loop seam
+        let allocations = count_allocations(|| {
+            for _ in 0..song_blocks {
+                engine.process(&mut buffer);
+            }
+        });
+        set_plugin_profiling(false);
+        assert_eq!(allocations, 0, "heap operations on the audio thread during playback");
+        Ok(())
+    }
+
+    #[test]
+    fn scheduler_honours_mute_probability_and_micro_offset() {
+        let note = |id: u32, start_tick: u64, probability: f32, micro_offset: i8, mute: bool| Note {
+            id: NoteId::from(id),
+            start_tick,
+            duration: u64::from(TICKS_PER_BEAT / 4),
+            key: 60 + (id as u8),
+            velocity: 100,
+            probability,
+            micro_offset,
+            mute,
+        };
+        let pattern = Pattern {
+            id: PatternId::from(1),
+            name: "Hats".to_string(),
+            length_ticks: u64::from(TICKS_PER_BEAT),
+            notes: vec![
+                // Pushed early, sounds before the clip starts
+                note(0, 0, 1.0, -60, false),
+                note(1, 240, 1.0, 0, true),
+                note(2, 480, 0.0, 0, false),
+                note(3, 720, 0.5, 0, false),
+            ],
+            next_note_id: 4,
+            groove: None,
+            scale: None,
+        };
+        let clip = Clip {
+            name: "Hats".to_string(),
+            id: ClipId::from(1),
+            start_time: TICKS_PER_BEAT,
+            source: KarbeatSource::Midi(pattern.id),
+            offset_start: 0,
+            loop_length: TICKS_PER_BEAT * 16,
+            groove: None,
+        };
+        let tempo_map = TempoMap::new(120.0);
+        let sample_rate = 48_000;
+        let clip_start = tempo_map.tick_to_sample(u64::from(TICKS_PER_BEAT), sample_rate) as u32;
+        let song_end = tempo_map.tick_to_sample(u64::from(TICKS_PER_BEAT * 17), sample_rate) as u32;
+
+        let note_ons = |seed: u64| {
+            let mut events = SmallVec::new();
+            let mut held_keys = HeldKeys::with_capacity(8);
+            AudioEngine::schedule_midi_events(
+                &mut events,
+                &mut held_keys,
+                &tempo_map,
+                sample_rate,
+                &clip,
+                &pattern,
+                seed,
+                0,
+                song_end
+            );
+            events
+                .iter()
+                .filter_map(|event| match event.data {
+                    MidiMessage::NoteOn { key, .. } => Some((event.sample_offset, key)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let played = note_ons(7);
+        let early = tempo_map.tick_to_sample(u64::from(TICKS_PER_BEAT) - 60, sample_rate) as usize;
+        assert_eq!(played.first(), Some(&(early, 60)));
+        assert!((early as u32) < clip_start);
+        assert!(played.iter().all(|&(_, key)| key == 60 || key == 63));
+        assert_eq!(played.iter().filter(|&&(_, key)| key == 60).count(), 16);
+
+        // Coin flips differ from one repetition to the next but not from one run to the next
+        let coin_flips = played.iter().filter(|&&(_, key)| key == 63).count();
+        assert!(coin_flips > 0 && coin_flips < 16);
+        assert_eq!(note_ons(7), played);
+    }
+
+    /// A generator that writes down the notes it is sent, by output frame and key, `true` for a
+    /// note on.
+    struct NoteProbe {
+        channels: usize,
+        frames: usize,
+        notes: Arc<parking_lot::Mutex<Vec<(usize, u8, bool)>>>,
+    }
+
+    impl KarbeatGenerator for NoteProbe {
+        fn name(&self) -> &str {
+            "Note probe"
+        }
+
+        fn prepare(&mut self, _sample_rate: f32, channels: usize, _max_buffer_size: usize) {
+            self.channels = channels.max(1);
+        }
+
+        fn reset(&mut self) {}
+
+        fn process(&mut self, output_buffer: &mut [f32], midi_events: &[MidiEvent]) {
+            let mut notes = self.notes.lock();
+            for event in midi_events {
+                let frame = self.frames + event.sample_offset;
+                match event.data {
+                    MidiMessage::NoteOn { key, .. } => notes.push((frame, key, true)),
+                    MidiMessage::NoteOff { key } => notes.push((frame, key, false)),
+                    _ => {}
+                }
+            }
+            self.frames += output_buffer.len() / self.channels;
+        }
+
+        fn set_parameter(&mut self, _id: u32, _value: f32) {}
+
+        fn get_parameter(&self, _id: u32) -> f32 {
+            0.0
+        }
+
+        fn apply_automation(&mut self, _id: u32, _value: f32) {}
+
+        fn clear_automation(&mut self, _id: u32) {}
+
+        fn default_parameters(&self) -> IndexMap<u32, f32> {
+            IndexMap::new()
+        }
+
+        fn get_parameter_specs(&self) -> Vec<ParameterSpec> {
+            Vec::new()
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    /// Plays `pattern` from a clip at the song start, looping `region`, and returns the notes
+    /// sent over `blocks` blocks.
+    fn loop_notes(
+        pattern: Pattern,
+        region: LoopRegion,
+        blocks: usize
+    ) -> anyhow::Result<Vec<(usize, u8, bool)>> {
+        let track_id = TrackId::from(1);
+        let generator_id = GeneratorId::from(1);
+        let mut track = KarbeatTrack {
+            id: track_id,
+            track_type: TrackType::Midi,
+            generator: Some(GeneratorInstance {
+                id: generator_id,
+                instance_type: GeneratorInstanceType::Plugin(PluginInstance::new("Note probe")),
+            }),
+            ..KarbeatTrack::default()
+        };
+        let length = pattern.length_ticks as u32;
+        track.add_clip(Clip {
+            name: pattern.name.clone(),
+            id: ClipId::from(1),
+            start_time: 0,
+            source: KarbeatSource::Midi(pattern.id),
+            offset_start: 0,
+            loop_length: length,
+            groove: None,
+        })?;
+
+        let mut state = AudioRenderState::default();
+        state.graph.buffer_size = BLOCK_FRAMES;
+        state.graph.tracks = vec![Arc::new(track)].into();
+        state.graph.patterns.insert(pattern.id, Arc::new(pattern));
+        state.graph.max_tick_index = length;
+
+        let notes = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let probe = NoteProbe { channels: 2, frames: 0, notes: Arc::clone(&notes) };
+        let (mut engine, mut commands, _states) = start_engine(state, 0)?;
+        send_all(&mut commands, vec![
+            AudioCommand::AddGenerator { generator_id, track_id, plugin: Box::new(probe) },
+            AudioCommand::SetLoopRegion(Some(region)),
+            AudioCommand::SetLooping(true),
+            AudioCommand::SetPlaying(true),
+        ])?;
+
+        let mut buffer = vec![0.0; BLOCK_FRAMES * 2];
+        for _ in 0..blocks {
+            engine.process(&mut buffer);
+        }
+        let sent = notes.lock().clone();
+        Ok(sent)
+    }
+
+    fn probe_note(id: u32, key: u8, start_tick: u64, duration: u64) -> Note {
+        Note {
+            id: NoteId::from(id),
+            start_tick,
+            duration,
+            key,
+            velocity: 100,
+            probability: 1.0,
+            micro_offset: 0,
+            mute: false,
+        }
+    }
+
+    #[test]
+    fn loop_region_wraps_on_the_sample_and_releases_held_notes() -> anyhow::Result<()> {
+        // 120 BPM at 48 kHz: 25 samples a tick, a beat is 24000 samples
+        let pattern = Pattern {
+            id: PatternId::from(1),
+            name: "Pads".to_string(),
+            length_ticks: u64::from(TICKS_PER_BEAT * 4),
+            notes: vec![
+                // Both still sound at the loop end, the first one starts over at the loop start
+                probe_note(0, 60, 0, u64::from(TICKS_PER_BEAT * 3)),
+                probe_note(1, 64, u64::from(TICKS_PER_BEAT * 3 / 2), u64::from(TICKS_PER_BEAT))
+            ],
+            next_note_id: 2,
+            groove: None,
+            scale: None,
+        };
+        // Two beats, the loop end falls in the middle of block 187
+        let region = LoopRegion { start: 0, end: u64::from(TICKS_PER_BEAT * 2) };
+        let notes = loop_notes(pattern, region, 96_000 / BLOCK_FRAMES + 1)?;
+
+        assert_eq!(notes, [
+            (0, 60, true),
+            (36_000, 64, true),
+            (48_000, 60, false),
+            (48_000, 64, false),
+            (48_000, 60, true),
+            (84_000, 64, true),
+            (96_000, 60, false),
+            (96_000, 64, false),
+            (96_000, 60, true),
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn loop_region_shorter_than_a_block_wraps_within_it() -> anyhow::Result<()> {
+        let pattern = Pattern {
+            id: PatternId::from(1),
+            name: "Ticks".to_string(),
+            length_ticks: u64::from(TICKS_PER_BEAT),
+            notes: vec![probe_note(0, 72, 0, 2)],
+            next_note_id: 1,
+            groove: None,
+            scale: None,
+        };
+        // 100 samples, two and a half loops a block
+        let region = LoopRegion { start: 0, end: 4 };
+        let notes = loop_notes(pattern, region, 2)?;
+
+        let expected: Vec<(usize, u8, bool)> = (0..2 * BLOCK_FRAMES)
+            .step_by(50)
+            .map(|frame| (frame, 72, frame % 100 == 0))
+            .collect();
+        assert_eq!(notes, expected);
+        Ok(())
+    }
+
+    /// Feeds `blocks` blocks of a stereo sine to the input and returns the peak of each
+    /// rendered block.
+    fn monitor_peaks(
+        engine: &mut AudioEngine,
+        input: &mut Producer<f32>,
+        blocks: usize
+    ) -> anyhow::Result<Vec<f32>> {
+        let mut buffer = vec![0.0; BLOCK_FRAMES * 2];
+        let mut peaks = Vec::with_capacity(blocks);
+        for _ in 0..blocks {
+            for frame in 0..BLOCK_FRAMES {
+                let sample = ((frame as f32) * std::f32::consts::TAU / 64.0).sin() * 0.5;
+                for _ in 0..2 {
+                    input.push(sample).map_err(|_| anyhow::anyhow!("Input ring full"))?;
+                }
+            }
+            engine.process(&mut buffer);
+            peaks.push(buffer.iter().fold(0.0f32, |peak, s| peak.max(s.abs())));
+        }
+        Ok(peaks)
+    }
+
+    #[test]
+    fn input_is_monitored_only_while_the_track_is_armed() -> anyhow::Result<()> {
+        let armed = KarbeatTrack {
+            id: TrackId::from(1),
+            track_type: TrackType::Audio,
+            record_armed: true,
+            monitor_mode: MonitorMode::Always,
+            ..KarbeatTrack::default()
+        };
+        let mut state = AudioRenderState::default();
+        state.graph.buffer_size = BLOCK_FRAMES;
+        state.graph.tracks = vec![Arc::new(armed.clone())].into();
+
+        let (mut input, consumer) = RingBuffer::new(BLOCK_FRAMES * 2 * 4);
+        let (mut engine, mut commands, mut states) = start_engine(state.clone(), 0)?;
+        send_all(&mut commands, vec![
+            AudioCommand::SetAudioInput(Some(AudioInputPort { consumer, channels: 2 }))
+        ])?;
+
+        let peaks = monitor_peaks(&mut engine, &mut input, 4)?;
+        assert!(peaks.iter().all(|&peak| peak > 0.1), "armed track should be heard: {peaks:?}");
+
+        let disarmed = KarbeatTrack { record_armed: false, ..armed };
+        state.graph.tracks = vec![Arc::new(disarmed)].into();
+        states.write(state);
+
+        let peaks = monitor_peaks(&mut engine, &mut input, 4)?;
+        assert!(peaks.iter().all(|&peak| peak == 0.0), "disarmed track should be silent: {peaks:?}");
+        Ok(())
+    }
+}
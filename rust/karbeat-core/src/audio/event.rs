@@ -6,10 +6,14 @@ use crate::shared::id::*;
 pub struct TransportFeedback {
     // Song playback position
     pub samples: u32,
+    /// 1-based beat inside the current bar, counted in units of `denominator`
     pub beat: usize,
     pub bar: usize,
     pub tempo: f32, // Useful for Flutter to interpolate movement
     pub sample_rate: u32,
+    /// Time signature at the playhead (pattern mode reports the project start signature)
+    pub numerator: u8,
+    pub denominator: u8,
 
     // Transport state
     pub is_playing: bool,
@@ -22,35 +26,29 @@ pub struct TransportFeedback {
     pub pattern_samples: u32,
     pub pattern_beat: usize,
     pub pattern_bar: usize,
+
+    /// Plugin latency of the mix: what is heard lags `samples` by this much
+    pub latency_samples: u32,
 }
 
 // Automation event for event-driven automation system
 
-pub enum GeneratorAutomationEvent {
-    PluginParam { param_id: u32, value: f32 },
-}
-
-pub enum TrackAutomationEvent {
-    Volume(f32),
-    Pan(f32),
-    PluginParam {
-        effect_id: EffectId,
-        param_id: u32,
-        value: f32,
-    },
+/// An automated value and where in the block it is reached, like `MidiEvent`
+#[derive(Clone, Copy, Debug)]
+pub struct AutomationEvent<T> {
+    /// Offset in samples within the current buffer (0 to buffer_size)
+    pub sample_offset: usize,
+    pub data: T,
 }
 
-pub enum BusAutomationEvent {
-    Volume(f32),
-    Pan(f32),
-    PluginParam {
-        effect_id: EffectId,
-        param_id: u32,
-        value: f32,
-    },
+#[derive(Clone, Copy, Debug)]
+pub enum GeneratorAutomationEvent {
+    PluginParam { param_id: u32, value: f32 },
 }
 
-pub enum MasterAutomationEvent {
+/// Automation of a channel strip: a track, a bus or the master
+#[derive(Clone, Copy, Debug)]
+pub enum ChannelAutomationEvent {
     Volume(f32),
     Pan(f32),
     PluginParam {
@@ -1,15 +1,17 @@
 use indexmap::IndexMap;
+use karbeat_dsp::loudness::{ LoudnessMeter, LoudnessReport };
 use karbeat_plugin_api::traits::{ KarbeatEffect, KarbeatGenerator };
 use rtrb::RingBuffer;
 use thiserror::Error;
 
 use crate::{
     audio::{
-        engine::AudioEngine,
+        engine::{ AudioEngine, EngineHandles },
+        load::EngineLoadReport,
         render_state::AudioRenderState,
         writer::{ AudioFormatBuilder, AudioWriter, BitPerSample },
     },
-    commands::AudioCommand,
+    commands::{ AudioCommand, PreparedPlugins },
     context::ctx,
     core::project::{ ApplicationState, GeneratorId, TrackId },
     shared::id::*
@@ -31,13 +33,14 @@ impl AudioExportError {
     }
 }
 
+/// Renders the song into `writer` and returns the loudness of what was written.
 pub fn export_project(
     app_state: &ApplicationState,
     output_path: &str,
     sample_rate: u32,
     bit_per_sample: BitPerSample,
     mut writer: impl AudioWriter
-) -> Result<(), AudioExportError> {
+) -> Result<LoudnessReport, AudioExportError> {
     log::info!("Starting offline render to: {}", output_path);
 
     let channels = 2; // Stereo
@@ -58,18 +61,30 @@ pub fn export_project(
     let (mut cmd_producer, cmd_consumer) = RingBuffer::<AudioCommand>::new(1024);
     let (pos_producer, mut _pos_consumer) = RingBuffer::new(1024);
     let (feedback_producer, mut _feedback_consumer) = RingBuffer::new(1024);
+    let (meter_producer, mut _meter_consumer) = RingBuffer::new(1024);
+    let (loudness_input, _loudness_output) = triple_buffer::TripleBuffer
+        ::new(&LoudnessReport::default())
+        .split();
+    let (load_input, _load_output) = triple_buffer::TripleBuffer::<EngineLoadReport>::default().split();
 
     // Instantiate the Headless Audio Engine
     let mut offline_engine = AudioEngine::new(
         state_out,
         cmd_consumer,
-        pos_producer,
-        feedback_producer,
+        EngineHandles {
+            position_producer: pos_producer,
+            feedback_producer,
+            meter_producer,
+            loudness_report: loudness_input,
+            load_report: load_input,
+        },
         sample_rate,
         channels as u16,
         app_state.transport.bpm,
         render_state.clone()
     );
+    // The export measures what is written below, the engine's own analyzer would repeat it
+    offline_engine.disable_loudness();
 
     // Hydrate the Engine (Load fresh plugin clones)
     let registry = ctx().plugin_registry.read();
@@ -162,12 +177,16 @@ pub fn export_project(
 
     // Send Setup Commands to the Engine
     cmd_producer
-        .push(AudioCommand::PreparePlugin {
-            generators,
-            track_effects,
-            bus_effects,
-            master_effects,
-        })
+        .push(
+            AudioCommand::PreparePlugin(
+                Box::new(PreparedPlugins {
+                    generators,
+                    track_effects,
+                    bus_effects,
+                    master_effects,
+                })
+            )
+        )
         .map_err(|_| AudioExportError::new("Engine", "Failed to send PreparePlugin command"))?;
 
     cmd_producer
@@ -183,11 +202,14 @@ pub fn export_project(
     // Determine exact render length
     // TODO: Track tails from effect calculations or add a long tail and trim when the audio gets 0 magnitude
     let tail_seconds = 3.0; // Wait 3 seconds after the last clip for reverb/delays to fade.
-    let total_samples =
-        render_state.graph.max_sample_index + (((sample_rate as f32) * tail_seconds) as u32);
+    let song_end_samples = render_state.graph.tempo_map
+        .tick_to_sample(render_state.graph.max_tick_index, sample_rate) as u32;
+    let total_samples = song_end_samples + (((sample_rate as f32) * tail_seconds) as u32);
     let mut processed_samples: u32 = 0;
 
     let mut mix_buffer = vec![0.0; block_size * channels as usize];
+    // Measures the file as written, whatever reaches the output
+    let mut loudness = LoudnessMeter::new(sample_rate);
 
     // The "Faster-Than-Realtime" Loop
     while processed_samples < total_samples {
@@ -198,6 +220,7 @@ pub fn export_project(
         // Process the exact slice needed
         let active_slice = &mut mix_buffer[..samples_to_process];
         offline_engine.process(active_slice);
+        loudness.process(active_slice, channels as usize);
 
         // Delegate encoding and writing entirely to the generic writer interface
         writer
@@ -207,6 +230,7 @@ pub fn export_project(
         // Keep the position/feedback queues from filling up and blocking
         while let Ok(_) = _pos_consumer.pop() {}
         while let Ok(_) = _feedback_consumer.pop() {}
+        while let Ok(_) = _meter_consumer.pop() {}
 
         processed_samples += frames_to_process as u32;
     }
@@ -215,6 +239,11 @@ pub fn export_project(
         .finalize()
         .map_err(|e| AudioExportError::new("Writer", format!("Finalize error: {}", e)))?;
 
-    log::info!("Offline render successfully completed!");
-    Ok(())
+    let report = loudness.report();
+    log::info!(
+        "Offline render successfully completed! {:.1} LUFS integrated, {:.1} dBTP",
+        report.integrated,
+        report.true_peak
+    );
+    Ok(report)
 }
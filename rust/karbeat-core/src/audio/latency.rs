@@ -0,0 +1,379 @@
+// src/audio/latency.rs
+//
+// Plugin delay compensation. Plugins with lookahead or linear-phase filters report how far their
+// output lags their input, and that lag adds up along every path through the mixer: a track's
+// generator and effects, then each bus it goes through, then the master. Where paths meet, at a
+// bus or the master, the faster ones are delayed to match the slowest so everything is summed in
+// time. The master's latency is what the whole mix lags the playhead by.
+//
+// Latencies are recomputed every block, since a plugin can change its own at any time, but the
+// delay lines only get their memory when the graph or the plugins change. In between a line's
+// delay moves within the room it was given and the audio in it is kept.
+
+use hashbrown::HashMap;
+
+use crate::{
+    audio::render_state::AudioEffectInstance,
+    core::project::mixer::{ RoutingConnection, RoutingNode },
+    shared::id::{ BusId, TrackId },
+};
+
+/// Nodes and connections the maps hold without growing on the audio thread
+const GRAPH_CAPACITY: usize = 256;
+
+/// Room every delay line gets at least, so latencies can grow a little without new memory
+const MIN_LINE_FRAMES: usize = 2048;
+
+/// Latency of an effects chain, the effects run one after the other.
+pub fn chain_latency(effects: &[AudioEffectInstance]) -> u32 {
+    effects
+        .iter()
+        .map(|effect| effect.plugin.latency_samples())
+        .fold(0, u32::saturating_add)
+}
+
+/// Fixed delay of an interleaved signal.
+#[derive(Default)]
+pub struct DelayLine {
+    ring: Vec<f32>,
+    position: usize,
+    /// Delay in samples, never more than the ring holds
+    delay: usize,
+    /// Samples written since the last non-silent one, the line is silent once they fill it
+    silent_run: usize,
+}
+
+impl DelayLine {
+    /// Makes room for a delay of `frames`, clearing the line when it has to grow. This is the
+    /// only place the line allocates.
+    pub fn reserve(&mut self, frames: u32, channels: usize) {
+        let len = (frames as usize) * channels;
+        if len > self.ring.len() {
+            let capacity = len.max(MIN_LINE_FRAMES * channels).next_power_of_two();
+            self.ring.clear();
+            self.ring.resize(capacity, 0.0);
+            self.position = 0;
+            self.silent_run = capacity;
+        }
+    }
+
+    /// Sets the delay within the reserved room, keeping the audio already in the line. A delay
+    /// longer than the room is cut short until the line is reserved again.
+    pub fn set_delay(&mut self, frames: u32, channels: usize) {
+        self.delay = ((frames as usize) * channels).min(self.ring.len());
+    }
+
+    pub fn delay_samples(&self) -> usize {
+        self.delay
+    }
+
+    /// Whether audio written earlier is still coming out.
+    pub fn is_ringing(&self) -> bool {
+        self.silent_run < self.delay
+    }
+
+    /// Writes `input` and reads what was written `delay` samples ago into `output`.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        if self.delay == 0 {
+            output.copy_from_slice(input);
+            return;
+        }
+
+        let len = self.ring.len();
+        let mut read = self.position + len - self.delay;
+        if read >= len {
+            read -= len;
+        }
+        for (sample, out) in input.iter().zip(output.iter_mut()) {
+            *out = self.ring[read];
+            self.ring[self.position] = *sample;
+            read += 1;
+            if read == len {
+                read = 0;
+            }
+            self.position += 1;
+            if self.position == len {
+                self.position = 0;
+            }
+            self.silent_run = if *sample == 0.0 { self.silent_run.saturating_add(1) } else { 0 };
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.ring.fill(0.0);
+        self.position = 0;
+        self.silent_run = self.ring.len();
+    }
+}
+
+/// Latencies through the routing graph and the delays that line its paths up, owned by the
+/// audio thread.
+pub struct DelayCompensation {
+    /// Latency at each track's and bus's output
+    output: HashMap<RoutingNode, u32>,
+    /// Latency the inputs of each bus and the master are aligned to
+    input: HashMap<RoutingNode, u32>,
+    /// Lines of the connections (source, destination) the graph has or had
+    delays: HashMap<(RoutingNode, RoutingNode), DelayLine>,
+    /// Delay in frames each connection of the graph needs, as of the last update
+    required: Vec<((RoutingNode, RoutingNode), u32)>,
+    /// Metronome clicks, aligned with where they are mixed in
+    click: DelayLine,
+    click_delay: u32,
+    /// Latency of the master output
+    total: u32,
+    channels: usize,
+    scratch: Vec<f32>,
+}
+
+impl DelayCompensation {
+    pub fn new(channels: usize, max_block_samples: usize) -> Self {
+        Self {
+            output: HashMap::with_capacity(GRAPH_CAPACITY),
+            input: HashMap::with_capacity(GRAPH_CAPACITY),
+            delays: HashMap::with_capacity(GRAPH_CAPACITY),
+            required: Vec::with_capacity(GRAPH_CAPACITY),
+            click: DelayLine::default(),
+            click_delay: 0,
+            total: 0,
+            channels: channels.max(1),
+            scratch: vec![0.0; max_block_samples],
+        }
+    }
+
+    /// Latency of the master output: how far the mix lags the playhead.
+    pub fn total_latency(&self) -> u32 {
+        self.total
+    }
+
+    /// Latency at a node's output, zero for nodes not in the graph.
+    pub fn output_latency(&self, node: RoutingNode) -> u32 {
+        if node == RoutingNode::Master {
+            return self.total;
+        }
+        self.output.get(&node).copied().unwrap_or(0)
+    }
+
+    /// Recomputes the latencies and delays. `buses` comes in routing order, so every bus
+    /// feeding another is visited first. Tracks without a connection go to the master.
+    /// `plugin_latency` is the latency of a node's own plugins. Lines only take delays that fit
+    /// the room `reserve_lines` gave them.
+    pub fn update(
+        &mut self,
+        tracks: impl Iterator<Item = TrackId> + Clone,
+        buses: impl Iterator<Item = BusId>,
+        routing: &[RoutingConnection],
+        click_target: Option<BusId>,
+        plugin_latency: impl Fn(RoutingNode) -> u32
+    ) {
+        self.output.clear();
+        self.input.clear();
+        self.required.clear();
+
+        for track_id in tracks.clone() {
+            let node = RoutingNode::Track(track_id);
+            self.output.insert(node, plugin_latency(node));
+        }
+
+        for bus_id in buses {
+            let node = RoutingNode::Bus(bus_id);
+            let input = self.aligned_input(node, routing);
+            self.input.insert(node, input);
+            self.output.insert(node, input.saturating_add(plugin_latency(node)));
+        }
+
+        let mut master_input = self.aligned_input(RoutingNode::Master, routing);
+        for track_id in tracks.clone() {
+            let node = RoutingNode::Track(track_id);
+            if !routing.iter().any(|conn| conn.source == node) {
+                master_input = master_input.max(self.output_latency(node));
+            }
+        }
+        self.input.insert(RoutingNode::Master, master_input);
+        self.total = master_input.saturating_add(plugin_latency(RoutingNode::Master));
+
+        // Every connection is delayed up to the latency its destination is aligned to
+        for conn in routing {
+            if matches!(conn.destination, RoutingNode::Track(_)) {
+                continue;
+            }
+            self.set_route_delay(conn.source, conn.destination);
+        }
+        for track_id in tracks {
+            let node = RoutingNode::Track(track_id);
+            if !routing.iter().any(|conn| conn.source == node) {
+                self.set_route_delay(node, RoutingNode::Master);
+            }
+        }
+
+        // Clicks have no plugins: they wait for the bus's inputs, or for the whole mix when
+        // they are added after the master
+        self.click_delay = match click_target {
+            Some(bus_id) => self.input.get(&RoutingNode::Bus(bus_id)).copied().unwrap_or(0),
+            None => self.total,
+        };
+        self.click.set_delay(self.click_delay, self.channels);
+    }
+
+    /// Gives every connection of the last update a line with room for its delay. Allocates for
+    /// new connections and grown delays, so it runs when the graph or the plugins change.
+    pub fn reserve_lines(&mut self) {
+        let channels = self.channels;
+        for &(route, delay) in &self.required {
+            let line = self.delays.entry(route).or_default();
+            line.reserve(delay, channels);
+            line.set_delay(delay, channels);
+        }
+        self.click.reserve(self.click_delay, channels);
+        self.click.set_delay(self.click_delay, channels);
+    }
+
+    /// Latency of the slowest connection into `node`
+    fn aligned_input(&self, node: RoutingNode, routing: &[RoutingConnection]) -> u32 {
+        routing
+            .iter()
+            .filter(|conn| conn.destination == node)
+            .map(|conn| self.output_latency(conn.source))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn set_route_delay(&mut self, source: RoutingNode, destination: RoutingNode) {
+        let aligned = self.input.get(&destination).copied().unwrap_or(0);
+        let delay = aligned.saturating_sub(self.output_latency(source));
+        self.required.push(((source, destination), delay));
+        if let Some(line) = self.delays.get_mut(&(source, destination)) {
+            line.set_delay(delay, self.channels);
+        }
+    }
+
+    /// Whether a connection out of `source` still has delayed audio to play out.
+    pub fn is_ringing(&self, source: RoutingNode) -> bool {
+        self.delays.iter().any(|((from, _), line)| *from == source && line.is_ringing())
+    }
+
+    pub fn is_click_ringing(&self) -> bool {
+        self.click.is_ringing()
+    }
+
+    /// `signal` as it has to be mixed into `destination`: delayed when the connection needs it.
+    pub fn delay<'a>(
+        &'a mut self,
+        source: RoutingNode,
+        destination: RoutingNode,
+        signal: &'a [f32]
+    ) -> &'a [f32] {
+        match self.delays.get_mut(&(source, destination)) {
+            Some(line) if line.delay_samples() > 0 => {
+                let out = grow(&mut self.scratch, signal.len());
+                line.process(signal, out);
+                out
+            }
+            _ => signal,
+        }
+    }
+
+    /// Metronome clicks as they have to be mixed in.
+    pub fn delay_click<'a>(&'a mut self, clicks: &'a [f32]) -> &'a [f32] {
+        if self.click.delay_samples() == 0 {
+            return clicks;
+        }
+        let out = grow(&mut self.scratch, clicks.len());
+        self.click.process(clicks, out);
+        out
+    }
+
+    /// Clears the delayed audio, e.g. when the transport is stopped and rewound.
+    pub fn reset(&mut self) {
+        self.delays.values_mut().for_each(DelayLine::reset);
+        self.click.reset();
+    }
+}
+
+/// The first `len` samples of `buffer`, growing it when a block is larger than expected.
+fn grow(buffer: &mut Vec<f32>, len: usize) -> &mut [f32] {
+    if buffer.len() < len {
+        buffer.resize(len, 0.0);
+    }
+    &mut buffer[..len]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn faster_paths_wait_for_the_slowest() {
+        let (a, b, c) = (TrackId::from(1), TrackId::from(2), TrackId::from(3));
+        let bus = BusId::from(1);
+        let routing = [
+            RoutingConnection::new(RoutingNode::Track(a), RoutingNode::Bus(bus)),
+            RoutingConnection::new(RoutingNode::Track(b), RoutingNode::Bus(bus)),
+            RoutingConnection::new(RoutingNode::Bus(bus), RoutingNode::Master),
+        ];
+        // A lookahead limiter on track a, a linear-phase EQ on the bus and on the master
+        let plugin_latency = |node| match node {
+            RoutingNode::Track(id) if id == a => 64,
+            RoutingNode::Bus(_) => 100,
+            RoutingNode::Master => 10,
+            _ => 0,
+        };
+
+        let mut compensation = DelayCompensation::new(2, 512);
+        compensation.update([a, b, c].into_iter(), [bus].into_iter(), &routing, None, plugin_latency);
+        compensation.reserve_lines();
+
+        assert_eq!(compensation.output_latency(RoutingNode::Bus(bus)), 164);
+        // Track c goes straight to the master and waits for the whole bus path
+        assert_eq!(compensation.total_latency(), 174);
+
+        // An impulse on track b comes out of its connection 64 frames late
+        let mut impulse = vec![0.0; 256];
+        impulse[0] = 1.0;
+        let delayed = compensation
+            .delay(RoutingNode::Track(b), RoutingNode::Bus(bus), &impulse)
+            .to_vec();
+        assert_eq!(delayed.iter().position(|&s| s == 1.0), Some(128));
+        assert!(!compensation.is_ringing(RoutingNode::Track(b)));
+
+        let silence = vec![0.0; 512];
+        let delayed = compensation
+            .delay(RoutingNode::Track(c), RoutingNode::Master, &impulse)
+            .to_vec();
+        assert!(delayed.iter().all(|&s| s == 0.0));
+        assert!(compensation.is_ringing(RoutingNode::Track(c)));
+        let delayed = compensation.delay(RoutingNode::Track(c), RoutingNode::Master, &silence);
+        assert_eq!(delayed.iter().position(|&s| s == 1.0), Some(164 * 2 - 256));
+
+        // Track a is the slowest into the bus and is not delayed
+        let passed = compensation.delay(RoutingNode::Track(a), RoutingNode::Bus(bus), &impulse);
+        assert_eq!(passed.iter().position(|&s| s == 1.0), Some(0));
+    }
+
+    #[test]
+    fn latency_changes_keep_the_delayed_audio() {
+        let (a, b) = (TrackId::from(1), TrackId::from(2));
+        let bus = BusId::from(1);
+        let routing = [
+            RoutingConnection::new(RoutingNode::Track(a), RoutingNode::Bus(bus)),
+            RoutingConnection::new(RoutingNode::Track(b), RoutingNode::Bus(bus)),
+        ];
+        let lookahead = |frames: u32| move |node| if node == RoutingNode::Track(a) { frames } else { 0 };
+
+        let mut compensation = DelayCompensation::new(2, 256);
+        compensation.update([a, b].into_iter(), [bus].into_iter(), &routing, None, lookahead(64));
+        compensation.reserve_lines();
+
+        let mut block = vec![0.0; 256];
+        block[200] = 1.0;
+        let delayed = compensation.delay(RoutingNode::Track(b), RoutingNode::Bus(bus), &block);
+        assert!(delayed.iter().all(|&s| s == 0.0));
+
+        // The lookahead grows while the impulse is still in the line, which moves it later
+        // instead of dropping it
+        compensation.update([a, b].into_iter(), [bus].into_iter(), &routing, None, lookahead(96));
+        let silence = vec![0.0; 256];
+        let delayed = compensation.delay(RoutingNode::Track(b), RoutingNode::Bus(bus), &silence);
+        assert_eq!(delayed.iter().position(|&s| s == 1.0), Some(200 + 96 * 2 - 256));
+    }
+}
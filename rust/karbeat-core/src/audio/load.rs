@@ -0,0 +1,348 @@
+// src/audio/load.rs
+//
+// CPU load of the audio thread. The engine times every callback against its real-time budget,
+// the time its frames take to play, and publishes the load a few times per second: the last
+// window, a smoothed average and the worst callback. Callbacks that overran their budget are
+// counted along with the xruns the backend reports. With plugin profiling on, every plugin's
+// `process` call is timed too, so the report can say which plugin the budget goes to.
+
+use std::{
+    sync::{ Arc, atomic::{ AtomicBool, AtomicU64, Ordering } },
+    time::{ Duration, Instant },
+};
+
+use triple_buffer::Input;
+
+use crate::{
+    audio::render_state::AudioPluginState,
+    core::project::mixer::RoutingNode,
+    shared::id::{ BusId, EffectId, GeneratorId, TrackId },
+};
+
+/// Reports published per second
+const REPORT_RATE_HZ: u32 = 4;
+
+/// Time constant of the smoothed averages
+const AVERAGE_SECONDS: f32 = 1.0;
+
+/// Plugins a report has room for. The lists are allocated with the report buffers so the audio
+/// thread never grows them, past this only the heaviest plugins are listed.
+pub const MAX_PROFILED_PLUGINS: usize = 512;
+
+/// Whether plugin `process` calls are timed. Off by default, timing costs two clock reads per
+/// plugin per block.
+static PLUGIN_PROFILING: AtomicBool = AtomicBool::new(false);
+
+pub fn set_plugin_profiling(enabled: bool) {
+    PLUGIN_PROFILING.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_plugin_profiling() -> bool {
+    PLUGIN_PROFILING.load(Ordering::Relaxed)
+}
+
+/// Which plugin a load belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluginSlot {
+    Generator(GeneratorId),
+    Effect {
+        node: RoutingNode,
+        effect_id: EffectId,
+    },
+}
+
+/// Load of one plugin, as a share of the real-time budget like the engine's.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PluginLoad {
+    pub slot: PluginSlot,
+    pub average: f32,
+    /// Slowest call of the last window against the budget of one block
+    pub peak: f32,
+}
+
+/// Load of the audio thread. Loads are shares of the real-time budget: 1.0 means rendering took
+/// as long as playing, above that the output drops out.
+///
+/// Build the report buffer with `TripleBuffer::default()` so every buffer gets room for
+/// [`MAX_PROFILED_PLUGINS`] plugins.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EngineLoadReport {
+    /// Load of the last window
+    pub load: f32,
+    /// Load smoothed over about a second
+    pub average: f32,
+    /// Slowest callback of the last window
+    pub peak: f32,
+    /// Slowest callback since the last reset
+    pub max_peak: f32,
+    /// Callbacks that took longer than their budget
+    pub overloads: u64,
+    /// Buffer underruns and overruns reported by the audio backend
+    pub xruns: u64,
+    /// Heaviest first, empty unless plugin profiling is on
+    pub plugins: Vec<PluginLoad>,
+}
+
+impl Default for EngineLoadReport {
+    fn default() -> Self {
+        Self {
+            load: 0.0,
+            average: 0.0,
+            peak: 0.0,
+            max_peak: 0.0,
+            overloads: 0,
+            xruns: 0,
+            plugins: Vec::with_capacity(MAX_PROFILED_PLUGINS),
+        }
+    }
+}
+
+/// Time one plugin spent in `process`.
+#[derive(Clone, Debug, Default)]
+pub struct PluginTimer {
+    /// Time spent since the previous report
+    busy: Duration,
+    /// Slowest call since the previous report
+    slowest: Duration,
+    average: f32,
+}
+
+impl PluginTimer {
+    /// Runs a plugin's `process`, timing it when profiling is on.
+    #[inline]
+    pub fn time<R>(&mut self, process: impl FnOnce() -> R) -> R {
+        if !is_plugin_profiling() {
+            return process();
+        }
+        let started = Instant::now();
+        let result = process();
+        let elapsed = started.elapsed();
+        self.busy += elapsed;
+        self.slowest = self.slowest.max(elapsed);
+        result
+    }
+
+    /// Ends the window, returning its load against `window` of real time.
+    fn take_load(&mut self, slot: PluginSlot, window: &Window, smoothing: f32) -> PluginLoad {
+        let load = window.share(self.busy);
+        self.average += (load - self.average) * smoothing;
+        let peak = window.share_of_block(self.slowest);
+        self.busy = Duration::ZERO;
+        self.slowest = Duration::ZERO;
+        PluginLoad { slot, average: self.average, peak }
+    }
+}
+
+/// Real time and callbacks covered by a report.
+#[derive(Clone, Copy, Debug, Default)]
+struct Window {
+    time: Duration,
+    callbacks: u32,
+}
+
+impl Window {
+    fn share(&self, busy: Duration) -> f32 {
+        if self.time.is_zero() { 0.0 } else { busy.as_secs_f32() / self.time.as_secs_f32() }
+    }
+
+    /// Share of the average callback's budget
+    fn share_of_block(&self, busy: Duration) -> f32 {
+        self.share(busy) * (self.callbacks.max(1) as f32)
+    }
+}
+
+/// Measures the load of the engine, owned by the audio thread.
+pub struct LoadMonitor {
+    report: Input<EngineLoadReport>,
+    xruns: Arc<AtomicU64>,
+    window: Window,
+    busy: Duration,
+    peak: f32,
+    max_peak: f32,
+    average: f32,
+    overloads: u64,
+    /// Frames rendered since the previous report
+    pending_frames: usize,
+}
+
+impl LoadMonitor {
+    pub fn new(report: Input<EngineLoadReport>) -> Self {
+        Self {
+            report,
+            xruns: Arc::new(AtomicU64::new(0)),
+            window: Window::default(),
+            busy: Duration::ZERO,
+            peak: 0.0,
+            max_peak: 0.0,
+            average: 0.0,
+            overloads: 0,
+            pending_frames: 0,
+        }
+    }
+
+    /// Counter the audio backend increments on every xrun.
+    pub fn xrun_counter(&self) -> Arc<AtomicU64> {
+        Arc::clone(&self.xruns)
+    }
+
+    /// Starts the peaks and counters over.
+    pub fn reset(&mut self) {
+        self.max_peak = 0.0;
+        self.overloads = 0;
+        self.xruns.store(0, Ordering::Relaxed);
+    }
+
+    /// Adds a callback that took `elapsed` to render `frames`, and publishes a report with the
+    /// plugins' loads once a window is complete.
+    pub fn record(
+        &mut self,
+        elapsed: Duration,
+        frames: usize,
+        sample_rate: u32,
+        plugins: &mut AudioPluginState
+    ) {
+        let budget = Duration::from_secs_f64((frames as f64) / (sample_rate.max(1) as f64));
+        let load = if budget.is_zero() {
+            0.0
+        } else {
+            elapsed.as_secs_f32() / budget.as_secs_f32()
+        };
+        if load > 1.0 {
+            self.overloads += 1;
+        }
+        self.peak = self.peak.max(load);
+        self.max_peak = self.max_peak.max(load);
+        self.busy += elapsed;
+        self.window.time += budget;
+        self.window.callbacks += 1;
+
+        self.pending_frames += frames;
+        if self.pending_frames < ((sample_rate / REPORT_RATE_HZ).max(1) as usize) {
+            return;
+        }
+        self.pending_frames = 0;
+        self.publish(plugins);
+    }
+
+    fn publish(&mut self, plugins: &mut AudioPluginState) {
+        let window = std::mem::take(&mut self.window);
+        let smoothing = 1.0 - (-window.time.as_secs_f32() / AVERAGE_SECONDS).exp();
+        let load = window.share(std::mem::take(&mut self.busy));
+        self.average += (load - self.average) * smoothing;
+
+        let report = self.report.input_buffer_mut();
+        report.load = load;
+        report.average = self.average;
+        report.peak = std::mem::take(&mut self.peak);
+        report.max_peak = self.max_peak;
+        report.overloads = self.overloads;
+        report.xruns = self.xruns.load(Ordering::Relaxed);
+        report.plugins.clear();
+
+        if is_plugin_profiling() {
+            let loads = &mut report.plugins;
+            for generator in plugins.generators.iter_mut().flatten() {
+                let slot = PluginSlot::Generator(generator.id);
+                push_load(loads, generator.load.take_load(slot, &window, smoothing));
+            }
+            let chains = plugins.track_effects
+                .iter_mut()
+                .enumerate()
+                .map(|(id, chain)| (RoutingNode::Track(TrackId::from(id as u32)), chain))
+                .chain(
+                    plugins.bus_effects
+                        .iter_mut()
+                        .enumerate()
+                        .map(|(id, chain)| (RoutingNode::Bus(BusId::from(id as u32)), chain))
+                )
+                .chain(std::iter::once((RoutingNode::Master, &mut plugins.master_effects)));
+            for (node, chain) in chains {
+                for effect in chain.iter_mut() {
+                    let slot = PluginSlot::Effect { node, effect_id: effect.id };
+                    push_load(loads, effect.load.take_load(slot, &window, smoothing));
+                }
+            }
+            loads.sort_unstable_by(|a, b| b.average.total_cmp(&a.average));
+        }
+        self.report.publish();
+    }
+}
+
+/// Adds a plugin's load without growing the list past [`MAX_PROFILED_PLUGINS`]. Once the list
+/// is full, a load only goes in instead of a lighter one.
+fn push_load(loads: &mut Vec<PluginLoad>, load: PluginLoad) {
+    if loads.len() < MAX_PROFILED_PLUGINS {
+        loads.push(load);
+        return;
+    }
+    let lightest = loads.iter_mut().min_by(|a, b| a.average.total_cmp(&b.average));
+    if let Some(lightest) = lightest {
+        if lightest.average < load.average {
+            *lightest = load;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use triple_buffer::TripleBuffer;
+
+    use super::*;
+
+    #[test]
+    fn load_is_measured_against_the_block_budget() {
+        let sample_rate = 48_000;
+        let (input, mut output) = TripleBuffer::<EngineLoadReport>::default().split();
+        let mut monitor = LoadMonitor::new(input);
+        let mut plugins = AudioPluginState::default();
+
+        // 10 ms blocks rendered in 5 ms, a quarter of a second
+        for _ in 0..25 {
+            monitor.record(Duration::from_millis(5), 480, sample_rate, &mut plugins);
+        }
+        let report = output.read().clone();
+        assert!((report.load - 0.5).abs() < 1e-3, "{}", report.load);
+        assert!((report.peak - 0.5).abs() < 1e-3);
+        assert!(report.average > 0.0 && report.average < 0.5);
+        assert_eq!(report.overloads, 0);
+
+        // One block that missed its deadline, and an xrun from the backend
+        monitor.xrun_counter().fetch_add(1, Ordering::Relaxed);
+        monitor.record(Duration::from_millis(15), 480, sample_rate, &mut plugins);
+        for _ in 0..24 {
+            monitor.record(Duration::from_millis(1), 480, sample_rate, &mut plugins);
+        }
+        let report = output.read().clone();
+        assert!((report.peak - 1.5).abs() < 1e-3, "{}", report.peak);
+        assert_eq!(report.overloads, 1);
+        assert_eq!(report.xruns, 1);
+
+        monitor.reset();
+        for _ in 0..25 {
+            monitor.record(Duration::from_millis(1), 480, sample_rate, &mut plugins);
+        }
+        let report = output.read().clone();
+        assert_eq!((report.overloads, report.xruns), (0, 0));
+        assert!((report.max_peak - 0.1).abs() < 1e-3);
+    }
+
+    #[test]
+    fn plugin_loads_stay_within_the_preallocated_list() {
+        let mut report = EngineLoadReport::default();
+        let capacity = report.plugins.capacity();
+        for id in 0..(MAX_PROFILED_PLUGINS as u32) + 10 {
+            let load = PluginLoad {
+                slot: PluginSlot::Generator(GeneratorId::from(id)),
+                average: id as f32,
+                peak: 0.0,
+            };
+            push_load(&mut report.plugins, load);
+        }
+
+        assert_eq!(report.plugins.len(), MAX_PROFILED_PLUGINS);
+        assert_eq!(report.plugins.capacity(), capacity);
+        // The lightest ones made room for the late heavy ones
+        let lightest = report.plugins.iter().map(|load| load.average).fold(f32::MAX, f32::min);
+        assert_eq!(lightest, 10.0);
+    }
+}
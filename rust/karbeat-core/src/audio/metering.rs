@@ -0,0 +1,261 @@
+// src/audio/metering.rs
+//
+// Level meters for the mixer. The engine measures every track, bus and the master after their
+// channel strip, accumulates peak and RMS over a short window and publishes one reading per
+// channel at a throttled rate. Peak hold and clip indicators are held on the audio thread so the
+// UI only has to draw the latest reading.
+
+use hashbrown::HashMap;
+use rtrb::Producer;
+
+use crate::shared::id::{ BusId, TrackId };
+
+/// Readings sent per second
+const METER_RATE_HZ: u32 = 30;
+
+/// How long the peak-hold marker stays before it falls to the current peak
+const PEAK_HOLD_SECONDS: f32 = 1.5;
+
+/// How long the clip indicator stays lit after the last sample at or above full scale
+const CLIP_HOLD_SECONDS: f32 = 3.0;
+
+/// Full scale: samples at or above this clip
+const CLIP_LEVEL: f32 = 1.0;
+
+/// Which mixer strip a reading belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MeterTarget {
+    Track(TrackId),
+    Bus(BusId),
+    Master,
+}
+
+/// One reading of a stereo strip, linear amplitude. Mono signals report the same level twice.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MeterLevels {
+    /// Highest absolute sample since the previous reading
+    pub peak: [f32; 2],
+    /// Root mean square since the previous reading
+    pub rms: [f32; 2],
+    /// Highest peak of the last `PEAK_HOLD_SECONDS`
+    pub peak_hold: [f32; 2],
+    /// A sample reached full scale in the last `CLIP_HOLD_SECONDS`
+    pub clipped: bool,
+}
+
+/// Meter reading sent from the audio thread to the UI.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeterFeedback {
+    pub target: MeterTarget,
+    pub levels: MeterLevels,
+}
+
+/// Running measurement of one strip.
+#[derive(Clone, Debug, Default)]
+pub struct ChannelMeter {
+    peak: [f32; 2],
+    sum_squares: [f64; 2],
+    frames: usize,
+    peak_hold: [f32; 2],
+    /// Frames left before each hold falls
+    peak_hold_left: [usize; 2],
+    clip_hold_left: usize,
+    /// Measured since the previous reading
+    active: bool,
+}
+
+impl ChannelMeter {
+    /// Adds an interleaved buffer to the running measurement.
+    pub fn measure(&mut self, buffer: &[f32], channels: usize) {
+        let channels = channels.max(1);
+        let mut clipped = false;
+        for frame in buffer.chunks_exact(channels) {
+            let left = frame[0];
+            let right = if channels > 1 { frame[1] } else { left };
+            for (side, sample) in [left, right].into_iter().enumerate() {
+                let level = sample.abs();
+                if level > self.peak[side] {
+                    self.peak[side] = level;
+                }
+                self.sum_squares[side] += (sample as f64) * (sample as f64);
+                clipped |= level >= CLIP_LEVEL;
+            }
+        }
+        self.frames += buffer.len() / channels;
+        self.active = true;
+        if clipped {
+            self.clip_hold_left = usize::MAX;
+        }
+    }
+
+    /// Ends the window: returns its reading and starts the next one. `frames` is the window
+    /// length, which ages the holds even when nothing was measured.
+    fn take_reading(&mut self, frames: usize, sample_rate: u32) -> MeterLevels {
+        let seconds = |s: f32| ((s * (sample_rate as f32)) as usize).max(1);
+        if self.clip_hold_left == usize::MAX {
+            self.clip_hold_left = seconds(CLIP_HOLD_SECONDS);
+        } else {
+            self.clip_hold_left = self.clip_hold_left.saturating_sub(frames);
+        }
+
+        let mut rms = [0.0; 2];
+        for side in 0..2 {
+            if self.frames > 0 {
+                rms[side] = (self.sum_squares[side] / (self.frames as f64)).sqrt() as f32;
+            }
+            self.peak_hold_left[side] = self.peak_hold_left[side].saturating_sub(frames);
+            if self.peak[side] >= self.peak_hold[side] || self.peak_hold_left[side] == 0 {
+                self.peak_hold[side] = self.peak[side];
+                self.peak_hold_left[side] = seconds(PEAK_HOLD_SECONDS);
+            }
+        }
+
+        let levels = MeterLevels {
+            peak: self.peak,
+            rms,
+            peak_hold: self.peak_hold,
+            clipped: self.clip_hold_left > 0,
+        };
+        self.peak = [0.0; 2];
+        self.sum_squares = [0.0; 2];
+        self.frames = 0;
+        self.active = false;
+        levels
+    }
+
+    /// Nothing left to show: silent and no hold lit.
+    fn is_idle(&self) -> bool {
+        !self.active && self.clip_hold_left == 0 && self.peak_hold.iter().all(|&p| p == 0.0)
+    }
+}
+
+/// Meters of the whole mixer, owned by the audio thread.
+pub struct MixerMeters {
+    /// One meter per track and bus, set up on graph changes so blocks never insert
+    strips: HashMap<MeterTarget, ChannelMeter>,
+    master: ChannelMeter,
+    producer: Producer<MeterFeedback>,
+    /// Frames rendered since the previous readings
+    pending_frames: usize,
+}
+
+impl MixerMeters {
+    pub fn new(producer: Producer<MeterFeedback>) -> Self {
+        Self {
+            strips: HashMap::with_capacity(64),
+            master: ChannelMeter::default(),
+            producer,
+            pending_frames: 0,
+        }
+    }
+
+    /// Gives every strip in `targets` a meter and drops the meters of strips that are gone.
+    /// Meters of the strips that stay keep their holds.
+    pub fn set_strips(&mut self, targets: impl IntoIterator<Item = MeterTarget>) {
+        let mut strips = HashMap::with_capacity(self.strips.len().max(64));
+        for target in targets {
+            if target != MeterTarget::Master {
+                let meter = self.strips.remove(&target).unwrap_or_default();
+                strips.insert(target, meter);
+            }
+        }
+        self.strips = strips;
+    }
+
+    /// Meter of a strip, `None` for a strip the mixer didn't have at the last graph change.
+    pub fn meter(&mut self, target: MeterTarget) -> Option<&mut ChannelMeter> {
+        match target {
+            MeterTarget::Master => Some(&mut self.master),
+            _ => self.strips.get_mut(&target),
+        }
+    }
+
+    pub fn master(&mut self) -> &mut ChannelMeter {
+        &mut self.master
+    }
+
+    /// Counts a rendered block and publishes the readings once a window is complete, returning
+    /// whether it did. Strips that went quiet are sent one last zero reading and then skipped
+    /// until they measure again.
+    pub fn advance(&mut self, frames: usize, sample_rate: u32) -> bool {
+        self.pending_frames += frames;
+        if self.pending_frames < ((sample_rate / METER_RATE_HZ).max(1) as usize) {
+            return false;
+        }
+        let window = std::mem::take(&mut self.pending_frames);
+
+        let meters = self.strips
+            .iter_mut()
+            .map(|(target, meter)| (*target, meter))
+            .chain(std::iter::once((MeterTarget::Master, &mut self.master)));
+        for (target, meter) in meters {
+            if meter.is_idle() {
+                continue;
+            }
+            let levels = meter.take_reading(window, sample_rate);
+            // Dropped readings are fine, the next window replaces them
+            let _ = self.producer.push(MeterFeedback { target, levels });
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn meter_reports_peak_rms_and_holds_clips() {
+        let sample_rate = 1_000;
+        let mut meter = ChannelMeter::default();
+
+        // Left a full-scale square wave, right silent
+        let buffer: Vec<f32> = (0..100)
+            .flat_map(|i| [if i % 2 == 0 { 1.0 } else { -1.0 }, 0.0])
+            .collect();
+        meter.measure(&buffer, 2);
+        let levels = meter.take_reading(100, sample_rate);
+        assert_eq!(levels.peak, [1.0, 0.0]);
+        assert!((levels.rms[0] - 1.0).abs() < 1e-6);
+        assert_eq!(levels.rms[1], 0.0);
+        assert!(levels.clipped);
+
+        // Quieter signal: the peak drops, the hold and clip stay
+        meter.measure(&[0.5, 0.5, -0.25, -0.25], 2);
+        let levels = meter.take_reading(2, sample_rate);
+        assert_eq!(levels.peak, [0.5, 0.5]);
+        assert_eq!(levels.peak_hold[0], 1.0);
+        assert!(levels.clipped);
+
+        // Past both holds everything falls back to the signal
+        let levels = meter.take_reading(sample_rate as usize * 4, sample_rate);
+        assert_eq!(levels.peak_hold, [0.0, 0.0]);
+        assert!(!levels.clipped);
+        assert!(meter.is_idle());
+    }
+
+    #[test]
+    fn only_strips_of_the_graph_are_metered() {
+        let (producer, mut readings) = rtrb::RingBuffer::new(16);
+        let mut meters = MixerMeters::new(producer);
+        let track = MeterTarget::Track(TrackId::from(1));
+        let bus = MeterTarget::Bus(BusId::from(1));
+        meters.set_strips([track, bus]);
+
+        if let Some(meter) = meters.meter(track) {
+            meter.measure(&[0.5, 0.5], 2);
+        }
+        assert!(meters.meter(MeterTarget::Track(TrackId::from(2))).is_none());
+        assert!(meters.advance(1_000, 1_000));
+        // The quiet bus and master have nothing to report
+        let sent: Vec<MeterTarget> = std::iter::from_fn(|| readings.pop().ok())
+            .map(|reading| reading.target)
+            .collect();
+        assert_eq!(sent, [track]);
+
+        // Removed strips lose their meter, the ones left keep their holds
+        meters.set_strips([track]);
+        assert!(meters.meter(bus).is_none());
+        assert_eq!(meters.meter(track).map(|meter| meter.peak_hold), Some([0.5, 0.5]));
+    }
+}
@@ -0,0 +1,356 @@
+// src/audio/metronome.rs
+//
+// Engine-owned click generator. Clicks are synthesized (a short decaying sine),
+// so the metronome needs no assets and never allocates on the audio thread.
+
+use crate::{
+    core::project::{
+        musical_time::TimeSignatureMap,
+        tempo_map::TempoMap,
+        transport::MetronomeSettings,
+    },
+    shared::id::BusId,
+};
+
+/// Pitch of the click on the first beat of a bar
+const ACCENT_FREQUENCY: f32 = 1760.0;
+/// Pitch of the other beats
+const BEAT_FREQUENCY: f32 = 880.0;
+/// Gain of a regular beat relative to the accented downbeat
+const BEAT_GAIN: f32 = 0.6;
+/// Length of one click in seconds
+const CLICK_SECONDS: f32 = 0.04;
+
+/// The click currently sounding.
+struct Click {
+    elapsed: u32,
+    length: u32,
+    phase_step: f32,
+    gain: f32,
+}
+
+/// Count-in in progress. The song playhead stays put until it has elapsed.
+struct CountIn {
+    elapsed: u32,
+    length: u32,
+    samples_per_beat: f64,
+    beats_per_bar: u32,
+}
+
+pub struct Metronome {
+    settings: MetronomeSettings,
+    click: Option<Click>,
+    count_in: Option<CountIn>,
+}
+
+impl Default for Metronome {
+    fn default() -> Self {
+        Self::new(MetronomeSettings::default())
+    }
+}
+
+impl Metronome {
+    pub fn new(settings: MetronomeSettings) -> Self {
+        Self {
+            settings,
+            click: None,
+            count_in: None,
+        }
+    }
+
+    pub fn settings(&self) -> &MetronomeSettings {
+        &self.settings
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.settings.enabled = enabled;
+    }
+
+    pub fn set_volume(&mut self, volume: f32) {
+        self.settings.volume = volume.clamp(0.0, 1.0);
+    }
+
+    pub fn set_target(&mut self, target: Option<BusId>) {
+        self.settings.target = target;
+    }
+
+    pub fn set_count_in_bars(&mut self, bars: u8) {
+        self.settings.count_in_bars = bars.min(MetronomeSettings::MAX_COUNT_IN_BARS);
+    }
+
+    pub fn target(&self) -> Option<BusId> {
+        self.settings.target
+    }
+
+    pub fn is_counting_in(&self) -> bool {
+        self.count_in.is_some()
+    }
+
+    /// Starts the configured count-in at `playhead_samples`, using the tempo and meter found there.
+    /// Does nothing when the count-in is disabled.
+    pub fn start_count_in(
+        &mut self,
+        tempo_map: &TempoMap,
+        time_signatures: &TimeSignatureMap,
+        playhead_samples: u32,
+        sample_rate: u32
+    ) {
+        let bars = self.settings.count_in_bars as u32;
+        if bars == 0 || sample_rate == 0 {
+            return;
+        }
+
+        let tick = tempo_map.sample_to_tick(playhead_samples as u64, sample_rate).ticks();
+        let signature = time_signatures.signature_at_tick(tick);
+        let bpm = tempo_map.bpm_at_tick(tick as f64).max(1.0) as f64;
+
+        // One signature beat lasts `4 / denominator` quarter notes
+        let quarter_seconds = 60.0 / bpm;
+        let beat_seconds = (quarter_seconds * 4.0) / (signature.denominator.max(1) as f64);
+        let samples_per_beat = beat_seconds * (sample_rate as f64);
+        let beats_per_bar = signature.numerator.max(1) as u32;
+
+        self.count_in = Some(CountIn {
+            elapsed: 0,
+            length: (samples_per_beat * ((bars * beats_per_bar) as f64)).round() as u32,
+            samples_per_beat,
+            beats_per_bar,
+        });
+    }
+
+    /// Silences the metronome immediately (transport stop).
+    pub fn reset(&mut self) {
+        self.click = None;
+        self.count_in = None;
+    }
+
+    /// Renders count-in clicks into `out` (interleaved, pre-zeroed) and returns how many frames
+    /// of the block the count-in used. The rest of the block belongs to song playback.
+    pub fn render_count_in(&mut self, out: &mut [f32], channels: usize, sample_rate: u32) -> usize {
+        let Some(count_in) = self.count_in.as_mut() else {
+            return 0;
+        };
+
+        let frames = out.len() / channels;
+        let consumed = frames.min(count_in.length.saturating_sub(count_in.elapsed) as usize);
+        let block_start = count_in.elapsed;
+        let block_end = block_start + (consumed as u32);
+
+        // Start from the beat at or before the block, rounding may place it inside the block
+        let mut beat = ((block_start as f64) / count_in.samples_per_beat).floor() as u32;
+        let samples_per_beat = count_in.samples_per_beat;
+        let beats_per_bar = count_in.beats_per_bar;
+        count_in.elapsed = block_end;
+        if count_in.elapsed >= count_in.length {
+            self.count_in = None;
+        }
+
+        let mut cursor = 0;
+        loop {
+            let beat_sample = ((beat as f64) * samples_per_beat).round() as u32;
+            if beat_sample >= block_end {
+                break;
+            }
+            if beat_sample >= block_start {
+                let offset = (beat_sample - block_start) as usize;
+                self.render_span(out, channels, cursor, offset);
+                self.trigger(beat.is_multiple_of(beats_per_bar), sample_rate);
+                cursor = offset;
+            }
+            beat += 1;
+        }
+        self.render_span(out, channels, cursor, consumed);
+
+        consumed
+    }
+
+    /// Renders the clicks of a song block starting at `block_start` (in samples) into `out`
+    /// (interleaved, pre-zeroed). Returns true when anything was written.
+    pub fn render_song_block(
+        &mut self,
+        out: &mut [f32],
+        channels: usize,
+        tempo_map: &TempoMap,
+        time_signatures: &TimeSignatureMap,
+        block_start: u32,
+        sample_rate: u32
+    ) -> bool {
+        if !self.settings.enabled && self.click.is_none() {
+            return false;
+        }
+
+        let frames = out.len() / channels;
+        let mut cursor = 0;
+
+        if self.settings.enabled {
+            let block_end = (block_start as u64) + (frames as u64);
+            let start_tick = tempo_map.sample_to_tick(block_start as u64, sample_rate).ticks();
+
+            // Start from the beat at or before the block, rounding may place it inside the block
+            let mut beat_tick = start_tick - (time_signatures.position_at(start_tick).tick as u64);
+            loop {
+                let beat_sample = tempo_map.tick_to_sample(beat_tick, sample_rate);
+                if beat_sample >= block_end {
+                    break;
+                }
+                let position = time_signatures.position_at(beat_tick);
+                if beat_sample >= (block_start as u64) {
+                    let offset = (beat_sample - (block_start as u64)) as usize;
+                    self.render_span(out, channels, cursor, offset);
+                    self.trigger(position.beat == 0, sample_rate);
+                    cursor = offset;
+                }
+                beat_tick += position.signature.ticks_per_beat();
+            }
+        }
+
+        self.render_span(out, channels, cursor, frames);
+        true
+    }
+
+    fn trigger(&mut self, accent: bool, sample_rate: u32) {
+        let frequency = if accent { ACCENT_FREQUENCY } else { BEAT_FREQUENCY };
+        let gain = if accent { 1.0 } else { BEAT_GAIN };
+        self.click = Some(Click {
+            elapsed: 0,
+            length: ((sample_rate as f32) * CLICK_SECONDS) as u32,
+            phase_step: (std::f32::consts::TAU * frequency) / (sample_rate.max(1) as f32),
+            gain,
+        });
+    }
+
+    /// Writes the sounding click into frames `from..to` of `out`.
+    fn render_span(&mut self, out: &mut [f32], channels: usize, from: usize, to: usize) {
+        let Some(click) = self.click.as_mut() else {
+            return;
+        };
+        let volume = self.settings.volume * click.gain;
+
+        for frame in out[from * channels..to * channels].chunks_exact_mut(channels) {
+            if click.elapsed >= click.length {
+                break;
+            }
+            let t = (click.elapsed as f32) / (click.length as f32);
+            // Exponential-ish decay that reaches silence at the end of the click
+            let envelope = (1.0 - t) * (1.0 - t);
+            let sample = (click.phase_step * (click.elapsed as f32)).sin() * envelope * volume;
+            for s in frame.iter_mut() {
+                *s += sample;
+            }
+            click.elapsed += 1;
+        }
+
+        if click.elapsed >= click.length {
+            self.click = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::project::{
+        musical_time::{ TimeSignature, TimeSignatureEvent },
+        tempo_map::{ TempoPoint, TempoRamp },
+    };
+
+    const SAMPLE_RATE: u32 = 48_000;
+
+    fn metronome() -> Metronome {
+        let mut metronome = Metronome::default();
+        metronome.set_enabled(true);
+        metronome.set_volume(1.0);
+        metronome
+    }
+
+    /// Start sample and peak of every click in a mono buffer. A click starts on `sin(0)`,
+    /// so its first sample is silent and the onset is the frame before the first nonzero one.
+    fn clicks(out: &[f32]) -> Vec<(usize, f32)> {
+        let mut clicks = Vec::new();
+        for i in 1..out.len() {
+            let silent_before = out[i.saturating_sub(64)..i].iter().all(|s| *s == 0.0);
+            if out[i] != 0.0 && silent_before {
+                let peak = out[i..(i + 100).min(out.len())]
+                    .iter()
+                    .fold(0.0f32, |peak, s| peak.max(s.abs()));
+                clicks.push((i - 1, peak));
+            }
+        }
+        clicks
+    }
+
+    fn is_accent(peak: f32) -> bool {
+        peak > (1.0 + BEAT_GAIN) / 2.0
+    }
+
+    #[test]
+    fn song_clicks_follow_meter_and_tempo_changes() {
+        // 3/4 at 120 BPM, dropping to 90 BPM on the second bar
+        let mut tempo_map = TempoMap::new(120.0);
+        tempo_map.insert_point(TempoPoint::new(3 * 960, 90.0, TempoRamp::Jump));
+        let signatures = TimeSignatureMap::new(TimeSignature::new(3, 4));
+
+        let beats = 9u64;
+        let frames = tempo_map.tick_to_sample(beats * 960, SAMPLE_RATE) as usize;
+        let mut out = vec![0.0; frames];
+        let mut metronome = metronome();
+        // An odd block size puts beats at arbitrary offsets inside the blocks
+        for (index, block) in out.chunks_mut(500).enumerate() {
+            let block_start = (index * 500) as u32;
+            metronome.render_song_block(
+                block,
+                1,
+                &tempo_map,
+                &signatures,
+                block_start,
+                SAMPLE_RATE
+            );
+        }
+
+        let clicks = clicks(&out);
+        assert_eq!(clicks.len(), beats as usize);
+        for (beat, (onset, peak)) in clicks.into_iter().enumerate() {
+            let expected = tempo_map.tick_to_sample((beat as u64) * 960, SAMPLE_RATE);
+            assert_eq!(onset as u64, expected, "beat {beat}");
+            assert_eq!(is_accent(peak), beat % 3 == 0, "beat {beat}");
+        }
+        // Beats are half a second apart before the change and two thirds after it
+        assert_eq!(tempo_map.tick_to_sample(3 * 960u64, SAMPLE_RATE), 72_000);
+        assert_eq!(tempo_map.tick_to_sample(4 * 960u64, SAMPLE_RATE), 104_000);
+    }
+
+    #[test]
+    fn count_in_uses_the_meter_and_tempo_at_the_playhead() {
+        // 90 BPM in 6/8 from bar 2: one eighth note lasts a third of a second
+        let mut tempo_map = TempoMap::new(120.0);
+        tempo_map.insert_point(TempoPoint::new(4 * 960, 90.0, TempoRamp::Jump));
+        let mut signatures = TimeSignatureMap::new(TimeSignature::COMMON);
+        signatures.insert(TimeSignatureEvent::new(1, TimeSignature::new(6, 8)));
+        let playhead = tempo_map.tick_to_sample(8 * 960u64, SAMPLE_RATE) as u32;
+
+        let mut metronome = metronome();
+        metronome.set_count_in_bars(2);
+        metronome.start_count_in(&tempo_map, &signatures, playhead, SAMPLE_RATE);
+        assert!(metronome.is_counting_in());
+
+        let eighth = 16_000;
+        let length = 2 * 6 * eighth;
+        let mut out = vec![0.0; length + 700];
+        let mut consumed = 0;
+        for block in out.chunks_mut(700) {
+            if !metronome.is_counting_in() {
+                break;
+            }
+            consumed += metronome.render_count_in(block, 1, SAMPLE_RATE);
+        }
+        assert_eq!(consumed, length);
+        assert!(!metronome.is_counting_in());
+
+        let clicks = clicks(&out);
+        assert_eq!(clicks.len(), 12);
+        for (beat, (onset, peak)) in clicks.into_iter().enumerate() {
+            assert_eq!(onset, beat * eighth, "beat {beat}");
+            assert_eq!(is_accent(peak), beat % 6 == 0, "beat {beat}");
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// src/audio/midi/alsa_raw.rs
+//
+// ALSA raw MIDI ports. Each port is listed as "<card> - <subdevice> (hw:C,D,S)" and opened by
+// its hw id. Input is read on a polling thread, output is written directly.
+
+use std::{
+    io::{ ErrorKind, Read, Write },
+    sync::{ Arc, atomic::{ AtomicBool, Ordering } },
+    thread::JoinHandle,
+};
+
+use alsa::{ Direction, ctl::Ctl, poll::Descriptors, rawmidi::{ self, Rawmidi } };
+use anyhow::{ Context, Result };
+
+use crate::audio::midi::{
+    MidiBackend,
+    MidiInputConnection,
+    MidiInputHandler,
+    MidiOutputConnection,
+    midi_clock_now,
+};
+
+/// How long the input thread waits for bytes before checking whether it should stop
+const INPUT_POLL_TIMEOUT_MS: i32 = 50;
+
+pub struct AlsaMidiBackend;
+
+impl AlsaMidiBackend {
+    fn ports(direction: Direction) -> Result<Vec<String>> {
+        let mut ports = Vec::new();
+        for card in alsa::card::Iter::new() {
+            let card = card?;
+            let ctl = Ctl::from_card(&card, false)?;
+            let card_name = card.get_name()?;
+            for info in rawmidi::Iter::new(&ctl) {
+                let info = info?;
+                if info.get_stream() != direction {
+                    continue;
+                }
+                ports.push(
+                    format!(
+                        "{} - {} (hw:{},{},{})",
+                        card_name,
+                        info.get_subdevice_name().unwrap_or_default(),
+                        card.get_index(),
+                        info.get_device(),
+                        info.get_subdevice()
+                    )
+                );
+            }
+        }
+        Ok(ports)
+    }
+}
+
+/// The hw id in a listed port name, or the name itself when it is already an id.
+fn device_id(port: &str) -> &str {
+    port.rsplit_once('(')
+        .and_then(|(_, id)| id.strip_suffix(')'))
+        .unwrap_or(port)
+}
+
+impl MidiBackend for AlsaMidiBackend {
+    fn name(&self) -> &str {
+        "ALSA"
+    }
+
+    fn input_ports(&self) -> Result<Vec<String>> {
+        Self::ports(Direction::Capture)
+    }
+
+    fn output_ports(&self) -> Result<Vec<String>> {
+        Self::ports(Direction::Playback)
+    }
+
+    fn open_input(&self, port: &str, mut handler: MidiInputHandler) -> Result<Box<dyn MidiInputConnection>> {
+        let midi = Rawmidi::new(device_id(port), Direction::Capture, true).with_context(||
+            format!("Failed to open MIDI input '{}'", port)
+        )?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let reader_stop = Arc::clone(&stop);
+        let name = port.to_string();
+        let reader = std::thread::Builder
+            ::new()
+            .name("karbeat-midi-in".into())
+            .spawn(move || {
+                let mut fds = match midi.get() {
+                    Ok(fds) => fds,
+                    Err(e) => {
+                        log::error!("MIDI input {}: {}", name, e);
+                        return;
+                    }
+                };
+                let mut buffer = [0u8; 256];
+                while !reader_stop.load(Ordering::Acquire) {
+                    if let Err(e) = alsa::poll::poll(&mut fds, INPUT_POLL_TIMEOUT_MS) {
+                        log::error!("MIDI input {}: {}", name, e);
+                        return;
+                    }
+                    loop {
+                        match midi.io().read(&mut buffer) {
+                            Ok(0) => break,
+                            Ok(read) => handler(midi_clock_now(), &buffer[..read]),
+                            Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                            Err(e) => {
+                                log::error!("MIDI input {}: {}", name, e);
+                                return;
+                            }
+                        }
+                    }
+                }
+            })
+            .context("Failed to spawn MIDI input thread")?;
+
+        Ok(
+            Box::new(AlsaInput {
+                name: port.to_string(),
+                stop,
+                reader: Some(reader),
+            })
+        )
+    }
+
+    fn open_output(&self, port: &str) -> Result<Box<dyn MidiOutputConnection>> {
+        let midi = Rawmidi::new(device_id(port), Direction::Playback, false).with_context(||
+            format!("Failed to open MIDI output '{}'", port)
+        )?;
+        Ok(Box::new(AlsaOutput { name: port.to_string(), midi }))
+    }
+}
+
+struct AlsaInput {
+    name: String,
+    stop: Arc<AtomicBool>,
+    reader: Option<JoinHandle<()>>,
+}
+
+impl MidiInputConnection for AlsaInput {
+    fn port_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Drop for AlsaInput {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+    }
+}
+
+struct AlsaOutput {
+    name: String,
+    midi: Rawmidi,
+}
+
+impl MidiOutputConnection for AlsaOutput {
+    fn port_name(&self) -> &str {
+        &self.name
+    }
+
+    fn send(&mut self, bytes: &[u8]) -> Result<()> {
+        self.midi.io().write_all(bytes)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,631 @@
+// src/audio/midi/mod.rs
+//
+// Hardware MIDI I/O. A `MidiBackend` enumerates and opens ports. Input bytes are parsed on the
+// port's thread, stamped with the MIDI clock and pushed into a ring the engine drains once per
+// block. Output goes the other way: the engine stamps messages with the time they are due and a
+// sender thread writes them to the port once that time comes.
+
+#[cfg(target_os = "linux")]
+pub mod alsa_raw;
+pub mod virtual_port;
+
+use std::{
+    ops::Range,
+    sync::{ Arc, atomic::{ AtomicBool, Ordering } },
+    thread::JoinHandle,
+    time::{ Duration, Instant },
+};
+
+use anyhow::{ Context, Result };
+use once_cell::sync::Lazy;
+use rtrb::{ Consumer, Producer, RingBuffer };
+
+use crate::{
+    core::project::{ plugin::{ MidiEvent, MidiMessage }, tempo_map::{ TICKS_PER_BEAT, TempoMap } },
+    shared::id::TrackId,
+};
+
+/// Messages an input port can queue before the engine drains them
+const INPUT_RING_CAPACITY: usize = 1024;
+/// Messages the engine can queue before the sender thread writes them
+const OUTPUT_RING_CAPACITY: usize = 1024;
+/// Input messages dispatched in one block, the rest wait for the next block
+const MAX_EVENTS_PER_BLOCK: usize = 256;
+/// How often the sender thread checks for due messages
+const SENDER_POLL_INTERVAL: Duration = Duration::from_millis(1);
+/// MIDI clock runs at 24 pulses per quarter note
+const CLOCK_PULSE_TICKS: u64 = (TICKS_PER_BEAT as u64) / 24;
+/// Song position pointer counts sixteenth notes
+const SONG_POSITION_TICKS: u64 = (TICKS_PER_BEAT as u64) / 4;
+
+const STATUS_CLOCK: u8 = 0xf8;
+const STATUS_START: u8 = 0xfa;
+const STATUS_CONTINUE: u8 = 0xfb;
+const STATUS_STOP: u8 = 0xfc;
+const STATUS_SONG_POSITION: u8 = 0xf2;
+
+static CLOCK_EPOCH: Lazy<Instant> = Lazy::new(Instant::now);
+
+/// Time on the clock shared by input stamping and output scheduling.
+pub fn midi_clock_now() -> Duration {
+    CLOCK_EPOCH.elapsed()
+}
+
+/// Receives the raw bytes read from an input port along with the time they arrived.
+pub type MidiInputHandler = Box<dyn FnMut(Duration, &[u8]) + Send>;
+
+/// A platform MIDI API, or an in-memory stand-in.
+pub trait MidiBackend: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn input_ports(&self) -> Result<Vec<String>>;
+
+    fn output_ports(&self) -> Result<Vec<String>>;
+
+    /// Opens an input port. Bytes are handed to `handler` until the connection is dropped.
+    fn open_input(&self, port: &str, handler: MidiInputHandler) -> Result<Box<dyn MidiInputConnection>>;
+
+    fn open_output(&self, port: &str) -> Result<Box<dyn MidiOutputConnection>>;
+}
+
+/// An open input port, closed when dropped.
+pub trait MidiInputConnection: Send {
+    fn port_name(&self) -> &str;
+}
+
+/// An open output port, closed when dropped.
+pub trait MidiOutputConnection: Send {
+    fn port_name(&self) -> &str;
+
+    /// Writes one complete message.
+    fn send(&mut self, bytes: &[u8]) -> Result<()>;
+}
+
+/// The MIDI API of the platform.
+pub fn system_backend() -> Arc<dyn MidiBackend> {
+    #[cfg(target_os = "linux")]
+    {
+        Arc::new(alsa_raw::AlsaMidiBackend)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        // No hardware backend yet, an empty virtual backend lists no ports
+        Arc::new(virtual_port::VirtualMidiBackend::default())
+    }
+}
+
+// =========================================================================
+// Wire format
+// =========================================================================
+
+/// Turns a raw byte stream into messages and their channel. Handles running status and messages
+/// split across reads. Realtime, system common and SysEx messages are skipped.
+#[derive(Default)]
+pub struct MidiParser {
+    /// Running status, 0 when there is none
+    status: u8,
+    data: [u8; 2],
+    len: usize,
+    in_sysex: bool,
+}
+
+impl MidiParser {
+    pub fn feed(&mut self, bytes: &[u8], mut emit: impl FnMut(u8, MidiMessage)) {
+        for &byte in bytes {
+            // Realtime bytes may appear anywhere, even inside another message
+            if byte >= STATUS_CLOCK {
+                continue;
+            }
+
+            if byte & 0x80 != 0 {
+                self.in_sysex = byte == 0xf0;
+                // System common messages cancel the running status
+                self.status = if byte < 0xf0 { byte } else { 0 };
+                self.len = 0;
+                continue;
+            }
+            if self.in_sysex || self.status == 0 {
+                continue;
+            }
+
+            self.data[self.len] = byte;
+            self.len += 1;
+            if self.len == data_len(self.status) {
+                self.len = 0;
+                if let Some(message) = decode(self.status, self.data) {
+                    emit(self.status & 0x0f, message);
+                }
+            }
+        }
+    }
+}
+
+fn data_len(status: u8) -> usize {
+    match status & 0xf0 {
+        0xc0 | 0xd0 => 1,
+        _ => 2,
+    }
+}
+
+/// Decodes a channel message without its channel. Program changes are not supported.
+fn decode(status: u8, data: [u8; 2]) -> Option<MidiMessage> {
+    let message = match status & 0xf0 {
+        0x80 => MidiMessage::NoteOff { key: data[0] },
+        0x90 if data[1] == 0 => MidiMessage::NoteOff { key: data[0] },
+        0x90 => MidiMessage::NoteOn { key: data[0], velocity: data[1] },
+        0xa0 => MidiMessage::PolyAftertouch { key: data[0], pressure: data[1] },
+        0xb0 => MidiMessage::ControlChange { controller: data[0], value: data[1] },
+        0xd0 => MidiMessage::ChannelPressure { pressure: data[0] },
+        0xe0 => MidiMessage::PitchBend { value: (data[0] as u16) | ((data[1] as u16) << 7) },
+        _ => {
+            return None;
+        }
+    };
+    Some(message)
+}
+
+/// A message on its way to an output port.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutgoingMidi {
+    /// Time on the MIDI clock when the message should be heard
+    pub due: Duration,
+    bytes: [u8; 3],
+    len: u8,
+}
+
+impl OutgoingMidi {
+    pub fn message(due: Duration, message: MidiMessage, channel: u8) -> Self {
+        let channel = channel & 0x0f;
+        let (status, data, len) = match message {
+            MidiMessage::NoteOn { key, velocity } => (0x90, [key, velocity], 3),
+            MidiMessage::NoteOff { key } => (0x80, [key, 0], 3),
+            MidiMessage::ControlChange { controller, value } => (0xb0, [controller, value], 3),
+            MidiMessage::PitchBend { value } => (0xe0, [value as u8, (value >> 7) as u8], 3),
+            MidiMessage::PolyAftertouch { key, pressure } => (0xa0, [key, pressure], 3),
+            MidiMessage::ChannelPressure { pressure } => (0xd0, [pressure, 0], 2),
+        };
+        Self {
+            due,
+            bytes: [status | channel, data[0] & 0x7f, data[1] & 0x7f],
+            len,
+        }
+    }
+
+    fn realtime(due: Duration, status: u8) -> Self {
+        Self { due, bytes: [status, 0, 0], len: 1 }
+    }
+
+    /// Song position pointer, `tick` is rounded down to a sixteenth note.
+    fn song_position(due: Duration, tick: u64) -> Self {
+        let sixteenths = (tick / SONG_POSITION_TICKS).min(0x3fff) as u16;
+        Self {
+            due,
+            bytes: [STATUS_SONG_POSITION, (sixteenths & 0x7f) as u8, (sixteenths >> 7) as u8],
+            len: 3,
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+}
+
+// =========================================================================
+// Input
+// =========================================================================
+
+/// A parsed input message with the time it arrived.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimedMidiMessage {
+    pub time: Duration,
+    pub message: MidiMessage,
+}
+
+/// Input stream handed to the audio thread.
+pub struct MidiInputPort {
+    consumer: Consumer<TimedMidiMessage>,
+}
+
+/// Creates the path from an input port to the engine. The handler goes to
+/// `MidiBackend::open_input`, the port to the engine via `AudioCommand::SetMidiInput`.
+///
+/// Control changes are offered to `controls` (channel, controller, value) first, those it
+/// returns true for don't reach the engine. Channels are merged (omni) from there on.
+pub fn input_channel(
+    mut controls: impl FnMut(u8, u8, u8) -> bool + Send + 'static
+) -> (MidiInputHandler, MidiInputPort) {
+    let (mut producer, consumer) = RingBuffer::new(INPUT_RING_CAPACITY);
+    let mut parser = MidiParser::default();
+    let handler: MidiInputHandler = Box::new(move |time, bytes| {
+        parser.feed(bytes, |channel, message| {
+            if let MidiMessage::ControlChange { controller, value } = message {
+                if controls(channel, controller, value) {
+                    return;
+                }
+            }
+            let _ = producer.push(TimedMidiMessage { time, message });
+        });
+    });
+    (handler, MidiInputPort { consumer })
+}
+
+/// Engine-owned MIDI input: the messages of the current block with their frame offsets.
+pub struct LiveMidiInput {
+    port: Option<MidiInputPort>,
+    /// Track that plays the input when no MIDI track is armed
+    target: Option<TrackId>,
+    /// (frame offset in the block, message), in arrival order
+    events: Vec<(usize, MidiMessage)>,
+}
+
+impl Default for LiveMidiInput {
+    fn default() -> Self {
+        Self {
+            port: None,
+            target: None,
+            events: Vec::with_capacity(MAX_EVENTS_PER_BLOCK),
+        }
+    }
+}
+
+impl LiveMidiInput {
+    pub fn set_port(&mut self, port: Option<MidiInputPort>) {
+        self.port = port;
+    }
+
+    pub fn set_target(&mut self, target: Option<TrackId>) {
+        self.target = target;
+    }
+
+    pub fn target(&self) -> Option<TrackId> {
+        self.target
+    }
+
+    /// Collects the messages for a block of `frames` starting at `now`.
+    /// Messages that arrived during the previous block period are replayed over this block
+    /// with the same spacing: one block of latency in exchange for jitter-free timing.
+    pub fn pull(&mut self, frames: usize, sample_rate: u32, now: Duration) {
+        self.events.clear();
+        let Some(port) = self.port.as_mut() else {
+            return;
+        };
+        if frames == 0 {
+            return;
+        }
+
+        let block = Duration::from_secs_f64((frames as f64) / (sample_rate.max(1) as f64));
+        let window_start = now.saturating_sub(block);
+        while self.events.len() < MAX_EVENTS_PER_BLOCK {
+            let Ok(event) = port.consumer.pop() else {
+                break;
+            };
+            let elapsed = event.time.saturating_sub(window_start).as_secs_f64();
+            let offset = ((elapsed * (sample_rate as f64)).round() as usize).min(frames - 1);
+            // Arrival order wins over rounding, offsets never go backwards
+            let offset = offset.max(self.events.last().map_or(0, |(last, _)| *last));
+            self.events.push((offset, event.message));
+        }
+    }
+
+    pub fn has_events(&self) -> bool {
+        !self.events.is_empty()
+    }
+
+    /// Indices of the messages that fall in frames `start..start + frames` of the block.
+    pub fn span(&self, start: usize, frames: usize) -> Range<usize> {
+        let from = self.events.partition_point(|(offset, _)| *offset < start);
+        let to = self.events.partition_point(|(offset, _)| *offset < start + frames);
+        from..to
+    }
+
+    /// Message at `index` with its offset relative to `start`.
+    pub fn event(&self, index: usize, start: usize) -> (usize, MidiMessage) {
+        let (offset, message) = self.events[index];
+        (offset - start, message)
+    }
+}
+
+// =========================================================================
+// Output
+// =========================================================================
+
+/// Output stream handed to the audio thread.
+pub struct MidiOutputPort {
+    producer: Producer<OutgoingMidi>,
+}
+
+/// What the engine sends to the output port.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MidiOutputSettings {
+    /// Send clock pulses and start/stop while the song plays
+    pub send_clock: bool,
+    /// Track whose notes (sequenced and live) are sent out
+    pub track: Option<TrackId>,
+    /// Channel of outgoing notes (0 - 15)
+    pub channel: u8,
+}
+
+/// Engine-owned MIDI output: stamps clock and note messages with the time they are due.
+#[derive(Default)]
+pub struct MidiOutput {
+    port: Option<MidiOutputPort>,
+    settings: MidiOutputSettings,
+    /// MIDI clock time of the first frame of the current block
+    block_time: Duration,
+    sample_rate: u32,
+    /// Playback started, start/continue goes out with the first song block (after the count-in)
+    start_pending: bool,
+    /// Start/continue was sent and no stop since
+    rolling: bool,
+}
+
+impl MidiOutput {
+    pub fn set_port(&mut self, port: Option<MidiOutputPort>) {
+        self.port = port;
+    }
+
+    pub fn set_settings(&mut self, settings: MidiOutputSettings) {
+        self.settings = settings;
+    }
+
+    pub fn begin_block(&mut self, now: Duration, sample_rate: u32) {
+        self.block_time = now;
+        self.sample_rate = sample_rate;
+    }
+
+    fn due(&self, frame: usize) -> Duration {
+        self.block_time + Duration::from_secs_f64((frame as f64) / (self.sample_rate.max(1) as f64))
+    }
+
+    fn push(&mut self, message: OutgoingMidi) {
+        if let Some(port) = self.port.as_mut() {
+            let _ = port.producer.push(message);
+        }
+    }
+
+    fn push_transport(&mut self, message: OutgoingMidi) {
+        if self.settings.send_clock {
+            self.push(message);
+        }
+    }
+
+    /// Song playback was started. Start (or continue from the song position) is sent with the
+    /// first clock pulse block.
+    pub fn transport_started(&mut self) {
+        self.start_pending = true;
+    }
+
+    pub fn transport_stopped(&mut self) {
+        self.start_pending = false;
+        if self.rolling {
+            self.rolling = false;
+            let due = self.due(0);
+            self.push_transport(OutgoingMidi::realtime(due, STATUS_STOP));
+        }
+    }
+
+    /// The playhead jumped to `tick` (loop wrap, seek) at `frame` of the block.
+    pub fn transport_jumped(&mut self, tick: u64, frame: usize) {
+        if !self.rolling {
+            return;
+        }
+        let due = self.due(frame);
+        self.push_transport(OutgoingMidi::realtime(due, STATUS_STOP));
+        self.push_transport(OutgoingMidi::song_position(due, tick));
+        self.push_transport(OutgoingMidi::realtime(due, STATUS_CONTINUE));
+    }
+
+    /// Sends start/continue if playback just started and the clock pulses that fall in a song slice of `frames` starting at playhead
+    /// `start_sample`, which is at `frame` of the block.
+    pub fn send_clock(
+        &mut self,
+        tempo_map: &TempoMap,
+        start_sample: u32,
+        frames: usize,
+        frame: usize
+    ) {
+        let sample_rate = self.sample_rate;
+        let start_tick = tempo_map.sample_to_tick(start_sample as u64, sample_rate).ticks();
+        if self.start_pending {
+            self.start_pending = false;
+            self.rolling = true;
+            let due = self.due(frame);
+            if start_tick == 0 {
+                self.push_transport(OutgoingMidi::realtime(due, STATUS_START));
+            } else {
+                self.push_transport(OutgoingMidi::song_position(due, start_tick));
+                self.push_transport(OutgoingMidi::realtime(due, STATUS_CONTINUE));
+            }
+        }
+        if !self.settings.send_clock || self.port.is_none() {
+            return;
+        }
+
+        let end_sample = (start_sample as u64) + (frames as u64);
+
+        let mut pulse = start_tick.div_ceil(CLOCK_PULSE_TICKS) * CLOCK_PULSE_TICKS;
+        loop {
+            let pulse_sample = tempo_map.tick_to_sample(pulse, sample_rate);
+            if pulse_sample >= end_sample {
+                break;
+            }
+            if pulse_sample >= (start_sample as u64) {
+                let offset = (pulse_sample - (start_sample as u64)) as usize;
+                let due = self.due(frame + offset);
+                self.push(OutgoingMidi::realtime(due, STATUS_CLOCK));
+            }
+            pulse += CLOCK_PULSE_TICKS;
+        }
+    }
+
+    /// Sends the notes `track_id` plays in a slice starting at `frame` of the block.
+    pub fn send_track_events(&mut self, track_id: TrackId, frame: usize, events: &[MidiEvent]) {
+        if self.settings.track != Some(track_id) || self.port.is_none() {
+            return;
+        }
+        let channel = self.settings.channel;
+        for event in events {
+            let due = self.due(frame + event.sample_offset);
+            self.push(OutgoingMidi::message(due, event.data, channel));
+        }
+    }
+}
+
+/// UI side of the MIDI output: owns the thread that writes due messages to the port.
+/// The thread stops and the port closes when the handle is dropped.
+pub struct MidiOutputHandle {
+    port_name: String,
+    stop: Arc<AtomicBool>,
+    sender: Option<JoinHandle<()>>,
+}
+
+impl MidiOutputHandle {
+    /// Starts the sender thread. `latency` is how long after the engine renders a frame it is
+    /// heard, outgoing messages wait that long so they line up with the audio.
+    /// The returned port is sent to the engine via `AudioCommand::SetMidiOutput`.
+    pub fn start(
+        mut connection: Box<dyn MidiOutputConnection>,
+        latency: impl Fn() -> Duration + Send + 'static
+    ) -> Result<(Self, MidiOutputPort)> {
+        let (producer, mut consumer) = RingBuffer::<OutgoingMidi>::new(OUTPUT_RING_CAPACITY);
+        let stop = Arc::new(AtomicBool::new(false));
+        let port_name = connection.port_name().to_string();
+
+        let sender_stop = Arc::clone(&stop);
+        let sender = std::thread::Builder
+            ::new()
+            .name("karbeat-midi-out".into())
+            .spawn(move || {
+                // The engine queues clock pulses before the notes of a block, so messages
+                // are put back in time order here
+                let mut pending: Vec<OutgoingMidi> = Vec::with_capacity(OUTPUT_RING_CAPACITY);
+                while !sender_stop.load(Ordering::Acquire) {
+                    let received = pending.len();
+                    while let Ok(message) = consumer.pop() {
+                        pending.push(message);
+                    }
+                    if pending.len() > received {
+                        pending.sort_by_key(|message| message.due);
+                    }
+
+                    let now = midi_clock_now();
+                    let latency = latency();
+                    let due = pending.partition_point(|message| message.due + latency <= now);
+                    for message in pending.drain(..due) {
+                        if let Err(e) = connection.send(message.bytes()) {
+                            log::warn!("MIDI output {}: {}", connection.port_name(), e);
+                        }
+                    }
+                    std::thread::sleep(SENDER_POLL_INTERVAL);
+                }
+            })
+            .context("Failed to spawn MIDI output thread")?;
+
+        let handle = Self {
+            port_name,
+            stop,
+            sender: Some(sender),
+        };
+        Ok((handle, MidiOutputPort { producer }))
+    }
+
+    pub fn port_name(&self) -> &str {
+        &self.port_name
+    }
+}
+
+impl Drop for MidiOutputHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(sender) = self.sender.take() {
+            let _ = sender.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::audio::midi::virtual_port::VirtualMidiBackend;
+
+    #[test]
+    fn virtual_port_input_reaches_the_block_at_its_offset() -> Result<()> {
+        let backend = VirtualMidiBackend::default();
+        backend.add_input("Keys");
+        let (handler, port) = input_channel(|_, controller, _| controller == 1);
+        let _connection = backend.open_input("Keys", handler)?;
+
+        // 480 frames at 48 kHz: the block replays what arrived in the 10 ms before `now`
+        let now = Duration::from_millis(100);
+        assert!(backend.send("Keys", Duration::from_millis(90), &[0x90, 60, 100]));
+        // Running status, then a pitch bend split across two reads
+        assert!(backend.send("Keys", Duration::from_millis(95), &[64, 0]));
+        assert!(backend.send("Keys", Duration::from_millis(95), &[0xe1, 0x00]));
+        assert!(backend.send("Keys", Duration::from_millis(95), &[0x40]));
+        assert!(backend.send("Keys", Duration::from_micros(97_500), &[0xd0, 33, 0xa0, 60, 12]));
+        // The mod wheel is taken by the control hook, the sustain pedal passes through
+        assert!(backend.send("Keys", Duration::from_millis(99), &[0xb3, 1, 90, 64, 127]));
+
+        let mut input = LiveMidiInput::default();
+        input.set_port(Some(port));
+        input.pull(480, 48_000, now);
+
+        let events: Vec<_> = input
+            .span(0, 480)
+            .map(|index| input.event(index, 0))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                (0, MidiMessage::NoteOn { key: 60, velocity: 100 }),
+                (240, MidiMessage::NoteOff { key: 64 }),
+                (240, MidiMessage::PitchBend { value: MidiMessage::PITCH_BEND_CENTER }),
+                (360, MidiMessage::ChannelPressure { pressure: 33 }),
+                (360, MidiMessage::PolyAftertouch { key: 60, pressure: 12 }),
+                (432, MidiMessage::ControlChange { controller: 64, value: 127 })
+            ]
+        );
+        assert_eq!(input.span(300, 180), 3..6);
+        assert_eq!(input.event(3, 300).0, 60);
+        Ok(())
+    }
+
+    #[test]
+    fn clock_and_notes_go_out_through_the_virtual_port() -> Result<()> {
+        let backend = VirtualMidiBackend::default();
+        backend.add_output("Synth");
+        let connection = backend.open_output("Synth")?;
+        let (handle, port) = MidiOutputHandle::start(connection, || Duration::ZERO)?;
+
+        let mut output = MidiOutput::default();
+        output.set_port(Some(port));
+        output.set_settings(MidiOutputSettings {
+            send_clock: true,
+            track: Some(TrackId::from(1)),
+            channel: 2,
+        });
+        // Due a little later so the sender sees the whole block before writing it
+        output.begin_block(midi_clock_now() + Duration::from_millis(50), 48_000);
+        output.transport_started();
+        // 120 BPM: one beat is 24000 frames, a pulse every 1000 frames
+        output.send_clock(&TempoMap::new(120.0), 0, 2500, 0);
+        output.send_track_events(TrackId::from(1), 0, &[
+            MidiEvent { sample_offset: 10, data: MidiMessage::NoteOn { key: 60, velocity: 90 } },
+        ]);
+        output.send_track_events(TrackId::from(2), 0, &[
+            MidiEvent { sample_offset: 10, data: MidiMessage::NoteOn { key: 62, velocity: 90 } },
+        ]);
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while backend.sent("Synth").len() < 5 && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        drop(handle);
+
+        assert_eq!(
+            backend.sent("Synth"),
+            vec![vec![0xfa], vec![0xf8], vec![0x92, 60, 90], vec![0xf8], vec![0xf8]]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+// src/audio/midi/virtual_port.rs
+//
+// In-memory MIDI backend. Ports exist only inside the process: bytes sent into an input port
+// reach whoever opened it, bytes written to an output port are kept until read back.
+// Stands in for hardware in tests and on platforms without a backend.
+
+use std::{ sync::Arc, time::Duration };
+
+use anyhow::{ Result, anyhow };
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+
+use crate::audio::midi::{ MidiBackend, MidiInputConnection, MidiInputHandler, MidiOutputConnection };
+
+#[derive(Default)]
+struct VirtualPorts {
+    /// Input ports and the handler of the connection that opened them
+    inputs: IndexMap<String, Option<MidiInputHandler>>,
+    /// Output ports and the messages written to them
+    outputs: IndexMap<String, Vec<Vec<u8>>>,
+}
+
+#[derive(Clone, Default)]
+pub struct VirtualMidiBackend {
+    ports: Arc<Mutex<VirtualPorts>>,
+}
+
+impl VirtualMidiBackend {
+    pub fn add_input(&self, name: &str) {
+        self.ports.lock().inputs.entry(name.to_string()).or_default();
+    }
+
+    pub fn add_output(&self, name: &str) {
+        self.ports.lock().outputs.entry(name.to_string()).or_default();
+    }
+
+    /// Plays `bytes` into an input port as if they arrived at `time` (MIDI clock).
+    /// Returns false when nobody has the port open.
+    pub fn send(&self, port: &str, time: Duration, bytes: &[u8]) -> bool {
+        let mut ports = self.ports.lock();
+        match ports.inputs.get_mut(port) {
+            Some(Some(handler)) => {
+                handler(time, bytes);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Messages written to an output port so far.
+    pub fn sent(&self, port: &str) -> Vec<Vec<u8>> {
+        self.ports.lock().outputs.get(port).cloned().unwrap_or_default()
+    }
+}
+
+impl MidiBackend for VirtualMidiBackend {
+    fn name(&self) -> &str {
+        "Virtual"
+    }
+
+    fn input_ports(&self) -> Result<Vec<String>> {
+        Ok(self.ports.lock().inputs.keys().cloned().collect())
+    }
+
+    fn output_ports(&self) -> Result<Vec<String>> {
+        Ok(self.ports.lock().outputs.keys().cloned().collect())
+    }
+
+    fn open_input(&self, port: &str, handler: MidiInputHandler) -> Result<Box<dyn MidiInputConnection>> {
+        let mut ports = self.ports.lock();
+        let slot = ports.inputs
+            .get_mut(port)
+            .ok_or_else(|| anyhow!("MIDI input '{}' not found", port))?;
+        if slot.is_some() {
+            return Err(anyhow!("MIDI input '{}' is already open", port));
+        }
+        *slot = Some(handler);
+
+        Ok(
+            Box::new(VirtualInput {
+                name: port.to_string(),
+                ports: Arc::clone(&self.ports),
+            })
+        )
+    }
+
+    fn open_output(&self, port: &str) -> Result<Box<dyn MidiOutputConnection>> {
+        if !self.ports.lock().outputs.contains_key(port) {
+            return Err(anyhow!("MIDI output '{}' not found", port));
+        }
+        Ok(
+            Box::new(VirtualOutput {
+                name: port.to_string(),
+                ports: Arc::clone(&self.ports),
+            })
+        )
+    }
+}
+
+struct VirtualInput {
+    name: String,
+    ports: Arc<Mutex<VirtualPorts>>,
+}
+
+impl MidiInputConnection for VirtualInput {
+    fn port_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Drop for VirtualInput {
+    fn drop(&mut self) {
+        if let Some(slot) = self.ports.lock().inputs.get_mut(&self.name) {
+            *slot = None;
+        }
+    }
+}
+
+struct VirtualOutput {
+    name: String,
+    ports: Arc<Mutex<VirtualPorts>>,
+}
+
+impl MidiOutputConnection for VirtualOutput {
+    fn port_name(&self) -> &str {
+        &self.name
+    }
+
+    fn send(&mut self, bytes: &[u8]) -> Result<()> {
+        let mut ports = self.ports.lock();
+        let sent = ports.outputs
+            .get_mut(&self.name)
+            .ok_or_else(|| anyhow!("MIDI output '{}' was removed", self.name))?;
+        sent.push(bytes.to_vec());
+        Ok(())
+    }
+}
@@ -1,6 +1,16 @@
 pub mod writer;
+pub mod analyzer;
+pub mod automation;
 pub mod backend;
 pub mod engine;
 pub mod event;
 pub mod exporter;
+pub mod latency;
+pub mod load;
+pub mod metering;
+pub mod metronome;
+pub mod midi;
+pub mod note_recorder;
+pub mod recorder;
+pub mod render_pool;
 pub mod render_state;
\ No newline at end of file
@@ -0,0 +1,227 @@
+// src/audio/note_recorder.rs
+//
+// Live note capture. The engine stamps note on/off events with the song position (in ticks)
+// and pushes them into a ring; the UI side drains it when recording stops and pairs the events
+// into notes.
+
+use std::time::{ Duration, Instant };
+
+use rtrb::{ Consumer, Producer, RingBuffer };
+
+use crate::shared::id::TrackId;
+
+/// Number of events a take can hold before the UI drains it
+const NOTE_RING_CAPACITY: usize = 8192;
+/// How long `finish` waits for the audio thread to close a stopped take
+const RELEASE_TIMEOUT: Duration = Duration::from_millis(500);
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// A live note event on the song timeline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RecordedNoteEvent {
+    /// Song playback started rolling at `tick`
+    Start {
+        tick: u64,
+    },
+    On {
+        track_id: TrackId,
+        key: u8,
+        velocity: u8,
+        tick: u64,
+    },
+    Off {
+        track_id: TrackId,
+        key: u8,
+        tick: u64,
+    },
+    /// Song playback jumped from the loop end at `tick` back to the loop start. Notes still
+    /// held end here
+    LoopWrap {
+        tick: u64,
+    },
+    /// Recording stopped at `tick`, notes still held end here
+    End {
+        tick: u64,
+    },
+}
+
+/// A note played during a take, on the song timeline (ticks).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecordedNote {
+    pub track_id: TrackId,
+    pub key: u8,
+    pub velocity: u8,
+    pub start_tick: u64,
+    pub end_tick: u64,
+}
+
+/// Notes of a finished take along with the range it covered (ticks).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecordedNotes {
+    pub notes: Vec<RecordedNote>,
+    /// Where song playback started rolling. `None` when it never did
+    pub start_tick: Option<u64>,
+    pub end_tick: Option<u64>,
+}
+
+/// Audio thread side of a note take.
+pub struct NoteTake {
+    sink: Producer<RecordedNoteEvent>,
+    started: bool,
+}
+
+impl NoteTake {
+    /// Marks where the take starts, only the first call counts.
+    pub fn mark_start(&mut self, tick: u64) {
+        if !self.started {
+            self.started = true;
+            self.push(RecordedNoteEvent::Start { tick });
+        }
+    }
+
+    /// Records an event, dropping it if the UI side stopped draining.
+    pub fn push(&mut self, event: RecordedNoteEvent) {
+        let _ = self.sink.push(event);
+    }
+}
+
+/// UI side of a note take.
+pub struct NoteTakeHandle {
+    source: Consumer<RecordedNoteEvent>,
+}
+
+impl NoteTakeHandle {
+    /// Creates a take. The returned `NoteTake` is sent to the audio thread.
+    pub fn start() -> (Self, NoteTake) {
+        let (sink, source) = RingBuffer::new(NOTE_RING_CAPACITY);
+        (Self { source }, NoteTake { sink, started: false })
+    }
+
+    /// Collects the recorded notes once the audio thread has closed the take.
+    /// `AudioCommand::StopNoteRecording` must be sent first.
+    pub fn finish(mut self) -> RecordedNotes {
+        let deadline = Instant::now() + RELEASE_TIMEOUT;
+        let mut events = Vec::new();
+
+        loop {
+            while let Ok(event) = self.source.pop() {
+                events.push(event);
+            }
+            let closed = matches!(events.last(), Some(RecordedNoteEvent::End { .. }));
+            if closed || self.source.is_abandoned() || Instant::now() >= deadline {
+                break;
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+
+        let start_tick = events.iter().find_map(|event| match event {
+            RecordedNoteEvent::Start { tick } => Some(*tick),
+            _ => None,
+        });
+        let end_tick = events.iter().find_map(|event| match event {
+            RecordedNoteEvent::End { tick } => Some(*tick),
+            _ => None,
+        });
+        RecordedNotes {
+            notes: pair_note_events(&events),
+            start_tick,
+            end_tick,
+        }
+    }
+}
+
+/// Pairs note on/off events into notes. A key struck again before its release ends the previous
+/// note, releases without a matching strike are ignored, notes held across a loop wrap end at
+/// the loop end, and notes still held end at the `End` event (or at the last event seen).
+pub fn pair_note_events(events: &[RecordedNoteEvent]) -> Vec<RecordedNote> {
+    let mut held: Vec<RecordedNote> = Vec::new();
+    let mut notes = Vec::new();
+    let mut last_tick = 0;
+
+    for event in events {
+        match *event {
+            RecordedNoteEvent::Start { .. } => {}
+            RecordedNoteEvent::On { track_id, key, velocity, tick } => {
+                if let Some(index) = held.iter().position(|n| n.track_id == track_id && n.key == key) {
+                    let mut note = held.swap_remove(index);
+                    note.end_tick = tick;
+                    notes.push(note);
+                }
+                held.push(RecordedNote {
+                    track_id,
+                    key,
+                    velocity,
+                    start_tick: tick,
+                    end_tick: tick,
+                });
+                last_tick = last_tick.max(tick);
+            }
+            RecordedNoteEvent::Off { track_id, key, tick } => {
+                if let Some(index) = held.iter().position(|n| n.track_id == track_id && n.key == key) {
+                    let mut note = held.swap_remove(index);
+                    note.end_tick = tick;
+                    notes.push(note);
+                }
+                last_tick = last_tick.max(tick);
+            }
+            RecordedNoteEvent::LoopWrap { tick } => {
+                for mut note in held.drain(..) {
+                    note.end_tick = tick;
+                    notes.push(note);
+                }
+            }
+            RecordedNoteEvent::End { tick } => {
+                last_tick = last_tick.max(tick);
+                break;
+            }
+        }
+    }
+
+    for mut note in held {
+        note.end_tick = last_tick;
+        notes.push(note);
+    }
+
+    notes.sort_by_key(|n| (n.start_tick, n.key));
+    notes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pairs_strikes_with_releases_and_closes_held_notes() {
+        let track_id = TrackId::from(1);
+        let events = [
+            RecordedNoteEvent::Start { tick: 0 },
+            RecordedNoteEvent::On { track_id, key: 60, velocity: 90, tick: 0 },
+            RecordedNoteEvent::Off { track_id, key: 62, tick: 100 },
+            RecordedNoteEvent::On { track_id, key: 64, velocity: 80, tick: 480 },
+            RecordedNoteEvent::Off { track_id, key: 60, tick: 960 },
+            RecordedNoteEvent::End { tick: 1920 },
+        ];
+
+        let notes = pair_note_events(&events);
+        assert_eq!(notes.len(), 2);
+        assert_eq!((notes[0].key, notes[0].start_tick, notes[0].end_tick), (60, 0, 960));
+        assert_eq!((notes[1].key, notes[1].start_tick, notes[1].end_tick), (64, 480, 1920));
+    }
+
+    #[test]
+    fn loop_wrap_closes_held_notes_at_the_loop_end() {
+        let track_id = TrackId::from(1);
+        let events = [
+            RecordedNoteEvent::Start { tick: 0 },
+            RecordedNoteEvent::On { track_id, key: 60, velocity: 90, tick: 3600 },
+            RecordedNoteEvent::LoopWrap { tick: 3840 },
+            // Released after the playhead went back to the loop start
+            RecordedNoteEvent::Off { track_id, key: 60, tick: 120 },
+            RecordedNoteEvent::End { tick: 960 },
+        ];
+
+        let notes = pair_note_events(&events);
+        assert_eq!(notes.len(), 1);
+        assert_eq!((notes[0].start_tick, notes[0].end_tick), (3600, 3840));
+    }
+}
@@ -0,0 +1,367 @@
+// src/audio/recorder.rs
+//
+// Audio input capture. The cpal input callback pushes interleaved samples into a ring that the
+// engine drains once per block. While a take is recording, the engine forwards the block into a
+// second ring that a writer thread streams to a WAV file in the session cache.
+// The audio thread never blocks or allocates on either ring.
+
+use std::{
+    path::{ Path, PathBuf },
+    sync::{ Arc, atomic::{ AtomicBool, AtomicU32, AtomicU64, Ordering } },
+    thread::JoinHandle,
+    time::{ Duration, Instant },
+};
+
+use anyhow::{ Context, Result, anyhow };
+use once_cell::sync::OnceCell;
+use rtrb::{ Consumer, Producer, RingBuffer };
+
+use crate::{
+    audio::{ note_recorder::NoteTakeHandle, writer::{ AudioFormat, BitPerSample, create_writer } },
+    shared::id::TrackId,
+};
+
+/// Recorded takes are always stereo, mono inputs are duplicated to both channels
+pub const RECORD_CHANNELS: u16 = 2;
+/// How much audio the take ring can hold before the writer thread falls behind
+const RECORD_RING_SECONDS: usize = 2;
+/// How often the writer thread wakes up to drain the take ring
+const WRITER_POLL_INTERVAL: Duration = Duration::from_millis(10);
+/// How long the writer waits for the audio thread to release a stopped take
+const WRITER_RELEASE_TIMEOUT: Duration = Duration::from_millis(500);
+
+const NOT_STARTED: u64 = u64::MAX;
+
+/// Input stream handed to the audio thread by the backend.
+pub struct AudioInputPort {
+    pub consumer: Consumer<f32>,
+    pub channels: u16,
+}
+
+/// Latency reported by the audio devices, in frames. Written by the stream callbacks, and by
+/// the engine for the plugin latency of the mix.
+#[derive(Default)]
+pub struct DeviceLatency {
+    input: AtomicU32,
+    output: AtomicU32,
+    mix: Arc<AtomicU32>,
+}
+
+impl DeviceLatency {
+    pub fn set_input(&self, frames: u32) {
+        self.input.store(frames, Ordering::Relaxed);
+    }
+
+    pub fn set_output(&self, frames: u32) {
+        self.output.store(frames, Ordering::Relaxed);
+    }
+
+    pub fn input(&self) -> u32 {
+        self.input.load(Ordering::Relaxed)
+    }
+
+    pub fn output(&self) -> u32 {
+        self.output.load(Ordering::Relaxed)
+    }
+
+    /// Where the engine publishes the plugin latency of the mix.
+    pub fn mix_report(&self) -> Arc<AtomicU32> {
+        Arc::clone(&self.mix)
+    }
+
+    pub fn mix(&self) -> u32 {
+        self.mix.load(Ordering::Relaxed)
+    }
+
+    /// Time between the playhead passing a sample and the speakers playing it.
+    pub fn playback(&self) -> u32 {
+        self.mix().saturating_add(self.output())
+    }
+
+    /// Time between the playhead passing a sample and the engine receiving the sound played
+    /// against it: what a take has to be shifted back by to line up with the timeline.
+    pub fn round_trip(&self) -> u32 {
+        self.input().saturating_add(self.playback())
+    }
+}
+
+/// Converts a device-reported delay into frames.
+pub fn duration_to_frames(duration: Duration, sample_rate: u32) -> u32 {
+    (duration.as_secs_f64() * (sample_rate as f64)).round() as u32
+}
+
+/// Converts a frame count into the time it takes to play.
+pub fn frames_to_duration(frames: usize, sample_rate: u32) -> Duration {
+    Duration::from_secs_f64((frames as f64) / (sample_rate.max(1) as f64))
+}
+
+/// State shared between the audio thread and the UI side of a take.
+#[derive(Default)]
+struct TakeShared {
+    /// Song playhead (in samples) of the first recorded frame
+    start_sample: AtomicU64,
+    /// Samples lost because the writer thread fell behind
+    dropped_samples: AtomicU64,
+}
+
+/// Audio thread side of a take: where captured blocks go.
+pub struct RecordingTake {
+    sink: Producer<f32>,
+    shared: Arc<TakeShared>,
+}
+
+/// UI side of a take: owns the writer thread.
+pub struct TakeHandle {
+    path: PathBuf,
+    shared: Arc<TakeShared>,
+    stop: Arc<AtomicBool>,
+    writer: JoinHandle<Result<u64>>,
+}
+
+/// A take that has been flushed to disk.
+#[derive(Clone, Debug)]
+pub struct FinishedTake {
+    pub path: PathBuf,
+    /// Song playhead (in samples) of the first frame. `None` when the transport never rolled
+    pub start_sample: Option<u64>,
+    pub frames: u64,
+}
+
+/// An audio take in progress along with the tracks that receive it.
+pub struct AudioRecording {
+    pub take: TakeHandle,
+    /// Name of the audio source created from the take
+    pub name: String,
+    pub tracks: Vec<TrackId>,
+}
+
+/// A recording in progress. Audio and notes are recorded when tracks of that kind are armed.
+pub struct ActiveRecording {
+    pub audio: Option<AudioRecording>,
+    pub notes: Option<NoteTakeHandle>,
+}
+
+impl TakeHandle {
+    /// Creates the WAV file at `path` and starts the writer thread.
+    /// The returned `RecordingTake` is sent to the audio thread.
+    pub fn start(path: &Path, sample_rate: u32) -> Result<(Self, RecordingTake)> {
+        let format = AudioFormat {
+            sample_rate,
+            channels: RECORD_CHANNELS,
+            bit_per_sample: BitPerSample::B32,
+        };
+        let mut writer = create_writer(path, format)?;
+
+        let capacity = (sample_rate as usize) * (RECORD_CHANNELS as usize) * RECORD_RING_SECONDS;
+        let (sink, mut source) = RingBuffer::<f32>::new(capacity);
+        let shared = Arc::new(TakeShared {
+            start_sample: AtomicU64::new(NOT_STARTED),
+            dropped_samples: AtomicU64::new(0),
+        });
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let writer_stop = Arc::clone(&stop);
+        let writer = std::thread::Builder
+            ::new()
+            .name("karbeat-take-writer".into())
+            .spawn(move || -> Result<u64> {
+                let mut scratch = Vec::with_capacity(capacity);
+                let mut written: u64 = 0;
+                let mut release_deadline: Option<Instant> = None;
+
+                loop {
+                    let available = source.slots();
+                    if available > 0 {
+                        let chunk = source.read_chunk(available)?;
+                        let (first, second) = chunk.as_slices();
+                        scratch.clear();
+                        scratch.extend_from_slice(first);
+                        scratch.extend_from_slice(second);
+                        chunk.commit_all();
+
+                        writer.write(&scratch)?;
+                        written += scratch.len() as u64;
+                        continue;
+                    }
+
+                    if source.is_abandoned() {
+                        break;
+                    }
+                    // The audio thread drops its end on the next block; give up if it has stalled
+                    if writer_stop.load(Ordering::Acquire) {
+                        let deadline = *release_deadline.get_or_insert_with(
+                            || Instant::now() + WRITER_RELEASE_TIMEOUT
+                        );
+                        if Instant::now() >= deadline {
+                            break;
+                        }
+                    }
+                    std::thread::sleep(WRITER_POLL_INTERVAL);
+                }
+
+                writer.finalize()?;
+                Ok(written / (RECORD_CHANNELS as u64))
+            })
+            .context("Failed to spawn take writer thread")?;
+
+        let handle = Self {
+            path: path.to_path_buf(),
+            shared: Arc::clone(&shared),
+            stop,
+            writer,
+        };
+        Ok((handle, RecordingTake { sink, shared }))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Waits for the writer to flush everything the audio thread captured and closes the file.
+    /// `AudioCommand::StopRecording` must be sent first.
+    pub fn finish(self) -> Result<FinishedTake> {
+        self.stop.store(true, Ordering::Release);
+        let frames = self.writer
+            .join()
+            .map_err(|_| anyhow!("Take writer thread panicked"))??;
+
+        let dropped = self.shared.dropped_samples.load(Ordering::Relaxed);
+        if dropped > 0 {
+            log::warn!("Recording dropped {} samples, the disk could not keep up", dropped);
+        }
+
+        let start_sample = self.shared.start_sample.load(Ordering::Acquire);
+        Ok(FinishedTake {
+            path: self.path,
+            start_sample: (start_sample != NOT_STARTED).then_some(start_sample),
+            frames,
+        })
+    }
+}
+
+/// Engine-owned input state: the block of input for the current callback and the running take.
+#[derive(Default)]
+pub struct InputRecorder {
+    port: Option<AudioInputPort>,
+    /// Stereo interleaved input of the current block (avoids allocation in audio thread)
+    buffer: Vec<f32>,
+    frames: usize,
+    take: Option<RecordingTake>,
+}
+
+impl InputRecorder {
+    pub fn set_port(&mut self, port: Option<AudioInputPort>) {
+        self.port = port;
+    }
+
+    pub fn start_take(&mut self, take: RecordingTake) {
+        self.take = Some(take);
+    }
+
+    /// Releases the running take, the writer thread notices and closes the file.
+    pub fn stop_take(&mut self) {
+        self.take = None;
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.take.is_some()
+    }
+
+    /// Stereo interleaved input for frames `start..start + frames` of the current block.
+    pub fn input_span(&self, start: usize, frames: usize) -> Option<&[f32]> {
+        let end = start + frames;
+        (self.port.is_some() && end <= self.frames).then(|| {
+            &self.buffer[start * (RECORD_CHANNELS as usize)..end * (RECORD_CHANNELS as usize)]
+        })
+    }
+
+    /// Reads one block of input. Missing input reads as silence, and input that piled up
+    /// beyond one block is skipped so the input latency stays bounded.
+    pub fn pull(&mut self, frames: usize) {
+        let len = frames * (RECORD_CHANNELS as usize);
+        if self.buffer.len() < len {
+            self.buffer.resize(len, 0.0);
+        }
+        self.buffer[..len].fill(0.0);
+        self.frames = frames;
+
+        let Some(port) = self.port.as_mut() else {
+            return;
+        };
+        let channels = port.channels.max(1) as usize;
+        let wanted = frames * channels;
+
+        let backlog = port.consumer.slots();
+        if backlog > wanted * 2 {
+            let skip = ((backlog - wanted) / channels) * channels;
+            if let Ok(chunk) = port.consumer.read_chunk(skip) {
+                chunk.commit_all();
+            }
+        }
+
+        let readable = (port.consumer.slots().min(wanted) / channels) * channels;
+        let Ok(chunk) = port.consumer.read_chunk(readable) else {
+            return;
+        };
+        let (first, second) = chunk.as_slices();
+        let sample = |index: usize| {
+            if index < first.len() { first[index] } else { second[index - first.len()] }
+        };
+
+        for (frame, out) in self.buffer[..(readable / channels) * 2].chunks_exact_mut(2).enumerate() {
+            let left = sample(frame * channels);
+            out[0] = left;
+            out[1] = if channels > 1 { sample(frame * channels + 1) } else { left };
+        }
+        chunk.commit_all();
+    }
+
+    /// Forwards frames `from..` of the current input block to the running take.
+    /// `playhead_samples` is the song position of frame `from`.
+    pub fn capture(&mut self, from: usize, playhead_samples: u32) {
+        let Some(take) = self.take.as_mut() else {
+            return;
+        };
+        if from >= self.frames {
+            return;
+        }
+
+        let _ = take.shared.start_sample.compare_exchange(
+            NOT_STARTED,
+            playhead_samples as u64,
+            Ordering::AcqRel,
+            Ordering::Relaxed
+        );
+
+        let block = &self.buffer[from * (RECORD_CHANNELS as usize)..self.frames * (RECORD_CHANNELS as usize)];
+        let writable = block.len().min(take.sink.slots());
+        if let Ok(chunk) = take.sink.write_chunk_uninit(writable) {
+            chunk.fill_from_iter(block.iter().copied());
+        }
+        if writable < block.len() {
+            take.shared.dropped_samples.fetch_add((block.len() - writable) as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+static RECORDINGS_DIR: OnceCell<PathBuf> = OnceCell::new();
+static NEXT_TAKE: AtomicU32 = AtomicU32::new(1);
+
+/// Picks the file for the next take in the session cache. Returns the path and a display name.
+pub fn next_take_path() -> Result<(PathBuf, String)> {
+    let dir = RECORDINGS_DIR.get_or_try_init(|| -> Result<PathBuf> {
+        // Kept for the whole session so recorded clips stay playable until the project is saved
+        Ok(
+            tempfile::Builder
+                ::new()
+                .prefix("karbeat_session_")
+                .tempdir()
+                .context("Failed to create the recording cache directory")?
+                .keep()
+        )
+    })?;
+
+    let number = NEXT_TAKE.fetch_add(1, Ordering::Relaxed);
+    let name = format!("Take {}", number);
+    Ok((dir.join(format!("take_{:03}.wav", number)), name))
+}
@@ -0,0 +1,325 @@
+// src/audio/render_pool.rs
+//
+// Worker threads that help the audio thread render a block. `RenderPool::run` hands the items of
+// a slice out one at a time to the workers and to the calling thread and returns once every item
+// is done. Each item is claimed by exactly one thread, so jobs get their item mutably without
+// locks, and the calling thread keeps claiming too: workers that are slow to wake up only make
+// the block render serially instead of stalling it. Nothing is allocated per run.
+//
+// Workers take on the scheduling of the first thread that runs work on them, normally the audio
+// thread, so a real-time callback isn't left waiting on workers the OS treats as background.
+
+use std::{
+    panic::{ AssertUnwindSafe, catch_unwind },
+    sync::{
+        Arc,
+        OnceLock,
+        atomic::{ AtomicBool, AtomicPtr, AtomicU64, AtomicUsize, Ordering },
+    },
+    thread::JoinHandle,
+};
+
+use anyhow::Context;
+
+/// Workers are capped, past this the per-block wake-ups cost more than the tracks they take
+pub const MAX_RENDER_THREADS: usize = 8;
+
+/// Items per run that fit in the claim word
+const MAX_ITEMS: usize = u16::MAX as usize;
+
+/// Spins of the calling thread between yields while it waits for the workers
+const WAIT_SPINS: u32 = 256;
+
+/// Workers for this machine: one per core besides the audio thread's.
+pub fn default_render_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|cores| cores.get().saturating_sub(1))
+        .unwrap_or(0)
+        .min(MAX_RENDER_THREADS)
+}
+
+/// Scheduling of the thread that drives the pool, copied onto the workers.
+#[derive(Debug, Clone, Copy)]
+struct Scheduling {
+    priority: thread_priority::ThreadPriority,
+    #[cfg(unix)]
+    policy: thread_priority::ThreadSchedulePolicy,
+}
+
+impl Scheduling {
+    /// The calling thread's scheduling, if workers should copy it. On unix only real-time
+    /// policies are copied: normal threads all read back as priority 0, which setting would turn
+    /// into the lowest niceness instead of the caller's.
+    #[cfg(unix)]
+    fn of_current_thread() -> Option<Self> {
+        use thread_priority::{ ThreadSchedulePolicy, thread_schedule_policy };
+
+        let policy = thread_schedule_policy().ok()?;
+        if !matches!(policy, ThreadSchedulePolicy::Realtime(_)) {
+            return None;
+        }
+        let priority = thread_priority::get_current_thread_priority().ok()?;
+        Some(Self { priority, policy })
+    }
+
+    #[cfg(not(unix))]
+    fn of_current_thread() -> Option<Self> {
+        let priority = thread_priority::get_current_thread_priority().ok()?;
+        Some(Self { priority })
+    }
+
+    #[cfg(unix)]
+    fn apply(&self) -> Result<(), thread_priority::Error> {
+        thread_priority::set_thread_priority_and_policy(
+            thread_priority::thread_native_id(),
+            self.priority,
+            self.policy
+        )
+    }
+
+    #[cfg(not(unix))]
+    fn apply(&self) -> Result<(), thread_priority::Error> {
+        self.priority.set_for_current()
+    }
+}
+
+/// Job of the current run, type-erased to a per-item call
+type Task<'a> = &'a (dyn Fn(usize) + Sync);
+
+struct Shared {
+    /// Run counter (bits 32-63), items of the run (16-31) and the next unclaimed item (0-15)
+    state: AtomicU64,
+    /// Claimed items of the current run that are still rendering, or not claimed yet
+    pending: AtomicUsize,
+    /// Points at the current run's `Task`, which lives on the calling thread's stack
+    task: AtomicPtr<()>,
+    /// Scheduling for the workers, taken from the first thread that runs work on them
+    scheduling: OnceLock<Option<Scheduling>>,
+    stop: AtomicBool,
+}
+
+impl Shared {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            state: AtomicU64::new(0),
+            pending: AtomicUsize::new(0),
+            task: AtomicPtr::new(std::ptr::null_mut()),
+            scheduling: OnceLock::new(),
+            stop: AtomicBool::new(false),
+        })
+    }
+
+    /// Claims and runs items of run `epoch` until it has none left.
+    fn work(&self, epoch: u64) {
+        loop {
+            let state = self.state.load(Ordering::Acquire);
+            let (count, next) = ((state >> 16) & 0xffff, state & 0xffff);
+            if state >> 32 != epoch || next >= count {
+                return;
+            }
+            if
+                self.state
+                    .compare_exchange_weak(state, state + 1, Ordering::AcqRel, Ordering::Acquire)
+                    .is_err()
+            {
+                continue;
+            }
+
+            // SAFETY: the claim succeeded, so run `epoch` is still waiting for this item and its
+            // task, published before the run's state, is alive until `pending` reaches zero.
+            let task = unsafe { *(self.task.load(Ordering::Acquire) as *const Task<'_>) };
+            // A panicking job must still count its item done, or the run never finishes
+            let _ = catch_unwind(AssertUnwindSafe(|| task(next as usize)));
+            self.pending.fetch_sub(1, Ordering::Release);
+        }
+    }
+}
+
+/// Raw pointer to the items of a run, shared with the workers.
+struct Items<T>(*mut T);
+
+// SAFETY: every item is handed to exactly one thread, see `RenderPool::run`
+unsafe impl<T: Send> Sync for Items<T> {}
+
+/// Render worker threads, stopped when dropped.
+pub struct RenderPool {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl RenderPool {
+    /// A pool of `threads` workers. Without workers every run renders on the calling thread.
+    pub fn new(threads: usize) -> anyhow::Result<Self> {
+        let shared = Shared::new();
+
+        let workers = (0..threads.min(MAX_RENDER_THREADS))
+            .map(|index| {
+                let shared = Arc::clone(&shared);
+                std::thread::Builder
+                    ::new()
+                    .name(format!("karbeat-render-{}", index))
+                    .spawn(move || {
+                        let mut seen = 0;
+                        let mut scheduled = false;
+                        while !shared.stop.load(Ordering::Acquire) {
+                            let epoch = shared.state.load(Ordering::Acquire) >> 32;
+                            if epoch == seen {
+                                std::thread::park();
+                                continue;
+                            }
+                            seen = epoch;
+                            if !scheduled {
+                                scheduled = true;
+                                if let Some(Some(scheduling)) = shared.scheduling.get() {
+                                    if let Err(e) = scheduling.apply() {
+                                        log::warn!(
+                                            "Render thread {} keeps its default priority: {:?}",
+                                            index,
+                                            e
+                                        );
+                                    }
+                                }
+                            }
+                            shared.work(epoch);
+                        }
+                    })
+                    .context("Failed to spawn render thread")
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self { shared, workers })
+    }
+
+    /// A pool that renders everything on the calling thread.
+    pub fn serial() -> Self {
+        Self { shared: Shared::new(), workers: Vec::new() }
+    }
+
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Runs `job` on every item, spread over the workers and the calling thread. Which thread
+    /// renders an item is up to scheduling, so jobs must not depend on it. A job that panics
+    /// leaves its item as it was at the panic, and the run carries on with the other items.
+    pub fn run<T: Send>(&self, items: &mut [T], job: &(dyn Fn(&mut T) + Sync)) {
+        if self.workers.is_empty() || items.len() < 2 || items.len() > MAX_ITEMS {
+            for item in items.iter_mut() {
+                let _ = catch_unwind(AssertUnwindSafe(|| job(item)));
+            }
+            return;
+        }
+
+        let base = Items(items.as_mut_ptr());
+        let per_item = |index: usize| {
+            let base = &base;
+            // SAFETY: `Shared::work` claims every index below `items.len()` exactly once, and
+            // `items` stays mutably borrowed until all of them are done.
+            job(unsafe { &mut *base.0.add(index) });
+        };
+        let task: Task<'_> = &per_item;
+
+        let shared = &self.shared;
+        shared.scheduling.get_or_init(Scheduling::of_current_thread);
+        let epoch = (shared.state.load(Ordering::Relaxed) >> 32).wrapping_add(1) & 0xffff_ffff;
+        shared.pending.store(items.len(), Ordering::Relaxed);
+        shared.task.store(&task as *const Task<'_> as *mut (), Ordering::Relaxed);
+        shared.state.store((epoch << 32) | ((items.len() as u64) << 16), Ordering::Release);
+        for worker in &self.workers {
+            worker.thread().unpark();
+        }
+
+        shared.work(epoch);
+        let mut spins = 0;
+        while shared.pending.load(Ordering::Acquire) != 0 {
+            spins += 1;
+            if spins % WAIT_SPINS == 0 {
+                std::thread::yield_now();
+            } else {
+                std::hint::spin_loop();
+            }
+        }
+    }
+}
+
+impl Drop for RenderPool {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::Release);
+        for worker in self.workers.drain(..) {
+            worker.thread().unpark();
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn visits(pool: &RenderPool, len: usize) -> Vec<usize> {
+        let mut counts = vec![0usize; len];
+        pool.run(&mut counts, &(|count| {
+            *count += 1;
+        }));
+        counts
+    }
+
+    #[test]
+    fn every_item_is_rendered_exactly_once() -> anyhow::Result<()> {
+        let pool = RenderPool::new(4)?;
+        assert_eq!(pool.threads(), 4);
+
+        for len in [2, 3, 17, 256] {
+            assert!(visits(&pool, len).iter().all(|&count| count == 1));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn renders_on_the_calling_thread_without_workers() -> anyhow::Result<()> {
+        let caller = std::thread::current().id();
+        let on_caller = |pool: &RenderPool, len: usize| {
+            let mut threads = vec![None; len];
+            pool.run(&mut threads, &(|thread| {
+                *thread = Some(std::thread::current().id());
+            }));
+            threads.iter().all(|&thread| thread == Some(caller))
+        };
+
+        assert!(on_caller(&RenderPool::serial(), 8));
+        assert!(on_caller(&RenderPool::new(0)?, 8));
+        assert!(on_caller(&RenderPool::new(4)?, 1));
+        assert!(visits(&RenderPool::serial(), 8).iter().all(|&count| count == 1));
+        Ok(())
+    }
+
+    #[test]
+    fn repeated_runs_render_every_item() -> anyhow::Result<()> {
+        let pool = RenderPool::new(3)?;
+        let mut totals = vec![0usize; 9];
+
+        for _ in 0..2_000 {
+            pool.run(&mut totals, &(|total| {
+                *total += 1;
+            }));
+        }
+        assert!(totals.iter().all(|&total| total == 2_000));
+        Ok(())
+    }
+
+    #[test]
+    fn a_panicking_job_does_not_stall_the_run() -> anyhow::Result<()> {
+        let pool = RenderPool::new(2)?;
+        let mut items: Vec<usize> = (0..8).collect();
+
+        pool.run(&mut items, &(|item| {
+            if *item == 3 {
+                panic!("job failed");
+            }
+            *item += 100;
+        }));
+        assert_eq!(items, [100, 101, 102, 3, 104, 105, 106, 107]);
+        assert!(visits(&pool, 8).iter().all(|&count| count == 1));
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use std::{ ops::Deref, sync::Arc };
 
 use crate::{
-    commands::AudioCommand,
+    audio::load::PluginTimer,
+    commands::{ AudioCommand, PreparedPlugins },
     context::utils::send_audio_command,
     core::project::{
         ApplicationState,
@@ -11,6 +12,8 @@ use crate::{
         TrackId,
         automation::{ AutomationPoint, AutomationTarget, CurveType },
         mixer::{ MixerState },
+        musical_time::TimeSignatureMap,
+        tempo_map::TempoMap,
         plugin::{ KarbeatEffect, KarbeatGenerator },
         track::{ KarbeatTrack, midi::{ Pattern} },
     },
@@ -29,11 +32,15 @@ pub struct AudioGeneratorInstance {
     pub id: GeneratorId,
     pub track_id: TrackId,
     pub plugin: Box<dyn KarbeatGenerator + Send + Sync>,
+    /// Time spent in `process`, while plugin profiling is on
+    pub load: PluginTimer,
 }
 
 pub struct AudioEffectInstance {
     pub id: EffectId,
     pub plugin: Box<dyn KarbeatEffect + Send + Sync>,
+    /// Time spent in `process`, while plugin profiling is on
+    pub load: PluginTimer,
 }
 
 /// Audio thread's owned plugin instances - NO locks required for access
@@ -236,7 +243,14 @@ pub struct AudioGraphState {
     pub asset_library: Arc<AssetLibrary>,
     /// Automation lanes for real-time parameter modulation
     pub automation_lanes: IndexMap<AutomationId, AudioAutomationLane>,
-    pub max_sample_index: u32,
+    /// End of the last clip in ticks
+    pub max_tick_index: u32,
+    /// Tempo map used to place ticks on the sample timeline
+    pub tempo_map: TempoMap,
+    /// Meter changes used for bar/beat feedback
+    pub time_signatures: TimeSignatureMap,
+    /// Seed of the note probability rolls
+    pub random_seed: u64,
     pub sample_rate: u32,
     pub buffer_size: usize,
 }
@@ -271,7 +285,10 @@ impl From<&ApplicationState> for AudioGraphState {
             mixer_state: app.mixer.clone(),
             asset_library: app.asset_library.clone(),
             automation_lanes,
-            max_sample_index: app.max_sample_index,
+            max_tick_index: app.max_tick_index,
+            tempo_map: app.effective_tempo_map(),
+            time_signatures: app.transport.time_signature_map.clone(),
+            random_seed: app.transport.random_seed,
             sample_rate: app.audio_config.sample_rate,
             buffer_size: if is_power_of_two(app.audio_config.buffer_size.into()) {
                 app.audio_config.buffer_size as usize
@@ -411,10 +428,14 @@ pub fn broadcast_plugin_state_loading() {
         })
         .collect();
 
-    send_audio_command(AudioCommand::PreparePlugin {
-        track_effects,
-        master_effects,
-        bus_effects,
-        generators,
-    });
+    send_audio_command(
+        AudioCommand::PreparePlugin(
+            Box::new(PreparedPlugins {
+                track_effects,
+                master_effects,
+                bus_effects,
+                generators,
+            })
+        )
+    );
 }
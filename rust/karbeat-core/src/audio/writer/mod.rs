@@ -35,6 +35,16 @@ pub trait AudioWriter: Send {
     fn finalize(&mut self) -> Result<()>;
 }
 
+impl<W: AudioWriter + ?Sized> AudioWriter for Box<W> {
+    fn write(&mut self, samples: &[f32]) -> Result<()> {
+        (**self).write(samples)
+    }
+
+    fn finalize(&mut self) -> Result<()> {
+        (**self).finalize()
+    }
+}
+
 /// Factory function to create the appropriate writer based on file extension
 pub fn create_writer(path: &Path, format: AudioFormat) -> Result<Box<dyn AudioWriter>> {
     let ext = path
@@ -45,9 +55,8 @@ pub fn create_writer(path: &Path, format: AudioFormat) -> Result<Box<dyn AudioWr
 
     match ext.as_str() {
         "wav" => Ok(Box::new(wav::WavAudioWriter::new(path, format)?)),
-        "mp3" => todo!("Add MP3 Audio Writer here"),
-        "flac" => todo!("Add FLAC Audio Writer here"),
-        "ogg" => todo!("Add OGG Audio Writer here"),
+        // TODO: Add MP3, FLAC and OGG Audio Writers here
+        "mp3" | "flac" | "ogg" => Err(anyhow!("Exporting .{} is not supported yet", ext)),
         _ => Err(anyhow!("Unsupported file extension: .{}", ext)),
     }
 }
\ No newline at end of file
@@ -1,9 +1,16 @@
 use indexmap::IndexMap;
 
 use crate::{
-    audio::engine::PlaybackMode,
+    audio::{
+        analyzer::AnalyzerTap,
+        engine::PlaybackMode,
+        midi::{ MidiInputPort, MidiOutputPort, MidiOutputSettings },
+        note_recorder::NoteTake,
+        recorder::{ AudioInputPort, RecordingTake },
+    },
     core::project::{
-        GeneratorId, mixer::RoutingConnection, plugin::{KarbeatEffect, KarbeatGenerator}, track::audio_waveform::AudioWaveform
+        GeneratorId, mixer::RoutingConnection, plugin::{KarbeatEffect, KarbeatGenerator}, track::audio_waveform::AudioWaveform,
+        transport::LoopRegion,
     }, shared::id::{BusId, EffectId, TrackId},
 };
 
@@ -14,6 +21,42 @@ pub enum AudioCommand {
     SetPlaying(bool),
     /// Set loop mode
     SetLooping(bool),
+    /// Set the loop in/out points (in ticks). `None` loops the whole song
+    SetLoopRegion(Option<LoopRegion>),
+    /// Enable or disable the metronome click during song playback
+    SetMetronomeEnabled(bool),
+    /// Set the metronome click gain (0.0 - 1.0)
+    SetMetronomeVolume(f32),
+    /// Route the metronome to a bus. `None` sends it straight to the output
+    SetMetronomeTarget(Option<BusId>),
+    /// Start copying a mixer strip into a spectrum analyzer
+    AddAnalyzerTap(AnalyzerTap),
+    /// Stop feeding the spectrum analyzer with this id
+    RemoveAnalyzerTap(u32),
+    /// Clear the master loudness analyzer (integrated loudness, range, true peak)
+    ResetLoudness,
+    /// Clear the peak, overload and xrun counts of the engine load report
+    ResetEngineLoad,
+    /// Set the number of count-in bars played before song playback starts (0 - 2)
+    SetCountInBars(u8),
+    /// Connect the audio input stream to the engine. `None` disconnects it
+    SetAudioInput(Option<AudioInputPort>),
+    /// Start capturing the input into a take while song playback rolls
+    StartRecording(RecordingTake),
+    /// Release the running take so its writer can close the file
+    StopRecording,
+    /// Start stamping live notes on armed MIDI tracks while song playback rolls
+    StartNoteRecording(NoteTake),
+    /// Close the running note take at the current playhead
+    StopNoteRecording,
+    /// Connect a MIDI input port to the engine. `None` disconnects it
+    SetMidiInput(Option<MidiInputPort>),
+    /// Track that plays the MIDI input while no MIDI track is armed
+    SetMidiInputTrack(Option<TrackId>),
+    /// Connect a MIDI output port to the engine. `None` disconnects it
+    SetMidiOutput(Option<MidiOutputPort>),
+    /// Choose what the engine sends to the MIDI output
+    SetMidiOutputSettings(MidiOutputSettings),
     /// Stop playback and reset playhead to 0
     StopAndReset,
     SetPlayhead(u32),
@@ -153,12 +196,15 @@ pub enum AudioCommand {
         routing: Vec<RoutingConnection>,
     },
     /// Prepare all of plugins from ApplicationState to AudioEngine (upon loading project)
-    PreparePlugin {
-        track_effects: IndexMap<TrackId, IndexMap<EffectId, Box<dyn KarbeatEffect + Send + Sync>>>,
-        master_effects: IndexMap<EffectId, Box<dyn KarbeatEffect + Send + Sync>>,
-        bus_effects: IndexMap<BusId, IndexMap<EffectId, Box<dyn KarbeatEffect + Send + Sync>>>,
-        generators: IndexMap<GeneratorId, Box<dyn KarbeatGenerator + Send + Sync>>,
-    }
+    PreparePlugin(Box<PreparedPlugins>),
+}
+
+/// Every plugin instance of a project, boxed so `AudioCommand` stays small
+pub struct PreparedPlugins {
+    pub track_effects: IndexMap<TrackId, IndexMap<EffectId, Box<dyn KarbeatEffect + Send + Sync>>>,
+    pub master_effects: IndexMap<EffectId, Box<dyn KarbeatEffect + Send + Sync>>,
+    pub bus_effects: IndexMap<BusId, IndexMap<EffectId, Box<dyn KarbeatEffect + Send + Sync>>>,
+    pub generators: IndexMap<GeneratorId, Box<dyn KarbeatGenerator + Send + Sync>>,
 }
 
 // ============================================================================
@@ -205,6 +251,11 @@ pub struct EffectParameterSnapshot {
     pub parameters: Vec<(u32, f32)>, // (param_id, value) pairs
 }
 
+/// What the audio thread lets go of, handed back so it is freed elsewhere
+pub enum Garbage {
+    AnalyzerTap(AnalyzerTap),
+}
+
 /// Messages from audio thread to UI thread
 #[derive(Clone, Debug)]
 pub enum AudioFeedback {
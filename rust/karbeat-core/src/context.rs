@@ -3,17 +3,37 @@
 //! This module replaces scattered lazy static globals with a single `KarbeatContext` struct
 //! for improved testability and explicit dependencies.
 
-use std::sync::{ Arc, Once };
+use std::{ path::PathBuf, sync::{ Arc, Once } };
 
+use karbeat_dsp::loudness::LoudnessReport;
 use once_cell::sync::Lazy;
 use parking_lot::{ Mutex, RwLock };
 use rtrb::Producer;
-use triple_buffer::Input;
+use triple_buffer::{ Input, Output };
 
 use crate::{
-    audio::{ event::TransportFeedback, render_state::AudioRenderState },
-    commands::{ AudioCommand, AudioFeedback },
-    core::{ history::HistoryManager, project::ApplicationState },
+    audio::{
+        analyzer::SpectrumAnalyzers,
+        backend::{ AudioBackend, AudioStream, default_backend },
+        event::TransportFeedback,
+        load::EngineLoadReport,
+        metering::MeterFeedback,
+        midi::{
+            MidiBackend,
+            MidiInputConnection,
+            MidiOutputHandle,
+            MidiOutputSettings,
+            system_backend,
+        },
+        recorder::{ ActiveRecording, DeviceLatency },
+        render_state::AudioRenderState,
+    },
+    commands::{ AudioCommand, AudioFeedback, Garbage },
+    core::{
+        file_manager::user_settings::{ UserSettings, default_settings_path, load_user_settings },
+        history::HistoryManager,
+        project::{ ApplicationState, midi_mapping::MidiLearnState },
+    },
 };
 use karbeat_plugins::registry::PluginRegistry;
 
@@ -44,18 +64,66 @@ pub struct KarbeatContext<'a> {
     /// Parameter feedback consumer (Audio → UI)
     pub feedback_consumer: Mutex<Option<rtrb::Consumer<AudioFeedback>>>,
 
+    /// What the audio thread removed, freed by `utils::collect_garbage` (Audio → UI)
+    pub garbage_consumer: Mutex<Option<rtrb::Consumer<Garbage>>>,
+
     /// Triple buffer input for audio render state
     pub render_state_producer: Mutex<Option<Input<AudioRenderState>>>,
 
     /// Shadow state tracking last sent render state
     pub current_render_state: Mutex<AudioRenderState>,
 
+    /// Settings file outside the project, `None` when there is nowhere to keep it
+    pub user_settings_path: RwLock<Option<PathBuf>>,
+
+    /// Machine settings (audio device, buffer size) loaded from the settings file
+    pub user_settings: Mutex<UserSettings>,
+
+    /// Where the engine's output goes, picked up by the next `start_audio_stream`
+    pub audio_backend: RwLock<Arc<dyn AudioBackend>>,
+
     /// Audio stream handle
-    pub stream_guard: Mutex<Option<cpal::Stream>>,
+    pub stream_guard: Mutex<Option<Box<dyn AudioStream>>>,
+
+    /// Audio input stream handle
+    pub input_stream_guard: Mutex<Option<cpal::Stream>>,
+
+    /// Latency reported by the input and output streams
+    pub device_latency: DeviceLatency,
+
+    /// Take being recorded, finalized when recording stops
+    pub recording: Mutex<Option<ActiveRecording>>,
+
+    /// MIDI API used to list and open ports
+    pub midi_backend: RwLock<Arc<dyn MidiBackend>>,
+
+    /// Open MIDI input port
+    pub midi_input: Mutex<Option<Box<dyn MidiInputConnection>>>,
+
+    /// Open MIDI output port and its sender thread
+    pub midi_output: Mutex<Option<MidiOutputHandle>>,
+
+    /// What the engine sends to the MIDI output
+    pub midi_output_settings: Mutex<MidiOutputSettings>,
+
+    /// MIDI learn and soft-takeover state of the controller mappings
+    pub midi_learn: Mutex<MidiLearnState>,
 
     /// Playback position ring buffer consumer
     pub position_consumer: Mutex<Option<rtrb::Consumer<TransportFeedback>>>,
 
+    /// Level meter ring buffer consumer
+    pub meter_consumer: Mutex<Option<rtrb::Consumer<MeterFeedback>>>,
+
+    /// Latest loudness report of the master output
+    pub loudness_report: Mutex<Option<Output<LoudnessReport>>>,
+
+    /// Latest CPU load report of the audio thread
+    pub engine_load: Mutex<Option<Output<EngineLoadReport>>>,
+
+    /// Spectrum analyzer workers fed by the engine's taps
+    pub spectrum_analyzers: Mutex<SpectrumAnalyzers>,
+
     /// Plugin factory registry
     pub plugin_registry: RwLock<PluginRegistry>,
 
@@ -65,15 +133,47 @@ pub struct KarbeatContext<'a> {
 
 impl<'a> KarbeatContext<'a> {
     fn new() -> Self {
+        let user_settings_path = default_settings_path();
+        let user_settings = user_settings_path
+            .as_deref()
+            .map(|path| {
+                load_user_settings(path).unwrap_or_else(|e| {
+                    log::warn!("{:#}, using default settings", e);
+                    UserSettings::default()
+                })
+            })
+            .unwrap_or_default();
+
+        let mut app_state = ApplicationState::default();
+        app_state.audio_config.selected_input_device = user_settings.audio.input_device
+            .clone()
+            .unwrap_or_default();
+
         Self {
-            app_state: Arc::new(RwLock::new(ApplicationState::default())),
+            app_state: Arc::new(RwLock::new(app_state)),
             history: Mutex::new(HistoryManager::new()),
             command_sender: Mutex::new(None),
             feedback_consumer: Mutex::new(None),
+            garbage_consumer: Mutex::new(None),
             render_state_producer: Mutex::new(None),
             current_render_state: Mutex::new(AudioRenderState::default()),
+            user_settings_path: RwLock::new(user_settings_path),
+            user_settings: Mutex::new(user_settings),
+            audio_backend: RwLock::new(default_backend()),
             stream_guard: Mutex::new(None),
+            input_stream_guard: Mutex::new(None),
+            device_latency: DeviceLatency::default(),
+            recording: Mutex::new(None),
+            midi_backend: RwLock::new(system_backend()),
+            midi_input: Mutex::new(None),
+            midi_output: Mutex::new(None),
+            midi_output_settings: Mutex::new(MidiOutputSettings::default()),
+            midi_learn: Mutex::new(MidiLearnState::default()),
             position_consumer: Mutex::new(None),
+            meter_consumer: Mutex::new(None),
+            loudness_report: Mutex::new(None),
+            engine_load: Mutex::new(None),
+            spectrum_analyzers: Mutex::new(SpectrumAnalyzers::default()),
             plugin_registry: RwLock::new(PluginRegistry::new_with_defaults()),
             mixer_event_sink: Mutex::new(None),
         }
@@ -109,9 +209,21 @@ pub mod utils {
         }
     }
 
+    /// Frees what the audio thread handed back.
+    pub fn collect_garbage() {
+        if let Some(consumer) = ctx().garbage_consumer.lock().as_mut() {
+            while let Ok(garbage) = consumer.pop() {
+                drop(garbage);
+            }
+        }
+    }
+
     pub fn try_send_audio_command_chain(commands: Vec<AudioCommand>) -> anyhow::Result<()> {
         if let Some(sender) = ctx().command_sender.lock().as_mut() {
-            commands.into_iter().try_for_each(|command| sender.push(command))?;
+            commands
+                .into_iter()
+                .try_for_each(|command| sender.push(command))
+                .map_err(|_| anyhow::anyhow!("Audio command queue is full"))?;
         }
 
         Ok(())
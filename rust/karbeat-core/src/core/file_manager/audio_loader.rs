@@ -139,6 +139,8 @@ pub fn load_audio_file(path_str: &str, name: Option<&str>) -> Result<AudioWavefo
 // Trait AudioLoader
 pub trait AudioLoader {
     fn load_audio(&mut self, path: &str, name: Option<&str>) -> Result<AudioSourceId>;
+    /// Adds an already decoded waveform to the asset library.
+    fn insert_audio(&mut self, waveform: AudioWaveform) -> AudioSourceId;
     fn get_audio_source(&self, id: &AudioSourceId) -> Option<Arc<AudioWaveform>>;
     fn get_audio_sources(&self) -> HashMap<AudioSourceId, Arc<AudioWaveform>>;
 }
@@ -155,9 +157,17 @@ impl AudioLoader for ApplicationState {
                 return Err(anyhow!("{}", error_msg));
             }
         };
+        let source_id = self.insert_audio(waveform);
+
+        log::info!("Successfully loaded audio: {} (ID: {})", path, source_id.to_u32());
+
+        Ok(source_id)
+    }
+
+    fn insert_audio(&mut self, waveform: AudioWaveform) -> AudioSourceId {
         let raw_id = self.asset_library.next_id;
         let source_id = AudioSourceId::from(raw_id);
-        
+
         let asset_library = Arc::make_mut(&mut self.asset_library);
         asset_library.next_id += 1;
 
@@ -167,9 +177,7 @@ impl AudioLoader for ApplicationState {
             .source_map
             .insert(source_id, Arc::new(waveform));
 
-        log::info!("Successfully loaded audio: {} (ID: {})", path, raw_id);
-
-        Ok(source_id)
+        source_id
     }
 
     fn get_audio_source(&self, id: &AudioSourceId) -> Option<Arc<AudioWaveform>> {
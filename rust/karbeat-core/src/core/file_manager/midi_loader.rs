@@ -0,0 +1,292 @@
+// src/core/file_manager/midi_loader.rs
+//
+// Reads the notes of a Standard MIDI File (format 0 or 1). Only what notes need is kept:
+// note ons and offs of every track and channel, merged and converted to the project's
+// resolution, and the time signatures that put them in bars. Tempo, controllers and the rest
+// are skipped.
+
+use std::path::Path;
+
+use anyhow::{ anyhow, Context, Result };
+
+use crate::core::project::{
+    Note,
+    NoteId,
+    musical_time::{ TimeSignature, TimeSignatureEvent, TimeSignatureMap },
+    tempo_map::TICKS_PER_BEAT,
+};
+
+/// What the project takes from a MIDI file.
+pub struct MidiFile {
+    /// Notes sorted by start
+    pub notes: Vec<Note>,
+    /// Meter of the file, `None` when it has no time signature events
+    pub time_signatures: Option<TimeSignatureMap>,
+}
+
+/// Reads the MIDI file at `path`.
+pub fn load_midi_file(path: &Path) -> Result<MidiFile> {
+    let bytes = std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    read_midi_file(&bytes)
+}
+
+/// Reads a Standard MIDI File. Notes still held when their track ends are closed there.
+pub fn read_midi_file(bytes: &[u8]) -> Result<MidiFile> {
+    let mut reader = Reader { bytes, position: 0 };
+
+    let header = reader.chunk(b"MThd")?;
+    if header.len() < 6 {
+        return Err(anyhow!("MIDI header is too short"));
+    }
+    let track_count = u16::from_be_bytes([header[2], header[3]]);
+    let division = u16::from_be_bytes([header[4], header[5]]);
+    if division & 0x8000 != 0 {
+        return Err(anyhow!("MIDI files timed in SMPTE frames are not supported"));
+    }
+    if division == 0 {
+        return Err(anyhow!("MIDI file has no tick resolution"));
+    }
+
+    let mut notes = Vec::new();
+    let mut signatures = Vec::new();
+    for _ in 0..track_count {
+        let track = reader.chunk(b"MTrk")?;
+        read_track(track, &mut notes, &mut signatures)?;
+    }
+
+    // File ticks to project ticks
+    let scale = |tick: u64| (tick * (TICKS_PER_BEAT as u64)) / (division as u64);
+    let mut notes: Vec<Note> = notes
+        .into_iter()
+        .map(|(start, end, key, velocity)| (scale(start), scale(end), key, velocity))
+        .enumerate()
+        .map(|(index, (start_tick, end_tick, key, velocity))| Note {
+            id: NoteId::from(index as u32),
+            start_tick,
+            duration: end_tick.saturating_sub(start_tick).max(1),
+            key,
+            velocity,
+            probability: 1.0,
+            micro_offset: 0,
+            mute: false,
+        })
+        .collect();
+    notes.sort();
+
+    let signatures = signatures
+        .into_iter()
+        .map(|(tick, signature)| (scale(tick), signature))
+        .collect();
+    Ok(MidiFile { notes, time_signatures: signature_map(signatures) })
+}
+
+/// Puts time signature changes at project ticks onto bar lines. The file is in 4/4 until its
+/// first change, and a change in the middle of a bar starts on the next one.
+fn signature_map(mut changes: Vec<(u64, TimeSignature)>) -> Option<TimeSignatureMap> {
+    if changes.is_empty() {
+        return None;
+    }
+    changes.sort_by_key(|&(tick, _)| tick);
+
+    let mut map = TimeSignatureMap::new(TimeSignature::COMMON);
+    for (tick, signature) in changes {
+        let position = map.position_at(tick);
+        let on_bar_line = position.beat == 0 && position.tick == 0;
+        let bar = if on_bar_line { position.bar } else { position.bar + 1 };
+        map.insert(TimeSignatureEvent::new(bar, signature));
+    }
+    Some(map)
+}
+
+/// Adds the notes of one track as (start, end, key, velocity) and its time signatures, both
+/// in file ticks.
+fn read_track(
+    bytes: &[u8],
+    notes: &mut Vec<(u64, u64, u8, u8)>,
+    signatures: &mut Vec<(u64, TimeSignature)>
+) -> Result<()> {
+    let mut reader = Reader { bytes, position: 0 };
+    let mut tick = 0u64;
+    let mut running_status = None;
+    // Notes waiting for their note off: (channel, key, start, velocity)
+    let mut open: Vec<(u8, u8, u64, u8)> = Vec::new();
+
+    while !reader.is_empty() {
+        tick += reader.variable_length()? as u64;
+
+        let status = match reader.peek()? {
+            byte if byte & 0x80 != 0 => {
+                reader.position += 1;
+                byte
+            }
+            _ => running_status.ok_or_else(|| anyhow!("MIDI data byte without a status"))?,
+        };
+
+        match status {
+            0xff => {
+                let meta_type = reader.byte()?;
+                let len = reader.variable_length()? as usize;
+                let data = reader.take(len)?;
+                // Time signature: numerator, denominator as a power of two, then clock rates
+                if meta_type == 0x58 && len >= 2 && data[1] < 8 {
+                    let signature = TimeSignature::new(data[0], 1 << data[1]);
+                    if signature.is_valid() {
+                        signatures.push((tick, signature));
+                    }
+                }
+                running_status = None;
+            }
+            0xf0 | 0xf7 => {
+                let len = reader.variable_length()? as usize;
+                reader.skip(len)?;
+                running_status = None;
+            }
+            0x80..=0xef => {
+                running_status = Some(status);
+                let channel = status & 0x0f;
+                match status & 0xf0 {
+                    0x90 | 0x80 => {
+                        let key = reader.byte()? & 0x7f;
+                        let velocity = reader.byte()? & 0x7f;
+                        // Earliest open note of the key is closed first
+                        if let Some(index) = open
+                            .iter()
+                            .position(|&(c, k, _, _)| c == channel && k == key) {
+                            let (_, _, start, on_velocity) = open.remove(index);
+                            notes.push((start, tick, key, on_velocity));
+                        }
+                        if status & 0xf0 == 0x90 && velocity > 0 {
+                            open.push((channel, key, tick, velocity));
+                        }
+                    }
+                    0xc0 | 0xd0 => reader.skip(1)?,
+                    _ => reader.skip(2)?,
+                }
+            }
+            _ => {
+                return Err(anyhow!("Unexpected MIDI status byte {status:#04x}"));
+            }
+        }
+    }
+
+    for (_, key, start, velocity) in open {
+        notes.push((start, tick, key, velocity));
+    }
+    Ok(())
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    fn peek(&self) -> Result<u8> {
+        self.bytes.get(self.position).copied().ok_or_else(|| anyhow!("MIDI data ends early"))
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<()> {
+        self.take(len).map(|_| ())
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.position
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| anyhow!("MIDI data ends early"))?;
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    /// A variable-length quantity, seven bits per byte
+    fn variable_length(&mut self) -> Result<u32> {
+        let mut value = 0u32;
+        for _ in 0..4 {
+            let byte = self.byte()?;
+            value = (value << 7) | ((byte & 0x7f) as u32);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(anyhow!("MIDI variable-length number is too long"))
+    }
+
+    /// The data of the next chunk, which has to be of type `id`. Unknown chunks in between
+    /// are skipped.
+    fn chunk(&mut self, id: &[u8; 4]) -> Result<&'a [u8]> {
+        loop {
+            let chunk_id = self.take(4)?;
+            let len = self.take(4)?;
+            let len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize;
+            let data = self.take(len)?;
+            if chunk_id == id {
+                return Ok(data);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn notes_are_read_at_project_resolution() -> Result<()> {
+        let track: &[u8] = &[
+            0x00, 0xff, 0x51, 0x03, 0x07, 0xa1, 0x20, // tempo, skipped
+            0x00, 0x90, 0x24, 0x64, // kick on
+            0x3c, 0x2a, 0x50, // hat on, running status
+            0x3c, 0x80, 0x24, 0x00, // kick off
+            0x00, 0x90, 0x2a, 0x00, // hat off as a zero velocity note on
+            0x00, 0xff, 0x2f, 0x00, // end of track
+        ];
+        let mut file = b"MThd".to_vec();
+        file.extend_from_slice(&[0, 0, 0, 6, 0, 0, 0, 1, 0, 96]);
+        file.extend_from_slice(b"MTrk");
+        file.extend_from_slice(&(track.len() as u32).to_be_bytes());
+        file.extend_from_slice(track);
+
+        let midi = read_midi_file(&file)?;
+        assert!(midi.time_signatures.is_none());
+        let notes = midi.notes;
+        assert_eq!(notes.len(), 2);
+        // 60 ticks at 96 per beat is 600 at 960
+        assert_eq!((notes[0].key, notes[0].start_tick, notes[0].duration), (36, 0, 1200));
+        assert_eq!((notes[1].key, notes[1].start_tick, notes[1].velocity), (42, 600, 80));
+        Ok(())
+    }
+
+    #[test]
+    fn time_signatures_land_on_bar_lines() -> Result<()> {
+        let track: &[u8] = &[
+            0x00, 0xff, 0x58, 0x04, 0x06, 0x03, 0x18, 0x08, // 6/8 from the start
+            0x00, 0x90, 0x24, 0x64, // kick on
+            0x60, 0x80, 0x24, 0x00, // kick off a beat later
+            0x00, 0xff, 0x58, 0x04, 0x03, 0x02, 0x18, 0x08, // 3/4 inside the first bar
+            0x00, 0xff, 0x2f, 0x00, // end of track
+        ];
+        let mut file = b"MThd".to_vec();
+        file.extend_from_slice(&[0, 0, 0, 6, 0, 0, 0, 1, 0, 96]);
+        file.extend_from_slice(b"MTrk");
+        file.extend_from_slice(&(track.len() as u32).to_be_bytes());
+        file.extend_from_slice(track);
+
+        let signatures = read_midi_file(&file)?.time_signatures.context("No time signatures")?;
+        assert_eq!(signatures.events(), &[
+            TimeSignatureEvent::new(0, TimeSignature::new(6, 8)),
+            TimeSignatureEvent::new(1, TimeSignature::new(3, 4)),
+        ]);
+        Ok(())
+    }
+}
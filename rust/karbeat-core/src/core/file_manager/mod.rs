@@ -1,3 +1,5 @@
 pub mod audio_loader;
 pub mod memmap;
+pub mod midi_loader;
 pub mod project_loader;
+pub mod user_settings;
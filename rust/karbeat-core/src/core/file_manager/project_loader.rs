@@ -10,7 +10,10 @@ use zip::{read::ZipArchive, write::SimpleFileOptions, CompressionMethod, ZipWrit
 
 use crate::core::{
     file_manager::audio_loader::load_audio_file,
-    project::{ApplicationState, AudioSourceId, ProjectMetadata},
+    project::{
+        tempo_map::TempoMap, ApplicationState, AudioHardwareConfig, AudioSourceId,
+        ProjectFormatVersion, ProjectMetadata,
+    },
 };
 
 const KARBEAT_MAGIC_HEADER: &[u8; 8] = b"KARBEAT1";
@@ -104,7 +107,17 @@ fn parse_embedded_audio_path(zip_name: &str) -> Option<(u32, String)> {
     Some((id, file_name.to_string()))
 }
 
+/// Loads a project, migrating legacy sample positions with the default session sample rate.
 pub fn load_karbeat_project(path: &Path) -> anyhow::Result<ApplicationState> {
+    load_karbeat_project_at_sample_rate(path, AudioHardwareConfig::default().sample_rate)
+}
+
+/// Loads a project. `sample_rate` is the rate the legacy (sample based) clip
+/// positions were recorded at, used only when migrating older projects.
+pub fn load_karbeat_project_at_sample_rate(
+    path: &Path,
+    sample_rate: u32,
+) -> anyhow::Result<ApplicationState> {
     let mut file =
         File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
 
@@ -125,6 +138,14 @@ pub fn load_karbeat_project(path: &Path) -> anyhow::Result<ApplicationState> {
     let mut app_state: ApplicationState =
         rmp_serde::from_slice(&project_bytes).context("Failed to deserialize project.msgpack")?;
 
+    if app_state.format_version < ProjectFormatVersion::TICK_TIMELINE {
+        migrate_sample_timeline_to_ticks(&mut app_state, sample_rate);
+    }
+
+    // Projects saved before meter changes existed only carry the start signature
+    let initial_signature = app_state.transport.time_signature.into();
+    app_state.transport.time_signature_map.ensure_origin(initial_signature);
+
     let library = Arc::make_mut(&mut app_state.asset_library);
     library.source_map.clear();
 
@@ -192,11 +213,39 @@ pub fn load_karbeat_project(path: &Path) -> anyhow::Result<ApplicationState> {
         .unwrap_or(0);
     library.next_id = library.next_id.max(max_source_id.saturating_add(1));
 
-    app_state.update_max_sample_index();
+    app_state.update_max_tick_index();
 
     Ok(app_state)
 }
 
+/// Converts clip positions of projects saved before the tempo map existed.
+/// Those projects had a single tempo (`transport.bpm`) and stored clips in output samples.
+fn migrate_sample_timeline_to_ticks(app_state: &mut ApplicationState, sample_rate: u32) {
+    let bpm = app_state.transport.bpm;
+    let tempo_map = TempoMap::new(bpm);
+    let to_ticks = |samples: u32| tempo_map.sample_to_tick(samples as u64, sample_rate).ticks() as u32;
+
+    for track_arc in app_state.tracks.values_mut() {
+        let track = Arc::make_mut(track_arc);
+        track.clips = track
+            .clips
+            .iter()
+            .map(|clip| {
+                let mut clip = (**clip).clone();
+                let end = to_ticks(clip.start_time.saturating_add(clip.loop_length));
+                clip.start_time = to_ticks(clip.start_time);
+                clip.offset_start = to_ticks(clip.offset_start);
+                clip.loop_length = end.saturating_sub(clip.start_time);
+                Arc::new(clip)
+            })
+            .collect();
+    }
+
+    app_state.transport.tempo_map = tempo_map;
+    app_state.format_version = ProjectFormatVersion::CURRENT;
+    log::info!("Migrated legacy sample-based timeline to ticks ({bpm} BPM @ {sample_rate} Hz)");
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -292,6 +341,45 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_migrate_legacy_sample_positions_to_ticks() -> anyhow::Result<()> {
+        use crate::core::project::{clip::Clip, KarbeatSource, KarbeatTrack, PatternId, TrackId};
+
+        let mut app_state = ApplicationState::default();
+        app_state.format_version = ProjectFormatVersion::LEGACY;
+        app_state.transport.bpm = 120.0;
+
+        // 120 BPM @ 48 kHz: one beat = 24000 samples = 960 ticks
+        let mut track = KarbeatTrack::default();
+        track.track_type = crate::core::project::TrackType::Midi;
+        track.clips.insert(Arc::new(Clip {
+            name: "legacy".to_string(),
+            id: Default::default(),
+            start_time: 48000,
+            source: KarbeatSource::Midi(PatternId::from(1)),
+            offset_start: 24000,
+            loop_length: 96000,
+            groove: None,
+        }));
+        app_state.tracks.insert(TrackId::from(1), Arc::new(track));
+
+        migrate_sample_timeline_to_ticks(&mut app_state, 48000);
+        app_state.update_max_tick_index();
+
+        let clip = app_state.tracks[&TrackId::from(1)].clips
+            .iter()
+            .next()
+            .context("Migrated track lost its clip")?
+            .clone();
+        assert_eq!(clip.start_time, 1920);
+        assert_eq!(clip.offset_start, 960);
+        assert_eq!(clip.loop_length, 3840);
+        assert_eq!(app_state.max_tick_index, 1920 + 3840);
+        assert_eq!(app_state.transport.tempo_map.initial_bpm(), 120.0);
+        assert_eq!(app_state.format_version, ProjectFormatVersion::CURRENT);
+        Ok(())
+    }
+
     #[test]
     fn it_should_be_able_to_load_valid_project() {
         // Because "validity" in this context requires a valid TOML and ZIP layout,
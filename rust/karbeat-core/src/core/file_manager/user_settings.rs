@@ -0,0 +1,97 @@
+// src/core/file_manager/user_settings.rs
+//
+// Settings that belong to the machine rather than to a project (audio device, buffer size).
+// Stored as TOML in the user's config directory, or wherever `KARBEAT_SETTINGS` points.
+
+use std::{ fs, path::{ Path, PathBuf } };
+
+use anyhow::Context;
+use serde::{ Deserialize, Serialize };
+
+/// Environment variable overriding the settings file location
+pub const SETTINGS_PATH_ENV: &str = "KARBEAT_SETTINGS";
+
+const SETTINGS_FILE_NAME: &str = "settings.toml";
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default)]
+pub struct UserSettings {
+    pub audio: AudioDeviceSettings,
+}
+
+/// Which audio device to open and how. `None` leaves the choice to the host.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct AudioDeviceSettings {
+    pub host: Option<String>,
+    pub output_device: Option<String>,
+    pub input_device: Option<String>,
+    pub sample_rate: Option<u32>,
+    /// Frames per block, a power of two
+    pub buffer_size: Option<u32>,
+}
+
+/// `$KARBEAT_SETTINGS`, else `settings.toml` in the platform config directory.
+/// `None` on platforms where it can't be derived from the environment (set it from the app).
+pub fn default_settings_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(SETTINGS_PATH_ENV) {
+        return Some(PathBuf::from(path));
+    }
+
+    let config_dir = if cfg!(target_os = "windows") {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| {
+            PathBuf::from(home).join("Library").join("Application Support")
+        })
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+    }?;
+    Some(config_dir.join("karbeat").join(SETTINGS_FILE_NAME))
+}
+
+/// Reads the settings file. A missing file gives the defaults.
+pub fn load_user_settings(path: &Path) -> anyhow::Result<UserSettings> {
+    match fs::read_to_string(path) {
+        Ok(text) =>
+            toml::from_str(&text).with_context(|| format!("Invalid settings file {}", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(UserSettings::default()),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    }
+}
+
+pub fn save_user_settings(path: &Path, settings: &UserSettings) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    let text = toml::to_string(settings)?;
+    fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn settings_round_trip_through_the_file() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("nested").join(SETTINGS_FILE_NAME);
+        assert_eq!(load_user_settings(&path)?, UserSettings::default());
+
+        let settings = UserSettings {
+            audio: AudioDeviceSettings {
+                host: Some("ALSA".into()),
+                output_device: Some("Speakers".into()),
+                input_device: None,
+                sample_rate: Some(44_100),
+                buffer_size: Some(256),
+            },
+        };
+        save_user_settings(&path, &settings)?;
+        assert_eq!(load_user_settings(&path)?, settings);
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{core::project::{
-     ApplicationState, Clip, ClipId, Note, NoteId, TrackId,
+     groove::Groove, theory::Scale, ApplicationState, Clip, ClipId, Note, NoteId, TrackId,
 }, shared::id::*};
 
 /// Every action to the projects that are stored in history
@@ -29,6 +29,30 @@ pub enum ProjectAction {
         old_duration: u64,
         new_duration: u64,
     },
+    /// Any change to a note that keeps its ID (e.g. committing a groove or a transform)
+    UpdateNote {
+        pattern_id: PatternId,
+        old_note: Note,
+        new_note: Note,
+    },
+    SetPatternGroove {
+        pattern_id: PatternId,
+        old_groove: Option<Groove>,
+        new_groove: Option<Groove>,
+    },
+    /// The key a pattern is written in
+    SetPatternScale {
+        pattern_id: PatternId,
+        old_scale: Option<Scale>,
+        new_scale: Option<Scale>,
+    },
+    /// The groove a MIDI clip plays with instead of its pattern's
+    SetClipGroove {
+        track_id: TrackId,
+        clip_id: ClipId,
+        old_groove: Option<Groove>,
+        new_groove: Option<Groove>,
+    },
     AddClip {
         track_id: TrackId,
         clip: Clip,
@@ -165,6 +189,32 @@ impl HistoryManager {
                 p.resize_note(index, *old_duration)
                     .map_err(|e| e.to_string())?;
             }
+            ProjectAction::UpdateNote { pattern_id, old_note, .. } => {
+                let pattern = app
+                    .pattern_pool
+                    .get_mut(pattern_id)
+                    .ok_or("Pattern not found")?;
+                Arc::make_mut(pattern)
+                    .replace_note(old_note.clone())
+                    .map_err(|e| e.to_string())?;
+            }
+            ProjectAction::SetPatternGroove { pattern_id, old_groove, .. } => {
+                let pattern = app
+                    .pattern_pool
+                    .get_mut(pattern_id)
+                    .ok_or("Pattern not found")?;
+                Arc::make_mut(pattern).groove = old_groove.clone();
+            }
+            ProjectAction::SetPatternScale { pattern_id, old_scale, .. } => {
+                let pattern = app
+                    .pattern_pool
+                    .get_mut(pattern_id)
+                    .ok_or("Pattern not found")?;
+                Arc::make_mut(pattern).scale = *old_scale;
+            }
+            ProjectAction::SetClipGroove { track_id, clip_id, old_groove, .. } => {
+                app.set_clip_groove(*track_id, *clip_id, old_groove.clone())?;
+            }
             ProjectAction::Batch(actions) => {
                 // Inverse of Batch: Undo actions in REVERSE order
                 for action in actions.iter().rev() {
@@ -200,8 +250,8 @@ impl HistoryManager {
                 // Remove current clip and insert old clip
                 track.clips.retain(|c| c.id != old_clip.id);
                 track.clips.insert(Arc::new(old_clip.clone()));
-                track.update_max_sample_index();
-                app.update_max_sample_index();
+                track.update_max_tick_index();
+                app.update_max_tick_index();
             }
         }
 
@@ -274,6 +324,32 @@ impl HistoryManager {
                 p.resize_note(index, *new_duration)
                     .map_err(|e| e.to_string())?;
             }
+            ProjectAction::UpdateNote { pattern_id, new_note, .. } => {
+                let pattern = app
+                    .pattern_pool
+                    .get_mut(pattern_id)
+                    .ok_or("Pattern not found")?;
+                Arc::make_mut(pattern)
+                    .replace_note(new_note.clone())
+                    .map_err(|e| e.to_string())?;
+            }
+            ProjectAction::SetPatternGroove { pattern_id, new_groove, .. } => {
+                let pattern = app
+                    .pattern_pool
+                    .get_mut(pattern_id)
+                    .ok_or("Pattern not found")?;
+                Arc::make_mut(pattern).groove = new_groove.clone();
+            }
+            ProjectAction::SetPatternScale { pattern_id, new_scale, .. } => {
+                let pattern = app
+                    .pattern_pool
+                    .get_mut(pattern_id)
+                    .ok_or("Pattern not found")?;
+                Arc::make_mut(pattern).scale = *new_scale;
+            }
+            ProjectAction::SetClipGroove { track_id, clip_id, new_groove, .. } => {
+                app.set_clip_groove(*track_id, *clip_id, new_groove.clone())?;
+            }
             ProjectAction::Batch(actions) => {
                 // Forward of Batch: Apply actions in NORMAL order
                 for action in actions.iter() {
@@ -310,8 +386,8 @@ impl HistoryManager {
                 // Remove old clip and insert new clip
                 track.clips.retain(|c| c.id != new_clip.id);
                 track.clips.insert(Arc::new(new_clip.clone()));
-                track.update_max_sample_index();
-                app.update_max_sample_index();
+                track.update_max_tick_index();
+                app.update_max_tick_index();
             }
         }
         Ok(())
@@ -8,6 +8,7 @@ pub enum ResizeEdge {
     Right,
 }
 
+use crate::core::project::groove::Groove;
 use crate::core::project::track::midi::{Pattern};
 use crate::core::project::{track::TrackType, ApplicationState, KarbeatSource};
 use crate::shared::{AudioSourceId, PatternId};
@@ -15,6 +16,9 @@ use crate::shared::id::{ClipId, TrackId};
 
 
 
+/// Shortest clip a batch resize can produce (~100 samples at 120 BPM / 48 kHz)
+const MIN_CLIP_LENGTH_TICKS: i64 = 4;
+
 pub enum ClipSourceType {
     Midi,
     Audio,
@@ -31,6 +35,7 @@ pub enum ClipSourceType {
 ///     source_id: 0,
 ///     offset_start: 0,
 ///     loop_length: 0,
+///     groove: None,
 /// };
 /// ```
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -39,12 +44,17 @@ pub struct Clip {
     pub name: String,
     /// Clip ID
     pub id: ClipId,
-    /// Refer to where it sits on the global timeline
+    /// Refer to where it sits on the global timeline (in ticks)
     pub start_time: u32,
     /// Source of the clip
     pub source: KarbeatSource,
-    pub offset_start: u32, // currently this does nothing since we set it always to 0
-    pub loop_length: u32,  // Refer to length of the entire clip when not shrinked
+    /// How far into the source the clip starts playing (in ticks)
+    pub offset_start: u32,
+    /// Length of the clip on the timeline (in ticks)
+    pub loop_length: u32,
+    /// Groove of a MIDI clip, played instead of its pattern's
+    #[serde(default)]
+    pub groove: Option<Groove>,
 }
 
 impl PartialEq for Clip {
@@ -76,7 +86,7 @@ impl ApplicationState {
         &mut self,
         track_id: TrackId,
         clip: Clip,
-        update_max_sample_index: bool,
+        update_max_tick_index: bool,
     ) -> anyhow::Result<()> {
         // Get the track
         match self.tracks.get_mut(&track_id) {
@@ -87,8 +97,8 @@ impl ApplicationState {
                 // Add Clip & Check bounds
                 // We pass the Clip by value. The track takes ownership and wraps it in Arc.
                 let _ = track.add_clip(clip)?;
-                if update_max_sample_index {
-                    self.update_max_sample_index();
+                if update_max_tick_index {
+                    self.update_max_tick_index();
                 }
             }
             _ => return Err(anyhow::anyhow!("Track not found")),
@@ -100,14 +110,14 @@ impl ApplicationState {
         &mut self,
         track_id: TrackId,
         clip_id: ClipId,
-        update_max_sample_index: bool,
+        update_max_tick_index: bool,
     ) -> anyhow::Result<Arc<Clip>> {
         let deleted_clip = if let Some(track_arc) = self.tracks.get_mut(&track_id) {
             let track= Arc::make_mut(track_arc);
             match track.remove_clip(&clip_id) {
                 Ok(clip) => {
-                    if update_max_sample_index {
-                        self.update_max_sample_index();
+                    if update_max_tick_index {
+                        self.update_max_tick_index();
                     }
                     Ok(clip)
                 }
@@ -129,6 +139,34 @@ impl ApplicationState {
             .map(|arc_clip| (**arc_clip).clone())
     }
 
+    /// Sets or clears the groove of a MIDI clip. Returns the clip before and after.
+    pub fn set_clip_groove(
+        &mut self,
+        track_id: TrackId,
+        clip_id: ClipId,
+        groove: Option<Groove>,
+    ) -> Result<(Clip, Clip), String> {
+        let track_arc = self.tracks.get_mut(&track_id).ok_or("Track not found")?;
+        let track = Arc::make_mut(track_arc);
+
+        let clip_arc = track
+            .clips
+            .iter()
+            .find(|c| c.id == clip_id)
+            .cloned()
+            .ok_or("Clip not found")?;
+        if !matches!(clip_arc.source, KarbeatSource::Midi(_)) {
+            return Err("Only MIDI clips can have a groove".to_string());
+        }
+
+        let mut modified_clip = (*clip_arc).clone();
+        modified_clip.groove = groove;
+        track.clips.remove(&clip_arc);
+        track.clips.insert(Arc::new(modified_clip.clone()));
+
+        Ok(((*clip_arc).clone(), modified_clip))
+    }
+
     /// Move a clip from one track to another (or within the same track) with a new start time.
     /// This removes the clip from the source track and adds it to the target track.
     /// Returns an error if the track or clip is not found, or if types are incompatible.
@@ -155,7 +193,7 @@ impl ApplicationState {
                 .ok_or("Clip not found in source track")?;
 
             track.clips.remove(&clip_arc);
-            track.update_max_sample_index();
+            track.update_max_tick_index();
 
             (*clip_arc).clone()
         };
@@ -177,7 +215,7 @@ impl ApplicationState {
                 .map_err(|e| e.to_string())?;
         }
 
-        self.update_max_sample_index();
+        self.update_max_tick_index();
         Ok(modified_clip)
     }
 
@@ -243,9 +281,9 @@ impl ApplicationState {
 
         // Re-insert the clip
         track.clips.insert(Arc::new(modified_clip.clone()));
-        track.update_max_sample_index();
+        track.update_max_tick_index();
 
-        self.update_max_sample_index();
+        self.update_max_tick_index();
         Ok(modified_clip)
     }
 
@@ -271,17 +309,20 @@ impl ApplicationState {
                     })?
                     .clone();
 
-                let project_sample_rate = self.audio_config.sample_rate as f64;
+                let converter = self.tick_converter();
+                let project_sample_rate = converter.sample_rate() as f64;
                 let source_sample_rate = audio_source.sample_rate as f64;
                 let buffer_len = crate::utils::get_waveform_buffer(&audio_source.buffer)
                     .map(|b| b.len())
                     .unwrap_or(0);
                 let source_frames = (buffer_len as u32) / (audio_source.channels as u32);
-                let timeline_length = if source_sample_rate > 0.0 {
-                    ((source_frames as f64) * (project_sample_rate / source_sample_rate)) as u32
+                let length_samples = if source_sample_rate > 0.0 {
+                    ((source_frames as f64) * (project_sample_rate / source_sample_rate)) as u64
                 } else {
-                    source_frames // Fallback to avoid division by zero
+                    source_frames as u64 // Fallback to avoid division by zero
                 };
+                // The clip covers the audio at the tempo found where it is dropped
+                let timeline_length = converter.span_to_ticks(start_time, length_samples) as u32;
 
                 let new_clip_id = ClipId::next(&mut self.clip_counter);
 
@@ -292,20 +333,13 @@ impl ApplicationState {
                     source: KarbeatSource::Audio(source_id),
                     offset_start: 0,
                     loop_length: timeline_length,
+                    groove: None,
                 };
                 self.add_clip_to_track(track_id, clip.clone(), true)?;
 
                 clip
             }
             ClipSourceType::Midi => {
-                let sample_rate = self.audio_config.sample_rate;
-                let bpm = if self.transport.bpm == 0.0 {
-                    120.0
-                } else {
-                    self.transport.bpm
-                };
-                let samples_per_beat = ((sample_rate as f32) / (bpm / 60.0)) as u32;
-
                 // Use existing pattern if source_id provided, otherwise create new
                 let (pattern_id, timeline_length) = if let Some(id) = source_id {
                     let pattern_id = PatternId::from(id);
@@ -314,15 +348,15 @@ impl ApplicationState {
                         .get(&pattern_id)
                         .ok_or_else(|| anyhow::anyhow!("Pattern {} not found", id))?;
 
-                    // Calculate length from pattern's ticks
-                    let samples_per_tick = (samples_per_beat as f32) / 960.0;
-                    let length = ((pattern.length_ticks as f32) * samples_per_tick) as u32;
-                    (pattern_id, length)
+                    (pattern_id, pattern.length_ticks as u32)
                 } else {
-                    // Create new pattern
+                    // Create new pattern, one bar long in the meter where it is dropped
                     let new_pattern_id = PatternId::next(&mut self.pattern_counter);
-                    let default_ticks = 4 * 960;
-                    let timeline_length = 4 * samples_per_beat;
+                    let default_ticks = self
+                        .transport
+                        .time_signature_map
+                        .ticks_per_bar_at(start_time as u64);
+                    let timeline_length = default_ticks as u32;
 
                     let pattern = Arc::new(Pattern {
                         id: new_pattern_id,
@@ -330,6 +364,8 @@ impl ApplicationState {
                         length_ticks: default_ticks,
                         notes: Vec::new(),
                         next_note_id: 0,
+                        groove: None,
+                        scale: None,
                     });
                     self.pattern_pool.insert(new_pattern_id, pattern);
                     (new_pattern_id, timeline_length)
@@ -349,6 +385,7 @@ impl ApplicationState {
                     source: KarbeatSource::Midi(pattern_id),
                     offset_start: 0,
                     loop_length: timeline_length,
+                    groove: None,
                 };
 
                 self.add_clip_to_track(track_id, clip.clone(), true)?;
@@ -364,7 +401,7 @@ impl ApplicationState {
         source_track_id: TrackId,
         target_track_id: TrackId,
         clip_ids: Vec<ClipId>,
-        delta_samples: i64,
+        delta_ticks: i64,
     ) -> Result<Vec<Clip>, String> {
         let mut result_clips = Vec::new();
         let target_type = if let Some(target) = self.tracks.get(&target_track_id) {
@@ -387,13 +424,13 @@ impl ApplicationState {
                     let mut modified_clip = (*clip).clone();
                     // Apply delta with clamping to 0
                     let new_start =
-                        ((modified_clip.start_time as i64) + delta_samples).max(0) as u32;
+                        ((modified_clip.start_time as i64) + delta_ticks).max(0) as u32;
                     modified_clip.start_time = new_start;
                     track.clips.insert(Arc::new(modified_clip.clone()));
                     result_clips.push(modified_clip);
                 }
             }
-            track.update_max_sample_index();
+            track.update_max_tick_index();
         } else {
             // Cross-track move
             let mut clips_to_move = Vec::new();
@@ -424,7 +461,7 @@ impl ApplicationState {
                         clips_to_move.push(clip);
                     }
                 }
-                source_track.update_max_sample_index();
+                source_track.update_max_tick_index();
             }
 
             // Add to target track
@@ -435,13 +472,13 @@ impl ApplicationState {
             );
             for clip in clips_to_move {
                 let mut modified_clip = (*clip).clone();
-                let new_start = ((modified_clip.start_time as i64) + delta_samples).max(0) as u32;
+                let new_start = ((modified_clip.start_time as i64) + delta_ticks).max(0) as u32;
                 modified_clip.start_time = new_start;
                 let _ = target_track.add_clip(modified_clip.clone());
                 result_clips.push(modified_clip);
             }
         }
-        self.update_max_sample_index();
+        self.update_max_tick_index();
         Ok(result_clips)
     }
 
@@ -450,7 +487,7 @@ impl ApplicationState {
         track_id: TrackId,
         clip_ids: Vec<ClipId>,
         edge: ResizeEdge,
-        delta_samples: i64,
+        delta_ticks: i64,
     ) -> Result<Vec<Clip>, String> {
         let track_arc = self.tracks.get_mut(&track_id).ok_or("Track not found")?;
         let track = Arc::make_mut(track_arc);
@@ -465,16 +502,16 @@ impl ApplicationState {
                 match edge {
                     ResizeEdge::Right => {
                         let current_end = modified_clip.start_time + modified_clip.loop_length;
-                        let new_end = ((current_end as i64) + delta_samples)
-                            .max((modified_clip.start_time as i64) + 100)
+                        let new_end = ((current_end as i64) + delta_ticks)
+                            .max((modified_clip.start_time as i64) + MIN_CLIP_LENGTH_TICKS)
                             as u32;
                         modified_clip.loop_length = new_end - modified_clip.start_time;
                     }
                     ResizeEdge::Left => {
                         let old_start = modified_clip.start_time;
                         let old_end = old_start + modified_clip.loop_length;
-                        let new_start = ((old_start as i64) + delta_samples)
-                            .clamp(0, (old_end as i64) - 100)
+                        let new_start = ((old_start as i64) + delta_ticks)
+                            .clamp(0, ((old_end as i64) - MIN_CLIP_LENGTH_TICKS).max(0))
                             as u32;
 
                         let delta = (new_start as i64) - (old_start as i64);
@@ -491,8 +528,8 @@ impl ApplicationState {
                 result_clips.push(modified_clip);
             }
         }
-        track.update_max_sample_index();
-        self.update_max_sample_index();
+        track.update_max_tick_index();
+        self.update_max_tick_index();
         Ok(result_clips)
     }
 }
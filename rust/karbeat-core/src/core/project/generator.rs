@@ -45,7 +45,7 @@ impl ApplicationState {
             track.remove_clip_by_source_id(generator_id, true);
         }
 
-        self.update_max_sample_index();
+        self.update_max_tick_index();
         Some(generator_id)
     }
 }
@@ -0,0 +1,224 @@
+// src/core/project/groove.rs
+//
+// Swing and groove templates. A groove moves notes off the grid and scales their velocity step
+// by step, for a whole pattern or for a single MIDI clip. The engine applies it while scheduling,
+// so the notes keep their written positions until the groove is committed into them.
+
+use serde::{ Deserialize, Serialize };
+
+use crate::core::project::{ Note, musical_time::TimeSignatureMap, tempo_map::TICKS_PER_BEAT };
+
+/// Furthest a groove moves a note off the grid, in ticks (a 16th note)
+pub const MAX_GROOVE_SHIFT: u64 = (TICKS_PER_BEAT as u64) / 4;
+
+/// Note value swing is applied to.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwingDivision {
+    Eighth,
+    Sixteenth,
+}
+
+impl SwingDivision {
+    pub fn step_ticks(self) -> u64 {
+        match self {
+            SwingDivision::Eighth => (TICKS_PER_BEAT as u64) / 2,
+            SwingDivision::Sixteenth => (TICKS_PER_BEAT as u64) / 4,
+        }
+    }
+}
+
+/// Timing and velocity of one grid step.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct GrooveStep {
+    /// Ticks notes on this step are moved by, negative moves them earlier
+    pub timing: i32,
+    /// Velocity multiplier of notes on this step
+    pub velocity: f32,
+}
+
+impl Default for GrooveStep {
+    fn default() -> Self {
+        Self { timing: 0, velocity: 1.0 }
+    }
+}
+
+/// A groove template: one step per grid line, repeated from the start of the pattern.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Groove {
+    pub name: String,
+    /// Grid the steps sit on
+    pub step_ticks: u64,
+    pub steps: Vec<GrooveStep>,
+    /// How much of the groove is applied (0.0 - 1.0)
+    pub amount: f32,
+}
+
+impl Groove {
+    /// Swing as a percentage of a pair of steps: 50 is straight, 66 is close to a triplet feel
+    /// and 75 is the most allowed. Every second step is delayed.
+    pub fn swing(division: SwingDivision, percent: f32) -> Self {
+        let percent = percent.clamp(50.0, 75.0);
+        let step_ticks = division.step_ticks();
+        let delay = ((percent / 100.0 - 0.5) * 2.0 * (step_ticks as f32)).round();
+        let note_value = match division {
+            SwingDivision::Eighth => "8th",
+            SwingDivision::Sixteenth => "16th",
+        };
+        Self {
+            name: format!("Swing {note_value} {}%", percent.round()),
+            step_ticks,
+            steps: vec![GrooveStep::default(), GrooveStep { timing: delay as i32, velocity: 1.0 }],
+            amount: 1.0,
+        }
+    }
+
+    /// Takes the groove of played notes: how far, on average, the notes near each grid step
+    /// are off it, and how loud they are compared to the rest. The template covers
+    /// `length_ticks`, steps without notes are left straight.
+    pub fn from_notes(
+        name: impl Into<String>,
+        notes: &[Note],
+        step_ticks: u64,
+        length_ticks: u64
+    ) -> anyhow::Result<Self> {
+        if step_ticks == 0 {
+            return Err(anyhow::anyhow!("Groove grid must be at least one tick"));
+        }
+        let notes: Vec<&Note> = notes
+            .iter()
+            .filter(|note| !note.mute)
+            .collect();
+        if notes.is_empty() {
+            return Err(anyhow::anyhow!("There are no notes to take a groove from"));
+        }
+
+        let step_count = length_ticks.div_ceil(step_ticks).max(1) as usize;
+        // Summed offset, summed velocity and note count of every step
+        let mut sums = vec![(0i64, 0u32, 0u32); step_count];
+        for note in &notes {
+            let index = (note.start_tick + step_ticks / 2) / step_ticks;
+            let offset = (note.start_tick as i64) - ((index * step_ticks) as i64);
+            let sum = &mut sums[(index as usize) % step_count];
+            sum.0 += offset;
+            sum.1 += note.velocity as u32;
+            sum.2 += 1;
+        }
+
+        let mean_velocity =
+            (notes.iter().map(|note| note.velocity as f32).sum::<f32>() / (notes.len() as f32)).max(1.0);
+        let reach = MAX_GROOVE_SHIFT.min(step_ticks / 2) as i64;
+        let steps = sums
+            .into_iter()
+            .map(|(offset, velocity, count)| {
+                if count == 0 {
+                    return GrooveStep::default();
+                }
+                GrooveStep {
+                    timing: (offset / (count as i64)).clamp(-reach, reach) as i32,
+                    velocity: (velocity as f32) / (count as f32) / mean_velocity,
+                }
+            })
+            .collect();
+
+        Ok(Self { name: name.into(), step_ticks, steps, amount: 1.0 })
+    }
+
+    /// Takes the groove of notes that have no length of their own to go by, such as an
+    /// imported MIDI file: the template covers the notes up to the end of their last bar,
+    /// with bars laid out by `signatures`.
+    pub fn from_played_notes(
+        name: impl Into<String>,
+        notes: &[Note],
+        step_ticks: u64,
+        signatures: &TimeSignatureMap
+    ) -> anyhow::Result<Self> {
+        let last_start = notes
+            .iter()
+            .map(|note| note.start_tick)
+            .max()
+            .unwrap_or(0);
+        let last_bar = signatures.position_at(last_start).bar;
+        let length_ticks = signatures.bar_start_tick(last_bar + 1);
+        Self::from_notes(name, notes, step_ticks, length_ticks)
+    }
+
+    pub fn with_amount(mut self, amount: f32) -> Self {
+        self.amount = amount.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Start tick and velocity of a note once the groove is applied. `start_tick` is counted
+    /// from the start of the pattern.
+    pub fn apply(&self, start_tick: u64, velocity: u8) -> (u64, u8) {
+        if self.steps.is_empty() || self.step_ticks == 0 || self.amount <= 0.0 {
+            return (start_tick, velocity);
+        }
+
+        let index = (start_tick + self.step_ticks / 2) / self.step_ticks;
+        let step = &self.steps[(index as usize) % self.steps.len()];
+
+        let reach = MAX_GROOVE_SHIFT as f32;
+        let shift = ((step.timing as f32) * self.amount).round().clamp(-reach, reach) as i64;
+        let start_tick = ((start_tick as i64) + shift).max(0) as u64;
+
+        let scale = 1.0 + (step.velocity - 1.0) * self.amount;
+        let velocity = ((velocity as f32) * scale).round().clamp(1.0, 127.0) as u8;
+
+        (start_tick, velocity)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::project::musical_time::TimeSignature;
+    use crate::shared::id::NoteId;
+
+    fn note(start_tick: u64, velocity: u8) -> Note {
+        Note {
+            id: NoteId::from(start_tick as u32),
+            start_tick,
+            duration: 60,
+            key: 42,
+            velocity,
+            probability: 1.0,
+            micro_offset: 0,
+            mute: false,
+        }
+    }
+
+    #[test]
+    fn swing_delays_every_second_step() {
+        let swing = Groove::swing(SwingDivision::Sixteenth, 62.5);
+        assert_eq!(swing.apply(0, 100), (0, 100));
+        assert_eq!(swing.apply(240, 100), (300, 100));
+        assert_eq!(swing.apply(480, 100), (480, 100));
+
+        // Half the amount, half the delay
+        assert_eq!(swing.with_amount(0.5).apply(720, 100), (750, 100));
+    }
+
+    #[test]
+    fn templates_are_taken_from_played_notes() -> anyhow::Result<()> {
+        // Offbeats played late and soft, downbeats loud
+        let played = [note(0, 120), note(250, 60), note(480, 120), note(730, 60)];
+        let groove = Groove::from_notes("Played", &played, 240, 960)?;
+
+        assert_eq!(groove.steps.len(), 4);
+        assert_eq!(groove.steps[1].timing, 10);
+        assert_eq!(groove.apply(240, 90), (250, 60));
+        assert_eq!(groove.apply(960, 90), (960, 120));
+        Ok(())
+    }
+
+    #[test]
+    fn played_notes_are_covered_to_the_end_of_their_bar() -> anyhow::Result<()> {
+        let played = [note(0, 100), note(3_000, 100)];
+        let waltz = TimeSignatureMap::new(TimeSignature::new(3, 4));
+        let groove = Groove::from_played_notes("Waltz", &played, 240, &waltz)?;
+
+        // Two bars of 3/4, not one bar of 4/4
+        assert_eq!(groove.steps.len(), 24);
+        Ok(())
+    }
+}
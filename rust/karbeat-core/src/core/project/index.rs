@@ -22,6 +22,7 @@ pub use super::transport::TransportState;
 use crate::{
     core::project::{
         automation::{ AutomationLane, AutomationPoint, AutomationTarget },
+        midi_mapping::MidiMappings,
         mixer::MixerState,
     },
 };
@@ -62,8 +63,17 @@ pub struct ApplicationState {
     // Counter for clips
     pub clip_counter: u32,
 
-    // Max samples index in the timeline
-    pub max_sample_index: u32,
+    // Max tick index in the timeline (end of the last clip)
+    pub max_tick_index: u32,
+
+    // Timeline format of the saved project. Files written before clips were
+    // anchored in ticks don't carry it and deserialize as legacy.
+    #[serde(default = "ProjectFormatVersion::legacy")]
+    pub format_version: ProjectFormatVersion,
+
+    // MIDI controller mappings (CC → automation target)
+    #[serde(default)]
+    pub midi_mappings: MidiMappings,
 
     // ========== NON-SERIALIZABLE SESSION DATA ===============
     // These fields are marked to be skipped during Save/Load
@@ -74,6 +84,28 @@ pub struct ApplicationState {
     pub clipboard: ClipboardContent,
 }
 
+/// Version of the on-disk project layout, used to migrate older projects on load.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ProjectFormatVersion(pub u32);
+
+impl ProjectFormatVersion {
+    /// Clip positions stored in output samples
+    pub const LEGACY: ProjectFormatVersion = ProjectFormatVersion(0);
+    /// Clip positions stored in ticks, tempo map in the transport
+    pub const TICK_TIMELINE: ProjectFormatVersion = ProjectFormatVersion(1);
+    pub const CURRENT: ProjectFormatVersion = Self::TICK_TIMELINE;
+
+    pub fn legacy() -> Self {
+        Self::LEGACY
+    }
+}
+
+impl Default for ProjectFormatVersion {
+    fn default() -> Self {
+        Self::CURRENT
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum KarbeatSource {
     /// Points to an AudioWaveform
@@ -189,13 +221,13 @@ impl Default for AudioHardwareConfig {
 }
 
 impl ApplicationState {
-    pub fn update_max_sample_index(&mut self) {
-        self.max_sample_index = self.tracks
+    pub fn update_max_tick_index(&mut self) {
+        self.max_tick_index = self.tracks
             .values_mut()
             .map(|t| {
                 let track_mut = Arc::make_mut(t);
-                track_mut.update_max_sample_index();
-                track_mut.max_sample_index
+                track_mut.update_max_tick_index();
+                track_mut.max_tick_index
             })
             .max()
             .unwrap_or(0);
@@ -219,7 +251,7 @@ impl ApplicationState {
             track.remove_clip_by_source_id(source_id, false);
         }
 
-        self.update_max_sample_index();
+        self.update_max_tick_index();
 
         Ok(source_id)
     }
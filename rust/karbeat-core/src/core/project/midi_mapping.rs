@@ -0,0 +1,218 @@
+// src/core/project/midi_mapping.rs
+//
+// MIDI controller mappings. A mapping binds a control change (channel + controller number) to an
+// automation target, scaled into a range. The table is saved with the project, the learn and
+// soft-takeover state only lives for the session.
+
+use hashbrown::HashMap;
+use serde::{ Deserialize, Serialize };
+
+use crate::{ core::project::automation::AutomationTarget, shared::id::TrackId };
+
+/// Highest value a 7-bit controller sends
+const CC_MAX: f32 = 127.0;
+
+// ============================================================================
+// MAPPING
+// ============================================================================
+
+/// A control change source: MIDI channel (0–15) and controller number (0–127).
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MidiCc {
+    pub channel: u8,
+    pub controller: u8,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct MidiMapping {
+    pub id: u32,
+    pub source: MidiCc,
+    pub target: AutomationTarget,
+    /// Target value sent for CC 0 (CC 127 when inverted)
+    pub min: f32,
+    /// Target value sent for CC 127 (CC 0 when inverted)
+    pub max: f32,
+    pub invert: bool,
+    /// Ignore the controller until it reaches the target's current value, so a knob that sits
+    /// elsewhere doesn't make the parameter jump.
+    pub soft_takeover: bool,
+}
+
+impl MidiMapping {
+    /// Position of a CC value in the mapped range (0.0–1.0), inversion applied.
+    pub fn position(&self, cc_value: u8) -> f32 {
+        let position = (cc_value.min(127) as f32) / CC_MAX;
+        if self.invert { 1.0 - position } else { position }
+    }
+
+    /// Target value at a position in the mapped range.
+    pub fn value_at(&self, position: f32) -> f32 {
+        self.min + (self.max - self.min) * position.clamp(0.0, 1.0)
+    }
+
+    /// Position of a target value in the mapped range, clamped to 0.0–1.0.
+    pub fn position_of(&self, value: f32) -> f32 {
+        let span = self.max - self.min;
+        if span.abs() <= f32::EPSILON {
+            return 0.0;
+        }
+        ((value - self.min) / span).clamp(0.0, 1.0)
+    }
+
+    /// Target value for a CC value.
+    pub fn value_for(&self, cc_value: u8) -> f32 {
+        self.value_at(self.position(cc_value))
+    }
+}
+
+// ============================================================================
+// MAPPING TABLE
+// ============================================================================
+
+/// Every controller mapping of the project. One source may drive several targets.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct MidiMappings {
+    pub mappings: Vec<MidiMapping>,
+    pub counter: u32,
+}
+
+impl MidiMappings {
+    /// Adds a mapping and returns it with its new id.
+    pub fn add(
+        &mut self,
+        source: MidiCc,
+        target: AutomationTarget,
+        min: f32,
+        max: f32
+    ) -> &MidiMapping {
+        self.counter += 1;
+        self.mappings.push(MidiMapping {
+            id: self.counter,
+            source,
+            target,
+            min,
+            max,
+            invert: false,
+            soft_takeover: false,
+        });
+        &self.mappings[self.mappings.len() - 1]
+    }
+
+    pub fn get(&self, id: u32) -> Option<&MidiMapping> {
+        self.mappings.iter().find(|m| m.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: u32) -> Option<&mut MidiMapping> {
+        self.mappings.iter_mut().find(|m| m.id == id)
+    }
+
+    pub fn remove(&mut self, id: u32) -> Option<MidiMapping> {
+        let index = self.mappings.iter().position(|m| m.id == id)?;
+        Some(self.mappings.remove(index))
+    }
+
+    /// Removes the mappings that drive `target`, returning how many there were.
+    pub fn remove_for_target(&mut self, target: &AutomationTarget) -> usize {
+        let before = self.mappings.len();
+        self.mappings.retain(|m| m.target != *target);
+        before - self.mappings.len()
+    }
+
+    /// Remove all mappings that reference a track (used when deleting tracks).
+    pub fn remove_for_track(&mut self, track_id: TrackId) {
+        self.mappings.retain(|m| !m.target.references_track(track_id));
+    }
+
+    /// Mappings driven by a control change source.
+    pub fn for_source(&self, source: MidiCc) -> impl Iterator<Item = &MidiMapping> {
+        self.mappings.iter().filter(move |m| m.source == source)
+    }
+}
+
+// ============================================================================
+// RUNTIME STATE
+// ============================================================================
+
+/// Session state of the controller mappings, not saved with the project.
+#[derive(Debug, Default)]
+pub struct MidiLearnState {
+    /// Target waiting for the next incoming control change
+    pub armed: Option<AutomationTarget>,
+    /// Soft-takeover progress by mapping id
+    pub takeover: HashMap<u32, Takeover>,
+}
+
+/// Soft-takeover progress of one mapping.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Takeover {
+    /// The controller reached the target value and drives it
+    pub picked_up: bool,
+    /// Position of the last CC value received
+    pub last_position: Option<f32>,
+    /// Value last sent to the target
+    pub last_sent: Option<f32>,
+}
+
+impl Takeover {
+    /// Feeds a controller position. Returns true when the controller drives the target and
+    /// the value should be sent. `current` is the target's position now, `tolerance` how close
+    /// the controller has to come to pick it up.
+    pub fn update(&mut self, position: f32, current: f32, tolerance: f32) -> bool {
+        if !self.picked_up {
+            let crossed = self.last_position.is_some_and(|last| {
+                (last - current).signum() != (position - current).signum()
+            });
+            self.picked_up = crossed || (position - current).abs() <= tolerance;
+        }
+        self.last_position = Some(position);
+        self.picked_up
+    }
+
+    /// Drops the pickup when the target was changed from elsewhere (UI, undo, another mapping).
+    pub fn check_moved(&mut self, current_value: f32, tolerance: f32) {
+        if let Some(sent) = self.last_sent {
+            if (current_value - sent).abs() > tolerance {
+                self.picked_up = false;
+                self.last_sent = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn soft_takeover_waits_for_the_controller_to_reach_the_value() {
+        let mapping = MidiMapping {
+            id: 1,
+            source: MidiCc { channel: 0, controller: 7 },
+            target: AutomationTarget::MasterVolume,
+            min: -60.0,
+            max: 0.0,
+            invert: false,
+            soft_takeover: true,
+        };
+        let tolerance = 1.0 / CC_MAX;
+        // Parameter sits at -30 dB, halfway up
+        let current = mapping.position_of(-30.0);
+        let mut takeover = Takeover::default();
+
+        assert!(!takeover.update(mapping.position(10), current, tolerance));
+        assert!(!takeover.update(mapping.position(40), current, tolerance));
+        // Jumping across the current value picks it up
+        assert!(takeover.update(mapping.position(70), current, tolerance));
+        takeover.last_sent = Some(mapping.value_for(70));
+
+        // Moved from the UI: wait again, this time from above
+        takeover.check_moved(-50.0, 60.0 / CC_MAX);
+        let current = mapping.position_of(-50.0);
+        assert!(!takeover.update(mapping.position(100), current, tolerance));
+        assert!(takeover.update(mapping.position(21), current, tolerance));
+
+        let inverted = MidiMapping { invert: true, ..mapping };
+        assert_eq!(inverted.value_for(127), -60.0);
+        assert_eq!(inverted.value_for(0), 0.0);
+    }
+}
@@ -5,9 +5,16 @@ pub mod automation;
 pub mod clip;
 pub mod clipboard;
 pub mod generator;
+pub mod groove;
+pub mod midi_mapping;
 pub mod mixer;
+pub mod musical_time;
 pub mod plugin;
+pub mod quantize;
+pub mod tempo_map;
+pub mod theory;
 pub mod track;
+pub mod transform;
 pub mod transport;
 
 pub use index::*;
\ No newline at end of file
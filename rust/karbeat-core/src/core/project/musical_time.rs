@@ -0,0 +1,362 @@
+// src/core/project/musical_time.rs
+//
+// Time signatures and bar/beat/tick arithmetic.
+// The tempo map decides where a tick lands in seconds; this module decides
+// which bar and beat a tick belongs to. The engine, the clip APIs and grid
+// quantization all go through `TimeSignatureMap` so that odd meters and
+// meter changes line up everywhere.
+
+use serde::{ Deserialize, Serialize };
+
+use crate::core::project::tempo_map::TICKS_PER_BEAT;
+
+/// Largest denominator accepted (a 64th note beat).
+const MAX_DENOMINATOR: u8 = 64;
+
+// ============================================================================
+// TIME SIGNATURE
+// ============================================================================
+
+/// A meter such as 4/4 or 7/8.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimeSignature {
+    /// Beats per bar
+    pub numerator: u8,
+    /// Note value of one beat (4 = quarter note, 8 = eighth note, ...)
+    pub denominator: u8,
+}
+
+impl Default for TimeSignature {
+    fn default() -> Self {
+        Self::COMMON
+    }
+}
+
+impl TimeSignature {
+    /// 4/4
+    pub const COMMON: TimeSignature = TimeSignature { numerator: 4, denominator: 4 };
+
+    pub fn new(numerator: u8, denominator: u8) -> Self {
+        Self { numerator, denominator }
+    }
+
+    /// A signature is valid when it has at least one beat and its denominator is a
+    /// power of two no larger than 64.
+    pub fn is_valid(&self) -> bool {
+        self.numerator > 0
+            && self.denominator.is_power_of_two()
+            && self.denominator <= MAX_DENOMINATOR
+    }
+
+    /// Length of one beat (one denominator note) in ticks.
+    pub fn ticks_per_beat(&self) -> u64 {
+        let denominator = self.denominator.clamp(1, MAX_DENOMINATOR) as u64;
+        ((TICKS_PER_BEAT as u64) * 4) / denominator
+    }
+
+    /// Length of one bar in ticks.
+    pub fn ticks_per_bar(&self) -> u64 {
+        (self.numerator.max(1) as u64) * self.ticks_per_beat()
+    }
+
+    /// Position of `tick` assuming this signature holds from tick 0.
+    pub fn position_at(&self, tick: u64) -> MusicalPosition {
+        position_in_segment(tick, 0, 0, *self)
+    }
+}
+
+impl From<(u8, u8)> for TimeSignature {
+    fn from((numerator, denominator): (u8, u8)) -> Self {
+        Self { numerator, denominator }
+    }
+}
+
+impl From<TimeSignature> for (u8, u8) {
+    fn from(value: TimeSignature) -> Self {
+        (value.numerator, value.denominator)
+    }
+}
+
+/// A meter change. Changes always happen on a bar line.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeSignatureEvent {
+    /// Bar (0-based) where the new signature starts
+    pub bar: u32,
+    pub signature: TimeSignature,
+}
+
+impl TimeSignatureEvent {
+    pub fn new(bar: u32, signature: TimeSignature) -> Self {
+        Self { bar, signature }
+    }
+}
+
+// ============================================================================
+// POSITIONS & GRID
+// ============================================================================
+
+/// A timeline position split into bar, beat and remaining ticks. All fields are 0-based.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MusicalPosition {
+    pub bar: u32,
+    /// Beat inside the bar, counted in units of the signature denominator
+    pub beat: u32,
+    /// Ticks past the start of the beat
+    pub tick: u32,
+    /// Signature in effect at this position
+    pub signature: TimeSignature,
+}
+
+/// Grid resolution used to snap or quantize timeline positions.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridDivision {
+    /// Snap to bar lines
+    Bar,
+    /// Snap to the beats of the current signature
+    Beat,
+    /// Snap to a fixed number of ticks, counted from the bar line
+    Ticks(u32),
+}
+
+impl GridDivision {
+    /// Grid of a note value: 4 for quarter notes, 16 for sixteenths. A triplet grid fits
+    /// three steps in the space of two.
+    pub fn note(value: u32, triplet: bool) -> Self {
+        let ticks = (TICKS_PER_BEAT * 4) / value.max(1);
+        GridDivision::Ticks(if triplet { (ticks * 2) / 3 } else { ticks })
+    }
+
+    /// Length of one grid step under `signature`.
+    pub fn step_ticks(&self, signature: TimeSignature) -> u64 {
+        match self {
+            GridDivision::Bar => signature.ticks_per_bar(),
+            GridDivision::Beat => signature.ticks_per_beat(),
+            GridDivision::Ticks(ticks) => *ticks as u64,
+        }
+    }
+}
+
+/// How a position is moved onto the grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SnapMode {
+    /// Move back to the previous grid line
+    #[default]
+    Floor,
+    /// Move to the closest grid line
+    Nearest,
+}
+
+/// Snaps `tick` to a grid that restarts at every bar line, so a quarter-note grid in
+/// 7/8 never crosses into the next bar.
+fn snap_in_bar(tick: u64, bar_start: u64, bar_len: u64, step: u64, mode: SnapMode) -> u64 {
+    if step == 0 {
+        return tick;
+    }
+    let offset = tick.saturating_sub(bar_start).min(bar_len);
+    let previous = (offset / step) * step;
+    let snapped = match mode {
+        SnapMode::Floor => previous,
+        SnapMode::Nearest => {
+            // The bar line itself is always a grid line, even when `step` does not divide the bar
+            let next = (previous + step).min(bar_len);
+            if offset - previous < next - offset { previous } else { next }
+        }
+    };
+    bar_start + snapped
+}
+
+fn position_in_segment(
+    tick: u64,
+    segment_start: u64,
+    segment_bar: u32,
+    signature: TimeSignature
+) -> MusicalPosition {
+    let offset = tick.saturating_sub(segment_start);
+    let bar_len = signature.ticks_per_bar();
+    let beat_len = signature.ticks_per_beat();
+    let in_bar = offset % bar_len;
+
+    MusicalPosition {
+        bar: segment_bar.saturating_add((offset / bar_len).min(u32::MAX as u64) as u32),
+        beat: (in_bar / beat_len) as u32,
+        tick: (in_bar % beat_len) as u32,
+        signature,
+    }
+}
+
+// ============================================================================
+// TIME SIGNATURE MAP
+// ============================================================================
+
+/// Sorted list of meter changes. The first event always sits on bar 0.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct TimeSignatureMap {
+    events: Vec<TimeSignatureEvent>,
+}
+
+impl TimeSignatureMap {
+    /// Creates a map with a single signature for the whole project.
+    pub fn new(signature: TimeSignature) -> Self {
+        Self {
+            events: vec![TimeSignatureEvent::new(0, signature)],
+        }
+    }
+
+    pub fn events(&self) -> &[TimeSignatureEvent] {
+        &self.events
+    }
+
+    /// Signature at the start of the project.
+    pub fn initial(&self) -> TimeSignature {
+        self.events
+            .first()
+            .map(|e| e.signature)
+            .unwrap_or_default()
+    }
+
+    /// Replaces the signature on bar 0.
+    pub fn set_initial(&mut self, signature: TimeSignature) {
+        self.ensure_origin(signature);
+        if let Some(first) = self.events.first_mut() {
+            first.signature = signature;
+        }
+    }
+
+    /// Adds an event on bar 0 with `signature` if the map does not start there yet.
+    /// Used for projects saved before meter changes existed.
+    pub fn ensure_origin(&mut self, signature: TimeSignature) {
+        if self.events.first().is_none_or(|e| e.bar != 0) {
+            self.events.insert(0, TimeSignatureEvent::new(0, signature));
+        }
+    }
+
+    /// Inserts a meter change, replacing any existing change on the same bar.
+    pub fn insert(&mut self, event: TimeSignatureEvent) {
+        match self.events.binary_search_by(|e| e.bar.cmp(&event.bar)) {
+            Ok(idx) => {
+                self.events[idx] = event;
+            }
+            Err(idx) => self.events.insert(idx, event),
+        }
+    }
+
+    /// Removes the meter change on `bar`. The event on bar 0 cannot be removed.
+    pub fn remove_at(&mut self, bar: u32) -> Option<TimeSignatureEvent> {
+        if bar == 0 {
+            return None;
+        }
+        let idx = self.events.binary_search_by(|e| e.bar.cmp(&bar)).ok()?;
+        Some(self.events.remove(idx))
+    }
+
+    /// Walks the events and returns `(start_tick, event)` of the last segment accepted
+    /// by `is_before`, which receives the start tick and event of each candidate.
+    fn find_segment<F>(&self, mut is_before: F) -> (u64, TimeSignatureEvent)
+        where F: FnMut(u64, &TimeSignatureEvent) -> bool
+    {
+        let mut current = TimeSignatureEvent::new(0, self.initial());
+        let mut start = 0u64;
+
+        for next in self.events.iter().skip(1) {
+            let bars = next.bar.saturating_sub(current.bar) as u64;
+            let next_start = start + bars * current.signature.ticks_per_bar();
+            if !is_before(next_start, next) {
+                break;
+            }
+            start = next_start;
+            current = *next;
+        }
+
+        (start, current)
+    }
+
+    /// Signature in effect at the given tick.
+    pub fn signature_at_tick(&self, tick: u64) -> TimeSignature {
+        self.find_segment(|start, _| start <= tick).1.signature
+    }
+
+    /// Signature in effect on the given bar (0-based).
+    pub fn signature_at_bar(&self, bar: u32) -> TimeSignature {
+        self.find_segment(|_, event| event.bar <= bar).1.signature
+    }
+
+    /// Tick where the given bar (0-based) starts.
+    pub fn bar_start_tick(&self, bar: u32) -> u64 {
+        let (start, event) = self.find_segment(|_, event| event.bar <= bar);
+        start + (bar.saturating_sub(event.bar) as u64) * event.signature.ticks_per_bar()
+    }
+
+    /// Length in ticks of the bar that contains `tick`.
+    pub fn ticks_per_bar_at(&self, tick: u64) -> u64 {
+        self.signature_at_tick(tick).ticks_per_bar()
+    }
+
+    /// Splits a tick position into bar, beat and ticks.
+    pub fn position_at(&self, tick: u64) -> MusicalPosition {
+        let (start, event) = self.find_segment(|start, _| start <= tick);
+        position_in_segment(tick, start, event.bar, event.signature)
+    }
+
+    /// Inverse of [`position_at`](Self::position_at). Beats and ticks past the end of the
+    /// bar simply carry over.
+    pub fn tick_at(&self, bar: u32, beat: u32, tick: u32) -> u64 {
+        let signature = self.signature_at_bar(bar);
+        self.bar_start_tick(bar) + (beat as u64) * signature.ticks_per_beat() + (tick as u64)
+    }
+
+    /// Moves `tick` onto the grid. Grid lines restart on every bar line.
+    pub fn snap(&self, tick: u64, grid: GridDivision, mode: SnapMode) -> u64 {
+        let position = self.position_at(tick);
+        let bar_start = self.bar_start_tick(position.bar);
+        let bar_len = position.signature.ticks_per_bar();
+        let step = grid.step_ticks(position.signature);
+
+        snap_in_bar(tick, bar_start, bar_len, step, mode)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn seven_eight_then_common() -> TimeSignatureMap {
+        let mut map = TimeSignatureMap::new(TimeSignature::new(7, 8));
+        map.insert(TimeSignatureEvent::new(2, TimeSignature::COMMON));
+        map
+    }
+
+    #[test]
+    fn bars_follow_meter_changes() {
+        let map = seven_eight_then_common();
+        let seven_eight = TimeSignature::new(7, 8).ticks_per_bar();
+        assert_eq!(seven_eight, 3360);
+
+        assert_eq!(map.bar_start_tick(1), seven_eight);
+        assert_eq!(map.bar_start_tick(2), 2 * seven_eight);
+        assert_eq!(map.bar_start_tick(3), 2 * seven_eight + 3840);
+
+        let position = map.position_at(seven_eight + 3 * 480 + 10);
+        assert_eq!((position.bar, position.beat, position.tick), (1, 3, 10));
+
+        let position = map.position_at(2 * seven_eight + 960);
+        assert_eq!((position.bar, position.beat, position.tick), (2, 1, 0));
+        assert_eq!(position.signature, TimeSignature::COMMON);
+
+        for tick in [0, 479, 3359, 3360, 6720, 9999, 50_000] {
+            let p = map.position_at(tick);
+            assert_eq!(map.tick_at(p.bar, p.beat, p.tick), tick);
+        }
+    }
+
+    #[test]
+    fn snapping_never_crosses_a_bar_line() {
+        let map = TimeSignatureMap::new(TimeSignature::new(7, 8));
+
+        // 7/8 bar is 3360 ticks; a quarter grid has lines at 0, 960, 1920, 2880
+        assert_eq!(map.snap(3000, GridDivision::Ticks(960), SnapMode::Floor), 2880);
+        assert_eq!(map.snap(3300, GridDivision::Ticks(960), SnapMode::Nearest), 3360);
+        assert_eq!(map.snap(4400, GridDivision::Ticks(960), SnapMode::Floor), 4320);
+        assert_eq!(map.snap(1700, GridDivision::Bar, SnapMode::Nearest), 3360);
+        assert_eq!(map.snap(1700, GridDivision::Beat, SnapMode::Floor), 1440);
+    }
+}
@@ -0,0 +1,113 @@
+// src/core/project/quantize.rs
+//
+// Note quantization. Notes are pulled towards the closest grid line, lines restart on every
+// bar line like everywhere else in the timeline. Strength and window keep some of the played
+// feel, swing delays every second line and note ends can be put on the grid too.
+
+use serde::{ Deserialize, Serialize };
+
+use crate::core::project::musical_time::{ GridDivision, SnapMode, TimeSignatureMap };
+
+/// How notes are quantized.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct QuantizeSettings {
+    /// Grid notes are moved to, see `GridDivision::note` for triplets
+    pub grid: GridDivision,
+    /// How far notes move towards their grid line (0.0 - 1.0)
+    pub strength: f32,
+    /// How far from its grid line a note may be and still move, as a fraction of half a grid
+    /// step (0.0 - 1.0). 1.0 moves every note
+    pub window: f32,
+    /// Put note ends on the grid too. Otherwise notes keep their length
+    pub quantize_ends: bool,
+    /// Swing of every second grid line in a bar: 50 is straight, 75 the most allowed
+    pub swing: f32,
+}
+
+impl Default for QuantizeSettings {
+    fn default() -> Self {
+        Self {
+            grid: GridDivision::note(16, false),
+            strength: 1.0,
+            window: 1.0,
+            quantize_ends: false,
+            swing: 50.0,
+        }
+    }
+}
+
+impl QuantizeSettings {
+    /// Start and duration of a note once quantized.
+    pub fn apply(&self, meter: &TimeSignatureMap, start_tick: u64, duration: u64) -> (u64, u64) {
+        let start = self.move_towards_grid(meter, start_tick);
+        if !self.quantize_ends {
+            return (start, duration);
+        }
+
+        let end = self.move_towards_grid(meter, start_tick + duration);
+        if end > start {
+            return (start, end - start);
+        }
+        // The end was pulled onto the start, the note keeps one grid step
+        let step = self.grid.step_ticks(meter.signature_at_tick(start)).max(1);
+        (start, step)
+    }
+
+    fn move_towards_grid(&self, meter: &TimeSignatureMap, tick: u64) -> u64 {
+        let position = meter.position_at(tick);
+        let bar_start = meter.bar_start_tick(position.bar);
+        let bar_len = position.signature.ticks_per_bar();
+        let step = self.grid.step_ticks(position.signature);
+        if step == 0 {
+            return tick;
+        }
+
+        let mut target = meter.snap(tick, self.grid, SnapMode::Nearest);
+        let index = (target - bar_start) / step;
+        if index % 2 == 1 && target < bar_start + bar_len {
+            let swing = (self.swing.clamp(50.0, 75.0) / 100.0 - 0.5) * 2.0;
+            target += ((step as f32) * swing).round() as u64;
+        }
+
+        let distance = (target as i64) - (tick as i64);
+        if self.window < 1.0 && (distance.unsigned_abs() as f32) > self.window * (step as f32) * 0.5 {
+            return tick;
+        }
+        ((tick as i64) + ((distance as f32) * self.strength.clamp(0.0, 1.0)).round() as i64) as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::project::musical_time::TimeSignature;
+
+    #[test]
+    fn strength_window_swing_and_ends() {
+        let meter = TimeSignatureMap::new(TimeSignature::COMMON);
+        let sixteenths = QuantizeSettings::default();
+
+        assert_eq!(sixteenths.apply(&meter, 250, 200), (240, 200));
+
+        let half = QuantizeSettings { strength: 0.5, ..sixteenths };
+        assert_eq!(half.apply(&meter, 260, 200), (250, 200));
+
+        // 40 ticks off is more than a quarter of a half step (30)
+        let narrow = QuantizeSettings { window: 0.25, ..sixteenths };
+        assert_eq!(narrow.apply(&meter, 280, 200), (280, 200));
+        assert_eq!(narrow.apply(&meter, 250, 200), (240, 200));
+
+        let swung = QuantizeSettings { swing: 62.5, ..sixteenths };
+        assert_eq!(swung.apply(&meter, 250, 200), (300, 200));
+        assert_eq!(swung.apply(&meter, 470, 200), (480, 200));
+
+        let triplet_ends = QuantizeSettings {
+            grid: GridDivision::note(8, true),
+            quantize_ends: true,
+            ..sixteenths
+        };
+        assert_eq!(triplet_ends.apply(&meter, 330, 300), (320, 320));
+        // An end pulled onto the start keeps one step
+        assert_eq!(triplet_ends.apply(&meter, 330, 20), (320, 320));
+    }
+}
@@ -0,0 +1,492 @@
+// src/core/project/tempo_map.rs
+//
+// Project tempo map and musical-time conversion.
+// Everything on the timeline (clips, notes, automation) is stored in ticks.
+// The tempo map is the single place where ticks are turned into seconds or
+// output samples, so the engine, the exporter and the UI APIs all agree on
+// where a tick lands even when the tempo changes or ramps.
+
+use serde::{ Deserialize, Serialize };
+
+use crate::core::project::{
+    automation::{AutomationTarget, CurveType},
+    ApplicationState,
+};
+
+/// Ticks per quarter note (PPQ) used by every timeline position in the project.
+pub const TICKS_PER_BEAT: u32 = 960;
+
+/// Lowest tempo accepted by the map. Guards the conversions against division by zero.
+const MIN_BPM: f64 = 1.0;
+
+/// Tempo used when a map ends up without any point (e.g. a hand-edited project file).
+const FALLBACK_BPM: f64 = 120.0;
+
+// ============================================================================
+// TICK TIME
+// ============================================================================
+
+/// A position or duration in musical time, measured in ticks
+/// ([`TICKS_PER_BEAT`] ticks per quarter note).
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(transparent)]
+pub struct TickTime(pub u64);
+
+impl TickTime {
+    pub const ZERO: TickTime = TickTime(0);
+
+    pub fn new(ticks: u64) -> Self {
+        Self(ticks)
+    }
+
+    pub fn ticks(self) -> u64 {
+        self.0
+    }
+
+    /// Builds a tick position from a (fractional) number of quarter notes.
+    pub fn from_beats(beats: f64) -> Self {
+        Self((beats.max(0.0) * TICKS_PER_BEAT as f64).round() as u64)
+    }
+
+    pub fn as_beats(self) -> f64 {
+        self.0 as f64 / TICKS_PER_BEAT as f64
+    }
+}
+
+impl From<u64> for TickTime {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<u32> for TickTime {
+    fn from(value: u32) -> Self {
+        Self(value as u64)
+    }
+}
+
+// ============================================================================
+// TEMPO POINTS
+// ============================================================================
+
+/// How the tempo travels from a point to the next one.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TempoRamp {
+    /// Hold this point's tempo until the next point, then jump.
+    #[default]
+    Jump,
+    /// Change the tempo linearly (per tick) until the next point is reached.
+    Linear,
+}
+
+/// A tempo change on the timeline.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct TempoPoint {
+    /// Position of the change in ticks
+    pub tick: u64,
+    /// Tempo in quarter notes per minute
+    pub bpm: f32,
+    /// Curve used to reach the NEXT point
+    pub ramp: TempoRamp,
+}
+
+impl TempoPoint {
+    pub fn new(tick: u64, bpm: f32, ramp: TempoRamp) -> Self {
+        Self { tick, bpm, ramp }
+    }
+}
+
+// ============================================================================
+// TEMPO MAP
+// ============================================================================
+
+/// Sorted list of tempo changes. The first point always sits at tick 0.
+///
+/// Conversions are closed-form per segment: a `Jump` segment has a constant
+/// tempo and a `Linear` segment integrates `60 / (PPQ * bpm(t))` over ticks,
+/// which gives a logarithm one way and an exponential the other way.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TempoMap {
+    points: Vec<TempoPoint>,
+}
+
+impl Default for TempoMap {
+    fn default() -> Self {
+        Self::new(FALLBACK_BPM as f32)
+    }
+}
+
+impl TempoMap {
+    /// Creates a map with a single constant tempo.
+    pub fn new(bpm: f32) -> Self {
+        Self {
+            points: vec![TempoPoint::new(0, bpm, TempoRamp::Jump)],
+        }
+    }
+
+    /// Builds a map from arbitrary points. Points are sorted, duplicates on the same
+    /// tick keep the last one, and a point at tick 0 is added if missing.
+    pub fn from_points(points: impl IntoIterator<Item = TempoPoint>) -> Self {
+        let mut map = Self { points: Vec::new() };
+        for point in points {
+            map.insert_point(point);
+        }
+        map.ensure_origin();
+        map
+    }
+
+    pub fn points(&self) -> &[TempoPoint] {
+        &self.points
+    }
+
+    /// Tempo at the start of the project.
+    pub fn initial_bpm(&self) -> f32 {
+        self.points.first().map(|p| p.bpm).unwrap_or(FALLBACK_BPM as f32)
+    }
+
+    /// Replaces the tempo of the point at tick 0.
+    pub fn set_initial_bpm(&mut self, bpm: f32) {
+        self.ensure_origin();
+        if let Some(first) = self.points.first_mut() {
+            first.bpm = bpm;
+        }
+    }
+
+    /// Returns true when the map has a single constant tempo.
+    pub fn is_constant(&self) -> bool {
+        self.points.len() <= 1
+    }
+
+    /// Inserts a tempo change, replacing any existing point on the same tick.
+    pub fn insert_point(&mut self, point: TempoPoint) {
+        match self.points.binary_search_by(|p| p.tick.cmp(&point.tick)) {
+            Ok(idx) => {
+                self.points[idx] = point;
+            }
+            Err(idx) => self.points.insert(idx, point),
+        }
+    }
+
+    /// Removes the tempo change on the given tick. The point at tick 0 cannot be removed.
+    pub fn remove_point_at(&mut self, tick: u64) -> Option<TempoPoint> {
+        if tick == 0 {
+            return None;
+        }
+        let idx = self.points.binary_search_by(|p| p.tick.cmp(&tick)).ok()?;
+        Some(self.points.remove(idx))
+    }
+
+    fn ensure_origin(&mut self) {
+        match self.points.first() {
+            Some(first) if first.tick == 0 => {}
+            Some(first) => {
+                let origin = TempoPoint::new(0, first.bpm, TempoRamp::Jump);
+                self.points.insert(0, origin);
+            }
+            None => self.points.push(TempoPoint::new(0, FALLBACK_BPM as f32, TempoRamp::Jump)),
+        }
+    }
+
+    /// Tempo (BPM) at the given tick, following ramps.
+    pub fn bpm_at_tick(&self, tick: f64) -> f32 {
+        let Some(idx) = self.segment_index(tick) else {
+            return FALLBACK_BPM as f32;
+        };
+        let point = &self.points[idx];
+        match (point.ramp, self.points.get(idx + 1)) {
+            (TempoRamp::Linear, Some(next)) if next.tick > point.tick => {
+                let t = ((tick - point.tick as f64) / (next.tick - point.tick) as f64).clamp(0.0, 1.0);
+                let bpm = point.bpm as f64 + (next.bpm as f64 - point.bpm as f64) * t;
+                bpm as f32
+            }
+            _ => point.bpm,
+        }
+    }
+
+    /// Converts a tick position into seconds from the project start.
+    /// Negative ticks are extrapolated with the initial tempo.
+    pub fn tick_to_seconds(&self, tick: f64) -> f64 {
+        if self.points.is_empty() {
+            return tick * seconds_per_tick(FALLBACK_BPM);
+        }
+        if tick <= 0.0 {
+            return tick * seconds_per_tick(sanitize_bpm(self.points[0].bpm));
+        }
+
+        let mut elapsed = 0.0;
+        for (idx, point) in self.points.iter().enumerate() {
+            let next = self.points.get(idx + 1);
+            let delta = tick - point.tick as f64;
+            match next {
+                Some(next) if tick >= next.tick as f64 => {
+                    elapsed += segment_seconds(point, Some(next), (next.tick - point.tick) as f64);
+                }
+                _ => {
+                    return elapsed + segment_seconds(point, next, delta);
+                }
+            }
+        }
+        elapsed
+    }
+
+    /// Converts seconds from the project start into a (fractional) tick position.
+    pub fn seconds_to_tick(&self, seconds: f64) -> f64 {
+        if self.points.is_empty() {
+            return seconds / seconds_per_tick(FALLBACK_BPM);
+        }
+        if seconds <= 0.0 {
+            return seconds / seconds_per_tick(sanitize_bpm(self.points[0].bpm));
+        }
+
+        let mut elapsed = 0.0;
+        for (idx, point) in self.points.iter().enumerate() {
+            let next = self.points.get(idx + 1);
+            if let Some(next) = next {
+                let segment = segment_seconds(point, Some(next), (next.tick - point.tick) as f64);
+                if seconds >= elapsed + segment {
+                    elapsed += segment;
+                    continue;
+                }
+            }
+            return point.tick as f64 + segment_ticks(point, next, seconds - elapsed);
+        }
+        0.0
+    }
+
+    /// Converts a tick position into a fractional sample position.
+    #[inline]
+    pub fn tick_to_sample_f64(&self, tick: f64, sample_rate: u32) -> f64 {
+        self.tick_to_seconds(tick) * sample_rate as f64
+    }
+
+    /// Converts a sample position into a fractional tick position.
+    #[inline]
+    pub fn sample_to_tick_f64(&self, sample: f64, sample_rate: u32) -> f64 {
+        if sample_rate == 0 {
+            return 0.0;
+        }
+        self.seconds_to_tick(sample / sample_rate as f64)
+    }
+
+    /// Converts a tick position into the nearest output sample.
+    #[inline]
+    pub fn tick_to_sample(&self, tick: impl Into<TickTime>, sample_rate: u32) -> u64 {
+        let tick = tick.into().ticks() as f64;
+        self.tick_to_sample_f64(tick, sample_rate).round().max(0.0) as u64
+    }
+
+    /// Converts an output sample into the tick it falls in.
+    #[inline]
+    pub fn sample_to_tick(&self, sample: u64, sample_rate: u32) -> TickTime {
+        // Nudge by a tiny epsilon so round-tripped tick boundaries don't floor one tick short
+        let tick = self.sample_to_tick_f64(sample as f64, sample_rate) + 1e-6;
+        TickTime(tick.floor().max(0.0) as u64)
+    }
+
+    /// Index of the point whose segment contains `tick`.
+    fn segment_index(&self, tick: f64) -> Option<usize> {
+        if self.points.is_empty() {
+            return None;
+        }
+        let idx = self.points.partition_point(|p| (p.tick as f64) <= tick);
+        Some(idx.saturating_sub(1))
+    }
+}
+
+#[inline]
+fn sanitize_bpm(bpm: f32) -> f64 {
+    if bpm.is_finite() {
+        (bpm as f64).max(MIN_BPM)
+    } else {
+        FALLBACK_BPM
+    }
+}
+
+#[inline]
+fn seconds_per_tick(bpm: f64) -> f64 {
+    60.0 / (bpm * TICKS_PER_BEAT as f64)
+}
+
+/// Seconds spent travelling `delta_ticks` from the start of a segment.
+fn segment_seconds(point: &TempoPoint, next: Option<&TempoPoint>, delta_ticks: f64) -> f64 {
+    let b0 = sanitize_bpm(point.bpm);
+    match (point.ramp, next) {
+        (TempoRamp::Linear, Some(next)) if next.tick > point.tick => {
+            let slope = (sanitize_bpm(next.bpm) - b0) / (next.tick - point.tick) as f64;
+            if slope.abs() < 1e-12 {
+                return delta_ticks * seconds_per_tick(b0);
+            }
+            let ppq = TICKS_PER_BEAT as f64;
+            (60.0 / (ppq * slope)) * ((b0 + slope * delta_ticks) / b0).ln()
+        }
+        _ => delta_ticks * seconds_per_tick(b0),
+    }
+}
+
+/// Ticks travelled after `seconds` from the start of a segment (inverse of [`segment_seconds`]).
+fn segment_ticks(point: &TempoPoint, next: Option<&TempoPoint>, seconds: f64) -> f64 {
+    let b0 = sanitize_bpm(point.bpm);
+    match (point.ramp, next) {
+        (TempoRamp::Linear, Some(next)) if next.tick > point.tick => {
+            let slope = (sanitize_bpm(next.bpm) - b0) / (next.tick - point.tick) as f64;
+            if slope.abs() < 1e-12 {
+                return seconds / seconds_per_tick(b0);
+            }
+            let ppq = TICKS_PER_BEAT as f64;
+            b0 * ((seconds * ppq * slope / 60.0).exp() - 1.0) / slope
+        }
+        _ => seconds / seconds_per_tick(b0),
+    }
+}
+
+// ============================================================================
+// TICK <-> SAMPLE CONVERTER
+// ============================================================================
+
+/// Owned snapshot of the tempo map bound to a sample rate.
+///
+/// UI-facing APIs take one of these before mapping project data so they can
+/// translate between ticks and samples without holding the project lock.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TickConverter {
+    tempo_map: TempoMap,
+    sample_rate: u32,
+}
+
+impl TickConverter {
+    pub fn new(tempo_map: TempoMap, sample_rate: u32) -> Self {
+        Self {
+            tempo_map,
+            sample_rate,
+        }
+    }
+
+    pub fn tempo_map(&self) -> &TempoMap {
+        &self.tempo_map
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn ticks_to_samples(&self, tick: impl Into<TickTime>) -> u64 {
+        self.tempo_map.tick_to_sample(tick, self.sample_rate)
+    }
+
+    pub fn samples_to_ticks(&self, sample: u64) -> TickTime {
+        self.tempo_map.sample_to_tick(sample, self.sample_rate)
+    }
+
+    /// Length in samples of a tick span starting at `start`.
+    pub fn span_to_samples(&self, start: impl Into<TickTime>, length_ticks: u64) -> u64 {
+        let start = start.into();
+        let end = TickTime(start.ticks() + length_ticks);
+        self.ticks_to_samples(end).saturating_sub(self.ticks_to_samples(start))
+    }
+
+    /// Length in ticks of a sample span starting at tick `start`.
+    pub fn span_to_ticks(&self, start: impl Into<TickTime>, length_samples: u64) -> u64 {
+        let start = start.into();
+        let start_sample = self.ticks_to_samples(start);
+        self.samples_to_ticks(start_sample + length_samples)
+            .ticks()
+            .saturating_sub(start.ticks())
+    }
+
+    /// Length in samples of an `offset_ticks` span that ends at tick `end`
+    /// (e.g. a clip's `offset_start` measured back from its `start_time`).
+    pub fn offset_to_samples(&self, end: impl Into<TickTime>, offset_ticks: u64) -> u64 {
+        let end = end.into().ticks() as f64;
+        let end_sample = self.tempo_map.tick_to_sample_f64(end, self.sample_rate);
+        let start_sample = self.tempo_map.tick_to_sample_f64(end - offset_ticks as f64, self.sample_rate);
+        (end_sample - start_sample).round().max(0.0) as u64
+    }
+
+    /// Converts a signed sample delta applied at tick `anchor` into a signed tick delta.
+    pub fn delta_to_ticks(&self, anchor: impl Into<TickTime>, delta_samples: i64) -> i64 {
+        let anchor = anchor.into();
+        let anchor_sample = self.ticks_to_samples(anchor) as i64;
+        let target_sample = (anchor_sample + delta_samples).max(0) as u64;
+        self.samples_to_ticks(target_sample).ticks() as i64 - anchor.ticks() as i64
+    }
+}
+
+// ============================================================================
+// PROJECT HELPERS
+// ============================================================================
+
+impl ApplicationState {
+    /// The tempo map the engine plays back: the transport's map, unless an enabled
+    /// `TempoBpm` automation lane with points is present, in which case the lane wins.
+    pub fn effective_tempo_map(&self) -> TempoMap {
+        let tempo_lane = self.automation_pool.values().find(|lane| {
+            lane.target == AutomationTarget::TempoBpm && lane.enabled && !lane.points.is_empty()
+        });
+
+        match tempo_lane {
+            Some(lane) => TempoMap::from_points(lane.points.iter().map(|p| {
+                let ramp = match p.curve_type {
+                    CurveType::Step => TempoRamp::Jump,
+                    CurveType::Linear | CurveType::Exponential => TempoRamp::Linear,
+                };
+                TempoPoint::new(p.time_ticks as u64, lane.denormalize(p.value), ramp)
+            })),
+            None => self.transport.tempo_map.clone(),
+        }
+    }
+
+    /// Converter bound to the session sample rate.
+    pub fn tick_converter(&self) -> TickConverter {
+        TickConverter::new(self.effective_tempo_map(), self.audio_config.sample_rate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn constant_tempo_round_trips() {
+        let map = TempoMap::new(120.0);
+        // 120 BPM at 48 kHz = 24000 samples per beat
+        assert_eq!(map.tick_to_sample(TICKS_PER_BEAT, 48000), 24000);
+        assert_eq!(map.sample_to_tick(24000, 48000), TickTime(TICKS_PER_BEAT as u64));
+        for tick in [0u64, 1, 479, 960, 12345, 1_000_000] {
+            let sample = map.tick_to_sample(tick, 44100);
+            let back = map.sample_to_tick(sample, 44100).ticks();
+            assert!(back.abs_diff(tick) <= 1, "tick {tick} came back as {back}");
+        }
+    }
+
+    #[test]
+    fn jump_changes_tempo_after_the_point() {
+        let mut map = TempoMap::new(120.0);
+        map.insert_point(TempoPoint::new(4 * 960, 60.0, TempoRamp::Jump));
+
+        // Four beats at 120 BPM = 2 s, then one beat at 60 BPM = 1 s
+        assert!((map.tick_to_seconds(4.0 * 960.0) - 2.0).abs() < 1e-9);
+        assert!((map.tick_to_seconds(5.0 * 960.0) - 3.0).abs() < 1e-9);
+        assert!((map.seconds_to_tick(3.0) - 5.0 * 960.0).abs() < 1e-6);
+        assert_eq!(map.bpm_at_tick(4.0 * 960.0), 60.0);
+    }
+
+    #[test]
+    fn linear_ramp_is_invertible() {
+        let mut map = TempoMap::new(100.0);
+        map.insert_point(TempoPoint::new(0, 100.0, TempoRamp::Linear));
+        map.insert_point(TempoPoint::new(8 * 960, 200.0, TempoRamp::Jump));
+
+        assert!((map.bpm_at_tick(4.0 * 960.0) - 150.0).abs() < 1e-3);
+
+        // Ramp must be faster than a constant 100 BPM and slower than 200 BPM
+        let ramp_seconds = map.tick_to_seconds(8.0 * 960.0);
+        assert!(ramp_seconds < 8.0 * 0.6 && ramp_seconds > 8.0 * 0.3);
+
+        for tick in [1.0, 960.0, 3000.0, 7679.0, 9000.0] {
+            let seconds = map.tick_to_seconds(tick);
+            assert!((map.seconds_to_tick(seconds) - tick).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,374 @@
+// src/core/project/theory.rs
+//
+// Scales, chords and chord detection. Pitches are MIDI keys, pitch classes count semitones
+// above C (0 - 11). A pattern can carry a scale, which the piano roll uses to point out notes
+// outside the key and to snap them back in.
+
+use serde::{ Deserialize, Serialize };
+
+use crate::core::project::Note;
+use crate::shared::id::NoteId;
+
+const NOTE_NAMES: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+
+/// Name of a pitch class, with sharps.
+pub fn pitch_class_name(pitch_class: u8) -> &'static str {
+    NOTE_NAMES[(pitch_class % 12) as usize]
+}
+
+/// Scales and modes, as intervals above the root.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScaleMode {
+    Major,
+    NaturalMinor,
+    HarmonicMinor,
+    MelodicMinor,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Locrian,
+    MajorPentatonic,
+    MinorPentatonic,
+    Blues,
+}
+
+impl ScaleMode {
+    pub const ALL: [ScaleMode; 12] = [
+        ScaleMode::Major,
+        ScaleMode::NaturalMinor,
+        ScaleMode::HarmonicMinor,
+        ScaleMode::MelodicMinor,
+        ScaleMode::Dorian,
+        ScaleMode::Phrygian,
+        ScaleMode::Lydian,
+        ScaleMode::Mixolydian,
+        ScaleMode::Locrian,
+        ScaleMode::MajorPentatonic,
+        ScaleMode::MinorPentatonic,
+        ScaleMode::Blues,
+    ];
+
+    pub fn intervals(self) -> &'static [u8] {
+        match self {
+            ScaleMode::Major => &[0, 2, 4, 5, 7, 9, 11],
+            ScaleMode::NaturalMinor => &[0, 2, 3, 5, 7, 8, 10],
+            ScaleMode::HarmonicMinor => &[0, 2, 3, 5, 7, 8, 11],
+            ScaleMode::MelodicMinor => &[0, 2, 3, 5, 7, 9, 11],
+            ScaleMode::Dorian => &[0, 2, 3, 5, 7, 9, 10],
+            ScaleMode::Phrygian => &[0, 1, 3, 5, 7, 8, 10],
+            ScaleMode::Lydian => &[0, 2, 4, 6, 7, 9, 11],
+            ScaleMode::Mixolydian => &[0, 2, 4, 5, 7, 9, 10],
+            ScaleMode::Locrian => &[0, 1, 3, 5, 6, 8, 10],
+            ScaleMode::MajorPentatonic => &[0, 2, 4, 7, 9],
+            ScaleMode::MinorPentatonic => &[0, 3, 5, 7, 10],
+            ScaleMode::Blues => &[0, 3, 5, 6, 7, 10],
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ScaleMode::Major => "Major",
+            ScaleMode::NaturalMinor => "Minor",
+            ScaleMode::HarmonicMinor => "Harmonic Minor",
+            ScaleMode::MelodicMinor => "Melodic Minor",
+            ScaleMode::Dorian => "Dorian",
+            ScaleMode::Phrygian => "Phrygian",
+            ScaleMode::Lydian => "Lydian",
+            ScaleMode::Mixolydian => "Mixolydian",
+            ScaleMode::Locrian => "Locrian",
+            ScaleMode::MajorPentatonic => "Major Pentatonic",
+            ScaleMode::MinorPentatonic => "Minor Pentatonic",
+            ScaleMode::Blues => "Blues",
+        }
+    }
+}
+
+/// A key: a root pitch class and a mode.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Scale {
+    /// Pitch class of the root (0 = C)
+    pub root: u8,
+    pub mode: ScaleMode,
+}
+
+impl Scale {
+    pub fn new(root: u8, mode: ScaleMode) -> Self {
+        Self { root: root % 12, mode }
+    }
+
+    /// e.g. "D Dorian"
+    pub fn name(&self) -> String {
+        format!("{} {}", pitch_class_name(self.root), self.mode.name())
+    }
+
+    pub fn contains(&self, key: u8) -> bool {
+        let from_root = (key + 12 - self.root % 12) % 12;
+        self.mode.intervals().contains(&from_root)
+    }
+
+    /// Closest key of the scale, the lower one when two are as close.
+    pub fn snap(&self, key: u8) -> u8 {
+        (0..=6u8)
+            .flat_map(|distance| [key.checked_sub(distance), key.checked_add(distance)])
+            .flatten()
+            .find(|&candidate| candidate <= 127 && self.contains(candidate))
+            .unwrap_or(key)
+    }
+
+    /// Notes outside the scale.
+    pub fn out_of_key(&self, notes: &[Note]) -> Vec<NoteId> {
+        notes
+            .iter()
+            .filter(|note| !self.contains(note.key))
+            .map(|note| note.id)
+            .collect()
+    }
+}
+
+/// Chord qualities, as intervals above the root.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChordType {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+    Sus2,
+    Sus4,
+    Major6,
+    Minor6,
+    Dominant7,
+    Major7,
+    Minor7,
+    MinorMajor7,
+    HalfDiminished7,
+    Diminished7,
+    Add9,
+    Dominant9,
+    Major9,
+    Minor9,
+}
+
+impl ChordType {
+    /// In the order chord detection prefers them when several fit the same notes.
+    pub const ALL: [ChordType; 18] = [
+        ChordType::Major,
+        ChordType::Minor,
+        ChordType::Dominant7,
+        ChordType::Major7,
+        ChordType::Minor7,
+        ChordType::Diminished,
+        ChordType::Augmented,
+        ChordType::Sus4,
+        ChordType::Sus2,
+        ChordType::HalfDiminished7,
+        ChordType::Diminished7,
+        ChordType::Major6,
+        ChordType::Minor6,
+        ChordType::MinorMajor7,
+        ChordType::Add9,
+        ChordType::Dominant9,
+        ChordType::Major9,
+        ChordType::Minor9,
+    ];
+
+    pub fn intervals(self) -> &'static [u8] {
+        match self {
+            ChordType::Major => &[0, 4, 7],
+            ChordType::Minor => &[0, 3, 7],
+            ChordType::Diminished => &[0, 3, 6],
+            ChordType::Augmented => &[0, 4, 8],
+            ChordType::Sus2 => &[0, 2, 7],
+            ChordType::Sus4 => &[0, 5, 7],
+            ChordType::Major6 => &[0, 4, 7, 9],
+            ChordType::Minor6 => &[0, 3, 7, 9],
+            ChordType::Dominant7 => &[0, 4, 7, 10],
+            ChordType::Major7 => &[0, 4, 7, 11],
+            ChordType::Minor7 => &[0, 3, 7, 10],
+            ChordType::MinorMajor7 => &[0, 3, 7, 11],
+            ChordType::HalfDiminished7 => &[0, 3, 6, 10],
+            ChordType::Diminished7 => &[0, 3, 6, 9],
+            ChordType::Add9 => &[0, 4, 7, 14],
+            ChordType::Dominant9 => &[0, 4, 7, 10, 14],
+            ChordType::Major9 => &[0, 4, 7, 11, 14],
+            ChordType::Minor9 => &[0, 3, 7, 10, 14],
+        }
+    }
+
+    /// Written after the root, e.g. "m7" in "Am7"
+    pub fn suffix(self) -> &'static str {
+        match self {
+            ChordType::Major => "",
+            ChordType::Minor => "m",
+            ChordType::Diminished => "dim",
+            ChordType::Augmented => "aug",
+            ChordType::Sus2 => "sus2",
+            ChordType::Sus4 => "sus4",
+            ChordType::Major6 => "6",
+            ChordType::Minor6 => "m6",
+            ChordType::Dominant7 => "7",
+            ChordType::Major7 => "maj7",
+            ChordType::Minor7 => "m7",
+            ChordType::MinorMajor7 => "m(maj7)",
+            ChordType::HalfDiminished7 => "m7b5",
+            ChordType::Diminished7 => "dim7",
+            ChordType::Add9 => "add9",
+            ChordType::Dominant9 => "9",
+            ChordType::Major9 => "maj9",
+            ChordType::Minor9 => "m9",
+        }
+    }
+
+    /// Pitch classes of the chord as bits, bit 0 is the root
+    fn pitch_class_set(self) -> u16 {
+        self.intervals()
+            .iter()
+            .fold(0, |set, interval| set | (1 << (interval % 12)))
+    }
+}
+
+/// A chord: a root pitch class and a quality.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Chord {
+    /// Pitch class of the root (0 = C)
+    pub root: u8,
+    pub chord_type: ChordType,
+    /// Pitch class of the lowest note when it isn't the root, written as a slash chord
+    pub bass: Option<u8>,
+}
+
+impl Chord {
+    pub fn new(root: u8, chord_type: ChordType) -> Self {
+        Self { root: root % 12, chord_type, bass: None }
+    }
+
+    /// e.g. "Am7" or "C/E"
+    pub fn name(&self) -> String {
+        let name = format!("{}{}", pitch_class_name(self.root), self.chord_type.suffix());
+        match self.bass {
+            Some(bass) => format!("{name}/{}", pitch_class_name(bass)),
+            None => name,
+        }
+    }
+
+    /// Spells the chord upwards from `root_key`. `inversion` moves that many of the lowest notes
+    /// up an octave. A chord reaching above 127 moves down by octaves as a whole.
+    pub fn keys(root_key: u8, chord_type: ChordType, inversion: usize) -> Vec<u8> {
+        let mut keys: Vec<u16> = chord_type
+            .intervals()
+            .iter()
+            .map(|&interval| (root_key as u16) + (interval as u16))
+            .collect();
+        for key in keys.iter_mut().take(inversion.min(chord_type.intervals().len() - 1)) {
+            *key += 12;
+        }
+        keys.sort_unstable();
+        let top = keys.last().copied().unwrap_or(0);
+        let shift = top.saturating_sub(127).div_ceil(12) * 12;
+        keys.into_iter()
+            .map(|key| (key - shift) as u8)
+            .collect()
+    }
+
+    /// Names the chord the keys form, whatever their octave and order. Chords with their root
+    /// in the bass win, the lowest note of an inverted chord becomes its slash bass. `None`
+    /// when the pitch classes are not exactly one of the known chords.
+    pub fn detect(keys: &[u8]) -> Option<Chord> {
+        let bass = keys.iter().min()? % 12;
+        let pitch_classes = keys.iter().fold(0u16, |set, key| set | (1 << (key % 12)));
+
+        let matches = |root: u8| {
+            // Pitch classes moved so `root` is bit 0
+            let rotated = ((pitch_classes >> root) | (pitch_classes << (12 - root))) & 0xfff;
+            ChordType::ALL
+                .into_iter()
+                .find(|chord_type| chord_type.pitch_class_set() == rotated)
+        };
+
+        if let Some(chord_type) = matches(bass) {
+            return Some(Chord::new(bass, chord_type));
+        }
+        // Otherwise the best ranked quality among the other roots
+        (0..12u8)
+            .filter(|&root| root != bass && pitch_classes & (1 << root) != 0)
+            .filter_map(|root| matches(root).map(|chord_type| (root, chord_type)))
+            .min_by_key(|&(_, chord_type)| ChordType::ALL.iter().position(|&t| t == chord_type))
+            .map(|(root, chord_type)| Chord { bass: Some(bass), ..Chord::new(root, chord_type) })
+    }
+}
+
+/// A chord found in a run of notes, in ticks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChordSpan {
+    pub start_tick: u64,
+    pub end_tick: u64,
+    pub chord: Chord,
+}
+
+/// The chords the notes play one after the other. A new chord is read wherever a note starts,
+/// from every note sounding there; the same chord read again just goes on.
+pub fn analyse_chords(notes: &[Note]) -> Vec<ChordSpan> {
+    let mut starts: Vec<u64> = notes.iter().map(|note| note.start_tick).collect();
+    starts.sort_unstable();
+    starts.dedup();
+
+    let mut spans: Vec<ChordSpan> = Vec::new();
+    let mut keys = Vec::new();
+    for &tick in &starts {
+        keys.clear();
+        keys.extend(
+            notes
+                .iter()
+                .filter(|note| note.start_tick <= tick && tick < note.start_tick + note.duration)
+                .map(|note| note.key)
+        );
+        let end_tick = notes
+            .iter()
+            .filter(|note| note.start_tick <= tick)
+            .map(|note| note.start_tick + note.duration)
+            .filter(|&end| end > tick)
+            .min()
+            .unwrap_or(tick);
+
+        let Some(chord) = Chord::detect(&keys) else {
+            continue;
+        };
+        match spans.last_mut() {
+            Some(last) if last.chord == chord && last.end_tick >= tick => {
+                last.end_tick = last.end_tick.max(end_tick);
+            }
+            _ => spans.push(ChordSpan { start_tick: tick, end_tick, chord }),
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scales_snap_to_the_closest_degree() {
+        let d_dorian = Scale::new(2, ScaleMode::Dorian);
+        assert_eq!(d_dorian.name(), "D Dorian");
+        assert!(d_dorian.contains(71) && !d_dorian.contains(70));
+        // A# sits between A and B, the lower one wins
+        assert_eq!(d_dorian.snap(70), 69);
+        assert_eq!(Scale::new(0, ScaleMode::MajorPentatonic).snap(65), 64);
+    }
+
+    #[test]
+    fn chords_are_spelled_and_detected() {
+        assert_eq!(Chord::keys(57, ChordType::Minor7, 0), [57, 60, 64, 67]);
+        assert_eq!(Chord::keys(60, ChordType::Major, 1), [64, 67, 72]);
+        // G major 9 from the top key would reach 141, the whole chord comes down two octaves
+        assert_eq!(Chord::keys(127, ChordType::Major9, 0), [103, 107, 110, 114, 117]);
+
+        let detect = |keys: &[u8]| Chord::detect(keys).map(|chord| chord.name());
+        assert_eq!(detect(&[57, 60, 64, 67]).as_deref(), Some("Am7"));
+        // C6 and Am7 share their notes, the bass decides
+        assert_eq!(detect(&[48, 64, 67, 69]).as_deref(), Some("C6"));
+        assert_eq!(detect(&[64, 67, 72]).as_deref(), Some("C/E"));
+        assert_eq!(detect(&[60, 61, 62]), None);
+    }
+}
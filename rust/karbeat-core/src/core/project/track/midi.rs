@@ -3,6 +3,11 @@ use std::sync::Arc;
 use serde::{ Deserialize, Serialize };
 
 use crate::core::project::ApplicationState;
+use crate::core::project::groove::Groove;
+use crate::core::project::quantize::QuantizeSettings;
+use crate::core::project::theory::Scale;
+use crate::core::project::transform::NoteTransform;
+use crate::core::project::musical_time::{ GridDivision, SnapMode, TimeSignature, TimeSignatureMap };
 use crate::core::project::Note;
 use crate::core::project::NoteId;
 use crate::shared::id::PatternId;
@@ -16,6 +21,14 @@ pub struct Pattern {
     pub notes: Vec<Note>,
 
     pub next_note_id: u32,
+
+    /// Groove applied while playing, the notes keep their written timing
+    #[serde(default)]
+    pub groove: Option<Groove>,
+
+    /// Key of the pattern, for snapping and pointing out notes outside it
+    #[serde(default)]
+    pub scale: Option<Scale>,
 }
 
 impl Pattern {
@@ -330,6 +343,45 @@ impl Pattern {
         Ok(())
     }
 
+    /// Swaps in a new state of the note with the same ID, keeping the pattern sorted and
+    /// long enough. Used by Undo/Redo and by edits that change several fields at once.
+    pub fn replace_note(&mut self, note: Note) -> anyhow::Result<()> {
+        if note.key > 127 {
+            return Err(anyhow::anyhow!("Invalid key {}", note.key));
+        }
+        let existing = self.notes
+            .iter_mut()
+            .find(|n| n.id == note.id)
+            .ok_or_else(|| anyhow::anyhow!("Note with ID {:?} not found", note.id))?;
+
+        let note_end = note.start_tick + note.duration;
+        if note_end > self.length_ticks {
+            self.length_ticks = note_end;
+        }
+        *existing = note;
+        self.sort_notes_unstable();
+        Ok(())
+    }
+
+    /// Moves the notes and sets their velocities as `groove` plays them. Returns the
+    /// (old, new) state of every note that changed.
+    pub fn commit_groove(&mut self, groove: &Groove) -> Vec<(Note, Note)> {
+        let mut changed = Vec::new();
+        for note in &mut self.notes {
+            let (start_tick, velocity) = groove.apply(note.start_tick, note.velocity);
+            if start_tick == note.start_tick && velocity == note.velocity {
+                continue;
+            }
+            let old = note.clone();
+            note.start_tick = start_tick;
+            note.velocity = velocity;
+            self.length_ticks = self.length_ticks.max(start_tick + note.duration);
+            changed.push((old, note.clone()));
+        }
+        self.sort_notes_unstable();
+        changed
+    }
+
     /// Clone a note and add it at a different time
     pub fn duplicate_note(&mut self, index: usize, new_start_tick: u64) -> anyhow::Result<Note> {
         if index >= self.notes.len() {
@@ -345,17 +397,88 @@ impl Pattern {
     }
 
     /// Quantize note start times to a grid
-    /// grid_size: snap to multiples of this tick value (e.g., 96 for 16th notes at 960 PPQ)
-    pub fn quantize_notes(&mut self, grid_size: u64) {
-        if grid_size == 0 {
-            return;
+    /// signature: meter of the pattern (patterns use the project start signature)
+    /// grid: e.g. `GridDivision::Ticks(240)` for 16th notes at 960 PPQ. Grid lines restart on
+    /// every bar line, so odd meters quantize correctly.
+    pub fn quantize_notes(&mut self, signature: TimeSignature, grid: GridDivision) {
+        let meter = TimeSignatureMap::new(signature);
+
+        for note in &mut self.notes {
+            note.start_tick = meter.snap(note.start_tick, grid, SnapMode::Floor);
         }
 
+        self.sort_notes_unstable();
+    }
+
+    /// Quantizes the selected notes, or every note when `note_ids` is empty. Returns the
+    /// (old, new) state of every note that changed.
+    pub fn quantize(
+        &mut self,
+        note_ids: &[NoteId],
+        signature: TimeSignature,
+        settings: &QuantizeSettings
+    ) -> Vec<(Note, Note)> {
+        let meter = TimeSignatureMap::new(signature);
+        let mut changed = Vec::new();
+
         for note in &mut self.notes {
-            note.start_tick = (note.start_tick / grid_size) * grid_size;
+            if !note_ids.is_empty() && !note_ids.contains(&note.id) {
+                continue;
+            }
+            let (start_tick, duration) = settings.apply(&meter, note.start_tick, note.duration);
+            if start_tick == note.start_tick && duration == note.duration {
+                continue;
+            }
+            let old = note.clone();
+            note.start_tick = start_tick;
+            note.duration = duration;
+            self.length_ticks = self.length_ticks.max(start_tick + duration);
+            changed.push((old, note.clone()));
         }
 
         self.sort_notes_unstable();
+        changed
+    }
+
+    /// Applies a transform to the selected notes, or every note when `note_ids` is empty.
+    /// Returns the old states of the notes it changed or removed and the new states of the
+    /// ones it changed.
+    pub fn transform_notes(
+        &mut self,
+        note_ids: &[NoteId],
+        transform: &NoteTransform
+    ) -> anyhow::Result<(Vec<Note>, Vec<Note>)> {
+        transform.validate()?;
+
+        let selected: Vec<Note> = self.notes
+            .iter()
+            .filter(|n| note_ids.is_empty() || note_ids.contains(&n.id))
+            .cloned()
+            .collect();
+        let transformed = transform.apply(selected.clone());
+
+        let old_notes: Vec<Note> = selected
+            .iter()
+            .filter(|old| !transformed.iter().any(|new| same_note(old, new)))
+            .cloned()
+            .collect();
+        let new_notes: Vec<Note> = transformed
+            .into_iter()
+            .filter(|new| !selected.iter().any(|old| same_note(old, new)))
+            .collect();
+
+        self.replace_notes(&old_notes, &new_notes);
+        Ok((old_notes, new_notes))
+    }
+
+    /// Swaps `old_notes` for `new_notes`, matched by ID.
+    pub fn replace_notes(&mut self, old_notes: &[Note], new_notes: &[Note]) {
+        self.notes.retain(|n| !old_notes.iter().any(|old| old.id == n.id));
+        for note in new_notes {
+            self.length_ticks = self.length_ticks.max(note.start_tick + note.duration);
+            self.notes.push(note.clone());
+        }
+        self.sort_notes_unstable();
     }
 
     /// Transpose all notes by a number of semitones
@@ -379,6 +502,18 @@ impl Pattern {
     }
 }
 
+/// Whether two notes are the same in every field, `Note`'s own equality only compares starts
+fn same_note(a: &Note, b: &Note) -> bool {
+    a.id == b.id &&
+        a.start_tick == b.start_tick &&
+        a.duration == b.duration &&
+        a.key == b.key &&
+        a.velocity == b.velocity &&
+        a.probability == b.probability &&
+        a.micro_offset == b.micro_offset &&
+        a.mute == b.mute
+}
+
 impl ApplicationState {
     pub fn add_note_to_pattern(
         &mut self,
@@ -15,6 +15,7 @@ use crate::{
         PluginInstance,
         automation::{ AutomationLane, AutomationTarget },
         mixer::MixerChannel,
+        tempo_map::TICKS_PER_BEAT,
     },
     shared::{ BusId, GeneratorId, id::{ ClipId, TrackId } },
 };
@@ -27,8 +28,14 @@ pub struct KarbeatTrack {
     pub color: Color,
     pub track_type: TrackType,
     pub clips: BTreeSet<Arc<Clip>>,
-    pub max_sample_index: u32,
+    pub max_tick_index: u32,
     pub generator: Option<GeneratorInstance>,
+    /// Armed audio tracks receive a clip of every take when recording stops
+    #[serde(default)]
+    pub record_armed: bool,
+    /// When the live input is heard through the track's mixer channel
+    #[serde(default)]
+    pub monitor_mode: MonitorMode,
 }
 
 impl Default for KarbeatTrack {
@@ -39,12 +46,25 @@ impl Default for KarbeatTrack {
             color: Color::new_from_rgb(255, 255, 255),
             track_type: TrackType::Audio,
             clips: BTreeSet::new(),
-            max_sample_index: 0,
+            max_tick_index: 0,
             generator: None,
+            record_armed: false,
+            monitor_mode: MonitorMode::Off,
         }
     }
 }
 
+/// Input monitoring of an armed audio track
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MonitorMode {
+    #[default]
+    Off,
+    /// Monitor whenever the track is armed
+    Always,
+    /// Monitor while armed and the transport is stopped or recording
+    Auto,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub enum TrackType {
     Audio,
@@ -73,8 +93,23 @@ impl KarbeatTrack {
             color,
             track_type,
             clips: BTreeSet::new(),
-            max_sample_index: 0,
+            max_tick_index: 0,
             generator: None,
+            record_armed: false,
+            monitor_mode: MonitorMode::Off,
+        }
+    }
+
+    /// Whether the live input should be played through this track right now.
+    /// Only armed audio tracks monitor.
+    pub fn is_monitoring(&self, is_playing: bool, is_recording: bool) -> bool {
+        if !self.record_armed || self.track_type != TrackType::Audio {
+            return false;
+        }
+        match self.monitor_mode {
+            MonitorMode::Off => false,
+            MonitorMode::Always => true,
+            MonitorMode::Auto => !is_playing || is_recording,
         }
     }
 
@@ -110,7 +145,7 @@ impl KarbeatTrack {
 
         if is_valid {
             // Calculate potential new max index BEFORE moving clip
-            let clip_end_sample = clip.start_time + clip.loop_length;
+            let clip_end_tick = clip.start_time + clip.loop_length;
 
             // 1. Wrap in Arc immediately
             let clip_arc = Arc::new(clip);
@@ -120,19 +155,19 @@ impl KarbeatTrack {
 
             clips_set.insert(clip_arc);
 
-            // update the max sample index
-            if clip_end_sample > self.max_sample_index {
-                self.max_sample_index = clip_end_sample;
+            // update the max tick index
+            if clip_end_tick > self.max_tick_index {
+                self.max_tick_index = clip_end_tick;
             }
 
-            // Return the end sample of this new clip
-            return Ok(clip_end_sample);
+            // Return the end tick of this new clip
+            return Ok(clip_end_tick);
         } else {
             return Err(anyhow::anyhow!("Warning: Mismatched Clip Source for Track Type"));
         }
     }
 
-    /// Remove the clip, change max_index_sample if the deleted clip are the latest end sample index
+    /// Remove the clip, change max_tick_index if the deleted clip are the latest end tick index
     pub fn remove_clip(&mut self, clip_id: &ClipId) -> anyhow::Result<Arc<Clip>> {
         let clips_set = &mut self.clips;
 
@@ -145,8 +180,8 @@ impl KarbeatTrack {
         clips_set.retain(|c| c.id != *clip_id);
 
         if clips_set.len() < initial_len {
-            // Recalculate max sample index if something was removed
-            self.max_sample_index = clips_set
+            // Recalculate max tick index if something was removed
+            self.max_tick_index = clips_set
                 .iter()
                 .map(|c| c.start_time + c.loop_length)
                 .max()
@@ -180,15 +215,15 @@ impl KarbeatTrack {
         let clips_vec = &mut self.clips;
         clips_vec.extend(new_clips.iter().cloned());
 
-        self.max_sample_index = clips_vec
+        self.max_tick_index = clips_vec
             .iter()
             .map(|c| c.start_time + c.loop_length)
             .max()
             .unwrap_or(0);
     }
 
-    pub fn update_max_sample_index(&mut self) {
-        self.max_sample_index = self.clips
+    pub fn update_max_tick_index(&mut self) {
+        self.max_tick_index = self.clips
             .iter()
             .map(|c| c.start_time + c.loop_length)
             .max()
@@ -198,7 +233,7 @@ impl KarbeatTrack {
     pub fn cut_clip(
         &mut self,
         clip_id: &ClipId,
-        cut_point_sample: u32,
+        cut_point_tick: u32,
         clip_counter: &mut u32
     ) -> anyhow::Result<(Clip, Clip)> {
         let clip_arc = self
@@ -208,8 +243,8 @@ impl KarbeatTrack {
             })?;
 
         if
-            cut_point_sample > clip_arc.start_time &&
-            cut_point_sample < clip_arc.start_time + clip_arc.loop_length
+            cut_point_tick > clip_arc.start_time &&
+            cut_point_tick < clip_arc.start_time + clip_arc.loop_length
         {
             // Remove using the exact Arc reference, not the inner data reference
             self.clips.remove(&clip_arc);
@@ -218,19 +253,19 @@ impl KarbeatTrack {
 
             // Create left clip
             let mut left_clip = clip.clone();
-            left_clip.loop_length = cut_point_sample - left_clip.start_time;
+            left_clip.loop_length = cut_point_tick - left_clip.start_time;
             left_clip.id = *clip_id; // Retain original ID for the first half
             self.clips.insert(Arc::new(left_clip.clone()));
 
             // Create right clip
             let mut right_clip = clip.clone();
             right_clip.id = ClipId::next(clip_counter); // Consume counter for new ID
-            right_clip.start_time = cut_point_sample;
-            right_clip.offset_start += cut_point_sample - clip.start_time;
-            right_clip.loop_length = clip.start_time + clip.loop_length - cut_point_sample;
+            right_clip.start_time = cut_point_tick;
+            right_clip.offset_start += cut_point_tick - clip.start_time;
+            right_clip.loop_length = clip.start_time + clip.loop_length - cut_point_tick;
             self.clips.insert(Arc::new(right_clip.clone()));
 
-            self.update_max_sample_index();
+            self.update_max_tick_index();
 
             log::info!("Successfully cut the clip");
             Ok((left_clip, right_clip))
@@ -381,11 +416,9 @@ impl ApplicationState {
             _ => bus.name.clone(),
         };
 
-        // Initiate the points in the first and last max sample index with the current value
-        // Fallback to ~4 seconds if the project is completely empty
-        let end_sample = self.max_sample_index.max(
-            (self.audio_config.sample_rate * 4) / ((self.transport.bpm * 60.0).floor() as u32)
-        );
+        // Initiate the points in the first and last max tick index with the current value
+        // Fallback to 4 beats if the project is completely empty
+        let end_tick = self.max_tick_index.max(4 * TICKS_PER_BEAT);
 
         let new_automation_lane = self.add_automation_lane_return_lane(
             automation_target,
@@ -409,7 +442,8 @@ impl ApplicationState {
             id: ClipId::next(&mut self.clip_counter),
             name: label,
             start_time: 0,
-            loop_length: end_sample,
+            loop_length: end_tick,
+            groove: None,
             offset_start: 0,
             source: KarbeatSource::Automation(new_automation_lane.id.clone()),
         };
@@ -449,7 +483,10 @@ impl ApplicationState {
         // Remove all automation lanes for this track
         self.remove_automation_lanes_for_track(track_id);
 
-        self.update_max_sample_index();
+        // Remove all controller mappings for this track
+        self.midi_mappings.remove_for_track(track_id);
+
+        self.update_max_tick_index();
 
         Ok(())
     }
@@ -458,7 +495,7 @@ impl ApplicationState {
         &mut self,
         track_id: &TrackId,
         clip_id: &ClipId,
-        cut_point_sample: u32
+        cut_point_tick: u32
     ) -> anyhow::Result<(Clip, Clip)> {
         let track_arc = self.tracks
             .get_mut(track_id)
@@ -468,7 +505,7 @@ impl ApplicationState {
 
         let clip_counter = &mut self.clip_counter;
 
-        let res = track_mut.cut_clip(clip_id, cut_point_sample, clip_counter)?;
+        let res = track_mut.cut_clip(clip_id, cut_point_tick, clip_counter)?;
 
         Ok(res)
     }
@@ -0,0 +1,331 @@
+// src/core/project/transform.rs
+//
+// Bulk note edits on a selection: pitch, velocity and timing transforms. A transform takes the
+// selected notes and gives back their new states, notes it drops (merging) are left out. The
+// pattern swaps them in as one edit, so every transform is a single undo step.
+
+use serde::{ Deserialize, Serialize };
+
+use crate::core::project::{ Note, theory::Scale };
+
+/// What a transpose does with notes that would leave the MIDI range.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeMode {
+    /// Stop at 0 or 127
+    Clamp,
+    /// Move back into range by whole octaves
+    FoldOctaves,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum NoteTransform {
+    Transpose {
+        semitones: i16,
+        mode: RangeMode,
+    },
+    /// Moves notes by degrees of `scale`. Notes outside the scale first drop to the degree
+    /// below them.
+    ScaleTranspose {
+        degrees: i32,
+        scale: Scale,
+    },
+    VelocityScale {
+        factor: f32,
+    },
+    /// Pulls velocities towards their average (0.0 - 1.0, 1.0 makes them all equal)
+    VelocityCompress {
+        amount: f32,
+    },
+    /// Moves velocities by up to `amount` either way
+    VelocityRandomize {
+        amount: u8,
+        seed: u64,
+    },
+    /// Extends every note up to the next note start
+    Legato,
+    /// Plays the selection backwards (retrograde)
+    Reverse,
+    /// Mirrors pitches around `pivot`
+    Invert {
+        pivot: u8,
+    },
+    /// Scales starts and lengths from the start of the selection
+    TimeStretch {
+        factor: f32,
+    },
+    /// Moves starts by up to `timing` ticks and velocities by up to `velocity` either way
+    Humanize {
+        timing: u64,
+        velocity: u8,
+        seed: u64,
+    },
+    /// Shortens notes so they end where the next note of the same key starts
+    SplitOverlaps,
+    /// Joins overlapping notes of the same key into one
+    MergeOverlaps,
+    /// Moves notes outside the scale to its closest key
+    SnapToScale {
+        scale: Scale,
+    },
+}
+
+impl NoteTransform {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        match self {
+            NoteTransform::ScaleTranspose { scale, .. } if scale.root > 11 => {
+                return Err(
+                    anyhow::anyhow!("Scale root must be a pitch class 0-11, got {}", scale.root)
+                );
+            }
+            NoteTransform::VelocityScale { factor } if *factor < 0.0 || factor.is_nan() => {
+                return Err(anyhow::anyhow!("Velocity factor must not be negative"));
+            }
+            NoteTransform::TimeStretch { factor } if *factor <= 0.0 || factor.is_nan() => {
+                return Err(anyhow::anyhow!("Stretch factor must be greater than 0"));
+            }
+            NoteTransform::Invert { pivot } if *pivot > 127 => {
+                return Err(anyhow::anyhow!("Pivot must be a MIDI key 0-127, got {pivot}"));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// New states of `notes`. Notes keep their IDs, merged notes are dropped.
+    pub fn apply(&self, mut notes: Vec<Note>) -> Vec<Note> {
+        notes.sort();
+        match self {
+            NoteTransform::Transpose { semitones, mode } => {
+                for note in &mut notes {
+                    note.key = fit_key((note.key as i32) + (*semitones as i32), *mode);
+                }
+            }
+            NoteTransform::ScaleTranspose { degrees, scale } => {
+                for note in &mut notes {
+                    note.key = scale_transpose(note.key, *degrees, scale);
+                }
+            }
+            NoteTransform::VelocityScale { factor } => {
+                for note in &mut notes {
+                    note.velocity = fit_velocity((note.velocity as f32) * factor);
+                }
+            }
+            NoteTransform::VelocityCompress { amount } => {
+                let count = notes.len().max(1) as f32;
+                let mean = notes.iter().map(|n| n.velocity as f32).sum::<f32>() / count;
+                let amount = amount.clamp(0.0, 1.0);
+                for note in &mut notes {
+                    let velocity = note.velocity as f32;
+                    note.velocity = fit_velocity(velocity + (mean - velocity) * amount);
+                }
+            }
+            NoteTransform::VelocityRandomize { amount, seed } => {
+                let mut rng = fastrand::Rng::with_seed(*seed);
+                let amount = *amount as i32;
+                for note in &mut notes {
+                    let offset = rng.i32(-amount..=amount);
+                    note.velocity = fit_velocity(((note.velocity as i32) + offset) as f32);
+                }
+            }
+            NoteTransform::Legato => {
+                let starts: Vec<u64> = notes.iter().map(|n| n.start_tick).collect();
+                for note in &mut notes {
+                    // Chords extend together, to the first start after theirs
+                    if let Some(&next) = starts.iter().find(|&&start| start > note.start_tick) {
+                        note.duration = next - note.start_tick;
+                    }
+                }
+            }
+            NoteTransform::Reverse => {
+                let span_start = notes.iter().map(|n| n.start_tick).min().unwrap_or(0);
+                let span_end = notes
+                    .iter()
+                    .map(|n| n.start_tick + n.duration)
+                    .max()
+                    .unwrap_or(0);
+                for note in &mut notes {
+                    note.start_tick = span_start + span_end - (note.start_tick + note.duration);
+                }
+            }
+            NoteTransform::Invert { pivot } => {
+                for note in &mut notes {
+                    let key = 2 * (*pivot as i32) - (note.key as i32);
+                    note.key = fit_key(key, RangeMode::FoldOctaves);
+                }
+            }
+            NoteTransform::TimeStretch { factor } => {
+                let origin = notes.iter().map(|n| n.start_tick).min().unwrap_or(0);
+                for note in &mut notes {
+                    let offset = ((note.start_tick - origin) as f64) * (*factor as f64);
+                    note.start_tick = origin + (offset.round() as u64);
+                    note.duration = (((note.duration as f64) * (*factor as f64)).round() as u64).max(1);
+                }
+            }
+            NoteTransform::Humanize { timing, velocity, seed } => {
+                let mut rng = fastrand::Rng::with_seed(*seed);
+                let (timing, velocity) = (*timing as i64, *velocity as i32);
+                for note in &mut notes {
+                    let shift = rng.i64(-timing..=timing);
+                    note.start_tick = ((note.start_tick as i64) + shift).max(0) as u64;
+                    let offset = rng.i32(-velocity..=velocity);
+                    note.velocity = fit_velocity(((note.velocity as i32) + offset) as f32);
+                }
+            }
+            NoteTransform::SplitOverlaps => {
+                let starts: Vec<(u8, u64)> = notes.iter().map(|n| (n.key, n.start_tick)).collect();
+                for note in &mut notes {
+                    let end = note.start_tick + note.duration;
+                    let next = starts
+                        .iter()
+                        .filter(|&&(key, start)| key == note.key && start > note.start_tick)
+                        .map(|&(_, start)| start)
+                        .min();
+                    if let Some(next) = next.filter(|&next| next < end) {
+                        note.duration = next - note.start_tick;
+                    }
+                }
+            }
+            NoteTransform::MergeOverlaps => {
+                let mut merged: Vec<Note> = Vec::with_capacity(notes.len());
+                for note in notes {
+                    // Notes are sorted by start, so only the last note of a key can overlap
+                    let open = merged
+                        .iter_mut()
+                        .rev()
+                        .find(|n| n.key == note.key)
+                        .filter(|n| n.start_tick + n.duration > note.start_tick);
+                    match open {
+                        Some(open) => {
+                            let end = (open.start_tick + open.duration).max(
+                                note.start_tick + note.duration
+                            );
+                            open.duration = end - open.start_tick;
+                            open.velocity = open.velocity.max(note.velocity);
+                        }
+                        None => merged.push(note),
+                    }
+                }
+                return merged;
+            }
+            NoteTransform::SnapToScale { scale } => {
+                for note in &mut notes {
+                    note.key = scale.snap(note.key);
+                }
+            }
+        }
+        notes
+    }
+}
+
+fn fit_key(key: i32, mode: RangeMode) -> u8 {
+    match mode {
+        RangeMode::Clamp => key.clamp(0, 127) as u8,
+        RangeMode::FoldOctaves => {
+            let mut key = key;
+            while key > 127 {
+                key -= 12;
+            }
+            while key < 0 {
+                key += 12;
+            }
+            key as u8
+        }
+    }
+}
+
+fn fit_velocity(velocity: f32) -> u8 {
+    velocity.round().clamp(1.0, 127.0) as u8
+}
+
+/// `key` moved by `degrees` steps of `scale`.
+fn scale_transpose(key: u8, degrees: i32, scale: &Scale) -> u8 {
+    let (root, intervals) = (scale.root, scale.mode.intervals());
+    let from_root = (key as i32) - (root as i32);
+    let octave = from_root.div_euclid(12);
+    let pitch_class = from_root.rem_euclid(12) as u8;
+    // Degree at or below the key, a key below the first degree belongs to the octave below
+    let degree = match intervals.iter().rposition(|&i| i <= pitch_class) {
+        Some(degree) => octave * (intervals.len() as i32) + (degree as i32),
+        None => octave * (intervals.len() as i32) - 1,
+    };
+
+    let target = degree + degrees;
+    let len = intervals.len() as i32;
+    let key = (root as i32) + target.div_euclid(len) * 12 +
+        (intervals[target.rem_euclid(len) as usize] as i32);
+    fit_key(key, RangeMode::FoldOctaves)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::project::theory::ScaleMode;
+    use crate::shared::id::NoteId;
+
+    fn note(id: u32, start_tick: u64, duration: u64, key: u8) -> Note {
+        Note {
+            id: NoteId::from(id),
+            start_tick,
+            duration,
+            key,
+            velocity: 100,
+            probability: 1.0,
+            micro_offset: 0,
+            mute: false,
+        }
+    }
+
+    fn keys(notes: &[Note]) -> Vec<u8> {
+        notes.iter().map(|n| n.key).collect()
+    }
+
+    #[test]
+    fn pitch_transforms_stay_in_range() {
+        let notes = vec![note(0, 0, 240, 60), note(1, 240, 240, 125)];
+
+        let clamp = NoteTransform::Transpose { semitones: 5, mode: RangeMode::Clamp };
+        assert_eq!(keys(&clamp.apply(notes.clone())), [65, 127]);
+        let fold = NoteTransform::Transpose { semitones: 5, mode: RangeMode::FoldOctaves };
+        assert_eq!(keys(&fold.apply(notes.clone())), [65, 118]);
+
+        // Two degrees up C major: C -> E, and B -> D in the next octave
+        let major = Scale::new(0, ScaleMode::Major);
+        let up = NoteTransform::ScaleTranspose { degrees: 2, scale: major };
+        assert_eq!(keys(&up.apply(vec![note(0, 0, 240, 60), note(1, 0, 240, 71)])), [64, 74]);
+        // C# is outside the scale, it counts as C
+        let down = NoteTransform::ScaleTranspose { degrees: -1, scale: major };
+        assert_eq!(keys(&down.apply(vec![note(0, 0, 240, 61)])), [59]);
+
+        let invert = NoteTransform::Invert { pivot: 60 };
+        assert_eq!(keys(&invert.apply(vec![note(0, 0, 240, 64)])), [56]);
+    }
+
+    #[test]
+    fn timing_transforms() {
+        let notes = vec![note(0, 0, 100, 60), note(1, 480, 100, 62), note(2, 960, 240, 64)];
+
+        let legato = NoteTransform::Legato.apply(notes.clone());
+        assert_eq!(legato.iter().map(|n| n.duration).collect::<Vec<_>>(), [480, 480, 240]);
+
+        let reversed = NoteTransform::Reverse.apply(notes.clone());
+        let starts: Vec<(u8, u64)> = reversed.iter().map(|n| (n.key, n.start_tick)).collect();
+        assert_eq!(starts, [(60, 1100), (62, 620), (64, 0)]);
+
+        let stretched = NoteTransform::TimeStretch { factor: 0.5 }.apply(notes);
+        assert_eq!(stretched[2].start_tick, 480);
+        assert_eq!(stretched[2].duration, 120);
+    }
+
+    #[test]
+    fn overlapping_notes_split_or_merge() {
+        let notes = vec![note(0, 0, 480, 60), note(1, 240, 480, 60), note(2, 240, 480, 62)];
+
+        let split = NoteTransform::SplitOverlaps.apply(notes.clone());
+        assert_eq!(split[0].duration, 240);
+        assert_eq!(split.len(), 3);
+
+        let merged = NoteTransform::MergeOverlaps.apply(notes);
+        assert_eq!(merged.len(), 2);
+        assert_eq!((merged[0].start_tick, merged[0].duration), (0, 720));
+    }
+}
@@ -1,12 +1,118 @@
 use serde::{Deserialize, Serialize};
 
+use crate::shared::id::BusId;
+use crate::core::project::{
+    musical_time::{ GridDivision, TimeSignature, TimeSignatureMap },
+    tempo_map::TempoMap,
+};
+
 /// Serializable project transport settings.
 /// Runtime transport state (is_playing, playhead, etc.) lives in the AudioEngine.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TransportState {
     // general state
+    /// Tempo at the start of the project. Mirrors the first point of `tempo_map`.
     pub bpm: f32,
+    /// Signature at the start of the project. Mirrors the first event of `time_signature_map`.
     pub time_signature: (u8, u8),
+    /// Tempo changes along the timeline
+    #[serde(default)]
+    pub tempo_map: TempoMap,
+    /// Meter changes along the timeline
+    #[serde(default)]
+    pub time_signature_map: TimeSignatureMap,
+    /// Loop in/out points used by song playback when looping is enabled.
+    /// `None` loops the whole song.
+    #[serde(default)]
+    pub loop_region: Option<LoopRegion>,
+    /// Click track and count-in settings
+    #[serde(default)]
+    pub metronome: MetronomeSettings,
+    /// How live notes are recorded into patterns
+    #[serde(default)]
+    pub midi_record: MidiRecordSettings,
+    /// Seed of the note probability rolls, the same seed plays and exports the same notes
+    #[serde(default)]
+    pub random_seed: u64,
+}
+
+/// Metronome click and count-in settings.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct MetronomeSettings {
+    /// Click during song playback
+    pub enabled: bool,
+    /// Linear gain of the click (0.0 - 1.0)
+    pub volume: f32,
+    /// Bus that receives the click. `None` sends it straight to the audio output,
+    /// bypassing the master bus.
+    pub target: Option<BusId>,
+    /// Bars of count-in before playback starts (0 disables the count-in)
+    pub count_in_bars: u8,
+}
+
+impl MetronomeSettings {
+    pub const MAX_COUNT_IN_BARS: u8 = 2;
+}
+
+impl Default for MetronomeSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            volume: 0.5,
+            target: None,
+            count_in_bars: 0,
+        }
+    }
+}
+
+/// How a MIDI take treats the notes already in the pattern.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MidiRecordMode {
+    /// Recorded notes are added on top of the existing ones
+    #[default]
+    Overdub,
+    /// Existing notes starting inside the recorded range are removed
+    Replace,
+}
+
+/// MIDI note recording settings.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MidiRecordSettings {
+    pub mode: MidiRecordMode,
+    /// Grid that recorded note starts snap to. `None` keeps the played timing
+    pub quantize: Option<GridDivision>,
+}
+
+/// A loop range on the timeline, in ticks. `end` is exclusive.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoopRegion {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl LoopRegion {
+    /// Returns `None` when the region would be empty.
+    pub fn new(start: u64, end: u64) -> Option<Self> {
+        (end > start).then_some(Self { start, end })
+    }
+
+    pub fn length(&self) -> u64 {
+        self.end.saturating_sub(self.start)
+    }
+}
+
+impl TransportState {
+    /// Sets the project start tempo, keeping `bpm` and the tempo map in sync.
+    pub fn set_bpm(&mut self, bpm: f32) {
+        self.bpm = bpm;
+        self.tempo_map.set_initial_bpm(bpm);
+    }
+
+    /// Sets the project start signature, keeping `time_signature` and the map in sync.
+    pub fn set_time_signature(&mut self, signature: TimeSignature) {
+        self.time_signature = signature.into();
+        self.time_signature_map.set_initial(signature);
+    }
 }
 
 impl Default for TransportState {
@@ -14,12 +120,25 @@ impl Default for TransportState {
         Self {
             bpm: 67.0,
             time_signature: (4, 4),
+            tempo_map: TempoMap::new(67.0),
+            time_signature_map: TimeSignatureMap::new(TimeSignature::COMMON),
+            loop_region: None,
+            metronome: MetronomeSettings::default(),
+            midi_record: MidiRecordSettings::default(),
+            random_seed: 0,
         }
     }
 }
 
 impl PartialEq for TransportState {
     fn eq(&self, other: &Self) -> bool {
-        self.bpm == other.bpm && self.time_signature == other.time_signature
+        self.bpm == other.bpm
+            && self.time_signature == other.time_signature
+            && self.tempo_map == other.tempo_map
+            && self.time_signature_map == other.time_signature_map
+            && self.loop_region == other.loop_region
+            && self.metronome == other.metronome
+            && self.midi_record == other.midi_record
+            && self.random_seed == other.random_seed
     }
 }
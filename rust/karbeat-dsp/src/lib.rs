@@ -6,8 +6,10 @@ pub mod bit_crush;
 pub mod chorus;
 pub mod filter;
 pub mod flanger;
+pub mod loudness;
 pub mod pitch_shift;
 pub mod reverb;
+pub mod spectrum;
 pub mod stretcher;
 
 pub mod prelude;
\ No newline at end of file
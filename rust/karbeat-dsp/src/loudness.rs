@@ -0,0 +1,468 @@
+//! Loudness and true-peak measurement after ITU-R BS.1770-4 / EBU R128.
+//!
+//! Momentary (400 ms) and short-term (3 s) loudness are computed over 100 ms hops. Integrated
+//! loudness and loudness range keep histograms of the gated blocks instead of every block, so the
+//! meter runs for hours in constant memory and never allocates after construction.
+
+use std::f64::consts::PI;
+
+/// Loudness of a mean square of 1.0 is -0.691 LUFS
+const LOUDNESS_OFFSET: f64 = -0.691;
+
+/// Blocks quieter than this never count towards integrated loudness or range
+const ABSOLUTE_GATE_LUFS: f64 = -70.0;
+/// Integrated loudness ignores blocks this far below the absolute-gated mean
+const INTEGRATED_RELATIVE_GATE_LU: f64 = -10.0;
+/// Loudness range ignores short-term values this far below the absolute-gated mean
+const RANGE_RELATIVE_GATE_LU: f64 = -20.0;
+/// Loudness range spans these percentiles of the gated short-term values
+const RANGE_LOW_PERCENTILE: f64 = 0.1;
+const RANGE_HIGH_PERCENTILE: f64 = 0.95;
+
+/// Histogram resolution and span
+const HISTOGRAM_STEP_LU: f64 = 0.1;
+const HISTOGRAM_MAX_LUFS: f64 = 30.0;
+const HISTOGRAM_BINS: usize = ((HISTOGRAM_MAX_LUFS - ABSOLUTE_GATE_LUFS) /
+    HISTOGRAM_STEP_LU) as usize;
+
+/// Hops per momentary and short-term window
+const HOP_SECONDS: f64 = 0.1;
+const MOMENTARY_HOPS: usize = 4;
+const SHORT_TERM_HOPS: usize = 30;
+
+/// True peak is measured at four times the sample rate
+const OVERSAMPLING: usize = 4;
+const TAPS_PER_PHASE: usize = 12;
+
+/// Channels measured: left and right, weighted 1.0 each
+const CHANNELS: usize = 2;
+
+fn energy_to_lufs(energy: f64) -> f64 {
+    if energy <= 0.0 {
+        f64::NEG_INFINITY
+    } else {
+        LOUDNESS_OFFSET + 10.0 * energy.log10()
+    }
+}
+
+fn lufs_to_energy(lufs: f64) -> f64 {
+    (10.0f64).powf((lufs - LOUDNESS_OFFSET) / 10.0)
+}
+
+fn histogram_bin(lufs: f64) -> Option<usize> {
+    if lufs < ABSOLUTE_GATE_LUFS {
+        return None;
+    }
+    let bin = ((lufs - ABSOLUTE_GATE_LUFS) / HISTOGRAM_STEP_LU) as usize;
+    Some(bin.min(HISTOGRAM_BINS - 1))
+}
+
+fn bin_lufs(bin: usize) -> f64 {
+    ABSOLUTE_GATE_LUFS + ((bin as f64) + 0.5) * HISTOGRAM_STEP_LU
+}
+
+/// Snapshot of a [`LoudnessMeter`]. Loudness in LUFS, range in LU, peak in dBTP.
+/// Measurements without enough signal are negative infinity (range: 0).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoudnessReport {
+    pub momentary: f32,
+    pub short_term: f32,
+    pub integrated: f32,
+    pub loudness_range: f32,
+    pub true_peak: f32,
+    pub max_momentary: f32,
+    pub max_short_term: f32,
+}
+
+impl Default for LoudnessReport {
+    fn default() -> Self {
+        Self {
+            momentary: f32::NEG_INFINITY,
+            short_term: f32::NEG_INFINITY,
+            integrated: f32::NEG_INFINITY,
+            loudness_range: 0.0,
+            true_peak: f32::NEG_INFINITY,
+            max_momentary: f32::NEG_INFINITY,
+            max_short_term: f32::NEG_INFINITY,
+        }
+    }
+}
+
+// ============================================================================
+// K-WEIGHTING
+// ============================================================================
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    z: [f64; 2],
+}
+
+impl Biquad {
+    #[inline]
+    fn process(&mut self, x: f64) -> f64 {
+        let y = self.b[0] * x + self.z[0];
+        self.z[0] = self.b[1] * x - self.a[0] * y + self.z[1];
+        self.z[1] = self.b[2] * x - self.a[1] * y;
+        y
+    }
+
+    fn reset(&mut self) {
+        self.z = [0.0; 2];
+    }
+}
+
+/// The BS.1770 pre-filter (high shelf) followed by the RLB high-pass, for any sample rate.
+#[derive(Clone, Copy, Debug)]
+struct KWeighting {
+    shelf: Biquad,
+    high_pass: Biquad,
+}
+
+impl KWeighting {
+    fn new(sample_rate: u32) -> Self {
+        let fs = sample_rate.max(1) as f64;
+
+        let f0 = 1681.974450955533;
+        let gain_db = 3.999843853973347;
+        let q = 0.7071752369554196;
+        let k = (PI * f0 / fs).tan();
+        let vh = (10.0f64).powf(gain_db / 20.0);
+        let vb = vh.powf(0.4996667741545416);
+        let a0 = 1.0 + k / q + k * k;
+        let shelf = Biquad {
+            b: [
+                (vh + (vb * k) / q + k * k) / a0,
+                (2.0 * (k * k - vh)) / a0,
+                (vh - (vb * k) / q + k * k) / a0,
+            ],
+            a: [(2.0 * (k * k - 1.0)) / a0, (1.0 - k / q + k * k) / a0],
+            z: [0.0; 2],
+        };
+
+        let f0 = 38.13547087602444;
+        let q = 0.5003270373238773;
+        let k = (PI * f0 / fs).tan();
+        let a0 = 1.0 + k / q + k * k;
+        let high_pass = Biquad {
+            b: [1.0, -2.0, 1.0],
+            a: [(2.0 * (k * k - 1.0)) / a0, (1.0 - k / q + k * k) / a0],
+            z: [0.0; 2],
+        };
+
+        Self { shelf, high_pass }
+    }
+
+    #[inline]
+    fn process(&mut self, x: f64) -> f64 {
+        self.high_pass.process(self.shelf.process(x))
+    }
+
+    fn reset(&mut self) {
+        self.shelf.reset();
+        self.high_pass.reset();
+    }
+}
+
+// ============================================================================
+// TRUE PEAK
+// ============================================================================
+
+/// Polyphase windowed-sinc interpolator, one per channel.
+#[derive(Clone, Debug)]
+struct TruePeak {
+    history: [f32; TAPS_PER_PHASE],
+    position: usize,
+    peak: f32,
+}
+
+impl TruePeak {
+    fn new() -> Self {
+        Self { history: [0.0; TAPS_PER_PHASE], position: 0, peak: 0.0 }
+    }
+
+    #[inline]
+    fn process(&mut self, sample: f32, phases: &[[f32; TAPS_PER_PHASE]; OVERSAMPLING]) {
+        self.history[self.position] = sample;
+        self.position = (self.position + 1) % TAPS_PER_PHASE;
+
+        for taps in phases {
+            let mut acc = 0.0;
+            // Newest sample meets the first tap
+            for (i, tap) in taps.iter().enumerate() {
+                let index = (self.position + TAPS_PER_PHASE - 1 - i) % TAPS_PER_PHASE;
+                acc += tap * self.history[index];
+            }
+            self.peak = self.peak.max(acc.abs());
+        }
+        // The interpolated points can miss a peak that lands on a sample
+        self.peak = self.peak.max(sample.abs());
+    }
+}
+
+/// Taps of the 4x interpolation filter, split by output phase: a Hann-windowed sinc with its
+/// cutoff at the original Nyquist frequency.
+fn interpolation_phases() -> [[f32; TAPS_PER_PHASE]; OVERSAMPLING] {
+    let length = TAPS_PER_PHASE * OVERSAMPLING;
+    let center = ((length - 1) as f64) / 2.0;
+    let mut phases = [[0.0; TAPS_PER_PHASE]; OVERSAMPLING];
+    for n in 0..length {
+        let t = ((n as f64) - center) / (OVERSAMPLING as f64);
+        let sinc = if t.abs() < 1e-12 { 1.0 } else { (PI * t).sin() / (PI * t) };
+        let window = 0.5 - 0.5 * ((2.0 * PI * ((n as f64) + 0.5)) / (length as f64)).cos();
+        phases[n % OVERSAMPLING][n / OVERSAMPLING] = (sinc * window) as f32;
+    }
+    phases
+}
+
+// ============================================================================
+// METER
+// ============================================================================
+
+/// Streaming EBU R128 loudness meter for a stereo signal.
+#[derive(Clone, Debug)]
+pub struct LoudnessMeter {
+    sample_rate: u32,
+    filters: [KWeighting; CHANNELS],
+    true_peaks: [TruePeak; CHANNELS],
+    phases: [[f32; TAPS_PER_PHASE]; OVERSAMPLING],
+
+    /// Frames per 100 ms hop
+    hop_frames: usize,
+    /// Weighted energy and frames of the hop in progress
+    hop_energy: f64,
+    hop_filled: usize,
+    /// Mean energy of the last hops, newest at `hop_index - 1`
+    hops: [f64; SHORT_TERM_HOPS],
+    hop_index: usize,
+    hops_done: usize,
+
+    /// Gated 400 ms blocks: count and summed energy per loudness bin
+    block_counts: Box<[u64]>,
+    block_energy: Box<[f64]>,
+    /// Gated short-term values: count per loudness bin
+    short_term_counts: Box<[u64]>,
+
+    max_momentary: f64,
+    max_short_term: f64,
+}
+
+impl LoudnessMeter {
+    pub fn new(sample_rate: u32) -> Self {
+        let sample_rate = sample_rate.max(1);
+        Self {
+            sample_rate,
+            filters: [KWeighting::new(sample_rate); CHANNELS],
+            true_peaks: [TruePeak::new(), TruePeak::new()],
+            phases: interpolation_phases(),
+            hop_frames: (((sample_rate as f64) * HOP_SECONDS).round() as usize).max(1),
+            hop_energy: 0.0,
+            hop_filled: 0,
+            hops: [0.0; SHORT_TERM_HOPS],
+            hop_index: 0,
+            hops_done: 0,
+            block_counts: vec![0; HISTOGRAM_BINS].into_boxed_slice(),
+            block_energy: vec![0.0; HISTOGRAM_BINS].into_boxed_slice(),
+            short_term_counts: vec![0; HISTOGRAM_BINS].into_boxed_slice(),
+            max_momentary: f64::NEG_INFINITY,
+            max_short_term: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Forgets everything measured so far.
+    pub fn reset(&mut self) {
+        for filter in &mut self.filters {
+            filter.reset();
+        }
+        self.true_peaks = [TruePeak::new(), TruePeak::new()];
+        self.hop_energy = 0.0;
+        self.hop_filled = 0;
+        self.hops = [0.0; SHORT_TERM_HOPS];
+        self.hop_index = 0;
+        self.hops_done = 0;
+        self.block_counts.fill(0);
+        self.block_energy.fill(0.0);
+        self.short_term_counts.fill(0);
+        self.max_momentary = f64::NEG_INFINITY;
+        self.max_short_term = f64::NEG_INFINITY;
+    }
+
+    /// Measures an interleaved buffer. Mono is measured as both channels, channels past the
+    /// second are ignored.
+    pub fn process(&mut self, buffer: &[f32], channels: usize) {
+        let channels = channels.max(1);
+        for frame in buffer.chunks_exact(channels) {
+            let left = frame[0];
+            let right = if channels > 1 { frame[1] } else { left };
+
+            let mut energy = 0.0;
+            for (channel, sample) in [left, right].into_iter().enumerate() {
+                let weighted = self.filters[channel].process(sample as f64);
+                energy += weighted * weighted;
+                self.true_peaks[channel].process(sample, &self.phases);
+            }
+            self.hop_energy += energy;
+            self.hop_filled += 1;
+
+            if self.hop_filled == self.hop_frames {
+                self.finish_hop();
+            }
+        }
+    }
+
+    fn finish_hop(&mut self) {
+        self.hops[self.hop_index] = self.hop_energy / (self.hop_filled as f64);
+        self.hop_index = (self.hop_index + 1) % SHORT_TERM_HOPS;
+        self.hops_done += 1;
+        self.hop_energy = 0.0;
+        self.hop_filled = 0;
+
+        if let Some(energy) = self.window_energy(MOMENTARY_HOPS) {
+            let lufs = energy_to_lufs(energy);
+            self.max_momentary = self.max_momentary.max(lufs);
+            if let Some(bin) = histogram_bin(lufs) {
+                self.block_counts[bin] += 1;
+                self.block_energy[bin] += energy;
+            }
+        }
+        if let Some(energy) = self.window_energy(SHORT_TERM_HOPS) {
+            let lufs = energy_to_lufs(energy);
+            self.max_short_term = self.max_short_term.max(lufs);
+            if let Some(bin) = histogram_bin(lufs) {
+                self.short_term_counts[bin] += 1;
+            }
+        }
+    }
+
+    /// Mean energy of the last `hops` hops, none until that many were measured.
+    fn window_energy(&self, hops: usize) -> Option<f64> {
+        if self.hops_done < hops {
+            return None;
+        }
+        let sum: f64 = (1..=hops)
+            .map(|back| self.hops[(self.hop_index + SHORT_TERM_HOPS - back) % SHORT_TERM_HOPS])
+            .sum();
+        Some(sum / (hops as f64))
+    }
+
+    /// Loudness of the last 400 ms.
+    pub fn momentary(&self) -> f64 {
+        self.window_energy(MOMENTARY_HOPS).map_or(f64::NEG_INFINITY, energy_to_lufs)
+    }
+
+    /// Loudness of the last 3 s.
+    pub fn short_term(&self) -> f64 {
+        self.window_energy(SHORT_TERM_HOPS).map_or(f64::NEG_INFINITY, energy_to_lufs)
+    }
+
+    /// Gated loudness of everything measured since the last reset.
+    pub fn integrated(&self) -> f64 {
+        let mean_above = |from_bin: usize| {
+            let count: u64 = self.block_counts[from_bin..].iter().sum();
+            let energy: f64 = self.block_energy[from_bin..].iter().sum();
+            (count > 0).then(|| energy / (count as f64))
+        };
+        let Some(ungated) = mean_above(0) else {
+            return f64::NEG_INFINITY;
+        };
+        let gate = energy_to_lufs(ungated) + INTEGRATED_RELATIVE_GATE_LU;
+        let gate_bin = histogram_bin(gate).unwrap_or(0);
+        mean_above(gate_bin).map_or(f64::NEG_INFINITY, energy_to_lufs)
+    }
+
+    /// Spread between the soft and loud parts (10th to 95th percentile of the gated short-term
+    /// loudness), in LU.
+    pub fn loudness_range(&self) -> f64 {
+        let total: u64 = self.short_term_counts.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let energy: f64 = self.short_term_counts
+            .iter()
+            .enumerate()
+            .map(|(bin, &count)| (count as f64) * lufs_to_energy(bin_lufs(bin)))
+            .sum();
+        let gate = energy_to_lufs(energy / (total as f64)) + RANGE_RELATIVE_GATE_LU;
+        let gate_bin = histogram_bin(gate).unwrap_or(0);
+
+        let gated = &self.short_term_counts[gate_bin..];
+        let gated_total: u64 = gated.iter().sum();
+        if gated_total == 0 {
+            return 0.0;
+        }
+        let percentile = |fraction: f64| {
+            let target = ((gated_total as f64) * fraction).floor() as u64;
+            let mut seen = 0;
+            for (offset, &count) in gated.iter().enumerate() {
+                seen += count;
+                if seen > target {
+                    return bin_lufs(gate_bin + offset);
+                }
+            }
+            bin_lufs(HISTOGRAM_BINS - 1)
+        };
+        (percentile(RANGE_HIGH_PERCENTILE) - percentile(RANGE_LOW_PERCENTILE)).max(0.0)
+    }
+
+    /// Highest inter-sample peak of any channel, linear.
+    pub fn true_peak(&self) -> f32 {
+        self.true_peaks.iter().fold(0.0, |peak, channel| peak.max(channel.peak))
+    }
+
+    pub fn report(&self) -> LoudnessReport {
+        let true_peak = self.true_peak();
+        LoudnessReport {
+            momentary: self.momentary() as f32,
+            short_term: self.short_term() as f32,
+            integrated: self.integrated() as f32,
+            loudness_range: self.loudness_range() as f32,
+            true_peak: if true_peak > 0.0 { 20.0 * true_peak.log10() } else { f32::NEG_INFINITY },
+            max_momentary: self.max_momentary as f32,
+            max_short_term: self.max_short_term as f32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sine(sample_rate: u32, frequency: f64, amplitude: f64, seconds: f64) -> Vec<f32> {
+        let frames = ((sample_rate as f64) * seconds) as usize;
+        (0..frames)
+            .flat_map(|i| {
+                let t = (i as f64) / (sample_rate as f64);
+                let s = (amplitude * (2.0 * PI * frequency * t).sin()) as f32;
+                [s, s]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn stereo_1k_sine_reads_its_level_in_lufs() {
+        // EBU Tech 3341: a 1 kHz sine on both channels reads its dBFS level in LUFS
+        let sample_rate = 48_000;
+        let mut meter = LoudnessMeter::new(sample_rate);
+        let amplitude = (10.0f64).powf(-23.0 / 20.0);
+        meter.process(&sine(sample_rate, 1_000.0, amplitude, 5.0), 2);
+
+        let report = meter.report();
+        assert!((report.integrated + 23.0).abs() < 0.1, "{:?}", report);
+        assert!((report.short_term + 23.0).abs() < 0.1, "{:?}", report);
+        assert!(report.loudness_range < 0.2);
+        assert!((report.true_peak + 23.0).abs() < 0.2, "{:?}", report);
+
+        // A part 20 dB quieter falls below the relative gate but widens the range
+        meter.reset();
+        assert_eq!(meter.report(), LoudnessReport::default());
+        meter.process(&sine(sample_rate, 1_000.0, 1.0, 5.0), 2);
+        meter.process(&sine(sample_rate, 1_000.0, 0.1, 5.0), 2);
+        assert!(meter.integrated().abs() < 0.2);
+        assert!(meter.loudness_range() > 15.0);
+    }
+}
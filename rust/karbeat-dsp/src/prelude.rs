@@ -4,8 +4,10 @@ pub use crate::bit_crush::*;
 pub use crate::chorus::*;
 pub use crate::envelope::*;
 pub use crate::flanger::*;
+pub use crate::loudness::*;
 pub use crate::filter::*;
 pub use crate::oscillator::*;
 pub use crate::pitch_shift::*;
 pub use crate::reverb::*;
+pub use crate::spectrum::*;
 pub use crate::stretcher::*;
\ No newline at end of file
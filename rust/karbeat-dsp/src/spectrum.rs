@@ -0,0 +1,168 @@
+//! FFT magnitude spectra for analyzers.
+//!
+//! [`SpectrumAnalyzer`] turns a block of samples into a magnitude spectrum in dBFS, scaled so a
+//! full-scale sine reads 0 dB whatever the window. [`log_spaced`] resamples a spectrum onto
+//! log-spaced frequencies, the way response curves are drawn.
+
+use std::sync::Arc;
+
+use num_complex::Complex;
+use rustfft::{ Fft, FftPlanner };
+
+use crate::windowing::Windowing;
+
+/// Smallest and largest FFT sizes the analyzer accepts
+pub const MIN_FFT_SIZE: usize = 64;
+pub const MAX_FFT_SIZE: usize = 32768;
+
+/// Floor of the reported magnitudes, in dBFS
+pub const SPECTRUM_FLOOR_DB: f32 = -144.0;
+
+pub struct SpectrumAnalyzer {
+    size: usize,
+    fft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    /// Amplitude that maps a full-scale sine to 0 dB: 2 / sum(window)
+    scale: f32,
+    buffer: Vec<Complex<f32>>,
+    scratch: Vec<Complex<f32>>,
+    magnitudes: Vec<f32>,
+}
+
+impl SpectrumAnalyzer {
+    /// An analyzer of `size` samples, rounded to a power of two within the supported range.
+    pub fn new(size: usize, window: Windowing) -> Self {
+        let size = size.clamp(MIN_FFT_SIZE, MAX_FFT_SIZE).next_power_of_two();
+        let fft = FftPlanner::new().plan_fft_forward(size);
+        let window = window.coefficients(size);
+        let window_sum: f32 = window.iter().sum();
+        let scratch = vec![Complex::default(); fft.get_inplace_scratch_len()];
+        Self {
+            size,
+            fft,
+            scale: 2.0 / window_sum.max(f32::EPSILON),
+            window,
+            buffer: vec![Complex::default(); size],
+            scratch,
+            magnitudes: vec![SPECTRUM_FLOOR_DB; size / 2 + 1],
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Number of bins in a spectrum, DC to Nyquist.
+    pub fn bins(&self) -> usize {
+        self.size / 2 + 1
+    }
+
+    /// Centre frequency of a bin.
+    pub fn bin_frequency(&self, bin: usize, sample_rate: u32) -> f32 {
+        ((bin as f32) * (sample_rate as f32)) / (self.size as f32)
+    }
+
+    /// Magnitude spectrum in dBFS of the last `size` samples. Shorter input is zero padded.
+    pub fn analyze(&mut self, samples: &[f32]) -> &[f32] {
+        let start = samples.len().saturating_sub(self.size);
+        let samples = &samples[start..];
+        for (i, slot) in self.buffer.iter_mut().enumerate() {
+            let sample = samples.get(i).copied().unwrap_or(0.0);
+            *slot = Complex::new(sample * self.window[i], 0.0);
+        }
+
+        self.fft.process_with_scratch(&mut self.buffer, &mut self.scratch);
+
+        for (magnitude, value) in self.magnitudes.iter_mut().zip(self.buffer.iter()) {
+            let amplitude = value.norm() * self.scale;
+            *magnitude = if amplitude > 0.0 {
+                (20.0 * amplitude.log10()).max(SPECTRUM_FLOOR_DB)
+            } else {
+                SPECTRUM_FLOOR_DB
+            };
+        }
+        // DC and Nyquist have no mirrored half to fold in
+        let last = self.magnitudes.len() - 1;
+        for bin in [0, last] {
+            self.magnitudes[bin] = (self.magnitudes[bin] - 6.0206).max(SPECTRUM_FLOOR_DB);
+        }
+        &self.magnitudes
+    }
+}
+
+/// Resamples a spectrum onto `num_points` log-spaced frequencies from `min_freq` to `max_freq`,
+/// as `(frequency, dB)` pairs. Each point takes the loudest bin it covers, or interpolates between
+/// the neighbouring bins where they are wider than the point spacing.
+pub fn log_spaced(
+    magnitudes: &[f32],
+    sample_rate: u32,
+    min_freq: f32,
+    max_freq: f32,
+    num_points: usize
+) -> Vec<(f32, f32)> {
+    if magnitudes.len() < 2 || num_points == 0 {
+        return Vec::new();
+    }
+    let fft_size = ((magnitudes.len() - 1) * 2) as f32;
+    let bin_width = (sample_rate as f32) / fft_size;
+    let last_bin = magnitudes.len() - 1;
+    let log_min = min_freq.max(1.0).log10();
+    let log_max = max_freq.max(min_freq.max(1.0)).log10();
+    let step = (log_max - log_min) / ((num_points - 1).max(1) as f32);
+
+    let bin_at = |freq: f32| (freq / bin_width).clamp(0.0, last_bin as f32);
+
+    (0..num_points)
+        .map(|i| {
+            let freq = (10.0f32).powf(log_min + (i as f32) * step);
+            // Range of bins this point stands for: halfway to its neighbours
+            let low = bin_at((10.0f32).powf(log_min + ((i as f32) - 0.5) * step));
+            let high = bin_at((10.0f32).powf(log_min + ((i as f32) + 0.5) * step));
+
+            let (first, last) = (low.ceil() as usize, high.floor() as usize);
+            let db = if first <= last {
+                magnitudes[first..=last].iter().copied().fold(SPECTRUM_FLOOR_DB, f32::max)
+            } else {
+                let position = bin_at(freq);
+                let below = position.floor() as usize;
+                let above = (below + 1).min(last_bin);
+                let t = position - (below as f32);
+                magnitudes[below] + (magnitudes[above] - magnitudes[below]) * t
+            };
+            (freq, db)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn full_scale_sine_reads_zero_db_at_its_bin() {
+        let sample_rate = 48_000;
+        let mut analyzer = SpectrumAnalyzer::new(1024, Windowing::BlackmanHarris);
+        // Exactly on bin 64
+        let frequency = analyzer.bin_frequency(64, sample_rate);
+        let samples: Vec<f32> = (0..1024)
+            .map(|i| {
+                let t = (i as f32) / (sample_rate as f32);
+                (2.0 * std::f32::consts::PI * frequency * t).sin()
+            })
+            .collect();
+
+        let spectrum = analyzer.analyze(&samples).to_vec();
+        assert_eq!(spectrum.len(), 513);
+        assert!(spectrum[64].abs() < 0.1, "{}", spectrum[64]);
+        // Far from the tone the window keeps leakage below -90 dB
+        assert!(spectrum[200] < -90.0, "{}", spectrum[200]);
+
+        let curve = log_spaced(&spectrum, sample_rate, 20.0, 20_000.0, 200);
+        assert_eq!(curve.len(), 200);
+        let (_, loudest) = curve
+            .iter()
+            .copied()
+            .fold((0.0, SPECTRUM_FLOOR_DB), |a, b| if b.1 > a.1 { b } else { a });
+        assert!(loudest > -1.0);
+    }
+}
@@ -1,13 +1,22 @@
 use std::f32::consts::PI;
 use wide::f32x4;
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Windowing {
+    #[default]
     Hann,
+    Hamming,
+    /// 4-term Blackman-Harris: very low side lobes (-92 dB) for analyzers
+    BlackmanHarris,
+    Rectangular,
 }
 
 /// A trait applied to slice types so they can process chunks of audio natively.
 pub trait WindowableSlice {
     fn apply_hann(&mut self);
+
+    /// Multiplies every frame by the window's coefficient at its position (scalar path)
+    fn apply_window(&mut self, window: Windowing);
 }
 
 // ============================================================================
@@ -51,6 +60,13 @@ impl WindowableSlice for [f32] {
             i += 1;
         }
     }
+
+    fn apply_window(&mut self, window: Windowing) {
+        let num_samples = self.len();
+        for (i, sample) in self.iter_mut().enumerate() {
+            *sample *= window.coefficient(i, num_samples);
+        }
+    }
 }
 
 // ============================================================================
@@ -104,6 +120,15 @@ impl WindowableSlice for [[f32; 2]] {
             i += 1;
         }
     }
+
+    fn apply_window(&mut self, window: Windowing) {
+        let num_samples = self.len();
+        for (i, frame) in self.iter_mut().enumerate() {
+            let mult = window.coefficient(i, num_samples);
+            frame[0] *= mult;
+            frame[1] *= mult;
+        }
+    }
 }
 
 impl Windowing {
@@ -115,6 +140,28 @@ impl Windowing {
     {
         match self {
             Windowing::Hann => buffer.apply_hann(),
+            other => buffer.apply_window(*other),
+        }
+    }
+
+    /// Coefficient at position `i` of a symmetric window `num_samples` long.
+    pub fn coefficient(&self, i: usize, num_samples: usize) -> f32 {
+        if num_samples <= 1 {
+            return 1.0;
         }
+        let phase = (2.0 * PI * (i as f32)) / ((num_samples - 1) as f32);
+        match self {
+            Windowing::Hann => 0.5 * (1.0 - phase.cos()),
+            Windowing::Hamming => 0.54 - 0.46 * phase.cos(),
+            Windowing::BlackmanHarris =>
+                0.35875 - 0.48829 * phase.cos() + 0.14128 * (2.0 * phase).cos() -
+                    0.01168 * (3.0 * phase).cos(),
+            Windowing::Rectangular => 1.0,
+        }
+    }
+
+    /// The whole window as a table, for applying it to many blocks of the same size.
+    pub fn coefficients(&self, num_samples: usize) -> Vec<f32> {
+        (0..num_samples).map(|i| self.coefficient(i, num_samples)).collect()
     }
 }
\ No newline at end of file
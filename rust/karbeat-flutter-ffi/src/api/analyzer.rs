@@ -0,0 +1,42 @@
+use karbeat_core::{
+    api::analyzer_api,
+    audio::analyzer::AnalyzerConfig,
+    core::project::mixer::RoutingNode,
+};
+
+use crate::{
+    api::{ mixer::UiRoutingNode, plugins::eq::UiResponseCurvePoint },
+    frb_generated::StreamSink,
+};
+
+/// Start a live spectrum of a mixer strip. Every spectrum arrives as `num_points` log-spaced
+/// points over the same frequencies as `get_eq_response_curve`, so the EQ can draw one over the
+/// other. `overlap` is the share of each `fft_size` block the next one reuses (0.0–0.95).
+/// Returns the analyzer id for `stop_spectrum_stream`.
+pub fn create_spectrum_stream(
+    node: UiRoutingNode,
+    fft_size: u32,
+    overlap: f32,
+    num_points: u32,
+    sink: StreamSink<Vec<UiResponseCurvePoint>>
+) -> Result<u32, String> {
+    let config = AnalyzerConfig {
+        fft_size: fft_size as usize,
+        overlap,
+        ..AnalyzerConfig::default()
+    };
+    analyzer_api
+        ::start_spectrum_analyzer(RoutingNode::from(&node), config, move |frame| {
+            let points = frame
+                .log_spaced(num_points as usize)
+                .into_iter()
+                .map(|(frequency, magnitude_db)| UiResponseCurvePoint { frequency, magnitude_db })
+                .collect();
+            sink.add(points).is_ok()
+        })
+        .map_err(|e| e.to_string())
+}
+
+pub fn stop_spectrum_stream(analyzer_id: u32) -> Result<(), String> {
+    analyzer_api::stop_spectrum_analyzer(analyzer_id).map_err(|e| e.to_string())
+}
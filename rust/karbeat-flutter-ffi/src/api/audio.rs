@@ -1,8 +1,14 @@
 use std::time::Duration;
 
 use karbeat_core::api::audio_api;
+use karbeat_core::audio::backend::AudioDeviceInfo;
+use karbeat_core::audio::load::{ EngineLoadReport, PluginLoad, PluginSlot };
+use karbeat_core::audio::writer::BitPerSample;
+use karbeat_dsp::loudness::LoudnessReport;
 use karbeat_core:: audio::event::TransportFeedback ;
+use karbeat_core::core::file_manager::user_settings::AudioDeviceSettings;
 use karbeat_core::core::project::{AudioSourceId, GeneratorId, TrackId};
+use crate::api::mixer::UiRoutingNode;
 use crate::api::project::{ AudioWaveformUiForAudioProperties, UiAudioHardwareConfig };
 use crate::frb_generated::StreamSink;
 
@@ -26,7 +32,8 @@ pub struct UiTransportFeedback {
 impl From<TransportFeedback> for UiTransportFeedback {
     fn from(f: TransportFeedback) -> Self {
         Self {
-            samples: f.samples,
+            // The playhead shows what is heard, behind the plugins' latency
+            samples: f.samples.saturating_sub(f.latency_samples),
             beat: f.beat,
             bar: f.bar,
             tempo: f.tempo,
@@ -43,6 +50,172 @@ impl From<TransportFeedback> for UiTransportFeedback {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct UiAudioDeviceInfo {
+    pub name: String,
+    pub is_default: bool,
+    pub sample_rates: Vec<u32>,
+    pub channel_counts: Vec<u16>,
+    pub buffer_sizes: Vec<u32>,
+}
+
+impl From<AudioDeviceInfo> for UiAudioDeviceInfo {
+    fn from(info: AudioDeviceInfo) -> Self {
+        Self {
+            name: info.name,
+            is_default: info.is_default,
+            sample_rates: info.sample_rates,
+            channel_counts: info.channel_counts,
+            buffer_sizes: info.buffer_sizes,
+        }
+    }
+}
+
+/// Device choice, `None` fields leave it to the host
+#[derive(Clone, Debug, Default)]
+pub struct UiAudioDeviceSettings {
+    pub host: Option<String>,
+    pub output_device: Option<String>,
+    pub input_device: Option<String>,
+    pub sample_rate: Option<u32>,
+    pub buffer_size: Option<u32>,
+}
+
+impl From<AudioDeviceSettings> for UiAudioDeviceSettings {
+    fn from(settings: AudioDeviceSettings) -> Self {
+        Self {
+            host: settings.host,
+            output_device: settings.output_device,
+            input_device: settings.input_device,
+            sample_rate: settings.sample_rate,
+            buffer_size: settings.buffer_size,
+        }
+    }
+}
+
+impl From<UiAudioDeviceSettings> for AudioDeviceSettings {
+    fn from(settings: UiAudioDeviceSettings) -> Self {
+        Self {
+            host: settings.host,
+            output_device: settings.output_device,
+            input_device: settings.input_device,
+            sample_rate: settings.sample_rate,
+            buffer_size: settings.buffer_size,
+        }
+    }
+}
+
+/// Loudness of the master output: LUFS, LU for the range, dBTP for the peak.
+/// Negative infinity until there is enough signal to measure.
+#[derive(Clone, Debug)]
+pub struct UiLoudnessReport {
+    pub momentary: f32,
+    pub short_term: f32,
+    pub integrated: f32,
+    pub loudness_range: f32,
+    pub true_peak: f32,
+    pub max_momentary: f32,
+    pub max_short_term: f32,
+}
+
+impl From<&LoudnessReport> for UiLoudnessReport {
+    fn from(report: &LoudnessReport) -> Self {
+        Self {
+            momentary: report.momentary,
+            short_term: report.short_term,
+            integrated: report.integrated,
+            loudness_range: report.loudness_range,
+            true_peak: report.true_peak,
+            max_momentary: report.max_momentary,
+            max_short_term: report.max_short_term,
+        }
+    }
+}
+
+/// CPU load of the audio thread as shares of the real-time budget, 1.0 being all of it.
+pub struct UiEngineLoad {
+    pub load: f32,
+    pub average: f32,
+    pub peak: f32,
+    pub max_peak: f32,
+    pub overloads: u64,
+    pub xruns: u64,
+    /// Heaviest first, empty unless plugin profiling is on
+    pub plugins: Vec<UiPluginLoad>,
+}
+
+impl From<&EngineLoadReport> for UiEngineLoad {
+    fn from(report: &EngineLoadReport) -> Self {
+        Self {
+            load: report.load,
+            average: report.average,
+            peak: report.peak,
+            max_peak: report.max_peak,
+            overloads: report.overloads,
+            xruns: report.xruns,
+            plugins: report.plugins.iter().map(UiPluginLoad::from).collect(),
+        }
+    }
+}
+
+pub enum UiPluginSlot {
+    Generator(u32),
+    Effect {
+        node: UiRoutingNode,
+        effect_id: u32,
+    },
+}
+
+pub struct UiPluginLoad {
+    pub slot: UiPluginSlot,
+    pub average: f32,
+    pub peak: f32,
+}
+
+impl From<&PluginLoad> for UiPluginLoad {
+    fn from(load: &PluginLoad) -> Self {
+        let slot = match &load.slot {
+            PluginSlot::Generator(id) => UiPluginSlot::Generator(id.to_u32()),
+            PluginSlot::Effect { node, effect_id } =>
+                UiPluginSlot::Effect {
+                    node: node.into(),
+                    effect_id: effect_id.to_u32(),
+                },
+        };
+        Self { slot, average: load.average, peak: load.peak }
+    }
+}
+
+pub fn list_audio_hosts() -> Vec<String> {
+    audio_api::list_audio_hosts()
+}
+
+pub fn list_audio_output_devices(host: Option<String>) -> Result<Vec<UiAudioDeviceInfo>, String> {
+    audio_api::list_audio_output_devices(host.as_deref())
+        .map(|devices| devices.into_iter().map(UiAudioDeviceInfo::from).collect())
+        .map_err(|e| e.to_string())
+}
+
+pub fn list_audio_input_devices(host: Option<String>) -> Result<Vec<UiAudioDeviceInfo>, String> {
+    audio_api::list_audio_input_devices(host.as_deref())
+        .map(|devices| devices.into_iter().map(UiAudioDeviceInfo::from).collect())
+        .map_err(|e| e.to_string())
+}
+
+pub fn get_audio_device_settings() -> UiAudioDeviceSettings {
+    UiAudioDeviceSettings::from(audio_api::get_audio_device_settings())
+}
+
+/// Saves the device settings and restarts the running stream on them
+pub fn set_audio_device_settings(settings: UiAudioDeviceSettings) -> Result<(), String> {
+    audio_api::set_audio_device_settings(settings.into()).map_err(|e| e.to_string())
+}
+
+/// Where to keep the user settings file, for platforms without a config directory
+pub fn set_user_settings_path(path: String) -> Result<(), String> {
+    audio_api::set_user_settings_path(path.into()).map_err(|e| e.to_string())
+}
+
 /// GETTER: Fetch details + Downsampled Buffer for UI
 pub fn get_audio_properties(id: u32) -> Option<AudioWaveformUiForAudioProperties> {
     audio_api::get_audio_source(AudioSourceId::from(id), |waveform| {
@@ -72,6 +245,68 @@ pub fn get_audio_config() -> Result<UiAudioHardwareConfig, String> {
     }))
 }
 
+/// Latest loudness analysis of the master output, none while no audio stream is running.
+pub fn get_loudness_report() -> Option<UiLoudnessReport> {
+    audio_api::get_loudness_report(|report| UiLoudnessReport::from(report))
+}
+
+pub fn reset_loudness_analyzer() {
+    audio_api::reset_loudness_analyzer();
+}
+
+pub enum UiBitPerSample {
+    B8,
+    B16,
+    B24,
+    B32,
+}
+
+impl From<UiBitPerSample> for BitPerSample {
+    fn from(value: UiBitPerSample) -> Self {
+        match value {
+            UiBitPerSample::B8 => BitPerSample::B8,
+            UiBitPerSample::B16 => BitPerSample::B16,
+            UiBitPerSample::B24 => BitPerSample::B24,
+            UiBitPerSample::B32 => BitPerSample::B32,
+        }
+    }
+}
+
+/// Render the song into an audio file (format from the extension) and return its loudness.
+pub fn export_project(
+    path: String,
+    sample_rate: u32,
+    bit_per_sample: UiBitPerSample
+) -> Result<UiLoudnessReport, String> {
+    audio_api
+        ::export_project(std::path::Path::new(&path), sample_rate, bit_per_sample.into())
+        .map(|report| UiLoudnessReport::from(&report))
+        .map_err(|e| e.to_string())
+}
+
+/// Latest CPU load of the audio thread, none while no audio stream is running.
+pub fn get_engine_load() -> Option<UiEngineLoad> {
+    audio_api::get_engine_load(|report| UiEngineLoad::from(report))
+}
+
+pub fn reset_engine_load() {
+    audio_api::reset_engine_load();
+}
+
+/// Frames the mix lags the playhead by, behind plugins with lookahead.
+pub fn get_mix_latency() -> u32 {
+    audio_api::get_mix_latency()
+}
+
+/// Times every plugin so [`get_engine_load`] can break the load down by plugin.
+pub fn set_plugin_profiling(enabled: bool) {
+    audio_api::set_plugin_profiling(enabled);
+}
+
+pub fn is_plugin_profiling() -> bool {
+    audio_api::is_plugin_profiling()
+}
+
 pub fn create_position_stream(sink: StreamSink<UiTransportFeedback>) -> Result<(), String> {
     // Spawn a thread to poll the ring buffer
    std::thread::spawn(move || {
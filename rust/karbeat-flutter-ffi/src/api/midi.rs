@@ -0,0 +1,88 @@
+//! api/midi.rs
+//! Hardware MIDI ports, input routing and clock/note output.
+
+use karbeat_core::api::midi_api;
+use karbeat_core::audio::midi::MidiOutputSettings;
+use karbeat_core::shared::id::TrackId;
+
+/// What the engine sends to the MIDI output port
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UiMidiOutputSettings {
+    /// Send clock pulses and start/stop while the song plays
+    pub send_clock: bool,
+    /// Track whose notes are sent out
+    pub track_id: Option<u32>,
+    /// Channel of outgoing notes (0 - 15)
+    pub channel: u8,
+}
+
+impl From<MidiOutputSettings> for UiMidiOutputSettings {
+    fn from(settings: MidiOutputSettings) -> Self {
+        Self {
+            send_clock: settings.send_clock,
+            track_id: settings.track.map(|id| id.to_u32()),
+            channel: settings.channel,
+        }
+    }
+}
+
+impl From<UiMidiOutputSettings> for MidiOutputSettings {
+    fn from(settings: UiMidiOutputSettings) -> Self {
+        Self {
+            send_clock: settings.send_clock,
+            track: settings.track_id.map(TrackId::from),
+            channel: settings.channel,
+        }
+    }
+}
+
+pub fn get_midi_backend_name() -> String {
+    midi_api::get_midi_backend_name()
+}
+
+pub fn list_midi_inputs() -> Result<Vec<String>, String> {
+    midi_api::list_midi_inputs().map_err(|e| e.to_string())
+}
+
+pub fn list_midi_outputs() -> Result<Vec<String>, String> {
+    midi_api::list_midi_outputs().map_err(|e| e.to_string())
+}
+
+/// open a MIDI input port by name, replacing the open one
+pub fn open_midi_input(port: String) -> Result<(), String> {
+    midi_api::open_midi_input(&port).map_err(|e| e.to_string())
+}
+
+pub fn close_midi_input() {
+    midi_api::close_midi_input();
+}
+
+pub fn get_open_midi_input() -> Option<String> {
+    midi_api::get_open_midi_input()
+}
+
+/// track played by the MIDI input while no MIDI track is armed (usually the selected track)
+pub fn set_midi_input_track(track_id: Option<u32>) {
+    midi_api::set_midi_input_track(track_id.map(TrackId::from));
+}
+
+/// open a MIDI output port by name, replacing the open one
+pub fn open_midi_output(port: String) -> Result<(), String> {
+    midi_api::open_midi_output(&port).map_err(|e| e.to_string())
+}
+
+pub fn close_midi_output() {
+    midi_api::close_midi_output();
+}
+
+pub fn get_open_midi_output() -> Option<String> {
+    midi_api::get_open_midi_output()
+}
+
+pub fn get_midi_output_settings() -> UiMidiOutputSettings {
+    midi_api::get_midi_output_settings().into()
+}
+
+pub fn set_midi_output_settings(settings: UiMidiOutputSettings) -> Result<(), String> {
+    midi_api::set_midi_output_settings(settings.into()).map_err(|e| e.to_string())
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{ collections::HashMap, time::Duration };
 
 use flutter_rust_bridge::frb;
 pub use karbeat_core::{
@@ -9,6 +9,7 @@ use karbeat_core::shared::id::*;
 
 use crate::frb_generated::StreamSink;
 use karbeat_core::{
+    audio::metering::{ MeterFeedback, MeterTarget },
     context::{ ctx, MixerParamEvent },
     core::project::mixer::{
         EffectInstance,
@@ -148,6 +149,39 @@ impl From<&UiRoutingNode> for RoutingNode {
     }
 }
 
+/// Level reading of one mixer strip, linear amplitude.
+pub struct UiMeterLevels {
+    pub node: UiRoutingNode,
+    pub peak_left: f32,
+    pub peak_right: f32,
+    pub rms_left: f32,
+    pub rms_right: f32,
+    pub peak_hold_left: f32,
+    pub peak_hold_right: f32,
+    pub clipped: bool,
+}
+
+impl From<MeterFeedback> for UiMeterLevels {
+    fn from(value: MeterFeedback) -> Self {
+        let node = match value.target {
+            MeterTarget::Track(id) => UiRoutingNode::Track(id.to_u32()),
+            MeterTarget::Bus(id) => UiRoutingNode::Bus(id.to_u32()),
+            MeterTarget::Master => UiRoutingNode::Master,
+        };
+        let levels = value.levels;
+        Self {
+            node,
+            peak_left: levels.peak[0],
+            peak_right: levels.peak[1],
+            rms_left: levels.rms[0],
+            rms_right: levels.rms[1],
+            peak_hold_left: levels.peak_hold[0],
+            peak_hold_right: levels.peak_hold[1],
+            clipped: levels.clipped,
+        }
+    }
+}
+
 /// UI representation of the mixer state.
 pub struct UiMixerState {
     pub channels: HashMap<u32, UiMixerChannel>,
@@ -314,6 +348,25 @@ pub fn create_mixer_event_stream(sink: StreamSink<UiMixerParamEvent>) -> Result<
     Ok(())
 }
 
+/// Create the Rust → Flutter stream of level meter readings. Each item holds the readings
+/// that arrived since the previous one, a strip missing from it keeps its last reading.
+pub fn create_meter_stream(sink: StreamSink<Vec<UiMeterLevels>>) -> Result<(), String> {
+    std::thread::spawn(move || {
+        loop {
+            let readings = mixer_api::drain_meter_feedback(UiMeterLevels::from);
+            if !readings.is_empty() && sink.add(readings).is_err() {
+                log::info!("Meter stream disconnected");
+                return;
+            }
+
+            // 16ms ~= 60fps
+            std::thread::sleep(Duration::from_millis(16));
+        }
+    });
+    log::info!("Meter stream connected");
+    Ok(())
+}
+
 /// Helper: push an event to the mixer sink (if connected).
 fn push_mixer_event(event: MixerParamEvent) {
     if let Some(sink) = ctx().mixer_event_sink.lock().as_ref() {
@@ -1,11 +1,14 @@
+pub mod analyzer;
 pub mod automation;
 pub mod plugins;
 pub mod audio;
+pub mod midi;
 pub mod mixer;
 pub mod network;
 pub mod pattern;
 pub mod plugin;
 pub mod project;
+pub mod recording;
 pub mod session;
 pub mod simple;
 pub mod track;
@@ -1,8 +1,18 @@
 use std::collections::HashMap;
 
 use karbeat_core::{
-    api::{ note_api as note_api, pattern_api as pattern_api },
-    core::project::{ GeneratorId, Note, NoteId, track::midi::{ Pattern } },
+    api::{ groove_api as groove_api, note_api as note_api, pattern_api as pattern_api },
+    core::project::{
+        GeneratorId,
+        Note,
+        NoteId,
+        groove::{ Groove, GrooveStep, SwingDivision },
+        musical_time::GridDivision,
+        quantize::QuantizeSettings,
+        theory::{ Chord, ChordType, Scale, ScaleMode },
+        transform::{ NoteTransform, RangeMode },
+        track::midi::{ Pattern },
+    },
 };
 use karbeat_core::shared::id::*;
 
@@ -151,6 +161,141 @@ pub fn change_note_params(
     Ok(note_ui)
 }
 
+/// UI-compatible quantize options
+pub struct UiQuantizeSettings {
+    /// Note value of the grid (4 = quarter, 16 = sixteenth)
+    pub note_value: u32,
+    pub triplet: bool,
+    /// How far notes move towards the grid (0.0 - 1.0)
+    pub strength: f32,
+    /// Fraction of half a grid step a note may be off and still move (1.0 moves all)
+    pub window: f32,
+    pub quantize_ends: bool,
+    /// 50 (straight) - 75
+    pub swing: f32,
+}
+
+impl From<UiQuantizeSettings> for QuantizeSettings {
+    fn from(value: UiQuantizeSettings) -> Self {
+        Self {
+            grid: GridDivision::note(value.note_value, value.triplet),
+            strength: value.strength,
+            window: value.window,
+            quantize_ends: value.quantize_ends,
+            swing: value.swing,
+        }
+    }
+}
+
+/// quantize the selected notes, or the whole pattern when `note_ids` is empty (undoable)
+pub fn quantize_notes(
+    pattern_id: u32,
+    note_ids: Vec<u32>,
+    settings: UiQuantizeSettings
+) -> Result<Vec<UiNote>, String> {
+    let notes = note_api
+        ::quantize_notes(
+            PatternId::from(pattern_id),
+            note_ids.into_iter().map(NoteId::from).collect(),
+            settings.into()
+        )
+        .map_err(|e| e.to_string())?;
+    Ok(notes.iter().map(UiNote::from).collect())
+}
+
+/// UI-compatible bulk note transform
+pub enum UiNoteTransform {
+    /// `fold_octaves` moves notes leaving 0-127 back by octaves, otherwise they are clamped
+    Transpose {
+        semitones: i16,
+        fold_octaves: bool,
+    },
+    /// `root` is a pitch class (0 = C)
+    ScaleTranspose {
+        degrees: i32,
+        root: u8,
+        mode: UiScaleMode,
+    },
+    VelocityScale {
+        factor: f32,
+    },
+    VelocityCompress {
+        amount: f32,
+    },
+    VelocityRandomize {
+        amount: u8,
+    },
+    Legato,
+    Reverse,
+    Invert {
+        pivot: u8,
+    },
+    TimeStretch {
+        factor: f32,
+    },
+    Humanize {
+        timing: u64,
+        velocity: u8,
+    },
+    SplitOverlaps,
+    MergeOverlaps,
+    /// `root` is a pitch class (0 = C)
+    SnapToScale {
+        root: u8,
+        mode: UiScaleMode,
+    },
+}
+
+impl From<UiNoteTransform> for NoteTransform {
+    fn from(value: UiNoteTransform) -> Self {
+        match value {
+            UiNoteTransform::Transpose { semitones, fold_octaves } => {
+                let mode = if fold_octaves { RangeMode::FoldOctaves } else { RangeMode::Clamp };
+                NoteTransform::Transpose { semitones, mode }
+            }
+            UiNoteTransform::ScaleTranspose { degrees, root, mode } => {
+                NoteTransform::ScaleTranspose { degrees, scale: Scale::new(root, mode.into()) }
+            }
+            UiNoteTransform::VelocityScale { factor } => NoteTransform::VelocityScale { factor },
+            UiNoteTransform::VelocityCompress { amount } => {
+                NoteTransform::VelocityCompress { amount }
+            }
+            UiNoteTransform::VelocityRandomize { amount } => {
+                NoteTransform::VelocityRandomize { amount, seed: fastrand::u64(..) }
+            }
+            UiNoteTransform::Legato => NoteTransform::Legato,
+            UiNoteTransform::Reverse => NoteTransform::Reverse,
+            UiNoteTransform::Invert { pivot } => NoteTransform::Invert { pivot },
+            UiNoteTransform::TimeStretch { factor } => NoteTransform::TimeStretch { factor },
+            UiNoteTransform::Humanize { timing, velocity } => {
+                NoteTransform::Humanize { timing, velocity, seed: fastrand::u64(..) }
+            }
+            UiNoteTransform::SplitOverlaps => NoteTransform::SplitOverlaps,
+            UiNoteTransform::MergeOverlaps => NoteTransform::MergeOverlaps,
+            UiNoteTransform::SnapToScale { root, mode } => {
+                NoteTransform::SnapToScale { scale: Scale::new(root, mode.into()) }
+            }
+        }
+    }
+}
+
+/// apply a bulk transform to the selected notes, or the whole pattern when `note_ids` is
+/// empty (undoable)
+pub fn transform_notes(
+    pattern_id: u32,
+    note_ids: Vec<u32>,
+    transform: UiNoteTransform
+) -> Result<Vec<UiNote>, String> {
+    let notes = note_api
+        ::transform_notes(
+            PatternId::from(pattern_id),
+            note_ids.into_iter().map(NoteId::from).collect(),
+            transform.into()
+        )
+        .map_err(|e| e.to_string())?;
+    Ok(notes.iter().map(UiNote::from).collect())
+}
+
 // ========================= PATTERN PREVIEW TRANSPORT ============================
 
 /// Play a pattern in isolation with a specific generator (looping automatically).
@@ -165,3 +310,371 @@ pub fn play_pattern_preview(pattern_id: u32, generator_id: u32) -> Result<(), St
 pub fn stop_pattern_preview() -> Result<(), String> {
     pattern_api::stop_pattern_preview().map_err(|e| e.to_string())
 }
+
+// ========================= GROOVE ============================
+
+pub enum UiSwingDivision {
+    Eighth,
+    Sixteenth,
+}
+
+impl From<UiSwingDivision> for SwingDivision {
+    fn from(value: UiSwingDivision) -> Self {
+        match value {
+            UiSwingDivision::Eighth => SwingDivision::Eighth,
+            UiSwingDivision::Sixteenth => SwingDivision::Sixteenth,
+        }
+    }
+}
+
+/// UI-compatible representation of a groove template
+#[derive(Clone)]
+pub struct UiGroove {
+    pub name: String,
+    /// Grid the steps sit on, in ticks
+    pub step_ticks: u64,
+    /// Ticks each step is moved by, negative is early
+    pub timings: Vec<i32>,
+    /// Velocity multiplier of each step
+    pub velocities: Vec<f32>,
+    /// How much of the groove is applied (0.0 - 1.0)
+    pub amount: f32,
+}
+
+impl From<&Groove> for UiGroove {
+    fn from(value: &Groove) -> Self {
+        Self {
+            name: value.name.clone(),
+            step_ticks: value.step_ticks,
+            timings: value.steps.iter().map(|step| step.timing).collect(),
+            velocities: value.steps.iter().map(|step| step.velocity).collect(),
+            amount: value.amount,
+        }
+    }
+}
+
+impl TryFrom<UiGroove> for Groove {
+    type Error = String;
+
+    fn try_from(value: UiGroove) -> Result<Self, Self::Error> {
+        if value.step_ticks == 0 {
+            return Err("Groove steps must be at least one tick long".to_string());
+        }
+        if value.timings.len() != value.velocities.len() {
+            return Err(
+                format!(
+                    "Groove has {} timings but {} velocities",
+                    value.timings.len(),
+                    value.velocities.len()
+                )
+            );
+        }
+        let steps = value.timings
+            .iter()
+            .zip(value.velocities.iter())
+            .map(|(&timing, &velocity)| GrooveStep { timing, velocity })
+            .collect();
+        Ok(Groove {
+            name: value.name,
+            step_ticks: value.step_ticks,
+            steps,
+            amount: value.amount.clamp(0.0, 1.0),
+        })
+    }
+}
+
+/// a swing groove, `percent` goes from 50 (straight) to 75
+pub fn swing_groove(division: UiSwingDivision, percent: f32) -> UiGroove {
+    UiGroove::from(&Groove::swing(division.into(), percent))
+}
+
+/// get the groove a pattern plays with, if any
+pub fn get_pattern_groove(pattern_id: u32) -> Result<Option<UiGroove>, String> {
+    let pattern = pattern_api::get_pattern(&PatternId::from(pattern_id)).map_err(|e| e.to_string())?;
+    Ok(pattern.groove.as_ref().map(UiGroove::from))
+}
+
+/// set or clear the groove a pattern plays with
+pub fn set_pattern_groove(pattern_id: u32, groove: Option<UiGroove>) -> Result<(), String> {
+    let groove = groove.map(Groove::try_from).transpose()?;
+    groove_api::set_pattern_groove(PatternId::from(pattern_id), groove).map_err(|e| e.to_string())
+}
+
+/// set or clear the groove of one MIDI clip, played instead of its pattern's
+pub fn set_clip_groove(track_id: u32, clip_id: u32, groove: Option<UiGroove>) -> Result<(), String> {
+    let groove = groove.map(Groove::try_from).transpose()?;
+    groove_api
+        ::set_clip_groove(TrackId::from(track_id), ClipId::from(clip_id), groove)
+        .map_err(|e| e.to_string())
+}
+
+/// take a groove template from the notes of a pattern
+pub fn groove_from_pattern(pattern_id: u32, step_ticks: u64) -> Result<UiGroove, String> {
+    groove_api
+        ::groove_from_pattern(PatternId::from(pattern_id), step_ticks)
+        .map(|groove| UiGroove::from(&groove))
+        .map_err(|e| e.to_string())
+}
+
+/// take a groove template from the notes of a MIDI file
+pub fn groove_from_midi_file(path: String, step_ticks: u64) -> Result<UiGroove, String> {
+    groove_api
+        ::groove_from_midi_file(std::path::Path::new(&path), step_ticks)
+        .map(|groove| UiGroove::from(&groove))
+        .map_err(|e| e.to_string())
+}
+
+/// write a pattern's groove into its notes (undoable)
+pub fn commit_pattern_groove(pattern_id: u32) -> Result<(), String> {
+    groove_api::commit_pattern_groove(PatternId::from(pattern_id)).map_err(|e| e.to_string())
+}
+
+/// write a clip's groove into the notes of its pattern (undoable)
+pub fn commit_clip_groove(track_id: u32, clip_id: u32) -> Result<(), String> {
+    groove_api
+        ::commit_clip_groove(TrackId::from(track_id), ClipId::from(clip_id))
+        .map_err(|e| e.to_string())
+}
+
+// ========================= SCALES & CHORDS ============================
+
+#[derive(Clone, Copy)]
+pub enum UiScaleMode {
+    Major,
+    NaturalMinor,
+    HarmonicMinor,
+    MelodicMinor,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Locrian,
+    MajorPentatonic,
+    MinorPentatonic,
+    Blues,
+}
+
+impl From<UiScaleMode> for ScaleMode {
+    fn from(value: UiScaleMode) -> Self {
+        match value {
+            UiScaleMode::Major => ScaleMode::Major,
+            UiScaleMode::NaturalMinor => ScaleMode::NaturalMinor,
+            UiScaleMode::HarmonicMinor => ScaleMode::HarmonicMinor,
+            UiScaleMode::MelodicMinor => ScaleMode::MelodicMinor,
+            UiScaleMode::Dorian => ScaleMode::Dorian,
+            UiScaleMode::Phrygian => ScaleMode::Phrygian,
+            UiScaleMode::Lydian => ScaleMode::Lydian,
+            UiScaleMode::Mixolydian => ScaleMode::Mixolydian,
+            UiScaleMode::Locrian => ScaleMode::Locrian,
+            UiScaleMode::MajorPentatonic => ScaleMode::MajorPentatonic,
+            UiScaleMode::MinorPentatonic => ScaleMode::MinorPentatonic,
+            UiScaleMode::Blues => ScaleMode::Blues,
+        }
+    }
+}
+
+impl From<ScaleMode> for UiScaleMode {
+    fn from(value: ScaleMode) -> Self {
+        match value {
+            ScaleMode::Major => UiScaleMode::Major,
+            ScaleMode::NaturalMinor => UiScaleMode::NaturalMinor,
+            ScaleMode::HarmonicMinor => UiScaleMode::HarmonicMinor,
+            ScaleMode::MelodicMinor => UiScaleMode::MelodicMinor,
+            ScaleMode::Dorian => UiScaleMode::Dorian,
+            ScaleMode::Phrygian => UiScaleMode::Phrygian,
+            ScaleMode::Lydian => UiScaleMode::Lydian,
+            ScaleMode::Mixolydian => UiScaleMode::Mixolydian,
+            ScaleMode::Locrian => UiScaleMode::Locrian,
+            ScaleMode::MajorPentatonic => UiScaleMode::MajorPentatonic,
+            ScaleMode::MinorPentatonic => UiScaleMode::MinorPentatonic,
+            ScaleMode::Blues => UiScaleMode::Blues,
+        }
+    }
+}
+
+/// UI-compatible key of a pattern
+#[derive(Clone)]
+pub struct UiScale {
+    /// Pitch class of the root (0 = C)
+    pub root: u8,
+    pub mode: UiScaleMode,
+    /// e.g. "D Dorian"
+    pub name: String,
+}
+
+impl From<&Scale> for UiScale {
+    fn from(value: &Scale) -> Self {
+        Self {
+            root: value.root,
+            mode: value.mode.into(),
+            name: value.name(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum UiChordType {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+    Sus2,
+    Sus4,
+    Major6,
+    Minor6,
+    Dominant7,
+    Major7,
+    Minor7,
+    MinorMajor7,
+    HalfDiminished7,
+    Diminished7,
+    Add9,
+    Dominant9,
+    Major9,
+    Minor9,
+}
+
+impl From<UiChordType> for ChordType {
+    fn from(value: UiChordType) -> Self {
+        match value {
+            UiChordType::Major => ChordType::Major,
+            UiChordType::Minor => ChordType::Minor,
+            UiChordType::Diminished => ChordType::Diminished,
+            UiChordType::Augmented => ChordType::Augmented,
+            UiChordType::Sus2 => ChordType::Sus2,
+            UiChordType::Sus4 => ChordType::Sus4,
+            UiChordType::Major6 => ChordType::Major6,
+            UiChordType::Minor6 => ChordType::Minor6,
+            UiChordType::Dominant7 => ChordType::Dominant7,
+            UiChordType::Major7 => ChordType::Major7,
+            UiChordType::Minor7 => ChordType::Minor7,
+            UiChordType::MinorMajor7 => ChordType::MinorMajor7,
+            UiChordType::HalfDiminished7 => ChordType::HalfDiminished7,
+            UiChordType::Diminished7 => ChordType::Diminished7,
+            UiChordType::Add9 => ChordType::Add9,
+            UiChordType::Dominant9 => ChordType::Dominant9,
+            UiChordType::Major9 => ChordType::Major9,
+            UiChordType::Minor9 => ChordType::Minor9,
+        }
+    }
+}
+
+impl From<ChordType> for UiChordType {
+    fn from(value: ChordType) -> Self {
+        match value {
+            ChordType::Major => UiChordType::Major,
+            ChordType::Minor => UiChordType::Minor,
+            ChordType::Diminished => UiChordType::Diminished,
+            ChordType::Augmented => UiChordType::Augmented,
+            ChordType::Sus2 => UiChordType::Sus2,
+            ChordType::Sus4 => UiChordType::Sus4,
+            ChordType::Major6 => UiChordType::Major6,
+            ChordType::Minor6 => UiChordType::Minor6,
+            ChordType::Dominant7 => UiChordType::Dominant7,
+            ChordType::Major7 => UiChordType::Major7,
+            ChordType::Minor7 => UiChordType::Minor7,
+            ChordType::MinorMajor7 => UiChordType::MinorMajor7,
+            ChordType::HalfDiminished7 => UiChordType::HalfDiminished7,
+            ChordType::Diminished7 => UiChordType::Diminished7,
+            ChordType::Add9 => UiChordType::Add9,
+            ChordType::Dominant9 => UiChordType::Dominant9,
+            ChordType::Major9 => UiChordType::Major9,
+            ChordType::Minor9 => UiChordType::Minor9,
+        }
+    }
+}
+
+/// UI-compatible chord found in a run of notes
+#[derive(Clone)]
+pub struct UiChordSpan {
+    pub start_tick: u64,
+    pub end_tick: u64,
+    /// Pitch class of the root (0 = C)
+    pub root: u8,
+    pub chord_type: UiChordType,
+    /// Pitch class of the lowest note of a slash chord
+    pub bass: Option<u8>,
+    /// e.g. "Am7" or "C/E"
+    pub name: String,
+}
+
+/// get the key of a pattern, if any
+pub fn get_pattern_scale(pattern_id: u32) -> Result<Option<UiScale>, String> {
+    let pattern = pattern_api::get_pattern(&PatternId::from(pattern_id)).map_err(|e| e.to_string())?;
+    Ok(pattern.scale.as_ref().map(UiScale::from))
+}
+
+/// set the key of a pattern, `root` is a pitch class (0 = C). No mode clears it
+pub fn set_pattern_scale(pattern_id: u32, root: u8, mode: Option<UiScaleMode>) -> Result<(), String> {
+    pattern_api
+        ::set_pattern_scale(
+            PatternId::from(pattern_id),
+            mode.map(|mode| Scale::new(root, mode.into()))
+        )
+        .map_err(|e| e.to_string())
+}
+
+/// ids of the notes outside the pattern's key, none when it has no key
+pub fn get_out_of_key_notes(pattern_id: u32) -> Result<Vec<u32>, String> {
+    let note_ids = pattern_api
+        ::get_out_of_key_notes(PatternId::from(pattern_id))
+        .map_err(|e| e.to_string())?;
+    Ok(note_ids.into_iter().map(u32::from).collect())
+}
+
+/// keys of a chord spelled up from `root_key`, without adding it
+pub fn chord_keys(root_key: u8, chord_type: UiChordType, inversion: u32) -> Vec<u8> {
+    Chord::keys(root_key, chord_type.into(), inversion as usize)
+}
+
+/// chords played by the selected notes, or the whole pattern when `note_ids` is empty
+pub fn analyse_chords(pattern_id: u32, note_ids: Vec<u32>) -> Result<Vec<UiChordSpan>, String> {
+    let note_ids: Vec<NoteId> = note_ids.into_iter().map(NoteId::from).collect();
+    let spans = pattern_api
+        ::analyse_chords(PatternId::from(pattern_id), &note_ids)
+        .map_err(|e| e.to_string())?;
+    Ok(
+        spans
+            .iter()
+            .map(|span| UiChordSpan {
+                start_tick: span.start_tick,
+                end_tick: span.end_tick,
+                root: span.chord.root,
+                chord_type: span.chord.chord_type.into(),
+                bass: span.chord.bass,
+                name: span.chord.name(),
+            })
+            .collect()
+    )
+}
+
+/// add the notes of a chord in one go (undoable)
+pub fn add_chord(
+    pattern_id: u32,
+    root_key: u8,
+    chord_type: UiChordType,
+    inversion: u32,
+    start_tick: u64,
+    duration: Option<u64>
+) -> Result<Vec<UiNote>, String> {
+    let notes = note_api
+        ::add_chord(
+            PatternId::from(pattern_id),
+            root_key,
+            chord_type.into(),
+            inversion as usize,
+            start_tick,
+            duration
+        )
+        .map_err(|e| e.to_string())?;
+    Ok(notes.iter().map(UiNote::from).collect())
+}
+
+/// move the selected notes, or the whole pattern when `note_ids` is empty, into the
+/// pattern's key (undoable)
+pub fn snap_to_scale(pattern_id: u32, note_ids: Vec<u32>) -> Result<Vec<UiNote>, String> {
+    let notes = note_api
+        ::snap_to_scale(PatternId::from(pattern_id), note_ids.into_iter().map(NoteId::from).collect())
+        .map_err(|e| e.to_string())?;
+    Ok(notes.iter().map(UiNote::from).collect())
+}
@@ -2,7 +2,7 @@ use std::{ collections::HashMap, ops::Deref };
 
 use chrono::{ DateTime, Utc };
 use flutter_rust_bridge::frb;
-use karbeat_core::api::{ audio_waveform_api, project_api, track_api };
+use karbeat_core::api::{ audio_waveform_api, project_api, track_api, transport_api };
 use karbeat_core::core::project::{ ApplicationState, PluginInstance };
 use karbeat_utils::audio_utils::quantize_to_i8;
 use serde::Serialize;
@@ -10,6 +10,7 @@ use karbeat_core::{
     core::project::{
         clip::Clip,
         generator::{ GeneratorInstance, GeneratorInstanceType },
+        tempo_map::TickConverter,
         track::{ audio_waveform::AudioWaveform, KarbeatTrack, TrackType },
         transport::TransportState,
         AudioHardwareConfig,
@@ -39,9 +40,10 @@ pub struct UiApplicationState {
 
 impl From<ApplicationState> for UiApplicationState {
     fn from(value: ApplicationState) -> Self {
+        let converter = value.tick_converter();
         let tracks: HashMap<u32, UiTrack> = value.tracks
             .iter()
-            .map(|(id, track)| (id.to_u32(), UiTrack::from(track.as_ref())))
+            .map(|(id, track)| (id.to_u32(), UiTrack::from_track(track.as_ref(), &converter)))
             .collect();
 
         let generators: HashMap<u32, UiGeneratorInstance> = value.generator_pool
@@ -72,7 +74,7 @@ impl From<ApplicationState> for UiApplicationState {
             generators,
             patterns,
             mixer: crate::api::mixer::UiMixerState::from(&value.mixer),
-            max_sample_index: value.max_sample_index,
+            max_sample_index: converter.ticks_to_samples(value.max_tick_index) as u32,
             audio_sources,
         }
     }
@@ -186,15 +188,17 @@ impl From<TransportState> for UiTransportState {
 
 impl From<UiTransportState> for TransportState {
     fn from(s: UiTransportState) -> Self {
-        Self {
-            bpm: s.bpm,
-            time_signature: s.time_signature,
-        }
+        let mut transport = Self::default();
+        transport.set_bpm(s.bpm);
+        transport.set_time_signature(s.time_signature.into());
+        transport
     }
 }
 
-impl From<&KarbeatTrack> for UiTrack {
-    fn from(value: &KarbeatTrack) -> Self {
+#[frb(ignore)]
+impl UiTrack {
+    /// Clip positions are converted from ticks to samples with `converter`.
+    pub fn from_track(value: &KarbeatTrack, converter: &TickConverter) -> Self {
         let generator_id = value.generator.as_ref().map(|gen_instance| gen_instance.id.to_u32());
         Self {
             id: value.id.to_u32(),
@@ -204,7 +208,7 @@ impl From<&KarbeatTrack> for UiTrack {
             clips: value
                 .clips_to_vec()
                 .iter()
-                .map(|c| UiClip::from(c.deref()))
+                .map(|c| UiClip::from_clip(c.deref(), converter))
                 .collect(),
             generator_id,
         }
@@ -272,8 +276,10 @@ pub enum UiClipSource {
     None, // represent clip with empty source, this is placeholder, as this will be removed when I already implement MIDI Pattern and automation
 }
 
-impl From<&Clip> for UiClip {
-    fn from(value: &Clip) -> Self {
+#[frb(ignore)]
+impl UiClip {
+    /// Builds the UI clip with its timeline values converted from ticks to samples.
+    pub fn from_clip(value: &Clip, converter: &TickConverter) -> Self {
         // Map source to either AudioWaveform, midi
         let source = match &value.source {
             KarbeatSource::Audio(source_id) =>
@@ -289,10 +295,10 @@ impl From<&Clip> for UiClip {
         Self {
             name: value.name.clone(),
             id: value.id.to_u32(),
-            start_time: value.start_time,
+            start_time: converter.ticks_to_samples(value.start_time) as u32,
             source,
-            offset_start: value.offset_start,
-            loop_length: value.loop_length,
+            offset_start: converter.offset_to_samples(value.start_time, value.offset_start as u64) as u32,
+            loop_length: converter.span_to_samples(value.start_time, value.loop_length as u64) as u32,
         }
     }
 }
@@ -525,14 +531,17 @@ pub fn add_audio_source(file_path: &str) -> Result<u32, String> {
 pub fn add_new_audio_track() -> UiTrack {
     let arc_track = { track_api::add_new_audio_track() };
     log::info!("[add_new_track] successfully added new track");
-    UiTrack::from(arc_track.as_ref())
+    UiTrack::from_track(arc_track.as_ref(), &transport_api::get_tick_converter())
 }
 
 /// Get all tracks on the session/project.
 ///
 /// Returns Map<u32, UiTrack> upon success, and Error when it fails
 pub fn get_tracks() -> Result<HashMap<u32, UiTrack>, String> {
-    track_api::get_tracks(|id, track| { (id, UiTrack::from(track)) }).map_err(|e| e.to_string())
+    let converter = transport_api::get_tick_converter();
+    track_api
+        ::get_tracks(|id, track| { (id, UiTrack::from_track(track, &converter)) })
+        .map_err(|e| e.to_string())
 }
 
 /// Get the newest max sample index of the project
@@ -0,0 +1,152 @@
+//! api/recording.rs
+//! Audio input selection, record arm and take recording.
+
+use karbeat_core::api::{ recording_api, transport_api };
+use karbeat_core::core::project::{
+    musical_time::GridDivision,
+    track::MonitorMode,
+    transport::MidiRecordMode,
+};
+use karbeat_core::shared::id::TrackId;
+
+use crate::api::project::UiClip;
+
+/// When an armed audio track plays its live input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UiMonitorMode {
+    Off,
+    /// Monitor whenever the track is armed
+    Always,
+    /// Monitor while armed and the transport is stopped or recording
+    Auto,
+}
+
+impl From<MonitorMode> for UiMonitorMode {
+    fn from(mode: MonitorMode) -> Self {
+        match mode {
+            MonitorMode::Off => Self::Off,
+            MonitorMode::Always => Self::Always,
+            MonitorMode::Auto => Self::Auto,
+        }
+    }
+}
+
+impl From<UiMonitorMode> for MonitorMode {
+    fn from(mode: UiMonitorMode) -> Self {
+        match mode {
+            UiMonitorMode::Off => Self::Off,
+            UiMonitorMode::Always => Self::Always,
+            UiMonitorMode::Auto => Self::Auto,
+        }
+    }
+}
+
+/// How recorded notes combine with the notes already in the pattern
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UiMidiRecordMode {
+    /// Keep existing notes and add the new ones
+    Overdub,
+    /// Remove existing notes that start inside the recorded range
+    Replace,
+}
+
+impl From<MidiRecordMode> for UiMidiRecordMode {
+    fn from(mode: MidiRecordMode) -> Self {
+        match mode {
+            MidiRecordMode::Overdub => Self::Overdub,
+            MidiRecordMode::Replace => Self::Replace,
+        }
+    }
+}
+
+impl From<UiMidiRecordMode> for MidiRecordMode {
+    fn from(mode: UiMidiRecordMode) -> Self {
+        match mode {
+            UiMidiRecordMode::Overdub => Self::Overdub,
+            UiMidiRecordMode::Replace => Self::Replace,
+        }
+    }
+}
+
+/// Recorded clip together with the track it was placed on
+pub struct UiRecordedClip {
+    pub track_id: u32,
+    pub clip: UiClip,
+}
+
+pub fn list_input_devices() -> Result<Vec<String>, String> {
+    recording_api::list_input_devices().map_err(|e| e.to_string())
+}
+
+/// open an input device by name (`None` for the system default), returns the opened device name
+pub fn set_input_device(name: Option<String>) -> Result<String, String> {
+    recording_api::set_input_device(name).map_err(|e| e.to_string())
+}
+
+pub fn close_input_device() {
+    recording_api::close_input_device();
+}
+
+/// arm or disarm an audio or MIDI track, armed tracks receive the take when recording stops
+pub fn set_track_record_arm(track_id: u32, armed: bool) -> Result<(), String> {
+    recording_api::set_track_record_arm(TrackId::from(track_id), armed).map_err(|e| e.to_string())
+}
+
+pub fn get_armed_tracks() -> Vec<u32> {
+    recording_api::get_armed_tracks()
+        .into_iter()
+        .map(|id| id.to_u32())
+        .collect()
+}
+
+pub fn set_track_monitor_mode(track_id: u32, mode: UiMonitorMode) -> Result<(), String> {
+    recording_api::set_track_monitor_mode(TrackId::from(track_id), mode.into()).map_err(|e| e.to_string())
+}
+
+pub fn get_track_monitor_mode(track_id: u32) -> Result<UiMonitorMode, String> {
+    recording_api::get_track_monitor_mode(TrackId::from(track_id))
+        .map(UiMonitorMode::from)
+        .map_err(|e| e.to_string())
+}
+
+pub fn get_midi_record_mode() -> UiMidiRecordMode {
+    recording_api::get_midi_record_settings().mode.into()
+}
+
+/// input quantize grid for recorded notes in ticks, `None` when notes are kept as played
+pub fn get_midi_record_quantize() -> Option<u32> {
+    match recording_api::get_midi_record_settings().quantize? {
+        GridDivision::Ticks(ticks) => Some(ticks),
+        GridDivision::Bar | GridDivision::Beat => None,
+    }
+}
+
+/// `quantize_ticks` snaps recorded note starts to a grid of that many ticks, `None` disables it
+pub fn set_midi_record_settings(mode: UiMidiRecordMode, quantize_ticks: Option<u32>) {
+    let quantize = quantize_ticks.filter(|&ticks| ticks > 0).map(GridDivision::Ticks);
+    recording_api::set_midi_record_settings(mode.into(), quantize);
+}
+
+/// start playback and record the input and live notes onto the armed tracks
+pub fn start_recording() -> Result<(), String> {
+    recording_api::start_recording().map_err(|e| e.to_string())
+}
+
+/// stop playback and recording, returns the clips created from the take (positions in samples)
+pub fn stop_recording() -> Result<Vec<UiRecordedClip>, String> {
+    let clips = recording_api::stop_recording().map_err(|e| e.to_string())?;
+    let converter = transport_api::get_tick_converter();
+    Ok(
+        clips
+            .iter()
+            .map(|(track_id, clip)| UiRecordedClip {
+                track_id: track_id.to_u32(),
+                clip: UiClip::from_clip(clip, &converter),
+            })
+            .collect()
+    )
+}
+
+pub fn is_recording() -> bool {
+    recording_api::is_recording()
+}
@@ -1,3 +1,5 @@
+use flutter_rust_bridge::frb;
+
 use crate::api::track::UiResizeEdge;
 use crate::api::{ pattern::UiNote, project::UiClip };
 use karbeat_core::api::{
@@ -6,9 +8,11 @@ use karbeat_core::api::{
     clipboard_api,
     note_api as note_api,
     pattern_api,
+    transport_api,
 };
 use karbeat_core::core::project::{ NoteId, PatternId };
 use karbeat_core::core::project::{ clipboard::ClipboardContent };
+use karbeat_core::core::project::tempo_map::TickConverter;
 
 use karbeat_core::shared::id::*;
 
@@ -26,8 +30,10 @@ pub enum UiClipboardContent {
     Clips(Vec<UiClip>), // A list of clips (for Track View)
 }
 
-impl From<&ClipboardContent> for UiClipboardContent {
-    fn from(clipboard: &ClipboardContent) -> Self {
+#[frb(ignore)]
+impl UiClipboardContent {
+    /// Clip positions are converted from ticks to samples through `converter`.
+    pub fn from_clipboard(clipboard: &ClipboardContent, converter: &TickConverter) -> Self {
         match clipboard {
             ClipboardContent::Empty => UiClipboardContent::Empty,
             ClipboardContent::Notes(notes) => {
@@ -35,7 +41,10 @@ impl From<&ClipboardContent> for UiClipboardContent {
                 UiClipboardContent::Notes(ui_notes)
             }
             ClipboardContent::Clips(clips) => {
-                let ui_clips = clips.iter().map(UiClip::from).collect();
+                let ui_clips = clips
+                    .iter()
+                    .map(|c| UiClip::from_clip(c, converter))
+                    .collect();
                 UiClipboardContent::Clips(ui_clips)
             }
         }
@@ -69,9 +78,10 @@ pub fn copy_pattern_notes(
     let pattern_id = PatternId::from(pattern_id);
     let note_ids: Vec<NoteId> = note_ids.into_iter().map(NoteId::from).collect();
 
+    let converter = transport_api::get_tick_converter();
     pattern_api
         ::copy_pattern_notes(pattern_id, note_ids, |clipboard| {
-            UiClipboardContent::from(clipboard)
+            UiClipboardContent::from_clipboard(clipboard, &converter)
         })
         .map_err(|e| e.to_string())
 }
@@ -114,8 +124,11 @@ pub fn copy_clips(track_id: u32, clip_ids: Vec<u32>) -> Result<UiClipboardConten
     let track_id = TrackId::from(track_id);
     let clip_ids: Vec<ClipId> = clip_ids.into_iter().map(ClipId::from).collect();
 
+    let converter = transport_api::get_tick_converter();
     clip_api
-        ::copy_clips(track_id, clip_ids, |clipboard| { UiClipboardContent::from(clipboard) })
+        ::copy_clips(track_id, clip_ids, |clipboard| {
+            UiClipboardContent::from_clipboard(clipboard, &converter)
+        })
         .map_err(|e| e.to_string())
 }
 
@@ -133,8 +146,12 @@ pub fn cut_clips(track_id: u32, clip_ids: Vec<u32>) -> Result<(), String> {
 /// Paste clips from clipboard to a target track at a specified start time.
 /// Clips are offset relative to the earliest clip's start time.
 pub fn paste_clips(target_track_id: u32, paste_start_time: u32) -> Result<(), String> {
+    let paste_start_tick = transport_api
+        ::get_tick_converter()
+        .samples_to_ticks(paste_start_time as u64)
+        .ticks() as u32;
     clip_api
-        ::paste_clips(TrackId::from(target_track_id), paste_start_time)
+        ::paste_clips(TrackId::from(target_track_id), paste_start_tick)
         .map_err(|e| format!("{}", e))?;
 
     Ok(())
@@ -157,12 +174,16 @@ pub fn move_clip(
     clip_id: u32,
     new_start_time: u32
 ) -> Result<(), String> {
+    let new_start_tick = transport_api
+        ::get_tick_converter()
+        .samples_to_ticks(new_start_time as u64)
+        .ticks() as u32;
     clip_api
         ::move_clip(
             TrackId::from(old_track_id),
             TrackId::from(new_track_id),
             ClipId::from(clip_id),
-            new_start_time
+            new_start_tick
         )
         .map_err(|e| format!("{}", e))?;
 
@@ -177,8 +198,12 @@ pub fn resize_clip(
     edge: UiResizeEdge,
     new_time_val: u32
 ) -> Result<(), String> {
+    let new_tick = transport_api
+        ::get_tick_converter()
+        .samples_to_ticks(new_time_val as u64)
+        .ticks() as u32;
     clip_api
-        ::resize_clip(TrackId::from(track_id), ClipId::from(clip_id), edge.into(), new_time_val)
+        ::resize_clip(TrackId::from(track_id), ClipId::from(clip_id), edge.into(), new_tick)
         .map_err(|e| format!("{}", e))?;
 
     Ok(())
@@ -189,7 +214,8 @@ pub fn resize_clip(
 // ==================================
 
 pub fn get_clipboard_contents() -> UiClipboardContent {
+    let converter = transport_api::get_tick_converter();
     clipboard_api::get_clipboard_contents(|clipboard_ref| {
-        UiClipboardContent::from(clipboard_ref)
+        UiClipboardContent::from_clipboard(clipboard_ref, &converter)
     })
 }
@@ -5,11 +5,13 @@ use std::collections::HashMap;
 use crate::api::project::{ AudioWaveformUiForClip, UiClip, UiTrack };
 use karbeat_core::core::project::AudioSourceId;
 use karbeat_core::core::project::clip::ResizeEdge;
+use karbeat_core::core::project::tempo_map::TickConverter;
 use karbeat_core::shared::id::*;
 use karbeat_core::api::{
     clip_api as clip_api,
     audio_waveform_api as audio_waveform_api,
     track_api as track_api,
+    transport_api as transport_api,
 };
 
 pub enum UiSourceType {
@@ -104,11 +106,14 @@ pub fn create_clip(
         UiSourceType::Midi => karbeat_core::core::project::clip::ClipSourceType::Midi,
     };
 
+    let converter = transport_api::get_tick_converter();
+    let start_tick = converter.samples_to_ticks(start_time as u64).ticks() as u32;
+
     let clip = clip_api
-        ::add_clip(source_id, core_source_type, track_id, start_time)
+        ::add_clip(source_id, core_source_type, track_id, start_tick)
         .map_err(|e| format!("{}", e))?;
 
-    let ui_clip = UiClip::from(&clip);
+    let ui_clip = UiClip::from_clip(&clip, &converter);
     Ok(ui_clip)
 }
 
@@ -131,11 +136,14 @@ pub fn resize_clip(
     let clip_id = ClipId::from(clip_id);
     let core_edge: ResizeEdge = edge.into();
 
+    let converter = transport_api::get_tick_converter();
+    let new_tick = converter.samples_to_ticks(new_time_val as u64).ticks() as u32;
+
     let res = clip_api
-        ::resize_clip(track_id, clip_id, core_edge, new_time_val)
+        ::resize_clip(track_id, clip_id, core_edge, new_tick)
         .map_err(|e| format!("{}", e))?;
 
-    Ok(UiClip::from(&res))
+    Ok(UiClip::from_clip(&res, &converter))
 }
 
 pub fn move_clip(
@@ -148,11 +156,14 @@ pub fn move_clip(
     let clip_id = ClipId::from(clip_id);
     let target_track_id = new_track_id.map(TrackId::from).unwrap_or(source_track_id);
 
+    let converter = transport_api::get_tick_converter();
+    let new_start_tick = converter.samples_to_ticks(new_start_time as u64).ticks() as u32;
+
     let res = clip_api
-        ::move_clip(source_track_id, target_track_id, clip_id, new_start_time)
+        ::move_clip(source_track_id, target_track_id, clip_id, new_start_tick)
         .map_err(|e| format!("{}", e))?;
 
-    Ok(UiClip::from(&res))
+    Ok(UiClip::from_clip(&res, &converter))
 }
 
 /// Cut a clip in half.
@@ -173,28 +184,37 @@ pub fn cut_clip(
     let source_track_id_typed = TrackId::from(source_track_id);
     let clip_id_typed = ClipId::from(clip_id);
 
+    let converter = transport_api::get_tick_converter();
+    let cut_point_tick = converter.samples_to_ticks(cut_point_sample as u64).ticks() as u32;
+
     let (c1, c2) = clip_api
-        ::cut_clip(source_track_id_typed, clip_id_typed, cut_point_sample)
+        ::cut_clip(source_track_id_typed, clip_id_typed, cut_point_tick)
         .map_err(|e| format!("{}", e))?;
 
-    Ok(vec![UiClip::from(&c1), UiClip::from(&c2)])
+    Ok(vec![UiClip::from_clip(&c1, &converter), UiClip::from_clip(&c2, &converter)])
 }
 
 /// Add a MIDI track with a generator by its registry ID (preferred method).
 pub fn add_midi_track_with_generator_id(registry_id: u32) -> Result<UiTrack, String> {
     let res = track_api::add_midi_track_with_generator_id(registry_id).map_err(|e| e.to_string())?;
-    Ok(UiTrack::from(res.as_ref()))
+    Ok(UiTrack::from_track(res.as_ref(), &transport_api::get_tick_converter()))
 }
 
 pub fn get_clip(track_id: u32, clip_id: u32) -> Result<UiClip, String> {
+    let converter = transport_api::get_tick_converter();
     clip_api
-        ::get_clip(TrackId::from(track_id), ClipId::from(clip_id), |c| UiClip::from(c))
+        ::get_clip(TrackId::from(track_id), ClipId::from(clip_id), |c| {
+            UiClip::from_clip(c, &converter)
+        })
         .map_err(|e| e.to_string())
 }
 
 // Alternatively, fetching the whole Track is often useful too and still cheaper than all tracks
 pub fn get_track(track_id: u32) -> Result<UiTrack, String> {
-    track_api::get_track(TrackId::from(track_id), |t| UiTrack::from(t)).map_err(|e| e.to_string())
+    let converter = transport_api::get_tick_converter();
+    track_api
+        ::get_track(TrackId::from(track_id), |t| UiTrack::from_track(t, &converter))
+        .map_err(|e| e.to_string())
 }
 
 // =====================================
@@ -212,14 +232,23 @@ pub fn move_clip_batch(
     let target_track_id = new_track_id.map(TrackId::from).unwrap_or(source_track_id);
     let clip_ids: Vec<ClipId> = clip_ids.into_iter().map(ClipId::from).collect();
 
+    let converter = transport_api::get_tick_converter();
+    let delta_ticks = batch_delta_to_ticks(
+        source_track_id,
+        &clip_ids,
+        ResizeEdge::Left,
+        delta_samples,
+        &converter
+    );
+
     let res = clip_api
-        ::batch_move_clips(source_track_id, target_track_id, clip_ids, delta_samples)
+        ::batch_move_clips(source_track_id, target_track_id, clip_ids, delta_ticks)
         .map_err(|e| format!("{}", e))?;
 
     Ok(
         res
             .iter()
-            .map(UiClip::from)
+            .map(|c| UiClip::from_clip(c, &converter))
             .collect()
     )
 }
@@ -233,19 +262,56 @@ pub fn resize_clip_batch(
 ) -> Result<Vec<UiClip>, String> {
     let track_id = TrackId::from(track_id);
     let clip_ids: Vec<ClipId> = clip_ids.into_iter().map(ClipId::from).collect();
+
     let core_edge: ResizeEdge = edge.into();
+
+    let converter = transport_api::get_tick_converter();
+    let delta_ticks = batch_delta_to_ticks(
+        track_id,
+        &clip_ids,
+        core_edge,
+        delta_samples,
+        &converter
+    );
+
     let res = clip_api
-        ::batch_resize_clips(track_id, clip_ids, core_edge, delta_samples)
+        ::batch_resize_clips(track_id, clip_ids, core_edge, delta_ticks)
         .map_err(|e| format!("{}", e))?;
 
     Ok(
         res
             .iter()
-            .map(UiClip::from)
+            .map(|c| UiClip::from_clip(c, &converter))
             .collect()
     )
 }
 
+/// Converts a drag delta (in samples) into ticks, measured at the dragged edge
+/// of the first clip so the result follows the tempo under the cursor.
+fn batch_delta_to_ticks(
+    track_id: TrackId,
+    clip_ids: &[ClipId],
+    edge: ResizeEdge,
+    delta_samples: i64,
+    converter: &TickConverter
+) -> i64 {
+    let anchor = clip_ids
+        .first()
+        .and_then(|clip_id| {
+            clip_api
+                ::get_clip(track_id, *clip_id, |c| {
+                    match edge {
+                        ResizeEdge::Left => c.start_time,
+                        ResizeEdge::Right => c.start_time + c.loop_length,
+                    }
+                })
+                .ok()
+        })
+        .unwrap_or(0);
+
+    converter.delta_to_ticks(anchor, delta_samples)
+}
+
 /// Delete clips in batch
 pub fn delete_clip_batch(track_id: u32, clip_ids: Vec<u32>) -> Result<(), String> {
     let track_id = TrackId::from(track_id);
@@ -3,6 +3,75 @@
 //! BPM is also persisted in ApplicationState for project serialization.
 
 use karbeat_core::api::transport_api;
+use karbeat_core::core::project::musical_time::{ TimeSignature, TimeSignatureEvent };
+use karbeat_core::core::project::tempo_map::{ TempoPoint, TempoRamp };
+use karbeat_core::core::project::transport::MetronomeSettings;
+use karbeat_core::shared::id::BusId;
+
+/// UI-compatible representation of a tempo map point
+pub struct UiTempoPoint {
+    /// Position in ticks (960 per quarter note)
+    pub tick: u64,
+    pub bpm: f32,
+    /// Whether the tempo ramps linearly from this point to the next one
+    pub linear: bool,
+}
+
+impl From<&TempoPoint> for UiTempoPoint {
+    fn from(value: &TempoPoint) -> Self {
+        Self {
+            tick: value.tick,
+            bpm: value.bpm,
+            linear: value.ramp == TempoRamp::Linear,
+        }
+    }
+}
+
+/// UI-compatible representation of a time signature change
+pub struct UiTimeSignatureEvent {
+    /// Bar (0-based) where the signature starts
+    pub bar: u32,
+    pub numerator: u8,
+    pub denominator: u8,
+}
+
+impl From<&TimeSignatureEvent> for UiTimeSignatureEvent {
+    fn from(value: &TimeSignatureEvent) -> Self {
+        Self {
+            bar: value.bar,
+            numerator: value.signature.numerator,
+            denominator: value.signature.denominator,
+        }
+    }
+}
+
+/// UI-compatible representation of the loop region, in samples
+pub struct UiLoopRegion {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// UI-compatible representation of the metronome settings
+pub struct UiMetronomeSettings {
+    pub enabled: bool,
+    /// Click gain (0.0 - 1.0)
+    pub volume: f32,
+    /// Bus receiving the click, `None` for the direct audio output
+    pub target_bus_id: Option<u32>,
+    /// Bars of count-in before playback (0 - 2)
+    pub count_in_bars: u8,
+}
+
+impl From<MetronomeSettings> for UiMetronomeSettings {
+    fn from(value: MetronomeSettings) -> Self {
+        Self {
+            enabled: value.enabled,
+            volume: value.volume,
+            target_bus_id: value.target.map(|id| id.to_u32()),
+            count_in_bars: value.count_in_bars,
+        }
+    }
+}
 
 /// set the play state of the transport
 pub fn set_playing(val: bool) -> Result<(), String> {
@@ -22,6 +91,31 @@ pub fn set_looping(val: bool) -> Result<(), String> {
     Ok(())
 }
 
+/// set the loop in/out points (in samples) used when looping is enabled
+pub fn set_loop_region(start: u32, end: u32) -> Result<(), String> {
+    let converter = transport_api::get_tick_converter();
+    let start_tick = converter.samples_to_ticks(start as u64).ticks();
+    let end_tick = converter.samples_to_ticks(end as u64).ticks();
+    transport_api::set_loop_region(start_tick, end_tick).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// clear the loop in/out points so looping covers the whole song
+pub fn clear_loop_region() -> Result<(), String> {
+    transport_api::clear_loop_region();
+    Ok(())
+}
+
+/// get the loop in/out points (in samples), if any
+pub fn get_loop_region() -> Option<UiLoopRegion> {
+    let region = transport_api::get_loop_region()?;
+    let converter = transport_api::get_tick_converter();
+    Some(UiLoopRegion {
+        start: converter.ticks_to_samples(region.start) as u32,
+        end: converter.ticks_to_samples(region.end) as u32,
+    })
+}
+
 /// set the BPM of the transport.
 /// writes to both ApplicationState (for serialization) and AudioCommand (for audio thread)
 pub fn set_bpm(val: f32) -> Result<(), String> {
@@ -34,3 +128,92 @@ pub fn stop_song_playback() -> Result<(), String> {
     transport_api::stop_song_playback();
     Ok(())
 }
+
+/// get all points of the project tempo map, ordered by tick
+pub fn get_tempo_points() -> Vec<UiTempoPoint> {
+    transport_api::get_tempo_map(|map| map.points().iter().map(UiTempoPoint::from).collect())
+}
+
+/// insert or replace a tempo point at `tick`
+pub fn set_tempo_point(tick: u64, bpm: f32, linear: bool) -> Result<(), String> {
+    let ramp = if linear { TempoRamp::Linear } else { TempoRamp::Jump };
+    transport_api::set_tempo_point(tick, bpm, ramp).map_err(|e| e.to_string())
+}
+
+/// remove the tempo point at `tick`. The initial tempo point cannot be removed
+pub fn remove_tempo_point(tick: u64) -> Result<UiTempoPoint, String> {
+    transport_api
+        ::remove_tempo_point(tick)
+        .map(|point| UiTempoPoint::from(&point))
+        .map_err(|e| e.to_string())
+}
+
+/// convert a tick position into samples using the current tempo map
+pub fn ticks_to_samples(tick: u64) -> u64 {
+    transport_api::get_tick_converter().ticks_to_samples(tick)
+}
+
+/// convert a sample position into ticks using the current tempo map
+pub fn samples_to_ticks(sample: u64) -> u64 {
+    transport_api::get_tick_converter().samples_to_ticks(sample).ticks()
+}
+
+/// get all time signature changes, ordered by bar
+pub fn get_time_signatures() -> Vec<UiTimeSignatureEvent> {
+    transport_api::get_time_signature_map(|map| {
+        map.events().iter().map(UiTimeSignatureEvent::from).collect()
+    })
+}
+
+/// set the time signature starting on `bar` (0-based). Bar 0 changes the project signature
+pub fn set_time_signature(bar: u32, numerator: u8, denominator: u8) -> Result<(), String> {
+    transport_api
+        ::set_time_signature(bar, TimeSignature::new(numerator, denominator))
+        .map_err(|e| e.to_string())
+}
+
+/// remove the time signature change on `bar`. The initial signature cannot be removed
+pub fn remove_time_signature(bar: u32) -> Result<UiTimeSignatureEvent, String> {
+    transport_api
+        ::remove_time_signature(bar)
+        .map(|event| UiTimeSignatureEvent::from(&event))
+        .map_err(|e| e.to_string())
+}
+
+/// get the metronome and count-in settings
+pub fn get_metronome_settings() -> UiMetronomeSettings {
+    UiMetronomeSettings::from(transport_api::get_metronome_settings())
+}
+
+/// enable or disable the metronome click during song playback
+pub fn set_metronome_enabled(val: bool) -> Result<(), String> {
+    transport_api::set_metronome_enabled(val);
+    Ok(())
+}
+
+/// set the metronome click gain (0.0 - 1.0)
+pub fn set_metronome_volume(val: f32) -> Result<(), String> {
+    transport_api::set_metronome_volume(val);
+    Ok(())
+}
+
+/// route the metronome to a bus, or `None` for the direct audio output
+pub fn set_metronome_target(bus_id: Option<u32>) -> Result<(), String> {
+    transport_api::set_metronome_target(bus_id.map(BusId::from)).map_err(|e| e.to_string())
+}
+
+/// set how many bars (0 - 2) are counted in before playback starts
+pub fn set_count_in_bars(bars: u8) -> Result<(), String> {
+    transport_api::set_count_in_bars(bars).map_err(|e| e.to_string())
+}
+
+/// get the seed of the note probability rolls
+pub fn get_random_seed() -> u64 {
+    transport_api::get_random_seed()
+}
+
+/// set the seed of the note probability rolls, the same seed always plays the same notes
+pub fn set_random_seed(seed: u64) -> Result<(), String> {
+    transport_api::set_random_seed(seed);
+    Ok(())
+}
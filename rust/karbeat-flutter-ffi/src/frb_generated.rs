@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -917136446;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -773139399;
 
 // Section: executor
 
@@ -78,6 +78,51 @@ fn wire__crate__api__project__add_audio_source_impl(
         },
     )
 }
+fn wire__crate__api__pattern__add_chord_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_chord",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_root_key = <u8>::sse_decode(&mut deserializer);
+            let api_chord_type = <crate::api::pattern::UiChordType>::sse_decode(&mut deserializer);
+            let api_inversion = <u32>::sse_decode(&mut deserializer);
+            let api_start_tick = <u64>::sse_decode(&mut deserializer);
+            let api_duration = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pattern::add_chord(
+                        api_pattern_id,
+                        api_root_key,
+                        api_chord_type,
+                        api_inversion,
+                        api_start_tick,
+                        api_duration,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__mixer__add_effect_to_bus_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -291,6 +336,41 @@ fn wire__crate__api__pattern__add_note_impl(
         },
     )
 }
+fn wire__crate__api__pattern__analyse_chords_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "analyse_chords",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::pattern::analyse_chords(api_pattern_id, api_note_ids)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__project__audio_hardware_config_new_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -478,7 +558,7 @@ fn wire__crate__api__track__change_track_name_impl(
         },
     )
 }
-fn wire__crate__api__session__copy_clips_impl(
+fn wire__crate__api__pattern__chord_keys_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -486,7 +566,7 @@ fn wire__crate__api__session__copy_clips_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "copy_clips",
+            debug_name: "chord_keys",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -500,19 +580,24 @@ fn wire__crate__api__session__copy_clips_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_root_key = <u8>::sse_decode(&mut deserializer);
+            let api_chord_type = <crate::api::pattern::UiChordType>::sse_decode(&mut deserializer);
+            let api_inversion = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::session::copy_clips(api_track_id, api_clip_ids)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::pattern::chord_keys(
+                        api_root_key,
+                        api_chord_type,
+                        api_inversion,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__copy_pattern_notes_impl(
+fn wire__crate__api__transport__clear_loop_region_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -520,7 +605,7 @@ fn wire__crate__api__session__copy_pattern_notes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "copy_pattern_notes",
+            debug_name: "clear_loop_region",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -534,20 +619,17 @@ fn wire__crate__api__session__copy_pattern_notes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
-            let api_note_ids = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::session::copy_pattern_notes(api_pattern_id, api_note_ids)?;
+                    let output_ok = crate::api::transport::clear_loop_region()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__create_bus_impl(
+fn wire__crate__api__recording__close_input_device_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -555,7 +637,7 @@ fn wire__crate__api__mixer__create_bus_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_bus",
+            debug_name: "close_input_device",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -569,18 +651,19 @@ fn wire__crate__api__mixer__create_bus_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::create_bus(api_name)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::recording::close_input_device();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__create_clip_impl(
+fn wire__crate__api__midi__close_midi_input_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -588,7 +671,7 @@ fn wire__crate__api__track__create_clip_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_clip",
+            debug_name: "close_midi_input",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -602,26 +685,19 @@ fn wire__crate__api__track__create_clip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_id = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_source_type = <crate::api::track::UiSourceType>::sse_decode(&mut deserializer);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_start_time = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::create_clip(
-                        api_source_id,
-                        api_source_type,
-                        api_track_id,
-                        api_start_time,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::midi::close_midi_input();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__create_mixer_event_stream_impl(
+fn wire__crate__api__midi__close_midi_output_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -629,7 +705,7 @@ fn wire__crate__api__mixer__create_mixer_event_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_mixer_event_stream",
+            debug_name: "close_midi_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -643,21 +719,19 @@ fn wire__crate__api__mixer__create_mixer_event_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::mixer::UiMixerParamEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::create_mixer_event_stream(api_sink)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::midi::close_midi_output();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio__create_position_stream_impl(
+fn wire__crate__api__pattern__commit_clip_groove_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -665,7 +739,7 @@ fn wire__crate__api__audio__create_position_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_position_stream",
+            debug_name: "commit_clip_groove",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -679,21 +753,20 @@ fn wire__crate__api__audio__create_position_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::audio::UiTransportFeedback,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio::create_position_stream(api_sink)?;
+                    let output_ok =
+                        crate::api::pattern::commit_clip_groove(api_track_id, api_clip_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__cut_clip_impl(
+fn wire__crate__api__pattern__commit_pattern_groove_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -701,7 +774,7 @@ fn wire__crate__api__track__cut_clip_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cut_clip",
+            debug_name: "commit_pattern_groove",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -715,24 +788,18 @@ fn wire__crate__api__track__cut_clip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_id = <u32>::sse_decode(&mut deserializer);
-            let api_cut_point_sample = <u32>::sse_decode(&mut deserializer);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::cut_clip(
-                        api_source_track_id,
-                        api_clip_id,
-                        api_cut_point_sample,
-                    )?;
+                    let output_ok = crate::api::pattern::commit_pattern_groove(api_pattern_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__cut_clips_impl(
+fn wire__crate__api__session__copy_clips_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -740,7 +807,7 @@ fn wire__crate__api__session__cut_clips_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cut_clips",
+            debug_name: "copy_clips",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -759,14 +826,14 @@ fn wire__crate__api__session__cut_clips_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::session::cut_clips(api_track_id, api_clip_ids)?;
+                    let output_ok = crate::api::session::copy_clips(api_track_id, api_clip_ids)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__cut_pattern_notes_impl(
+fn wire__crate__api__session__copy_pattern_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -774,7 +841,7 @@ fn wire__crate__api__session__cut_pattern_notes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cut_pattern_notes",
+            debug_name: "copy_pattern_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -794,14 +861,14 @@ fn wire__crate__api__session__cut_pattern_notes_impl(
             move |context| {
                 transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        crate::api::session::cut_pattern_notes(api_pattern_id, api_note_ids)?;
+                        crate::api::session::copy_pattern_notes(api_pattern_id, api_note_ids)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__delete_bus_impl(
+fn wire__crate__api__mixer__create_bus_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -809,7 +876,7 @@ fn wire__crate__api__mixer__delete_bus_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_bus",
+            debug_name: "create_bus",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -823,18 +890,18 @@ fn wire__crate__api__mixer__delete_bus_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bus_id = <u32>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::delete_bus(api_bus_id)?;
+                    let output_ok = crate::api::mixer::create_bus(api_name)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__delete_clip_impl(
+fn wire__crate__api__track__create_clip_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -842,7 +909,7 @@ fn wire__crate__api__track__delete_clip_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_clip",
+            debug_name: "create_clip",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -856,19 +923,26 @@ fn wire__crate__api__track__delete_clip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_source_type = <crate::api::track::UiSourceType>::sse_decode(&mut deserializer);
             let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_id = <u32>::sse_decode(&mut deserializer);
+            let api_start_time = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::delete_clip(api_track_id, api_clip_id)?;
+                    let output_ok = crate::api::track::create_clip(
+                        api_source_id,
+                        api_source_type,
+                        api_track_id,
+                        api_start_time,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__delete_clip_batch_impl(
+fn wire__crate__api__mixer__create_meter_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -876,7 +950,7 @@ fn wire__crate__api__track__delete_clip_batch_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_clip_batch",
+            debug_name: "create_meter_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -890,20 +964,21 @@ fn wire__crate__api__track__delete_clip_batch_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                Vec<crate::api::mixer::UiMeterLevels>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::track::delete_clip_batch(api_track_id, api_clip_ids)?;
+                    let output_ok = crate::api::mixer::create_meter_stream(api_sink)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__delete_clips_impl(
+fn wire__crate__api__mixer__create_mixer_event_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -911,7 +986,7 @@ fn wire__crate__api__session__delete_clips_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_clips",
+            debug_name: "create_mixer_event_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -925,19 +1000,21 @@ fn wire__crate__api__session__delete_clips_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::mixer::UiMixerParamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::session::delete_clips(api_track_id, api_clip_ids)?;
+                    let output_ok = crate::api::mixer::create_mixer_event_stream(api_sink)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__pattern__delete_note_impl(
+fn wire__crate__api__audio__create_position_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -945,7 +1022,7 @@ fn wire__crate__api__pattern__delete_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_note",
+            debug_name: "create_position_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -959,19 +1036,21 @@ fn wire__crate__api__pattern__delete_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
-            let api_note_id = <u32>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::audio::UiTransportFeedback,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::pattern::delete_note(api_pattern_id, api_note_id)?;
+                    let output_ok = crate::api::audio::create_position_stream(api_sink)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__delete_pattern_notes_impl(
+fn wire__crate__api__analyzer__create_spectrum_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -979,7 +1058,7 @@ fn wire__crate__api__session__delete_pattern_notes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_pattern_notes",
+            debug_name: "create_spectrum_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -993,20 +1072,31 @@ fn wire__crate__api__session__delete_pattern_notes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
-            let api_note_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_node = <crate::api::mixer::UiRoutingNode>::sse_decode(&mut deserializer);
+            let api_fft_size = <u32>::sse_decode(&mut deserializer);
+            let api_overlap = <f32>::sse_decode(&mut deserializer);
+            let api_num_points = <u32>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                Vec<crate::api::plugins::eq::UiResponseCurvePoint>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::session::delete_pattern_notes(api_pattern_id, api_note_ids)?;
+                    let output_ok = crate::api::analyzer::create_spectrum_stream(
+                        api_node,
+                        api_fft_size,
+                        api_overlap,
+                        api_num_points,
+                        api_sink,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__execute_effect_instance_command_impl(
+fn wire__crate__api__track__cut_clip_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1014,7 +1104,7 @@ fn wire__crate__api__plugin__execute_effect_instance_command_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "execute_effect_instance_command",
+            debug_name: "cut_clip",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1028,18 +1118,16 @@ fn wire__crate__api__plugin__execute_effect_instance_command_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
-            let api_effect_id = <u32>::sse_decode(&mut deserializer);
-            let api_command = <String>::sse_decode(&mut deserializer);
-            let api_payload_json = <String>::sse_decode(&mut deserializer);
+            let api_source_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_id = <u32>::sse_decode(&mut deserializer);
+            let api_cut_point_sample = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::execute_effect_instance_command(
-                        api_target,
-                        api_effect_id,
-                        api_command,
-                        api_payload_json,
+                    let output_ok = crate::api::track::cut_clip(
+                        api_source_track_id,
+                        api_clip_id,
+                        api_cut_point_sample,
                     )?;
                     Ok(output_ok)
                 })())
@@ -1047,7 +1135,7 @@ fn wire__crate__api__plugin__execute_effect_instance_command_impl(
         },
     )
 }
-fn wire__crate__api__plugin__execute_generator_instance_command_impl(
+fn wire__crate__api__session__cut_clips_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1055,7 +1143,7 @@ fn wire__crate__api__plugin__execute_generator_instance_command_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "execute_generator_instance_command",
+            debug_name: "cut_clips",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1069,24 +1157,19 @@ fn wire__crate__api__plugin__execute_generator_instance_command_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_generator_id = <u32>::sse_decode(&mut deserializer);
-            let api_command = <String>::sse_decode(&mut deserializer);
-            let api_payload_json = <String>::sse_decode(&mut deserializer);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::execute_generator_instance_command(
-                        api_generator_id,
-                        api_command,
-                        api_payload_json,
-                    )?;
+                    let output_ok = crate::api::session::cut_clips(api_track_id, api_clip_ids)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__execute_plugin_command_effect_impl(
+fn wire__crate__api__session__cut_pattern_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1094,7 +1177,7 @@ fn wire__crate__api__plugin__execute_plugin_command_effect_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "execute_plugin_command_effect",
+            debug_name: "cut_pattern_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1108,25 +1191,20 @@ fn wire__crate__api__plugin__execute_plugin_command_effect_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_effect_registry_id = <u32>::sse_decode(&mut deserializer);
-            let api_command = <String>::sse_decode(&mut deserializer);
-            let api_payload_json = <String>::sse_decode(&mut deserializer);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_ids = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
+                transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::plugin::execute_plugin_command_effect(
-                            api_effect_registry_id,
-                            api_command,
-                            api_payload_json,
-                        ))?;
+                        crate::api::session::cut_pattern_notes(api_pattern_id, api_note_ids)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__execute_plugin_command_generator_impl(
+fn wire__crate__api__mixer__delete_bus_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1134,7 +1212,7 @@ fn wire__crate__api__plugin__execute_plugin_command_generator_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "execute_plugin_command_generator",
+            debug_name: "delete_bus",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1148,25 +1226,18 @@ fn wire__crate__api__plugin__execute_plugin_command_generator_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_gen_registry_id = <u32>::sse_decode(&mut deserializer);
-            let api_command = <String>::sse_decode(&mut deserializer);
-            let api_payload_json = <String>::sse_decode(&mut deserializer);
+            let api_bus_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::plugin::execute_plugin_command_generator(
-                            api_gen_registry_id,
-                            api_command,
-                            api_payload_json,
-                        ))?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::mixer::delete_bus(api_bus_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio__get_audio_config_impl(
+fn wire__crate__api__track__delete_clip_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1174,7 +1245,7 @@ fn wire__crate__api__audio__get_audio_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_audio_config",
+            debug_name: "delete_clip",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1188,17 +1259,19 @@ fn wire__crate__api__audio__get_audio_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio::get_audio_config()?;
+                    let output_ok = crate::api::track::delete_clip(api_track_id, api_clip_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio__get_audio_properties_impl(
+fn wire__crate__api__track__delete_clip_batch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1206,7 +1279,7 @@ fn wire__crate__api__audio__get_audio_properties_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_audio_properties",
+            debug_name: "delete_clip_batch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1220,19 +1293,20 @@ fn wire__crate__api__audio__get_audio_properties_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
+                transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::audio::get_audio_properties(api_id))?;
+                        crate::api::track::delete_clip_batch(api_track_id, api_clip_ids)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__project__get_audio_source_list_impl(
+fn wire__crate__api__session__delete_clips_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1240,7 +1314,7 @@ fn wire__crate__api__project__get_audio_source_list_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_audio_source_list",
+            debug_name: "delete_clips",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1254,18 +1328,19 @@ fn wire__crate__api__project__get_audio_source_list_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::project::get_audio_source_list())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::session::delete_clips(api_track_id, api_clip_ids)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__get_audio_waveform_clips_data_impl(
+fn wire__crate__api__pattern__delete_note_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1273,7 +1348,7 @@ fn wire__crate__api__track__get_audio_waveform_clips_data_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_audio_waveform_clips_data",
+            debug_name: "delete_note",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1287,17 +1362,19 @@ fn wire__crate__api__track__get_audio_waveform_clips_data_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::get_audio_waveform_clips_data()?;
+                    let output_ok = crate::api::pattern::delete_note(api_pattern_id, api_note_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__get_audio_waveform_for_clip_impl(
+fn wire__crate__api__session__delete_pattern_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1305,7 +1382,7 @@ fn wire__crate__api__track__get_audio_waveform_for_clip_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_audio_waveform_for_clip",
+            debug_name: "delete_pattern_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1319,19 +1396,20 @@ fn wire__crate__api__track__get_audio_waveform_for_clip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_audio_source_id = <u32>::sse_decode(&mut deserializer);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_ids = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        crate::api::track::get_audio_waveform_for_clip(api_audio_source_id)?;
+                        crate::api::session::delete_pattern_notes(api_pattern_id, api_note_ids)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__get_audio_waveform_for_clip_all_available_in_tracks_impl(
+fn wire__crate__api__plugin__execute_effect_instance_command_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1339,7 +1417,7 @@ fn wire__crate__api__track__get_audio_waveform_for_clip_all_available_in_tracks_
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_audio_waveform_for_clip_all_available_in_tracks",
+            debug_name: "execute_effect_instance_command",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1353,18 +1431,26 @@ fn wire__crate__api__track__get_audio_waveform_for_clip_all_available_in_tracks_
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
+            let api_effect_id = <u32>::sse_decode(&mut deserializer);
+            let api_command = <String>::sse_decode(&mut deserializer);
+            let api_payload_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::track::get_audio_waveform_for_clip_all_available_in_tracks()?;
+                    let output_ok = crate::api::plugin::execute_effect_instance_command(
+                        api_target,
+                        api_effect_id,
+                        api_command,
+                        api_payload_json,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__get_audio_waveform_for_clip_only_in_specific_track_impl(
+fn wire__crate__api__plugin__execute_generator_instance_command_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1372,7 +1458,7 @@ fn wire__crate__api__track__get_audio_waveform_for_clip_only_in_specific_track_i
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_audio_waveform_for_clip_only_in_specific_track",
+            debug_name: "execute_generator_instance_command",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1386,21 +1472,24 @@ fn wire__crate__api__track__get_audio_waveform_for_clip_only_in_specific_track_i
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            let api_command = <String>::sse_decode(&mut deserializer);
+            let api_payload_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::track::get_audio_waveform_for_clip_only_in_specific_track(
-                            api_track_id,
-                        )?;
+                    let output_ok = crate::api::plugin::execute_generator_instance_command(
+                        api_generator_id,
+                        api_command,
+                        api_payload_json,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_available_effects_with_ids_impl(
+fn wire__crate__api__plugin__execute_plugin_command_effect_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1408,7 +1497,7 @@ fn wire__crate__api__plugin__get_available_effects_with_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_available_effects_with_ids",
+            debug_name: "execute_plugin_command_effect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1422,17 +1511,25 @@ fn wire__crate__api__plugin__get_available_effects_with_ids_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_effect_registry_id = <u32>::sse_decode(&mut deserializer);
+            let api_command = <String>::sse_decode(&mut deserializer);
+            let api_payload_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::get_available_effects_with_ids()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::plugin::execute_plugin_command_effect(
+                            api_effect_registry_id,
+                            api_command,
+                            api_payload_json,
+                        ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_available_generators_with_ids_impl(
+fn wire__crate__api__plugin__execute_plugin_command_generator_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1440,7 +1537,7 @@ fn wire__crate__api__plugin__get_available_generators_with_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_available_generators_with_ids",
+            debug_name: "execute_plugin_command_generator",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1454,17 +1551,25 @@ fn wire__crate__api__plugin__get_available_generators_with_ids_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_gen_registry_id = <u32>::sse_decode(&mut deserializer);
+            let api_command = <String>::sse_decode(&mut deserializer);
+            let api_payload_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::get_available_generators_with_ids()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::plugin::execute_plugin_command_generator(
+                            api_gen_registry_id,
+                            api_command,
+                            api_payload_json,
+                        ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__get_bus_mixer_channel_specs_impl(
+fn wire__crate__api__audio__export_project_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1472,7 +1577,7 @@ fn wire__crate__api__mixer__get_bus_mixer_channel_specs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_bus_mixer_channel_specs",
+            debug_name: "export_project",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1486,12 +1591,17 @@ fn wire__crate__api__mixer__get_bus_mixer_channel_specs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bus_id = <u32>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            let api_bit_per_sample =
+                <crate::api::audio::UiBitPerSample>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::mixer::get_bus_mixer_channel_specs(api_bus_id),
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio::export_project(
+                        api_path,
+                        api_sample_rate,
+                        api_bit_per_sample,
                     )?;
                     Ok(output_ok)
                 })())
@@ -1499,7 +1609,7 @@ fn wire__crate__api__mixer__get_bus_mixer_channel_specs_impl(
         },
     )
 }
-fn wire__crate__api__mixer__get_buses_impl(
+fn wire__crate__api__recording__get_armed_tracks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1507,7 +1617,7 @@ fn wire__crate__api__mixer__get_buses_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_buses",
+            debug_name: "get_armed_tracks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1524,14 +1634,14 @@ fn wire__crate__api__mixer__get_buses_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::mixer::get_buses())?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::recording::get_armed_tracks())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__get_clip_impl(
+fn wire__crate__api__audio__get_audio_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1539,7 +1649,7 @@ fn wire__crate__api__track__get_clip_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_clip",
+            debug_name: "get_audio_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1553,19 +1663,17 @@ fn wire__crate__api__track__get_clip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::get_clip(api_track_id, api_clip_id)?;
+                    let output_ok = crate::api::audio::get_audio_config()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__get_clipboard_contents_impl(
+fn wire__crate__api__audio__get_audio_device_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1573,7 +1681,7 @@ fn wire__crate__api__session__get_clipboard_contents_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_clipboard_contents",
+            debug_name: "get_audio_device_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1591,14 +1699,14 @@ fn wire__crate__api__session__get_clipboard_contents_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::session::get_clipboard_contents())?;
+                        Result::<_, ()>::Ok(crate::api::audio::get_audio_device_settings())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_effect_impl(
+fn wire__crate__api__audio__get_audio_properties_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1606,7 +1714,7 @@ fn wire__crate__api__plugin__get_effect_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_effect",
+            debug_name: "get_audio_properties",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1620,19 +1728,19 @@ fn wire__crate__api__plugin__get_effect_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_effect_id = <u32>::sse_decode(&mut deserializer);
+            let api_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::get_effect(api_track_id, api_effect_id)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::audio::get_audio_properties(api_id))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_effect_from_master_impl(
+fn wire__crate__api__project__get_audio_source_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1640,7 +1748,7 @@ fn wire__crate__api__plugin__get_effect_from_master_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_effect_from_master",
+            debug_name: "get_audio_source_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1654,18 +1762,18 @@ fn wire__crate__api__plugin__get_effect_from_master_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_effect_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::get_effect_from_master(api_effect_id)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::project::get_audio_source_list())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_effect_parameter_specs_impl(
+fn wire__crate__api__track__get_audio_waveform_clips_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1673,7 +1781,7 @@ fn wire__crate__api__plugin__get_effect_parameter_specs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_effect_parameter_specs",
+            debug_name: "get_audio_waveform_clips_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1687,20 +1795,17 @@ fn wire__crate__api__plugin__get_effect_parameter_specs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
-            let api_effect_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::plugin::get_effect_parameter_specs(api_target, api_effect_id)?;
+                    let output_ok = crate::api::track::get_audio_waveform_clips_data()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_effects_from_track_impl(
+fn wire__crate__api__track__get_audio_waveform_for_clip_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1708,7 +1813,7 @@ fn wire__crate__api__plugin__get_effects_from_track_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_effects_from_track",
+            debug_name: "get_audio_waveform_for_clip",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1722,18 +1827,19 @@ fn wire__crate__api__plugin__get_effects_from_track_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_audio_source_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::get_effects_from_track(api_track_id)?;
+                    let output_ok =
+                        crate::api::track::get_audio_waveform_for_clip(api_audio_source_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugins__eq__get_eq_response_curve_impl(
+fn wire__crate__api__track__get_audio_waveform_for_clip_all_available_in_tracks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1741,7 +1847,7 @@ fn wire__crate__api__plugins__eq__get_eq_response_curve_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_eq_response_curve",
+            debug_name: "get_audio_waveform_for_clip_all_available_in_tracks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1755,24 +1861,18 @@ fn wire__crate__api__plugins__eq__get_eq_response_curve_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
-            let api_effect_id = <u32>::sse_decode(&mut deserializer);
-            let api_num_points = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugins::eq::get_eq_response_curve(
-                        api_target,
-                        api_effect_id,
-                        api_num_points,
-                    )?;
+                    let output_ok =
+                        crate::api::track::get_audio_waveform_for_clip_all_available_in_tracks()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_generator_impl(
+fn wire__crate__api__track__get_audio_waveform_for_clip_only_in_specific_track_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1780,7 +1880,7 @@ fn wire__crate__api__plugin__get_generator_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_generator",
+            debug_name: "get_audio_waveform_for_clip_only_in_specific_track",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1794,18 +1894,21 @@ fn wire__crate__api__plugin__get_generator_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::get_generator(api_generator_id)?;
+                    let output_ok =
+                        crate::api::track::get_audio_waveform_for_clip_only_in_specific_track(
+                            api_track_id,
+                        )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__project__get_generator_list_impl(
+fn wire__crate__api__plugin__get_available_effects_with_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1813,7 +1916,7 @@ fn wire__crate__api__project__get_generator_list_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_generator_list",
+            debug_name: "get_available_effects_with_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1830,14 +1933,14 @@ fn wire__crate__api__project__get_generator_list_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::project::get_generator_list()?;
+                    let output_ok = crate::api::plugin::get_available_effects_with_ids()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_generator_parameter_impl(
+fn wire__crate__api__plugin__get_available_generators_with_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1845,7 +1948,7 @@ fn wire__crate__api__plugin__get_generator_parameter_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_generator_parameter",
+            debug_name: "get_available_generators_with_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1859,22 +1962,17 @@ fn wire__crate__api__plugin__get_generator_parameter_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_generator_id = <u32>::sse_decode(&mut deserializer);
-            let api_param_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::get_generator_parameter(
-                        api_generator_id,
-                        api_param_id,
-                    )?;
+                    let output_ok = crate::api::plugin::get_available_generators_with_ids()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_generator_parameter_specs_impl(
+fn wire__crate__api__mixer__get_bus_mixer_channel_specs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1882,7 +1980,7 @@ fn wire__crate__api__plugin__get_generator_parameter_specs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_generator_parameter_specs",
+            debug_name: "get_bus_mixer_channel_specs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1896,19 +1994,20 @@ fn wire__crate__api__plugin__get_generator_parameter_specs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            let api_bus_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::plugin::get_generator_parameter_specs(api_generator_id)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::mixer::get_bus_mixer_channel_specs(api_bus_id),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__get_master_bus_impl(
+fn wire__crate__api__mixer__get_buses_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1916,7 +2015,7 @@ fn wire__crate__api__mixer__get_master_bus_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_master_bus",
+            debug_name: "get_buses",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1933,14 +2032,14 @@ fn wire__crate__api__mixer__get_master_bus_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::mixer::get_master_bus())?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::mixer::get_buses())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__get_master_bus_populated_impl(
+fn wire__crate__api__track__get_clip_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1948,7 +2047,7 @@ fn wire__crate__api__mixer__get_master_bus_populated_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_master_bus_populated",
+            debug_name: "get_clip",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1962,18 +2061,19 @@ fn wire__crate__api__mixer__get_master_bus_populated_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::mixer::get_master_bus_populated())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::track::get_clip(api_track_id, api_clip_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__get_master_channel_specs_impl(
+fn wire__crate__api__session__get_clipboard_contents_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1981,7 +2081,7 @@ fn wire__crate__api__mixer__get_master_channel_specs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_master_channel_specs",
+            debug_name: "get_clipboard_contents",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1999,14 +2099,14 @@ fn wire__crate__api__mixer__get_master_channel_specs_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::mixer::get_master_channel_specs())?;
+                        Result::<_, ()>::Ok(crate::api::session::get_clipboard_contents())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__get_master_effects_impl(
+fn wire__crate__api__plugin__get_effect_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2014,7 +2114,7 @@ fn wire__crate__api__plugin__get_master_effects_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_master_effects",
+            debug_name: "get_effect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2028,17 +2128,19 @@ fn wire__crate__api__plugin__get_master_effects_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_effect_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::plugin::get_master_effects())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::plugin::get_effect(api_track_id, api_effect_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__project__get_max_sample_index_impl(
+fn wire__crate__api__plugin__get_effect_from_master_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2046,7 +2148,7 @@ fn wire__crate__api__project__get_max_sample_index_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_max_sample_index",
+            debug_name: "get_effect_from_master",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2060,17 +2162,18 @@ fn wire__crate__api__project__get_max_sample_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_effect_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::project::get_max_sample_index()?;
+                    let output_ok = crate::api::plugin::get_effect_from_master(api_effect_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__get_mixer_channel_impl(
+fn wire__crate__api__plugin__get_effect_parameter_specs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2078,7 +2181,7 @@ fn wire__crate__api__mixer__get_mixer_channel_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_mixer_channel",
+            debug_name: "get_effect_parameter_specs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2092,18 +2195,20 @@ fn wire__crate__api__mixer__get_mixer_channel_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
+            let api_effect_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::get_mixer_channel(api_track_id)?;
+                    let output_ok =
+                        crate::api::plugin::get_effect_parameter_specs(api_target, api_effect_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__get_mixer_channel_populated_impl(
+fn wire__crate__api__plugin__get_effects_from_track_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2111,7 +2216,7 @@ fn wire__crate__api__mixer__get_mixer_channel_populated_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_mixer_channel_populated",
+            debug_name: "get_effects_from_track",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2129,14 +2234,14 @@ fn wire__crate__api__mixer__get_mixer_channel_populated_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::get_mixer_channel_populated(api_track_id)?;
+                    let output_ok = crate::api::plugin::get_effects_from_track(api_track_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__get_mixer_state_impl(
+fn wire__crate__api__audio__get_engine_load_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2144,7 +2249,7 @@ fn wire__crate__api__mixer__get_mixer_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_mixer_state",
+            debug_name: "get_engine_load",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2161,14 +2266,14 @@ fn wire__crate__api__mixer__get_mixer_state_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::mixer::get_mixer_state())?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::audio::get_engine_load())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__pattern__get_pattern_impl(
+fn wire__crate__api__plugins__eq__get_eq_response_curve_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2176,7 +2281,7 @@ fn wire__crate__api__pattern__get_pattern_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_pattern",
+            debug_name: "get_eq_response_curve",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2190,18 +2295,24 @@ fn wire__crate__api__pattern__get_pattern_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
+            let api_effect_id = <u32>::sse_decode(&mut deserializer);
+            let api_num_points = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::pattern::get_pattern(api_pattern_id)?;
+                    let output_ok = crate::api::plugins::eq::get_eq_response_curve(
+                        api_target,
+                        api_effect_id,
+                        api_num_points,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__pattern__get_patterns_impl(
+fn wire__crate__api__plugin__get_generator_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2209,7 +2320,7 @@ fn wire__crate__api__pattern__get_patterns_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_patterns",
+            debug_name: "get_generator",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2223,17 +2334,18 @@ fn wire__crate__api__pattern__get_patterns_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_generator_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::pattern::get_patterns()?;
+                    let output_ok = crate::api::plugin::get_generator(api_generator_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__project__get_project_metadata_impl(
+fn wire__crate__api__project__get_generator_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2241,7 +2353,7 @@ fn wire__crate__api__project__get_project_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_project_metadata",
+            debug_name: "get_generator_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2258,14 +2370,14 @@ fn wire__crate__api__project__get_project_metadata_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::project::get_project_metadata()?;
+                    let output_ok = crate::api::project::get_generator_list()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__get_routing_matrix_impl(
+fn wire__crate__api__plugin__get_generator_parameter_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2273,7 +2385,7 @@ fn wire__crate__api__mixer__get_routing_matrix_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_routing_matrix",
+            debug_name: "get_generator_parameter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2287,17 +2399,22 @@ fn wire__crate__api__mixer__get_routing_matrix_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            let api_param_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::mixer::get_routing_matrix())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::plugin::get_generator_parameter(
+                        api_generator_id,
+                        api_param_id,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__get_track_impl(
+fn wire__crate__api__plugin__get_generator_parameter_specs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2305,7 +2422,7 @@ fn wire__crate__api__track__get_track_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_track",
+            debug_name: "get_generator_parameter_specs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2319,18 +2436,19 @@ fn wire__crate__api__track__get_track_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_generator_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::get_track(api_track_id)?;
+                    let output_ok =
+                        crate::api::plugin::get_generator_parameter_specs(api_generator_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__get_track_mixer_channel_specs_impl(
+fn wire__crate__api__transport__get_loop_region_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2338,7 +2456,7 @@ fn wire__crate__api__mixer__get_track_mixer_channel_specs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_track_mixer_channel_specs",
+            debug_name: "get_loop_region",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2352,20 +2470,17 @@ fn wire__crate__api__mixer__get_track_mixer_channel_specs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::mixer::get_track_mixer_channel_specs(api_track_id),
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::transport::get_loop_region())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__project__get_tracks_impl(
+fn wire__crate__api__audio__get_loudness_report_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2373,7 +2488,7 @@ fn wire__crate__api__project__get_tracks_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_tracks",
+            debug_name: "get_loudness_report",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2389,15 +2504,15 @@ fn wire__crate__api__project__get_tracks_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::project::get_tracks()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::audio::get_loudness_report())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__project__get_transport_state_impl(
+fn wire__crate__api__mixer__get_master_bus_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2405,7 +2520,7 @@ fn wire__crate__api__project__get_transport_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_transport_state",
+            debug_name: "get_master_bus",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2421,24 +2536,25 @@ fn wire__crate__api__project__get_transport_state_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::project::get_transport_state()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::mixer::get_master_bus())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__mixer__get_master_bus_populated_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_master_bus_populated",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2450,16 +2566,18 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::mixer::get_master_bus_populated())?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__mixer__get_master_channel_specs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2467,7 +2585,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "get_master_channel_specs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2484,16 +2602,15 @@ fn wire__crate__api__simple__init_app_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::mixer::get_master_channel_specs())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__serialization__load_project_impl(
+fn wire__crate__api__plugin__get_master_effects_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2501,7 +2618,7 @@ fn wire__crate__api__serialization__load_project_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_project",
+            debug_name: "get_master_effects",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2515,18 +2632,17 @@ fn wire__crate__api__serialization__load_project_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::serialization::load_project(&api_path_name)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::plugin::get_master_effects())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__move_clip_impl(
+fn wire__crate__api__project__get_max_sample_index_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2534,7 +2650,7 @@ fn wire__crate__api__session__move_clip_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "move_clip",
+            debug_name: "get_max_sample_index",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2548,26 +2664,17 @@ fn wire__crate__api__session__move_clip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_old_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_new_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_id = <u32>::sse_decode(&mut deserializer);
-            let api_new_start_time = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::session::move_clip(
-                        api_old_track_id,
-                        api_new_track_id,
-                        api_clip_id,
-                        api_new_start_time,
-                    )?;
+                    let output_ok = crate::api::project::get_max_sample_index()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__move_clip_impl(
+fn wire__crate__api__transport__get_metronome_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2575,7 +2682,7 @@ fn wire__crate__api__track__move_clip_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "move_clip",
+            debug_name: "get_metronome_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2589,26 +2696,18 @@ fn wire__crate__api__track__move_clip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_id = <u32>::sse_decode(&mut deserializer);
-            let api_new_start_time = <u32>::sse_decode(&mut deserializer);
-            let api_new_track_id = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::move_clip(
-                        api_source_track_id,
-                        api_clip_id,
-                        api_new_start_time,
-                        api_new_track_id,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::transport::get_metronome_settings())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__move_clip_batch_impl(
+fn wire__crate__api__midi__get_midi_backend_name_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2616,7 +2715,7 @@ fn wire__crate__api__track__move_clip_batch_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "move_clip_batch",
+            debug_name: "get_midi_backend_name",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2630,26 +2729,17 @@ fn wire__crate__api__track__move_clip_batch_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
-            let api_delta_samples = <i64>::sse_decode(&mut deserializer);
-            let api_new_track_id = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::move_clip_batch(
-                        api_source_track_id,
-                        api_clip_ids,
-                        api_delta_samples,
-                        api_new_track_id,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::midi::get_midi_backend_name())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__pattern__move_note_impl(
+fn wire__crate__api__midi__get_midi_output_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2657,7 +2747,7 @@ fn wire__crate__api__pattern__move_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "move_note",
+            debug_name: "get_midi_output_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2671,35 +2761,28 @@ fn wire__crate__api__pattern__move_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
-            let api_note_id = <u32>::sse_decode(&mut deserializer);
-            let api_new_start_tick = <u64>::sse_decode(&mut deserializer);
-            let api_new_key = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::pattern::move_note(
-                        api_pattern_id,
-                        api_note_id,
-                        api_new_start_tick,
-                        api_new_key,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::midi::get_midi_output_settings())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugins__eq__parse_eq_curve_response_impl(
+fn wire__crate__api__recording__get_midi_record_mode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_eq_curve_response",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_midi_record_mode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2711,16 +2794,18 @@ fn wire__crate__api__plugins__eq__parse_eq_curve_response_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_json_str = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::plugins::eq::parse_eq_curve_response(api_json_str)?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::recording::get_midi_record_mode())?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__session__paste_clips_impl(
+fn wire__crate__api__recording__get_midi_record_quantize_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2728,7 +2813,7 @@ fn wire__crate__api__session__paste_clips_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "paste_clips",
+            debug_name: "get_midi_record_quantize",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2742,22 +2827,18 @@ fn wire__crate__api__session__paste_clips_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_target_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_paste_start_time = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::session::paste_clips(
-                        api_target_track_id,
-                        api_paste_start_time,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::recording::get_midi_record_quantize())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__paste_pattern_notes_impl(
+fn wire__crate__api__audio__get_mix_latency_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2765,7 +2846,7 @@ fn wire__crate__api__session__paste_pattern_notes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "paste_pattern_notes",
+            debug_name: "get_mix_latency",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2779,22 +2860,17 @@ fn wire__crate__api__session__paste_pattern_notes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_target_pattern_id = <u32>::sse_decode(&mut deserializer);
-            let api_playhead_tick = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::session::paste_pattern_notes(
-                        api_target_pattern_id,
-                        api_playhead_tick,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::audio::get_mix_latency())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__pattern__play_pattern_preview_impl(
+fn wire__crate__api__mixer__get_mixer_channel_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2802,7 +2878,7 @@ fn wire__crate__api__pattern__play_pattern_preview_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "play_pattern_preview",
+            debug_name: "get_mixer_channel",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2816,22 +2892,18 @@ fn wire__crate__api__pattern__play_pattern_preview_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
-            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::pattern::play_pattern_preview(
-                        api_pattern_id,
-                        api_generator_id,
-                    )?;
+                    let output_ok = crate::api::mixer::get_mixer_channel(api_track_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio__play_preview_note_impl(
+fn wire__crate__api__mixer__get_mixer_channel_populated_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2839,7 +2911,7 @@ fn wire__crate__api__audio__play_preview_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "play_preview_note",
+            debug_name: "get_mixer_channel_populated",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2854,25 +2926,17 @@ fn wire__crate__api__audio__play_preview_note_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_note_key = <i32>::sse_decode(&mut deserializer);
-            let api_velocity = <i32>::sse_decode(&mut deserializer);
-            let api_is_on = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio::play_preview_note(
-                        api_track_id,
-                        api_note_key,
-                        api_velocity,
-                        api_is_on,
-                    )?;
+                    let output_ok = crate::api::mixer::get_mixer_channel_populated(api_track_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio__play_preview_note_generator_impl(
+fn wire__crate__api__mixer__get_mixer_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2880,7 +2944,7 @@ fn wire__crate__api__audio__play_preview_note_generator_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "play_preview_note_generator",
+            debug_name: "get_mixer_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2894,26 +2958,17 @@ fn wire__crate__api__audio__play_preview_note_generator_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_generator_id = <u32>::sse_decode(&mut deserializer);
-            let api_note_key = <i32>::sse_decode(&mut deserializer);
-            let api_velocity = <i32>::sse_decode(&mut deserializer);
-            let api_is_on = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio::play_preview_note_generator(
-                        api_generator_id,
-                        api_note_key,
-                        api_velocity,
-                        api_is_on,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::mixer::get_mixer_state())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio__play_source_preview_impl(
+fn wire__crate__api__midi__get_open_midi_input_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2921,7 +2976,7 @@ fn wire__crate__api__audio__play_source_preview_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "play_source_preview",
+            debug_name: "get_open_midi_input",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2935,20 +2990,17 @@ fn wire__crate__api__audio__play_source_preview_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::audio::play_source_preview(api_id);
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::midi::get_open_midi_input())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__poll_effect_parameter_feedback_impl(
+fn wire__crate__api__midi__get_open_midi_output_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2956,7 +3008,7 @@ fn wire__crate__api__plugin__poll_effect_parameter_feedback_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "poll_effect_parameter_feedback",
+            debug_name: "get_open_midi_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2973,15 +3025,14 @@ fn wire__crate__api__plugin__poll_effect_parameter_feedback_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::plugin::poll_effect_parameter_feedback())?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::midi::get_open_midi_output())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__poll_generator_parameter_feedback_impl(
+fn wire__crate__api__pattern__get_out_of_key_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2989,7 +3040,7 @@ fn wire__crate__api__plugin__poll_generator_parameter_feedback_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "poll_generator_parameter_feedback",
+            debug_name: "get_out_of_key_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3003,28 +3054,28 @@ fn wire__crate__api__plugin__poll_generator_parameter_feedback_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::plugin::poll_generator_parameter_feedback(),
-                    )?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pattern::get_out_of_key_notes(api_pattern_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__project__project_metadata_new_impl(
+fn wire__crate__api__pattern__get_pattern_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "project_metadata_new",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_pattern",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -3036,15 +3087,18 @@ fn wire__crate__api__project__project_metadata_new_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::project::project_metadata_new())?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pattern::get_pattern(api_pattern_id)?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__plugin__query_effect_parameters_impl(
+fn wire__crate__api__pattern__get_pattern_groove_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3052,7 +3106,7 @@ fn wire__crate__api__plugin__query_effect_parameters_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "query_effect_parameters",
+            debug_name: "get_pattern_groove",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3066,20 +3120,18 @@ fn wire__crate__api__plugin__query_effect_parameters_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
-            let api_effect_id = <u32>::sse_decode(&mut deserializer);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::plugin::query_effect_parameters(api_target, api_effect_id)?;
+                    let output_ok = crate::api::pattern::get_pattern_groove(api_pattern_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__query_generator_parameters_impl(
+fn wire__crate__api__pattern__get_pattern_scale_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3087,7 +3139,7 @@ fn wire__crate__api__plugin__query_generator_parameters_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "query_generator_parameters",
+            debug_name: "get_pattern_scale",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3101,19 +3153,18 @@ fn wire__crate__api__plugin__query_generator_parameters_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::plugin::query_generator_parameters(api_generator_id)?;
+                    let output_ok = crate::api::pattern::get_pattern_scale(api_pattern_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__redo_impl(
+fn wire__crate__api__pattern__get_patterns_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3121,7 +3172,7 @@ fn wire__crate__api__session__redo_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "redo",
+            debug_name: "get_patterns",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3138,14 +3189,14 @@ fn wire__crate__api__session__redo_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::session::redo()?;
+                    let output_ok = crate::api::pattern::get_patterns()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__remove_effect_from_master_bus_impl(
+fn wire__crate__api__project__get_project_metadata_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3153,7 +3204,7 @@ fn wire__crate__api__mixer__remove_effect_from_master_bus_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_effect_from_master_bus",
+            debug_name: "get_project_metadata",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3167,19 +3218,17 @@ fn wire__crate__api__mixer__remove_effect_from_master_bus_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_effect_instance_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::mixer::remove_effect_from_master_bus(api_effect_instance_id)?;
+                    let output_ok = crate::api::project::get_project_metadata()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__remove_effect_from_mixer_channel_impl(
+fn wire__crate__api__transport__get_random_seed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3187,7 +3236,7 @@ fn wire__crate__api__mixer__remove_effect_from_mixer_channel_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_effect_from_mixer_channel",
+            debug_name: "get_random_seed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3201,22 +3250,17 @@ fn wire__crate__api__mixer__remove_effect_from_mixer_channel_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_effect_instance_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::remove_effect_from_mixer_channel(
-                        api_track_id,
-                        api_effect_instance_id,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::transport::get_random_seed())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__remove_routing_impl(
+fn wire__crate__api__mixer__get_routing_matrix_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3224,7 +3268,7 @@ fn wire__crate__api__mixer__remove_routing_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_routing",
+            debug_name: "get_routing_matrix",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3238,24 +3282,17 @@ fn wire__crate__api__mixer__remove_routing_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source = <crate::api::mixer::UiRoutingNode>::sse_decode(&mut deserializer);
-            let api_destination = <crate::api::mixer::UiRoutingNode>::sse_decode(&mut deserializer);
-            let api_is_send = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::remove_routing(
-                        api_source,
-                        api_destination,
-                        api_is_send,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::mixer::get_routing_matrix())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__rename_bus_impl(
+fn wire__crate__api__transport__get_tempo_points_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3263,7 +3300,7 @@ fn wire__crate__api__mixer__rename_bus_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rename_bus",
+            debug_name: "get_tempo_points",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3277,19 +3314,17 @@ fn wire__crate__api__mixer__rename_bus_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bus_id = <u32>::sse_decode(&mut deserializer);
-            let api_new_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::rename_bus(api_bus_id, api_new_name)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::transport::get_tempo_points())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__session__resize_clip_impl(
+fn wire__crate__api__transport__get_time_signatures_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3297,7 +3332,7 @@ fn wire__crate__api__session__resize_clip_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "resize_clip",
+            debug_name: "get_time_signatures",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3311,26 +3346,18 @@ fn wire__crate__api__session__resize_clip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_id = <u32>::sse_decode(&mut deserializer);
-            let api_edge = <crate::api::track::UiResizeEdge>::sse_decode(&mut deserializer);
-            let api_new_time_val = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::session::resize_clip(
-                        api_track_id,
-                        api_clip_id,
-                        api_edge,
-                        api_new_time_val,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::transport::get_time_signatures())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__resize_clip_impl(
+fn wire__crate__api__track__get_track_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3338,7 +3365,7 @@ fn wire__crate__api__track__resize_clip_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "resize_clip",
+            debug_name: "get_track",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3353,25 +3380,17 @@ fn wire__crate__api__track__resize_clip_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_id = <u32>::sse_decode(&mut deserializer);
-            let api_edge = <crate::api::track::UiResizeEdge>::sse_decode(&mut deserializer);
-            let api_new_time_val = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::resize_clip(
-                        api_track_id,
-                        api_clip_id,
-                        api_edge,
-                        api_new_time_val,
-                    )?;
+                    let output_ok = crate::api::track::get_track(api_track_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__track__resize_clip_batch_impl(
+fn wire__crate__api__mixer__get_track_mixer_channel_specs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3379,7 +3398,7 @@ fn wire__crate__api__track__resize_clip_batch_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "resize_clip_batch",
+            debug_name: "get_track_mixer_channel_specs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3394,17 +3413,11 @@ fn wire__crate__api__track__resize_clip_batch_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
-            let api_edge = <crate::api::track::UiResizeEdge>::sse_decode(&mut deserializer);
-            let api_delta_samples = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::track::resize_clip_batch(
-                        api_track_id,
-                        api_clip_ids,
-                        api_edge,
-                        api_delta_samples,
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::mixer::get_track_mixer_channel_specs(api_track_id),
                     )?;
                     Ok(output_ok)
                 })())
@@ -3412,7 +3425,7 @@ fn wire__crate__api__track__resize_clip_batch_impl(
         },
     )
 }
-fn wire__crate__api__pattern__resize_note_impl(
+fn wire__crate__api__recording__get_track_monitor_mode_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3420,7 +3433,7 @@ fn wire__crate__api__pattern__resize_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "resize_note",
+            debug_name: "get_track_monitor_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3434,24 +3447,18 @@ fn wire__crate__api__pattern__resize_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
-            let api_note_id = <u32>::sse_decode(&mut deserializer);
-            let api_new_duration = <u64>::sse_decode(&mut deserializer);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::pattern::resize_note(
-                        api_pattern_id,
-                        api_note_id,
-                        api_new_duration,
-                    )?;
+                    let output_ok = crate::api::recording::get_track_monitor_mode(api_track_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__serialization__save_project_impl(
+fn wire__crate__api__project__get_tracks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3459,7 +3466,7 @@ fn wire__crate__api__serialization__save_project_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_project",
+            debug_name: "get_tracks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3473,18 +3480,17 @@ fn wire__crate__api__serialization__save_project_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::serialization::save_project(&api_path_name)?;
+                    let output_ok = crate::api::project::get_tracks()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__transport__set_bpm_impl(
+fn wire__crate__api__project__get_transport_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3492,7 +3498,7 @@ fn wire__crate__api__transport__set_bpm_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_bpm",
+            debug_name: "get_transport_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3506,28 +3512,26 @@ fn wire__crate__api__transport__set_bpm_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_val = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transport::set_bpm(api_val)?;
+                    let output_ok = crate::api::project::get_transport_state()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__set_bus_params_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__greet_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_bus_params",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3539,20 +3543,2447 @@ fn wire__crate__api__mixer__set_bus_params_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bus_id = <u32>::sse_decode(&mut deserializer);
-            let api_params =
-                <Vec<crate::api::mixer::UiMixerChannelParams>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::set_bus_params(api_bus_id, api_params)?;
-                    Ok(output_ok)
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__pattern__groove_from_midi_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "groove_from_midi_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_step_ticks = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::pattern::groove_from_midi_file(api_path, api_step_ticks)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pattern__groove_from_pattern_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "groove_from_pattern",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_step_ticks = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::pattern::groove_from_pattern(api_pattern_id, api_step_ticks)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__is_plugin_profiling_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_plugin_profiling",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::audio::is_plugin_profiling())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__is_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::recording::is_recording())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__list_audio_hosts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_audio_hosts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::audio::list_audio_hosts())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__list_audio_input_devices_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_audio_input_devices",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio::list_audio_input_devices(api_host)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__list_audio_output_devices_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_audio_output_devices",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio::list_audio_output_devices(api_host)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__list_input_devices_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_input_devices",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::recording::list_input_devices()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__midi__list_midi_inputs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_midi_inputs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::midi::list_midi_inputs()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__midi__list_midi_outputs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_midi_outputs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::midi::list_midi_outputs()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__serialization__load_project_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_project",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::serialization::load_project(&api_path_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__session__move_clip_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_clip",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_old_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_new_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_id = <u32>::sse_decode(&mut deserializer);
+            let api_new_start_time = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::session::move_clip(
+                        api_old_track_id,
+                        api_new_track_id,
+                        api_clip_id,
+                        api_new_start_time,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__track__move_clip_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_clip",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_id = <u32>::sse_decode(&mut deserializer);
+            let api_new_start_time = <u32>::sse_decode(&mut deserializer);
+            let api_new_track_id = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::track::move_clip(
+                        api_source_track_id,
+                        api_clip_id,
+                        api_new_start_time,
+                        api_new_track_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__track__move_clip_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_clip_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_delta_samples = <i64>::sse_decode(&mut deserializer);
+            let api_new_track_id = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::track::move_clip_batch(
+                        api_source_track_id,
+                        api_clip_ids,
+                        api_delta_samples,
+                        api_new_track_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pattern__move_note_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_note",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_id = <u32>::sse_decode(&mut deserializer);
+            let api_new_start_tick = <u64>::sse_decode(&mut deserializer);
+            let api_new_key = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pattern::move_note(
+                        api_pattern_id,
+                        api_note_id,
+                        api_new_start_tick,
+                        api_new_key,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__midi__open_midi_input_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_midi_input",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_port = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::midi::open_midi_input(api_port)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__midi__open_midi_output_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_midi_output",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_port = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::midi::open_midi_output(api_port)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__plugins__eq__parse_eq_curve_response_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_eq_curve_response",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_json_str = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::plugins::eq::parse_eq_curve_response(api_json_str)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__session__paste_clips_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "paste_clips",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_paste_start_time = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::session::paste_clips(
+                        api_target_track_id,
+                        api_paste_start_time,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__session__paste_pattern_notes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "paste_pattern_notes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_playhead_tick = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::session::paste_pattern_notes(
+                        api_target_pattern_id,
+                        api_playhead_tick,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pattern__play_pattern_preview_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "play_pattern_preview",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pattern::play_pattern_preview(
+                        api_pattern_id,
+                        api_generator_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__play_preview_note_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "play_preview_note",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_key = <i32>::sse_decode(&mut deserializer);
+            let api_velocity = <i32>::sse_decode(&mut deserializer);
+            let api_is_on = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio::play_preview_note(
+                        api_track_id,
+                        api_note_key,
+                        api_velocity,
+                        api_is_on,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__play_preview_note_generator_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "play_preview_note_generator",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_key = <i32>::sse_decode(&mut deserializer);
+            let api_velocity = <i32>::sse_decode(&mut deserializer);
+            let api_is_on = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio::play_preview_note_generator(
+                        api_generator_id,
+                        api_note_key,
+                        api_velocity,
+                        api_is_on,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__play_source_preview_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "play_source_preview",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::audio::play_source_preview(api_id);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__plugin__poll_effect_parameter_feedback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "poll_effect_parameter_feedback",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::plugin::poll_effect_parameter_feedback())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__plugin__poll_generator_parameter_feedback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "poll_generator_parameter_feedback",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::plugin::poll_generator_parameter_feedback(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__project__project_metadata_new_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "project_metadata_new",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::project::project_metadata_new())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__pattern__quantize_notes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "quantize_notes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_settings =
+                <crate::api::pattern::UiQuantizeSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pattern::quantize_notes(
+                        api_pattern_id,
+                        api_note_ids,
+                        api_settings,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__plugin__query_effect_parameters_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_effect_parameters",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
+            let api_effect_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::plugin::query_effect_parameters(api_target, api_effect_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__plugin__query_generator_parameters_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_generator_parameters",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::plugin::query_generator_parameters(api_generator_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__session__redo_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "redo",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::session::redo()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mixer__remove_effect_from_master_bus_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_effect_from_master_bus",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_effect_instance_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::mixer::remove_effect_from_master_bus(api_effect_instance_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mixer__remove_effect_from_mixer_channel_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_effect_from_mixer_channel",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_effect_instance_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::mixer::remove_effect_from_mixer_channel(
+                        api_track_id,
+                        api_effect_instance_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mixer__remove_routing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_routing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source = <crate::api::mixer::UiRoutingNode>::sse_decode(&mut deserializer);
+            let api_destination = <crate::api::mixer::UiRoutingNode>::sse_decode(&mut deserializer);
+            let api_is_send = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::mixer::remove_routing(
+                        api_source,
+                        api_destination,
+                        api_is_send,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__remove_tempo_point_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_tempo_point",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tick = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::remove_tempo_point(api_tick)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__remove_time_signature_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_time_signature",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bar = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::remove_time_signature(api_bar)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mixer__rename_bus_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rename_bus",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bus_id = <u32>::sse_decode(&mut deserializer);
+            let api_new_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::mixer::rename_bus(api_bus_id, api_new_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__reset_engine_load_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_engine_load",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::audio::reset_engine_load();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__reset_loudness_analyzer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_loudness_analyzer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::audio::reset_loudness_analyzer();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__session__resize_clip_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resize_clip",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_id = <u32>::sse_decode(&mut deserializer);
+            let api_edge = <crate::api::track::UiResizeEdge>::sse_decode(&mut deserializer);
+            let api_new_time_val = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::session::resize_clip(
+                        api_track_id,
+                        api_clip_id,
+                        api_edge,
+                        api_new_time_val,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__track__resize_clip_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resize_clip",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_id = <u32>::sse_decode(&mut deserializer);
+            let api_edge = <crate::api::track::UiResizeEdge>::sse_decode(&mut deserializer);
+            let api_new_time_val = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::track::resize_clip(
+                        api_track_id,
+                        api_clip_id,
+                        api_edge,
+                        api_new_time_val,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__track__resize_clip_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resize_clip_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_edge = <crate::api::track::UiResizeEdge>::sse_decode(&mut deserializer);
+            let api_delta_samples = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::track::resize_clip_batch(
+                        api_track_id,
+                        api_clip_ids,
+                        api_edge,
+                        api_delta_samples,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pattern__resize_note_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resize_note",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_id = <u32>::sse_decode(&mut deserializer);
+            let api_new_duration = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pattern::resize_note(
+                        api_pattern_id,
+                        api_note_id,
+                        api_new_duration,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__samples_to_ticks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "samples_to_ticks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sample = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::transport::samples_to_ticks(api_sample))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__serialization__save_project_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_project",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::serialization::save_project(&api_path_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__set_audio_device_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_audio_device_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::api::audio::UiAudioDeviceSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio::set_audio_device_settings(api_settings)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_bpm_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_bpm",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_val = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_bpm(api_val)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mixer__set_bus_params_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_bus_params",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bus_id = <u32>::sse_decode(&mut deserializer);
+            let api_params =
+                <Vec<crate::api::mixer::UiMixerChannelParams>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::mixer::set_bus_params(api_bus_id, api_params)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pattern__set_clip_groove_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_clip_groove",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_clip_id = <u32>::sse_decode(&mut deserializer);
+            let api_groove = <Option<crate::api::pattern::UiGroove>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pattern::set_clip_groove(
+                        api_track_id,
+                        api_clip_id,
+                        api_groove,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_count_in_bars_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_count_in_bars",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bars = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_count_in_bars(api_bars)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__plugin__set_effect_parameter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_effect_parameter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
+            let api_effect_id = <u32>::sse_decode(&mut deserializer);
+            let api_param_id = <u32>::sse_decode(&mut deserializer);
+            let api_value = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::plugin::set_effect_parameter(
+                        api_target,
+                        api_effect_id,
+                        api_param_id,
+                        api_value,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__plugin__set_generator_parameter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_generator_parameter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_generator_id = <u32>::sse_decode(&mut deserializer);
+            let api_param_id = <u32>::sse_decode(&mut deserializer);
+            let api_value = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::plugin::set_generator_parameter(
+                        api_generator_id,
+                        api_param_id,
+                        api_value,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__set_input_device_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_input_device",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::recording::set_input_device(api_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_loop_region_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_loop_region",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_start = <u32>::sse_decode(&mut deserializer);
+            let api_end = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_loop_region(api_start, api_end)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_looping_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_looping",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_val = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_looping(api_val)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mixer__set_master_bus_params_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_master_bus_params",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_params =
+                <Vec<crate::api::mixer::UiMixerChannelParams>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::mixer::set_master_bus_params(api_params)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_metronome_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_metronome_enabled",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_val = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_metronome_enabled(api_val)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_metronome_target_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_metronome_target",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bus_id = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_metronome_target(api_bus_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_metronome_volume_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_metronome_volume",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_val = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_metronome_volume(api_val)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__midi__set_midi_input_track_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_midi_input_track",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::midi::set_midi_input_track(api_track_id);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__midi__set_midi_output_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_midi_output_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::api::midi::UiMidiOutputSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::midi::set_midi_output_settings(api_settings)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__set_midi_record_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_midi_record_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mode = <crate::api::recording::UiMidiRecordMode>::sse_decode(&mut deserializer);
+            let api_quantize_ticks = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::recording::set_midi_record_settings(
+                            api_mode,
+                            api_quantize_ticks,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mixer__set_mixer_channel_params_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_mixer_channel_params",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_params =
+                <Vec<crate::api::mixer::UiMixerChannelParams>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::mixer::set_mixer_channel_params(api_track_id, api_params)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pattern__set_pattern_groove_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_pattern_groove",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_groove = <Option<crate::api::pattern::UiGroove>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::pattern::set_pattern_groove(api_pattern_id, api_groove)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pattern__set_pattern_scale_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_pattern_scale",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_root = <u8>::sse_decode(&mut deserializer);
+            let api_mode =
+                <Option<crate::api::pattern::UiScaleMode>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::pattern::set_pattern_scale(api_pattern_id, api_root, api_mode)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_playhead_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_playhead",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_val = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_playhead(api_val)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_playing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_playing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_val = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_playing(api_val)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__set_plugin_profiling_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_plugin_profiling",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::audio::set_plugin_profiling(api_enabled);
+                    })?;
+                    Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__set_effect_parameter_impl(
+fn wire__crate__api__transport__set_random_seed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3560,7 +5991,7 @@ fn wire__crate__api__plugin__set_effect_parameter_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_effect_parameter",
+            debug_name: "set_random_seed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3574,18 +6005,51 @@ fn wire__crate__api__plugin__set_effect_parameter_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_target = <crate::api::plugin::UiEffectTarget>::sse_decode(&mut deserializer);
-            let api_effect_id = <u32>::sse_decode(&mut deserializer);
-            let api_param_id = <u32>::sse_decode(&mut deserializer);
-            let api_value = <f32>::sse_decode(&mut deserializer);
+            let api_seed = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::set_effect_parameter(
-                        api_target,
-                        api_effect_id,
-                        api_param_id,
-                        api_value,
+                    let output_ok = crate::api::transport::set_random_seed(api_seed)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mixer__set_routing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_routing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source = <crate::api::mixer::UiRoutingNode>::sse_decode(&mut deserializer);
+            let api_destination = <crate::api::mixer::UiRoutingNode>::sse_decode(&mut deserializer);
+            let api_send_level = <f32>::sse_decode(&mut deserializer);
+            let api_is_send = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::mixer::set_routing(
+                        api_source,
+                        api_destination,
+                        api_send_level,
+                        api_is_send,
                     )?;
                     Ok(output_ok)
                 })())
@@ -3593,7 +6057,7 @@ fn wire__crate__api__plugin__set_effect_parameter_impl(
         },
     )
 }
-fn wire__crate__api__plugin__set_generator_parameter_impl(
+fn wire__crate__api__transport__set_tempo_point_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3601,7 +6065,7 @@ fn wire__crate__api__plugin__set_generator_parameter_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_generator_parameter",
+            debug_name: "set_tempo_point",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3615,16 +6079,52 @@ fn wire__crate__api__plugin__set_generator_parameter_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_generator_id = <u32>::sse_decode(&mut deserializer);
-            let api_param_id = <u32>::sse_decode(&mut deserializer);
-            let api_value = <f32>::sse_decode(&mut deserializer);
+            let api_tick = <u64>::sse_decode(&mut deserializer);
+            let api_bpm = <f32>::sse_decode(&mut deserializer);
+            let api_linear = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::plugin::set_generator_parameter(
-                        api_generator_id,
-                        api_param_id,
-                        api_value,
+                    let output_ok =
+                        crate::api::transport::set_tempo_point(api_tick, api_bpm, api_linear)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transport__set_time_signature_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_time_signature",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bar = <u32>::sse_decode(&mut deserializer);
+            let api_numerator = <u8>::sse_decode(&mut deserializer);
+            let api_denominator = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transport::set_time_signature(
+                        api_bar,
+                        api_numerator,
+                        api_denominator,
                     )?;
                     Ok(output_ok)
                 })())
@@ -3632,7 +6132,7 @@ fn wire__crate__api__plugin__set_generator_parameter_impl(
         },
     )
 }
-fn wire__crate__api__transport__set_looping_impl(
+fn wire__crate__api__recording__set_track_monitor_mode_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3640,7 +6140,7 @@ fn wire__crate__api__transport__set_looping_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_looping",
+            debug_name: "set_track_monitor_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3654,18 +6154,20 @@ fn wire__crate__api__transport__set_looping_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_val = <bool>::sse_decode(&mut deserializer);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_mode = <crate::api::recording::UiMonitorMode>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transport::set_looping(api_val)?;
+                    let output_ok =
+                        crate::api::recording::set_track_monitor_mode(api_track_id, api_mode)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__set_master_bus_params_impl(
+fn wire__crate__api__recording__set_track_record_arm_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3673,7 +6175,7 @@ fn wire__crate__api__mixer__set_master_bus_params_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_master_bus_params",
+            debug_name: "set_track_record_arm",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3687,19 +6189,154 @@ fn wire__crate__api__mixer__set_master_bus_params_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_params =
-                <Vec<crate::api::mixer::UiMixerChannelParams>>::sse_decode(&mut deserializer);
+            let api_track_id = <u32>::sse_decode(&mut deserializer);
+            let api_armed = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::set_master_bus_params(api_params)?;
+                    let output_ok =
+                        crate::api::recording::set_track_record_arm(api_track_id, api_armed)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__set_user_settings_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_user_settings_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio::set_user_settings_path(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pattern__snap_to_scale_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "snap_to_scale",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::pattern::snap_to_scale(api_pattern_id, api_note_ids)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__start_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::recording::start_recording()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__stop_all_previews_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_all_previews",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::audio::stop_all_previews();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__set_mixer_channel_params_impl(
+fn wire__crate__api__pattern__stop_pattern_preview_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3707,7 +6344,7 @@ fn wire__crate__api__mixer__set_mixer_channel_params_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_mixer_channel_params",
+            debug_name: "stop_pattern_preview",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3721,21 +6358,17 @@ fn wire__crate__api__mixer__set_mixer_channel_params_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <u32>::sse_decode(&mut deserializer);
-            let api_params =
-                <Vec<crate::api::mixer::UiMixerChannelParams>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::mixer::set_mixer_channel_params(api_track_id, api_params)?;
+                    let output_ok = crate::api::pattern::stop_pattern_preview()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__transport__set_playhead_impl(
+fn wire__crate__api__recording__stop_recording_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3743,7 +6376,7 @@ fn wire__crate__api__transport__set_playhead_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_playhead",
+            debug_name: "stop_recording",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3757,18 +6390,17 @@ fn wire__crate__api__transport__set_playhead_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_val = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transport::set_playhead(api_val)?;
+                    let output_ok = crate::api::recording::stop_recording()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__transport__set_playing_impl(
+fn wire__crate__api__transport__stop_song_playback_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3776,7 +6408,7 @@ fn wire__crate__api__transport__set_playing_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_playing",
+            debug_name: "stop_song_playback",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3790,18 +6422,17 @@ fn wire__crate__api__transport__set_playing_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_val = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transport::set_playing(api_val)?;
+                    let output_ok = crate::api::transport::stop_song_playback()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__mixer__set_routing_impl(
+fn wire__crate__api__analyzer__stop_spectrum_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3809,7 +6440,7 @@ fn wire__crate__api__mixer__set_routing_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_routing",
+            debug_name: "stop_spectrum_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3823,26 +6454,18 @@ fn wire__crate__api__mixer__set_routing_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source = <crate::api::mixer::UiRoutingNode>::sse_decode(&mut deserializer);
-            let api_destination = <crate::api::mixer::UiRoutingNode>::sse_decode(&mut deserializer);
-            let api_send_level = <f32>::sse_decode(&mut deserializer);
-            let api_is_send = <bool>::sse_decode(&mut deserializer);
+            let api_analyzer_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mixer::set_routing(
-                        api_source,
-                        api_destination,
-                        api_send_level,
-                        api_is_send,
-                    )?;
+                    let output_ok = crate::api::analyzer::stop_spectrum_stream(api_analyzer_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio__stop_all_previews_impl(
+fn wire__crate__api__pattern__swing_groove_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3850,7 +6473,7 @@ fn wire__crate__api__audio__stop_all_previews_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_all_previews",
+            debug_name: "swing_groove",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3864,19 +6487,23 @@ fn wire__crate__api__audio__stop_all_previews_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_division =
+                <crate::api::pattern::UiSwingDivision>::sse_decode(&mut deserializer);
+            let api_percent = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::audio::stop_all_previews();
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::pattern::swing_groove(
+                        api_division,
+                        api_percent,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__pattern__stop_pattern_preview_impl(
+fn wire__crate__api__plugin__sync_effect_parameters_from_audio_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3884,7 +6511,7 @@ fn wire__crate__api__pattern__stop_pattern_preview_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_pattern_preview",
+            debug_name: "sync_effect_parameters_from_audio",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3898,17 +6525,21 @@ fn wire__crate__api__pattern__stop_pattern_preview_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_snapshots =
+                <Vec<crate::api::plugin::UiEffectParameterSnapshot>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::pattern::stop_pattern_preview()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::plugin::sync_effect_parameters_from_audio(&api_snapshots);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__transport__stop_song_playback_impl(
+fn wire__crate__api__plugin__sync_generator_parameters_from_audio_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3916,7 +6547,7 @@ fn wire__crate__api__transport__stop_song_playback_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_song_playback",
+            debug_name: "sync_generator_parameters_from_audio",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3930,17 +6561,22 @@ fn wire__crate__api__transport__stop_song_playback_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_snapshots = <Vec<crate::api::plugin::UiGeneratorParameterSnapshot>>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transport::stop_song_playback()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::plugin::sync_generator_parameters_from_audio(&api_snapshots);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__sync_effect_parameters_from_audio_impl(
+fn wire__crate__api__transport__ticks_to_samples_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3948,7 +6584,7 @@ fn wire__crate__api__plugin__sync_effect_parameters_from_audio_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_effect_parameters_from_audio",
+            debug_name: "ticks_to_samples",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3962,21 +6598,19 @@ fn wire__crate__api__plugin__sync_effect_parameters_from_audio_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_snapshots =
-                <Vec<crate::api::plugin::UiEffectParameterSnapshot>>::sse_decode(&mut deserializer);
+            let api_tick = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::plugin::sync_effect_parameters_from_audio(&api_snapshots);
-                    })?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::transport::ticks_to_samples(api_tick))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__plugin__sync_generator_parameters_from_audio_impl(
+fn wire__crate__api__pattern__transform_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3984,7 +6618,7 @@ fn wire__crate__api__plugin__sync_generator_parameters_from_audio_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_generator_parameters_from_audio",
+            debug_name: "transform_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3998,15 +6632,18 @@ fn wire__crate__api__plugin__sync_generator_parameters_from_audio_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_snapshots = <Vec<crate::api::plugin::UiGeneratorParameterSnapshot>>::sse_decode(
-                &mut deserializer,
-            );
+            let api_pattern_id = <u32>::sse_decode(&mut deserializer);
+            let api_note_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_transform =
+                <crate::api::pattern::UiNoteTransform>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::plugin::sync_generator_parameters_from_audio(&api_snapshots);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pattern::transform_notes(
+                        api_pattern_id,
+                        api_note_ids,
+                        api_transform,
+                    )?;
                     Ok(output_ok)
                 })())
             }
@@ -4077,6 +6714,39 @@ fn wire__crate__api__project__transport_state_new_with_param_impl(
         },
     )
 }
+fn wire__crate__api__audio__ui_audio_device_settings_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ui_audio_device_settings_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::audio::UiAudioDeviceSettings::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__session__ui_clipboard_content_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -4363,6 +7033,32 @@ impl SseDecode for std::collections::HashMap<u32, crate::api::project::UiTrack>
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        Vec<crate::api::mixer::UiMeterLevels>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<crate::api::plugins::eq::UiResponseCurvePoint>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode
     for StreamSink<
         crate::api::mixer::UiMixerParamEvent,
@@ -4550,6 +7246,30 @@ impl SseDecode for Vec<crate::api::mixer::ParameterSpecDTO> {
     }
 }
 
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4562,6 +7282,18 @@ impl SseDecode for Vec<i8> {
     }
 }
 
+impl SseDecode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u16>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4694,6 +7426,32 @@ impl SseDecode for Vec<(u32, crate::api::project::UiTrack)> {
     }
 }
 
+impl SseDecode for Vec<crate::api::audio::UiAudioDeviceInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::audio::UiAudioDeviceInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::pattern::UiChordSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::pattern::UiChordSpan>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::project::UiClip> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4748,51 +7506,115 @@ impl SseDecode for Vec<crate::api::mixer::UiEffectSummary> {
     }
 }
 
-impl SseDecode for Vec<crate::api::plugin::UiGeneratorParameterSnapshot> {
+impl SseDecode for Vec<crate::api::plugin::UiGeneratorParameterSnapshot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::plugin::UiGeneratorParameterSnapshot>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::mixer::UiMeterLevels> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::mixer::UiMeterLevels>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::mixer::UiMixerChannelParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::mixer::UiMixerChannelParams>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::pattern::UiNote> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::pattern::UiNote>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::plugin::UiParameterValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::plugin::UiParameterValue>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::plugin::UiPluginInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::plugin::UiGeneratorParameterSnapshot>::sse_decode(deserializer));
+            ans_.push(<crate::api::plugin::UiPluginInfo>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::mixer::UiMixerChannelParams> {
+impl SseDecode for Vec<crate::api::audio::UiPluginLoad> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::mixer::UiMixerChannelParams>::sse_decode(
-                deserializer,
-            ));
+            ans_.push(<crate::api::audio::UiPluginLoad>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::pattern::UiNote> {
+impl SseDecode for Vec<crate::api::plugin::UiPluginParameter> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::pattern::UiNote>::sse_decode(deserializer));
+            ans_.push(<crate::api::plugin::UiPluginParameter>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::plugin::UiParameterValue> {
+impl SseDecode for Vec<crate::api::recording::UiRecordedClip> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::plugin::UiParameterValue>::sse_decode(
+            ans_.push(<crate::api::recording::UiRecordedClip>::sse_decode(
                 deserializer,
             ));
         }
@@ -4800,25 +7622,27 @@ impl SseDecode for Vec<crate::api::plugin::UiParameterValue> {
     }
 }
 
-impl SseDecode for Vec<crate::api::plugin::UiPluginInfo> {
+impl SseDecode for Vec<crate::api::plugins::eq::UiResponseCurvePoint> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::plugin::UiPluginInfo>::sse_decode(deserializer));
+            ans_.push(<crate::api::plugins::eq::UiResponseCurvePoint>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::plugin::UiPluginParameter> {
+impl SseDecode for Vec<crate::api::mixer::UiRoutingConnection> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::plugin::UiPluginParameter>::sse_decode(
+            ans_.push(<crate::api::mixer::UiRoutingConnection>::sse_decode(
                 deserializer,
             ));
         }
@@ -4826,13 +7650,13 @@ impl SseDecode for Vec<crate::api::plugin::UiPluginParameter> {
     }
 }
 
-impl SseDecode for Vec<crate::api::plugins::eq::UiResponseCurvePoint> {
+impl SseDecode for Vec<crate::api::transport::UiTempoPoint> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::plugins::eq::UiResponseCurvePoint>::sse_decode(
+            ans_.push(<crate::api::transport::UiTempoPoint>::sse_decode(
                 deserializer,
             ));
         }
@@ -4840,13 +7664,13 @@ impl SseDecode for Vec<crate::api::plugins::eq::UiResponseCurvePoint> {
     }
 }
 
-impl SseDecode for Vec<crate::api::mixer::UiRoutingConnection> {
+impl SseDecode for Vec<crate::api::transport::UiTimeSignatureEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::mixer::UiRoutingConnection>::sse_decode(
+            ans_.push(<crate::api::transport::UiTimeSignatureEvent>::sse_decode(
                 deserializer,
             ));
         }
@@ -4949,6 +7773,87 @@ impl SseDecode for Option<u32> {
     }
 }
 
+impl SseDecode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::audio::UiEngineLoad> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::audio::UiEngineLoad>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::pattern::UiGroove> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::pattern::UiGroove>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::transport::UiLoopRegion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::transport::UiLoopRegion>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::audio::UiLoudnessReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::audio::UiLoudnessReport>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::pattern::UiScale> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::pattern::UiScale>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::pattern::UiScaleMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::pattern::UiScaleMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<Vec<crate::api::mixer::ParameterSpecDTO>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5169,6 +8074,42 @@ impl SseDecode for crate::api::project::UiApplicationState {
     }
 }
 
+impl SseDecode for crate::api::audio::UiAudioDeviceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_isDefault = <bool>::sse_decode(deserializer);
+        let mut var_sampleRates = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_channelCounts = <Vec<u16>>::sse_decode(deserializer);
+        let mut var_bufferSizes = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::audio::UiAudioDeviceInfo {
+            name: var_name,
+            is_default: var_isDefault,
+            sample_rates: var_sampleRates,
+            channel_counts: var_channelCounts,
+            buffer_sizes: var_bufferSizes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio::UiAudioDeviceSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_host = <Option<String>>::sse_decode(deserializer);
+        let mut var_outputDevice = <Option<String>>::sse_decode(deserializer);
+        let mut var_inputDevice = <Option<String>>::sse_decode(deserializer);
+        let mut var_sampleRate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bufferSize = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::audio::UiAudioDeviceSettings {
+            host: var_host,
+            output_device: var_outputDevice,
+            input_device: var_inputDevice,
+            sample_rate: var_sampleRate,
+            buffer_size: var_bufferSize,
+        };
+    }
+}
+
 impl SseDecode for crate::api::project::UiAudioHardwareConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5187,6 +8128,20 @@ impl SseDecode for crate::api::project::UiAudioHardwareConfig {
     }
 }
 
+impl SseDecode for crate::api::audio::UiBitPerSample {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audio::UiBitPerSample::B8,
+            1 => crate::api::audio::UiBitPerSample::B16,
+            2 => crate::api::audio::UiBitPerSample::B24,
+            3 => crate::api::audio::UiBitPerSample::B32,
+            _ => unreachable!("Invalid variant for UiBitPerSample: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::mixer::UiBus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5201,6 +8156,54 @@ impl SseDecode for crate::api::mixer::UiBus {
     }
 }
 
+impl SseDecode for crate::api::pattern::UiChordSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startTick = <u64>::sse_decode(deserializer);
+        let mut var_endTick = <u64>::sse_decode(deserializer);
+        let mut var_root = <u8>::sse_decode(deserializer);
+        let mut var_chordType = <crate::api::pattern::UiChordType>::sse_decode(deserializer);
+        let mut var_bass = <Option<u8>>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        return crate::api::pattern::UiChordSpan {
+            start_tick: var_startTick,
+            end_tick: var_endTick,
+            root: var_root,
+            chord_type: var_chordType,
+            bass: var_bass,
+            name: var_name,
+        };
+    }
+}
+
+impl SseDecode for crate::api::pattern::UiChordType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::pattern::UiChordType::Major,
+            1 => crate::api::pattern::UiChordType::Minor,
+            2 => crate::api::pattern::UiChordType::Diminished,
+            3 => crate::api::pattern::UiChordType::Augmented,
+            4 => crate::api::pattern::UiChordType::Sus2,
+            5 => crate::api::pattern::UiChordType::Sus4,
+            6 => crate::api::pattern::UiChordType::Major6,
+            7 => crate::api::pattern::UiChordType::Minor6,
+            8 => crate::api::pattern::UiChordType::Dominant7,
+            9 => crate::api::pattern::UiChordType::Major7,
+            10 => crate::api::pattern::UiChordType::Minor7,
+            11 => crate::api::pattern::UiChordType::MinorMajor7,
+            12 => crate::api::pattern::UiChordType::HalfDiminished7,
+            13 => crate::api::pattern::UiChordType::Diminished7,
+            14 => crate::api::pattern::UiChordType::Add9,
+            15 => crate::api::pattern::UiChordType::Dominant9,
+            16 => crate::api::pattern::UiChordType::Major9,
+            17 => crate::api::pattern::UiChordType::Minor9,
+            _ => unreachable!("Invalid variant for UiChordType: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::project::UiClip {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5337,6 +8340,28 @@ impl SseDecode for crate::api::plugin::UiEffectTarget {
     }
 }
 
+impl SseDecode for crate::api::audio::UiEngineLoad {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_load = <f32>::sse_decode(deserializer);
+        let mut var_average = <f32>::sse_decode(deserializer);
+        let mut var_peak = <f32>::sse_decode(deserializer);
+        let mut var_maxPeak = <f32>::sse_decode(deserializer);
+        let mut var_overloads = <u64>::sse_decode(deserializer);
+        let mut var_xruns = <u64>::sse_decode(deserializer);
+        let mut var_plugins = <Vec<crate::api::audio::UiPluginLoad>>::sse_decode(deserializer);
+        return crate::api::audio::UiEngineLoad {
+            load: var_load,
+            average: var_average,
+            peak: var_peak,
+            max_peak: var_maxPeak,
+            overloads: var_overloads,
+            xruns: var_xruns,
+            plugins: var_plugins,
+        };
+    }
+}
+
 impl SseDecode for crate::api::project::UiGeneratorInstance {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5388,6 +8413,124 @@ impl SseDecode for crate::api::plugin::UiGeneratorParameterSnapshot {
     }
 }
 
+impl SseDecode for crate::api::pattern::UiGroove {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_stepTicks = <u64>::sse_decode(deserializer);
+        let mut var_timings = <Vec<i32>>::sse_decode(deserializer);
+        let mut var_velocities = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_amount = <f32>::sse_decode(deserializer);
+        return crate::api::pattern::UiGroove {
+            name: var_name,
+            step_ticks: var_stepTicks,
+            timings: var_timings,
+            velocities: var_velocities,
+            amount: var_amount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::transport::UiLoopRegion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        return crate::api::transport::UiLoopRegion {
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio::UiLoudnessReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_momentary = <f32>::sse_decode(deserializer);
+        let mut var_shortTerm = <f32>::sse_decode(deserializer);
+        let mut var_integrated = <f32>::sse_decode(deserializer);
+        let mut var_loudnessRange = <f32>::sse_decode(deserializer);
+        let mut var_truePeak = <f32>::sse_decode(deserializer);
+        let mut var_maxMomentary = <f32>::sse_decode(deserializer);
+        let mut var_maxShortTerm = <f32>::sse_decode(deserializer);
+        return crate::api::audio::UiLoudnessReport {
+            momentary: var_momentary,
+            short_term: var_shortTerm,
+            integrated: var_integrated,
+            loudness_range: var_loudnessRange,
+            true_peak: var_truePeak,
+            max_momentary: var_maxMomentary,
+            max_short_term: var_maxShortTerm,
+        };
+    }
+}
+
+impl SseDecode for crate::api::mixer::UiMeterLevels {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_node = <crate::api::mixer::UiRoutingNode>::sse_decode(deserializer);
+        let mut var_peakLeft = <f32>::sse_decode(deserializer);
+        let mut var_peakRight = <f32>::sse_decode(deserializer);
+        let mut var_rmsLeft = <f32>::sse_decode(deserializer);
+        let mut var_rmsRight = <f32>::sse_decode(deserializer);
+        let mut var_peakHoldLeft = <f32>::sse_decode(deserializer);
+        let mut var_peakHoldRight = <f32>::sse_decode(deserializer);
+        let mut var_clipped = <bool>::sse_decode(deserializer);
+        return crate::api::mixer::UiMeterLevels {
+            node: var_node,
+            peak_left: var_peakLeft,
+            peak_right: var_peakRight,
+            rms_left: var_rmsLeft,
+            rms_right: var_rmsRight,
+            peak_hold_left: var_peakHoldLeft,
+            peak_hold_right: var_peakHoldRight,
+            clipped: var_clipped,
+        };
+    }
+}
+
+impl SseDecode for crate::api::transport::UiMetronomeSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_volume = <f32>::sse_decode(deserializer);
+        let mut var_targetBusId = <Option<u32>>::sse_decode(deserializer);
+        let mut var_countInBars = <u8>::sse_decode(deserializer);
+        return crate::api::transport::UiMetronomeSettings {
+            enabled: var_enabled,
+            volume: var_volume,
+            target_bus_id: var_targetBusId,
+            count_in_bars: var_countInBars,
+        };
+    }
+}
+
+impl SseDecode for crate::api::midi::UiMidiOutputSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sendClock = <bool>::sse_decode(deserializer);
+        let mut var_trackId = <Option<u32>>::sse_decode(deserializer);
+        let mut var_channel = <u8>::sse_decode(deserializer);
+        return crate::api::midi::UiMidiOutputSettings {
+            send_clock: var_sendClock,
+            track_id: var_trackId,
+            channel: var_channel,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::UiMidiRecordMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::recording::UiMidiRecordMode::Overdub,
+            1 => crate::api::recording::UiMidiRecordMode::Replace,
+            _ => unreachable!("Invalid variant for UiMidiRecordMode: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::mixer::UiMixerChannel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5479,6 +8622,19 @@ impl SseDecode for crate::api::mixer::UiMixerState {
     }
 }
 
+impl SseDecode for crate::api::recording::UiMonitorMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::recording::UiMonitorMode::Off,
+            1 => crate::api::recording::UiMonitorMode::Always,
+            2 => crate::api::recording::UiMonitorMode::Auto,
+            _ => unreachable!("Invalid variant for UiMonitorMode: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::pattern::UiNote {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5503,6 +8659,88 @@ impl SseDecode for crate::api::pattern::UiNote {
     }
 }
 
+impl SseDecode for crate::api::pattern::UiNoteTransform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_semitones = <i16>::sse_decode(deserializer);
+                let mut var_foldOctaves = <bool>::sse_decode(deserializer);
+                return crate::api::pattern::UiNoteTransform::Transpose {
+                    semitones: var_semitones,
+                    fold_octaves: var_foldOctaves,
+                };
+            }
+            1 => {
+                let mut var_degrees = <i32>::sse_decode(deserializer);
+                let mut var_root = <u8>::sse_decode(deserializer);
+                let mut var_mode = <crate::api::pattern::UiScaleMode>::sse_decode(deserializer);
+                return crate::api::pattern::UiNoteTransform::ScaleTranspose {
+                    degrees: var_degrees,
+                    root: var_root,
+                    mode: var_mode,
+                };
+            }
+            2 => {
+                let mut var_factor = <f32>::sse_decode(deserializer);
+                return crate::api::pattern::UiNoteTransform::VelocityScale { factor: var_factor };
+            }
+            3 => {
+                let mut var_amount = <f32>::sse_decode(deserializer);
+                return crate::api::pattern::UiNoteTransform::VelocityCompress {
+                    amount: var_amount,
+                };
+            }
+            4 => {
+                let mut var_amount = <u8>::sse_decode(deserializer);
+                return crate::api::pattern::UiNoteTransform::VelocityRandomize {
+                    amount: var_amount,
+                };
+            }
+            5 => {
+                return crate::api::pattern::UiNoteTransform::Legato;
+            }
+            6 => {
+                return crate::api::pattern::UiNoteTransform::Reverse;
+            }
+            7 => {
+                let mut var_pivot = <u8>::sse_decode(deserializer);
+                return crate::api::pattern::UiNoteTransform::Invert { pivot: var_pivot };
+            }
+            8 => {
+                let mut var_factor = <f32>::sse_decode(deserializer);
+                return crate::api::pattern::UiNoteTransform::TimeStretch { factor: var_factor };
+            }
+            9 => {
+                let mut var_timing = <u64>::sse_decode(deserializer);
+                let mut var_velocity = <u8>::sse_decode(deserializer);
+                return crate::api::pattern::UiNoteTransform::Humanize {
+                    timing: var_timing,
+                    velocity: var_velocity,
+                };
+            }
+            10 => {
+                return crate::api::pattern::UiNoteTransform::SplitOverlaps;
+            }
+            11 => {
+                return crate::api::pattern::UiNoteTransform::MergeOverlaps;
+            }
+            12 => {
+                let mut var_root = <u8>::sse_decode(deserializer);
+                let mut var_mode = <crate::api::pattern::UiScaleMode>::sse_decode(deserializer);
+                return crate::api::pattern::UiNoteTransform::SnapToScale {
+                    root: var_root,
+                    mode: var_mode,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::api::plugin::UiParameterType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5575,6 +8813,20 @@ impl SseDecode for crate::api::project::UiPluginInstance {
     }
 }
 
+impl SseDecode for crate::api::audio::UiPluginLoad {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_slot = <crate::api::audio::UiPluginSlot>::sse_decode(deserializer);
+        let mut var_average = <f32>::sse_decode(deserializer);
+        let mut var_peak = <f32>::sse_decode(deserializer);
+        return crate::api::audio::UiPluginLoad {
+            slot: var_slot,
+            average: var_average,
+            peak: var_peak,
+        };
+    }
+}
+
 impl SseDecode for crate::api::plugin::UiPluginParameter {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5603,6 +8855,30 @@ impl SseDecode for crate::api::plugin::UiPluginParameter {
     }
 }
 
+impl SseDecode for crate::api::audio::UiPluginSlot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <u32>::sse_decode(deserializer);
+                return crate::api::audio::UiPluginSlot::Generator(var_field0);
+            }
+            1 => {
+                let mut var_node = <crate::api::mixer::UiRoutingNode>::sse_decode(deserializer);
+                let mut var_effectId = <u32>::sse_decode(deserializer);
+                return crate::api::audio::UiPluginSlot::Effect {
+                    node: var_node,
+                    effect_id: var_effectId,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::api::project::UiProjectMetadata {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5619,6 +8895,38 @@ impl SseDecode for crate::api::project::UiProjectMetadata {
     }
 }
 
+impl SseDecode for crate::api::pattern::UiQuantizeSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_noteValue = <u32>::sse_decode(deserializer);
+        let mut var_triplet = <bool>::sse_decode(deserializer);
+        let mut var_strength = <f32>::sse_decode(deserializer);
+        let mut var_window = <f32>::sse_decode(deserializer);
+        let mut var_quantizeEnds = <bool>::sse_decode(deserializer);
+        let mut var_swing = <f32>::sse_decode(deserializer);
+        return crate::api::pattern::UiQuantizeSettings {
+            note_value: var_noteValue,
+            triplet: var_triplet,
+            strength: var_strength,
+            window: var_window,
+            quantize_ends: var_quantizeEnds,
+            swing: var_swing,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::UiRecordedClip {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_trackId = <u32>::sse_decode(deserializer);
+        let mut var_clip = <crate::api::project::UiClip>::sse_decode(deserializer);
+        return crate::api::recording::UiRecordedClip {
+            track_id: var_trackId,
+            clip: var_clip,
+        };
+    }
+}
+
 impl SseDecode for crate::api::track::UiResizeEdge {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5682,6 +8990,42 @@ impl SseDecode for crate::api::mixer::UiRoutingNode {
     }
 }
 
+impl SseDecode for crate::api::pattern::UiScale {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_root = <u8>::sse_decode(deserializer);
+        let mut var_mode = <crate::api::pattern::UiScaleMode>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        return crate::api::pattern::UiScale {
+            root: var_root,
+            mode: var_mode,
+            name: var_name,
+        };
+    }
+}
+
+impl SseDecode for crate::api::pattern::UiScaleMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::pattern::UiScaleMode::Major,
+            1 => crate::api::pattern::UiScaleMode::NaturalMinor,
+            2 => crate::api::pattern::UiScaleMode::HarmonicMinor,
+            3 => crate::api::pattern::UiScaleMode::MelodicMinor,
+            4 => crate::api::pattern::UiScaleMode::Dorian,
+            5 => crate::api::pattern::UiScaleMode::Phrygian,
+            6 => crate::api::pattern::UiScaleMode::Lydian,
+            7 => crate::api::pattern::UiScaleMode::Mixolydian,
+            8 => crate::api::pattern::UiScaleMode::Locrian,
+            9 => crate::api::pattern::UiScaleMode::MajorPentatonic,
+            10 => crate::api::pattern::UiScaleMode::MinorPentatonic,
+            11 => crate::api::pattern::UiScaleMode::Blues,
+            _ => unreachable!("Invalid variant for UiScaleMode: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::track::UiSourceType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5694,6 +9038,46 @@ impl SseDecode for crate::api::track::UiSourceType {
     }
 }
 
+impl SseDecode for crate::api::pattern::UiSwingDivision {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::pattern::UiSwingDivision::Eighth,
+            1 => crate::api::pattern::UiSwingDivision::Sixteenth,
+            _ => unreachable!("Invalid variant for UiSwingDivision: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::transport::UiTempoPoint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tick = <u64>::sse_decode(deserializer);
+        let mut var_bpm = <f32>::sse_decode(deserializer);
+        let mut var_linear = <bool>::sse_decode(deserializer);
+        return crate::api::transport::UiTempoPoint {
+            tick: var_tick,
+            bpm: var_bpm,
+            linear: var_linear,
+        };
+    }
+}
+
+impl SseDecode for crate::api::transport::UiTimeSignatureEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bar = <u32>::sse_decode(deserializer);
+        let mut var_numerator = <u8>::sse_decode(deserializer);
+        let mut var_denominator = <u8>::sse_decode(deserializer);
+        return crate::api::transport::UiTimeSignatureEvent {
+            bar: var_bar,
+            numerator: var_numerator,
+            denominator: var_denominator,
+        };
+    }
+}
+
 impl SseDecode for crate::api::project::UiTrack {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5795,335 +9179,544 @@ fn pde_ffi_dispatcher_primary_impl(
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
         1 => wire__crate__api__project__add_audio_source_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__mixer__add_effect_to_bus_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__mixer__add_effect_to_master_bus_impl(
+        2 => wire__crate__api__pattern__add_chord_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__mixer__add_effect_to_bus_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__mixer__add_effect_to_master_bus_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        4 => wire__crate__api__mixer__add_effect_to_mixer_channel_by_id_impl(
+        5 => wire__crate__api__mixer__add_effect_to_mixer_channel_by_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        5 => wire__crate__api__track__add_midi_track_with_generator_id_impl(
+        6 => wire__crate__api__track__add_midi_track_with_generator_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        6 => wire__crate__api__project__add_new_audio_track_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__pattern__add_note_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__pattern__change_note_params_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__track__change_track_color_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__track__change_track_name_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__session__copy_clips_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__session__copy_pattern_notes_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__mixer__create_bus_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__track__create_clip_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__mixer__create_mixer_event_stream_impl(
+        7 => wire__crate__api__project__add_new_audio_track_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__pattern__add_note_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__pattern__analyse_chords_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__pattern__change_note_params_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__track__change_track_color_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__track__change_track_name_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__pattern__chord_keys_impl(port, ptr, rust_vec_len, data_len),
+        16 => {
+            wire__crate__api__transport__clear_loop_region_impl(port, ptr, rust_vec_len, data_len)
+        }
+        17 => {
+            wire__crate__api__recording__close_input_device_impl(port, ptr, rust_vec_len, data_len)
+        }
+        18 => wire__crate__api__midi__close_midi_input_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__midi__close_midi_output_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__pattern__commit_clip_groove_impl(port, ptr, rust_vec_len, data_len),
+        21 => {
+            wire__crate__api__pattern__commit_pattern_groove_impl(port, ptr, rust_vec_len, data_len)
+        }
+        22 => wire__crate__api__session__copy_clips_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__session__copy_pattern_notes_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__mixer__create_bus_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__track__create_clip_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__mixer__create_meter_stream_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__mixer__create_mixer_event_stream_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        18 => {
+        28 => {
             wire__crate__api__audio__create_position_stream_impl(port, ptr, rust_vec_len, data_len)
         }
-        19 => wire__crate__api__track__cut_clip_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__session__cut_clips_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__session__cut_pattern_notes_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__mixer__delete_bus_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__track__delete_clip_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__track__delete_clip_batch_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__session__delete_clips_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__pattern__delete_note_impl(port, ptr, rust_vec_len, data_len),
-        27 => {
+        29 => wire__crate__api__analyzer__create_spectrum_stream_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__track__cut_clip_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__session__cut_clips_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__session__cut_pattern_notes_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__mixer__delete_bus_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__track__delete_clip_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__track__delete_clip_batch_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__session__delete_clips_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__pattern__delete_note_impl(port, ptr, rust_vec_len, data_len),
+        38 => {
             wire__crate__api__session__delete_pattern_notes_impl(port, ptr, rust_vec_len, data_len)
         }
-        28 => wire__crate__api__plugin__execute_effect_instance_command_impl(
+        39 => wire__crate__api__plugin__execute_effect_instance_command_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__plugin__execute_generator_instance_command_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        29 => wire__crate__api__plugin__execute_generator_instance_command_impl(
+        41 => wire__crate__api__plugin__execute_plugin_command_effect_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        30 => wire__crate__api__plugin__execute_plugin_command_effect_impl(
+        42 => wire__crate__api__plugin__execute_plugin_command_generator_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        31 => wire__crate__api__plugin__execute_plugin_command_generator_impl(
+        43 => wire__crate__api__audio__export_project_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__recording__get_armed_tracks_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__audio__get_audio_config_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__audio__get_audio_device_settings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        32 => wire__crate__api__audio__get_audio_config_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__audio__get_audio_properties_impl(port, ptr, rust_vec_len, data_len),
-        34 => {
+        47 => wire__crate__api__audio__get_audio_properties_impl(port, ptr, rust_vec_len, data_len),
+        48 => {
             wire__crate__api__project__get_audio_source_list_impl(port, ptr, rust_vec_len, data_len)
         }
-        35 => wire__crate__api__track__get_audio_waveform_clips_data_impl(
+        49 => wire__crate__api__track__get_audio_waveform_clips_data_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        36 => wire__crate__api__track__get_audio_waveform_for_clip_impl(
+        50 => wire__crate__api__track__get_audio_waveform_for_clip_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        37 => wire__crate__api__track__get_audio_waveform_for_clip_all_available_in_tracks_impl(
+        51 => wire__crate__api__track__get_audio_waveform_for_clip_all_available_in_tracks_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        38 => wire__crate__api__track__get_audio_waveform_for_clip_only_in_specific_track_impl(
+        52 => wire__crate__api__track__get_audio_waveform_for_clip_only_in_specific_track_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        39 => wire__crate__api__plugin__get_available_effects_with_ids_impl(
+        53 => wire__crate__api__plugin__get_available_effects_with_ids_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        40 => wire__crate__api__plugin__get_available_generators_with_ids_impl(
+        54 => wire__crate__api__plugin__get_available_generators_with_ids_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        41 => wire__crate__api__mixer__get_bus_mixer_channel_specs_impl(
+        55 => wire__crate__api__mixer__get_bus_mixer_channel_specs_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        42 => wire__crate__api__mixer__get_buses_impl(port, ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__track__get_clip_impl(port, ptr, rust_vec_len, data_len),
-        44 => wire__crate__api__session__get_clipboard_contents_impl(
+        56 => wire__crate__api__mixer__get_buses_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__track__get_clip_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__session__get_clipboard_contents_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        45 => wire__crate__api__plugin__get_effect_impl(port, ptr, rust_vec_len, data_len),
-        46 => {
+        59 => wire__crate__api__plugin__get_effect_impl(port, ptr, rust_vec_len, data_len),
+        60 => {
             wire__crate__api__plugin__get_effect_from_master_impl(port, ptr, rust_vec_len, data_len)
         }
-        47 => wire__crate__api__plugin__get_effect_parameter_specs_impl(
+        61 => wire__crate__api__plugin__get_effect_parameter_specs_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => {
+        62 => {
             wire__crate__api__plugin__get_effects_from_track_impl(port, ptr, rust_vec_len, data_len)
         }
-        49 => wire__crate__api__plugins__eq__get_eq_response_curve_impl(
+        63 => wire__crate__api__audio__get_engine_load_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__plugins__eq__get_eq_response_curve_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        50 => wire__crate__api__plugin__get_generator_impl(port, ptr, rust_vec_len, data_len),
-        51 => wire__crate__api__project__get_generator_list_impl(port, ptr, rust_vec_len, data_len),
-        52 => wire__crate__api__plugin__get_generator_parameter_impl(
+        65 => wire__crate__api__plugin__get_generator_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__project__get_generator_list_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__plugin__get_generator_parameter_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        53 => wire__crate__api__plugin__get_generator_parameter_specs_impl(
+        68 => wire__crate__api__plugin__get_generator_parameter_specs_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        54 => wire__crate__api__mixer__get_master_bus_impl(port, ptr, rust_vec_len, data_len),
-        55 => wire__crate__api__mixer__get_master_bus_populated_impl(
+        69 => wire__crate__api__transport__get_loop_region_impl(port, ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__audio__get_loudness_report_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__mixer__get_master_bus_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__mixer__get_master_bus_populated_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        56 => wire__crate__api__mixer__get_master_channel_specs_impl(
+        73 => wire__crate__api__mixer__get_master_channel_specs_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        57 => wire__crate__api__plugin__get_master_effects_impl(port, ptr, rust_vec_len, data_len),
-        58 => {
+        74 => wire__crate__api__plugin__get_master_effects_impl(port, ptr, rust_vec_len, data_len),
+        75 => {
             wire__crate__api__project__get_max_sample_index_impl(port, ptr, rust_vec_len, data_len)
         }
-        59 => wire__crate__api__mixer__get_mixer_channel_impl(port, ptr, rust_vec_len, data_len),
-        60 => wire__crate__api__mixer__get_mixer_channel_populated_impl(
+        76 => wire__crate__api__transport__get_metronome_settings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        77 => wire__crate__api__midi__get_midi_backend_name_impl(port, ptr, rust_vec_len, data_len),
+        78 => {
+            wire__crate__api__midi__get_midi_output_settings_impl(port, ptr, rust_vec_len, data_len)
+        }
+        79 => wire__crate__api__recording__get_midi_record_mode_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        80 => wire__crate__api__recording__get_midi_record_quantize_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        81 => wire__crate__api__audio__get_mix_latency_impl(port, ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__mixer__get_mixer_channel_impl(port, ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__mixer__get_mixer_channel_populated_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        61 => wire__crate__api__mixer__get_mixer_state_impl(port, ptr, rust_vec_len, data_len),
-        62 => wire__crate__api__pattern__get_pattern_impl(port, ptr, rust_vec_len, data_len),
-        63 => wire__crate__api__pattern__get_patterns_impl(port, ptr, rust_vec_len, data_len),
-        64 => {
+        84 => wire__crate__api__mixer__get_mixer_state_impl(port, ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__midi__get_open_midi_input_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__midi__get_open_midi_output_impl(port, ptr, rust_vec_len, data_len),
+        87 => {
+            wire__crate__api__pattern__get_out_of_key_notes_impl(port, ptr, rust_vec_len, data_len)
+        }
+        88 => wire__crate__api__pattern__get_pattern_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__pattern__get_pattern_groove_impl(port, ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__pattern__get_pattern_scale_impl(port, ptr, rust_vec_len, data_len),
+        91 => wire__crate__api__pattern__get_patterns_impl(port, ptr, rust_vec_len, data_len),
+        92 => {
             wire__crate__api__project__get_project_metadata_impl(port, ptr, rust_vec_len, data_len)
         }
-        65 => wire__crate__api__mixer__get_routing_matrix_impl(port, ptr, rust_vec_len, data_len),
-        66 => wire__crate__api__track__get_track_impl(port, ptr, rust_vec_len, data_len),
-        67 => wire__crate__api__mixer__get_track_mixer_channel_specs_impl(
+        93 => wire__crate__api__transport__get_random_seed_impl(port, ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__mixer__get_routing_matrix_impl(port, ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__transport__get_tempo_points_impl(port, ptr, rust_vec_len, data_len),
+        96 => {
+            wire__crate__api__transport__get_time_signatures_impl(port, ptr, rust_vec_len, data_len)
+        }
+        97 => wire__crate__api__track__get_track_impl(port, ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__mixer__get_track_mixer_channel_specs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => wire__crate__api__recording__get_track_monitor_mode_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        68 => wire__crate__api__project__get_tracks_impl(port, ptr, rust_vec_len, data_len),
-        69 => {
+        100 => wire__crate__api__project__get_tracks_impl(port, ptr, rust_vec_len, data_len),
+        101 => {
             wire__crate__api__project__get_transport_state_impl(port, ptr, rust_vec_len, data_len)
         }
-        71 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        72 => wire__crate__api__serialization__load_project_impl(port, ptr, rust_vec_len, data_len),
-        73 => wire__crate__api__session__move_clip_impl(port, ptr, rust_vec_len, data_len),
-        74 => wire__crate__api__track__move_clip_impl(port, ptr, rust_vec_len, data_len),
-        75 => wire__crate__api__track__move_clip_batch_impl(port, ptr, rust_vec_len, data_len),
-        76 => wire__crate__api__pattern__move_note_impl(port, ptr, rust_vec_len, data_len),
-        78 => wire__crate__api__session__paste_clips_impl(port, ptr, rust_vec_len, data_len),
-        79 => {
+        103 => {
+            wire__crate__api__pattern__groove_from_midi_file_impl(port, ptr, rust_vec_len, data_len)
+        }
+        104 => {
+            wire__crate__api__pattern__groove_from_pattern_impl(port, ptr, rust_vec_len, data_len)
+        }
+        105 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        106 => wire__crate__api__audio__is_plugin_profiling_impl(port, ptr, rust_vec_len, data_len),
+        107 => wire__crate__api__recording__is_recording_impl(port, ptr, rust_vec_len, data_len),
+        108 => wire__crate__api__audio__list_audio_hosts_impl(port, ptr, rust_vec_len, data_len),
+        109 => wire__crate__api__audio__list_audio_input_devices_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__crate__api__audio__list_audio_output_devices_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        111 => {
+            wire__crate__api__recording__list_input_devices_impl(port, ptr, rust_vec_len, data_len)
+        }
+        112 => wire__crate__api__midi__list_midi_inputs_impl(port, ptr, rust_vec_len, data_len),
+        113 => wire__crate__api__midi__list_midi_outputs_impl(port, ptr, rust_vec_len, data_len),
+        114 => {
+            wire__crate__api__serialization__load_project_impl(port, ptr, rust_vec_len, data_len)
+        }
+        115 => wire__crate__api__session__move_clip_impl(port, ptr, rust_vec_len, data_len),
+        116 => wire__crate__api__track__move_clip_impl(port, ptr, rust_vec_len, data_len),
+        117 => wire__crate__api__track__move_clip_batch_impl(port, ptr, rust_vec_len, data_len),
+        118 => wire__crate__api__pattern__move_note_impl(port, ptr, rust_vec_len, data_len),
+        119 => wire__crate__api__midi__open_midi_input_impl(port, ptr, rust_vec_len, data_len),
+        120 => wire__crate__api__midi__open_midi_output_impl(port, ptr, rust_vec_len, data_len),
+        122 => wire__crate__api__session__paste_clips_impl(port, ptr, rust_vec_len, data_len),
+        123 => {
             wire__crate__api__session__paste_pattern_notes_impl(port, ptr, rust_vec_len, data_len)
         }
-        80 => {
+        124 => {
             wire__crate__api__pattern__play_pattern_preview_impl(port, ptr, rust_vec_len, data_len)
         }
-        81 => wire__crate__api__audio__play_preview_note_impl(port, ptr, rust_vec_len, data_len),
-        82 => wire__crate__api__audio__play_preview_note_generator_impl(
+        125 => wire__crate__api__audio__play_preview_note_impl(port, ptr, rust_vec_len, data_len),
+        126 => wire__crate__api__audio__play_preview_note_generator_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        83 => wire__crate__api__audio__play_source_preview_impl(port, ptr, rust_vec_len, data_len),
-        84 => wire__crate__api__plugin__poll_effect_parameter_feedback_impl(
+        127 => wire__crate__api__audio__play_source_preview_impl(port, ptr, rust_vec_len, data_len),
+        128 => wire__crate__api__plugin__poll_effect_parameter_feedback_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        85 => wire__crate__api__plugin__poll_generator_parameter_feedback_impl(
+        129 => wire__crate__api__plugin__poll_generator_parameter_feedback_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        87 => wire__crate__api__plugin__query_effect_parameters_impl(
+        131 => wire__crate__api__pattern__quantize_notes_impl(port, ptr, rust_vec_len, data_len),
+        132 => wire__crate__api__plugin__query_effect_parameters_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        88 => wire__crate__api__plugin__query_generator_parameters_impl(
+        133 => wire__crate__api__plugin__query_generator_parameters_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        89 => wire__crate__api__session__redo_impl(port, ptr, rust_vec_len, data_len),
-        90 => wire__crate__api__mixer__remove_effect_from_master_bus_impl(
+        134 => wire__crate__api__session__redo_impl(port, ptr, rust_vec_len, data_len),
+        135 => wire__crate__api__mixer__remove_effect_from_master_bus_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        91 => wire__crate__api__mixer__remove_effect_from_mixer_channel_impl(
+        136 => wire__crate__api__mixer__remove_effect_from_mixer_channel_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        92 => wire__crate__api__mixer__remove_routing_impl(port, ptr, rust_vec_len, data_len),
-        93 => wire__crate__api__mixer__rename_bus_impl(port, ptr, rust_vec_len, data_len),
-        94 => wire__crate__api__session__resize_clip_impl(port, ptr, rust_vec_len, data_len),
-        95 => wire__crate__api__track__resize_clip_impl(port, ptr, rust_vec_len, data_len),
-        96 => wire__crate__api__track__resize_clip_batch_impl(port, ptr, rust_vec_len, data_len),
-        97 => wire__crate__api__pattern__resize_note_impl(port, ptr, rust_vec_len, data_len),
-        98 => wire__crate__api__serialization__save_project_impl(port, ptr, rust_vec_len, data_len),
-        99 => wire__crate__api__transport__set_bpm_impl(port, ptr, rust_vec_len, data_len),
-        100 => wire__crate__api__mixer__set_bus_params_impl(port, ptr, rust_vec_len, data_len),
-        101 => {
+        137 => wire__crate__api__mixer__remove_routing_impl(port, ptr, rust_vec_len, data_len),
+        138 => {
+            wire__crate__api__transport__remove_tempo_point_impl(port, ptr, rust_vec_len, data_len)
+        }
+        139 => wire__crate__api__transport__remove_time_signature_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        140 => wire__crate__api__mixer__rename_bus_impl(port, ptr, rust_vec_len, data_len),
+        141 => wire__crate__api__audio__reset_engine_load_impl(port, ptr, rust_vec_len, data_len),
+        142 => {
+            wire__crate__api__audio__reset_loudness_analyzer_impl(port, ptr, rust_vec_len, data_len)
+        }
+        143 => wire__crate__api__session__resize_clip_impl(port, ptr, rust_vec_len, data_len),
+        144 => wire__crate__api__track__resize_clip_impl(port, ptr, rust_vec_len, data_len),
+        145 => wire__crate__api__track__resize_clip_batch_impl(port, ptr, rust_vec_len, data_len),
+        146 => wire__crate__api__pattern__resize_note_impl(port, ptr, rust_vec_len, data_len),
+        147 => {
+            wire__crate__api__transport__samples_to_ticks_impl(port, ptr, rust_vec_len, data_len)
+        }
+        148 => {
+            wire__crate__api__serialization__save_project_impl(port, ptr, rust_vec_len, data_len)
+        }
+        149 => wire__crate__api__audio__set_audio_device_settings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        150 => wire__crate__api__transport__set_bpm_impl(port, ptr, rust_vec_len, data_len),
+        151 => wire__crate__api__mixer__set_bus_params_impl(port, ptr, rust_vec_len, data_len),
+        152 => wire__crate__api__pattern__set_clip_groove_impl(port, ptr, rust_vec_len, data_len),
+        153 => {
+            wire__crate__api__transport__set_count_in_bars_impl(port, ptr, rust_vec_len, data_len)
+        }
+        154 => {
             wire__crate__api__plugin__set_effect_parameter_impl(port, ptr, rust_vec_len, data_len)
         }
-        102 => wire__crate__api__plugin__set_generator_parameter_impl(
+        155 => wire__crate__api__plugin__set_generator_parameter_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        103 => wire__crate__api__transport__set_looping_impl(port, ptr, rust_vec_len, data_len),
-        104 => {
+        156 => {
+            wire__crate__api__recording__set_input_device_impl(port, ptr, rust_vec_len, data_len)
+        }
+        157 => wire__crate__api__transport__set_loop_region_impl(port, ptr, rust_vec_len, data_len),
+        158 => wire__crate__api__transport__set_looping_impl(port, ptr, rust_vec_len, data_len),
+        159 => {
             wire__crate__api__mixer__set_master_bus_params_impl(port, ptr, rust_vec_len, data_len)
         }
-        105 => wire__crate__api__mixer__set_mixer_channel_params_impl(
+        160 => wire__crate__api__transport__set_metronome_enabled_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        161 => wire__crate__api__transport__set_metronome_target_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        162 => wire__crate__api__transport__set_metronome_volume_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        163 => wire__crate__api__midi__set_midi_input_track_impl(port, ptr, rust_vec_len, data_len),
+        164 => {
+            wire__crate__api__midi__set_midi_output_settings_impl(port, ptr, rust_vec_len, data_len)
+        }
+        165 => wire__crate__api__recording__set_midi_record_settings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        166 => wire__crate__api__mixer__set_mixer_channel_params_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        167 => {
+            wire__crate__api__pattern__set_pattern_groove_impl(port, ptr, rust_vec_len, data_len)
+        }
+        168 => wire__crate__api__pattern__set_pattern_scale_impl(port, ptr, rust_vec_len, data_len),
+        169 => wire__crate__api__transport__set_playhead_impl(port, ptr, rust_vec_len, data_len),
+        170 => wire__crate__api__transport__set_playing_impl(port, ptr, rust_vec_len, data_len),
+        171 => {
+            wire__crate__api__audio__set_plugin_profiling_impl(port, ptr, rust_vec_len, data_len)
+        }
+        172 => wire__crate__api__transport__set_random_seed_impl(port, ptr, rust_vec_len, data_len),
+        173 => wire__crate__api__mixer__set_routing_impl(port, ptr, rust_vec_len, data_len),
+        174 => wire__crate__api__transport__set_tempo_point_impl(port, ptr, rust_vec_len, data_len),
+        175 => {
+            wire__crate__api__transport__set_time_signature_impl(port, ptr, rust_vec_len, data_len)
+        }
+        176 => wire__crate__api__recording__set_track_monitor_mode_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        106 => wire__crate__api__transport__set_playhead_impl(port, ptr, rust_vec_len, data_len),
-        107 => wire__crate__api__transport__set_playing_impl(port, ptr, rust_vec_len, data_len),
-        108 => wire__crate__api__mixer__set_routing_impl(port, ptr, rust_vec_len, data_len),
-        109 => wire__crate__api__audio__stop_all_previews_impl(port, ptr, rust_vec_len, data_len),
-        110 => {
+        177 => wire__crate__api__recording__set_track_record_arm_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        178 => {
+            wire__crate__api__audio__set_user_settings_path_impl(port, ptr, rust_vec_len, data_len)
+        }
+        179 => wire__crate__api__pattern__snap_to_scale_impl(port, ptr, rust_vec_len, data_len),
+        180 => wire__crate__api__recording__start_recording_impl(port, ptr, rust_vec_len, data_len),
+        181 => wire__crate__api__audio__stop_all_previews_impl(port, ptr, rust_vec_len, data_len),
+        182 => {
             wire__crate__api__pattern__stop_pattern_preview_impl(port, ptr, rust_vec_len, data_len)
         }
-        111 => {
+        183 => wire__crate__api__recording__stop_recording_impl(port, ptr, rust_vec_len, data_len),
+        184 => {
             wire__crate__api__transport__stop_song_playback_impl(port, ptr, rust_vec_len, data_len)
         }
-        112 => wire__crate__api__plugin__sync_effect_parameters_from_audio_impl(
+        185 => {
+            wire__crate__api__analyzer__stop_spectrum_stream_impl(port, ptr, rust_vec_len, data_len)
+        }
+        186 => wire__crate__api__pattern__swing_groove_impl(port, ptr, rust_vec_len, data_len),
+        187 => wire__crate__api__plugin__sync_effect_parameters_from_audio_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        113 => wire__crate__api__plugin__sync_generator_parameters_from_audio_impl(
+        188 => wire__crate__api__plugin__sync_generator_parameters_from_audio_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        116 => wire__crate__api__session__ui_clipboard_content_default_impl(
+        189 => {
+            wire__crate__api__transport__ticks_to_samples_impl(port, ptr, rust_vec_len, data_len)
+        }
+        190 => wire__crate__api__pattern__transform_notes_impl(port, ptr, rust_vec_len, data_len),
+        193 => wire__crate__api__audio__ui_audio_device_settings_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        194 => wire__crate__api__session__ui_clipboard_content_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        119 => wire__crate__api__project__ui_project_metadata_default_impl(
+        197 => wire__crate__api__project__ui_project_metadata_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        120 => wire__crate__api__project__ui_transport_state_default_impl(
+        198 => wire__crate__api__project__ui_transport_state_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        121 => wire__crate__api__session__undo_impl(port, ptr, rust_vec_len, data_len),
+        199 => wire__crate__api__session__undo_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -6136,25 +9729,27 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        8 => wire__crate__api__project__audio_hardware_config_new_impl(ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__project__audio_hardware_config_new_with_param_impl(
+        10 => {
+            wire__crate__api__project__audio_hardware_config_new_impl(ptr, rust_vec_len, data_len)
+        }
+        11 => wire__crate__api__project__audio_hardware_config_new_with_param_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        70 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        77 => {
+        102 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        121 => {
             wire__crate__api__plugins__eq__parse_eq_curve_response_impl(ptr, rust_vec_len, data_len)
         }
-        86 => wire__crate__api__project__project_metadata_new_impl(ptr, rust_vec_len, data_len),
-        114 => wire__crate__api__project__transport_state_new_impl(ptr, rust_vec_len, data_len),
-        115 => wire__crate__api__project__transport_state_new_with_param_impl(
+        130 => wire__crate__api__project__project_metadata_new_impl(ptr, rust_vec_len, data_len),
+        191 => wire__crate__api__project__transport_state_new_impl(ptr, rust_vec_len, data_len),
+        192 => wire__crate__api__project__transport_state_new_with_param_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        117 => wire__crate__api__mixer__ui_mixer_state_new_impl(ptr, rust_vec_len, data_len),
-        118 => {
+        195 => wire__crate__api__mixer__ui_mixer_state_new_impl(ptr, rust_vec_len, data_len),
+        196 => {
             wire__crate__api__mixer__ui_mixer_state_new_with_param_impl(ptr, rust_vec_len, data_len)
         }
         _ => unreachable!(),
@@ -6343,6 +9938,54 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::project::UiApplicationState>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio::UiAudioDeviceInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.is_default.into_into_dart().into_dart(),
+            self.sample_rates.into_into_dart().into_dart(),
+            self.channel_counts.into_into_dart().into_dart(),
+            self.buffer_sizes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio::UiAudioDeviceInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio::UiAudioDeviceInfo>
+    for crate::api::audio::UiAudioDeviceInfo
+{
+    fn into_into_dart(self) -> crate::api::audio::UiAudioDeviceInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio::UiAudioDeviceSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.host.into_into_dart().into_dart(),
+            self.output_device.into_into_dart().into_dart(),
+            self.input_device.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.buffer_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio::UiAudioDeviceSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio::UiAudioDeviceSettings>
+    for crate::api::audio::UiAudioDeviceSettings
+{
+    fn into_into_dart(self) -> crate::api::audio::UiAudioDeviceSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::project::UiAudioHardwareConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -6356,30 +9999,115 @@ impl flutter_rust_bridge::IntoDart for crate::api::project::UiAudioHardwareConfi
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::project::UiAudioHardwareConfig
+    for crate::api::project::UiAudioHardwareConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project::UiAudioHardwareConfig>
+    for crate::api::project::UiAudioHardwareConfig
+{
+    fn into_into_dart(self) -> crate::api::project::UiAudioHardwareConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio::UiBitPerSample {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::B8 => 0.into_dart(),
+            Self::B16 => 1.into_dart(),
+            Self::B24 => 2.into_dart(),
+            Self::B32 => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio::UiBitPerSample
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio::UiBitPerSample>
+    for crate::api::audio::UiBitPerSample
+{
+    fn into_into_dart(self) -> crate::api::audio::UiBitPerSample {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::mixer::UiBus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.channel.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::mixer::UiBus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::mixer::UiBus> for crate::api::mixer::UiBus {
+    fn into_into_dart(self) -> crate::api::mixer::UiBus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pattern::UiChordSpan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_tick.into_into_dart().into_dart(),
+            self.end_tick.into_into_dart().into_dart(),
+            self.root.into_into_dart().into_dart(),
+            self.chord_type.into_into_dart().into_dart(),
+            self.bass.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pattern::UiChordSpan
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::project::UiAudioHardwareConfig>
-    for crate::api::project::UiAudioHardwareConfig
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pattern::UiChordSpan>
+    for crate::api::pattern::UiChordSpan
 {
-    fn into_into_dart(self) -> crate::api::project::UiAudioHardwareConfig {
+    fn into_into_dart(self) -> crate::api::pattern::UiChordSpan {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::mixer::UiBus {
+impl flutter_rust_bridge::IntoDart for crate::api::pattern::UiChordType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.channel.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        match self {
+            Self::Major => 0.into_dart(),
+            Self::Minor => 1.into_dart(),
+            Self::Diminished => 2.into_dart(),
+            Self::Augmented => 3.into_dart(),
+            Self::Sus2 => 4.into_dart(),
+            Self::Sus4 => 5.into_dart(),
+            Self::Major6 => 6.into_dart(),
+            Self::Minor6 => 7.into_dart(),
+            Self::Dominant7 => 8.into_dart(),
+            Self::Major7 => 9.into_dart(),
+            Self::Minor7 => 10.into_dart(),
+            Self::MinorMajor7 => 11.into_dart(),
+            Self::HalfDiminished7 => 12.into_dart(),
+            Self::Diminished7 => 13.into_dart(),
+            Self::Add9 => 14.into_dart(),
+            Self::Dominant9 => 15.into_dart(),
+            Self::Major9 => 16.into_dart(),
+            Self::Minor9 => 17.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::mixer::UiBus {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::mixer::UiBus> for crate::api::mixer::UiBus {
-    fn into_into_dart(self) -> crate::api::mixer::UiBus {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pattern::UiChordType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pattern::UiChordType>
+    for crate::api::pattern::UiChordType
+{
+    fn into_into_dart(self) -> crate::api::pattern::UiChordType {
         self
     }
 }
@@ -6556,6 +10284,32 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::plugin::UiEffectTarget>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio::UiEngineLoad {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.load.into_into_dart().into_dart(),
+            self.average.into_into_dart().into_dart(),
+            self.peak.into_into_dart().into_dart(),
+            self.max_peak.into_into_dart().into_dart(),
+            self.overloads.into_into_dart().into_dart(),
+            self.xruns.into_into_dart().into_dart(),
+            self.plugins.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio::UiEngineLoad
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio::UiEngineLoad>
+    for crate::api::audio::UiEngineLoad
+{
+    fn into_into_dart(self) -> crate::api::audio::UiEngineLoad {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::project::UiGeneratorInstance {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -6631,6 +10385,167 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::plugin::UiGeneratorParameterS
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pattern::UiGroove {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.step_ticks.into_into_dart().into_dart(),
+            self.timings.into_into_dart().into_dart(),
+            self.velocities.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::pattern::UiGroove {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pattern::UiGroove>
+    for crate::api::pattern::UiGroove
+{
+    fn into_into_dart(self) -> crate::api::pattern::UiGroove {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::transport::UiLoopRegion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::transport::UiLoopRegion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::transport::UiLoopRegion>
+    for crate::api::transport::UiLoopRegion
+{
+    fn into_into_dart(self) -> crate::api::transport::UiLoopRegion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio::UiLoudnessReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.momentary.into_into_dart().into_dart(),
+            self.short_term.into_into_dart().into_dart(),
+            self.integrated.into_into_dart().into_dart(),
+            self.loudness_range.into_into_dart().into_dart(),
+            self.true_peak.into_into_dart().into_dart(),
+            self.max_momentary.into_into_dart().into_dart(),
+            self.max_short_term.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio::UiLoudnessReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio::UiLoudnessReport>
+    for crate::api::audio::UiLoudnessReport
+{
+    fn into_into_dart(self) -> crate::api::audio::UiLoudnessReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::mixer::UiMeterLevels {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.node.into_into_dart().into_dart(),
+            self.peak_left.into_into_dart().into_dart(),
+            self.peak_right.into_into_dart().into_dart(),
+            self.rms_left.into_into_dart().into_dart(),
+            self.rms_right.into_into_dart().into_dart(),
+            self.peak_hold_left.into_into_dart().into_dart(),
+            self.peak_hold_right.into_into_dart().into_dart(),
+            self.clipped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::mixer::UiMeterLevels
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::mixer::UiMeterLevels>
+    for crate::api::mixer::UiMeterLevels
+{
+    fn into_into_dart(self) -> crate::api::mixer::UiMeterLevels {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::transport::UiMetronomeSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.volume.into_into_dart().into_dart(),
+            self.target_bus_id.into_into_dart().into_dart(),
+            self.count_in_bars.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::transport::UiMetronomeSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::transport::UiMetronomeSettings>
+    for crate::api::transport::UiMetronomeSettings
+{
+    fn into_into_dart(self) -> crate::api::transport::UiMetronomeSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::midi::UiMidiOutputSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.send_clock.into_into_dart().into_dart(),
+            self.track_id.into_into_dart().into_dart(),
+            self.channel.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::midi::UiMidiOutputSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::midi::UiMidiOutputSettings>
+    for crate::api::midi::UiMidiOutputSettings
+{
+    fn into_into_dart(self) -> crate::api::midi::UiMidiOutputSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::UiMidiRecordMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Overdub => 0.into_dart(),
+            Self::Replace => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::UiMidiRecordMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::UiMidiRecordMode>
+    for crate::api::recording::UiMidiRecordMode
+{
+    fn into_into_dart(self) -> crate::api::recording::UiMidiRecordMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::mixer::UiMixerChannel {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -6739,6 +10654,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::mixer::UiMixerState>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::UiMonitorMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Off => 0.into_dart(),
+            Self::Always => 1.into_dart(),
+            Self::Auto => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::UiMonitorMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::UiMonitorMode>
+    for crate::api::recording::UiMonitorMode
+{
+    fn into_into_dart(self) -> crate::api::recording::UiMonitorMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::pattern::UiNote {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -6763,6 +10700,78 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::pattern::UiNote>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pattern::UiNoteTransform {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::pattern::UiNoteTransform::Transpose {
+                semitones,
+                fold_octaves,
+            } => [
+                0.into_dart(),
+                semitones.into_into_dart().into_dart(),
+                fold_octaves.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::pattern::UiNoteTransform::ScaleTranspose {
+                degrees,
+                root,
+                mode,
+            } => [
+                1.into_dart(),
+                degrees.into_into_dart().into_dart(),
+                root.into_into_dart().into_dart(),
+                mode.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::pattern::UiNoteTransform::VelocityScale { factor } => {
+                [2.into_dart(), factor.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::pattern::UiNoteTransform::VelocityCompress { amount } => {
+                [3.into_dart(), amount.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::pattern::UiNoteTransform::VelocityRandomize { amount } => {
+                [4.into_dart(), amount.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::pattern::UiNoteTransform::Legato => [5.into_dart()].into_dart(),
+            crate::api::pattern::UiNoteTransform::Reverse => [6.into_dart()].into_dart(),
+            crate::api::pattern::UiNoteTransform::Invert { pivot } => {
+                [7.into_dart(), pivot.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::pattern::UiNoteTransform::TimeStretch { factor } => {
+                [8.into_dart(), factor.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::pattern::UiNoteTransform::Humanize { timing, velocity } => [
+                9.into_dart(),
+                timing.into_into_dart().into_dart(),
+                velocity.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::pattern::UiNoteTransform::SplitOverlaps => [10.into_dart()].into_dart(),
+            crate::api::pattern::UiNoteTransform::MergeOverlaps => [11.into_dart()].into_dart(),
+            crate::api::pattern::UiNoteTransform::SnapToScale { root, mode } => [
+                12.into_dart(),
+                root.into_into_dart().into_dart(),
+                mode.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pattern::UiNoteTransform
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pattern::UiNoteTransform>
+    for crate::api::pattern::UiNoteTransform
+{
+    fn into_into_dart(self) -> crate::api::pattern::UiNoteTransform {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::plugin::UiParameterType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -6875,6 +10884,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::project::UiPluginInstance>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio::UiPluginLoad {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.slot.into_into_dart().into_dart(),
+            self.average.into_into_dart().into_dart(),
+            self.peak.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio::UiPluginLoad
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio::UiPluginLoad>
+    for crate::api::audio::UiPluginLoad
+{
+    fn into_into_dart(self) -> crate::api::audio::UiPluginLoad {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::plugin::UiPluginParameter {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -6896,33 +10927,109 @@ impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
     for crate::api::plugin::UiPluginParameter
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::plugin::UiPluginParameter>
-    for crate::api::plugin::UiPluginParameter
+impl flutter_rust_bridge::IntoIntoDart<crate::api::plugin::UiPluginParameter>
+    for crate::api::plugin::UiPluginParameter
+{
+    fn into_into_dart(self) -> crate::api::plugin::UiPluginParameter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio::UiPluginSlot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::audio::UiPluginSlot::Generator(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::audio::UiPluginSlot::Effect { node, effect_id } => [
+                1.into_dart(),
+                node.into_into_dart().into_dart(),
+                effect_id.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio::UiPluginSlot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio::UiPluginSlot>
+    for crate::api::audio::UiPluginSlot
+{
+    fn into_into_dart(self) -> crate::api::audio::UiPluginSlot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::project::UiProjectMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.author.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::project::UiProjectMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::project::UiProjectMetadata>
+    for crate::api::project::UiProjectMetadata
+{
+    fn into_into_dart(self) -> crate::api::project::UiProjectMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pattern::UiQuantizeSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.note_value.into_into_dart().into_dart(),
+            self.triplet.into_into_dart().into_dart(),
+            self.strength.into_into_dart().into_dart(),
+            self.window.into_into_dart().into_dart(),
+            self.quantize_ends.into_into_dart().into_dart(),
+            self.swing.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pattern::UiQuantizeSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pattern::UiQuantizeSettings>
+    for crate::api::pattern::UiQuantizeSettings
 {
-    fn into_into_dart(self) -> crate::api::plugin::UiPluginParameter {
+    fn into_into_dart(self) -> crate::api::pattern::UiQuantizeSettings {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::project::UiProjectMetadata {
+impl flutter_rust_bridge::IntoDart for crate::api::recording::UiRecordedClip {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.name.into_into_dart().into_dart(),
-            self.author.into_into_dart().into_dart(),
-            self.version.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
+            self.track_id.into_into_dart().into_dart(),
+            self.clip.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::project::UiProjectMetadata
+    for crate::api::recording::UiRecordedClip
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::project::UiProjectMetadata>
-    for crate::api::project::UiProjectMetadata
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::UiRecordedClip>
+    for crate::api::recording::UiRecordedClip
 {
-    fn into_into_dart(self) -> crate::api::project::UiProjectMetadata {
+    fn into_into_dart(self) -> crate::api::recording::UiRecordedClip {
         self
     }
 }
@@ -7020,6 +11127,56 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::mixer::UiRoutingNode>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pattern::UiScale {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.root.into_into_dart().into_dart(),
+            self.mode.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::pattern::UiScale {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pattern::UiScale>
+    for crate::api::pattern::UiScale
+{
+    fn into_into_dart(self) -> crate::api::pattern::UiScale {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pattern::UiScaleMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Major => 0.into_dart(),
+            Self::NaturalMinor => 1.into_dart(),
+            Self::HarmonicMinor => 2.into_dart(),
+            Self::MelodicMinor => 3.into_dart(),
+            Self::Dorian => 4.into_dart(),
+            Self::Phrygian => 5.into_dart(),
+            Self::Lydian => 6.into_dart(),
+            Self::Mixolydian => 7.into_dart(),
+            Self::Locrian => 8.into_dart(),
+            Self::MajorPentatonic => 9.into_dart(),
+            Self::MinorPentatonic => 10.into_dart(),
+            Self::Blues => 11.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pattern::UiScaleMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pattern::UiScaleMode>
+    for crate::api::pattern::UiScaleMode
+{
+    fn into_into_dart(self) -> crate::api::pattern::UiScaleMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::track::UiSourceType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -7041,6 +11198,71 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::track::UiSourceType>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pattern::UiSwingDivision {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Eighth => 0.into_dart(),
+            Self::Sixteenth => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pattern::UiSwingDivision
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pattern::UiSwingDivision>
+    for crate::api::pattern::UiSwingDivision
+{
+    fn into_into_dart(self) -> crate::api::pattern::UiSwingDivision {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::transport::UiTempoPoint {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tick.into_into_dart().into_dart(),
+            self.bpm.into_into_dart().into_dart(),
+            self.linear.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::transport::UiTempoPoint
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::transport::UiTempoPoint>
+    for crate::api::transport::UiTempoPoint
+{
+    fn into_into_dart(self) -> crate::api::transport::UiTempoPoint {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::transport::UiTimeSignatureEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bar.into_into_dart().into_dart(),
+            self.numerator.into_into_dart().into_dart(),
+            self.denominator.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::transport::UiTimeSignatureEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::transport::UiTimeSignatureEvent>
+    for crate::api::transport::UiTimeSignatureEvent
+{
+    fn into_into_dart(self) -> crate::api::transport::UiTimeSignatureEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::project::UiTrack {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -7221,6 +11443,30 @@ impl SseEncode for std::collections::HashMap<u32, crate::api::project::UiTrack>
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        Vec<crate::api::mixer::UiMeterLevels>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        Vec<crate::api::plugins::eq::UiResponseCurvePoint>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         crate::api::mixer::UiMixerParamEvent,
@@ -7376,6 +11622,26 @@ impl SseEncode for Vec<crate::api::mixer::ParameterSpecDTO> {
     }
 }
 
+impl SseEncode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i32>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7386,6 +11652,16 @@ impl SseEncode for Vec<i8> {
     }
 }
 
+impl SseEncode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u16>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7488,6 +11764,26 @@ impl SseEncode for Vec<(u32, crate::api::project::UiTrack)> {
     }
 }
 
+impl SseEncode for Vec<crate::api::audio::UiAudioDeviceInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::audio::UiAudioDeviceInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::pattern::UiChordSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::pattern::UiChordSpan>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::project::UiClip> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7538,6 +11834,16 @@ impl SseEncode for Vec<crate::api::plugin::UiGeneratorParameterSnapshot> {
     }
 }
 
+impl SseEncode for Vec<crate::api::mixer::UiMeterLevels> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::mixer::UiMeterLevels>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::mixer::UiMixerChannelParams> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7578,114 +11884,224 @@ impl SseEncode for Vec<crate::api::plugin::UiPluginInfo> {
     }
 }
 
-impl SseEncode for Vec<crate::api::plugin::UiPluginParameter> {
+impl SseEncode for Vec<crate::api::audio::UiPluginLoad> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::audio::UiPluginLoad>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::plugin::UiPluginParameter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::plugin::UiPluginParameter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::recording::UiRecordedClip> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::recording::UiRecordedClip>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::plugins::eq::UiResponseCurvePoint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::plugins::eq::UiResponseCurvePoint>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::mixer::UiRoutingConnection> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::mixer::UiRoutingConnection>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::transport::UiTempoPoint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::transport::UiTempoPoint>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::transport::UiTimeSignatureEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::transport::UiTimeSignatureEvent>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode
+    for Option<std::collections::HashMap<u32, crate::api::project::AudioWaveformUiForSourceList>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <std::collections::HashMap<u32, crate::api::project::AudioWaveformUiForSourceList>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::project::AudioWaveformUiForAudioProperties> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::plugin::UiPluginParameter>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::project::AudioWaveformUiForAudioProperties>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::plugins::eq::UiResponseCurvePoint> {
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::plugins::eq::UiResponseCurvePoint>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::mixer::UiRoutingConnection> {
+impl SseEncode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mixer::UiRoutingConnection>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<i64> {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <i64>::sse_encode(value, serializer);
+            <u32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<u64> {
+impl SseEncode for Option<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
+            <u8>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode
-    for Option<std::collections::HashMap<u32, crate::api::project::AudioWaveformUiForSourceList>>
-{
+impl SseEncode for Option<crate::api::audio::UiEngineLoad> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <std::collections::HashMap<u32, crate::api::project::AudioWaveformUiForSourceList>>::sse_encode(value, serializer);
+            <crate::api::audio::UiEngineLoad>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for Option<crate::api::pattern::UiGroove> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+            <crate::api::pattern::UiGroove>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::api::project::AudioWaveformUiForAudioProperties> {
+impl SseEncode for Option<crate::api::transport::UiLoopRegion> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::project::AudioWaveformUiForAudioProperties>::sse_encode(value, serializer);
+            <crate::api::transport::UiLoopRegion>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<bool> {
+impl SseEncode for Option<crate::api::audio::UiLoudnessReport> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <bool>::sse_encode(value, serializer);
+            <crate::api::audio::UiLoudnessReport>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<f32> {
+impl SseEncode for Option<crate::api::pattern::UiScale> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <f32>::sse_encode(value, serializer);
+            <crate::api::pattern::UiScale>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<u32> {
+impl SseEncode for Option<crate::api::pattern::UiScaleMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
+            <crate::api::pattern::UiScaleMode>::sse_encode(value, serializer);
         }
     }
 }
@@ -7871,6 +12287,28 @@ impl SseEncode for crate::api::project::UiApplicationState {
     }
 }
 
+impl SseEncode for crate::api::audio::UiAudioDeviceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <bool>::sse_encode(self.is_default, serializer);
+        <Vec<u32>>::sse_encode(self.sample_rates, serializer);
+        <Vec<u16>>::sse_encode(self.channel_counts, serializer);
+        <Vec<u32>>::sse_encode(self.buffer_sizes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio::UiAudioDeviceSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.host, serializer);
+        <Option<String>>::sse_encode(self.output_device, serializer);
+        <Option<String>>::sse_encode(self.input_device, serializer);
+        <Option<u32>>::sse_encode(self.sample_rate, serializer);
+        <Option<u32>>::sse_encode(self.buffer_size, serializer);
+    }
+}
+
 impl SseEncode for crate::api::project::UiAudioHardwareConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7882,6 +12320,24 @@ impl SseEncode for crate::api::project::UiAudioHardwareConfig {
     }
 }
 
+impl SseEncode for crate::api::audio::UiBitPerSample {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::audio::UiBitPerSample::B8 => 0,
+                crate::api::audio::UiBitPerSample::B16 => 1,
+                crate::api::audio::UiBitPerSample::B24 => 2,
+                crate::api::audio::UiBitPerSample::B32 => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::mixer::UiBus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7891,6 +12347,50 @@ impl SseEncode for crate::api::mixer::UiBus {
     }
 }
 
+impl SseEncode for crate::api::pattern::UiChordSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.start_tick, serializer);
+        <u64>::sse_encode(self.end_tick, serializer);
+        <u8>::sse_encode(self.root, serializer);
+        <crate::api::pattern::UiChordType>::sse_encode(self.chord_type, serializer);
+        <Option<u8>>::sse_encode(self.bass, serializer);
+        <String>::sse_encode(self.name, serializer);
+    }
+}
+
+impl SseEncode for crate::api::pattern::UiChordType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::pattern::UiChordType::Major => 0,
+                crate::api::pattern::UiChordType::Minor => 1,
+                crate::api::pattern::UiChordType::Diminished => 2,
+                crate::api::pattern::UiChordType::Augmented => 3,
+                crate::api::pattern::UiChordType::Sus2 => 4,
+                crate::api::pattern::UiChordType::Sus4 => 5,
+                crate::api::pattern::UiChordType::Major6 => 6,
+                crate::api::pattern::UiChordType::Minor6 => 7,
+                crate::api::pattern::UiChordType::Dominant7 => 8,
+                crate::api::pattern::UiChordType::Major7 => 9,
+                crate::api::pattern::UiChordType::Minor7 => 10,
+                crate::api::pattern::UiChordType::MinorMajor7 => 11,
+                crate::api::pattern::UiChordType::HalfDiminished7 => 12,
+                crate::api::pattern::UiChordType::Diminished7 => 13,
+                crate::api::pattern::UiChordType::Add9 => 14,
+                crate::api::pattern::UiChordType::Dominant9 => 15,
+                crate::api::pattern::UiChordType::Major9 => 16,
+                crate::api::pattern::UiChordType::Minor9 => 17,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::project::UiClip {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -7996,6 +12496,19 @@ impl SseEncode for crate::api::plugin::UiEffectTarget {
     }
 }
 
+impl SseEncode for crate::api::audio::UiEngineLoad {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.load, serializer);
+        <f32>::sse_encode(self.average, serializer);
+        <f32>::sse_encode(self.peak, serializer);
+        <f32>::sse_encode(self.max_peak, serializer);
+        <u64>::sse_encode(self.overloads, serializer);
+        <u64>::sse_encode(self.xruns, serializer);
+        <Vec<crate::api::audio::UiPluginLoad>>::sse_encode(self.plugins, serializer);
+    }
+}
+
 impl SseEncode for crate::api::project::UiGeneratorInstance {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8035,6 +12548,87 @@ impl SseEncode for crate::api::plugin::UiGeneratorParameterSnapshot {
     }
 }
 
+impl SseEncode for crate::api::pattern::UiGroove {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.step_ticks, serializer);
+        <Vec<i32>>::sse_encode(self.timings, serializer);
+        <Vec<f32>>::sse_encode(self.velocities, serializer);
+        <f32>::sse_encode(self.amount, serializer);
+    }
+}
+
+impl SseEncode for crate::api::transport::UiLoopRegion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio::UiLoudnessReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.momentary, serializer);
+        <f32>::sse_encode(self.short_term, serializer);
+        <f32>::sse_encode(self.integrated, serializer);
+        <f32>::sse_encode(self.loudness_range, serializer);
+        <f32>::sse_encode(self.true_peak, serializer);
+        <f32>::sse_encode(self.max_momentary, serializer);
+        <f32>::sse_encode(self.max_short_term, serializer);
+    }
+}
+
+impl SseEncode for crate::api::mixer::UiMeterLevels {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::mixer::UiRoutingNode>::sse_encode(self.node, serializer);
+        <f32>::sse_encode(self.peak_left, serializer);
+        <f32>::sse_encode(self.peak_right, serializer);
+        <f32>::sse_encode(self.rms_left, serializer);
+        <f32>::sse_encode(self.rms_right, serializer);
+        <f32>::sse_encode(self.peak_hold_left, serializer);
+        <f32>::sse_encode(self.peak_hold_right, serializer);
+        <bool>::sse_encode(self.clipped, serializer);
+    }
+}
+
+impl SseEncode for crate::api::transport::UiMetronomeSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <f32>::sse_encode(self.volume, serializer);
+        <Option<u32>>::sse_encode(self.target_bus_id, serializer);
+        <u8>::sse_encode(self.count_in_bars, serializer);
+    }
+}
+
+impl SseEncode for crate::api::midi::UiMidiOutputSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.send_clock, serializer);
+        <Option<u32>>::sse_encode(self.track_id, serializer);
+        <u8>::sse_encode(self.channel, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recording::UiMidiRecordMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::recording::UiMidiRecordMode::Overdub => 0,
+                crate::api::recording::UiMidiRecordMode::Replace => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::mixer::UiMixerChannel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8104,6 +12698,23 @@ impl SseEncode for crate::api::mixer::UiMixerState {
     }
 }
 
+impl SseEncode for crate::api::recording::UiMonitorMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::recording::UiMonitorMode::Off => 0,
+                crate::api::recording::UiMonitorMode::Always => 1,
+                crate::api::recording::UiMonitorMode::Auto => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::pattern::UiNote {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8118,6 +12729,77 @@ impl SseEncode for crate::api::pattern::UiNote {
     }
 }
 
+impl SseEncode for crate::api::pattern::UiNoteTransform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::pattern::UiNoteTransform::Transpose {
+                semitones,
+                fold_octaves,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <i16>::sse_encode(semitones, serializer);
+                <bool>::sse_encode(fold_octaves, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::ScaleTranspose {
+                degrees,
+                root,
+                mode,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <i32>::sse_encode(degrees, serializer);
+                <u8>::sse_encode(root, serializer);
+                <crate::api::pattern::UiScaleMode>::sse_encode(mode, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::VelocityScale { factor } => {
+                <i32>::sse_encode(2, serializer);
+                <f32>::sse_encode(factor, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::VelocityCompress { amount } => {
+                <i32>::sse_encode(3, serializer);
+                <f32>::sse_encode(amount, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::VelocityRandomize { amount } => {
+                <i32>::sse_encode(4, serializer);
+                <u8>::sse_encode(amount, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::Legato => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::Reverse => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::Invert { pivot } => {
+                <i32>::sse_encode(7, serializer);
+                <u8>::sse_encode(pivot, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::TimeStretch { factor } => {
+                <i32>::sse_encode(8, serializer);
+                <f32>::sse_encode(factor, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::Humanize { timing, velocity } => {
+                <i32>::sse_encode(9, serializer);
+                <u64>::sse_encode(timing, serializer);
+                <u8>::sse_encode(velocity, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::SplitOverlaps => {
+                <i32>::sse_encode(10, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::MergeOverlaps => {
+                <i32>::sse_encode(11, serializer);
+            }
+            crate::api::pattern::UiNoteTransform::SnapToScale { root, mode } => {
+                <i32>::sse_encode(12, serializer);
+                <u8>::sse_encode(root, serializer);
+                <crate::api::pattern::UiScaleMode>::sse_encode(mode, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for crate::api::plugin::UiParameterType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8173,6 +12855,15 @@ impl SseEncode for crate::api::project::UiPluginInstance {
     }
 }
 
+impl SseEncode for crate::api::audio::UiPluginLoad {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::audio::UiPluginSlot>::sse_encode(self.slot, serializer);
+        <f32>::sse_encode(self.average, serializer);
+        <f32>::sse_encode(self.peak, serializer);
+    }
+}
+
 impl SseEncode for crate::api::plugin::UiPluginParameter {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8189,6 +12880,26 @@ impl SseEncode for crate::api::plugin::UiPluginParameter {
     }
 }
 
+impl SseEncode for crate::api::audio::UiPluginSlot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::audio::UiPluginSlot::Generator(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <u32>::sse_encode(field0, serializer);
+            }
+            crate::api::audio::UiPluginSlot::Effect { node, effect_id } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::mixer::UiRoutingNode>::sse_encode(node, serializer);
+                <u32>::sse_encode(effect_id, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for crate::api::project::UiProjectMetadata {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8199,6 +12910,26 @@ impl SseEncode for crate::api::project::UiProjectMetadata {
     }
 }
 
+impl SseEncode for crate::api::pattern::UiQuantizeSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.note_value, serializer);
+        <bool>::sse_encode(self.triplet, serializer);
+        <f32>::sse_encode(self.strength, serializer);
+        <f32>::sse_encode(self.window, serializer);
+        <bool>::sse_encode(self.quantize_ends, serializer);
+        <f32>::sse_encode(self.swing, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recording::UiRecordedClip {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.track_id, serializer);
+        <crate::api::project::UiClip>::sse_encode(self.clip, serializer);
+    }
+}
+
 impl SseEncode for crate::api::track::UiResizeEdge {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8255,6 +12986,41 @@ impl SseEncode for crate::api::mixer::UiRoutingNode {
     }
 }
 
+impl SseEncode for crate::api::pattern::UiScale {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.root, serializer);
+        <crate::api::pattern::UiScaleMode>::sse_encode(self.mode, serializer);
+        <String>::sse_encode(self.name, serializer);
+    }
+}
+
+impl SseEncode for crate::api::pattern::UiScaleMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::pattern::UiScaleMode::Major => 0,
+                crate::api::pattern::UiScaleMode::NaturalMinor => 1,
+                crate::api::pattern::UiScaleMode::HarmonicMinor => 2,
+                crate::api::pattern::UiScaleMode::MelodicMinor => 3,
+                crate::api::pattern::UiScaleMode::Dorian => 4,
+                crate::api::pattern::UiScaleMode::Phrygian => 5,
+                crate::api::pattern::UiScaleMode::Lydian => 6,
+                crate::api::pattern::UiScaleMode::Mixolydian => 7,
+                crate::api::pattern::UiScaleMode::Locrian => 8,
+                crate::api::pattern::UiScaleMode::MajorPentatonic => 9,
+                crate::api::pattern::UiScaleMode::MinorPentatonic => 10,
+                crate::api::pattern::UiScaleMode::Blues => 11,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::track::UiSourceType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -8271,6 +13037,40 @@ impl SseEncode for crate::api::track::UiSourceType {
     }
 }
 
+impl SseEncode for crate::api::pattern::UiSwingDivision {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::pattern::UiSwingDivision::Eighth => 0,
+                crate::api::pattern::UiSwingDivision::Sixteenth => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::transport::UiTempoPoint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.tick, serializer);
+        <f32>::sse_encode(self.bpm, serializer);
+        <bool>::sse_encode(self.linear, serializer);
+    }
+}
+
+impl SseEncode for crate::api::transport::UiTimeSignatureEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.bar, serializer);
+        <u8>::sse_encode(self.numerator, serializer);
+        <u8>::sse_encode(self.denominator, serializer);
+    }
+}
+
 impl SseEncode for crate::api::project::UiTrack {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3,10 +3,9 @@
 use std::{path::PathBuf, sync::Arc};
 
 use memmap2::MmapOptions;
-use rtrb::RingBuffer;
 
 pub(crate) use karbeat_core::{
-    audio::{backend::start_audio_stream, render_state::AudioRenderState},
+    audio::backend::start_audio_engine,
     commands::AudioCommand,
     context::{ctx, INIT_LOGGER},
     core::project::track::audio_waveform::AudioWaveform,
@@ -62,35 +61,18 @@ fn generate_startup_beep() -> AudioWaveform {
     }
 }
 
+/// Starts the engine on the backend picked at startup (`KARBEAT_AUDIO_BACKEND`, the sound card
+/// by default) with the device settings from the user settings file.
 pub fn init_engine() {
-    let initial_state = {
-        let app = ctx().app_state.read();
-        AudioRenderState::from(&*app)
-    };
-
-    log::info!(
-        "Init Engine with Buffer Size: {}",
-        initial_state.graph.buffer_size
-    );
-    let (state_in, state_out) = triple_buffer::TripleBuffer::new(&initial_state).split();
-
-    {
-        let mut render_state_guard = ctx().render_state_producer.lock();
-        *render_state_guard = Some(state_in);
-    }
-    // Capacity 128 is plenty for manual clicks
-    let (cmd_prod, cmd_cons) = RingBuffer::new(128);
-
-    // Store Producer in context
-    let mut guard = ctx().command_sender.lock();
-    *guard = Some(cmd_prod);
-
-    match start_audio_stream(state_out, cmd_cons, initial_state) {
+    match start_audio_engine() {
         Ok(_) => {
-            log::info!("Audio Engine Successfully initialized");
+            log::info!(
+                "Audio Engine Successfully initialized with Buffer Size: {}",
+                ctx().app_state.read().audio_config.buffer_size
+            );
 
             // SEND STARTUP BEEP
-            if let Some(producer) = guard.as_mut() {
+            if let Some(producer) = ctx().command_sender.lock().as_mut() {
                 let beep_waveform = generate_startup_beep();
                 let _ = producer.push(AudioCommand::PlayOneShot(beep_waveform));
                 log::info!("Startup beep command sent");
@@ -5,17 +5,21 @@
 
 use indexmap::IndexMap;
 use karbeat_dsp::{envelope::{EnvelopeSettings, EnvelopeStage}, filter::SimpleFilterMode};
-use karbeat_plugin_types::ParameterSpec;
+use karbeat_plugin_types::{ Param, ParameterSpec };
+use smallvec::{ SmallVec, smallvec };
 
 
+/// How long gain and cutoff changes glide for, so automating them doesn't click
+const PARAM_SMOOTHING_SECONDS: f32 = 0.02;
+
 // ============================================================================
 // FILTER
 // ============================================================================
 
 /// State-variable filter for the synthesizer
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct SynthFilter {
-    pub cutoff: f32,    // Hz (20.0 - 20000.0)
+    pub cutoff: Param<f32>, // Hz (20.0 - 20000.0)
     pub resonance: f32, // 0.0 to 0.95
     pub mode: SimpleFilterMode,
     // Internal state (Stereo)
@@ -28,7 +32,8 @@ pub struct SynthFilter {
 impl Default for SynthFilter {
     fn default() -> Self {
         Self {
-            cutoff: 2000.0,
+            cutoff: Param::new_float(1, "Cutoff", "Filter", 2000.0, 20.0, 20000.0, 1.0)
+                .with_smoothing(PARAM_SMOOTHING_SECONDS),
             resonance: 0.2,
             mode: SimpleFilterMode::LowPass,
             s1_l: 0.0,
@@ -46,6 +51,19 @@ impl SynthFilter {
         self.s2_l = 0.0;
         self.s1_r = 0.0;
         self.s2_r = 0.0;
+        self.cutoff.reset_smoothing();
+    }
+
+    /// `g` and the inverse denominator of the TPT filter at `cutoff`
+    fn coefficients(cutoff: f32, k: f32, sample_rate: f32) -> (f32, f32) {
+        // Constrain cutoff to slightly below Nyquist to prevent tan() explosion
+        let nyquist = sample_rate * 0.499;
+        let clamped_cutoff = cutoff.clamp(20.0, nyquist);
+
+        // g = tan(PI * cutoff / sample_rate) maps the frequency domain correctly
+        let g = (std::f32::consts::PI * clamped_cutoff / sample_rate).tan();
+
+        (g, 1.0 / (1.0 + g * (g + k)))
     }
 
     /// Process a stereo interleaved buffer through the filter
@@ -55,24 +73,22 @@ impl SynthFilter {
             return;
         }
 
-        // Constrain cutoff to slightly below Nyquist to prevent tan() explosion
-        let nyquist = sample_rate * 0.499;
-        let clamped_cutoff = self.cutoff.clamp(20.0, nyquist);
-
-        // g = tan(PI * cutoff / sample_rate) maps the frequency domain correctly
-        let g = (std::f32::consts::PI * clamped_cutoff / sample_rate).tan();
-
         // Map resonance (0.0 -> 0.95) to damping (k)
         // res 0.0 => k = 2.0 (Butterworth/No resonance)
         // res 0.95 => k = 0.1 (High resonance)
         let k = 2.0 - (2.0 * self.resonance.clamp(0.0, 0.95));
 
         // Pre-calculate inverse denominator for the loop
-        let denom_inv = 1.0 / (1.0 + g * (g + k));
+        let (mut g, mut denom_inv) = Self::coefficients(self.cutoff.smoothed(), k, sample_rate);
 
         let frames = buffer.len() / 2;
 
         for i in 0..frames {
+            // While the cutoff glides, the coefficients follow it sample by sample
+            if self.cutoff.is_smoothing() {
+                (g, denom_inv) = Self::coefficients(self.cutoff.next_smoothed(), k, sample_rate);
+            }
+
             let l_idx = i * 2;
             let r_idx = i * 2 + 1;
 
@@ -127,7 +143,9 @@ impl SynthFilter {
 pub struct SynthVoice {
     pub note: u8,
     pub velocity: u8,
-    pub phase: Vec<f32>, // Phase for each oscillator (flexible count)
+    /// Phase for each oscillator (flexible count). Inline up to 8, so starting a note on the
+    /// audio thread does not allocate
+    pub phase: SmallVec<[f32; 8]>,
 
     // Envelope State
     pub env_stage: EnvelopeStage,
@@ -144,7 +162,7 @@ impl SynthVoice {
         Self {
             note,
             velocity,
-            phase: vec![0.0; num_oscillators],
+            phase: smallvec![0.0; num_oscillators],
             env_stage: EnvelopeStage::Attack,
             env_level: 0.0,
             env_timer: 0.0,
@@ -246,7 +264,7 @@ pub struct StandardSynthBase {
     pub channels: usize,
     pub active_voices: Vec<SynthVoice>,
     pub voice_buffer: Vec<f32>,
-    pub gain: f32,
+    pub gain: Param<f32>,
     pub filter: SynthFilter,
     pub amp_envelope: EnvelopeSettings,
 }
@@ -260,21 +278,28 @@ impl Default for StandardSynthBase {
 impl StandardSynthBase {
     /// Create a new SynthBase with the given sample rate
     pub fn new(sample_rate: f32, channels: usize) -> Self {
-        Self {
+        let mut base = Self {
             sample_rate,
             active_voices: Vec::with_capacity(16),
             voice_buffer: Vec::with_capacity(512),
-            gain: 0.5,
+            gain: Param::new_float(0, "Master Gain", "Output", 0.5, 0.0, 1.0, 0.01).with_smoothing(
+                PARAM_SMOOTHING_SECONDS
+            ),
             filter: SynthFilter::default(),
             amp_envelope: EnvelopeSettings::default(),
             channels,
-        }
+        };
+        base.gain.set_sample_rate(sample_rate);
+        base.filter.cutoff.set_sample_rate(sample_rate);
+        base
     }
 
     /// Prepare for playback
     pub fn prepare(&mut self, sample_rate: f32, channels: usize, max_buffer_size: usize) {
         self.sample_rate = sample_rate;
         self.channels = channels;
+        self.gain.set_sample_rate(sample_rate);
+        self.filter.cutoff.set_sample_rate(sample_rate);
         if self.voice_buffer.len() < max_buffer_size {
             self.voice_buffer.resize(max_buffer_size, 0.0);
         }
@@ -284,13 +309,21 @@ impl StandardSynthBase {
     pub fn reset(&mut self) {
         self.active_voices.clear();
         self.filter.reset();
+        self.gain.reset_smoothing();
     }
 
     /// Apply filter and gain to a stereo buffer
     pub fn apply_filter_and_gain(&mut self, buffer: &mut [f32]) {
         self.filter.process(buffer, self.sample_rate);
-        for sample in buffer.iter_mut() {
-            *sample *= self.gain;
+        self.apply_gain(buffer);
+    }
+
+    /// Apply the master gain to a stereo buffer, gliding to it when it changes
+    pub fn apply_gain(&mut self, buffer: &mut [f32]) {
+        for frame in buffer.chunks_exact_mut(2) {
+            let gain = self.gain.next_smoothed();
+            frame[0] *= gain;
+            frame[1] *= gain;
         }
     }
 
@@ -313,11 +346,11 @@ impl StandardSynthBase {
     pub fn set_parameter(&mut self, id: u32, value: f32) -> bool {
         match id {
             0 => {
-                self.gain = value.clamp(0.0, 1.0);
+                self.gain.set_base(value);
                 true
             }
             1 => {
-                self.filter.cutoff = value.clamp(20.0, 20000.0);
+                self.filter.cutoff.set_base(value);
                 true
             }
             2 => {
@@ -351,8 +384,8 @@ impl StandardSynthBase {
     /// Get base parameters (IDs 0-7). Returns Some if parameter was handled.
     pub fn get_parameter(&self, id: u32) -> Option<f32> {
         match id {
-            0 => Some(self.gain),
-            1 => Some(self.filter.cutoff),
+            0 => Some(self.gain.get_base()),
+            1 => Some(self.filter.cutoff.get_base()),
             2 => Some(self.filter.resonance),
             3 => Some(self.filter.mode as u32 as f32),
             4 => Some(self.amp_envelope.attack.get()),
@@ -379,13 +412,13 @@ impl StandardSynthBase {
 
     pub fn get_parameter_specs(&self) -> Vec<ParameterSpec> {
         vec![
-            ParameterSpec::new_float(0, "Master Gain", "Output", self.gain, 0.0, 1.0, 0.5),
+            ParameterSpec::new_float(0, "Master Gain", "Output", self.gain.get_base(), 0.0, 1.0, 0.5),
             // Filter
             ParameterSpec::new_float(
                 1,
                 "Cutoff",
                 "Filter",
-                self.filter.cutoff,
+                self.filter.cutoff.get_base(),
                 20.0,
                 20000.0,
                 2000.0,
@@ -454,8 +487,8 @@ impl StandardSynthBase {
 
     pub fn apply_automation(&mut self, id: u32, value: f32) {
         match id {
-            0 => self.gain = value.clamp(0.0, 1.0),
-            1 => self.filter.cutoff = value.clamp(20.0, 20000.0),
+            0 => self.gain.apply_automation(value),
+            1 => self.filter.cutoff.apply_automation(value),
             2 => self.filter.resonance = value.clamp(0.0, 0.95),
             3 => self.filter.mode = SimpleFilterMode::from(value),
             4 => self.amp_envelope.attack.set_base(value),
@@ -467,10 +500,16 @@ impl StandardSynthBase {
     }
 
     pub fn clear_automation(&mut self, id: u32) {
-        // For base parameters, clearing automation means snapping back to the default value.
-        // We can reuse the logic from default_parameters().
-        if let Some(&default_val) = Self::default_parameters().get(&id) {
-            self.set_parameter(id, default_val);
+        match id {
+            0 => self.gain.clear_automation(),
+            1 => self.filter.cutoff.clear_automation(),
+            // For other base parameters, clearing automation means snapping back to the default value.
+            // We can reuse the logic from default_parameters().
+            _ => {
+                if let Some(&default_val) = Self::default_parameters().get(&id) {
+                    self.set_parameter(id, default_val);
+                }
+            }
         }
     }
 
@@ -24,6 +24,12 @@ pub trait KarbeatEffect: Send + Sync {
     /// * `buffer` - Interleaved stereo buffer [L, R, L, R...]
     fn process(&mut self, buffer: &mut [f32]);
 
+    /// Samples the output lags the input by (lookahead, linear-phase filters). The engine
+    /// delays the other paths through the mixer by as much, so everything stays in time.
+    fn latency_samples(&self) -> u32 {
+        0
+    }
+
     /// Set a parameter value (0.0 to 1.0)
     fn set_parameter(&mut self, id: u32, value: f32);
 
@@ -69,6 +75,12 @@ pub trait KarbeatGenerator: Send + Sync {
     /// * `midi_events` - List of events (Note On/Off) for this specific buffer block.
     fn process(&mut self, output_buffer: &mut [f32], midi_events: &[MidiEvent]);
 
+    /// Samples a note sounds after the event that started it. The engine delays the other
+    /// paths through the mixer by as much, so everything stays in time.
+    fn latency_samples(&self) -> u32 {
+        0
+    }
+
     fn set_parameter(&mut self, id: u32, value: f32);
     fn get_parameter(&self, id: u32) -> f32;
 
@@ -100,10 +112,22 @@ pub struct MidiEvent {
     pub data: MidiMessage,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MidiMessage {
     NoteOn { key: u8, velocity: u8 },
     NoteOff { key: u8 },
     ControlChange { controller: u8, value: u8 },
+    /// 14-bit bend amount, 8192 is the center (no bend)
+    PitchBend { value: u16 },
+    /// Pressure on a single held key
+    PolyAftertouch { key: u8, pressure: u8 },
+    /// Pressure applied to the whole channel
+    ChannelPressure { pressure: u8 },
+}
+
+impl MidiMessage {
+    /// Pitch bend value with no bend applied
+    pub const PITCH_BEND_CENTER: u16 = 8192;
 }
 
 pub enum KarbeatPlugin {
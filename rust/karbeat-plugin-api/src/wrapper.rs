@@ -48,6 +48,11 @@ pub trait RawSynthEngine: Send + Sync {
     /// Write stereo interleaved audio to output buffer.
     fn process(&mut self, base: &mut StandardSynthBase, output: &mut [f32], midi: &[MidiEvent]);
 
+    /// OPTIONAL: Samples the output lags the MIDI input by
+    fn latency_samples(&self) -> u32 {
+        0
+    }
+
     /// Set a custom parameter (IDs beyond base range, typically >= 8)
     fn set_custom_parameter(&mut self, id: u32, value: f32);
 
@@ -85,6 +90,11 @@ pub trait RawEffectEngine: Send + Sync {
     /// Process audio in-place using the shared base state.
     fn process(&mut self, base: &mut crate::effect_base::StandardEffectBase, buffer: &mut [f32]);
 
+    /// OPTIONAL: Samples the output lags the input by
+    fn latency_samples(&self) -> u32 {
+        0
+    }
+
     /// Reset internal effect state (delay lines, etc.)
     fn reset(&mut self);
 
@@ -122,6 +132,10 @@ pub trait EffectEngine<B: EffectBase>: Send + Sync {
     fn reset(&mut self);
     /// Engine receives the generic Base, allowing it to read custom state
     fn process(&mut self, base: &mut B, buffer: &mut [f32]);
+    /// OPTIONAL: Samples the output lags the input by
+    fn latency_samples(&self) -> u32 {
+        0
+    }
     fn get_custom_parameter(&self, id: u32) -> Option<f32>;
     fn set_custom_parameter(&mut self, id: u32, value: f32);
     fn default_parameters(&self) -> HashMap<u32, f32>;
@@ -188,6 +202,10 @@ impl<T: RawSynthEngine + Clone + 'static> KarbeatGenerator for RawSynthWrapper<T
             .process(&mut self.base, output_buffer, midi_events);
     }
 
+    fn latency_samples(&self) -> u32 {
+        self.engine.latency_samples()
+    }
+
     fn set_parameter(&mut self, id: u32, value: f32) {
         if !self.base.set_parameter(id, value) {
             self.engine.set_custom_parameter(id, value);
@@ -232,6 +250,47 @@ impl<T: RawSynthEngine + Clone + 'static> KarbeatGenerator for RawSynthWrapper<T
 // EFFECT WRAPPER
 // ============================================================================
 
+/// Delays the dry signal by the effect's latency, so it lines up with the wet one in the mix.
+#[derive(Clone, Default)]
+struct DryDelay {
+    ring: Vec<f32>,
+    position: usize,
+}
+
+impl DryDelay {
+    /// Makes room for a delay of `samples`, only allocating when it grows
+    fn reserve(&mut self, samples: usize) {
+        if self.ring.len() < samples {
+            self.ring.clear();
+            self.ring.resize(samples.next_power_of_two(), 0.0);
+            self.position = 0;
+        }
+    }
+
+    /// Delays `buffer` in place by `samples`
+    fn process(&mut self, buffer: &mut [f32], samples: usize) {
+        if samples == 0 {
+            return;
+        }
+        // The room is made in `prepare`, this only grows when the latency outgrows it
+        self.reserve(samples);
+
+        let len = self.ring.len();
+        let mut read = (self.position + len - samples) % len;
+        for sample in buffer.iter_mut() {
+            let dry = *sample;
+            *sample = self.ring[read];
+            self.ring[self.position] = dry;
+            read = (read + 1) % len;
+            self.position = (self.position + 1) % len;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.ring.fill(0.0);
+    }
+}
+
 /// Wrapper that adds automation and base parameters to any effect engine.
 /// Implements `KarbeatEffect` so it can be used directly in the audio engine.
 #[derive(Clone)]
@@ -242,6 +301,7 @@ pub struct RawEffectWrapper<T: RawEffectEngine + Clone> {
     pub base: StandardEffectBase,
     /// Buffer for dry signal (for mix processing)
     dry_buffer: Vec<f32>,
+    dry_delay: DryDelay,
 }
 
 impl<T: RawEffectEngine + Clone> RawEffectWrapper<T> {
@@ -251,6 +311,7 @@ impl<T: RawEffectEngine + Clone> RawEffectWrapper<T> {
             engine,
             base: StandardEffectBase::new(sample_rate, channels),
             dry_buffer: Vec::new(),
+            dry_delay: DryDelay::default(),
         }
     }
 
@@ -279,11 +340,13 @@ impl<T: RawEffectEngine + Clone + 'static> KarbeatEffect for RawEffectWrapper<T>
         if self.dry_buffer.len() < max_buffer_size * 2 {
             self.dry_buffer.resize(max_buffer_size * 2, 0.0);
         }
+        self.dry_delay.reserve((self.engine.latency_samples() as usize) * channels);
     }
 
     fn reset(&mut self) {
         self.base.reset();
         self.engine.reset();
+        self.dry_delay.reset();
     }
 
     fn process(&mut self, buffer: &mut [f32]) {
@@ -297,6 +360,8 @@ impl<T: RawEffectEngine + Clone + 'static> KarbeatEffect for RawEffectWrapper<T>
             self.dry_buffer.resize(buf_len, 0.0);
         }
         self.dry_buffer[..buf_len].copy_from_slice(buffer);
+        let latency = (self.engine.latency_samples() as usize) * self.base.channels;
+        self.dry_delay.process(&mut self.dry_buffer[..buf_len], latency);
 
         // Process through engine
         self.engine.process(&mut self.base, buffer);
@@ -305,6 +370,11 @@ impl<T: RawEffectEngine + Clone + 'static> KarbeatEffect for RawEffectWrapper<T>
         self.base.apply_mix(&self.dry_buffer[..buf_len], buffer);
     }
 
+    fn latency_samples(&self) -> u32 {
+        // A bypassed effect passes its input straight through
+        if self.base.bypass { 0 } else { self.engine.latency_samples() }
+    }
+
     fn set_parameter(&mut self, id: u32, value: f32) {
         if !self.base.set_parameter(id, value) {
             self.engine.set_custom_parameter(id, value);
@@ -355,6 +425,7 @@ where
     base: B,
     engine: E,
     dry_buffer: Vec<f32>,
+    dry_delay: DryDelay,
 }
 
 impl<E, B> EffectWrapper<E, B>
@@ -367,6 +438,7 @@ where
             base,
             engine,
             dry_buffer: Vec::new(),
+            dry_delay: DryDelay::default(),
         }
     }
 
@@ -395,11 +467,13 @@ where
         if self.dry_buffer.len() < max_buffer_size * 2 {
             self.dry_buffer.resize(max_buffer_size * 2, 0.0);
         }
+        self.dry_delay.reserve((self.engine.latency_samples() as usize) * channels);
     }
 
     fn reset(&mut self) {
         self.base.reset();
         self.engine.reset();
+        self.dry_delay.reset();
     }
 
     fn process(&mut self, buffer: &mut [f32]) {
@@ -413,6 +487,8 @@ where
             self.dry_buffer.resize(buf_len, 0.0);
         }
         self.dry_buffer[..buf_len].copy_from_slice(buffer);
+        let latency = (self.engine.latency_samples() as usize) * self.base.channels();
+        self.dry_delay.process(&mut self.dry_buffer[..buf_len], latency);
 
         // Pass the generic base to the engine
         self.engine.process(&mut self.base, buffer);
@@ -421,6 +497,10 @@ where
         self.base.apply_mix(&self.dry_buffer[..buf_len], buffer);
     }
 
+    fn latency_samples(&self) -> u32 {
+        if self.base.is_bypass() { 0 } else { self.engine.latency_samples() }
+    }
+
     fn set_parameter(&mut self, id: u32, value: f32) {
         if !self.base.set_parameter(id, value) {
             self.engine.set_custom_parameter(id, value);
@@ -210,6 +210,23 @@ pub struct Param<T: ParamType> {
     current_value: T,
 
     pub bounds: ParamBounds<T>,
+
+    /// Glide of the DSP value toward `current_value`, off unless asked for with `with_smoothing`
+    #[serde(skip)]
+    smoothing: Smoothing,
+}
+
+/// Linear glide toward a parameter's latest value, so jumps from automation or the UI don't click.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Smoothing {
+    /// Length of a glide, 0 jumps straight to the new value
+    seconds: f32,
+    /// Length of a glide at the prepared sample rate
+    length: u32,
+    value: f32,
+    target: f32,
+    step: f32,
+    remaining: u32,
 }
 
 impl<T: ParamType> Param<T> {
@@ -238,7 +255,12 @@ impl<T: ParamType> Param<T> {
     /// Apply an automation frame from the sequencer.
     /// This modifies `current_value` but leaves `base_value` untouched.
     pub fn apply_automation(&mut self, automated_f32: f32) {
-        self.current_value = T::from_f32_clamped(automated_f32, &self.bounds);
+        self.current_value = self.automated_value(automated_f32);
+    }
+
+    /// The value an automation frame would set, leaving the parameter untouched.
+    pub fn automated_value(&self, automated_f32: f32) -> T {
+        T::from_f32_clamped(automated_f32, &self.bounds)
     }
 
     /// Clear automation and snap back to the user's base value.
@@ -307,8 +329,67 @@ impl Param<f32> {
             base_value: default.clamp(min, max),
             current_value: default.clamp(min, max),
             bounds: ParamBounds::Continuous { min, max, step },
+            smoothing: Smoothing::default(),
         }
     }
+
+    /// Glides the value returned by `next_smoothed` over `seconds` whenever the parameter
+    /// changes, instead of jumping. Takes effect once `set_sample_rate` is called.
+    pub fn with_smoothing(mut self, seconds: f32) -> Self {
+        self.smoothing.seconds = seconds.max(0.0);
+        self.reset_smoothing();
+        self
+    }
+
+    /// Times glides against `sample_rate`, call it from the plugin's `prepare`.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.smoothing.length = (self.smoothing.seconds * sample_rate.max(0.0)).round() as u32;
+    }
+
+    /// Advances the glide by one sample and returns the value to use for it.
+    #[inline]
+    pub fn next_smoothed(&mut self) -> f32 {
+        let target = self.current_value;
+        let smoothing = &mut self.smoothing;
+        if target != smoothing.target {
+            smoothing.target = target;
+            smoothing.remaining = smoothing.length;
+            if smoothing.length == 0 {
+                smoothing.value = target;
+            } else {
+                smoothing.step = (target - smoothing.value) / (smoothing.length as f32);
+            }
+        }
+        if smoothing.remaining > 0 {
+            smoothing.remaining -= 1;
+            smoothing.value = if smoothing.remaining == 0 {
+                target
+            } else {
+                smoothing.value + smoothing.step
+            };
+        }
+        smoothing.value
+    }
+
+    /// The value of the last `next_smoothed` call, without advancing.
+    pub fn smoothed(&self) -> f32 {
+        if self.smoothing.length == 0 {
+            return self.current_value;
+        }
+        self.smoothing.value
+    }
+
+    /// Whether `next_smoothed` would return something else than the last value.
+    pub fn is_smoothing(&self) -> bool {
+        self.smoothing.remaining > 0 || self.smoothing.target != self.current_value
+    }
+
+    /// Jumps to the current value, dropping any glide in progress (e.g. on reset).
+    pub fn reset_smoothing(&mut self) {
+        self.smoothing.value = self.current_value;
+        self.smoothing.target = self.current_value;
+        self.smoothing.remaining = 0;
+    }
 }
 
 impl Param<bool> {
@@ -320,6 +401,7 @@ impl Param<bool> {
             base_value: default,
             current_value: default,
             bounds: ParamBounds::Toggle,
+            smoothing: Smoothing::default(),
         }
     }
 }
@@ -345,6 +427,7 @@ impl Param<usize> {
                     .map(|s| s.to_string())
                     .collect(),
             },
+            smoothing: Smoothing::default(),
         }
     }
 }
@@ -365,6 +448,7 @@ impl<T: EnumParam> Param<T> {
                     .map(|s| s.to_string())
                     .collect(),
             },
+            smoothing: Smoothing::default(),
         }
     }
 }
@@ -377,3 +461,28 @@ pub trait AutoParams {
     fn auto_clear_automation(&mut self, id: u32);
     fn auto_get_parameter_specs(&self) -> Vec<ParameterSpec>;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn smoothed_values_glide_to_automation() {
+        let mut cutoff = Param::new_float(1, "Cutoff", "Filter", 100.0, 20.0, 20000.0, 1.0)
+            .with_smoothing(0.001);
+        cutoff.set_sample_rate(4000.0);
+        assert_eq!(cutoff.next_smoothed(), 100.0);
+
+        // A jump is spread over 4 samples, then held
+        cutoff.apply_automation(500.0);
+        let glide: Vec<f32> = (0..5).map(|_| cutoff.next_smoothed()).collect();
+        assert_eq!(glide, [200.0, 300.0, 400.0, 500.0, 500.0]);
+        assert!(!cutoff.is_smoothing());
+
+        // Without smoothing the value jumps
+        let mut gain = Param::new_float(2, "Gain", "Output", 0.5, 0.0, 1.0, 0.01);
+        gain.set_sample_rate(4000.0);
+        gain.set_base(1.0);
+        assert_eq!(gain.next_smoothed(), 1.0);
+    }
+}
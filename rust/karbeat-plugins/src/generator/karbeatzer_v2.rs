@@ -196,9 +196,7 @@ impl RawSynthEngine for KarbeatzerEngine {
                 }
 
                 // Apply gain
-                for sample in out_slice.iter_mut() {
-                    *sample *= base.gain;
-                }
+                base.apply_gain(out_slice);
             }
 
             // Process MIDI events
@@ -168,9 +168,7 @@ impl RawSynthEngine for MyRetroEngine {
                 }
 
                 // Apply Master Synth Gain
-                for sample in out_slice.iter_mut() {
-                    *sample *= base.gain;
-                }
+                base.apply_gain(out_slice);
             }
 
             // Handle MIDI events at this exact frame